@@ -0,0 +1,144 @@
+#![allow(unused_mut)]
+
+use crdtosphere::prelude::*;
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+
+const BATCH_SIZES: [usize; 5] = [2, 4, 8, 16, 32];
+
+fn benchmark_gcounter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("GCounter batch merge");
+
+    for &size in BATCH_SIZES.iter() {
+        // Node IDs repeat once the batch outgrows the counter capacity
+        let others: Vec<GCounter<DefaultConfig>> = (0..size)
+            .map(|i| {
+                let mut counter = GCounter::<DefaultConfig>::new((i % 16) as u8);
+                counter.increment(i as u32 + 1).unwrap();
+                counter
+            })
+            .collect();
+        let base = GCounter::<DefaultConfig>::new(0);
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("merge_loop", size),
+            &others,
+            |b, others| {
+                b.iter(|| {
+                    let mut counter = base.clone();
+                    for other in others.iter() {
+                        counter.merge(black_box(other)).unwrap();
+                    }
+                    counter
+                });
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("merge_batch", size),
+            &others,
+            |b, others| {
+                b.iter(|| {
+                    let mut counter = base.clone();
+                    counter.merge_batch(black_box(others.iter())).unwrap();
+                    counter
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn benchmark_lww_register(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LWWRegister batch merge");
+
+    for &size in BATCH_SIZES.iter() {
+        let others: Vec<LWWRegister<[u8; 32], DefaultConfig>> = (0..size)
+            .map(|i| {
+                let mut register = LWWRegister::new((i % 16) as u8);
+                register.set([i as u8; 32], 1000 + i as u64).unwrap();
+                register
+            })
+            .collect();
+        let base = LWWRegister::<[u8; 32], DefaultConfig>::new(0);
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("merge_loop", size),
+            &others,
+            |b, others| {
+                b.iter(|| {
+                    let mut register = base.clone();
+                    for other in others.iter() {
+                        register.merge(black_box(other)).unwrap();
+                    }
+                    register
+                });
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("merge_batch", size),
+            &others,
+            |b, others| {
+                b.iter(|| {
+                    let mut register = base.clone();
+                    register.merge_batch(black_box(others.iter())).unwrap();
+                    register
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn benchmark_lww_map(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LWWMap batch merge");
+
+    for &size in BATCH_SIZES.iter() {
+        // Every input writes the same keys, so most entries are superseded
+        let others: Vec<LWWMap<u8, [u8; 32], DefaultConfig>> = (0..size)
+            .map(|i| {
+                let mut map = LWWMap::<u8, [u8; 32], DefaultConfig>::new((i % 16) as u8);
+                for key in 0..8u8 {
+                    map.insert(key, [i as u8; 32], 1000 + i as u64).unwrap();
+                }
+                map
+            })
+            .collect();
+        let base = LWWMap::<u8, [u8; 32], DefaultConfig>::new(0);
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("merge_loop", size),
+            &others,
+            |b, others| {
+                b.iter(|| {
+                    let mut map = base.clone();
+                    for other in others.iter() {
+                        map.merge(black_box(other)).unwrap();
+                    }
+                    map
+                });
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("merge_batch", size),
+            &others,
+            |b, others| {
+                b.iter(|| {
+                    let mut map = base.clone();
+                    map.merge_batch(black_box(others.iter())).unwrap();
+                    map
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark_gcounter,
+    benchmark_lww_register,
+    benchmark_lww_map
+);
+criterion_main!(benches);
@@ -234,6 +234,8 @@ fn benchmark_orset(c: &mut Criterion) {
     group.finish();
 }
 
+// `remove` is deprecated in favour of `delete`; keep benchmarking both
+#[allow(deprecated)]
 fn benchmark_lww_map(c: &mut Criterion) {
     let mut group = c.benchmark_group("LWWMap");
 
@@ -293,6 +295,21 @@ fn benchmark_lww_map(c: &mut Criterion) {
             });
         });
 
+        group.bench_with_input(BenchmarkId::new("delete", size), size, |b, &size| {
+            b.iter(|| {
+                let mut map = LWWMap::<u32, u32, DefaultConfig>::new(0);
+                // Fill the map first
+                for i in 0..size {
+                    map.insert(i as u32, (i * 2) as u32, (1000 + i) as u64)
+                        .unwrap();
+                }
+                // Then delete all entries
+                for i in 0..size {
+                    black_box(map.delete(&(i as u32), (2000 + i) as u64).unwrap());
+                }
+            });
+        });
+
         group.bench_with_input(
             BenchmarkId::new("remove_and_reinsert", size),
             size,
@@ -18,6 +18,12 @@ enum ConfigKey {
     LogLevel = 5,
 }
 
+impl StateHash for ConfigKey {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        (*self as u8).hash_into(hasher);
+    }
+}
+
 fn get_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -177,19 +183,19 @@ fn main() -> Result<(), CRDTError> {
         }
     }
 
-    println!("\n🗑️  Remove Operation Example");
+    println!("\n🗑️  Delete Operation Example");
     println!("---------------------------");
 
-    // Demonstrate remove functionality
+    // Demonstrate delete functionality
     println!(
         "Before removal - LogLevel: {:?}",
         local_config.get(&ConfigKey::LogLevel)
     );
     println!("Map length: {}", local_config.len());
 
-    // Remove a configuration entry
-    let removed_value = local_config.remove(&ConfigKey::LogLevel);
-    println!("Removed LogLevel: {:?}", removed_value);
+    // Delete a configuration entry; the tombstone replicates on merge
+    let deleted = local_config.delete(&ConfigKey::LogLevel, get_timestamp())?;
+    println!("Deleted LogLevel: {}", deleted);
     println!(
         "After removal - LogLevel: {:?}",
         local_config.get(&ConfigKey::LogLevel)
@@ -197,9 +203,9 @@ fn main() -> Result<(), CRDTError> {
     println!("Map length: {}", local_config.len());
     println!("Remaining capacity: {}", local_config.remaining_capacity());
 
-    // Demonstrate that removed entries free up capacity
-    println!("\nAdding new configuration after removal:");
-    local_config.insert(ConfigKey::LogLevel, 1, get_timestamp())?; // Debug level
+    // Demonstrate that a newer write brings a deleted key back
+    println!("\nAdding new configuration after deletion:");
+    local_config.insert(ConfigKey::LogLevel, 1, get_timestamp() + 1)?; // Debug level
     println!(
         "Re-added LogLevel: {:?}",
         local_config.get(&ConfigKey::LogLevel)
@@ -213,8 +219,8 @@ fn main() -> Result<(), CRDTError> {
     println!("  • Node ID tiebreaking for same timestamps");
     println!("  • Deterministic merge operations");
     println!("  • Fixed memory allocation");
-    println!("  • Remove operations that free capacity");
-    println!("  • Remove and re-insert functionality");
+    println!("  • Deletions that survive merges");
+    println!("  • Delete and re-insert functionality");
 
     Ok(())
 }
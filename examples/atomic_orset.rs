@@ -337,7 +337,7 @@ fn capacity_management_demo() -> Result<(), CRDTError> {
     println!("  Is full: {}", registry.is_full());
 
     // Test that further adds fail
-    match registry.add(200, get_timestamp()) {
+    match registry.add(200, get_timestamp()).map_err(|e| e.cause()) {
         Err(CRDTError::BufferOverflow) => println!("  ✓ Buffer overflow correctly detected"),
         Ok(_) => panic!("Add should have failed due to capacity"),
         Err(e) => panic!("Unexpected error: {:?}", e),
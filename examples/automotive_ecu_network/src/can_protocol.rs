@@ -5,6 +5,10 @@
 
 use crate::ecu_types::*;
 use crdtosphere::automotive::ReliabilityLevel;
+use crdtosphere::error::CRDTResult;
+use crdtosphere::prelude::*;
+#[cfg(feature = "crc-protection")]
+use crdtosphere::serialization::crc::{CrcProtectedFrame, CRC_LEN};
 use heapless::Vec;
 use core::convert::TryInto;
 
@@ -65,6 +69,10 @@ pub enum CANError {
 }
 
 /// CAN message serializer/deserializer for CRDT data
+///
+/// Classical frames already use all 8 data bytes, so they carry no CRC of
+/// their own. With the `crc-protection` feature, every message sent through
+/// `CANFDCodec` is CRC-protected instead.
 pub struct CANCodec;
 
 impl CANCodec {
@@ -383,6 +391,507 @@ impl CANCodec {
     }
 }
 
+/// Maximum CAN FD frame data length
+pub const CANFD_MAX_DATA_LEN: usize = 64;
+
+/// Size of the fragment header carried by every full-state frame
+pub const CANFD_FRAGMENT_HEADER_LEN: usize = 8;
+
+/// Size of the CRC32 prefix carried by every CAN FD frame
+#[cfg(feature = "crc-protection")]
+pub const CANFD_CRC_LEN: usize = CRC_LEN;
+
+/// Size of the CRC32 prefix carried by every CAN FD frame
+#[cfg(not(feature = "crc-protection"))]
+pub const CANFD_CRC_LEN: usize = 0;
+
+/// CRDT state bytes carried by one full-state frame
+pub const CANFD_FRAGMENT_PAYLOAD_LEN: usize =
+    CANFD_MAX_DATA_LEN - CANFD_CRC_LEN - CANFD_FRAGMENT_HEADER_LEN;
+
+/// CRC framing for CAN FD frame data
+#[cfg(feature = "crc-protection")]
+type CANFDCrcFrame = CrcProtectedFrame<{ CANFD_MAX_DATA_LEN - CRC_LEN }>;
+
+/// Maximum size of a reassembled CRDT state
+pub const CANFD_MAX_STATE_LEN: usize = 512;
+
+/// Maximum number of frames a full CRDT state is split into
+pub const CANFD_MAX_FRAGMENTS: usize = CANFD_MAX_STATE_LEN.div_ceil(CANFD_FRAGMENT_PAYLOAD_LEN);
+
+/// Valid CAN FD data lengths, indexed by DLC
+const CANFD_DLC_LENGTHS: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
+
+/// CAN FD frame for CRDT synchronization
+#[derive(Debug, Clone)]
+pub struct CANFDFrame {
+    /// CAN message ID (11-bit standard)
+    pub id: u16,
+    /// Data payload (up to 64 bytes, padded to a valid CAN FD length)
+    pub data: Vec<u8, CANFD_MAX_DATA_LEN>,
+    /// Data length code (0-15)
+    pub dlc: u8,
+    /// Bitrate switch flag (data phase sent at the higher bitrate)
+    pub bitrate_switch: bool,
+    /// FD format flag (false for classical CAN frames on an FD bus)
+    pub fd_format: bool,
+}
+
+impl CANFDFrame {
+    /// Creates a new CAN FD frame
+    ///
+    /// Data lengths that CAN FD cannot encode (e.g. 10 bytes) are padded
+    /// with zeros up to the next valid length.
+    pub fn new(id: u16, data: &[u8], bitrate_switch: bool) -> Result<Self, CANError> {
+        if data.len() > CANFD_MAX_DATA_LEN {
+            return Err(CANError::DataTooLong);
+        }
+        
+        let dlc = CANFD_DLC_LENGTHS
+            .iter()
+            .position(|&len| len >= data.len())
+            .ok_or(CANError::DataTooLong)?;
+        
+        let mut frame_data = Vec::new();
+        frame_data.extend_from_slice(data).map_err(|_| CANError::BufferFull)?;
+        frame_data.resize(CANFD_DLC_LENGTHS[dlc], 0).map_err(|_| CANError::BufferFull)?;
+        
+        Ok(Self {
+            id,
+            data: frame_data,
+            dlc: dlc as u8,
+            bitrate_switch,
+            fd_format: true,
+        })
+    }
+    
+    /// Wraps a classical CAN frame for transmission on a CAN FD bus
+    pub fn from_classic(frame: &CANFrame) -> Result<Self, CANError> {
+        let mut fd_frame = Self::new(frame.id, frame.data(), false)?;
+        fd_frame.fd_format = false;
+        Ok(fd_frame)
+    }
+    
+    /// Converts back to a classical CAN frame
+    ///
+    /// Fails if the payload does not fit in 8 bytes.
+    pub fn to_classic(&self) -> Result<CANFrame, CANError> {
+        CANFrame::new(self.id, &self.data)
+    }
+    
+    /// Gets the data as a slice
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+    
+    /// Checks if this is a high-priority safety message
+    pub fn is_safety_critical(&self) -> bool {
+        self.id >= 0x100 && self.id < 0x200
+    }
+}
+
+/// CRDT carried by a full-state message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CRDTStateKind {
+    EmergencyBrake = 1,
+    TemperatureFusion = 2,
+    SystemConfig = 3,
+    ErrorCounter = 4,
+}
+
+impl CRDTStateKind {
+    fn from_u8(value: u8) -> Result<Self, CANError> {
+        match value {
+            1 => Ok(CRDTStateKind::EmergencyBrake),
+            2 => Ok(CRDTStateKind::TemperatureFusion),
+            3 => Ok(CRDTStateKind::SystemConfig),
+            4 => Ok(CRDTStateKind::ErrorCounter),
+            _ => Err(CANError::DeserializationError),
+        }
+    }
+}
+
+/// CRDT whose full state can be sent with `CANFDCodec::serialize_full_crdt_state`
+///
+/// The state is sent as its in-memory bytes, so implementors must be plain
+/// data (no pointers or references) and every ECU must run the same build.
+pub trait FullStateCRDT<C: MemoryConfig>: CRDT<C> {
+    /// Identifies the CRDT on the receiving side
+    const STATE_KIND: CRDTStateKind;
+}
+
+impl FullStateCRDT<DefaultConfig> for EmergencyBrakeCRDT {
+    const STATE_KIND: CRDTStateKind = CRDTStateKind::EmergencyBrake;
+}
+
+impl FullStateCRDT<DefaultConfig> for TemperatureFusionCRDT {
+    const STATE_KIND: CRDTStateKind = CRDTStateKind::TemperatureFusion;
+}
+
+impl FullStateCRDT<DefaultConfig> for ConfigRegisterCRDT {
+    const STATE_KIND: CRDTStateKind = CRDTStateKind::SystemConfig;
+}
+
+impl FullStateCRDT<DefaultConfig> for ErrorCounterCRDT {
+    const STATE_KIND: CRDTStateKind = CRDTStateKind::ErrorCounter;
+}
+
+/// Header of a full-state frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FullStateHeader {
+    /// Position of this frame in the sequence (0-based)
+    pub sequence: u8,
+    /// Total number of frames in the sequence
+    pub total_frames: u8,
+    /// ECU that sent the state
+    pub source: ECUNodeId,
+    /// CRDT carried by the sequence
+    pub kind: CRDTStateKind,
+    /// Number of state bytes in this frame
+    pub payload_len: u8,
+    /// Timestamp of the state (24-bit)
+    pub timestamp: u64,
+}
+
+/// CAN FD message serializer/deserializer for CRDT data
+///
+/// Single-frame messages use the same layouts as `CANCodec`. Full CRDT
+/// states are fragmented across frames with this header:
+///
+/// | Byte | Content |
+/// |------|---------|
+/// | 0 | Sequence number |
+/// | 1 | Total frame count |
+/// | 2 | Source ECU ID |
+/// | 3 | CRDT state kind |
+/// | 4 | State bytes in this frame |
+/// | 5-7 | Timestamp (24-bit, little endian) |
+/// | 8-63 | State bytes |
+///
+/// With the `crc-protection` feature, the data of every frame (single-frame
+/// messages and fragments alike) is wrapped in a `CrcProtectedFrame`: a
+/// CRC32 of the rest of the frame, including padding, comes first and the
+/// layouts above follow it. Single-frame messages grow to 12 bytes and
+/// fragments carry 52 state bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct CANFDCodec {
+    /// Whether frames are sent with bitrate switching
+    bitrate_switch: bool,
+}
+
+impl CANFDCodec {
+    /// Creates a new CAN FD codec
+    pub fn new(bitrate_switch: bool) -> Self {
+        Self { bitrate_switch }
+    }
+    
+    /// Converts a classical frame produced by `CANCodec` into an FD frame
+    fn encapsulate(&self, frame: CANFrame) -> Result<CANFDFrame, CANError> {
+        self.build_frame(frame.id, frame.data())
+    }
+    
+    /// Builds an FD frame, CRC-protecting the data with `crc-protection`
+    fn build_frame(&self, id: u16, data: &[u8]) -> Result<CANFDFrame, CANError> {
+        #[cfg(feature = "crc-protection")]
+        {
+            // Pad up front so the CRC also covers the padding the frame needs
+            let frame_len = CANFD_DLC_LENGTHS
+                .iter()
+                .copied()
+                .find(|&len| len >= CRC_LEN + data.len())
+                .ok_or(CANError::DataTooLong)?;
+            let mut payload: Vec<u8, CANFD_MAX_DATA_LEN> = Vec::new();
+            payload.extend_from_slice(data).map_err(|_| CANError::DataTooLong)?;
+            payload.resize(frame_len - CRC_LEN, 0).map_err(|_| CANError::DataTooLong)?;
+            
+            let mut buf = [0u8; CANFD_MAX_DATA_LEN];
+            let len = CANFDCrcFrame::encode(&payload, &mut buf)
+                .map_err(|_| CANError::SerializationError)?;
+            CANFDFrame::new(id, &buf[..len], self.bitrate_switch)
+        }
+        
+        #[cfg(not(feature = "crc-protection"))]
+        {
+            CANFDFrame::new(id, data, self.bitrate_switch)
+        }
+    }
+    
+    /// Returns the message bytes of an FD frame
+    ///
+    /// With `crc-protection` the CRC is checked and stripped first.
+    ///
+    /// # Returns
+    /// The message bytes, or `CRDTError::InvalidState` if the CRC check fails
+    pub fn frame_payload(frame: &CANFDFrame) -> CRDTResult<&[u8]> {
+        #[cfg(feature = "crc-protection")]
+        {
+            CANFDCrcFrame::decode(frame.data())
+        }
+        
+        #[cfg(not(feature = "crc-protection"))]
+        {
+            Ok(frame.data())
+        }
+    }
+    
+    /// Recovers the classical frame carried by a single-frame FD message
+    ///
+    /// # Returns
+    /// The classical frame, or `CRDTError::InvalidState` if the CRC check
+    /// fails or the message does not fit in 8 bytes
+    pub fn unwrap_classic(frame: &CANFDFrame) -> CRDTResult<CANFrame> {
+        let payload = Self::frame_payload(frame)?;
+        CANFrame::new(frame.id, payload).map_err(|_| CRDTError::InvalidState)
+    }
+    
+    /// Serializes a brake command to CAN FD frame
+    pub fn serialize_brake_command(
+        &self,
+        source: ECUNodeId,
+        brake_cmd: &BrakeCommand,
+        timestamp: u64
+    ) -> Result<CANFDFrame, CANError> {
+        self.encapsulate(CANCodec::serialize_brake_command(source, brake_cmd, timestamp)?)
+    }
+    
+    /// Deserializes a brake command from CAN FD frame
+    pub fn deserialize_brake_command(
+        frame: &CANFDFrame
+    ) -> Result<(ECUNodeId, BrakeCommand, u64), CANError> {
+        let classic = Self::unwrap_classic(frame).map_err(|_| CANError::DeserializationError)?;
+        CANCodec::deserialize_brake_command(&classic)
+    }
+    
+    /// Serializes a temperature reading to CAN FD frame
+    pub fn serialize_temperature_reading(
+        &self,
+        source: ECUNodeId,
+        temperature: f32,
+        reliability: ReliabilityLevel,
+        timestamp: u64
+    ) -> Result<CANFDFrame, CANError> {
+        self.encapsulate(CANCodec::serialize_temperature_reading(
+            source, temperature, reliability, timestamp
+        )?)
+    }
+    
+    /// Deserializes a temperature reading from CAN FD frame
+    pub fn deserialize_temperature_reading(
+        frame: &CANFDFrame
+    ) -> Result<(ECUNodeId, f32, ReliabilityLevel, u64), CANError> {
+        let classic = Self::unwrap_classic(frame).map_err(|_| CANError::DeserializationError)?;
+        CANCodec::deserialize_temperature_reading(&classic)
+    }
+    
+    /// Serializes system configuration to CAN FD frame
+    pub fn serialize_system_config(
+        &self,
+        source: ECUNodeId,
+        config: &SystemConfig,
+        timestamp: u64
+    ) -> Result<CANFDFrame, CANError> {
+        self.encapsulate(CANCodec::serialize_system_config(source, config, timestamp)?)
+    }
+    
+    /// Deserializes system configuration from CAN FD frame
+    pub fn deserialize_system_config(
+        frame: &CANFDFrame
+    ) -> Result<(ECUNodeId, SystemConfig, u64), CANError> {
+        let classic = Self::unwrap_classic(frame).map_err(|_| CANError::DeserializationError)?;
+        CANCodec::deserialize_system_config(&classic)
+    }
+    
+    /// Serializes error count to CAN FD frame
+    pub fn serialize_error_count(
+        &self,
+        source: ECUNodeId,
+        count: u64,
+        timestamp: u64
+    ) -> Result<CANFDFrame, CANError> {
+        self.encapsulate(CANCodec::serialize_error_count(source, count, timestamp)?)
+    }
+    
+    /// Deserializes error count from CAN FD frame
+    pub fn deserialize_error_count(
+        frame: &CANFDFrame
+    ) -> Result<(ECUNodeId, u64, u64), CANError> {
+        let classic = Self::unwrap_classic(frame).map_err(|_| CANError::DeserializationError)?;
+        CANCodec::deserialize_error_count(&classic)
+    }
+    
+    /// Creates a heartbeat CAN FD frame
+    pub fn create_heartbeat(&self, source: ECUNodeId, timestamp: u64) -> Result<CANFDFrame, CANError> {
+        self.encapsulate(CANCodec::create_heartbeat(source, timestamp)?)
+    }
+    
+    /// Serializes a complete CRDT state into a sequence of CAN FD frames
+    ///
+    /// The CRDT's `size_bytes()` worth of state is split into frames of up to
+    /// 56 state bytes each, so a state of up to 512 bytes needs at most
+    /// `CANFD_MAX_FRAGMENTS` frames. All frames use `CANMessageId::CRDTStateFull`.
+    ///
+    /// # Returns
+    /// The frames in sequence order, or `CRDTError::BufferOverflow` if the
+    /// state is larger than `CANFD_MAX_STATE_LEN`
+    pub fn serialize_full_crdt_state<C: MemoryConfig, T: FullStateCRDT<C>>(
+        &self,
+        crdt: &T,
+        node_id: ECUNodeId,
+        ts: u64
+    ) -> CRDTResult<Vec<CANFDFrame, CANFD_MAX_FRAGMENTS>> {
+        let state_len = crdt.size_bytes().min(core::mem::size_of::<T>());
+        if state_len > CANFD_MAX_STATE_LEN {
+            return Err(CRDTError::BufferOverflow);
+        }
+        
+        // SAFETY: FullStateCRDT implementors are plain data, and the slice
+        // stays within the object since state_len <= size_of::<T>()
+        let state = unsafe {
+            core::slice::from_raw_parts(crdt as *const T as *const u8, state_len)
+        };
+        
+        let total_frames = state.len().div_ceil(CANFD_FRAGMENT_PAYLOAD_LEN).max(1);
+        let timestamp_bytes = (ts as u32).to_le_bytes();
+        let mut frames = Vec::new();
+        
+        for sequence in 0..total_frames {
+            let start = sequence * CANFD_FRAGMENT_PAYLOAD_LEN;
+            let end = (start + CANFD_FRAGMENT_PAYLOAD_LEN).min(state.len());
+            let chunk = &state[start..end];
+            
+            let mut data: Vec<u8, CANFD_MAX_DATA_LEN> = Vec::new();
+            let header = [
+                sequence as u8,
+                total_frames as u8,
+                node_id as u8,
+                T::STATE_KIND as u8,
+                chunk.len() as u8,
+                timestamp_bytes[0],
+                timestamp_bytes[1],
+                timestamp_bytes[2],
+            ];
+            data.extend_from_slice(&header).map_err(|_| CRDTError::BufferOverflow)?;
+            data.extend_from_slice(chunk).map_err(|_| CRDTError::BufferOverflow)?;
+            
+            let frame = self.build_frame(CANMessageId::CRDTStateFull as u16, &data)
+                .map_err(|_| CRDTError::BufferOverflow)?;
+            frames.push(frame).map_err(|_| CRDTError::BufferOverflow)?;
+        }
+        
+        Ok(frames)
+    }
+    
+    /// Parses the header of a full-state frame
+    pub fn parse_full_state_header(frame: &CANFDFrame) -> Result<FullStateHeader, CANError> {
+        if frame.id != CANMessageId::CRDTStateFull as u16 {
+            return Err(CANError::InvalidFrame);
+        }
+        
+        let data = Self::frame_payload(frame).map_err(|_| CANError::DeserializationError)?;
+        if data.len() < CANFD_FRAGMENT_HEADER_LEN {
+            return Err(CANError::InvalidFrame);
+        }
+        
+        // Parse source ECU
+        let source = match data[2] {
+            1 => ECUNodeId::Engine,
+            2 => ECUNodeId::Brake,
+            3 => ECUNodeId::Steering,
+            4 => ECUNodeId::Gateway,
+            _ => return Err(CANError::DeserializationError),
+        };
+        
+        let kind = CRDTStateKind::from_u8(data[3])?;
+        
+        let payload_len = data[4];
+        if CANFD_FRAGMENT_HEADER_LEN + payload_len as usize > data.len() {
+            return Err(CANError::InvalidFrame);
+        }
+        
+        // Parse timestamp (24-bit)
+        let mut timestamp_bytes = [0u8; 4];
+        timestamp_bytes[0..3].copy_from_slice(&data[5..8]);
+        let timestamp = u32::from_le_bytes(timestamp_bytes) as u64;
+        
+        Ok(FullStateHeader {
+            sequence: data[0],
+            total_frames: data[1],
+            source,
+            kind,
+            payload_len,
+            timestamp,
+        })
+    }
+    
+    /// Reassembles the CRDT state bytes from a complete set of full-state frames
+    ///
+    /// Frames may be given in any order, but every sequence number must be
+    /// present exactly once and all frames must belong to the same message.
+    ///
+    /// # Returns
+    /// The state bytes, or `CRDTError::InvalidState` if the frames are
+    /// incomplete or inconsistent
+    pub fn reassemble(frames: &[CANFDFrame]) -> CRDTResult<Vec<u8, CANFD_MAX_STATE_LEN>> {
+        let first = frames.first().ok_or(CRDTError::InvalidState)?;
+        let first_header = Self::parse_full_state_header(first).map_err(|_| CRDTError::InvalidState)?;
+        if first_header.total_frames as usize != frames.len() {
+            return Err(CRDTError::InvalidState);
+        }
+        
+        let mut state = Vec::new();
+        for sequence in 0..first_header.total_frames {
+            let mut matching = frames.iter().filter_map(|frame| {
+                Self::parse_full_state_header(frame)
+                    .ok()
+                    .filter(|header| header.sequence == sequence)
+                    .map(|header| (frame, header))
+            });
+            
+            let (frame, header) = matching.next().ok_or(CRDTError::InvalidState)?;
+            if matching.next().is_some() {
+                return Err(CRDTError::InvalidState);
+            }
+            
+            if header.total_frames != first_header.total_frames
+                || header.source != first_header.source
+                || header.kind != first_header.kind
+                || header.timestamp != first_header.timestamp
+            {
+                return Err(CRDTError::InvalidState);
+            }
+            
+            let end = CANFD_FRAGMENT_HEADER_LEN + header.payload_len as usize;
+            state
+                .extend_from_slice(&Self::frame_payload(frame)?[CANFD_FRAGMENT_HEADER_LEN..end])
+                .map_err(|_| CRDTError::BufferOverflow)?;
+        }
+        
+        Ok(state)
+    }
+    
+    /// Rebuilds a CRDT from reassembled state bytes
+    ///
+    /// The result is validated before it is returned.
+    ///
+    /// # Safety
+    /// `state` must have been produced by `serialize_full_crdt_state` for the
+    /// same type `T` on a build with the same memory layout.
+    pub unsafe fn decode_full_crdt_state<C: MemoryConfig, T: FullStateCRDT<C>>(
+        state: &[u8]
+    ) -> CRDTResult<T> {
+        if state.len() != core::mem::size_of::<T>() {
+            return Err(CRDTError::InvalidState);
+        }
+        
+        // SAFETY: The length matches and the caller guarantees the bytes are
+        // a valid T; read_unaligned handles the byte buffer's alignment
+        let crdt = unsafe { core::ptr::read_unaligned(state.as_ptr() as *const T) };
+        crdt.validate()?;
+        Ok(crdt)
+    }
+}
+
 /// CAN bus interface for ECU communication
 pub trait CANBus {
     /// Transmits a CAN frame
@@ -488,6 +997,8 @@ impl CANBus for MockCANBus {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crdtosphere::automotive::SensorReading;
+    use crdtosphere::memory::NodeId;
     
     #[test]
     fn test_brake_command_serialization() {
@@ -549,4 +1060,139 @@ mod tests {
         assert!(safety_frame.is_safety_critical());
         assert!(!normal_frame.is_safety_critical());
     }
+    
+    #[test]
+    fn test_canfd_frame_padding() {
+        let frame = CANFDFrame::new(0x123, &[1; 10], true).unwrap();
+        assert_eq!(frame.dlc, 9);
+        assert_eq!(frame.data.len(), 12);
+        assert!(frame.bitrate_switch);
+        assert!(frame.fd_format);
+        
+        assert_eq!(CANFDFrame::new(0x123, &[0; 65], true).unwrap_err(), CANError::DataTooLong);
+    }
+    
+    #[test]
+    fn test_canfd_message_serialization() {
+        let codec = CANFDCodec::new(true);
+        let brake_cmd = BrakeCommand::emergency_brake(ECUNodeId::Brake);
+        
+        let frame = codec.serialize_brake_command(ECUNodeId::Brake, &brake_cmd, 12345).unwrap();
+        assert_eq!(frame.id, CANMessageId::EmergencyBrake as u16);
+        
+        let (source, parsed_cmd, timestamp) = CANFDCodec::deserialize_brake_command(&frame).unwrap();
+        assert_eq!(source, ECUNodeId::Brake);
+        assert_eq!(parsed_cmd, brake_cmd);
+        assert_eq!(timestamp, 12345);
+    }
+    
+    /// Sends a CRDT through the full-state codec and returns the rebuilt copy
+    fn full_state_round_trip<T: FullStateCRDT<DefaultConfig>>(crdt: &T) -> T {
+        let codec = CANFDCodec::new(true);
+        let mut frames = codec
+            .serialize_full_crdt_state(crdt, ECUNodeId::Gateway, 4321)
+            .unwrap();
+        assert_eq!(
+            frames.len(),
+            crdt.size_bytes().div_ceil(CANFD_FRAGMENT_PAYLOAD_LEN)
+        );
+        
+        // Frames may arrive out of order
+        frames.reverse();
+        
+        let header = CANFDCodec::parse_full_state_header(&frames[0]).unwrap();
+        assert_eq!(header.source, ECUNodeId::Gateway);
+        assert_eq!(header.kind, T::STATE_KIND);
+        assert_eq!(header.timestamp, 4321);
+        
+        let state = CANFDCodec::reassemble(&frames).unwrap();
+        assert_eq!(state.len(), crdt.size_bytes());
+        unsafe { CANFDCodec::decode_full_crdt_state(&state).unwrap() }
+    }
+    
+    #[test]
+    fn test_full_state_round_trip_emergency_brake() {
+        let mut crdt = EmergencyBrakeCRDT::new(NodeId::from(ECUNodeId::Brake), ECUNodeId::Brake.safety_level());
+        crdt.set(BrakeCommand::emergency_brake(ECUNodeId::Brake), 1000).unwrap();
+        
+        let decoded = full_state_round_trip(&crdt);
+        assert!(decoded.eq(&crdt));
+        assert_eq!(decoded.get(), crdt.get());
+    }
+    
+    #[test]
+    fn test_full_state_round_trip_temperature_fusion() {
+        let mut crdt = TemperatureFusionCRDT::new(NodeId::from(ECUNodeId::Engine));
+        for i in 0..4u64 {
+            let reading = SensorReading::new(
+                85.0 + i as f32,
+                1000 + i,
+                NodeId::from(ECUNodeId::Engine),
+                ReliabilityLevel::High,
+                ECUNodeId::Engine.safety_level(),
+            );
+            crdt.add_reading(reading).unwrap();
+        }
+        
+        let decoded = full_state_round_trip(&crdt);
+        assert!(decoded.eq(&crdt));
+        assert_eq!(decoded.fused_value(), crdt.fused_value());
+    }
+    
+    #[test]
+    fn test_full_state_round_trip_system_config() {
+        let mut crdt = ConfigRegisterCRDT::new(NodeId::from(ECUNodeId::Engine));
+        crdt.set(SystemConfig::default(), 2000).unwrap();
+        
+        let decoded = full_state_round_trip(&crdt);
+        assert!(decoded.eq(&crdt));
+        assert_eq!(decoded.get(), Some(&SystemConfig::default()));
+    }
+    
+    #[test]
+    fn test_full_state_round_trip_error_counter() {
+        let mut crdt = ErrorCounterCRDT::new(NodeId::from(ECUNodeId::Steering));
+        crdt.increment(42).unwrap();
+        
+        let decoded = full_state_round_trip(&crdt);
+        assert!(decoded.eq(&crdt));
+        assert_eq!(decoded.value(), 42);
+    }
+    
+    #[cfg(feature = "crc-protection")]
+    #[test]
+    fn test_crc_protected_frames() {
+        let codec = CANFDCodec::new(true);
+        let brake_cmd = BrakeCommand::emergency_brake(ECUNodeId::Brake);
+        
+        let frame = codec.serialize_brake_command(ECUNodeId::Brake, &brake_cmd, 12345).unwrap();
+        assert_eq!(frame.data.len(), CAN_MAX_DATA_LEN + CANFD_CRC_LEN);
+        assert!(CANFDCodec::deserialize_brake_command(&frame).is_ok());
+        
+        let mut corrupted = frame.clone();
+        corrupted.data[6] ^= 0x01;
+        assert_eq!(CANFDCodec::frame_payload(&corrupted).unwrap_err(), CRDTError::InvalidState);
+        assert!(CANFDCodec::deserialize_brake_command(&corrupted).is_err());
+        
+        // A corrupted fragment spoils the whole state
+        let mut crdt = ErrorCounterCRDT::new(NodeId::from(ECUNodeId::Engine));
+        crdt.increment(7).unwrap();
+        let mut frames = codec.serialize_full_crdt_state(&crdt, ECUNodeId::Engine, 100).unwrap();
+        frames[0].data[20] ^= 0x01;
+        assert_eq!(CANFDCodec::reassemble(&frames).unwrap_err(), CRDTError::InvalidState);
+    }
+    
+    #[test]
+    fn test_reassemble_rejects_incomplete_sequence() {
+        let codec = CANFDCodec::new(false);
+        let mut crdt = ErrorCounterCRDT::new(NodeId::from(ECUNodeId::Engine));
+        crdt.increment(7).unwrap();
+        
+        let frames = codec.serialize_full_crdt_state(&crdt, ECUNodeId::Engine, 100).unwrap();
+        assert!(frames.len() > 1);
+        assert_eq!(
+            CANFDCodec::reassemble(&frames[1..]).unwrap_err(),
+            CRDTError::InvalidState
+        );
+    }
 }
@@ -4,7 +4,8 @@
 //! all ECUs in the automotive network demonstration.
 
 use crdtosphere::prelude::*;
-use crdtosphere::automotive::{SafetyCRDT, SensorFusion, SensorReading, ReliabilityLevel, SafetyLevel, ASILLevel};
+use crdtosphere::automotive::{SafetyCRDT, SensorFusion, SensorReading, ReliabilityLevel, SafetyLevel, ASILLevel, AutosarNodeId};
+use crdtosphere::memory::NodeId;
 use heapless::Vec;
 use core::fmt;
 
@@ -47,9 +48,19 @@ pub enum ECUNodeId {
     Gateway = 4,
 }
 
+/// Base CAN ID of the network management PDUs, one per ECU
+pub const NM_BASE_CAN_ID: u16 = 0x600;
+
 impl ECUNodeId {
-    pub fn as_node_id(self) -> u8 {
-        self as u8
+    /// AUTOSAR identity of this ECU: its NM PDU CAN ID and CRDT index
+    pub fn autosar_id(self) -> AutosarNodeId {
+        AutosarNodeId::from(NM_BASE_CAN_ID + self as u16)
+    }
+}
+
+impl From<ECUNodeId> for NodeId {
+    fn from(ecu: ECUNodeId) -> Self {
+        NodeId::from(ecu.autosar_id())
     }
 }
 
@@ -75,6 +86,9 @@ pub enum CANMessageId {
     // Diagnostic messages
     ErrorCounts = 0x400,
     SystemStatus = 0x401,
+    
+    // Full state synchronization (CAN FD, fragmented)
+    CRDTStateFull = 0x500,
 }
 
 /// Emergency brake command with safety prioritization
@@ -118,6 +132,14 @@ impl BrakeCommand {
     }
 }
 
+impl StateHash for BrakeCommand {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.pressure.hash_into(hasher);
+        self.emergency.hash_into(hasher);
+        (self.source as u8).hash_into(hasher);
+    }
+}
+
 /// System configuration parameters
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SystemConfig {
@@ -145,6 +167,16 @@ impl Default for SystemConfig {
     }
 }
 
+impl StateHash for SystemConfig {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.max_rpm.hash_into(hasher);
+        self.temp_warning.hash_into(hasher);
+        self.temp_critical.hash_into(hasher);
+        self.abs_enabled.hash_into(hasher);
+        self.stability_control.hash_into(hasher);
+    }
+}
+
 /// ECU State containing all CRDTs for an ECU
 pub struct ECUState {
     /// Emergency brake command coordination
@@ -169,13 +201,13 @@ pub struct ECUState {
 impl ECUState {
     /// Creates a new ECU state for the given node
     pub fn new(node_id: ECUNodeId, safety_level: SafetyLevel) -> Self {
-        let node_id_u8 = node_id.as_node_id();
+        let crdt_node = NodeId::from(node_id);
         
         Self {
-            emergency_brake: EmergencyBrakeCRDT::new(node_id_u8, safety_level),
-            temperature_fusion: TemperatureFusionCRDT::new(node_id_u8),
-            system_config: ConfigRegisterCRDT::new(node_id_u8),
-            error_counter: ErrorCounterCRDT::new(node_id_u8),
+            emergency_brake: EmergencyBrakeCRDT::new(crdt_node, safety_level),
+            temperature_fusion: TemperatureFusionCRDT::new(crdt_node),
+            system_config: ConfigRegisterCRDT::new(crdt_node),
+            error_counter: ErrorCounterCRDT::new(crdt_node),
             node_id,
             safety_level,
         }
@@ -197,7 +229,7 @@ impl ECUState {
         let reading = SensorReading::new(
             temperature,
             timestamp,
-            self.node_id.as_node_id(),
+            NodeId::from(self.node_id),
             reliability,
             self.safety_level,
         );
@@ -42,6 +42,8 @@ pub use sensor_manager::*;
 
 use crdtosphere::prelude::*;
 use crdtosphere::automotive::{ASILLevel, ReliabilityLevel};
+use crdtosphere::error::{CRDTResult, ErrorContext};
+use crdtosphere::memory::NodeId;
 use heapless::Vec;
 
 /// Main ECU application structure
@@ -58,27 +60,81 @@ pub struct ECUApplication<B: CANBus> {
     pub system_time: SystemTime,
     /// Message processing statistics
     pub stats: ECUStatistics,
+    /// Where the most recent CRDT error on a received frame was raised
+    pub last_error_context: Option<ErrorContext>,
+    /// Full-state CAN FD frames received so far
+    fd_fragments: Vec<CANFDFrame, CANFD_MAX_FRAGMENTS>,
 }
 
 /// ECU performance and diagnostic statistics
-#[derive(Debug, Default)]
-pub struct ECUStatistics {
+///
+/// Every counter is a `GCounter`, so each ECU only increments its own slot
+/// and broadcasts its statistics; merging them gives the network-wide view
+/// without polling every ECU.
+#[derive(Debug, Clone)]
+pub struct ECUStatistics<C: MemoryConfig = DefaultConfig> {
     /// Total CAN messages transmitted
-    pub messages_transmitted: u64,
+    pub messages_transmitted: GCounter<C>,
     /// Total CAN messages received
-    pub messages_received: u64,
+    pub messages_received: GCounter<C>,
     /// Total CRDT merge operations
-    pub crdt_merges: u64,
+    pub crdt_merges: GCounter<C>,
     /// Total safety violations detected
-    pub safety_violations: u64,
+    pub safety_violations: GCounter<C>,
     /// Total sensor readings processed
-    pub sensor_readings: u64,
+    pub sensor_readings: GCounter<C>,
     /// Total emergency brake activations
-    pub emergency_brakes: u64,
+    pub emergency_brakes: GCounter<C>,
     /// CAN bus errors
-    pub can_errors: u64,
+    pub can_errors: GCounter<C>,
     /// CRDT validation errors
-    pub crdt_errors: u64,
+    pub crdt_errors: GCounter<C>,
+}
+
+impl<C: MemoryConfig> ECUStatistics<C> {
+    /// Creates zeroed statistics for the given node
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            messages_transmitted: GCounter::new(node_id),
+            messages_received: GCounter::new(node_id),
+            crdt_merges: GCounter::new(node_id),
+            safety_violations: GCounter::new(node_id),
+            sensor_readings: GCounter::new(node_id),
+            emergency_brakes: GCounter::new(node_id),
+            can_errors: GCounter::new(node_id),
+            crdt_errors: GCounter::new(node_id),
+        }
+    }
+    
+    /// Merges statistics broadcast by another ECU
+    pub fn merge(&mut self, other: &ECUStatistics<C>) -> CRDTResult<()> {
+        self.messages_transmitted.merge(&other.messages_transmitted)?;
+        self.messages_received.merge(&other.messages_received)?;
+        self.crdt_merges.merge(&other.crdt_merges)?;
+        self.safety_violations.merge(&other.safety_violations)?;
+        self.sensor_readings.merge(&other.sensor_readings)?;
+        self.emergency_brakes.merge(&other.emergency_brakes)?;
+        self.can_errors.merge(&other.can_errors)?;
+        self.crdt_errors.merge(&other.crdt_errors)?;
+        Ok(())
+    }
+    
+    /// Total messages transmitted by all known nodes
+    pub fn network_total_messages(&self) -> u64 {
+        self.messages_transmitted.value()
+    }
+    
+    /// Messages sent and received per node, as `(node_id, sent, received)`
+    ///
+    /// Nodes that have neither sent nor received anything are skipped.
+    pub fn per_node_stats(&self) -> impl Iterator<Item = (NodeId, u64, u64)> + '_ {
+        (0..C::MAX_NODES).filter_map(move |node| {
+            let node_id = node as NodeId;
+            let sent = self.messages_transmitted.node_value(node_id);
+            let received = self.messages_received.node_value(node_id);
+            (sent > 0 || received > 0).then_some((node_id, sent, received))
+        })
+    }
 }
 
 impl<B: CANBus> ECUApplication<B> {
@@ -93,7 +149,9 @@ impl<B: CANBus> ECUApplication<B> {
             safety_manager: SafetyManager::new(node_id, safety_level),
             sensor_manager: SensorManager::new(node_id),
             system_time: SystemTime::new(),
-            stats: ECUStatistics::default(),
+            stats: ECUStatistics::new(NodeId::from(node_id)),
+            last_error_context: None,
+            fd_fragments: Vec::new(),
         }
     }
     
@@ -124,14 +182,19 @@ impl<B: CANBus> ECUApplication<B> {
     /// Processes incoming CAN messages and updates CRDTs
     fn process_can_messages(&mut self) -> Result<(), ECUError> {
         while let Ok(Some(frame)) = self.can_bus.receive() {
-            self.stats.messages_received += 1;
+            self.stats.messages_received.increment(1)?;
             
             match self.process_can_frame(&frame) {
                 Ok(_) => {
-                    self.stats.crdt_merges += 1;
+                    self.stats.crdt_merges.increment(1)?;
+                }
+                Err(ECUError::CRDTError(error)) => {
+                    // Log which CRDT failed and how full it was, then continue
+                    self.last_error_context = error.context().copied();
+                    self.stats.crdt_errors.increment(1)?;
                 }
-                Err(e) => {
-                    self.stats.can_errors += 1;
+                Err(_) => {
+                    self.stats.can_errors.increment(1)?;
                     // Log error but continue processing
                 }
             }
@@ -150,7 +213,7 @@ impl<B: CANBus> ECUApplication<B> {
                 
                 // Create temporary CRDT for merging
                 let mut temp_brake_crdt = EmergencyBrakeCRDT::new(
-                    source.as_node_id(),
+                    NodeId::from(source),
                     source.safety_level()
                 );
                 temp_brake_crdt.set(brake_cmd, timestamp)?;
@@ -160,7 +223,7 @@ impl<B: CANBus> ECUApplication<B> {
                 
                 // Check if this is an emergency brake activation
                 if brake_cmd.emergency {
-                    self.stats.emergency_brakes += 1;
+                    self.stats.emergency_brakes.increment(1)?;
                     self.safety_manager.handle_emergency_brake(brake_cmd, current_time)?;
                 }
             }
@@ -173,7 +236,7 @@ impl<B: CANBus> ECUApplication<B> {
                 // Add temperature reading to our fusion
                 self.state.add_temperature_reading(temperature, timestamp, reliability)?;
                 
-                self.stats.sensor_readings += 1;
+                self.stats.sensor_readings.increment(1)?;
                 
                 // Check for temperature-based safety conditions
                 self.sensor_manager.process_temperature_reading(
@@ -186,7 +249,7 @@ impl<B: CANBus> ECUApplication<B> {
                     .map_err(|_| ECUError::DeserializationError)?;
                 
                 // Create temporary CRDT for merging
-                let mut temp_config_crdt = LWWRegister::new(source.as_node_id());
+                let mut temp_config_crdt = LWWRegister::new(NodeId::from(source));
                 temp_config_crdt.set(config, timestamp)?;
                 
                 // Merge with our state
@@ -197,16 +260,15 @@ impl<B: CANBus> ECUApplication<B> {
                 let (source, count, timestamp) = CANCodec::deserialize_error_count(frame)
                     .map_err(|_| ECUError::DeserializationError)?;
                 
-                // Create temporary CRDT for merging
-                let mut temp_counter_crdt = GCounter::new(source.as_node_id());
-                // Note: We can't directly set a counter value, so we increment by the difference
-                // This is a simplified approach for the demo
-                if count > 0 {
-                    temp_counter_crdt.increment(count as u32)?;
-                    
-                    // Merge with our state
-                    self.state.error_counter.merge(&temp_counter_crdt)?;
-                }
+                let count = u32::try_from(count).map_err(|_| ECUError::DeserializationError)?;
+                
+                // Merge the source's count straight into our state
+                self.state.error_counter.merge_from_raw(NodeId::from(source), count)?;
+            }
+            
+            id if id == CANMessageId::CRDTStateFull as u16 => {
+                // Full states are fragmented CAN FD frames, see process_canfd_frame
+                return Err(ECUError::DeserializationError);
             }
             
             _ => {
@@ -217,6 +279,68 @@ impl<B: CANBus> ECUApplication<B> {
         Ok(())
     }
     
+    /// Processes a CAN FD frame and updates the appropriate CRDT
+    ///
+    /// Full-state frames are buffered until the whole sequence has arrived,
+    /// then reassembled and merged. Other messages are handled like their
+    /// classical CAN equivalents.
+    pub fn process_canfd_frame(&mut self, frame: &CANFDFrame) -> Result<(), ECUError> {
+        // Drop frames that fail the CRC check (a no-op without crc-protection)
+        if let Err(e) = CANFDCodec::frame_payload(frame) {
+            self.stats.crdt_errors.increment(1)?;
+            return Err(e.into());
+        }
+        
+        if frame.id != CANMessageId::CRDTStateFull as u16 {
+            let classic = CANFDCodec::unwrap_classic(frame)?;
+            return self.process_can_frame(&classic);
+        }
+        
+        let header = CANFDCodec::parse_full_state_header(frame)
+            .map_err(|_| ECUError::DeserializationError)?;
+        
+        // A new sequence replaces any incomplete one
+        if header.sequence == 0 {
+            self.fd_fragments.clear();
+        }
+        self.fd_fragments.push(frame.clone())
+            .map_err(|_| ECUError::DeserializationError)?;
+        
+        if self.fd_fragments.len() < header.total_frames as usize {
+            return Ok(());
+        }
+        
+        let state = CANFDCodec::reassemble(&self.fd_fragments);
+        self.fd_fragments.clear();
+        let state = state?;
+        
+        // SAFETY: All ECUs in the network run the same build, so the state
+        // bytes have the layout of the CRDT named by the header
+        unsafe {
+            match header.kind {
+                CRDTStateKind::EmergencyBrake => {
+                    let remote: EmergencyBrakeCRDT = CANFDCodec::decode_full_crdt_state(&state)?;
+                    self.state.emergency_brake.merge(&remote)?;
+                }
+                CRDTStateKind::TemperatureFusion => {
+                    let remote: TemperatureFusionCRDT = CANFDCodec::decode_full_crdt_state(&state)?;
+                    self.state.temperature_fusion.merge(&remote)?;
+                }
+                CRDTStateKind::SystemConfig => {
+                    let remote: ConfigRegisterCRDT = CANFDCodec::decode_full_crdt_state(&state)?;
+                    self.state.system_config.merge(&remote)?;
+                }
+                CRDTStateKind::ErrorCounter => {
+                    let remote: ErrorCounterCRDT = CANFDCodec::decode_full_crdt_state(&state)?;
+                    self.state.error_counter.merge(&remote)?;
+                }
+            }
+        }
+        
+        self.stats.crdt_merges.increment(1)?;
+        Ok(())
+    }
+    
     /// Updates sensor readings (simulated for demo)
     fn update_sensor_readings(&mut self, current_time: u64) -> Result<(), ECUError> {
         // Simulate temperature readings based on ECU type
@@ -234,7 +358,7 @@ impl<B: CANBus> ECUApplication<B> {
         // Add temperature reading to our fusion
         self.state.add_temperature_reading(temperature, current_time, reliability)?;
         
-        self.stats.sensor_readings += 1;
+        self.stats.sensor_readings.increment(1)?;
         
         // Process the reading through sensor manager
         self.sensor_manager.process_temperature_reading(
@@ -248,7 +372,7 @@ impl<B: CANBus> ECUApplication<B> {
     fn check_safety_conditions(&mut self, current_time: u64) -> Result<(), ECUError> {
         // Check for critical temperature
         if self.state.is_temperature_critical() {
-            self.stats.safety_violations += 1;
+            self.stats.safety_violations.increment(1)?;
             
             // Trigger emergency brake if we're a safety-critical ECU
             if self.state.safety_level.priority() >= ASILLevel::AsilC as u8 {
@@ -272,7 +396,7 @@ impl<B: CANBus> ECUApplication<B> {
             if self.can_bus.is_transmit_ready() {
                 self.can_bus.transmit(&heartbeat)
                     .map_err(|_| ECUError::TransmissionError)?;
-                self.stats.messages_transmitted += 1;
+                self.stats.messages_transmitted.increment(1)?;
             }
         }
         
@@ -293,7 +417,7 @@ impl<B: CANBus> ECUApplication<B> {
                 if self.can_bus.is_transmit_ready() {
                     self.can_bus.transmit(&temp_frame)
                         .map_err(|_| ECUError::TransmissionError)?;
-                    self.stats.messages_transmitted += 1;
+                    self.stats.messages_transmitted.increment(1)?;
                 }
             }
         }
@@ -309,7 +433,7 @@ impl<B: CANBus> ECUApplication<B> {
                 if self.can_bus.is_transmit_ready() {
                     self.can_bus.transmit(&error_frame)
                         .map_err(|_| ECUError::TransmissionError)?;
-                    self.stats.messages_transmitted += 1;
+                    self.stats.messages_transmitted.increment(1)?;
                 }
             }
         }
@@ -322,7 +446,7 @@ impl<B: CANBus> ECUApplication<B> {
         match self.state.validate_all() {
             Ok(_) => Ok(()),
             Err(e) => {
-                self.stats.crdt_errors += 1;
+                self.stats.crdt_errors.increment(1)?;
                 Err(e.into())
             }
         }
@@ -343,14 +467,23 @@ impl<B: CANBus> ECUApplication<B> {
             self.can_bus.transmit(&brake_frame)
                 .map_err(|_| ECUError::TransmissionError)?;
             
-            self.stats.messages_transmitted += 1;
-            self.stats.emergency_brakes += 1;
+            self.stats.messages_transmitted.increment(1)?;
+            self.stats.emergency_brakes.increment(1)?;
         }
         
         Ok(())
     }
     
+    /// Merges statistics broadcast by another ECU into our view
+    pub fn merge_statistics(&mut self, remote: &ECUStatistics) -> Result<(), ECUError> {
+        self.stats.merge(remote)?;
+        Ok(())
+    }
+    
     /// Gets current system status for monitoring
+    ///
+    /// `stats` is the merged view: our own counts plus everything merged
+    /// through [`merge_statistics`](Self::merge_statistics).
     pub fn get_system_status(&self) -> SystemStatus {
         SystemStatus {
             node_id: self.state.node_id,
@@ -360,6 +493,7 @@ impl<B: CANBus> ECUApplication<B> {
             error_count: self.state.get_error_count(),
             can_bus_state: self.can_bus.get_error_state(),
             stats: self.stats.clone(),
+            last_error_context: self.last_error_context,
         }
     }
 }
@@ -373,27 +507,15 @@ pub struct SystemStatus {
     pub safety_critical_temperature: Option<f32>,
     pub error_count: u64,
     pub can_bus_state: CANBusState,
+    /// Network-wide statistics merged from all ECUs heard from
     pub stats: ECUStatistics,
+    /// Where the most recent CRDT error on a received frame was raised
+    pub last_error_context: Option<ErrorContext>,
 }
 
 // Re-export ECUError from ecu_types to avoid duplication
 pub use crate::ecu_types::ECUError;
 
-impl Clone for ECUStatistics {
-    fn clone(&self) -> Self {
-        Self {
-            messages_transmitted: self.messages_transmitted,
-            messages_received: self.messages_received,
-            crdt_merges: self.crdt_merges,
-            safety_violations: self.safety_violations,
-            sensor_readings: self.sensor_readings,
-            emergency_brakes: self.emergency_brakes,
-            can_errors: self.can_errors,
-            crdt_errors: self.crdt_errors,
-        }
-    }
-}
-
 /// Utility functions for testing and simulation
 pub mod simulation {
     use super::*;
@@ -9,7 +9,7 @@ use crdtosphere::automotive::{ReliabilityLevel, SensorFusion, SensorReading};
 use crdtosphere::automotive::safety::{SafetyLevel, ASILLevel};
 use crdtosphere::registers::LWWRegister;
 use crdtosphere::counters::GCounter;
-use crdtosphere::memory::DefaultConfig;
+use crdtosphere::memory::{DefaultConfig, NodeId};
 use crate::{ECUNodeId, SystemConfig, ECUError};
 
 /// Memory region addresses for CRDT I/O
@@ -52,9 +52,9 @@ impl MemoryMappedCRDTState {
         };
 
         Self {
-            temperature_fusion: SensorFusion::new(node_id as u8),
-            system_config: LWWRegister::new(node_id as u8),
-            error_counter: GCounter::new(node_id as u8),
+            temperature_fusion: SensorFusion::new(NodeId::from(node_id)),
+            system_config: LWWRegister::new(NodeId::from(node_id)),
+            error_counter: GCounter::new(NodeId::from(node_id)),
             emergency_state: 0,
             node_id,
             reliability_level,
@@ -88,7 +88,7 @@ impl MemoryMappedCRDTState {
             let reading = SensorReading::new(
                 raw_temp,
                 timestamp,
-                self.node_id as u8,
+                NodeId::from(self.node_id),
                 self.reliability_level,
                 safety_level,
             );
@@ -233,7 +233,7 @@ impl MemoryMappedCRDTState {
 
         // Encode temperature message
         let temp_message = CRDTMessage {
-            node_id: self.node_id as u8,
+            node_id: NodeId::from(self.node_id),
             message_type: CRDTMessageType::TemperatureFusion as u8,
             timestamp: timestamp as u32,
             data: fused_temp.to_bits(),
@@ -255,7 +255,7 @@ impl MemoryMappedCRDTState {
         };
 
         let emergency_message = CRDTMessage {
-            node_id: self.node_id as u8,
+            node_id: NodeId::from(self.node_id),
             message_type: CRDTMessageType::EmergencyCoordination as u8,
             timestamp: timestamp as u32,
             data: emergency_state,
@@ -276,7 +276,7 @@ impl MemoryMappedCRDTState {
         };
 
         let config_message = CRDTMessage {
-            node_id: self.node_id as u8,
+            node_id: NodeId::from(self.node_id),
             message_type: CRDTMessageType::ConfigurationSync as u8,
             timestamp: timestamp as u32,
             data: config_state,
@@ -297,7 +297,7 @@ impl MemoryMappedCRDTState {
         };
 
         let error_message = CRDTMessage {
-            node_id: self.node_id as u8,
+            node_id: NodeId::from(self.node_id),
             message_type: CRDTMessageType::ErrorCounting as u8,
             timestamp: timestamp as u32,
             data: error_count,
@@ -320,7 +320,7 @@ impl MemoryMappedCRDTState {
                 let message = ptr::read_volatile(can_rx_ptr);
 
                 // Skip messages from ourselves
-                if message.node_id == self.node_id as u8 {
+                if message.node_id == NodeId::from(self.node_id) {
                     continue;
                 }
 
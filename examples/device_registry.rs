@@ -17,6 +17,12 @@ enum Capability {
     Accelerometer,
 }
 
+impl StateHash for Capability {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        (self.clone() as u8).hash_into(hasher);
+    }
+}
+
 fn main() -> Result<(), CRDTError> {
     // Device capability sets
     let mut mobile_device = GSet::<Capability, DefaultConfig>::new();
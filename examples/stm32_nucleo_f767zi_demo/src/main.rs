@@ -137,7 +137,7 @@ fn main() -> ! {
     if let Err(e) = validate_crdt_properties() {
         error!("❌ CRDT property validation failed");
         // Print error details based on error type
-        match e {
+        match e.cause() {
             crdtosphere::error::CRDTError::InvalidMerge => error!("Error type: InvalidMerge"),
             crdtosphere::error::CRDTError::OutOfMemory => error!("Error type: OutOfMemory"),
             crdtosphere::error::CRDTError::NodeCountExceeded => error!("Error type: NodeCountExceeded"),
@@ -0,0 +1,38 @@
+//! GCounter merge commutativity
+//!
+//! The input is two CBOR-encoded `GCounter` states: the first byte gives the
+//! length of the first state, the rest of the input is the second state.
+//! Inputs that do not decode, or decode to wrapped counters, are skipped.
+
+#![no_main]
+
+use crdtosphere::prelude::*;
+use crdtosphere::serialization::from_cbor_bytes;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&split, rest)) = data.split_first() else {
+        return;
+    };
+    let (first, second) = rest.split_at((split as usize).min(rest.len()));
+
+    let Ok(a) = from_cbor_bytes::<GCounter<DefaultConfig>>(first) else {
+        return;
+    };
+    let Ok(b) = from_cbor_bytes::<GCounter<DefaultConfig>>(second) else {
+        return;
+    };
+
+    // Wrapped peers are refused by merge in the default overflow mode
+    if a.has_wrapped() || b.has_wrapped() {
+        return;
+    }
+
+    let mut ab = a.clone();
+    ab.merge(&b).unwrap();
+    let mut ba = b.clone();
+    ba.merge(&a).unwrap();
+
+    assert!(ab.eq(&ba), "merge is not commutative: {ab:?} vs {ba:?}");
+    assert_eq!(ab.value(), ba.value());
+});
@@ -0,0 +1,81 @@
+//! ORSet operation log consistency
+//!
+//! Replays a sequence of adds, removes and merges on two replicas and checks
+//! `contains()` against a model of the log after every step. Timestamps grow
+//! with each operation, so for every element the latest effective add or
+//! remove seen by a replica decides membership. A capacity error ends the run,
+//! since the model no longer describes the replica.
+
+#![no_main]
+
+use crdtosphere::prelude::*;
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+/// Number of distinct elements, small enough to collide often
+const ELEMENTS: usize = 4;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Add { replica: bool, element: u8 },
+    Remove { replica: bool, element: u8 },
+    Merge { into_first: bool },
+}
+
+/// Latest effective operation per element: (timestamp, present)
+type Model = [Option<(u64, bool)>; ELEMENTS];
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut replicas = [
+        ORSet::<u32, DefaultConfig>::new(1),
+        ORSet::<u32, DefaultConfig>::new(2),
+    ];
+    let mut models: [Model; 2] = [[None; ELEMENTS]; 2];
+
+    for (index, op) in ops.into_iter().enumerate() {
+        let timestamp = index as u64 + 1;
+
+        match op {
+            Op::Add { replica, element } => {
+                let (r, e) = (replica as usize, element as usize % ELEMENTS);
+                if replicas[r].add(e as u32, timestamp).is_err() {
+                    return;
+                }
+                models[r][e] = Some((timestamp, true));
+            }
+            Op::Remove { replica, element } => {
+                let (r, e) = (replica as usize, element as usize % ELEMENTS);
+                let was_present = replicas[r].contains(&(e as u32));
+                let Ok(removed) = replicas[r].remove(&(e as u32), timestamp) else {
+                    return;
+                };
+                assert_eq!(removed, was_present);
+                if removed {
+                    models[r][e] = Some((timestamp, false));
+                }
+            }
+            Op::Merge { into_first } => {
+                let (dst, src) = if into_first { (0, 1) } else { (1, 0) };
+                let other = replicas[src].clone();
+                if replicas[dst].merge(&other).is_err() {
+                    return;
+                }
+                for e in 0..ELEMENTS {
+                    models[dst][e] = models[dst][e].max(models[src][e]);
+                }
+            }
+        }
+
+        for (replica, model) in replicas.iter().zip(models.iter()) {
+            for (e, latest) in model.iter().enumerate() {
+                let expected = latest.is_some_and(|(_, present)| present);
+                assert_eq!(
+                    replica.contains(&(e as u32)),
+                    expected,
+                    "element {e} disagrees with the log on node {}",
+                    replica.node_id()
+                );
+            }
+        }
+    }
+});
@@ -0,0 +1,51 @@
+//! LWWMap merge commutativity
+//!
+//! Builds two `LWWMap` replicas from arbitrary inserts and deletes, then
+//! checks that `merge(a, b)` equals `merge(b, a)`. Operations that overflow a
+//! replica are skipped, and pairs whose union does not fit are ignored.
+
+#![no_main]
+
+use crdtosphere::prelude::*;
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Insert { key: u8, value: u32, timestamp: u16 },
+    Delete { key: u8, timestamp: u16 },
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    first: Vec<Op>,
+    second: Vec<Op>,
+}
+
+fn build(node_id: u8, ops: &[Op]) -> LWWMap<u8, u32, DefaultConfig> {
+    let mut map = LWWMap::new(node_id);
+    for op in ops {
+        let _ = match *op {
+            Op::Insert {
+                key,
+                value,
+                timestamp,
+            } => map.insert(key, value, timestamp as u64).map(|_| ()),
+            Op::Delete { key, timestamp } => map.delete(&key, timestamp as u64).map(|_| ()),
+        };
+    }
+    map
+}
+
+fuzz_target!(|input: Input| {
+    let a = build(1, &input.first);
+    let b = build(2, &input.second);
+
+    let mut ab = a.clone();
+    let mut ba = b.clone();
+    if ab.merge(&b).is_err() || ba.merge(&a).is_err() {
+        return;
+    }
+
+    assert!(ab.eq(&ba), "merge is not commutative: {ab:?} vs {ba:?}");
+});
@@ -0,0 +1,31 @@
+//! CBOR decoding robustness
+//!
+//! Feeds arbitrary bytes to `from_cbor_bytes()` for every CRDT that
+//! implements `minicbor::Decode`. Decoding may fail, but must never panic,
+//! and whatever decodes must survive validation without panicking.
+
+#![no_main]
+
+use crdtosphere::prelude::*;
+use crdtosphere::serialization::from_cbor_bytes;
+use libfuzzer_sys::fuzz_target;
+
+fn decode<'b, T>(data: &'b [u8])
+where
+    T: minicbor::Decode<'b, ()> + CRDT<DefaultConfig>,
+{
+    if let Ok(crdt) = from_cbor_bytes::<T>(data) {
+        let _ = crdt.validate();
+        let _ = crdt.state_hash();
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    decode::<GCounter<DefaultConfig>>(data);
+    decode::<PNCounter<DefaultConfig>>(data);
+    decode::<LWWRegister<u32, DefaultConfig>>(data);
+    decode::<MVRegister<u32, DefaultConfig>>(data);
+    decode::<GSet<u32, DefaultConfig>>(data);
+    decode::<ORSet<u32, DefaultConfig>>(data);
+    decode::<LWWMap<u8, u32, DefaultConfig>>(data);
+});
@@ -0,0 +1,182 @@
+#ifndef CRDTOSPHERE_H
+#define CRDTOSPHERE_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Number of live handles available per CRDT type
+#define CRDTOSPHERE_POOL_CAPACITY 8
+
+// Operation succeeded
+#define CRDTOSPHERE_OK 0
+
+// Handle is NULL, already freed, or of the wrong type
+#define CRDTOSPHERE_ERR_INVALID_HANDLE -1
+
+// Output pointer is NULL
+#define CRDTOSPHERE_ERR_NULL_POINTER -2
+
+// Register or key holds no value
+#define CRDTOSPHERE_ERR_NOT_FOUND -3
+
+// Fixed capacity exhausted
+#define CRDTOSPHERE_ERR_BUFFER_OVERFLOW -4
+
+// Counter arithmetic overflow
+#define CRDTOSPHERE_ERR_OVERFLOW -5
+
+// Node ID out of range
+#define CRDTOSPHERE_ERR_INVALID_NODE_ID -6
+
+// Operation not allowed, such as incrementing by zero
+#define CRDTOSPHERE_ERR_INVALID_OPERATION -7
+
+// Any other error
+#define CRDTOSPHERE_ERR_OTHER -100
+
+// Opaque handle to a `GCounter<DefaultConfig>`
+typedef struct GCounterHandle GCounterHandle;
+
+// Opaque handle to an `LWWMap<u8, u32, DefaultConfig>`
+typedef struct LWWMapHandle LWWMapHandle;
+
+// Opaque handle to an `LWWRegister<u32, DefaultConfig>`
+typedef struct LWWRegisterHandle LWWRegisterHandle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a grow-only counter
+//
+// # Returns
+// A new handle, or NULL if the pool is exhausted or `node_id` is out of
+// range
+struct GCounterHandle *crdtosphere_gcounter_new(uint8_t node_id);
+
+// Increments this node's count
+//
+// # Safety
+// `handle` must not be used concurrently from another thread.
+int32_t crdtosphere_gcounter_increment(struct GCounterHandle *handle, uint32_t amount);
+
+// Returns the counter's total, or 0 for an invalid handle
+//
+// # Safety
+// `handle` must not be modified concurrently from another thread.
+uint64_t crdtosphere_gcounter_value(const struct GCounterHandle *handle);
+
+// Merges `src` into `dst`
+//
+// # Safety
+// Neither handle may be used concurrently from another thread.
+int32_t crdtosphere_gcounter_merge(struct GCounterHandle *dst, const struct GCounterHandle *src);
+
+// Releases a counter; NULL and invalid handles are ignored
+//
+// # Safety
+// `handle` must not be used afterwards, nor concurrently from another
+// thread.
+void crdtosphere_gcounter_free(struct GCounterHandle *handle);
+
+// Creates a last-writer-wins register holding a `uint32_t`
+//
+// # Returns
+// A new handle, or NULL if the pool is exhausted or `node_id` is out of
+// range
+struct LWWRegisterHandle *crdtosphere_lww_register_new(uint8_t node_id);
+
+// Writes a value; it only takes effect if `timestamp` is the latest
+//
+// # Safety
+// `handle` must not be used concurrently from another thread.
+int32_t crdtosphere_lww_register_set(struct LWWRegisterHandle *handle,
+                                     uint32_t value,
+                                     uint64_t timestamp);
+
+// Reads the current value into `out`
+//
+// # Returns
+// `CRDTOSPHERE_ERR_NOT_FOUND` if the register was never written
+//
+// # Safety
+// `handle` must not be modified concurrently from another thread, and `out`
+// must be NULL or valid for writing a `uint32_t`.
+int32_t crdtosphere_lww_register_get(const struct LWWRegisterHandle *handle, uint32_t *out);
+
+// Merges `src` into `dst`
+//
+// # Safety
+// Neither handle may be used concurrently from another thread.
+int32_t crdtosphere_lww_register_merge(struct LWWRegisterHandle *dst,
+                                       const struct LWWRegisterHandle *src);
+
+// Releases a register; NULL and invalid handles are ignored
+//
+// # Safety
+// `handle` must not be used afterwards, nor concurrently from another
+// thread.
+void crdtosphere_lww_register_free(struct LWWRegisterHandle *handle);
+
+// Creates a last-writer-wins map from `uint8_t` keys to `uint32_t` values
+//
+// # Returns
+// A new handle, or NULL if the pool is exhausted or `node_id` is out of
+// range
+struct LWWMapHandle *crdtosphere_lww_map_new(uint8_t node_id);
+
+// Inserts or updates a key; it only takes effect if `timestamp` is the
+// latest for that key
+//
+// # Safety
+// `handle` must not be used concurrently from another thread.
+int32_t crdtosphere_lww_map_insert(struct LWWMapHandle *handle,
+                                   uint8_t key,
+                                   uint32_t value,
+                                   uint64_t timestamp);
+
+// Reads the value of `key` into `out`
+//
+// # Returns
+// `CRDTOSPHERE_ERR_NOT_FOUND` if the key is absent or deleted
+//
+// # Safety
+// `handle` must not be modified concurrently from another thread, and `out`
+// must be NULL or valid for writing a `uint32_t`.
+int32_t crdtosphere_lww_map_get(const struct LWWMapHandle *handle, uint8_t key, uint32_t *out);
+
+// Deletes a key with a timestamped tombstone
+//
+// The tombstone is recorded even if the key holds no value yet, so a
+// concurrent older insert from another replica stays deleted.
+//
+// # Safety
+// `handle` must not be used concurrently from another thread.
+int32_t crdtosphere_lww_map_delete(struct LWWMapHandle *handle, uint8_t key, uint64_t timestamp);
+
+// Returns the number of live keys, or 0 for an invalid handle
+//
+// # Safety
+// `handle` must not be modified concurrently from another thread.
+size_t crdtosphere_lww_map_len(const struct LWWMapHandle *handle);
+
+// Merges `src` into `dst`
+//
+// # Safety
+// Neither handle may be used concurrently from another thread.
+int32_t crdtosphere_lww_map_merge(struct LWWMapHandle *dst, const struct LWWMapHandle *src);
+
+// Releases a map; NULL and invalid handles are ignored
+//
+// # Safety
+// `handle` must not be used afterwards, nor concurrently from another
+// thread.
+void crdtosphere_lww_map_free(struct LWWMapHandle *handle);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CRDTOSPHERE_H */
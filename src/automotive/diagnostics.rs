@@ -0,0 +1,421 @@
+//! Diagnostic Trouble Code CRDTs for Automotive Applications
+//!
+//! This module implements a network-wide registry of active fault codes,
+//! so every ECU sees the same set of DTCs without duplicates after merge.
+
+use crate::automotive::safety::ASILLevel;
+use crate::clock::{FnvHasher, StateHash};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::sets::ORSet;
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Diagnostic Trouble Code
+///
+/// Two DTCs are equal when their `code` matches: the same fault observed by
+/// different ECUs is one DTC, whatever each ECU recorded as metadata.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DTC {
+    /// Fault code (e.g. an OBD-II or manufacturer code)
+    pub code: u32,
+    /// Safety integrity level of the affected function
+    pub asil: ASILLevel,
+    /// Timestamp when the fault was first observed
+    pub first_seen: u64,
+    /// Severity, higher is more severe
+    pub severity: u8,
+}
+
+impl DTC {
+    /// Creates a new diagnostic trouble code
+    pub fn new(code: u32, asil: ASILLevel, first_seen: u64, severity: u8) -> Self {
+        Self {
+            code,
+            asil,
+            first_seen,
+            severity,
+        }
+    }
+
+    /// Creates a DTC that only carries the code, for lookups
+    fn probe(code: u32) -> Self {
+        Self::new(code, ASILLevel::QM, 0, 0)
+    }
+}
+
+impl PartialEq for DTC {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl Eq for DTC {}
+
+impl StateHash for DTC {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.code.hash_into(hasher);
+    }
+}
+
+/// Diagnostic Trouble Code registry CRDT
+///
+/// Tracks the active DTCs of the whole network in an observed-remove set.
+/// A code set by one ECU and independently observed by another appears
+/// once after merge; when several ECUs recorded it, the reported DTC has the
+/// earliest `first_seen` and the highest severity and ASIL level.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `CAPACITY`: The maximum number of DTC entries (defaults to 32)
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::automotive::{ASILLevel, DTCRegistry, DTC};
+///
+/// let mut engine = DTCRegistry::<DefaultConfig>::new(1);
+/// let mut gateway = DTCRegistry::<DefaultConfig>::new(2);
+///
+/// engine.set_active(DTC::new(0x0217, ASILLevel::AsilB, 1000, 3), 1000)?;
+/// gateway.set_active(DTC::new(0x0217, ASILLevel::AsilB, 1005, 3), 1005)?;
+///
+/// gateway.merge(&engine)?;
+/// assert!(gateway.is_active(0x0217));
+/// assert_eq!(gateway.iter_active().count(), 1);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DTCRegistry<C: MemoryConfig, const CAPACITY: usize = 32> {
+    /// Active fault codes
+    codes: ORSet<DTC, C, CAPACITY>,
+    /// Whether clearing is blocked while an ASIL-D DTC is stored (local only)
+    freeze_on_asil_d: bool,
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> DTCRegistry<C, CAPACITY> {
+    /// Creates a new DTC registry
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node
+    ///
+    /// # Returns
+    /// A new registry with no active DTCs
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            codes: ORSet::with_capacity(node_id),
+            freeze_on_asil_d: false,
+        }
+    }
+
+    /// Marks a DTC as active
+    ///
+    /// # Arguments
+    /// * `dtc` - The fault code and its metadata
+    /// * `timestamp` - The timestamp of this operation
+    ///
+    /// # Returns
+    /// Ok(true) if this node had not recorded the code yet, Ok(false) otherwise,
+    /// or an error if the registry is full
+    pub fn set_active(&mut self, dtc: DTC, timestamp: u64) -> CRDTResult<bool> {
+        self.codes.add(dtc, timestamp)
+    }
+
+    /// Clears an active DTC
+    ///
+    /// # Arguments
+    /// * `code` - The fault code to clear
+    /// * `timestamp` - The timestamp of this operation
+    ///
+    /// # Returns
+    /// Ok(true) if the code was active, Ok(false) if it wasn't,
+    /// `CRDTError::SafetyViolation` if the registry is frozen by an ASIL-D DTC,
+    /// or an error if the tombstone storage is full
+    pub fn clear_active(&mut self, code: u32, timestamp: u64) -> CRDTResult<bool> {
+        if self.is_frozen() {
+            return Err(CRDTError::SafetyViolation);
+        }
+
+        self.codes.remove(&DTC::probe(code), timestamp)
+    }
+
+    /// Checks if a DTC is active
+    ///
+    /// # Arguments
+    /// * `code` - The fault code to look up
+    ///
+    /// # Returns
+    /// true if the code is active on any node seen so far
+    pub fn is_active(&self, code: u32) -> bool {
+        self.codes.contains(&DTC::probe(code))
+    }
+
+    /// Returns the number of active DTCs
+    pub fn active_count(&self) -> usize {
+        self.codes.len()
+    }
+
+    /// Returns the active DTCs sorted by severity, most severe first
+    ///
+    /// DTCs with equal severity are ordered by code.
+    ///
+    /// # Returns
+    /// An iterator over the active DTCs, one per code
+    pub fn iter_active(&self) -> impl Iterator<Item = DTC> {
+        let mut active = [None; CAPACITY];
+        let mut count = 0;
+
+        for dtc in self.codes.iter() {
+            match active[..count]
+                .iter_mut()
+                .flatten()
+                .find(|existing: &&mut DTC| existing.code == dtc.code)
+            {
+                Some(existing) => {
+                    // Combine what each node recorded for the same fault
+                    existing.first_seen = existing.first_seen.min(dtc.first_seen);
+                    existing.severity = existing.severity.max(dtc.severity);
+                    existing.asil = existing.asil.max(dtc.asil);
+                }
+                None => {
+                    active[count] = Some(*dtc);
+                    count += 1;
+                }
+            }
+        }
+
+        // Severity descending, then code ascending
+        active[..count].sort_unstable_by(|a, b| {
+            let (a, b) = (a.as_ref(), b.as_ref());
+            b.map(|dtc| dtc.severity)
+                .cmp(&a.map(|dtc| dtc.severity))
+                .then_with(|| a.map(|dtc| dtc.code).cmp(&b.map(|dtc| dtc.code)))
+        });
+
+        active.into_iter().flatten()
+    }
+
+    /// Blocks `clear_active` while an ASIL-D DTC is stored
+    ///
+    /// Once an ASIL-D fault is active, no code can be cleared from this
+    /// registry, so the fault survives gateway resets and diagnostic sessions.
+    /// The freeze is local: it is not copied by merge.
+    pub fn freeze_after_asil_d_fault(&mut self) {
+        self.freeze_on_asil_d = true;
+    }
+
+    /// Checks if clearing is currently blocked
+    ///
+    /// # Returns
+    /// true if the freeze is armed and an ASIL-D DTC is active
+    pub fn is_frozen(&self) -> bool {
+        self.freeze_on_asil_d && self.codes.iter().any(|dtc| dtc.asil == ASILLevel::AsilD)
+    }
+
+    /// Gets this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.codes.node_id()
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for DTCRegistry<C, CAPACITY> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.codes.merge(&other.codes)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.codes.eq(&other.codes)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.codes.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        // Order-independent hash over the active codes
+        let mut hash = 0u32;
+        for dtc in self.iter_active() {
+            hash ^= dtc.code ^ ((dtc.severity as u32) << 24);
+        }
+        hash ^ self.active_count() as u32
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.codes.can_merge(&other.codes)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for DTCRegistry<C, CAPACITY> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.codes.element_entries()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        self.codes.compact()
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.codes.can_add_element()
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for DTCRegistry<C, CAPACITY> {
+    const MAX_MERGE_CYCLES: u32 = <ORSet<DTC, C, CAPACITY> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 =
+        <ORSet<DTC, C, CAPACITY> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 =
+        <ORSet<DTC, C, CAPACITY> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.codes.merge_bounded(&other.codes)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.codes.validate_bounded()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        self.codes.remaining_budget()
+    }
+
+    fn set_budget(&mut self, cycles: u32) {
+        self.codes.set_budget(cycles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_set_and_clear() {
+        let mut registry = DTCRegistry::<DefaultConfig>::new(1);
+        assert!(!registry.is_active(0x0100));
+
+        assert!(
+            registry
+                .set_active(DTC::new(0x0100, ASILLevel::AsilA, 1000, 2), 1000)
+                .unwrap()
+        );
+        assert!(registry.is_active(0x0100));
+        assert_eq!(registry.active_count(), 1);
+
+        assert!(registry.clear_active(0x0100, 2000).unwrap());
+        assert!(!registry.is_active(0x0100));
+        assert!(!registry.clear_active(0x0100, 2001).unwrap());
+    }
+
+    #[test]
+    fn test_independent_observations_merge_once() {
+        let mut engine = DTCRegistry::<DefaultConfig>::new(1);
+        let mut brake = DTCRegistry::<DefaultConfig>::new(2);
+
+        engine
+            .set_active(DTC::new(0x0217, ASILLevel::AsilB, 1000, 3), 1000)
+            .unwrap();
+        brake
+            .set_active(DTC::new(0x0217, ASILLevel::AsilC, 900, 5), 1005)
+            .unwrap();
+
+        let mut merged1 = engine.clone();
+        merged1.merge(&brake).unwrap();
+        let mut merged2 = brake.clone();
+        merged2.merge(&engine).unwrap();
+
+        assert_eq!(merged1.active_count(), 1);
+        let mut active = merged1.iter_active();
+        let dtc = active.next().unwrap();
+        assert!(active.next().is_none());
+
+        // Metadata from both observations is combined
+        assert_eq!(dtc.first_seen, 900);
+        assert_eq!(dtc.severity, 5);
+        assert_eq!(dtc.asil, ASILLevel::AsilC);
+
+        assert!(merged1.eq(&merged2));
+        assert_eq!(merged1.state_hash(), merged2.state_hash());
+    }
+
+    #[test]
+    fn test_iter_active_sorted_by_severity() {
+        let mut registry = DTCRegistry::<DefaultConfig>::new(1);
+        registry
+            .set_active(DTC::new(0x10, ASILLevel::QM, 1000, 1), 1000)
+            .unwrap();
+        registry
+            .set_active(DTC::new(0x20, ASILLevel::AsilB, 1001, 7), 1001)
+            .unwrap();
+        registry
+            .set_active(DTC::new(0x30, ASILLevel::AsilA, 1002, 4), 1002)
+            .unwrap();
+        registry
+            .set_active(DTC::new(0x05, ASILLevel::AsilA, 1003, 4), 1003)
+            .unwrap();
+        registry.clear_active(0x10, 1004).unwrap();
+
+        let mut codes = [0u32; 3];
+        for (slot, dtc) in codes.iter_mut().zip(registry.iter_active()) {
+            *slot = dtc.code;
+        }
+        assert_eq!(codes, [0x20, 0x05, 0x30]);
+        assert_eq!(registry.iter_active().count(), 3);
+    }
+
+    #[test]
+    fn test_freeze_after_asil_d_fault() {
+        let mut registry = DTCRegistry::<DefaultConfig>::new(1);
+        registry.freeze_after_asil_d_fault();
+        registry
+            .set_active(DTC::new(0x01, ASILLevel::AsilB, 1000, 2), 1000)
+            .unwrap();
+
+        // Armed, but no ASIL-D fault yet
+        assert!(!registry.is_frozen());
+        assert!(registry.clear_active(0x01, 1001).unwrap());
+
+        registry
+            .set_active(DTC::new(0x02, ASILLevel::AsilD, 1002, 9), 1002)
+            .unwrap();
+        registry
+            .set_active(DTC::new(0x03, ASILLevel::QM, 1003, 1), 1003)
+            .unwrap();
+        assert!(registry.is_frozen());
+        assert_eq!(
+            registry.clear_active(0x02, 1004),
+            Err(CRDTError::SafetyViolation)
+        );
+        assert_eq!(
+            registry.clear_active(0x03, 1004),
+            Err(CRDTError::SafetyViolation)
+        );
+        assert!(registry.is_active(0x02));
+        assert!(registry.is_active(0x03));
+    }
+
+    #[test]
+    fn test_capacity_beyond_default_set_size() {
+        let mut registry = DTCRegistry::<DefaultConfig>::new(1);
+        for code in 0..20u32 {
+            registry
+                .set_active(DTC::new(code, ASILLevel::QM, 1000, 1), 1000 + code as u64)
+                .unwrap();
+        }
+
+        assert_eq!(registry.active_count(), 20);
+        assert!(registry.validate().is_ok());
+    }
+}
@@ -3,9 +3,15 @@
 //! This module provides CRDTs specifically designed for automotive applications,
 //! with ISO 26262 safety compliance and ECU coordination patterns.
 
+pub mod diagnostics;
+pub mod node;
 pub mod safety;
 pub mod sensors;
+pub mod watchdog;
 
 // Re-export main types
-pub use safety::{ASILLevel, SafetyCRDT, SafetyLevel};
-pub use sensors::{ReliabilityLevel, SensorFusion, SensorReading};
+pub use diagnostics::{DTC, DTCRegistry};
+pub use node::{AutosarNodeId, AutosarNodeRegistry};
+pub use safety::{ASILLevel, MergePolicy, SafetyCRDT, SafetyLevel};
+pub use sensors::{ReliabilityLevel, SensorFusion, SensorReading, VehicleSpeedOdometer};
+pub use watchdog::HeartbeatMonitor;
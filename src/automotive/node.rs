@@ -0,0 +1,308 @@
+//! AUTOSAR Node Identifiers for Automotive Networks
+//!
+//! This module separates an ECU's physical CAN address from its CRDT node
+//! index, so the two can no longer be confused, and provides a replicated
+//! registry that hands out CRDT indices to ECUs as they join the network.
+
+use crate::error::{CRDTError, CRDTResult};
+use crate::maps::LWWMap;
+use crate::memory::{DefaultConfig, NodeId};
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Identity of an ECU on an AUTOSAR network
+///
+/// Pairs the CAN identifier an ECU transmits on with the node index its
+/// CRDTs use. Only the index is a [`NodeId`]; the conversion is explicit.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::automotive::AutosarNodeId;
+/// use crdtosphere::memory::NodeId;
+///
+/// // Network management PDU of the ECU with source node identifier 0x1F
+/// let ecu = AutosarNodeId::from(0x51Fu16);
+/// assert_eq!(ecu.can_id, 0x51F);
+/// assert_eq!(NodeId::from(ecu), 0x1F);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AutosarNodeId {
+    /// CAN identifier the ECU transmits on
+    pub can_id: u16,
+    /// Node index used by the ECU's CRDTs
+    pub crdt_index: u8,
+}
+
+impl AutosarNodeId {
+    /// Creates a node identifier from a CAN ID and a CRDT index
+    pub const fn new(can_id: u16, crdt_index: u8) -> Self {
+        Self { can_id, crdt_index }
+    }
+}
+
+impl From<AutosarNodeId> for NodeId {
+    fn from(id: AutosarNodeId) -> Self {
+        id.crdt_index
+    }
+}
+
+/// Derives the CRDT index from a network management CAN ID
+///
+/// Follows the AUTOSAR CanNm address plan, where an NM PDU is sent on a base
+/// ID plus the ECU's source node identifier in the low byte, so the index is
+/// the low byte of `can_id`. Networks without a fixed address plan should
+/// assign indices with an [`AutosarNodeRegistry`] instead.
+impl From<u16> for AutosarNodeId {
+    fn from(can_id: u16) -> Self {
+        Self::new(can_id, can_id as u8)
+    }
+}
+
+/// Replicated assignment of CRDT indices to ECUs
+///
+/// Maps CAN IDs to CRDT node indices in a last-writer-wins map. Merging two
+/// registries unions their assignments. If two ECUs were given the same index
+/// concurrently, the lower CAN ID keeps it, as it would win arbitration on the
+/// bus; the other ECU has no index until it calls
+/// [`register_ecu`](Self::register_ecu) again.
+///
+/// # Type Parameters
+/// - `MAX_ECUS`: The maximum number of ECUs, and of CRDT indices handed out
+///   (defaults to 16)
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::automotive::AutosarNodeRegistry;
+/// use crdtosphere::traits::CRDT;
+///
+/// let mut gateway = AutosarNodeRegistry::<16>::new(0);
+/// assert_eq!(gateway.register_ecu(0x7E0, 1000)?, 0); // Engine
+/// assert_eq!(gateway.register_ecu(0x7E1, 1000)?, 1); // Transmission
+///
+/// let mut brake = AutosarNodeRegistry::<16>::new(2);
+/// brake.merge(&gateway)?;
+/// assert_eq!(brake.lookup_by_can_id(0x7E1), Some(1));
+/// assert_eq!(brake.lookup_by_crdt_index(0), Some(0x7E0));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct AutosarNodeRegistry<const MAX_ECUS: usize = 16> {
+    /// CRDT index assigned to each CAN ID
+    assignments: LWWMap<u16, u8, DefaultConfig, MAX_ECUS>,
+}
+
+impl<const MAX_ECUS: usize> AutosarNodeRegistry<MAX_ECUS> {
+    /// Creates an empty registry
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            assignments: LWWMap::with_capacity(node_id),
+        }
+    }
+
+    /// Assigns a CRDT index to an ECU
+    ///
+    /// An ECU that already holds an index keeps it. Otherwise it gets the
+    /// lowest index no other ECU holds.
+    ///
+    /// # Arguments
+    /// * `can_id` - The CAN ID of the ECU
+    /// * `timestamp` - The timestamp of this operation
+    ///
+    /// # Returns
+    /// The ECU's CRDT index, `CRDTError::BufferOverflow` if every index is
+    /// taken or the registry is full, or `CRDTError::InvalidOperation` if a
+    /// newer assignment for `can_id` overrides this one
+    pub fn register_ecu(&mut self, can_id: u16, timestamp: u64) -> CRDTResult<u8> {
+        if let Some(index) = self.lookup_by_can_id(can_id) {
+            return Ok(index);
+        }
+
+        let index = (0..MAX_ECUS)
+            .filter_map(|index| u8::try_from(index).ok())
+            .find(|&index| self.lookup_by_crdt_index(index).is_none())
+            .ok_or(CRDTError::BufferOverflow)?;
+        self.assignments.insert(can_id, index, timestamp)?;
+
+        self.lookup_by_can_id(can_id)
+            .ok_or(CRDTError::InvalidOperation)
+    }
+
+    /// Looks up the CRDT index of an ECU
+    ///
+    /// # Arguments
+    /// * `can_id` - The CAN ID of the ECU
+    ///
+    /// # Returns
+    /// The ECU's CRDT index, or None if it is not registered or lost its
+    /// index to an ECU with a lower CAN ID
+    pub fn lookup_by_can_id(&self, can_id: u16) -> Option<NodeId> {
+        let index = *self.assignments.get(&can_id)?;
+        (self.lookup_by_crdt_index(index) == Some(can_id)).then_some(index)
+    }
+
+    /// Looks up the ECU holding a CRDT index
+    ///
+    /// # Arguments
+    /// * `index` - The CRDT index
+    ///
+    /// # Returns
+    /// The CAN ID of the ECU holding the index, or None if it is free
+    pub fn lookup_by_crdt_index(&self, index: u8) -> Option<u16> {
+        self.assignments
+            .iter()
+            .filter(|&(_, &assigned)| assigned == index)
+            .map(|(&can_id, _)| can_id)
+            .min()
+    }
+
+    /// Returns an iterator over the ECUs holding an index
+    ///
+    /// # Returns
+    /// An iterator over the resolved node identifiers
+    pub fn iter(&self) -> impl Iterator<Item = AutosarNodeId> + '_ {
+        self.assignments.iter().filter_map(|(&can_id, &index)| {
+            (self.lookup_by_crdt_index(index) == Some(can_id))
+                .then_some(AutosarNodeId::new(can_id, index))
+        })
+    }
+
+    /// Gets this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.assignments.node_id()
+    }
+}
+
+impl<const MAX_ECUS: usize> CRDT<DefaultConfig> for AutosarNodeRegistry<MAX_ECUS> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.assignments.merge(&other.assignments)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.assignments.eq(&other.assignments)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.assignments.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.assignments.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.assignments.can_merge(&other.assignments)
+    }
+}
+
+impl<const MAX_ECUS: usize> BoundedCRDT<DefaultConfig> for AutosarNodeRegistry<MAX_ECUS> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = MAX_ECUS;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.assignments.element_count()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        self.assignments.compact()
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.assignments.can_add_element()
+    }
+}
+
+impl<const MAX_ECUS: usize> RealTimeCRDT<DefaultConfig> for AutosarNodeRegistry<MAX_ECUS> {
+    const MAX_MERGE_CYCLES: u32 =
+        <LWWMap<u16, u8, DefaultConfig, MAX_ECUS> as RealTimeCRDT<DefaultConfig>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 = <LWWMap<u16, u8, DefaultConfig, MAX_ECUS> as RealTimeCRDT<
+        DefaultConfig,
+    >>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 = <LWWMap<u16, u8, DefaultConfig, MAX_ECUS> as RealTimeCRDT<
+        DefaultConfig,
+    >>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.assignments.merge_bounded(&other.assignments)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.assignments.validate_bounded()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        self.assignments.remaining_budget()
+    }
+
+    fn set_budget(&mut self, cycles: u32) {
+        self.assignments.set_budget(cycles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_id_conversions() {
+        let ecu = AutosarNodeId::new(0x1F, 3);
+        assert_eq!(NodeId::from(ecu), 3);
+        assert_eq!(AutosarNodeId::from(0x503u16), AutosarNodeId::new(0x503, 3));
+    }
+
+    #[test]
+    fn test_register_and_lookup() {
+        let mut registry = AutosarNodeRegistry::<4>::new(0);
+        assert_eq!(registry.register_ecu(0x7E0, 1000).unwrap(), 0);
+        assert_eq!(registry.register_ecu(0x7E8, 1000).unwrap(), 1);
+
+        // Registering again keeps the index
+        assert_eq!(registry.register_ecu(0x7E0, 2000).unwrap(), 0);
+        assert_eq!(registry.lookup_by_can_id(0x7E8), Some(1));
+        assert_eq!(registry.lookup_by_crdt_index(1), Some(0x7E8));
+        assert_eq!(registry.lookup_by_can_id(0x123), None);
+        assert_eq!(registry.lookup_by_crdt_index(2), None);
+
+        registry.register_ecu(0x100, 1000).unwrap();
+        registry.register_ecu(0x200, 1000).unwrap();
+        assert_eq!(
+            registry.register_ecu(0x300, 1000).map_err(|e| e.cause()),
+            Err(CRDTError::BufferOverflow)
+        );
+        assert_eq!(registry.iter().count(), 4);
+    }
+
+    #[test]
+    fn test_concurrent_registration() {
+        let mut gateway = AutosarNodeRegistry::<8>::new(0);
+        let mut brake = AutosarNodeRegistry::<8>::new(1);
+        gateway.register_ecu(0x7E0, 1000).unwrap();
+        brake.register_ecu(0x7E1, 1000).unwrap();
+
+        // Both handed out index 0; the lower CAN ID keeps it on both replicas
+        let mut merged = gateway.clone();
+        merged.merge(&brake).unwrap();
+        brake.merge(&gateway).unwrap();
+        assert!(merged.eq(&brake));
+        assert_eq!(merged.lookup_by_crdt_index(0), Some(0x7E0));
+        assert_eq!(merged.lookup_by_can_id(0x7E0), Some(0));
+        assert_eq!(merged.lookup_by_can_id(0x7E1), None);
+
+        // The displaced ECU registers again and gets a free index
+        assert_eq!(brake.register_ecu(0x7E1, 2000).unwrap(), 1);
+        merged.merge(&brake).unwrap();
+        assert_eq!(merged.lookup_by_can_id(0x7E1), Some(1));
+        assert_eq!(merged.iter().count(), 2);
+    }
+}
@@ -3,10 +3,12 @@
 //! This module implements CRDTs with ISO 26262 safety level integration,
 //! providing safety-prioritized conflict resolution for automotive ECUs.
 
-use crate::clock::CompactTimestamp;
-use crate::error::{CRDTError, CRDTResult};
+use crate::clock::fnv::hash_value;
+use crate::clock::{CompactTimestamp, StateHash};
+use crate::error::{CRDTError, CRDTResult, SafetyError};
 use crate::memory::{MemoryConfig, NodeId};
-use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+use crate::traits::safety::{CertificationLevel, SafetyMetadata, SafetyStatus};
+use crate::traits::{BoundedCRDT, CRDT, NoSafety, RealTimeCRDT, SafetyMergePolicy};
 use core::cmp::Ordering;
 
 /// ISO 26262 Automotive Safety Integrity Levels
@@ -43,6 +45,28 @@ impl ASILLevel {
     }
 }
 
+impl SafetyMergePolicy for ASILLevel {
+    fn priority(level: Self) -> u8 {
+        level.verification_level()
+    }
+
+    fn is_safety_critical(level: Self) -> bool {
+        level.is_safety_critical()
+    }
+}
+
+impl From<SafetyLevel> for ASILLevel {
+    fn from(level: SafetyLevel) -> Self {
+        asil_of(level)
+    }
+}
+
+impl From<SafetyLevel> for NoSafety {
+    fn from(_level: SafetyLevel) -> Self {
+        NoSafety
+    }
+}
+
 /// General safety level enumeration supporting multiple standards
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SafetyLevel {
@@ -83,6 +107,86 @@ impl SafetyLevel {
     }
 }
 
+/// Conflict resolution policy of a [`SafetyCRDT`] merge
+///
+/// The policy decides which of two values wins; remaining ties are broken by
+/// the later timestamp and then by the higher node ID.
+///
+/// Levels are ranked in the hierarchy of the memory configuration's
+/// [`SafetyConfig`](crate::memory::MemoryConfig::SafetyConfig) first, then
+/// by their own order. With the default
+/// [`NoSafety`](crate::traits::NoSafety) only the latter applies; with
+/// e.g. [`ASILLevel`] an ASIL D value outranks a SIL 3 value although
+/// [`SafetyLevel`] orders industrial levels above automotive ones. A replica without a
+/// value adopts any value it merges. All replicas of a value must use the
+/// same policy, otherwise they will not converge.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum MergePolicy {
+    /// The value with the higher ASIL wins, then the later timestamp
+    #[default]
+    HighestASIL,
+    /// The value with the lower ASIL wins, then the later timestamp
+    LowestASIL,
+    /// The later value wins, then the higher ASIL
+    LatestTimestamp,
+    /// The earlier value wins, then the higher ASIL
+    EarliestTimestamp,
+    /// The value whose ASIL compares `Greater` wins, then the later timestamp
+    ///
+    /// The function must be a total order for replicas to converge.
+    CustomPriority(fn(ASILLevel, ASILLevel) -> Ordering),
+}
+
+impl MergePolicy {
+    /// Compares two stamped values; `Greater` means `ours` wins
+    ///
+    /// `P` is the safety hierarchy levels are ranked in.
+    fn compare<P>(
+        &self,
+        ours: (SafetyLevel, CompactTimestamp, NodeId),
+        theirs: (SafetyLevel, CompactTimestamp, NodeId),
+    ) -> Ordering
+    where
+        P: SafetyMergePolicy + From<SafetyLevel>,
+    {
+        let (our_level, our_time, our_node) = ours;
+        let (their_level, their_time, their_node) = theirs;
+        let our_level = (P::priority(P::from(our_level)), our_level);
+        let their_level = (P::priority(P::from(their_level)), their_level);
+        let primary = match self {
+            MergePolicy::HighestASIL => our_level.cmp(&their_level).then(our_time.cmp(&their_time)),
+            MergePolicy::LowestASIL => their_level.cmp(&our_level).then(our_time.cmp(&their_time)),
+            MergePolicy::LatestTimestamp => {
+                our_time.cmp(&their_time).then(our_level.cmp(&their_level))
+            }
+            MergePolicy::EarliestTimestamp => {
+                their_time.cmp(&our_time).then(our_level.cmp(&their_level))
+            }
+            MergePolicy::CustomPriority(priority) => {
+                priority(asil_of(our_level.1), asil_of(their_level.1))
+                    .then(our_time.cmp(&their_time))
+            }
+        };
+        primary.then(our_node.cmp(&their_node))
+    }
+}
+
+/// Maps a safety level onto the ASIL scale for custom merge policies
+///
+/// Non-automotive levels are mapped by their numeric priority.
+fn asil_of(level: SafetyLevel) -> ASILLevel {
+    match level {
+        SafetyLevel::Automotive(asil) => asil,
+        other => match other.priority() {
+            0 => ASILLevel::QM,
+            1 => ASILLevel::AsilA,
+            2 => ASILLevel::AsilB,
+            3 => ASILLevel::AsilC,
+            _ => ASILLevel::AsilD,
+        },
+    }
+}
+
 /// Safety-Critical CRDT with ISO 26262 compliance
 ///
 /// This CRDT implements safety-prioritized conflict resolution where
@@ -96,6 +200,9 @@ impl SafetyLevel {
 /// - ASIL-D values always override lower safety levels
 /// - Same safety level uses timestamp ordering
 /// - Safety verification is performed on all operations
+/// - Merges can use another [`MergePolicy`], see [`with_merge_policy`](Self::with_merge_policy)
+/// - An emergency freeze locks the value on every replica it reaches, see
+///   [`emergency_freeze`](crate::traits::SafetyCRDT::emergency_freeze)
 ///
 /// # Example
 /// ```rust
@@ -137,6 +244,12 @@ pub struct SafetyCRDT<T, C: MemoryConfig> {
     local_node_id: NodeId,
     /// This node's safety level
     local_safety_level: SafetyLevel,
+    /// How merges resolve conflicting values
+    merge_policy: MergePolicy,
+    /// Reason and time of an emergency freeze
+    frozen: Option<(SafetyError, u64)>,
+    /// Token required to lift a freeze, if lifting is allowed
+    unfreeze_token: Option<u32>,
     /// Phantom data for memory config
     _phantom: core::marker::PhantomData<C>,
 }
@@ -144,6 +257,7 @@ pub struct SafetyCRDT<T, C: MemoryConfig> {
 impl<T, C: MemoryConfig> SafetyCRDT<T, C>
 where
     T: Clone + PartialEq,
+    C::SafetyConfig: From<SafetyLevel>,
 {
     /// Creates a new safety CRDT for the given node and safety level
     ///
@@ -152,7 +266,8 @@ where
     /// * `safety_level` - The safety level this node operates at
     ///
     /// # Returns
-    /// A new empty safety CRDT
+    /// A new empty safety CRDT without an unfreeze token, so a freeze can
+    /// only be lifted by replacing the instance
     pub fn new(node_id: NodeId, safety_level: SafetyLevel) -> Self {
         Self {
             value: None,
@@ -161,10 +276,125 @@ where
             node_id,
             local_node_id: node_id,
             local_safety_level: safety_level,
+            merge_policy: MergePolicy::HighestASIL,
+            frozen: None,
+            unfreeze_token: None,
             _phantom: core::marker::PhantomData,
         }
     }
 
+    /// Creates a new safety CRDT that can be unfrozen with a token
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node
+    /// * `safety_level` - The safety level this node operates at
+    /// * `unfreeze_token` - The token [`force_unfreeze`](crate::traits::SafetyCRDT::force_unfreeze)
+    ///   must be given to lift a freeze
+    ///
+    /// # Returns
+    /// A new empty safety CRDT
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// use crdtosphere::automotive::{ASILLevel, SafetyCRDT, SafetyLevel};
+    /// use crdtosphere::error::SafetyError;
+    /// use crdtosphere::traits::SafetyCRDT as _;
+    ///
+    /// let level = SafetyLevel::automotive(ASILLevel::AsilD);
+    /// let mut brake = SafetyCRDT::<u8, DefaultConfig>::with_unfreeze_token(1, level, 0xC0DE);
+    /// brake.set(80, 1000)?;
+    ///
+    /// // A safety violation locks the brake command
+    /// brake.emergency_freeze(SafetyError::SafetyBarrierBreach, 1100)?;
+    /// assert_eq!(brake.set(20, 1200), Err(CRDTError::InvalidOperation));
+    /// assert_eq!(brake.get(), Some(&80));
+    ///
+    /// // Only the configured token lifts the freeze
+    /// assert!(brake.force_unfreeze(0).is_err());
+    /// brake.force_unfreeze(0xC0DE)?;
+    /// brake.set(20, 1200)?;
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn with_unfreeze_token(
+        node_id: NodeId,
+        safety_level: SafetyLevel,
+        unfreeze_token: u32,
+    ) -> Self {
+        let mut crdt = Self::new(node_id, safety_level);
+        crdt.unfreeze_token = Some(unfreeze_token);
+        crdt
+    }
+
+    /// Creates a new automotive safety CRDT with an explicit merge policy
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node
+    /// * `asil` - The ASIL this node operates at
+    /// * `policy` - How merges resolve conflicting values
+    ///
+    /// # Returns
+    /// A new empty safety CRDT
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// use crdtosphere::automotive::{ASILLevel, MergePolicy, SafetyCRDT};
+    ///
+    /// let mut setpoint =
+    ///     SafetyCRDT::<u16, DefaultConfig>::with_merge_policy(1, ASILLevel::AsilD, MergePolicy::LatestTimestamp);
+    /// setpoint.set(900, 1000)?;
+    ///
+    /// let mut update =
+    ///     SafetyCRDT::<u16, DefaultConfig>::with_merge_policy(2, ASILLevel::AsilB, MergePolicy::LatestTimestamp);
+    /// update.set(950, 2000)?;
+    ///
+    /// assert_eq!(setpoint.winning_node_id(&update), 2);
+    /// setpoint.merge(&update)?;
+    /// assert_eq!(setpoint.get(), Some(&950));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn with_merge_policy(node_id: NodeId, asil: ASILLevel, policy: MergePolicy) -> Self {
+        let mut crdt = Self::new(node_id, SafetyLevel::automotive(asil));
+        crdt.merge_policy = policy;
+        crdt
+    }
+
+    /// Gets the merge policy
+    pub fn merge_policy(&self) -> MergePolicy {
+        self.merge_policy
+    }
+
+    /// Returns the node whose value a merge with `other` would keep
+    ///
+    /// Lets callers such as a safety manager vet a merge before performing it.
+    ///
+    /// # Returns
+    /// The node ID of the winning value; if neither side holds a value, the
+    /// node ID of `self`'s current (empty) state
+    pub fn winning_node_id(&self, other: &Self) -> NodeId {
+        if self.other_wins(other) {
+            other.node_id
+        } else {
+            self.node_id
+        }
+    }
+
+    /// Checks whether a merge would adopt `other`'s value
+    fn other_wins(&self, other: &Self) -> bool {
+        if other.value.is_none() {
+            return false;
+        }
+        if self.value.is_none() {
+            return true;
+        }
+
+        self.merge_policy.compare::<C::SafetyConfig>(
+            (self.safety_level, self.timestamp, self.node_id),
+            (other.safety_level, other.timestamp, other.node_id),
+        ) == Ordering::Less
+    }
+
     /// Sets a new value with safety verification
     ///
     /// # Arguments
@@ -172,8 +402,13 @@ where
     /// * `timestamp` - The timestamp for this update
     ///
     /// # Returns
-    /// Ok(()) if successful, or an error if safety verification fails
+    /// Ok(()) if successful, `CRDTError::InvalidOperation` if the CRDT is
+    /// frozen, or another error if safety verification fails
     pub fn set(&mut self, value: T, timestamp: u64) -> CRDTResult<()> {
+        if self.frozen.is_some() {
+            return Err(CRDTError::InvalidOperation);
+        }
+
         let new_timestamp = CompactTimestamp::new(timestamp);
 
         // Safety verification: can only set if we have appropriate safety level
@@ -262,36 +497,28 @@ where
 
 impl<T, C: MemoryConfig> CRDT<C> for SafetyCRDT<T, C>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+    C::SafetyConfig: From<SafetyLevel>,
 {
     type Error = CRDTError;
 
     fn merge(&mut self, other: &Self) -> CRDTResult<()> {
-        // Safety-prioritized merge
-        if let Some(ref other_value) = other.value {
-            let should_merge = match self.safety_level.cmp(&other.safety_level) {
-                Ordering::Less => {
-                    // Other has higher safety priority - always accept
-                    true
-                }
-                Ordering::Greater => {
-                    // We have higher safety priority - keep ours
-                    false
-                }
-                Ordering::Equal => {
-                    // Same safety level - use timestamp ordering
-                    other.timestamp > self.timestamp
-                }
-            };
-
-            if should_merge {
-                self.value = Some(other_value.clone());
-                self.safety_level = other.safety_level;
-                self.timestamp = other.timestamp;
-                self.node_id = other.node_id;
-            }
+        // A frozen replica accepts nothing until it is unfrozen
+        if self.frozen.is_some() {
+            return Err(CRDTError::InvalidOperation);
         }
 
+        // Conflicts are resolved by the configured merge policy
+        if self.other_wins(other) {
+            self.value = other.value.clone();
+            self.safety_level = other.safety_level;
+            self.timestamp = other.timestamp;
+            self.node_id = other.node_id;
+        }
+
+        // Freezing is monotone: a frozen replica freezes everyone it reaches
+        self.frozen = other.frozen;
+
         Ok(())
     }
 
@@ -300,6 +527,7 @@ where
             && self.safety_level == other.safety_level
             && self.timestamp == other.timestamp
             && self.node_id == other.node_id
+            && self.frozen == other.frozen
     }
 
     fn size_bytes(&self) -> usize {
@@ -313,8 +541,7 @@ where
     fn state_hash(&self) -> u32 {
         let mut hash = 0u32;
         if let Some(ref value) = self.value {
-            let value_ptr = value as *const T as usize;
-            hash ^= value_ptr as u32;
+            hash ^= hash_value(value);
         }
         hash ^= (self.safety_level.priority() as u32) << 24;
         hash ^= (self.timestamp.as_u64() as u32) << 8;
@@ -330,7 +557,8 @@ where
 
 impl<T, C: MemoryConfig> BoundedCRDT<C> for SafetyCRDT<T, C>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+    C::SafetyConfig: From<SafetyLevel>,
 {
     const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
     const MAX_ELEMENTS: usize = 1; // Single value
@@ -356,7 +584,8 @@ where
 
 impl<T, C: MemoryConfig> RealTimeCRDT<C> for SafetyCRDT<T, C>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+    C::SafetyConfig: From<SafetyLevel>,
 {
     const MAX_MERGE_CYCLES: u32 = 50; // Very fast merge for safety-critical systems
     const MAX_VALIDATE_CYCLES: u32 = 25;
@@ -382,6 +611,87 @@ where
     }
 }
 
+impl<T, C: MemoryConfig> crate::traits::SafetyCRDT<C> for SafetyCRDT<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+    C::SafetyConfig: From<SafetyLevel>,
+{
+    type SafetyLevel = SafetyLevel;
+
+    fn safety_level(&self) -> SafetyLevel {
+        self.safety_level
+    }
+
+    fn safety_merge(&mut self, other: &Self) -> CRDTResult<()> {
+        // The merge policy already protects safety-critical values
+        self.merge(other)
+    }
+
+    fn validate_safety(&self) -> CRDTResult<()> {
+        self.verify_safety()
+    }
+
+    fn can_safely_merge(&self, _other: &Self) -> bool {
+        self.frozen.is_none()
+    }
+
+    fn min_safety_level(&self) -> SafetyLevel {
+        // Writers below the current value's level cannot override it
+        self.safety_level
+    }
+
+    fn set_safety_level(&mut self, level: SafetyLevel) -> CRDTResult<()> {
+        if self.frozen.is_some() {
+            return Err(CRDTError::InvalidOperation);
+        }
+        self.local_safety_level = level;
+        Ok(())
+    }
+
+    fn safety_check(&self) -> CRDTResult<SafetyStatus> {
+        match self.verify_safety() {
+            Ok(()) => Ok(SafetyStatus::safe()),
+            Err(_) => Ok(SafetyStatus::unsafe_with_violations(1)),
+        }
+    }
+
+    fn safety_metadata(&self) -> SafetyMetadata<SafetyLevel> {
+        let certification_level = match self.local_safety_level.priority() {
+            0 => CertificationLevel::None,
+            1 => CertificationLevel::Basic,
+            2 => CertificationLevel::Standard,
+            3 => CertificationLevel::High,
+            _ => CertificationLevel::Critical,
+        };
+
+        SafetyMetadata {
+            safety_level: self.safety_level,
+            min_safety_level: self.min_safety_level(),
+            certification_level,
+            last_validation: 0,
+            check_interval: 0, // Checked on demand, not periodically
+        }
+    }
+
+    fn emergency_freeze(&mut self, reason: SafetyError, timestamp: u64) -> CRDTResult<()> {
+        // The first freeze is kept; refreezing does not move its timestamp
+        self.frozen.get_or_insert((reason, timestamp));
+        Ok(())
+    }
+
+    fn freeze_reason(&self) -> Option<(SafetyError, u64)> {
+        self.frozen
+    }
+
+    fn force_unfreeze(&mut self, authorization_token: u32) -> CRDTResult<()> {
+        if self.unfreeze_token != Some(authorization_token) {
+            return Err(CRDTError::SafetyViolation);
+        }
+        self.frozen = None;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -421,6 +731,22 @@ mod tests {
         assert!(crdt.is_safety_critical());
     }
 
+    #[test]
+    fn test_state_hash_uses_value() {
+        let mut crdt =
+            SafetyCRDT::<u32, DefaultConfig>::new(1, SafetyLevel::automotive(ASILLevel::AsilD));
+        crdt.set(100, 1000).unwrap();
+
+        // A copy at another address hashes the same, another value does not
+        let copy = crdt.clone();
+        assert_eq!(crdt.state_hash(), copy.state_hash());
+
+        let mut other =
+            SafetyCRDT::<u32, DefaultConfig>::new(1, SafetyLevel::automotive(ASILLevel::AsilD));
+        other.set(101, 1000).unwrap();
+        assert_ne!(crdt.state_hash(), other.state_hash());
+    }
+
     #[test]
     fn test_safety_prioritized_merge() {
         let mut asil_d_crdt =
@@ -454,6 +780,125 @@ mod tests {
         assert_eq!(crdt1.get(), Some(&200)); // Later timestamp wins
     }
 
+    #[test]
+    fn test_highest_asil_policy_ignores_timestamps() {
+        let mut asil_d = SafetyCRDT::<u32, DefaultConfig>::with_merge_policy(
+            1,
+            ASILLevel::AsilD,
+            MergePolicy::HighestASIL,
+        );
+        asil_d.set(100, 1000).unwrap();
+        let mut asil_b = SafetyCRDT::<u32, DefaultConfig>::with_merge_policy(
+            2,
+            ASILLevel::AsilB,
+            MergePolicy::HighestASIL,
+        );
+        asil_b.set(50, 9000).unwrap(); // Much later, but lower ASIL
+
+        assert_eq!(asil_d.winning_node_id(&asil_b), 1);
+        assert_eq!(asil_b.winning_node_id(&asil_d), 1);
+
+        let mut merged_d = asil_d.clone();
+        merged_d.merge(&asil_b).unwrap();
+        let mut merged_b = asil_b.clone();
+        merged_b.merge(&asil_d).unwrap();
+        assert_eq!(merged_d.get(), Some(&100));
+        assert_eq!(merged_b.get(), Some(&100));
+        assert!(merged_d.eq(&merged_b));
+    }
+
+    #[test]
+    fn test_merge_policies() {
+        fn pair(
+            policy: MergePolicy,
+        ) -> (
+            SafetyCRDT<u32, DefaultConfig>,
+            SafetyCRDT<u32, DefaultConfig>,
+        ) {
+            let mut asil_d = SafetyCRDT::with_merge_policy(1, ASILLevel::AsilD, policy);
+            asil_d.set(100, 1000).unwrap();
+            let mut asil_b = SafetyCRDT::with_merge_policy(2, ASILLevel::AsilB, policy);
+            asil_b.set(50, 2000).unwrap();
+            (asil_d, asil_b)
+        }
+
+        let (asil_d, asil_b) = pair(MergePolicy::LowestASIL);
+        assert_eq!(asil_d.winning_node_id(&asil_b), 2);
+
+        let (asil_d, asil_b) = pair(MergePolicy::LatestTimestamp);
+        assert_eq!(asil_d.winning_node_id(&asil_b), 2);
+
+        let (asil_d, asil_b) = pair(MergePolicy::EarliestTimestamp);
+        assert_eq!(asil_b.winning_node_id(&asil_d), 1);
+
+        // Prefer ASIL-B over everything else
+        let (mut asil_d, asil_b) = pair(MergePolicy::CustomPriority(|ours, theirs| {
+            (ours == ASILLevel::AsilB).cmp(&(theirs == ASILLevel::AsilB))
+        }));
+        assert_eq!(asil_d.winning_node_id(&asil_b), 2);
+        asil_d.merge(&asil_b).unwrap();
+        assert_eq!(asil_d.get(), Some(&50));
+        assert_eq!(asil_d.value_node_id(), 2);
+    }
+
+    #[test]
+    fn test_configured_safety_hierarchy() {
+        use crate::memory::define_memory_config;
+
+        define_memory_config! {
+            name: VehicleConfig,
+            total_memory: 32 * 1024,
+            max_registers: 50,
+            max_counters: 25,
+            max_sets: 15,
+            max_maps: 10,
+            max_nodes: 16,
+            safety_config: ASILLevel,
+        }
+
+        assert_eq!(ASILLevel::priority(ASILLevel::AsilC), 3);
+        assert!(!<ASILLevel as SafetyMergePolicy>::is_safety_critical(
+            ASILLevel::QM
+        ));
+
+        // An ASIL D brake command and a later level-3 industrial value
+        fn merged<C: MemoryConfig>() -> Option<u32>
+        where
+            C::SafetyConfig: From<SafetyLevel>,
+        {
+            let mut brake = SafetyCRDT::<u32, C>::new(1, SafetyLevel::automotive(ASILLevel::AsilD));
+            brake.set(80, 1000).unwrap();
+            let mut other = SafetyCRDT::<u32, C>::new(2, SafetyLevel::Industrial(3));
+            other.set(20, 2000).unwrap();
+            brake.merge(&other).unwrap();
+            brake.get().copied()
+        }
+
+        // Ranked as ASILs, level 3 is ASIL C and the brake command wins
+        assert_eq!(merged::<VehicleConfig>(), Some(80));
+        // Without a hierarchy the variant order puts industrial levels first
+        assert_eq!(merged::<DefaultConfig>(), Some(20));
+    }
+
+    #[test]
+    fn test_empty_replica_adopts_value() {
+        let mut asil_d =
+            SafetyCRDT::<u32, DefaultConfig>::new(1, SafetyLevel::automotive(ASILLevel::AsilD));
+        let mut qm =
+            SafetyCRDT::<u32, DefaultConfig>::new(2, SafetyLevel::automotive(ASILLevel::QM));
+        qm.set(20, 1000).unwrap();
+
+        // Nothing to protect yet, so replicas converge on the only value
+        assert_eq!(asil_d.winning_node_id(&qm), 2);
+        asil_d.merge(&qm).unwrap();
+        assert_eq!(asil_d.get(), Some(&20));
+
+        // An empty state never wins
+        let empty =
+            SafetyCRDT::<u32, DefaultConfig>::new(3, SafetyLevel::automotive(ASILLevel::AsilD));
+        assert_eq!(asil_d.winning_node_id(&empty), 2);
+    }
+
     #[test]
     fn test_safety_verification() {
         let crdt =
@@ -486,4 +931,54 @@ mod tests {
         assert!(crdt1.merge_bounded(&crdt2).is_ok());
         assert!(crdt1.validate_bounded().is_ok());
     }
+
+    #[test]
+    fn test_emergency_freeze() {
+        use crate::traits::SafetyCRDT as _;
+
+        let level = SafetyLevel::automotive(ASILLevel::AsilD);
+        let mut brake = SafetyCRDT::<u32, DefaultConfig>::with_unfreeze_token(1, level, 7);
+        let mut other = SafetyCRDT::<u32, DefaultConfig>::new(2, level);
+        brake.set(80, 1000).unwrap();
+        other.set(90, 2000).unwrap();
+
+        brake
+            .emergency_freeze(SafetyError::SafetyBarrierBreach, 1500)
+            .unwrap();
+        assert!(brake.is_frozen());
+        assert!(!brake.can_safely_merge(&other));
+
+        // Updates and merges are rejected while frozen
+        assert_eq!(brake.set(10, 3000), Err(CRDTError::InvalidOperation));
+        assert_eq!(brake.merge(&other), Err(CRDTError::InvalidOperation));
+        assert_eq!(brake.get(), Some(&80));
+
+        // A second freeze keeps the first reason
+        brake
+            .emergency_freeze(SafetyError::RedundancyFailure, 1600)
+            .unwrap();
+        assert_eq!(
+            brake.freeze_reason(),
+            Some((SafetyError::SafetyBarrierBreach, 1500))
+        );
+
+        // The freeze propagates through merges
+        other.merge(&brake).unwrap();
+        assert!(other.is_frozen());
+        assert_eq!(other.get(), Some(&90));
+        assert!(other.force_unfreeze(7).is_err()); // No token configured
+
+        // Unfreezing needs the configured token
+        assert_eq!(brake.force_unfreeze(8), Err(CRDTError::SafetyViolation));
+        brake.force_unfreeze(7).unwrap();
+        assert!(!brake.is_frozen());
+        brake.set(10, 3000).unwrap();
+        assert_eq!(brake.get(), Some(&10));
+
+        // A replica that is still frozen freezes it again
+        let mut fresh = SafetyCRDT::<u32, DefaultConfig>::new(3, level);
+        fresh.merge(&other).unwrap();
+        brake.merge(&fresh).unwrap();
+        assert!(brake.is_frozen());
+    }
 }
@@ -4,8 +4,11 @@
 //! weighting and automotive-specific sensor coordination patterns.
 
 use crate::automotive::safety::{ASILLevel, SafetyLevel};
-use crate::clock::CompactTimestamp;
+use crate::clock::fnv::hash_value;
+use crate::clock::{CompactTimestamp, StateHash};
+use crate::counters::{GCounter, PNCounter};
 use crate::error::{CRDTError, CRDTResult};
+use crate::maps::LWWMap;
 use crate::memory::{MemoryConfig, NodeId};
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
@@ -102,6 +105,14 @@ impl<T> SensorReading<T> {
 /// - Safety-level prioritization
 /// - Outlier detection and rejection
 /// - Temporal consistency checking
+/// - Per-sensor weight overrides
+///
+/// # Weight Overrides
+/// Each sensor's weight can be scaled at runtime with
+/// [`set_reliability_weight`](Self::set_reliability_weight), e.g. to distrust
+/// a temperature sensor next to a hot engine. Overrides live in an
+/// [`LWWMap`] that is merged with the readings, so a change made on one ECU
+/// reaches every ECU and the latest change wins.
 ///
 /// # Example
 /// ```rust
@@ -136,6 +147,8 @@ pub struct SensorFusion<T, C: MemoryConfig> {
     readings: [Option<SensorReading<T>>; 8],
     /// Current count of readings
     reading_count: usize,
+    /// Weight overrides per sensor node (0 = ignore, 255 = fully trusted)
+    weights: LWWMap<NodeId, u8, C, 8>,
     /// Node ID for this fusion unit
     #[allow(dead_code)]
     node_id: NodeId,
@@ -158,6 +171,7 @@ where
         Self {
             readings: [const { None }; 8],
             reading_count: 0,
+            weights: LWWMap::with_capacity(node_id),
             node_id,
             _phantom: core::marker::PhantomData,
         }
@@ -198,6 +212,51 @@ where
         Ok(())
     }
 
+    /// Overrides the weight of a sensor node's readings
+    ///
+    /// The reading's own weight is scaled by `weight / 255`.
+    ///
+    /// # Arguments
+    /// * `node_id` - The sensor node
+    /// * `weight` - 0 to ignore the sensor, 255 to trust it fully
+    /// * `timestamp` - The timestamp of this change
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error if overrides for 8 nodes already exist
+    pub fn set_reliability_weight(
+        &mut self,
+        node_id: NodeId,
+        weight: u8,
+        timestamp: u64,
+    ) -> CRDTResult<()> {
+        self.weights.insert(node_id, weight, timestamp)?;
+        Ok(())
+    }
+
+    /// Ignores a sensor node's readings (weight 0)
+    pub fn disable_sensor(&mut self, node_id: NodeId, timestamp: u64) -> CRDTResult<()> {
+        self.set_reliability_weight(node_id, 0, timestamp)
+    }
+
+    /// Trusts a sensor node's readings fully again (weight 255)
+    pub fn enable_sensor(&mut self, node_id: NodeId, timestamp: u64) -> CRDTResult<()> {
+        self.set_reliability_weight(node_id, u8::MAX, timestamp)
+    }
+
+    /// Gets the weight override of a sensor node
+    ///
+    /// # Returns
+    /// The override, or 255 if none has been set
+    pub fn reliability_weight(&self, node_id: NodeId) -> u8 {
+        self.weights.get(&node_id).copied().unwrap_or(u8::MAX)
+    }
+
+    /// Returns the weight of a reading after applying its node's override
+    fn reading_weight(&self, reading: &SensorReading<T>) -> f32 {
+        reading.effective_weight() * self.reliability_weight(reading.node_id) as f32
+            / u8::MAX as f32
+    }
+
     /// Gets all current sensor readings
     ///
     /// # Returns
@@ -279,8 +338,10 @@ where
 impl<C: MemoryConfig> SensorFusion<f32, C> {
     /// Computes the reliability-weighted average of all readings
     ///
+    /// Each reading's weight is scaled by its node's weight override.
+    ///
     /// # Returns
-    /// The fused sensor value, or None if no readings
+    /// The fused sensor value, or None if no readings or all are disabled
     pub fn fused_value(&self) -> Option<f32> {
         if self.reading_count == 0 {
             return None;
@@ -290,7 +351,7 @@ impl<C: MemoryConfig> SensorFusion<f32, C> {
         let mut total_weight = 0.0;
 
         for reading in self.readings() {
-            let weight = reading.effective_weight();
+            let weight = self.reading_weight(reading);
             weighted_sum += reading.value * weight;
             total_weight += weight;
         }
@@ -313,7 +374,7 @@ impl<C: MemoryConfig> SensorFusion<f32, C> {
 
         for reading in self.safety_filtered_readings(SafetyLevel::automotive(ASILLevel::AsilC)) {
             has_safety_readings = true;
-            let weight = reading.effective_weight();
+            let weight = self.reading_weight(reading);
             weighted_sum += reading.value * weight;
             total_weight += weight;
         }
@@ -339,7 +400,7 @@ impl<C: MemoryConfig> SensorFusion<f32, C> {
         let mut total_weight = 0.0;
 
         for reading in self.readings() {
-            let weight = reading.effective_weight();
+            let weight = self.reading_weight(reading);
             let diff = reading.value - mean;
             variance_sum += weight * diff * diff;
             total_weight += weight;
@@ -400,11 +461,126 @@ impl<C: MemoryConfig> SensorFusion<f32, C> {
 
         outliers
     }
+
+    /// Checks whether any reading deviates from the fused mean by more than `sigma` standard deviations
+    ///
+    /// Intended for ISO 26262 plausibility monitoring. A single reading is
+    /// never an outlier.
+    ///
+    /// # Arguments
+    /// * `sigma` - Standard deviation threshold
+    ///
+    /// # Returns
+    /// true if at least one reading is an outlier
+    pub fn outlier_detected(&self, sigma: f32) -> bool {
+        self.detect_outliers(sigma)
+            .iter()
+            .any(|node| node.is_some())
+    }
+
+    /// Computes the reliability-weighted median of all readings
+    ///
+    /// Unlike [`fused_value`](Self::fused_value), a single faulty sensor
+    /// cannot drag the result away from the other readings.
+    ///
+    /// # Returns
+    /// The value at the 50th percentile of cumulative weight, or None if no readings
+    pub fn fused_median(&self) -> Option<f32> {
+        let (sorted, count) = self.sorted_by_value();
+        let sorted = &sorted[..count];
+
+        let total_weight: f32 = sorted.iter().map(|(_, weight)| weight).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        let half_weight = total_weight / 2.0;
+        let mut cumulative_weight = 0.0;
+        for &(value, weight) in sorted {
+            cumulative_weight += weight;
+            if cumulative_weight >= half_weight {
+                return Some(value);
+            }
+        }
+
+        // Only reachable through rounding; the largest value is the answer
+        sorted.last().map(|&(value, _)| value)
+    }
+
+    /// Computes the reliability-weighted mean after trimming both tails
+    ///
+    /// The bottom and top `trim_fraction` of the cumulative weight are
+    /// dropped, partially trimming readings that straddle a cut.
+    ///
+    /// # Arguments
+    /// * `trim_fraction` - Fraction of weight to drop from each tail, clamped to 0.0..=0.5
+    ///
+    /// # Returns
+    /// The trimmed mean, the median if everything was trimmed, or None if no readings
+    pub fn fused_trimmed_mean(&self, trim_fraction: f32) -> Option<f32> {
+        let trim_fraction = if trim_fraction.is_nan() {
+            0.0
+        } else {
+            trim_fraction.clamp(0.0, 0.5)
+        };
+
+        let (sorted, count) = self.sorted_by_value();
+        let sorted = &sorted[..count];
+
+        let total_weight: f32 = sorted.iter().map(|(_, weight)| weight).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        let lower_cut = total_weight * trim_fraction;
+        let upper_cut = total_weight - lower_cut;
+
+        let mut weighted_sum = 0.0;
+        let mut kept_weight = 0.0;
+        let mut cumulative_weight = 0.0;
+        for &(value, weight) in sorted {
+            let start = cumulative_weight;
+            let end = cumulative_weight + weight;
+            cumulative_weight = end;
+
+            let kept = end.min(upper_cut) - start.max(lower_cut);
+            if kept > 0.0 {
+                weighted_sum += value * kept;
+                kept_weight += kept;
+            }
+        }
+
+        if kept_weight > 0.0 {
+            Some(weighted_sum / kept_weight)
+        } else {
+            self.fused_median()
+        }
+    }
+
+    /// Returns (value, weight) pairs sorted by value, with the number in use
+    fn sorted_by_value(&self) -> ([(f32, f32); 8], usize) {
+        let mut sorted = [(0.0f32, 0.0f32); 8];
+        let mut count = 0;
+
+        // Insertion sort keeps this allocation-free and O(n²) in the worst case
+        for reading in self.readings() {
+            let entry = (reading.value, self.reading_weight(reading));
+            let mut i = count;
+            while i > 0 && sorted[i - 1].0.total_cmp(&entry.0).is_gt() {
+                sorted[i] = sorted[i - 1];
+                i -= 1;
+            }
+            sorted[i] = entry;
+            count += 1;
+        }
+
+        (sorted, count)
+    }
 }
 
 impl<T, C: MemoryConfig> CRDT<C> for SensorFusion<T, C>
 where
-    T: Clone + PartialEq + Copy + core::fmt::Debug,
+    T: Clone + PartialEq + Copy + core::fmt::Debug + StateHash,
 {
     type Error = CRDTError;
 
@@ -413,11 +589,11 @@ where
         for reading in other.readings() {
             self.add_reading(*reading)?;
         }
-        Ok(())
+        self.weights.merge(&other.weights)
     }
 
     fn eq(&self, other: &Self) -> bool {
-        if self.reading_count != other.reading_count {
+        if self.reading_count != other.reading_count || !self.weights.eq(&other.weights) {
             return false;
         }
 
@@ -446,18 +622,19 @@ where
     }
 
     fn validate(&self) -> CRDTResult<()> {
-        self.validate_readings()
+        self.validate_readings()?;
+        self.weights.validate()
     }
 
     fn state_hash(&self) -> u32 {
         let mut hash = 0u32;
         for reading in self.readings() {
-            let value_ptr = &reading.value as *const T as usize;
-            hash ^=
-                (value_ptr as u32) ^ (reading.timestamp.as_u64() as u32) ^ (reading.node_id as u32);
+            hash ^= hash_value(&reading.value)
+                ^ (reading.timestamp.as_u64() as u32)
+                ^ (reading.node_id as u32);
         }
         hash ^= self.reading_count as u32;
-        hash
+        hash ^ self.weights.state_hash()
     }
 
     fn can_merge(&self, other: &Self) -> bool {
@@ -476,13 +653,13 @@ where
             }
         }
 
-        self.reading_count + new_nodes <= 8
+        self.reading_count + new_nodes <= 8 && self.weights.can_merge(&other.weights)
     }
 }
 
 impl<T, C: MemoryConfig> BoundedCRDT<C> for SensorFusion<T, C>
 where
-    T: Clone + PartialEq + Copy + core::fmt::Debug,
+    T: Clone + PartialEq + Copy + core::fmt::Debug + StateHash,
 {
     const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
     const MAX_ELEMENTS: usize = 8; // Maximum number of sensor readings
@@ -508,7 +685,7 @@ where
 
 impl<T, C: MemoryConfig> RealTimeCRDT<C> for SensorFusion<T, C>
 where
-    T: Clone + PartialEq + Copy + core::fmt::Debug,
+    T: Clone + PartialEq + Copy + core::fmt::Debug + StateHash,
 {
     const MAX_MERGE_CYCLES: u32 = 200; // Bounded by number of readings
     const MAX_VALIDATE_CYCLES: u32 = 100;
@@ -534,6 +711,231 @@ where
     }
 }
 
+/// Vehicle speed and odometer CRDT with plausibility checking
+///
+/// Fuses the speed reports of several ECUs (e.g. ABS and traction control)
+/// in a [`SensorFusion`] and counts odometer pulses in a [`PNCounter`]. Each
+/// node measures its own pulse rate over windows of
+/// [`RATE_WINDOW_MS`](Self::RATE_WINDOW_MS); when a window closes, the rate
+/// is checked against the fused speed and any disagreement of more than 10%
+/// is counted as an ISO 26262 plausibility violation in a [`GCounter`].
+///
+/// Merging combines the speed readings, pulse counts and violation counts.
+/// The pulse rate itself is a local measurement and is not merged.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::automotive::{ReliabilityLevel, VehicleSpeedOdometer};
+///
+/// // 1000 odometer pulses per km
+/// let mut abs = VehicleSpeedOdometer::<DefaultConfig>::new(1, 1000);
+/// abs.update_speed(36.0, ReliabilityLevel::High, 0)?;
+///
+/// // 36 km/h is 10 pulses per second
+/// for i in 0..=10 {
+///     abs.record_odometer_pulse(i * 100)?;
+/// }
+/// assert!(abs.is_plausible());
+/// assert_eq!(abs.plausibility_violation_count(), 0);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct VehicleSpeedOdometer<C: MemoryConfig> {
+    /// Speed reports from all ECUs, in km/h
+    speed: SensorFusion<f32, C>,
+    /// Odometer pulses from all ECUs
+    pulses: PNCounter<C>,
+    /// Detected plausibility violations
+    violations: GCounter<C>,
+    /// Odometer pulses per kilometer (wheel circumference factor)
+    pulses_per_km: u32,
+    /// Start timestamp of the current rate window and own pulses at that time
+    window_start: Option<(u64, u32)>,
+    /// Own pulse rate over the last complete window, in pulses per second
+    pulse_rate: Option<f32>,
+    /// This ECU's node ID
+    node_id: NodeId,
+}
+
+impl<C: MemoryConfig> VehicleSpeedOdometer<C> {
+    /// Length of the pulse rate measurement window in milliseconds
+    pub const RATE_WINDOW_MS: u64 = 1000;
+
+    /// Allowed relative deviation between pulse rate and fused speed
+    pub const PLAUSIBILITY_TOLERANCE: f32 = 0.1;
+
+    /// Creates a new speed and odometer CRDT
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this ECU
+    /// * `pulses_per_km` - Odometer pulses per kilometer driven
+    pub fn new(node_id: NodeId, pulses_per_km: u32) -> Self {
+        Self {
+            speed: SensorFusion::new(node_id),
+            pulses: PNCounter::new(node_id),
+            violations: GCounter::new(node_id),
+            pulses_per_km,
+            window_start: None,
+            pulse_rate: None,
+            node_id,
+        }
+    }
+
+    /// Reports this ECU's speed measurement
+    ///
+    /// # Arguments
+    /// * `speed_kph` - Measured speed in km/h
+    /// * `reliability` - Reliability of the speed sensor
+    /// * `timestamp` - Time of the measurement in milliseconds
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error if the speed fusion is full
+    pub fn update_speed(
+        &mut self,
+        speed_kph: f32,
+        reliability: ReliabilityLevel,
+        timestamp: u64,
+    ) -> CRDTResult<()> {
+        self.speed.add_reading(SensorReading::new(
+            speed_kph,
+            timestamp,
+            self.node_id,
+            reliability,
+            SafetyLevel::automotive(ASILLevel::AsilB),
+        ))
+    }
+
+    /// Records one odometer pulse seen by this ECU
+    ///
+    /// Closes the current rate window once it spans at least
+    /// [`RATE_WINDOW_MS`](Self::RATE_WINDOW_MS), updates the pulse rate and
+    /// records a violation if it is not plausible.
+    ///
+    /// # Arguments
+    /// * `timestamp` - Time of the pulse in milliseconds
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error if a counter overflows
+    pub fn record_odometer_pulse(&mut self, timestamp: u64) -> CRDTResult<()> {
+        self.pulses.increment(1)?;
+        let own_pulses = self.pulses.positive_contribution_of(self.node_id);
+
+        let Some((start, start_pulses)) = self.window_start else {
+            self.window_start = Some((timestamp, own_pulses));
+            return Ok(());
+        };
+
+        let elapsed = timestamp.saturating_sub(start);
+        if elapsed >= Self::RATE_WINDOW_MS {
+            let window_pulses = own_pulses.wrapping_sub(start_pulses);
+            self.pulse_rate = Some(window_pulses as f32 * 1000.0 / elapsed as f32);
+            self.window_start = Some((timestamp, own_pulses));
+
+            if !self.is_plausible() {
+                self.violations.increment(1)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether the pulse rate is consistent with the fused speed
+    ///
+    /// # Returns
+    /// false if the last measured pulse rate deviates from the rate expected
+    /// at the fused speed by more than 10%, true otherwise (including while
+    /// either value is not yet known)
+    pub fn is_plausible(&self) -> bool {
+        let (Some(speed_kph), Some(pulse_rate)) = (self.fused_speed(), self.pulse_rate) else {
+            return true;
+        };
+
+        let expected = speed_kph * self.pulses_per_km as f32 / 3600.0;
+        (pulse_rate - expected).abs() <= expected.abs() * Self::PLAUSIBILITY_TOLERANCE
+    }
+
+    /// Returns the number of plausibility violations seen by all ECUs
+    pub fn plausibility_violation_count(&self) -> u64 {
+        self.violations.value()
+    }
+
+    /// Returns the reliability-weighted speed of all ECUs in km/h
+    pub fn fused_speed(&self) -> Option<f32> {
+        self.speed.fused_value()
+    }
+
+    /// Returns the speed reported by one ECU in km/h
+    ///
+    /// # Arguments
+    /// * `node_id` - The ECU to look up
+    pub fn speed_at_node(&self, node_id: NodeId) -> Option<f32> {
+        self.speed
+            .readings()
+            .find(|reading| reading.node_id == node_id)
+            .map(|reading| reading.value)
+    }
+
+    /// Returns this ECU's pulse rate over the last complete window, in
+    /// pulses per second
+    pub fn pulse_rate(&self) -> Option<f32> {
+        self.pulse_rate
+    }
+
+    /// Returns the odometer pulses counted by all ECUs
+    pub fn odometer_pulses(&self) -> i64 {
+        self.pulses.value()
+    }
+
+    /// Returns the distance covered by all counted pulses in kilometers
+    pub fn distance_km(&self) -> f32 {
+        self.odometer_pulses() as f32 / self.pulses_per_km as f32
+    }
+
+    /// Returns this ECU's node ID
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+}
+
+impl<C: MemoryConfig> CRDT<C> for VehicleSpeedOdometer<C> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.speed.merge(&other.speed)?;
+        self.pulses.merge(&other.pulses)?;
+        self.violations.merge(&other.violations)?;
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.speed.eq(&other.speed)
+            && self.pulses.eq(&other.pulses)
+            && self.violations.eq(&other.violations)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.speed.validate()?;
+        self.pulses.validate()?;
+        self.violations.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.speed.state_hash()
+            ^ self.pulses.state_hash().rotate_left(8)
+            ^ self.violations.state_hash().rotate_left(16)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.speed.can_merge(&other.speed)
+            && self.pulses.can_merge(&other.pulses)
+            && self.violations.can_merge(&other.violations)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -630,6 +1032,49 @@ mod tests {
         assert!(fused < 25.0);
     }
 
+    #[test]
+    fn test_reliability_weight_overrides() {
+        let mut engine_ecu = SensorFusion::<f32, DefaultConfig>::new(1);
+        let mut body_ecu = SensorFusion::<f32, DefaultConfig>::new(2);
+        for (fusion, ts) in [(&mut engine_ecu, 1000), (&mut body_ecu, 1000)] {
+            for (node, value) in [(1, 20.0), (2, 40.0)] {
+                let reading = SensorReading::new(
+                    value,
+                    ts,
+                    node,
+                    ReliabilityLevel::Medium,
+                    SafetyLevel::automotive(ASILLevel::AsilB),
+                );
+                fusion.add_reading(reading).unwrap();
+            }
+        }
+        let close_to = |fused: Option<f32>, expected: f32| (fused.unwrap() - expected).abs() < 1e-3;
+        assert_eq!(engine_ecu.reliability_weight(2), 255);
+        assert!(close_to(engine_ecu.fused_value(), 30.0));
+
+        // Node 2 sits next to the engine: trust it a third as much
+        engine_ecu.set_reliability_weight(2, 85, 2000).unwrap();
+        assert!(close_to(engine_ecu.fused_value(), 25.0));
+
+        engine_ecu.disable_sensor(1, 2100).unwrap();
+        assert!(close_to(engine_ecu.fused_value(), 40.0));
+        engine_ecu.disable_sensor(2, 2200).unwrap();
+        assert_eq!(engine_ecu.fused_value(), None);
+        engine_ecu.enable_sensor(1, 2300).unwrap();
+        engine_ecu.enable_sensor(2, 2300).unwrap();
+        assert!(close_to(engine_ecu.fused_value(), 30.0));
+
+        // Overrides propagate through merge; the latest one wins
+        body_ecu.disable_sensor(2, 3000).unwrap();
+        engine_ecu.merge(&body_ecu).unwrap();
+        body_ecu.merge(&engine_ecu).unwrap();
+        assert!(engine_ecu.eq(&body_ecu));
+        assert_eq!(engine_ecu.reliability_weight(1), 255);
+        assert_eq!(body_ecu.reliability_weight(2), 0);
+        assert!(close_to(body_ecu.fused_value(), 20.0));
+        assert!(body_ecu.validate().is_ok());
+    }
+
     #[test]
     fn test_safety_critical_filtering() {
         let mut fusion = SensorFusion::<f32, DefaultConfig>::new(1);
@@ -700,6 +1145,109 @@ mod tests {
         assert!(outliers.contains(&Some(3))); // Node 3 should be detected as outlier
     }
 
+    fn reading(value: f32, node_id: NodeId, reliability: ReliabilityLevel) -> SensorReading<f32> {
+        SensorReading::new(
+            value,
+            1000,
+            node_id,
+            reliability,
+            SafetyLevel::automotive(ASILLevel::AsilB),
+        )
+    }
+
+    #[test]
+    fn test_fused_median_resists_outlier() {
+        let mut fusion = SensorFusion::<f32, DefaultConfig>::new(1);
+        assert_eq!(fusion.fused_median(), None);
+
+        fusion
+            .add_reading(reading(90.0, 1, ReliabilityLevel::High))
+            .unwrap();
+        fusion
+            .add_reading(reading(91.0, 2, ReliabilityLevel::High))
+            .unwrap();
+        fusion
+            .add_reading(reading(89.0, 3, ReliabilityLevel::Medium))
+            .unwrap();
+        fusion
+            .add_reading(reading(200.0, 4, ReliabilityLevel::Low))
+            .unwrap();
+
+        // The faulty sensor pulls the mean up but not the median
+        assert!(fusion.fused_value().unwrap() > 95.0);
+        assert_eq!(fusion.fused_median(), Some(90.0));
+    }
+
+    #[test]
+    fn test_fused_median_weighting() {
+        let mut fusion = SensorFusion::<f32, DefaultConfig>::new(1);
+        fusion
+            .add_reading(reading(10.0, 1, ReliabilityLevel::Low))
+            .unwrap();
+        fusion
+            .add_reading(reading(20.0, 2, ReliabilityLevel::Low))
+            .unwrap();
+        fusion
+            .add_reading(reading(30.0, 3, ReliabilityLevel::UltraHigh))
+            .unwrap();
+
+        // The ultra-high reliability sensor carries most of the weight
+        assert_eq!(fusion.fused_median(), Some(30.0));
+    }
+
+    #[test]
+    fn test_fused_trimmed_mean() {
+        let mut fusion = SensorFusion::<f32, DefaultConfig>::new(1);
+        assert_eq!(fusion.fused_trimmed_mean(0.1), None);
+
+        for (node_id, value) in [(1, 10.0), (2, 20.0), (3, 30.0), (4, 40.0), (5, 1000.0)] {
+            fusion
+                .add_reading(reading(value, node_id, ReliabilityLevel::Medium))
+                .unwrap();
+        }
+
+        // No trimming is the plain weighted mean
+        let mean = fusion.fused_value().unwrap();
+        assert!((fusion.fused_trimmed_mean(0.0).unwrap() - mean).abs() < 1e-3);
+
+        // Trimming one reading's worth of weight from each tail drops 10 and 1000
+        assert!((fusion.fused_trimmed_mean(0.2).unwrap() - 30.0).abs() < 1e-3);
+
+        // Trimming everything falls back to the median, out-of-range fractions are clamped
+        assert_eq!(fusion.fused_trimmed_mean(0.5), fusion.fused_median());
+        assert_eq!(fusion.fused_trimmed_mean(2.0), fusion.fused_median());
+        assert!((fusion.fused_trimmed_mean(-1.0).unwrap() - mean).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_outlier_detected() {
+        let mut fusion = SensorFusion::<f32, DefaultConfig>::new(1);
+        for (node_id, value) in [(1, 90.0), (2, 90.5), (3, 89.5), (4, 90.0), (5, 90.2)] {
+            fusion
+                .add_reading(reading(value, node_id, ReliabilityLevel::High))
+                .unwrap();
+        }
+        assert!(!fusion.outlier_detected(2.0));
+
+        fusion
+            .add_reading(reading(200.0, 6, ReliabilityLevel::High))
+            .unwrap();
+        assert!(fusion.outlier_detected(2.0));
+    }
+
+    #[test]
+    fn test_robust_fusion_single_reading() {
+        let mut fusion = SensorFusion::<f32, DefaultConfig>::new(1);
+        fusion
+            .add_reading(reading(42.0, 1, ReliabilityLevel::Low))
+            .unwrap();
+
+        assert_eq!(fusion.fused_median(), Some(42.0));
+        assert_eq!(fusion.fused_trimmed_mean(0.25), Some(42.0));
+        assert_eq!(fusion.fused_trimmed_mean(0.5), Some(42.0));
+        assert!(!fusion.outlier_detected(0.0));
+    }
+
     #[test]
     fn test_sensor_fusion_merge() {
         let mut fusion1 = SensorFusion::<f32, DefaultConfig>::new(1);
@@ -727,6 +1275,34 @@ mod tests {
 
         fusion1.merge(&fusion2).unwrap();
         assert_eq!(fusion1.reading_count(), 2);
+
+        // The hash follows reading values, not where they are stored
+        let copy = fusion1.clone();
+        assert_eq!(fusion1.state_hash(), copy.state_hash());
+        fusion2.merge(&fusion1).unwrap();
+        assert_eq!(fusion1.state_hash(), fusion2.state_hash());
+
+        // Same reading metadata with another value
+        let mut other = SensorFusion::<f32, DefaultConfig>::new(1);
+        other
+            .add_reading(SensorReading::new(
+                21.0,
+                1000,
+                1,
+                ReliabilityLevel::High,
+                SafetyLevel::automotive(ASILLevel::AsilC),
+            ))
+            .unwrap();
+        let mut same = SensorFusion::<f32, DefaultConfig>::new(1);
+        same.add_reading(SensorReading::new(
+            20.0,
+            1000,
+            1,
+            ReliabilityLevel::High,
+            SafetyLevel::automotive(ASILLevel::AsilC),
+        ))
+        .unwrap();
+        assert_ne!(other.state_hash(), same.state_hash());
     }
 
     #[test]
@@ -758,4 +1334,86 @@ mod tests {
         assert!(fusion1.merge_bounded(&fusion2).is_ok());
         assert!(fusion1.validate_bounded().is_ok());
     }
+
+    /// Records pulses every `interval_ms` from `start` until `end`
+    fn drive<C: MemoryConfig>(
+        odometer: &mut VehicleSpeedOdometer<C>,
+        start: u64,
+        end: u64,
+        interval_ms: u64,
+    ) {
+        let mut t = start;
+        while t <= end {
+            odometer.record_odometer_pulse(t).unwrap();
+            t += interval_ms;
+        }
+    }
+
+    #[test]
+    fn test_vehicle_speed_plausible() {
+        let mut odometer = VehicleSpeedOdometer::<DefaultConfig>::new(1, 1000);
+        assert!(odometer.is_plausible());
+
+        // 100 km/h at 1000 pulses/km is ~27.8 pulses/s, one every 36 ms
+        odometer
+            .update_speed(100.0, ReliabilityLevel::High, 0)
+            .unwrap();
+        drive(&mut odometer, 0, 2016, 36);
+
+        let rate = odometer.pulse_rate().unwrap();
+        assert!((rate - 27.78).abs() < 0.1);
+        assert!(odometer.is_plausible());
+        assert_eq!(odometer.plausibility_violation_count(), 0);
+        assert_eq!(odometer.odometer_pulses(), 57);
+        assert!((odometer.distance_km() - 0.057).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_vehicle_speed_violation() {
+        let mut odometer = VehicleSpeedOdometer::<DefaultConfig>::new(1, 1000);
+
+        // Speed sensor reports half the speed the wheels turn at
+        odometer
+            .update_speed(50.0, ReliabilityLevel::High, 0)
+            .unwrap();
+        drive(&mut odometer, 0, 2016, 36);
+        assert!(!odometer.is_plausible());
+        assert_eq!(odometer.plausibility_violation_count(), 2);
+
+        // Within 10% is still plausible
+        odometer
+            .update_speed(95.0, ReliabilityLevel::High, 3000)
+            .unwrap();
+        drive(&mut odometer, 2052, 3060, 36);
+        assert!(odometer.is_plausible());
+        assert_eq!(odometer.plausibility_violation_count(), 2);
+    }
+
+    #[test]
+    fn test_vehicle_speed_merge() {
+        let mut abs = VehicleSpeedOdometer::<DefaultConfig>::new(1, 1000);
+        let mut tcs = VehicleSpeedOdometer::<DefaultConfig>::new(2, 1000);
+
+        abs.update_speed(100.0, ReliabilityLevel::High, 1000)
+            .unwrap();
+        tcs.update_speed(104.0, ReliabilityLevel::Medium, 1000)
+            .unwrap();
+        tcs.update_speed(50.0, ReliabilityLevel::Medium, 0).unwrap(); // Stale
+        drive(&mut abs, 0, 1008, 36);
+        drive(&mut tcs, 0, 1008, 72);
+        assert_eq!(tcs.plausibility_violation_count(), 1);
+
+        abs.merge(&tcs).unwrap();
+        tcs.merge(&abs).unwrap();
+        assert!(abs.eq(&tcs));
+
+        assert_eq!(abs.speed_at_node(1), Some(100.0));
+        assert_eq!(abs.speed_at_node(2), Some(104.0));
+        assert_eq!(abs.speed_at_node(3), None);
+        assert_eq!(abs.odometer_pulses(), 29 + 15);
+        assert_eq!(abs.plausibility_violation_count(), 1);
+
+        // Each ECU keeps its own pulse rate
+        assert_ne!(abs.pulse_rate(), tcs.pulse_rate());
+    }
 }
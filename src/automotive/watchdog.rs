@@ -0,0 +1,330 @@
+//! Heartbeat Watchdog CRDT for Automotive Applications
+//!
+//! This module implements distributed liveness monitoring: every ECU
+//! records the heartbeats it hears, and merging spreads the latest
+//! heartbeat of each node across the network, so any ECU can decide
+//! whether a peer has missed its deadline and a safety action is due.
+
+use crate::error::{CRDTError, CRDTResult};
+use crate::maps::LWWMap;
+use crate::memory::{MemoryConfig, NodeId};
+use crate::sets::GSet;
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Heartbeat monitor CRDT
+///
+/// Keeps the last heartbeat timestamp of each node in an [`LWWMap`]; merge
+/// takes the latest heartbeat per node. The set of required nodes, the
+/// ECUs without which the function is degraded, grows by merge as well.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `MAX_NODES`: The maximum number of monitored nodes (defaults to 4)
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::automotive::HeartbeatMonitor;
+///
+/// let mut gateway = HeartbeatMonitor::<DefaultConfig>::new(0);
+/// gateway.require_node(1)?;
+/// gateway.require_node(2)?;
+///
+/// let mut brake_ecu = HeartbeatMonitor::<DefaultConfig>::new(1);
+/// brake_ecu.record_heartbeat(1, 1000)?;
+/// gateway.merge(&brake_ecu)?;
+/// gateway.record_heartbeat(2, 900)?;
+///
+/// // 50ms deadline: node 2 has gone quiet
+/// assert!(gateway.is_alive(1, 1040, 50));
+/// assert!(!gateway.all_alive(1040, 50));
+/// assert_eq!(gateway.min_alive_fraction(1040, 50), 0.5);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct HeartbeatMonitor<C: MemoryConfig, const MAX_NODES: usize = 4> {
+    /// Latest heartbeat timestamp per node
+    last_seen: LWWMap<NodeId, u64, C, MAX_NODES>,
+    /// Nodes that must be alive for `all_alive`
+    required_nodes: GSet<NodeId, C, MAX_NODES>,
+}
+
+impl<C: MemoryConfig, const MAX_NODES: usize> HeartbeatMonitor<C, MAX_NODES> {
+    /// Creates a new heartbeat monitor
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node
+    ///
+    /// # Returns
+    /// A new monitor with no heartbeats and no required nodes
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            last_seen: LWWMap::with_capacity(node_id),
+            required_nodes: GSet::with_capacity(),
+        }
+    }
+
+    /// Records a heartbeat from a node
+    ///
+    /// Heartbeats older than the one already recorded are ignored.
+    ///
+    /// # Arguments
+    /// * `node_id` - The node that sent the heartbeat
+    /// * `timestamp` - When the heartbeat was sent
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::BufferOverflow` if MAX_NODES
+    /// other nodes are already monitored
+    pub fn record_heartbeat(&mut self, node_id: NodeId, timestamp: u64) -> CRDTResult<()> {
+        if self
+            .last_seen(node_id)
+            .is_some_and(|seen| seen >= timestamp)
+        {
+            return Ok(());
+        }
+        self.last_seen.insert(node_id, timestamp, timestamp)?;
+        Ok(())
+    }
+
+    /// Marks a node as required for [`all_alive`](Self::all_alive)
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::BufferOverflow` if MAX_NODES
+    /// nodes are already required
+    pub fn require_node(&mut self, node_id: NodeId) -> CRDTResult<()> {
+        self.required_nodes.insert(node_id)?;
+        Ok(())
+    }
+
+    /// Returns the set of required nodes
+    pub fn required_nodes(&self) -> &GSet<NodeId, C, MAX_NODES> {
+        &self.required_nodes
+    }
+
+    /// Gets the latest heartbeat timestamp of a node
+    ///
+    /// # Returns
+    /// The timestamp, or None if the node was never heard from
+    pub fn last_seen(&self, node_id: NodeId) -> Option<u64> {
+        self.last_seen.get(&node_id).copied()
+    }
+
+    /// Checks if a node has sent a heartbeat within the deadline
+    ///
+    /// # Arguments
+    /// * `node_id` - The node to check
+    /// * `current_time` - The current time
+    /// * `deadline_ms` - Maximum time since the last heartbeat
+    ///
+    /// # Returns
+    /// true if the node's last heartbeat is at most `deadline_ms` old
+    pub fn is_alive(&self, node_id: NodeId, current_time: u64, deadline_ms: u64) -> bool {
+        self.last_seen(node_id)
+            .is_some_and(|seen| current_time.saturating_sub(seen) <= deadline_ms)
+    }
+
+    /// Returns the nodes that have missed the deadline
+    ///
+    /// Includes required nodes that were never heard from, followed by the
+    /// other monitored nodes whose heartbeat is too old.
+    pub fn dead_nodes(&self, current_time: u64, deadline_ms: u64) -> impl Iterator<Item = NodeId> {
+        let required = self.required_nodes.iter().copied();
+        let others = self
+            .last_seen
+            .keys()
+            .copied()
+            .filter(|node| !self.required_nodes.contains(node));
+        required
+            .chain(others)
+            .filter(move |&node| !self.is_alive(node, current_time, deadline_ms))
+    }
+
+    /// Checks if every required node is alive
+    ///
+    /// # Returns
+    /// true if all required nodes met the deadline (or none are required)
+    pub fn all_alive(&self, current_time: u64, deadline_ms: u64) -> bool {
+        self.required_nodes
+            .iter()
+            .all(|&node| self.is_alive(node, current_time, deadline_ms))
+    }
+
+    /// Returns the fraction of required nodes that are alive
+    ///
+    /// Lets the application degrade gracefully, e.g. limp-home mode when
+    /// fewer than half of the required ECUs respond.
+    ///
+    /// # Returns
+    /// A value between 0.0 and 1.0, or 1.0 if no nodes are required
+    pub fn min_alive_fraction(&self, current_time: u64, deadline_ms: u64) -> f32 {
+        let required = self.required_nodes.len();
+        if required == 0 {
+            return 1.0;
+        }
+
+        let alive = self
+            .required_nodes
+            .iter()
+            .filter(|&&node| self.is_alive(node, current_time, deadline_ms))
+            .count();
+        alive as f32 / required as f32
+    }
+
+    /// Gets this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.last_seen.node_id()
+    }
+}
+
+impl<C: MemoryConfig, const MAX_NODES: usize> CRDT<C> for HeartbeatMonitor<C, MAX_NODES> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.last_seen.merge(&other.last_seen)?;
+        self.required_nodes.merge(&other.required_nodes)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.last_seen.eq(&other.last_seen) && self.required_nodes.eq(&other.required_nodes)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.last_seen.validate()?;
+        self.required_nodes.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        // Order-independent hash over the heartbeats and required nodes
+        let mut hash = 0u32;
+        for (&node, &seen) in self.last_seen.iter() {
+            hash ^= (seen as u32).rotate_left(node as u32 % 32) ^ ((node as u32) << 24);
+        }
+        for &node in self.required_nodes.iter() {
+            hash ^= 1 << (node % 32);
+        }
+        hash
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.last_seen.can_merge(&other.last_seen)
+            && self.required_nodes.can_merge(&other.required_nodes)
+    }
+}
+
+impl<C: MemoryConfig, const MAX_NODES: usize> BoundedCRDT<C> for HeartbeatMonitor<C, MAX_NODES> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = MAX_NODES;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.last_seen.len()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Heartbeats are never deleted, so there is nothing to compact
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.element_count() < Self::MAX_ELEMENTS
+    }
+}
+
+impl<C: MemoryConfig, const MAX_NODES: usize> RealTimeCRDT<C> for HeartbeatMonitor<C, MAX_NODES> {
+    const MAX_MERGE_CYCLES: u32 =
+        <LWWMap<NodeId, u64, C, MAX_NODES> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES
+            + <GSet<NodeId, C, MAX_NODES> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 =
+        <LWWMap<NodeId, u64, C, MAX_NODES> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES
+            + <GSet<NodeId, C, MAX_NODES> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 =
+        <LWWMap<NodeId, u64, C, MAX_NODES> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES
+            + <GSet<NodeId, C, MAX_NODES> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For this simple implementation, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For this simple implementation, we don't track budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_heartbeats_and_deadlines() {
+        let mut monitor = HeartbeatMonitor::<DefaultConfig>::new(0);
+        monitor.record_heartbeat(1, 1000).unwrap();
+        monitor.record_heartbeat(1, 900).unwrap();
+        monitor.record_heartbeat(2, 950).unwrap();
+        monitor.require_node(1).unwrap();
+        monitor.require_node(3).unwrap();
+
+        assert_eq!(monitor.last_seen(1), Some(1000));
+        assert!(monitor.is_alive(1, 1100, 100));
+        assert!(!monitor.is_alive(1, 1101, 100));
+        assert!(!monitor.is_alive(3, 1000, 100));
+
+        // Node 3 was never heard from; node 2 is monitored but late
+        let mut dead = monitor.dead_nodes(1060, 100);
+        assert_eq!(dead.next(), Some(3));
+        assert_eq!(dead.next(), Some(2));
+        assert_eq!(dead.next(), None);
+        drop(dead);
+
+        assert!(!monitor.all_alive(1060, 100));
+        assert_eq!(monitor.min_alive_fraction(1060, 100), 0.5);
+        monitor.record_heartbeat(3, 1050).unwrap();
+        assert!(monitor.all_alive(1060, 100));
+        assert_eq!(monitor.min_alive_fraction(1060, 100), 1.0);
+
+        // The fifth node does not fit
+        monitor.record_heartbeat(4, 1000).unwrap();
+        assert_eq!(
+            monitor.record_heartbeat(5, 1000),
+            Err(CRDTError::BufferOverflow)
+        );
+    }
+
+    #[test]
+    fn test_merge_takes_latest_heartbeat() {
+        let mut a = HeartbeatMonitor::<DefaultConfig>::new(1);
+        let mut b = HeartbeatMonitor::<DefaultConfig>::new(2);
+        a.record_heartbeat(3, 1000).unwrap();
+        a.require_node(3).unwrap();
+        b.record_heartbeat(3, 2000).unwrap();
+        b.record_heartbeat(2, 1500).unwrap();
+        b.require_node(2).unwrap();
+
+        let mut ab = a.clone();
+        ab.merge(&b).unwrap();
+        let mut ba = b.clone();
+        ba.merge(&a).unwrap();
+
+        assert_eq!(ab.last_seen(3), Some(2000));
+        assert_eq!(ab.required_nodes().len(), 2);
+        assert!(ab.eq(&ba));
+        assert_eq!(ab.state_hash(), ba.state_hash());
+        assert!(ab.validate().is_ok());
+    }
+}
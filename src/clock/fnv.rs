@@ -0,0 +1,365 @@
+//! FNV-1a hashing for CRDT state
+//!
+//! `state_hash()` is used to compare replicas across nodes, so it has to
+//! depend only on the replicated data, never on where that data lives in
+//! memory. FNV-1a is small, needs no tables and can run in `const` context,
+//! which makes it a good fit for microcontrollers.
+//!
+//! Integers are hashed in little-endian byte order. Element values are
+//! hashed through [`StateHash`], which writes their fields rather than their
+//! in-memory bytes, so padding, pointers and endianness never reach the hash.
+
+/// FNV-1a 32-bit offset basis
+const OFFSET_BASIS: u32 = 0x811c_9dc5;
+
+/// FNV-1a 32-bit prime
+const PRIME: u32 = 0x0100_0193;
+
+/// 32-bit FNV-1a hasher
+///
+/// # Memory Usage
+/// - Fixed size: 4 bytes
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::clock::FnvHasher;
+///
+/// let mut hasher = FnvHasher::new();
+/// hasher.write_bytes(b"foobar");
+/// assert_eq!(hasher.finish(), 0xbf9c_f968);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FnvHasher {
+    /// Current hash state
+    state: u32,
+}
+
+impl FnvHasher {
+    /// Creates a hasher at the FNV-1a offset basis
+    pub const fn new() -> Self {
+        Self {
+            state: OFFSET_BASIS,
+        }
+    }
+
+    /// Feeds bytes into the hash
+    pub const fn write_bytes(&mut self, bytes: &[u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.state ^= bytes[i] as u32;
+            self.state = self.state.wrapping_mul(PRIME);
+            i += 1;
+        }
+    }
+
+    /// Feeds a `u32` into the hash in little-endian byte order
+    pub const fn write_u32(&mut self, v: u32) {
+        self.write_bytes(&v.to_le_bytes());
+    }
+
+    /// Feeds a `u64` into the hash in little-endian byte order
+    pub const fn write_u64(&mut self, v: u64) {
+        self.write_bytes(&v.to_le_bytes());
+    }
+
+    /// Feeds a value into the hash through its [`StateHash`] impl
+    pub fn write_value<T: StateHash + ?Sized>(&mut self, value: &T) {
+        value.hash_into(self);
+    }
+
+    /// Returns the hash of everything written so far
+    pub const fn finish(&self) -> u32 {
+        self.state
+    }
+}
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lets `core::hash::Hash` types feed the hasher
+///
+/// Integers are written in little-endian byte order, and `usize`/`isize` as
+/// 64-bit values, so a `Hash` impl that only writes integers hashes equally
+/// on every node.
+impl core::hash::Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.write_bytes(bytes);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        FnvHasher::write_u32(self, i);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        FnvHasher::write_u64(self, i);
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        FnvHasher::write_u64(self, i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_bytes(&(i as i64).to_le_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        FnvHasher::finish(self) as u64
+    }
+}
+
+/// Values that can be hashed into `state_hash()`
+///
+/// Equal values must write the same bytes on every node, whatever their
+/// memory layout, so implementations write fields and integers in
+/// little-endian order, never raw memory. Implemented for integers, `bool`,
+/// `char`, floats (by bit pattern), `str`, slices, arrays, tuples and
+/// `Option`, and with the `alloc` feature for `String` and `Vec`.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::clock::{FnvHasher, StateHash};
+///
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// enum Gear {
+///     Park,
+///     Drive,
+/// }
+///
+/// impl StateHash for Gear {
+///     fn hash_into(&self, hasher: &mut FnvHasher) {
+///         hasher.write_bytes(&[*self as u8]);
+///     }
+/// }
+/// ```
+pub trait StateHash {
+    /// Feeds the value into `hasher`
+    fn hash_into(&self, hasher: &mut FnvHasher);
+}
+
+/// Implements `StateHash` for integers through their little-endian bytes
+macro_rules! impl_state_hash_le {
+    ($($ty:ty),*) => {
+        $(
+            impl StateHash for $ty {
+                fn hash_into(&self, hasher: &mut FnvHasher) {
+                    hasher.write_bytes(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_state_hash_le!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl StateHash for usize {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        hasher.write_u64(*self as u64);
+    }
+}
+
+impl StateHash for isize {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        (*self as i64).hash_into(hasher);
+    }
+}
+
+impl StateHash for bool {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        hasher.write_bytes(&[*self as u8]);
+    }
+}
+
+impl StateHash for char {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        hasher.write_u32(*self as u32);
+    }
+}
+
+impl StateHash for f32 {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        hasher.write_u32(self.to_bits());
+    }
+}
+
+impl StateHash for f64 {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        hasher.write_u64(self.to_bits());
+    }
+}
+
+impl StateHash for () {
+    fn hash_into(&self, _hasher: &mut FnvHasher) {}
+}
+
+impl<T: StateHash + ?Sized> StateHash for &T {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        (**self).hash_into(hasher);
+    }
+}
+
+impl StateHash for str {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.len().hash_into(hasher);
+        hasher.write_bytes(self.as_bytes());
+    }
+}
+
+impl<T: StateHash> StateHash for [T] {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.len().hash_into(hasher);
+        for item in self {
+            item.hash_into(hasher);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StateHash for alloc::string::String {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.as_str().hash_into(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: StateHash> StateHash for alloc::vec::Vec<T> {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.as_slice().hash_into(hasher);
+    }
+}
+
+impl<T: StateHash, const N: usize> StateHash for [T; N] {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.as_slice().hash_into(hasher);
+    }
+}
+
+impl<T: StateHash> StateHash for Option<T> {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        match self {
+            None => hasher.write_bytes(&[0]),
+            Some(value) => {
+                hasher.write_bytes(&[1]);
+                value.hash_into(hasher);
+            }
+        }
+    }
+}
+
+/// Implements `StateHash` for tuples by hashing each field in order
+macro_rules! impl_state_hash_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: StateHash),+> StateHash for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn hash_into(&self, hasher: &mut FnvHasher) {
+                let ($($name,)+) = self;
+                $($name.hash_into(hasher);)+
+            }
+        }
+    };
+}
+
+impl_state_hash_tuple!(A);
+impl_state_hash_tuple!(A, B);
+impl_state_hash_tuple!(A, B, C);
+impl_state_hash_tuple!(A, B, C, D);
+
+/// Hashes a single value with a fresh hasher
+///
+/// Used by set and map types, which XOR the per-element hashes so that
+/// the result does not depend on storage order.
+pub(crate) fn hash_value<T: StateHash + ?Sized>(value: &T) -> u32 {
+    let mut hasher = FnvHasher::new();
+    hasher.write_value(value);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_vectors() {
+        let hash = |bytes: &[u8]| {
+            let mut hasher = FnvHasher::new();
+            hasher.write_bytes(bytes);
+            hasher.finish()
+        };
+        assert_eq!(hash(b""), 0x811c_9dc5);
+        assert_eq!(hash(b"a"), 0xe40c_292c);
+        assert_eq!(hash(b"foobar"), 0xbf9c_f968);
+    }
+
+    #[test]
+    fn test_integers_and_values() {
+        let mut from_u32 = FnvHasher::new();
+        from_u32.write_u32(0x0403_0201);
+        let mut from_bytes = FnvHasher::new();
+        from_bytes.write_bytes(&[1, 2, 3, 4]);
+        assert_eq!(from_u32.finish(), from_bytes.finish());
+
+        let mut from_u64 = FnvHasher::new();
+        from_u64.write_u64(7);
+        let mut from_value = FnvHasher::new();
+        from_value.write_value(&7u64);
+        assert_eq!(from_u64.finish(), from_value.finish());
+
+        // Padding between tuple fields is not hashed
+        let mut from_tuple = FnvHasher::new();
+        from_tuple.write_value(&(1u8, 0x0403_0201u32));
+        let mut from_fields = FnvHasher::new();
+        from_fields.write_bytes(&[1, 1, 2, 3, 4]);
+        assert_eq!(from_tuple.finish(), from_fields.finish());
+
+        // Lengths keep adjacent strings and slices apart
+        assert_ne!(hash_value(&("ab", "c")), hash_value(&("a", "bc")));
+        assert_ne!(
+            hash_value(&(&[1u8, 2][..], &[3u8][..])),
+            hash_value(&(&[1u8][..], &[2u8, 3][..]))
+        );
+
+        // Floats hash by bit pattern
+        assert_eq!(hash_value(&1.5f32), hash_value(&1.5f32.to_bits()));
+        assert_ne!(hash_value(&0.0f64), hash_value(&-0.0f64));
+
+        // Equal values hash equally wherever they are stored
+        let a = [5u16, 9];
+        let b = 9u16;
+        assert_eq!(hash_value(&a[1]), hash_value(&b));
+        assert_ne!(hash_value(&a[0]), hash_value(&b));
+
+        // Usable in const context
+        const HASH: u32 = {
+            let mut hasher = FnvHasher::new();
+            hasher.write_u32(1);
+            hasher.finish()
+        };
+        assert_eq!(HASH, hash_value(&1u32));
+    }
+}
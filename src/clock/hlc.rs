@@ -0,0 +1,350 @@
+//! Hybrid logical clock
+//!
+//! A hybrid logical clock (HLC) follows physical time when it can and falls
+//! back to a logical counter when it cannot: when two events share the same
+//! millisecond, or when the physical clock steps backwards after an NTP
+//! correction. Timestamps stay close to wall time while remaining strictly
+//! monotonic and consistent with causality.
+
+use crate::clock::CompactTimestamp;
+use crate::error::{CRDTError, CRDTResult};
+
+/// Number of bits holding the logical counter
+const LOGICAL_BITS: u32 = 16;
+
+/// Mask selecting the 48-bit wall time
+const WALL_MASK: u64 = (1 << 48) - 1;
+
+/// Hybrid logical clock timestamp
+///
+/// Packs a 48-bit wall time in milliseconds and a 16-bit logical counter
+/// into a single `u64`, wall time in the upper bits. Comparing the packed
+/// values orders timestamps by wall time first and logical counter second,
+/// so an `HLCTimestamp` can be used anywhere a plain `u64` timestamp is
+/// expected.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let ts = HLCTimestamp::new(1000, 3);
+/// assert_eq!(ts.wall_ms(), 1000);
+/// assert_eq!(ts.logical(), 3);
+/// assert_eq!(ts.as_u64(), (1000 << 16) | 3);
+/// assert!(ts < HLCTimestamp::new(1001, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HLCTimestamp(u64);
+
+impl HLCTimestamp {
+    /// Creates a timestamp from wall time and a logical counter
+    ///
+    /// Wall time is truncated to 48 bits, enough for roughly 8900 years of
+    /// milliseconds since the Unix epoch.
+    pub const fn new(wall_ms: u64, logical: u16) -> Self {
+        Self(((wall_ms & WALL_MASK) << LOGICAL_BITS) | logical as u64)
+    }
+
+    /// Creates a timestamp from its packed representation
+    pub const fn from_u64(packed: u64) -> Self {
+        Self(packed)
+    }
+
+    /// Returns the wall time component in milliseconds
+    pub const fn wall_ms(&self) -> u64 {
+        self.0 >> LOGICAL_BITS
+    }
+
+    /// Returns the logical counter component
+    pub const fn logical(&self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Returns the packed timestamp
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<HLCTimestamp> for u64 {
+    fn from(ts: HLCTimestamp) -> Self {
+        ts.0
+    }
+}
+
+impl From<HLCTimestamp> for CompactTimestamp {
+    fn from(ts: HLCTimestamp) -> Self {
+        CompactTimestamp::new(ts.0)
+    }
+}
+
+/// Hybrid logical clock
+///
+/// Each node owns one clock and ticks it for every local event and every
+/// message it sends or receives. The physical time is passed in explicitly,
+/// like every other timestamp in this crate, so the clock works with any
+/// time source.
+///
+/// Remote timestamps that run ahead of the local physical clock by more than
+/// `max_skew_ms` are rejected with `CRDTError::ClockSkew` instead of dragging
+/// the local clock into the future.
+///
+/// # Memory Usage
+/// - Fixed size: 24 bytes
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut node_a = HybridLogicalClock::new(500);
+/// let mut node_b = HybridLogicalClock::new(500);
+///
+/// // Both nodes write in the same millisecond
+/// let ts_a = node_a.send(1000);
+/// let ts_b = node_b.receive(ts_a, 1000)?;
+/// assert!(ts_b > ts_a);
+///
+/// // Node B's physical clock steps backwards after an NTP correction
+/// let ts_b2 = node_b.local_event(900);
+/// assert!(ts_b2 > ts_b);
+///
+/// // A message from far in the future is rejected
+/// let future = HLCTimestamp::new(5000, 0);
+/// assert_eq!(node_b.receive(future, 1000), Err(CRDTError::ClockSkew));
+///
+/// // HLC timestamps can be written straight into registers
+/// let mut register = LWWRegister::<u32, DefaultConfig>::new(1);
+/// register.set(42, node_a.local_event(1001))?;
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HybridLogicalClock {
+    /// Highest wall time observed so far in milliseconds
+    wall_ms: u64,
+
+    /// Logical counter within `wall_ms`
+    logical: u16,
+
+    /// Largest accepted lead of a remote clock in milliseconds
+    max_skew_ms: u64,
+}
+
+impl HybridLogicalClock {
+    /// Creates a new clock at time zero
+    ///
+    /// # Arguments
+    /// * `max_skew_ms` - How far a remote timestamp may run ahead of the
+    ///   local physical clock before `receive` rejects it
+    pub const fn new(max_skew_ms: u64) -> Self {
+        Self {
+            wall_ms: 0,
+            logical: 0,
+            max_skew_ms,
+        }
+    }
+
+    /// Returns the last timestamp issued by this clock
+    pub const fn now(&self) -> HLCTimestamp {
+        HLCTimestamp::new(self.wall_ms, self.logical)
+    }
+
+    /// Returns the configured skew threshold in milliseconds
+    pub const fn max_skew_ms(&self) -> u64 {
+        self.max_skew_ms
+    }
+
+    /// Timestamps a local event
+    ///
+    /// # Arguments
+    /// * `physical_now_ms` - The current reading of the local physical clock
+    ///
+    /// # Returns
+    /// A timestamp strictly greater than every timestamp issued before
+    pub fn local_event(&mut self, physical_now_ms: u64) -> HLCTimestamp {
+        let physical = physical_now_ms & WALL_MASK;
+        if physical > self.wall_ms {
+            self.set(physical, 0);
+        } else {
+            self.set(self.wall_ms, self.logical as u32 + 1);
+        }
+        self.now()
+    }
+
+    /// Timestamps an outgoing message
+    ///
+    /// Sending is a local event; the returned timestamp travels with the
+    /// message and is passed to `receive` on the other side.
+    pub fn send(&mut self, physical_now_ms: u64) -> HLCTimestamp {
+        self.local_event(physical_now_ms)
+    }
+
+    /// Timestamps the receipt of a message
+    ///
+    /// # Arguments
+    /// * `msg_ts` - The timestamp carried by the message
+    /// * `physical_now_ms` - The current reading of the local physical clock
+    ///
+    /// # Returns
+    /// A timestamp strictly greater than both `msg_ts` and every timestamp
+    /// issued before, or `CRDTError::ClockSkew` if `msg_ts` runs ahead of
+    /// `physical_now_ms` by more than the skew threshold. The clock is left
+    /// unchanged on error.
+    pub fn receive(
+        &mut self,
+        msg_ts: HLCTimestamp,
+        physical_now_ms: u64,
+    ) -> CRDTResult<HLCTimestamp> {
+        let physical = physical_now_ms & WALL_MASK;
+        let msg_wall = msg_ts.wall_ms();
+        if msg_wall > physical.saturating_add(self.max_skew_ms) {
+            return Err(CRDTError::ClockSkew);
+        }
+
+        let wall = self.wall_ms.max(msg_wall).max(physical);
+        let logical = if wall == self.wall_ms && wall == msg_wall {
+            self.logical.max(msg_ts.logical()) as u32 + 1
+        } else if wall == self.wall_ms {
+            self.logical as u32 + 1
+        } else if wall == msg_wall {
+            msg_ts.logical() as u32 + 1
+        } else {
+            0
+        };
+
+        self.set(wall, logical);
+        Ok(self.now())
+    }
+
+    /// Stores a new state, carrying logical overflow into the wall time
+    ///
+    /// A counter past `u16::MAX` moves the clock one millisecond ahead, which
+    /// is exactly the next packed value, so monotonicity is preserved.
+    fn set(&mut self, wall_ms: u64, logical: u32) {
+        self.wall_ms = (wall_ms + (logical >> LOGICAL_BITS) as u64) & WALL_MASK;
+        self.logical = logical as u16;
+    }
+}
+
+/// Timestamp types accepted by CRDT write operations
+///
+/// Implemented for plain `u64` milliseconds, `CompactTimestamp`, and
+/// `HLCTimestamp`, so writes accept either raw times or hybrid logical clock
+/// readings. Integer literals still infer to `u64`.
+pub trait IntoTimestamp {
+    /// Converts the value into a raw `u64` timestamp
+    fn into_timestamp(self) -> u64;
+}
+
+impl IntoTimestamp for u64 {
+    fn into_timestamp(self) -> u64 {
+        self
+    }
+}
+
+impl IntoTimestamp for CompactTimestamp {
+    fn into_timestamp(self) -> u64 {
+        self.as_u64()
+    }
+}
+
+impl IntoTimestamp for HLCTimestamp {
+    fn into_timestamp(self) -> u64 {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_packing() {
+        let ts = HLCTimestamp::new(0x1234_5678_9abc, 0xdef0);
+        assert_eq!(ts.wall_ms(), 0x1234_5678_9abc);
+        assert_eq!(ts.logical(), 0xdef0);
+        assert_eq!(ts.as_u64(), 0x1234_5678_9abc_def0);
+        assert_eq!(HLCTimestamp::from_u64(ts.as_u64()), ts);
+
+        // Wall time beyond 48 bits is truncated
+        assert_eq!(HLCTimestamp::new(1 << 48, 1).wall_ms(), 0);
+
+        let compact: CompactTimestamp = ts.into();
+        assert_eq!(compact.as_u64(), ts.as_u64());
+        assert!(HLCTimestamp::new(10, u16::MAX) < HLCTimestamp::new(11, 0));
+    }
+
+    #[test]
+    fn test_local_events_follow_physical_time() {
+        let mut clock = HybridLogicalClock::new(1000);
+
+        assert_eq!(clock.local_event(100), HLCTimestamp::new(100, 0));
+        assert_eq!(clock.local_event(100), HLCTimestamp::new(100, 1));
+        assert_eq!(clock.send(250), HLCTimestamp::new(250, 0));
+
+        // Physical clock stepping backwards keeps the clock monotonic
+        assert_eq!(clock.local_event(200), HLCTimestamp::new(250, 1));
+        assert_eq!(clock.now(), HLCTimestamp::new(250, 1));
+    }
+
+    #[test]
+    fn test_receive() {
+        let mut clock = HybridLogicalClock::new(1000);
+        clock.local_event(100);
+
+        // Remote ahead of both clocks
+        let ts = clock.receive(HLCTimestamp::new(500, 7), 200).unwrap();
+        assert_eq!(ts, HLCTimestamp::new(500, 8));
+
+        // Same wall time on both sides takes the larger counter
+        let ts = clock.receive(HLCTimestamp::new(500, 3), 300).unwrap();
+        assert_eq!(ts, HLCTimestamp::new(500, 9));
+
+        // Local wall time ahead of the remote one
+        let ts = clock.receive(HLCTimestamp::new(400, 50), 300).unwrap();
+        assert_eq!(ts, HLCTimestamp::new(500, 10));
+
+        // Physical time ahead of everything resets the counter
+        let ts = clock.receive(HLCTimestamp::new(600, 5), 700).unwrap();
+        assert_eq!(ts, HLCTimestamp::new(700, 0));
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        let mut clock = HybridLogicalClock::new(100);
+        clock.local_event(1000);
+
+        // Exactly at the threshold is accepted
+        assert!(clock.receive(HLCTimestamp::new(1100, 0), 1000).is_ok());
+
+        let before = clock;
+        assert_eq!(
+            clock.receive(HLCTimestamp::new(1201, 0), 1100),
+            Err(CRDTError::ClockSkew)
+        );
+        assert_eq!(clock, before);
+    }
+
+    #[test]
+    fn test_logical_overflow_carries_into_wall_time() {
+        let mut clock = HybridLogicalClock::new(1000);
+        let ts = clock
+            .receive(HLCTimestamp::new(100, u16::MAX), 100)
+            .unwrap();
+        assert_eq!(ts, HLCTimestamp::new(101, 0));
+
+        let mut clock = HybridLogicalClock::new(1000);
+        clock
+            .receive(HLCTimestamp::new(100, u16::MAX - 1), 0)
+            .unwrap();
+        let last = clock.local_event(0);
+        assert_eq!(last, HLCTimestamp::new(101, 0));
+        assert!(clock.local_event(0) > last);
+    }
+
+    #[test]
+    fn test_into_timestamp() {
+        assert_eq!(5u64.into_timestamp(), 5);
+        assert_eq!(CompactTimestamp::new(7).into_timestamp(), 7);
+        assert_eq!(HLCTimestamp::new(1, 2).into_timestamp(), (1 << 16) | 2);
+    }
+}
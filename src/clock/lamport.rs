@@ -0,0 +1,129 @@
+//! Lamport logical clock
+//!
+//! A Lamport clock orders events by causality alone: every local event
+//! increments the counter, and every received timestamp pushes the counter
+//! past it. No physical clock or clock synchronisation is involved.
+//!
+//! # When to use Lamport timestamps
+//! Prefer Lamport timestamps over wall-clock timestamps when:
+//! - nodes have no NTP, GPS or other shared time source, or their clocks
+//!   drift and step unpredictably
+//! - only the order of writes matters, not when they happened
+//! - a write must always win against every write it has observed, which a
+//!   lagging wall clock cannot guarantee
+//!
+//! Together with the node ID as tie breaker, Lamport timestamps give a total
+//! order of all writes within a session. They carry no notion of real time,
+//! so they cannot drive TTLs or expiry; use wall-clock timestamps or a
+//! [`HybridLogicalClock`](crate::clock::HybridLogicalClock) for that.
+
+/// Lamport logical clock
+///
+/// # Memory Usage
+/// - Fixed size: 8 bytes
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut node_a = LamportClock::new();
+/// let mut node_b = LamportClock::new();
+///
+/// let sent = node_a.tick();
+/// node_b.tick();
+/// node_b.tick();
+///
+/// // Receiving orders the event after both the message and local history
+/// assert_eq!(node_b.update_from(sent), 3);
+/// assert_eq!(node_a.update_from(node_b.current()), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LamportClock {
+    /// Current logical time
+    pub value: u64,
+}
+
+impl LamportClock {
+    /// Creates a new clock at logical time zero
+    pub const fn new() -> Self {
+        Self { value: 0 }
+    }
+
+    /// Timestamps a local event
+    ///
+    /// # Returns
+    /// The incremented logical time
+    pub fn tick(&mut self) -> u64 {
+        self.value = self.value.saturating_add(1);
+        self.value
+    }
+
+    /// Timestamps the receipt of a remote timestamp
+    ///
+    /// # Arguments
+    /// * `received` - The logical time carried by the message
+    ///
+    /// # Returns
+    /// A logical time greater than both `received` and the current time
+    pub fn update_from(&mut self, received: u64) -> u64 {
+        self.value = self.value.max(received).saturating_add(1);
+        self.value
+    }
+
+    /// Returns the current logical time
+    pub const fn current(&self) -> u64 {
+        self.value
+    }
+}
+
+/// Ticks a Lamport clock to timestamp a write
+///
+/// Lets callers drive timestamped CRDTs such as
+/// [`LWWRegister`](crate::registers::LWWRegister) with logical time.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::clock::from_lamport_clock;
+///
+/// let mut clock = LamportClock::new();
+/// let mut register = LWWRegister::<u32, DefaultConfig>::new(1);
+/// register.set(10, from_lamport_clock(&mut clock))?;
+/// register.set(20, from_lamport_clock(&mut clock))?;
+/// assert_eq!(register.get(), Some(&20));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+pub fn from_lamport_clock(clock: &mut LamportClock) -> u64 {
+    clock.tick()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick() {
+        let mut clock = LamportClock::new();
+        assert_eq!(clock.current(), 0);
+        assert_eq!(clock.tick(), 1);
+        assert_eq!(clock.tick(), 2);
+        assert_eq!(from_lamport_clock(&mut clock), 3);
+        assert_eq!(clock.current(), 3);
+    }
+
+    #[test]
+    fn test_update_from() {
+        let mut clock = LamportClock::new();
+        clock.tick();
+
+        // Remote ahead
+        assert_eq!(clock.update_from(10), 11);
+        // Remote behind still advances the clock
+        assert_eq!(clock.update_from(5), 12);
+        assert_eq!(clock.update_from(12), 13);
+
+        // Saturates instead of wrapping
+        assert_eq!(clock.update_from(u64::MAX), u64::MAX);
+        assert_eq!(clock.tick(), u64::MAX);
+    }
+}
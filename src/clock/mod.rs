@@ -2,6 +2,22 @@
 //!
 //! This module provides the CompactTimestamp type used by CRDTs.
 //! All CRDTs use explicit timestamps passed as parameters for deterministic behavior.
+//! A VectorClock is available for tracking causality without synchronised clocks,
+//! a HybridLogicalClock produces timestamps that follow wall time while
+//! staying monotonic across clock jumps, and a LamportClock orders writes
+//! without any physical time source. FnvHasher backs the `state_hash()`
+//! implementations so that equal states hash equally on every node.
+
+pub mod fnv;
+pub mod hlc;
+pub mod lamport;
+pub mod vector;
+
+// Re-export main types
+pub use fnv::{FnvHasher, StateHash};
+pub use hlc::{HLCTimestamp, HybridLogicalClock, IntoTimestamp};
+pub use lamport::{LamportClock, from_lamport_clock};
+pub use vector::VectorClock;
 
 /// Compact timestamp for embedded systems
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -11,6 +27,12 @@ pub struct CompactTimestamp {
 }
 
 impl CompactTimestamp {
+    /// Latest representable timestamp
+    pub const MAX: Self = Self::new(u64::MAX);
+
+    /// Earliest representable timestamp
+    pub const MIN: Self = Self::new(0);
+
     /// Creates a new timestamp
     pub const fn new(value: u64) -> Self {
         Self { value }
@@ -30,4 +52,124 @@ impl CompactTimestamp {
     pub const fn as_u64(&self) -> u64 {
         self.value
     }
+
+    /// Creates a timestamp from milliseconds
+    ///
+    /// Same as [`new`](Self::new); timestamps count milliseconds throughout
+    /// the crate.
+    pub const fn from_millis(ms: u64) -> Self {
+        Self::new(ms)
+    }
+
+    /// Creates a timestamp from microseconds, truncated to milliseconds
+    pub const fn from_micros(us: u64) -> Self {
+        Self::new(us / 1000)
+    }
+
+    /// Returns the timestamp `duration_ms` later, clamped to [`MAX`](Self::MAX)
+    pub const fn saturating_add(self, duration_ms: u64) -> Self {
+        Self::new(self.value.saturating_add(duration_ms))
+    }
+
+    /// Returns the milliseconds from `other` to `self`
+    ///
+    /// # Returns
+    /// None if `other` is later than `self`
+    pub const fn checked_sub(self, other: Self) -> Option<u64> {
+        self.value.checked_sub(other.value)
+    }
+
+    /// Returns the milliseconds elapsed since `earlier`
+    ///
+    /// # Panics
+    /// In debug builds if `earlier` is later than `self`; release builds
+    /// return 0 instead
+    pub fn elapsed_since(self, earlier: Self) -> u64 {
+        debug_assert!(self.value >= earlier.value, "`earlier` is after `self`");
+        self.value.saturating_sub(earlier.value)
+    }
+
+    /// Returns true if `self` is strictly later than `other`
+    pub const fn is_after(self, other: Self) -> bool {
+        self.value > other.value
+    }
+
+    /// Returns true if `self` is strictly earlier than `other`
+    pub const fn is_before(self, other: Self) -> bool {
+        self.value < other.value
+    }
+
+    /// Returns true if the two timestamps are at most `window_ms` apart
+    pub const fn within(self, other: Self, window_ms: u64) -> bool {
+        self.value.abs_diff(other.value) <= window_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic_at_bounds() {
+        let ts = CompactTimestamp::from_millis(1000);
+        assert_eq!(ts.saturating_add(500), CompactTimestamp::new(1500));
+        assert_eq!(
+            CompactTimestamp::MAX.saturating_add(1),
+            CompactTimestamp::MAX
+        );
+        assert_eq!(
+            CompactTimestamp::new(u64::MAX - 1).saturating_add(u64::MAX),
+            CompactTimestamp::MAX
+        );
+
+        assert_eq!(ts.checked_sub(CompactTimestamp::new(400)), Some(600));
+        assert_eq!(ts.checked_sub(ts), Some(0));
+        assert_eq!(ts.checked_sub(CompactTimestamp::new(1001)), None);
+        assert_eq!(
+            CompactTimestamp::MAX.checked_sub(CompactTimestamp::MIN),
+            Some(u64::MAX)
+        );
+
+        assert_eq!(ts.elapsed_since(CompactTimestamp::MIN), 1000);
+        assert_eq!(ts.elapsed_since(ts), 0);
+        assert_eq!(
+            CompactTimestamp::MAX.elapsed_since(CompactTimestamp::MIN),
+            u64::MAX
+        );
+
+        assert_eq!(
+            CompactTimestamp::from_micros(1_999),
+            CompactTimestamp::new(1)
+        );
+        assert_eq!(
+            CompactTimestamp::from_micros(u64::MAX).value(),
+            u64::MAX / 1000
+        );
+        assert_eq!(CompactTimestamp::MIN, CompactTimestamp::zero());
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn test_elapsed_since_later() {
+        let ts = CompactTimestamp::new(10);
+        assert_eq!(ts.elapsed_since(CompactTimestamp::new(11)), 0);
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let a = CompactTimestamp::new(100);
+        let b = CompactTimestamp::new(150);
+        assert!(b.is_after(a));
+        assert!(a.is_before(b));
+        assert!(!a.is_after(a));
+        assert!(!a.is_before(a));
+        assert!(CompactTimestamp::MAX.is_after(CompactTimestamp::MIN));
+
+        assert!(a.within(b, 50));
+        assert!(b.within(a, 50));
+        assert!(!a.within(b, 49));
+        assert!(a.within(a, 0));
+        assert!(CompactTimestamp::MIN.within(CompactTimestamp::MAX, u64::MAX));
+        assert!(!CompactTimestamp::MAX.within(CompactTimestamp::MIN, u64::MAX - 1));
+    }
 }
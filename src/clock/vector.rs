@@ -0,0 +1,285 @@
+//! Vector clock for causality tracking
+//!
+//! A vector clock keeps one logical counter per node and allows CRDTs to
+//! detect causal ordering between events without synchronised wall clocks.
+//! Uses zero allocation with a fixed array for deterministic memory usage.
+
+use crate::clock::{CompactTimestamp, FnvHasher};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+
+/// Vector clock with a fixed number of tracked nodes
+///
+/// Each node owns one monotonically increasing counter indexed by its node ID.
+/// Two clocks can be compared to find out whether one event causally precedes
+/// another or whether they happened concurrently.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `N`: The maximum number of nodes this clock can track (defaults to 16)
+///
+/// # Memory Usage
+/// - Fixed size: 4 * N bytes
+/// - Example: For 8 nodes = 32 bytes, for 16 nodes = 64 bytes
+/// - The type is `Copy`, so small clocks can be passed around by value
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut clock1 = VectorClock::<DefaultConfig>::new();
+/// clock1.increment(1)?;
+///
+/// let mut clock2 = clock1;
+/// clock2.increment(2)?;
+/// assert!(clock1.happens_before(&clock2));
+///
+/// clock1.increment(1)?;
+/// assert!(clock1.concurrent_with(&clock2));
+///
+/// clock1.merge(&clock2);
+/// assert!(clock2.happens_before(&clock1));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug)]
+pub struct VectorClock<C: MemoryConfig, const N: usize = 16> {
+    /// Logical counters for each node (indexed by node ID)
+    counters: [u32; N],
+
+    /// Phantom data to maintain the memory config type
+    _phantom: core::marker::PhantomData<C>,
+}
+
+// Implement Clone and Copy manually so they don't depend on `C: Copy`
+impl<C: MemoryConfig, const N: usize> Clone for VectorClock<C, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: MemoryConfig, const N: usize> Copy for VectorClock<C, N> {}
+
+impl<C: MemoryConfig, const N: usize> PartialEq for VectorClock<C, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counters == other.counters
+    }
+}
+
+impl<C: MemoryConfig, const N: usize> Eq for VectorClock<C, N> {}
+
+impl<C: MemoryConfig, const N: usize> VectorClock<C, N> {
+    /// Creates a new vector clock with all counters at zero
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let clock = VectorClock::<DefaultConfig, 8>::new();
+    /// assert!(clock.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            counters: [0; N],
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Advances the counter of the given node by one
+    ///
+    /// # Arguments
+    /// * `node` - The node that performed an event
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `CRDTError::NodeCountExceeded` if the node ID is
+    /// not tracked by this clock, or `CRDTError::BufferOverflow` if the counter
+    /// is exhausted
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut clock = VectorClock::<DefaultConfig, 4>::new();
+    /// clock.increment(3)?;
+    /// assert_eq!(clock.get(3), 1);
+    /// assert!(clock.increment(4).is_err());
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn increment(&mut self, node: NodeId) -> CRDTResult<()> {
+        let node_index = node as usize;
+        if node_index >= N {
+            return Err(CRDTError::NodeCountExceeded);
+        }
+
+        self.counters[node_index] = self.counters[node_index]
+            .checked_add(1)
+            .ok_or(CRDTError::BufferOverflow)?;
+        Ok(())
+    }
+
+    /// Gets the counter value for a specific node
+    ///
+    /// # Returns
+    /// The counter for that node, or 0 if the node is not tracked
+    pub fn get(&self, node: NodeId) -> u32 {
+        self.counters.get(node as usize).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of nodes this clock can track
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Checks if no events have been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.counters.iter().all(|&c| c == 0)
+    }
+
+    /// Checks if this clock causally precedes another
+    ///
+    /// Returns true if every counter is less than or equal to the other's
+    /// and at least one is strictly less.
+    pub fn happens_before(&self, other: &Self) -> bool {
+        let mut strictly_less = false;
+        for (&ours, &theirs) in self.counters.iter().zip(other.counters.iter()) {
+            if ours > theirs {
+                return false;
+            }
+            if ours < theirs {
+                strictly_less = true;
+            }
+        }
+        strictly_less
+    }
+
+    /// Checks if neither clock causally precedes the other
+    ///
+    /// Equal clocks are not considered concurrent.
+    pub fn concurrent_with(&self, other: &Self) -> bool {
+        self != other && !self.happens_before(other) && !other.happens_before(self)
+    }
+
+    /// Compares two clocks under the causal partial order
+    ///
+    /// # Returns
+    /// `Some(Ordering)` if the clocks are comparable, `None` if concurrent
+    pub fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        if self == other {
+            Some(core::cmp::Ordering::Equal)
+        } else if self.happens_before(other) {
+            Some(core::cmp::Ordering::Less)
+        } else if other.happens_before(self) {
+            Some(core::cmp::Ordering::Greater)
+        } else {
+            None
+        }
+    }
+
+    /// Merges another clock into this one by taking the component-wise maximum
+    pub fn merge(&mut self, other: &Self) {
+        for (ours, &theirs) in self.counters.iter_mut().zip(other.counters.iter()) {
+            *ours = (*ours).max(theirs);
+        }
+    }
+
+    /// Sum of all counters, the total number of events observed
+    pub fn total_events(&self) -> u64 {
+        self.counters.iter().map(|&c| c as u64).sum()
+    }
+}
+
+impl<C: MemoryConfig, const N: usize> Default for VectorClock<C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: MemoryConfig, const N: usize> From<VectorClock<C, N>> for CompactTimestamp {
+    /// Projects the vector clock into a single timestamp
+    ///
+    /// The upper 32 bits hold the total event count, so a clock that happens
+    /// before another always converts to a smaller timestamp. The lower 32
+    /// bits hold an FNV-1a hash of the counters to break ties between
+    /// concurrent clocks deterministically.
+    fn from(clock: VectorClock<C, N>) -> Self {
+        let mut hasher = FnvHasher::new();
+        for &counter in clock.counters.iter() {
+            hasher.write_u32(counter);
+        }
+        let hash = hasher.finish();
+
+        let events = clock.total_events().min(u32::MAX as u64);
+        CompactTimestamp::new((events << 32) | hash as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_new_clock() {
+        let clock = VectorClock::<DefaultConfig>::new();
+        assert!(clock.is_empty());
+        assert_eq!(clock.capacity(), 16);
+        assert_eq!(clock.total_events(), 0);
+    }
+
+    #[test]
+    fn test_increment() {
+        let mut clock = VectorClock::<DefaultConfig, 4>::new();
+        clock.increment(0).unwrap();
+        clock.increment(0).unwrap();
+        clock.increment(3).unwrap();
+
+        assert_eq!(clock.get(0), 2);
+        assert_eq!(clock.get(3), 1);
+        assert_eq!(clock.get(10), 0);
+        assert_eq!(clock.increment(4), Err(CRDTError::NodeCountExceeded));
+    }
+
+    #[test]
+    fn test_causal_ordering() {
+        let mut a = VectorClock::<DefaultConfig, 4>::new();
+        a.increment(1).unwrap();
+
+        let mut b = a;
+        b.increment(2).unwrap();
+
+        assert!(a.happens_before(&b));
+        assert!(!b.happens_before(&a));
+        assert!(!a.happens_before(&a));
+        assert_eq!(a.partial_cmp(&b), Some(core::cmp::Ordering::Less));
+
+        a.increment(1).unwrap();
+        assert!(a.concurrent_with(&b));
+        assert!(b.concurrent_with(&a));
+        assert!(!a.concurrent_with(&a));
+        assert_eq!(a.partial_cmp(&b), None);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = VectorClock::<DefaultConfig, 4>::new();
+        let mut b = VectorClock::<DefaultConfig, 4>::new();
+        a.increment(0).unwrap();
+        a.increment(0).unwrap();
+        b.increment(1).unwrap();
+
+        a.merge(&b);
+        assert_eq!(a.get(0), 2);
+        assert_eq!(a.get(1), 1);
+        assert!(b.happens_before(&a));
+    }
+
+    #[test]
+    fn test_compact_timestamp_conversion() {
+        let mut a = VectorClock::<DefaultConfig, 4>::new();
+        a.increment(0).unwrap();
+        let mut b = a;
+        b.increment(1).unwrap();
+
+        let ts_a: CompactTimestamp = a.into();
+        let ts_b: CompactTimestamp = b.into();
+        assert!(ts_a < ts_b);
+        assert_eq!(ts_a, CompactTimestamp::from(a));
+    }
+}
@@ -2,11 +2,12 @@
 //!
 //! This module provides pre-defined memory configurations for common platforms and use cases.
 
-use crate::memory::define_memory_config;
+use crate::memory::{MemoryConfig, define_memory_config};
 
 // Automotive configurations
 define_memory_config! {
     name: AutomotiveECUConfig,
+    doc: "Automotive ECUs with a 128KB CRDT budget and up to 64 nodes",
     total_memory: 128 * 1024,  // 128KB for automotive ECUs
     max_registers: 500,
     max_counters: 100,
@@ -24,6 +25,7 @@ define_memory_config! {
 
 define_memory_config! {
     name: AutomotiveSensorConfig,
+    doc: "Automotive sensor nodes with a 16KB CRDT budget",
     total_memory: 16 * 1024,  // 16KB for sensor nodes
     max_registers: 50,
     max_counters: 20,
@@ -42,6 +44,7 @@ define_memory_config! {
 // STM32 configurations
 define_memory_config! {
     name: STM32F4Config,
+    doc: "STM32F4 microcontrollers with a 32KB CRDT budget",
     total_memory: 32 * 1024,  // 32KB for STM32F4
     max_registers: 100,
     max_counters: 50,
@@ -59,6 +62,7 @@ define_memory_config! {
 
 define_memory_config! {
     name: STM32F0Config,
+    doc: "STM32F0 microcontrollers with an 8KB CRDT budget",
     total_memory: 8 * 1024,  // 8KB for STM32F0
     max_registers: 25,
     max_counters: 15,
@@ -77,8 +81,9 @@ define_memory_config! {
 // IoT configurations
 define_memory_config! {
     name: IoTSensorConfig,
+    doc: "IoT sensor nodes with a 4KB CRDT budget",
     total_memory: 4 * 1024,  // 4KB for IoT sensors
-    max_registers: 20,
+    max_registers: 19,       // Leaves room for cache-line aligned map counters on AURIX
     max_counters: 10,
     max_sets: 5,
     max_maps: 3,
@@ -94,6 +99,7 @@ define_memory_config! {
 
 define_memory_config! {
     name: IoTGatewayConfig,
+    doc: "IoT gateways aggregating sensors, with a 64KB CRDT budget",
     total_memory: 64 * 1024,  // 64KB for IoT gateways
     max_registers: 200,
     max_counters: 100,
@@ -109,9 +115,47 @@ define_memory_config! {
     cache_line_size: 32,
 }
 
+// nRF52 configurations
+define_memory_config! {
+    name: NRF52SensorConfig,
+    doc: "nRF52 BLE sensors with a 4KB CRDT budget",
+    total_memory: 4 * 1024,  // 4KB for nRF52 BLE sensors
+    max_registers: 19,       // Leaves room for cache-line aligned map counters on AURIX
+    max_counters: 10,
+    max_sets: 5,
+    max_maps: 3,
+    max_nodes: 8,
+    max_set_elements: 8,
+    max_map_entries: 8,
+    max_history_size: 2,
+    clock_memory_budget: 128,
+    error_buffer_size: 64,
+    memory_alignment: 4,
+    cache_line_size: 32,
+}
+
+define_memory_config! {
+    name: NRF52GatewayConfig,
+    doc: "nRF52 BLE gateways with a 16KB CRDT budget",
+    total_memory: 16 * 1024,  // 16KB for nRF52 BLE gateways
+    max_registers: 50,
+    max_counters: 20,
+    max_sets: 10,
+    max_maps: 5,
+    max_nodes: 8,
+    max_set_elements: 16,
+    max_map_entries: 16,
+    max_history_size: 4,
+    clock_memory_budget: 256,
+    error_buffer_size: 128,
+    memory_alignment: 4,
+    cache_line_size: 32,
+}
+
 // Robotics configurations
 define_memory_config! {
     name: RoboticsControllerConfig,
+    doc: "Robotics controllers with a 256KB CRDT budget",
     total_memory: 256 * 1024,  // 256KB for robotics controllers
     max_registers: 1000,
     max_counters: 200,
@@ -129,6 +173,7 @@ define_memory_config! {
 
 define_memory_config! {
     name: RoboticsSensorConfig,
+    doc: "Robotics sensor modules with a 32KB CRDT budget",
     total_memory: 32 * 1024,  // 32KB for robotics sensors
     max_registers: 100,
     max_counters: 50,
@@ -147,6 +192,7 @@ define_memory_config! {
 // Industrial configurations
 define_memory_config! {
     name: IndustrialPLCConfig,
+    doc: "Industrial PLCs with a 512KB CRDT budget",
     total_memory: 512 * 1024,  // 512KB for industrial PLCs
     max_registers: 2000,
     max_counters: 500,
@@ -164,6 +210,7 @@ define_memory_config! {
 
 define_memory_config! {
     name: IndustrialSensorConfig,
+    doc: "Industrial field sensors with a 16KB CRDT budget",
     total_memory: 16 * 1024,  // 16KB for industrial sensors
     max_registers: 50,
     max_counters: 25,
@@ -182,8 +229,9 @@ define_memory_config! {
 // Testing configurations
 define_memory_config! {
     name: TestingMinimalConfig,
+    doc: "Minimal 2KB configuration for testing",
     total_memory: 2 * 1024,  // 2KB minimal config for testing
-    max_registers: 10,
+    max_registers: 2,        // Leaves room for cache-line aligned counters on AURIX
     max_counters: 5,
     max_sets: 3,
     max_maps: 2,
@@ -199,7 +247,8 @@ define_memory_config! {
 
 define_memory_config! {
     name: TestingMaximalConfig,
-    total_memory: 1024 * 1024,  // 1MB maximal config for testing
+    doc: "Maximal 2MB configuration for testing",
+    total_memory: 2 * 1024 * 1024,  // 2MB maximal config for testing
     max_registers: 10000,
     max_counters: 5000,
     max_sets: 1000,
@@ -213,3 +262,147 @@ define_memory_config! {
     memory_alignment: 8,
     cache_line_size: 64,
 }
+
+/// Compile-time summary of a memory configuration
+///
+/// Lets tooling and firmware pick or report a preset at runtime without
+/// naming its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryConfigSummary {
+    /// Name of the configuration type
+    pub name: &'static str,
+    /// Description of the configuration, empty if it has none
+    pub doc: &'static str,
+    /// Total CRDT memory budget in bytes
+    pub total_memory: usize,
+    /// Maximum number of registers
+    pub max_registers: usize,
+    /// Maximum number of sets
+    pub max_sets: usize,
+    /// Maximum number of nodes
+    pub max_nodes: usize,
+}
+
+/// Returns the summary of a memory configuration
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::configs::{IndustrialPLCConfig, summary};
+///
+/// const PLC: crdtosphere::configs::MemoryConfigSummary = summary::<IndustrialPLCConfig>();
+/// assert_eq!(PLC.name, "IndustrialPLCConfig");
+/// assert_eq!(PLC.max_nodes, 64);
+/// ```
+pub const fn summary<C: MemoryConfig>() -> MemoryConfigSummary {
+    MemoryConfigSummary {
+        name: C::NAME,
+        doc: C::DOC,
+        total_memory: C::TOTAL_CRDT_MEMORY,
+        max_registers: C::MAX_REGISTERS,
+        max_sets: C::MAX_SETS,
+        max_nodes: C::MAX_NODES,
+    }
+}
+
+/// Builds [`ALL_CONFIGS`] from a list of configuration types
+macro_rules! config_summaries {
+    ($($config:ty),* $(,)?) => {
+        /// Summaries of every preset in this module, smallest total memory first
+        pub static ALL_CONFIGS: &[MemoryConfigSummary] = &[$(summary::<$config>()),*];
+    };
+}
+
+config_summaries![
+    TestingMinimalConfig,
+    IoTSensorConfig,
+    NRF52SensorConfig,
+    STM32F0Config,
+    AutomotiveSensorConfig,
+    NRF52GatewayConfig,
+    IndustrialSensorConfig,
+    STM32F4Config,
+    RoboticsSensorConfig,
+    IoTGatewayConfig,
+    AutomotiveECUConfig,
+    RoboticsControllerConfig,
+    IndustrialPLCConfig,
+    TestingMaximalConfig,
+];
+
+/// Looks up a preset by its type name
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::configs::find_by_name;
+///
+/// let gateway = find_by_name("IoTGatewayConfig").unwrap();
+/// assert_eq!(gateway.total_memory, 64 * 1024);
+/// assert!(find_by_name("GatewayConfig").is_none());
+/// ```
+pub fn find_by_name(name: &str) -> Option<MemoryConfigSummary> {
+    ALL_CONFIGS
+        .iter()
+        .find(|config| config.name == name)
+        .copied()
+}
+
+/// Returns the smallest preset that meets all of the given minimums
+///
+/// Presets are compared by total memory; among equally sized presets the
+/// first in [`ALL_CONFIGS`] wins.
+///
+/// # Arguments
+/// * `max_registers` - Minimum number of registers required
+/// * `max_nodes` - Minimum number of nodes required
+/// * `max_sets` - Minimum number of sets required
+///
+/// # Returns
+/// The smallest fitting preset, or None if no preset is large enough
+pub fn smallest_fitting(
+    max_registers: usize,
+    max_nodes: usize,
+    max_sets: usize,
+) -> Option<MemoryConfigSummary> {
+    ALL_CONFIGS
+        .iter()
+        .filter(|config| {
+            config.max_registers >= max_registers
+                && config.max_nodes >= max_nodes
+                && config.max_sets >= max_sets
+        })
+        .min_by_key(|config| config.total_memory)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_configs_sorted_and_named() {
+        assert_eq!(ALL_CONFIGS.len(), 14);
+        for pair in ALL_CONFIGS.windows(2) {
+            assert!(pair[0].total_memory <= pair[1].total_memory);
+        }
+        assert_eq!(
+            find_by_name("STM32F0Config"),
+            Some(summary::<STM32F0Config>())
+        );
+        assert_eq!(find_by_name("DefaultConfig"), None);
+        assert!(ALL_CONFIGS.iter().all(|config| !config.doc.is_empty()));
+    }
+
+    #[test]
+    fn test_smallest_fitting() {
+        assert_eq!(
+            smallest_fitting(0, 0, 0).map(|config| config.name),
+            Some("TestingMinimalConfig")
+        );
+        // AutomotiveSensorConfig and IndustrialSensorConfig only have 50 registers
+        assert_eq!(
+            smallest_fitting(100, 32, 20).map(|config| config.name),
+            Some("STM32F4Config")
+        );
+        assert_eq!(smallest_fitting(usize::MAX, 0, 0), None);
+    }
+}
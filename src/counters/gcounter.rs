@@ -7,16 +7,42 @@
 //! - Standard: Requires `&mut self` for modifications, single-threaded
 //! - Atomic: Allows `&self` for modifications, multi-threaded safe
 
-use crate::error::{CRDTError, CRDTResult};
+use crate::clock::FnvHasher;
+use crate::error::{CRDTError, CRDTResult, ErrorContext};
 use crate::memory::{MemoryConfig, NodeId};
-use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+use crate::traits::{BoundedCRDT, CRDT, DeltaCRDT, MemoryBreakdown, RealTimeCRDT, Snapshotable};
 
 #[cfg(feature = "hardware-atomic")]
-use core::sync::atomic::{AtomicU32, Ordering};
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "postcard")]
+use crate::serialization::postcard::seq_max_size;
+#[cfg(feature = "postcard")]
+use postcard::experimental::max_size::MaxSize;
+
+/// How a [`GCounter`] handles a node counter that would pass `u32::MAX`
+///
+/// A wrapped counter looks *smaller* than the value it wrapped from, so the
+/// max-based merge would silently discard it. Pick the mode that matches how
+/// the application wants to find out.
+///
+/// Safety-critical counters on AURIX systems should use [`OverflowMode::Error`]
+/// (the default), so an overflow is reported instead of corrupting the count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OverflowMode {
+    /// Clamp the node counter at `u32::MAX`
+    Saturate,
+    /// Wrap modulo 2^32 and raise the wrap flag (see [`GCounter::has_wrapped`])
+    Wrap,
+    /// Reject the operation with [`CRDTError::Overflow`]
+    #[default]
+    Error,
+}
+
 /// Grow-only Counter with configurable node array
 ///
 /// This counter can only be incremented and provides eventual consistency
@@ -37,6 +63,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// |--------|----------|--------|------------|---------------|-------|
 /// | `increment()` | ✅ | ✅ | `&mut self` / `&self` | Single / Multi | Core operation |
 /// | `inc()` | ✅ | ✅ | `&mut self` / `&self` | Single / Multi | Convenience method |
+/// | `has_wrapped()` | ✅ | ✅ | `&self` | Single / Multi | Wrap detection |
 /// | `merge()` | ✅ | ✅ | `&mut self` | Single / Multi | CRDT merge |
 /// | `value()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
 /// | `node_value()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
@@ -74,12 +101,16 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 #[cfg_attr(feature = "stm32", repr(align(4)))] // STM32 word alignment
 #[cfg_attr(feature = "cortex-m", repr(align(4)))] // ARM word alignment
 #[cfg_attr(feature = "riscv", repr(align(8)))] // RISC-V double-word alignment
+#[cfg_attr(feature = "esp32", repr(align(4)))] // Xtensa word alignment
+#[cfg_attr(feature = "nrf52", repr(align(4)))] // Cortex-M4 word alignment
 #[cfg_attr(
     not(any(
         feature = "aurix",
         feature = "stm32",
         feature = "cortex-m",
-        feature = "riscv"
+        feature = "riscv",
+        feature = "esp32",
+        feature = "nrf52"
     )),
     repr(align(4))
 )] // Default alignment
@@ -95,6 +126,17 @@ pub struct GCounter<C: MemoryConfig, const CAPACITY: usize = 16> {
     /// This node's ID
     node_id: NodeId,
 
+    /// What to do when a node counter would overflow
+    overflow_mode: OverflowMode,
+
+    /// Set once a node counter has wrapped in `OverflowMode::Wrap`
+    #[cfg(not(feature = "hardware-atomic"))]
+    wrapped: bool,
+
+    /// Atomic wrap flag
+    #[cfg(feature = "hardware-atomic")]
+    wrapped: AtomicBool,
+
     /// Phantom data to maintain the memory config type
     _phantom: core::marker::PhantomData<C>,
 }
@@ -107,6 +149,8 @@ impl<C: MemoryConfig, const CAPACITY: usize> Clone for GCounter<C, CAPACITY> {
             Self {
                 counters: self.counters,
                 node_id: self.node_id,
+                overflow_mode: self.overflow_mode,
+                wrapped: self.wrapped,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -122,6 +166,8 @@ impl<C: MemoryConfig, const CAPACITY: usize> Clone for GCounter<C, CAPACITY> {
             Self {
                 counters: new_counters,
                 node_id: self.node_id,
+                overflow_mode: self.overflow_mode,
+                wrapped: AtomicBool::new(self.has_wrapped()),
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -143,12 +189,14 @@ impl<C: MemoryConfig, const CAPACITY: usize> GCounter<C, CAPACITY> {
     /// let counter = GCounter::<DefaultConfig, 32>::with_capacity(1);
     /// assert_eq!(counter.value(), 0);
     /// ```
-    pub fn with_capacity(node_id: NodeId) -> Self {
+    pub const fn with_capacity(node_id: NodeId) -> Self {
         #[cfg(not(feature = "hardware-atomic"))]
         {
             Self {
                 counters: [0; CAPACITY],
                 node_id,
+                overflow_mode: OverflowMode::Error,
+                wrapped: false,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -158,10 +206,35 @@ impl<C: MemoryConfig, const CAPACITY: usize> GCounter<C, CAPACITY> {
             Self {
                 counters: [const { AtomicU32::new(0) }; CAPACITY],
                 node_id,
+                overflow_mode: OverflowMode::Error,
+                wrapped: AtomicBool::new(false),
                 _phantom: core::marker::PhantomData,
             }
         }
     }
+
+    /// Creates a new grow-only counter with an explicit overflow policy
+    ///
+    /// Counters built with `new` or `with_capacity` use [`OverflowMode::Error`].
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < CAPACITY)
+    /// * `mode` - How to handle a node counter passing `u32::MAX`
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = GCounter::<DefaultConfig>::with_overflow_mode(1, OverflowMode::Saturate);
+    /// counter.increment(u32::MAX)?;
+    /// counter.increment(10)?; // clamps instead of failing
+    /// assert_eq!(counter.node_value(1), u32::MAX as u64);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn with_overflow_mode(node_id: NodeId, mode: OverflowMode) -> Self {
+        let mut counter = Self::with_capacity(node_id);
+        counter.overflow_mode = mode;
+        counter
+    }
 }
 
 impl<C: MemoryConfig> GCounter<C, 16> {
@@ -195,7 +268,8 @@ impl<C: MemoryConfig, const CAPACITY: usize> GCounter<C, CAPACITY> {
     /// * `amount` - The amount to increment by (must be > 0)
     ///
     /// # Returns
-    /// Ok(()) if successful, or an error if the increment would cause overflow
+    /// Ok(()) if successful, or `CRDTError::Overflow` if the increment would
+    /// overflow and the counter uses [`OverflowMode::Error`]
     ///
     /// # Examples
     ///
@@ -224,17 +298,32 @@ impl<C: MemoryConfig, const CAPACITY: usize> GCounter<C, CAPACITY> {
     #[cfg(not(feature = "hardware-atomic"))]
     pub fn increment(&mut self, amount: u32) -> CRDTResult<()> {
         if amount == 0 {
-            return Err(CRDTError::InvalidOperation);
+            let ctx = self.increment_context(self.node_value(self.node_id));
+            return Err(CRDTError::InvalidOperation.with_context(ctx));
         }
 
         let node_index = self.node_id as usize;
         if node_index >= CAPACITY {
-            return Err(CRDTError::InvalidNodeId);
+            let ctx =
+                ErrorContext::new("GCounter", "increment", self.node_id, node_index, CAPACITY);
+            return Err(CRDTError::InvalidNodeId.with_context(ctx));
         }
 
-        // Check for overflow
-        if self.counters[node_index] > u32::MAX - amount {
-            return Err(CRDTError::BufferOverflow);
+        // Check for overflow before touching the counter
+        let current = self.counters[node_index];
+        if current > u32::MAX - amount {
+            match self.overflow_mode {
+                OverflowMode::Saturate => self.counters[node_index] = u32::MAX,
+                OverflowMode::Wrap => {
+                    self.counters[node_index] = current.wrapping_add(amount);
+                    self.wrapped = true;
+                }
+                OverflowMode::Error => {
+                    let ctx = self.increment_context(current as u64);
+                    return Err(CRDTError::Overflow.with_context(ctx));
+                }
+            }
+            return Ok(());
         }
 
         self.counters[node_index] += amount;
@@ -254,27 +343,57 @@ impl<C: MemoryConfig, const CAPACITY: usize> GCounter<C, CAPACITY> {
     #[cfg(feature = "hardware-atomic")]
     pub fn increment(&self, amount: u32) -> CRDTResult<()> {
         if amount == 0 {
-            return Err(CRDTError::InvalidOperation);
+            let ctx = self.increment_context(self.node_value(self.node_id));
+            return Err(CRDTError::InvalidOperation.with_context(ctx));
         }
 
         let node_index = self.node_id as usize;
         if node_index >= CAPACITY {
-            return Err(CRDTError::InvalidNodeId);
+            let ctx =
+                ErrorContext::new("GCounter", "increment", self.node_id, node_index, CAPACITY);
+            return Err(CRDTError::InvalidNodeId.with_context(ctx));
         }
 
-        // Use atomic fetch_add with overflow check
-        let old_value = self.counters[node_index].fetch_add(amount, Ordering::Relaxed);
-
-        // Check for overflow (if old_value + amount wrapped around)
-        if old_value > u32::MAX - amount {
-            // Rollback the increment
-            self.counters[node_index].fetch_sub(amount, Ordering::Relaxed);
-            return Err(CRDTError::BufferOverflow);
+        let counter = &self.counters[node_index];
+        match self.overflow_mode {
+            OverflowMode::Saturate => {
+                // The closure always returns Some, so this cannot fail
+                let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |value| {
+                    Some(value.saturating_add(amount))
+                });
+            }
+            OverflowMode::Wrap => {
+                // fetch_add wraps natively; only the flag needs raising
+                let old_value = counter.fetch_add(amount, Ordering::Relaxed);
+                if old_value > u32::MAX - amount {
+                    self.wrapped.store(true, Ordering::Relaxed);
+                }
+            }
+            OverflowMode::Error => {
+                counter
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |value| {
+                        value.checked_add(amount)
+                    })
+                    .map_err(|current| {
+                        CRDTError::Overflow.with_context(self.increment_context(current as u64))
+                    })?;
+            }
         }
 
         Ok(())
     }
 
+    /// Describes this node's slot for an error raised by `increment`
+    fn increment_context(&self, current: u64) -> ErrorContext {
+        ErrorContext::new(
+            "GCounter",
+            "increment",
+            self.node_id,
+            current as usize,
+            u32::MAX as usize,
+        )
+    }
+
     /// Increments this node's counter by 1
     ///
     /// # Returns
@@ -416,6 +535,496 @@ impl<C: MemoryConfig, const CAPACITY: usize> GCounter<C, CAPACITY> {
                 .count()
         }
     }
+
+    /// Gets the count a node has contributed
+    ///
+    /// # Arguments
+    /// * `node_id` - The node ID to look up
+    ///
+    /// # Returns
+    /// The node's counter entry, or 0 if the node has not been seen
+    pub fn value_at(&self, node_id: NodeId) -> u32 {
+        let node_index = node_id as usize;
+        if node_index >= CAPACITY {
+            return 0;
+        }
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.counters[node_index]
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            self.counters[node_index].load(Ordering::Relaxed)
+        }
+    }
+
+    /// Iterates over the nodes that have contributed to the counter
+    ///
+    /// # Returns
+    /// `(node_id, count)` pairs for every node with a non-zero count, in
+    /// node ID order
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut gateway = GCounter::<DefaultConfig>::new(1);
+    /// let mut brake_ecu = GCounter::<DefaultConfig>::new(3);
+    /// gateway.increment(40)?;
+    /// brake_ecu.increment(2)?;
+    /// gateway.merge(&brake_ecu)?;
+    ///
+    /// assert_eq!(gateway.value(), 42);
+    /// assert!(gateway.contributions().eq([(1, 40), (3, 2)]));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn contributions(&self) -> impl Iterator<Item = (NodeId, u32)> + '_ {
+        (0..CAPACITY)
+            .map(|index| (index as NodeId, self.value_at(index as NodeId)))
+            .filter(|&(_, count)| count > 0)
+    }
+
+    /// Gets the number of nodes that have contributed to the counter
+    ///
+    /// Same as [`active_nodes`](Self::active_nodes).
+    pub fn node_count(&self) -> usize {
+        self.active_nodes()
+    }
+
+    /// Finds the node with the highest count
+    ///
+    /// # Returns
+    /// The `(node_id, count)` pair with the largest count, preferring the
+    /// lowest node ID on ties, or None if the counter is empty
+    pub fn dominant_contributor(&self) -> Option<(NodeId, u32)> {
+        self.contributions().fold(
+            None,
+            |best: Option<(NodeId, u32)>, (node, count)| match best {
+                Some((_, best_count)) if best_count >= count => best,
+                _ => Some((node, count)),
+            },
+        )
+    }
+
+    /// Builds a histogram of per-node counts
+    ///
+    /// Bucket `i` holds the number of contributing nodes whose count lies in
+    /// `[i * bucket_size, (i + 1) * bucket_size)`; larger counts land in the
+    /// last bucket. Nodes that have not contributed are left out.
+    ///
+    /// # Arguments
+    /// * `bucket_size` - Width of each bucket (0 is treated as 1)
+    ///
+    /// # Returns
+    /// The number of nodes per bucket
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = GCounter::<DefaultConfig>::new(0);
+    /// for node in 0..4 {
+    ///     let mut ecu = GCounter::<DefaultConfig>::new(node);
+    ///     ecu.increment(if node == 3 { 500 } else { 10 + node as u32 })?;
+    ///     counter.merge(&ecu)?;
+    /// }
+    ///
+    /// // Node 3 stands out
+    /// assert_eq!(counter.histogram::<4>(100), [3, 0, 0, 1]);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn histogram<const BUCKETS: usize>(&self, bucket_size: u32) -> [u32; BUCKETS] {
+        let mut buckets = [0u32; BUCKETS];
+        if BUCKETS == 0 {
+            return buckets;
+        }
+
+        let bucket_size = bucket_size.max(1);
+        for (_, count) in self.contributions() {
+            let bucket = ((count / bucket_size) as usize).min(BUCKETS - 1);
+            buckets[bucket] += 1;
+        }
+        buckets
+    }
+
+    /// Returns the overflow policy of this counter
+    pub fn overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Returns true if any node counter in this state has wrapped
+    ///
+    /// Only counters in [`OverflowMode::Wrap`] can wrap themselves, but the
+    /// flag is also picked up by merging a wrapped peer state.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = GCounter::<DefaultConfig>::with_overflow_mode(1, OverflowMode::Wrap);
+    /// counter.increment(u32::MAX)?;
+    /// counter.increment(2)?;
+    /// assert!(counter.has_wrapped());
+    /// assert_eq!(counter.node_value(1), 1);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn has_wrapped(&self) -> bool {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.wrapped
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            self.wrapped.load(Ordering::Relaxed)
+        }
+    }
+
+    /// Clears the wrap flag once the application has handled the wrap
+    pub fn clear_wrap_flag(&mut self) {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.wrapped = false;
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            *self.wrapped.get_mut() = false;
+        }
+    }
+
+    /// Captures the current state for a later usage reading
+    ///
+    /// # Returns
+    /// A frozen copy of the counter, with no reset recorded yet
+    pub fn checkpoint(&self) -> GCounterCheckpoint<C, CAPACITY> {
+        GCounterCheckpoint {
+            counter: self.clone(),
+            last_reset: None,
+        }
+    }
+
+    /// Returns the usage counted since a checkpoint
+    ///
+    /// # Arguments
+    /// * `checkpoint` - The state at the start of the period
+    ///
+    /// # Returns
+    /// `self.value() - checkpoint.value()`, or 0 if the checkpoint is ahead
+    pub fn period_usage(&self, checkpoint: &GCounterCheckpoint<C, CAPACITY>) -> u64 {
+        self.value().saturating_sub(checkpoint.value())
+    }
+
+    /// Ends a metering period by zeroing this node's count
+    ///
+    /// Other nodes' counts are left alone and raised to at least their
+    /// checkpointed values; each node resets its own count. As with
+    /// `PNCounter::reset_node`, merges keep the per-node maximum, so a
+    /// replica that still holds this node's old count will bring it back.
+    /// Roll over only once peers have been reset too, or keep the
+    /// checkpoints and bill with [`period_usage`](Self::period_usage) instead.
+    ///
+    /// On success the checkpoint is rolled over to the start of the next
+    /// period and remembers `timestamp`, so keep reusing the same checkpoint
+    /// rather than taking a fresh one after each reset.
+    ///
+    /// # Arguments
+    /// * `checkpoint` - The state at the start of the period
+    /// * `timestamp` - When the period ended (must be newer than the
+    ///   checkpoint's [`last_reset`](GCounterCheckpoint::last_reset))
+    ///
+    /// # Returns
+    /// The usage of the period that ended, as returned by
+    /// [`period_usage`](Self::period_usage), or `CRDTError::InvalidOperation`
+    /// for a stale timestamp
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut meter = GCounter::<DefaultConfig>::new(1);
+    /// meter.increment(100)?;
+    /// let mut period = meter.checkpoint();
+    ///
+    /// meter.increment(42)?;
+    /// assert_eq!(meter.period_usage(&period), 42);
+    /// assert_eq!(meter.reset_since_checkpoint(&mut period, 1000)?, 42);
+    /// assert_eq!(meter.value(), 0);
+    /// assert_eq!(period.last_reset(), Some(1000));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn reset_since_checkpoint(
+        &mut self,
+        checkpoint: &mut GCounterCheckpoint<C, CAPACITY>,
+        timestamp: u64,
+    ) -> CRDTResult<u64> {
+        if checkpoint.last_reset.is_some_and(|last| timestamp <= last) {
+            return Err(CRDTError::InvalidOperation);
+        }
+
+        let usage = self.period_usage(checkpoint);
+        let own_index = self.node_id as usize;
+        for i in 0..CAPACITY {
+            let value = if i == own_index {
+                0
+            } else {
+                self.counter_at(i).max(checkpoint.counter.counter_at(i))
+            };
+            self.store_counter(i, value);
+        }
+
+        *checkpoint = GCounterCheckpoint {
+            counter: self.clone(),
+            last_reset: Some(timestamp),
+        };
+        Ok(usage)
+    }
+
+    /// Sets the wrap flag
+    fn mark_wrapped(&mut self) {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.wrapped = true;
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            *self.wrapped.get_mut() = true;
+        }
+    }
+
+    /// Reads the raw counter stored at a node index
+    fn counter_at(&self, index: usize) -> u32 {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.counters[index]
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            self.counters[index].load(Ordering::Relaxed)
+        }
+    }
+
+    /// Overwrites the raw counter stored at a node index
+    fn store_counter(&mut self, index: usize, value: u32) {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.counters[index] = value;
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            self.counters[index].store(value, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Frozen copy of a [`GCounter`] taken at the start of a metering period
+///
+/// Created by [`GCounter::checkpoint`]; it cannot be incremented or merged.
+/// [`GCounter::reset_since_checkpoint`] rolls it over to the next period.
+#[derive(Debug, Clone)]
+pub struct GCounterCheckpoint<C: MemoryConfig, const CAPACITY: usize = 16> {
+    /// Counter state at the start of the period
+    counter: GCounter<C, CAPACITY>,
+    /// Timestamp of the reset that started the period
+    last_reset: Option<u64>,
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> GCounterCheckpoint<C, CAPACITY> {
+    /// Gets the total count at the checkpoint
+    pub fn value(&self) -> u64 {
+        self.counter.value()
+    }
+
+    /// Gets the timestamp of the reset that started this period
+    ///
+    /// # Returns
+    /// The timestamp passed to the last successful
+    /// [`GCounter::reset_since_checkpoint`] with this checkpoint, or None if
+    /// it was never used for a reset
+    pub fn last_reset(&self) -> Option<u64> {
+        self.last_reset
+    }
+}
+
+/// Version byte of the [`GCounter::encode_to_slice`] layout
+const SLICE_FORMAT_VERSION: u8 = 1;
+
+// Compact binary encoding for GCounter
+impl<C: MemoryConfig, const CAPACITY: usize> GCounter<C, CAPACITY> {
+    /// Largest number of bytes [`encode_to_slice`](Self::encode_to_slice) writes
+    pub const ENCODE_MAX_BYTES: usize = 3 + CAPACITY * 4;
+
+    /// Encodes the counter into a byte slice without serde
+    ///
+    /// The layout is `[version, node_id, node_count, counts: [u32; node_count]]`
+    /// with little-endian counts, where trailing zero slots are omitted. It
+    /// fits CAN payloads and needs no serde dependency. The overflow mode is
+    /// local policy and is not encoded.
+    ///
+    /// # Arguments
+    /// * `buf` - The output buffer, at most [`ENCODE_MAX_BYTES`](Self::ENCODE_MAX_BYTES) long
+    ///
+    /// # Returns
+    /// The number of bytes written, `CRDTError::BufferOverflow` if the buffer
+    /// is too small, or `CRDTError::Overflow` if a node counter has wrapped,
+    /// since the layout cannot tell peers to distrust its counts
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = GCounter::<DefaultConfig>::new(1);
+    /// counter.increment(5)?;
+    ///
+    /// let mut frame = [0u8; 16];
+    /// let len = counter.encode_to_slice(&mut frame)?;
+    /// assert_eq!(&frame[..len], &[1, 1, 2, 0, 0, 0, 0, 5, 0, 0, 0]);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> CRDTResult<usize> {
+        if self.has_wrapped() {
+            return Err(CRDTError::Overflow);
+        }
+
+        // Trailing zero slots carry no state
+        let mut used = CAPACITY;
+        while used > 0 && self.counter_at(used - 1) == 0 {
+            used -= 1;
+        }
+        let node_count = u8::try_from(used).map_err(|_| CRDTError::InvalidState)?;
+
+        let len = 3 + used * 4;
+        if buf.len() < len {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        buf[0] = SLICE_FORMAT_VERSION;
+        buf[1] = self.node_id;
+        buf[2] = node_count;
+        for (i, slot) in buf[3..len].chunks_exact_mut(4).enumerate() {
+            slot.copy_from_slice(&self.counter_at(i).to_le_bytes());
+        }
+        Ok(len)
+    }
+
+    /// Decodes a counter written by [`encode_to_slice`](Self::encode_to_slice)
+    ///
+    /// # Arguments
+    /// * `buf` - The encoded bytes
+    ///
+    /// # Returns
+    /// The decoded counter, `CRDTError::BufferOverflow` if the buffer is
+    /// shorter than the layout or the state does not fit CAPACITY, or
+    /// `CRDTError::InvalidState` for an unknown version
+    pub fn decode_from_slice(buf: &[u8]) -> CRDTResult<Self> {
+        let [version, node_id, node_count, ..] = *buf else {
+            return Err(CRDTError::BufferOverflow);
+        };
+        if version != SLICE_FORMAT_VERSION {
+            return Err(CRDTError::InvalidState);
+        }
+
+        let used = node_count as usize;
+        if node_id as usize >= CAPACITY || used > CAPACITY {
+            return Err(CRDTError::BufferOverflow);
+        }
+        if buf.len() < 3 + used * 4 {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        let mut counter = Self::with_capacity(node_id);
+        for (i, slot) in buf[3..3 + used * 4].chunks_exact(4).enumerate() {
+            counter.store_counter(i, u32::from_le_bytes([slot[0], slot[1], slot[2], slot[3]]));
+        }
+        Ok(counter)
+    }
+
+    /// Merges one node's count received from a peer
+    ///
+    /// Same as merging a counter that holds only `count` for `node_id`, but
+    /// without building one: the stored count is raised to `count` if it is
+    /// lower and left alone otherwise.
+    ///
+    /// # Arguments
+    /// * `node_id` - The node the count belongs to
+    /// * `count` - The node's count as the peer knows it
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::InvalidNodeId` if `node_id` is
+    /// not below CAPACITY
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut gateway = GCounter::<DefaultConfig>::new(1);
+    ///
+    /// // Error count reported by the brake ECU in a CAN frame
+    /// gateway.merge_from_raw(3, 12)?;
+    /// gateway.merge_from_raw(3, 10)?; // A stale frame changes nothing
+    /// assert_eq!(gateway.value_at(3), 12);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn merge_from_raw(&mut self, node_id: NodeId, count: u32) -> CRDTResult<()> {
+        let index = node_id as usize;
+        if index >= CAPACITY {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        if count > self.counter_at(index) {
+            self.store_counter(index, count);
+        }
+        Ok(())
+    }
+
+    /// Merges several `(node_id, count)` pairs received from a peer
+    ///
+    /// Applies [`merge_from_raw`](Self::merge_from_raw) to each pair.
+    ///
+    /// # Arguments
+    /// * `entries` - The pairs to merge, e.g. from
+    ///   [`contributions`](Self::contributions) on the sending node
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::InvalidNodeId` if any node is
+    /// not below CAPACITY, in which case nothing is merged
+    pub fn merge_from_slice(&mut self, entries: &[(NodeId, u32)]) -> CRDTResult<()> {
+        if entries
+            .iter()
+            .any(|&(node_id, _)| node_id as usize >= CAPACITY)
+        {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        for &(node_id, count) in entries {
+            self.merge_from_raw(node_id, count)?;
+        }
+        Ok(())
+    }
+
+    /// Overwrites one node's count
+    ///
+    /// Meant for restoring a counter from persisted storage. Unlike
+    /// [`merge_from_raw`](Self::merge_from_raw) this can lower a count,
+    /// which breaks the grow-only guarantee: replicas that already merged
+    /// the higher count keep it, and the replicas stop converging. Only use
+    /// it on a counter that has not been shared yet.
+    ///
+    /// # Arguments
+    /// * `node_id` - The node whose count to set
+    /// * `count` - The count to store
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::InvalidNodeId` if `node_id` is
+    /// not below CAPACITY
+    pub fn set_raw(&mut self, node_id: NodeId, count: u32) -> CRDTResult<()> {
+        let index = node_id as usize;
+        if index >= CAPACITY {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        self.store_counter(index, count);
+        Ok(())
+    }
 }
 
 // Serde implementation for GCounter
@@ -427,7 +1036,7 @@ impl<C: MemoryConfig, const CAPACITY: usize> Serialize for GCounter<C, CAPACITY>
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("GCounter", 2)?;
+        let mut state = serializer.serialize_struct("GCounter", 4)?;
 
         // Serialize the logical state (counter values) as slice to handle any CAPACITY
         #[cfg(not(feature = "hardware-atomic"))]
@@ -445,6 +1054,8 @@ impl<C: MemoryConfig, const CAPACITY: usize> Serialize for GCounter<C, CAPACITY>
             state.serialize_field("counters", &counters[..])?;
         }
         state.serialize_field("node_id", &self.node_id)?;
+        state.serialize_field("overflow_mode", &self.overflow_mode)?;
+        state.serialize_field("wrapped", &self.has_wrapped())?;
         state.end()
     }
 }
@@ -455,14 +1066,17 @@ impl<'de, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de> for GCounter<
     where
         D: Deserializer<'de>,
     {
+        use crate::serialization::fields::SeqFields;
         use core::fmt;
         use serde::de::{self, MapAccess, Visitor};
 
         #[derive(Deserialize)]
-        #[serde(field_identifier, rename_all = "lowercase")]
+        #[serde(field_identifier, rename_all = "snake_case")]
         enum Field {
             Counters,
             NodeId,
+            OverflowMode,
+            Wrapped,
         }
 
         struct GCounterVisitor<C: MemoryConfig, const CAPACITY: usize> {
@@ -476,12 +1090,21 @@ impl<'de, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de> for GCounter<
                 formatter.write_str("struct GCounter")
             }
 
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                self.visit_map(SeqFields::new(seq, FIELDS))
+            }
+
             fn visit_map<V>(self, mut map: V) -> Result<GCounter<C, CAPACITY>, V::Error>
             where
                 V: MapAccess<'de>,
             {
                 let mut counters = None;
                 let mut node_id = None;
+                let mut overflow_mode = None;
+                let mut wrapped = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -550,11 +1173,26 @@ impl<'de, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de> for GCounter<
                             }
                             node_id = Some(map.next_value()?);
                         }
+                        Field::OverflowMode => {
+                            if overflow_mode.is_some() {
+                                return Err(de::Error::duplicate_field("overflow_mode"));
+                            }
+                            overflow_mode = Some(map.next_value()?);
+                        }
+                        Field::Wrapped => {
+                            if wrapped.is_some() {
+                                return Err(de::Error::duplicate_field("wrapped"));
+                            }
+                            wrapped = Some(map.next_value()?);
+                        }
                     }
                 }
 
                 let counters = counters.ok_or_else(|| de::Error::missing_field("counters"))?;
                 let node_id = node_id.ok_or_else(|| de::Error::missing_field("node_id"))?;
+                // Counters serialized before overflow modes existed
+                let overflow_mode = overflow_mode.unwrap_or_default();
+                let wrapped = wrapped.unwrap_or(false);
 
                 // Reconstruct the GCounter
                 #[cfg(not(feature = "hardware-atomic"))]
@@ -562,6 +1200,8 @@ impl<'de, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de> for GCounter<
                     Ok(GCounter {
                         counters,
                         node_id,
+                        overflow_mode,
+                        wrapped,
                         _phantom: core::marker::PhantomData,
                     })
                 }
@@ -576,13 +1216,15 @@ impl<'de, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de> for GCounter<
                     Ok(GCounter {
                         counters: atomic_counters,
                         node_id,
+                        overflow_mode,
+                        wrapped: AtomicBool::new(wrapped),
                         _phantom: core::marker::PhantomData,
                     })
                 }
             }
         }
 
-        const FIELDS: &[&str] = &["counters", "node_id"];
+        const FIELDS: &[&str] = &["counters", "node_id", "overflow_mode", "wrapped"];
         deserializer.deserialize_struct(
             "GCounter",
             FIELDS,
@@ -593,10 +1235,99 @@ impl<'de, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de> for GCounter<
     }
 }
 
+// Postcard size bound for GCounter
+#[cfg(feature = "postcard")]
+impl MaxSize for OverflowMode {
+    // Single-byte variant index
+    const POSTCARD_MAX_SIZE: usize = 1;
+}
+
+#[cfg(feature = "postcard")]
+impl<C: MemoryConfig, const CAPACITY: usize> MaxSize for GCounter<C, CAPACITY> {
+    const POSTCARD_MAX_SIZE: usize = {
+        seq_max_size(CAPACITY, u32::POSTCARD_MAX_SIZE)
+            + NodeId::POSTCARD_MAX_SIZE
+            + OverflowMode::POSTCARD_MAX_SIZE
+            + bool::POSTCARD_MAX_SIZE
+    };
+}
+
+// CBOR implementation for GCounter
+#[cfg(feature = "cbor")]
+impl<Ctx, C: MemoryConfig, const CAPACITY: usize> minicbor::Encode<Ctx> for GCounter<C, CAPACITY> {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut Ctx,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        // Trailing zero slots carry no state
+        let mut used = CAPACITY;
+        while used > 0 && self.counter_at(used - 1) == 0 {
+            used -= 1;
+        }
+
+        let wrapped = self.has_wrapped();
+        e.array(if wrapped { 3 } else { 2 })?;
+        e.u8(self.node_id)?;
+        e.array(used as u64)?;
+        for i in 0..used {
+            e.u32(self.counter_at(i))?;
+        }
+        if wrapped {
+            e.bool(true)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<'b, Ctx, C: MemoryConfig, const CAPACITY: usize> minicbor::Decode<'b, Ctx>
+    for GCounter<C, CAPACITY>
+{
+    fn decode(
+        d: &mut minicbor::Decoder<'b>,
+        _ctx: &mut Ctx,
+    ) -> Result<Self, minicbor::decode::Error> {
+        use crate::serialization::cbor;
+
+        let fields = cbor::array_len(d)?;
+        if fields != 2 && fields != 3 {
+            return Err(cbor::invalid_state());
+        }
+
+        let node_id = d.u8()?;
+        if node_id as usize >= CAPACITY {
+            return Err(cbor::capacity_exceeded());
+        }
+
+        let mut counter = Self::with_capacity(node_id);
+        let used = cbor::bounded_array_len(d, CAPACITY)?;
+        for i in 0..used {
+            let value = d.u32()?;
+            counter.store_counter(i, value);
+        }
+
+        if fields == 3 && d.bool()? {
+            counter.mark_wrapped();
+        }
+
+        Ok(counter)
+    }
+}
+
 impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for GCounter<C, CAPACITY> {
     type Error = CRDTError;
 
     fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        // A wrapped peer carries counters that compare smaller than they are;
+        // refuse them up front rather than losing counts to the max below
+        if other.has_wrapped() {
+            if self.overflow_mode == OverflowMode::Error {
+                return Err(CRDTError::Overflow);
+            }
+            self.mark_wrapped();
+        }
+
         // Take the maximum value for each node
         #[cfg(not(feature = "hardware-atomic"))]
         {
@@ -628,6 +1359,40 @@ impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for GCounter<C, CAPACITY> {
         Ok(())
     }
 
+    fn merge_batch<'a, I>(&mut self, others: I) -> CRDTResult<()>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        // Accumulate the per-node maxima first and write them back once
+        let mut maxima = [0u32; CAPACITY];
+        for (i, max) in maxima.iter_mut().enumerate() {
+            *max = self.counter_at(i);
+        }
+
+        let mut wrapped = false;
+        for other in others {
+            if other.has_wrapped() {
+                if self.overflow_mode == OverflowMode::Error {
+                    return Err(CRDTError::Overflow);
+                }
+                wrapped = true;
+            }
+
+            for (i, max) in maxima.iter_mut().enumerate() {
+                *max = (*max).max(other.counter_at(i));
+            }
+        }
+
+        for (i, &max) in maxima.iter().enumerate() {
+            self.store_counter(i, max);
+        }
+        if wrapped {
+            self.mark_wrapped();
+        }
+        Ok(())
+    }
+
     fn eq(&self, other: &Self) -> bool {
         #[cfg(not(feature = "hardware-atomic"))]
         {
@@ -662,6 +1427,11 @@ impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for GCounter<C, CAPACITY> {
             return Err(CRDTError::InvalidNodeId);
         }
 
+        // A counter that must never wrap has wrapped
+        if self.overflow_mode == OverflowMode::Error && self.has_wrapped() {
+            return Err(CRDTError::InvalidState);
+        }
+
         // Platform-specific validation rules
         #[cfg(feature = "aurix")]
         {
@@ -699,29 +1469,24 @@ impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for GCounter<C, CAPACITY> {
     }
 
     fn state_hash(&self) -> u32 {
-        // Simple hash based on counter values
-        let mut hash = 0u32;
+        // FNV-1a over the node counters in node order
+        let mut hasher = FnvHasher::new();
 
         #[cfg(not(feature = "hardware-atomic"))]
         {
-            for (i, &value) in self.counters.iter().enumerate() {
-                if value > 0 {
-                    hash ^= value ^ ((i as u32) << 16);
-                }
+            for &value in self.counters.iter() {
+                hasher.write_u32(value);
             }
         }
 
         #[cfg(feature = "hardware-atomic")]
         {
-            for (i, atomic) in self.counters.iter().enumerate() {
-                let value = atomic.load(Ordering::Relaxed);
-                if value > 0 {
-                    hash ^= value ^ ((i as u32) << 16);
-                }
+            for atomic in self.counters.iter() {
+                hasher.write_u32(atomic.load(Ordering::Relaxed));
             }
         }
 
-        hash
+        hasher.finish()
     }
 
     fn can_merge(&self, _other: &Self) -> bool {
@@ -752,6 +1517,17 @@ impl<C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for GCounter<C, CAPA
         // For counters, we can always "add" (increment) if not at max capacity
         self.element_count() < Self::MAX_ELEMENTS
     }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        let slot = core::mem::size_of::<u32>();
+        let nodes = self.active_nodes();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            nodes * slot,
+            0,
+            (CAPACITY - nodes) * slot,
+        )
+    }
 }
 
 impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for GCounter<C, CAPACITY> {
@@ -779,6 +1555,37 @@ impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for GCounter<C, CAP
     }
 }
 
+impl<C: MemoryConfig, const CAPACITY: usize> DeltaCRDT<C> for GCounter<C, CAPACITY> {
+    fn delta_since(&self, baseline: &Self) -> Self {
+        // Only the per-node counters that advanced beyond the baseline
+        let mut delta = Self::with_overflow_mode(self.node_id, self.overflow_mode);
+        if self.has_wrapped() {
+            delta.mark_wrapped();
+        }
+        for i in 0..CAPACITY {
+            let ours = self.counter_at(i);
+            if ours > baseline.counter_at(i) {
+                delta.store_counter(i, ours);
+            }
+        }
+        delta
+    }
+
+    fn apply_delta(&mut self, delta: &Self) -> CRDTResult<()> {
+        delta.validate().map_err(|_| CRDTError::InvalidMerge)?;
+        self.merge(delta)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> Snapshotable<C> for GCounter<C, CAPACITY> {
+    /// The state hash paired with the total count, which only grows
+    type Snapshot = (u32, u64);
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.state_hash(), self.value())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -846,7 +1653,88 @@ mod tests {
         assert_eq!(counter.node_value(1), u32::MAX as u64);
 
         // This should fail (overflow)
-        assert!(counter.increment(1).is_err());
+        assert_eq!(counter.increment(1), Err(CRDTError::Overflow));
+        assert_eq!(counter.node_value(1), u32::MAX as u64);
+        assert!(!counter.has_wrapped());
+    }
+
+    #[test]
+    fn test_increment_error_context() {
+        let mut counter = GCounter::<DefaultConfig>::new(2);
+        counter.increment(u32::MAX - 1).unwrap();
+
+        let error = counter.increment(2).unwrap_err();
+        assert_eq!(error.cause(), CRDTError::Overflow);
+        let ctx = error.context().unwrap();
+        assert_eq!((ctx.crdt_type, ctx.operation), ("GCounter", "increment"));
+        assert_eq!(ctx.node_id, 2);
+        assert_eq!(ctx.current_count, (u32::MAX - 1) as usize);
+        assert_eq!(ctx.capacity, u32::MAX as usize);
+
+        let error = counter.increment(0).unwrap_err();
+        assert_eq!(error.cause(), CRDTError::InvalidOperation);
+        assert_eq!(
+            error.context().map(|ctx| ctx.current_count),
+            Some((u32::MAX - 1) as usize)
+        );
+    }
+
+    #[test]
+    fn test_overflow_mode_saturate() {
+        let mut counter = GCounter::<DefaultConfig>::with_overflow_mode(1, OverflowMode::Saturate);
+        assert_eq!(counter.overflow_mode(), OverflowMode::Saturate);
+
+        counter.increment(u32::MAX - 5).unwrap();
+        counter.increment(10).unwrap();
+        assert_eq!(counter.node_value(1), u32::MAX as u64);
+        assert!(!counter.has_wrapped());
+        assert!(counter.validate().is_ok());
+    }
+
+    #[test]
+    fn test_overflow_mode_wrap() {
+        let mut counter = GCounter::<DefaultConfig>::with_overflow_mode(1, OverflowMode::Wrap);
+        counter.increment(u32::MAX - 5).unwrap();
+        assert!(!counter.has_wrapped());
+
+        counter.increment(10).unwrap();
+        assert_eq!(counter.node_value(1), 4);
+        assert!(counter.has_wrapped());
+        assert!(counter.validate().is_ok());
+
+        counter.clear_wrap_flag();
+        assert!(!counter.has_wrapped());
+    }
+
+    #[test]
+    fn test_merge_wrapped_state() {
+        let mut wrapping = GCounter::<DefaultConfig>::with_overflow_mode(1, OverflowMode::Wrap);
+        wrapping.increment(u32::MAX).unwrap();
+        wrapping.increment(1).unwrap();
+
+        // Error mode refuses the wrapped state without applying any of it
+        let mut strict = GCounter::<DefaultConfig>::new(2);
+        strict.increment(3).unwrap();
+        assert_eq!(strict.merge(&wrapping), Err(CRDTError::Overflow));
+        assert_eq!(strict.value(), 3);
+        assert!(!strict.has_wrapped());
+
+        // Other modes merge it but inherit the wrap flag
+        let mut lenient = GCounter::<DefaultConfig>::with_overflow_mode(2, OverflowMode::Saturate);
+        lenient.merge(&wrapping).unwrap();
+        assert!(lenient.has_wrapped());
+    }
+
+    #[test]
+    fn test_validate_wrapped_error_mode() {
+        let mut counter = GCounter::<DefaultConfig>::new(1);
+        assert_eq!(counter.overflow_mode(), OverflowMode::Error);
+
+        counter.mark_wrapped();
+        assert_eq!(counter.validate(), Err(CRDTError::InvalidState));
+
+        counter.clear_wrap_flag();
+        assert!(counter.validate().is_ok());
     }
 
     #[test]
@@ -869,6 +1757,106 @@ mod tests {
         assert_eq!(counter1.active_nodes(), 2);
     }
 
+    #[test]
+    fn test_merge_batch() {
+        let mut others = [
+            GCounter::<DefaultConfig>::new(1),
+            GCounter::<DefaultConfig>::new(2),
+            GCounter::<DefaultConfig>::new(1),
+            GCounter::<DefaultConfig>::new(3),
+        ];
+        for (i, other) in others.iter_mut().enumerate() {
+            other.increment(i as u32 + 1).unwrap();
+        }
+
+        let mut batched = GCounter::<DefaultConfig>::new(0);
+        batched.increment(7).unwrap();
+        let mut sequential = batched.clone();
+
+        batched.merge_batch(others.iter()).unwrap();
+        for other in others.iter() {
+            sequential.merge(other).unwrap();
+        }
+
+        assert!(batched.eq(&sequential));
+        assert_eq!(batched.node_value(1), 3); // Max of the duplicate node 1 states
+        assert_eq!(batched.value(), 7 + 3 + 2 + 4);
+
+        // An empty batch leaves the counter untouched
+        batched.merge_batch(core::iter::empty()).unwrap();
+        assert!(batched.eq(&sequential));
+    }
+
+    #[test]
+    fn test_merge_batch_wrapped_state() {
+        let mut wrapping = GCounter::<DefaultConfig>::with_overflow_mode(1, OverflowMode::Wrap);
+        wrapping.increment(u32::MAX).unwrap();
+        wrapping.increment(1).unwrap();
+        let mut plain = GCounter::<DefaultConfig>::new(2);
+        plain.increment(5).unwrap();
+
+        // Error mode rejects the whole batch before applying any of it
+        let mut strict = GCounter::<DefaultConfig>::new(3);
+        assert_eq!(
+            strict.merge_batch([&plain, &wrapping]),
+            Err(CRDTError::Overflow)
+        );
+        assert_eq!(strict.value(), 0);
+
+        let mut lenient = GCounter::<DefaultConfig>::with_overflow_mode(3, OverflowMode::Wrap);
+        lenient.merge_batch([&plain, &wrapping]).unwrap();
+        assert!(lenient.has_wrapped());
+        assert_eq!(lenient.node_value(2), 5);
+    }
+
+    #[test]
+    fn test_slice_encoding() {
+        let mut counter = GCounter::<DefaultConfig>::new(2);
+        counter.increment(7).unwrap();
+        let mut other = GCounter::<DefaultConfig>::new(5);
+        other.increment(300).unwrap();
+        counter.merge(&other).unwrap();
+
+        let mut buf = [0u8; GCounter::<DefaultConfig>::ENCODE_MAX_BYTES];
+        let len = counter.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(len, 3 + 6 * 4);
+        assert_eq!(&buf[..3], &[1, 2, 6]);
+
+        let decoded = GCounter::<DefaultConfig>::decode_from_slice(&buf[..len]).unwrap();
+        assert!(decoded.eq(&counter));
+        assert_eq!(decoded.node_id(), 2);
+
+        // Short buffers on either side, and an unknown version
+        assert_eq!(
+            counter.encode_to_slice(&mut buf[..len - 1]),
+            Err(CRDTError::BufferOverflow)
+        );
+        assert_eq!(
+            GCounter::<DefaultConfig>::decode_from_slice(&buf[..len - 1]).err(),
+            Some(CRDTError::BufferOverflow)
+        );
+        buf[0] = 9;
+        assert_eq!(
+            GCounter::<DefaultConfig>::decode_from_slice(&buf[..len]).err(),
+            Some(CRDTError::InvalidState)
+        );
+
+        // State from a larger counter does not fit
+        let mut wide = GCounter::<DefaultConfig, 32>::with_capacity(20);
+        wide.increment(1).unwrap();
+        let mut wide_buf = [0u8; GCounter::<DefaultConfig, 32>::ENCODE_MAX_BYTES];
+        let len = wide.encode_to_slice(&mut wide_buf).unwrap();
+        assert_eq!(
+            GCounter::<DefaultConfig>::decode_from_slice(&wide_buf[..len]).err(),
+            Some(CRDTError::BufferOverflow)
+        );
+
+        let mut wrapping = GCounter::<DefaultConfig>::with_overflow_mode(1, OverflowMode::Wrap);
+        wrapping.increment(u32::MAX).unwrap();
+        wrapping.increment(1).unwrap();
+        assert_eq!(wrapping.encode_to_slice(&mut buf), Err(CRDTError::Overflow));
+    }
+
     #[test]
     fn test_merge_with_overlap() {
         let mut counter1 = GCounter::<DefaultConfig>::new(1);
@@ -940,6 +1928,19 @@ mod tests {
         assert_eq!(counter.element_count(), 2); // Two active nodes
     }
 
+    #[test]
+    fn test_memory_breakdown() {
+        let mut counter = GCounter::<DefaultConfig, 4>::with_capacity(1);
+        counter.increment(5).unwrap();
+
+        let breakdown = counter.memory_breakdown();
+        assert_eq!(breakdown.element_data, 4);
+        assert_eq!(breakdown.tombstone_data, 0);
+        assert_eq!(breakdown.unused_capacity, 12);
+        assert_eq!(breakdown.total(), counter.memory_usage());
+        assert_eq!(counter.utilization_percent(), 25);
+    }
+
     #[test]
     fn test_validation() {
         let counter = GCounter::<DefaultConfig>::new(1);
@@ -1017,6 +2018,196 @@ mod tests {
         assert_eq!(counter1.node_value(2), 3);
     }
 
+    #[test]
+    fn test_delta_since() {
+        let mut counter1 = GCounter::<DefaultConfig>::new(1);
+        let mut counter2 = GCounter::<DefaultConfig>::new(2);
+
+        counter1.increment(5).unwrap();
+        counter2.increment(3).unwrap();
+        counter2.merge(&counter1).unwrap();
+        counter1.increment(2).unwrap();
+
+        // Only node 1's advanced counter is shipped
+        let delta = counter1.delta_since(&counter2);
+        assert_eq!(delta.node_value(1), 7);
+        assert_eq!(delta.node_value(2), 0);
+        assert_eq!(delta.active_nodes(), 1);
+
+        counter2.apply_delta(&delta).unwrap();
+        assert_eq!(counter2.value(), 10);
+
+        // Identical states produce an empty delta that applies as a no-op
+        let empty = counter1.delta_since(&counter1.clone());
+        assert!(empty.is_empty());
+        let before = counter2.clone();
+        counter2.apply_delta(&empty).unwrap();
+        assert!(counter2.eq(&before));
+    }
+
+    #[test]
+    fn test_apply_invalid_delta() {
+        let mut counter = GCounter::<DefaultConfig>::new(1);
+        let invalid = GCounter::<DefaultConfig>::new(200);
+        assert_eq!(counter.apply_delta(&invalid), Err(CRDTError::InvalidMerge));
+    }
+
+    #[test]
+    fn test_delta_bandwidth() {
+        let mut counter1 = GCounter::<DefaultConfig>::new(1);
+        let counter2 = GCounter::<DefaultConfig>::new(2);
+        counter1.increment(5).unwrap();
+
+        // One active node out of 16: 4 bytes of payload instead of 64
+        let delta = counter1.delta_since(&counter2);
+        let full_payload = counter1.capacity() * core::mem::size_of::<u32>();
+        let delta_payload = delta.active_nodes() * core::mem::size_of::<u32>();
+        assert_eq!(delta.size_bytes(), counter1.size_bytes());
+        assert!(delta_payload * 16 <= full_payload);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut counter = GCounter::<DefaultConfig>::new(1);
+        counter.increment(5).unwrap();
+        let snap = counter.snapshot();
+
+        // Merging a state we already contain changes nothing
+        let stale = counter.clone();
+        counter.merge(&stale).unwrap();
+        assert!(!counter.has_changed_since(&snap));
+
+        let mut other = GCounter::<DefaultConfig>::new(2);
+        other.increment(1).unwrap();
+        counter.merge(&other).unwrap();
+        assert!(counter.has_changed_since(&snap));
+    }
+
+    #[test]
+    fn test_checkpoint_and_reset() {
+        let mut meter = GCounter::<DefaultConfig>::new(1);
+        let mut peer = GCounter::<DefaultConfig>::new(2);
+        meter.increment(100).unwrap();
+        peer.increment(30).unwrap();
+        let start = meter.checkpoint();
+        assert_eq!(start.value(), 100);
+
+        meter.increment(25).unwrap();
+        meter.merge(&peer).unwrap();
+        assert_eq!(meter.period_usage(&start), 55);
+
+        // Only this node's count is zeroed
+        let mut period = start.clone();
+        assert_eq!(meter.reset_since_checkpoint(&mut period, 1000), Ok(55));
+        assert_eq!(meter.node_value(1), 0);
+        assert_eq!(meter.node_value(2), 30);
+        assert_eq!(period.last_reset(), Some(1000));
+        assert_eq!(start.last_reset(), None);
+        assert!(meter.validate().is_ok());
+
+        // The checkpoint now starts the next period
+        meter.increment(7).unwrap();
+        assert_eq!(meter.period_usage(&period), 7);
+        assert_eq!(meter.period_usage(&start), 0);
+
+        // Stale timestamps are rejected and change nothing
+        assert_eq!(
+            meter.reset_since_checkpoint(&mut period, 1000),
+            Err(CRDTError::InvalidOperation)
+        );
+        assert_eq!(meter.value(), 37);
+        assert_eq!(period.last_reset(), Some(1000));
+        assert_eq!(meter.reset_since_checkpoint(&mut period, 2000), Ok(7));
+        assert_eq!(meter.value(), 30);
+        assert_eq!(period.value(), 30);
+
+        // A fresh checkpoint knows nothing of earlier resets
+        assert_eq!(meter.checkpoint().last_reset(), None);
+    }
+
+    #[test]
+    fn test_per_node_contributions() {
+        let mut gateway = GCounter::<DefaultConfig>::new(0);
+        assert_eq!(gateway.dominant_contributor(), None);
+        assert_eq!(gateway.histogram::<3>(10), [0, 0, 0]);
+
+        for (node, count) in [(1, 5), (2, 25), (4, 12)] {
+            let mut ecu = GCounter::<DefaultConfig>::new(node);
+            ecu.increment(count).unwrap();
+            gateway.merge(&ecu).unwrap();
+        }
+
+        assert_eq!(gateway.value_at(2), 25);
+        assert_eq!(gateway.value_at(3), 0);
+        assert_eq!(gateway.value_at(200), 0);
+        assert!(gateway.contributions().eq([(1, 5), (2, 25), (4, 12)]));
+        assert_eq!(gateway.node_count(), 3);
+        assert_eq!(gateway.dominant_contributor(), Some((2, 25)));
+        assert_eq!(gateway.histogram::<3>(10), [1, 1, 1]);
+        assert_eq!(gateway.histogram::<2>(10), [1, 2]);
+        assert_eq!(gateway.histogram::<0>(10), []);
+    }
+
+    #[test]
+    fn test_equal_contributions() {
+        let mut gateway = GCounter::<DefaultConfig>::new(0);
+        for node in 0..4 {
+            let mut ecu = GCounter::<DefaultConfig>::new(node);
+            ecu.increment(7).unwrap();
+            gateway.merge(&ecu).unwrap();
+        }
+
+        assert_eq!(gateway.node_count(), 4);
+        assert!(gateway.contributions().all(|(_, count)| count == 7));
+        // Ties go to the lowest node ID
+        assert_eq!(gateway.dominant_contributor(), Some((0, 7)));
+        assert_eq!(gateway.histogram::<2>(7), [0, 4]);
+        assert_eq!(gateway.histogram::<4>(0), [0, 0, 0, 4]);
+    }
+
+    #[test]
+    fn test_concurrent_raw_merges() {
+        let mut ecus = [
+            GCounter::<DefaultConfig>::new(0),
+            GCounter::<DefaultConfig>::new(1),
+            GCounter::<DefaultConfig>::new(2),
+        ];
+        ecus[0].increment(3).unwrap();
+        ecus[1].increment(5).unwrap();
+        ecus[2].increment(7).unwrap();
+
+        // Frames sent by every ECU, including one it sent before its last increment
+        let frames = [(0, 3), (1, 5), (2, 7), (2, 4)];
+
+        // Each ECU receives all frames in a different order
+        let mut reference = ecus[0].clone();
+        reference.merge(&ecus[1]).unwrap();
+        reference.merge(&ecus[2]).unwrap();
+        for (rotation, ecu) in ecus.iter_mut().enumerate() {
+            for i in 0..frames.len() {
+                let (node, count) = frames[(i + rotation) % frames.len()];
+                ecu.merge_from_raw(node, count).unwrap();
+            }
+            assert!(ecu.eq(&reference));
+        }
+        assert_eq!(ecus[0].value(), 15);
+
+        // A slice merge is all or nothing
+        let mut gateway = GCounter::<DefaultConfig>::new(3);
+        assert_eq!(
+            gateway.merge_from_slice(&[(1, 9), (16, 1)]),
+            Err(CRDTError::InvalidNodeId)
+        );
+        assert!(gateway.is_empty());
+        gateway.merge_from_slice(&frames).unwrap();
+        assert!(gateway.eq(&reference));
+
+        // Restoring from storage can lower a count
+        gateway.set_raw(2, 1).unwrap();
+        assert_eq!(gateway.value_at(2), 1);
+        assert_eq!(gateway.set_raw(16, 1), Err(CRDTError::InvalidNodeId));
+    }
+
     #[cfg(all(test, feature = "serde"))]
     mod serde_tests {
         use super::*;
@@ -0,0 +1,264 @@
+//! Grow-Decrement Counter CRDT
+//!
+//! A counter where every node can only decrement, e.g. for fuel or other
+//! resource consumption. It tracks consumption per node like a [`GCounter`]
+//! tracks increments, without the unused positive array of a `PNCounter`.
+
+use crate::counters::GCounter;
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::traits::{BoundedCRDT, CRDT, MemoryBreakdown, RealTimeCRDT, Snapshotable};
+
+/// Grow-decrement Counter with configurable node array
+///
+/// Each node records how much it has consumed; [`value`](Self::value) is the
+/// total consumed across all nodes. Merge takes the per-node maximum, as in
+/// [`GCounter`].
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `CAPACITY`: The maximum number of nodes this counter can track (defaults to 16)
+///
+/// # Memory Usage
+/// - Same as a [`GCounter`] of the same capacity: 4 * CAPACITY + 8 bytes
+/// - Half the size of a `PNCounter`
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut pump_a = GDecCounter::<DefaultConfig>::new(1);
+/// let mut pump_b = GDecCounter::<DefaultConfig>::new(2);
+///
+/// pump_a.decrement(40)?;
+/// pump_b.decrement(25)?;
+/// pump_a.merge(&pump_b)?;
+///
+/// assert_eq!(pump_a.value(), 65); // Litres consumed in total
+/// assert_eq!(pump_a.remaining_capacity_for_node(2), Some(u32::MAX - 25));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct GDecCounter<C: MemoryConfig, const CAPACITY: usize = 16> {
+    /// Amount consumed by each node
+    consumed: GCounter<C, CAPACITY>,
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> GDecCounter<C, CAPACITY> {
+    /// Creates a new grow-decrement counter with custom capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < CAPACITY)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let counter = GDecCounter::<DefaultConfig, 32>::with_capacity(1);
+    /// assert_eq!(counter.capacity(), 32);
+    /// ```
+    pub fn with_capacity(node_id: NodeId) -> Self {
+        Self {
+            consumed: GCounter::with_capacity(node_id),
+        }
+    }
+
+    /// Decrements this node's counter by the given amount
+    ///
+    /// # Arguments
+    /// * `amount` - The amount consumed (must be > 0)
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `CRDTError::InvalidOperation` if `amount` is
+    /// zero, or `CRDTError::Overflow` if this node's total consumption would
+    /// pass `u32::MAX`
+    pub fn decrement(&mut self, amount: u32) -> CRDTResult<()> {
+        self.consumed.increment(amount)
+    }
+
+    /// Gets the total amount consumed across all nodes
+    pub fn value(&self) -> u64 {
+        self.consumed.value()
+    }
+
+    /// Gets the amount consumed by a specific node
+    pub fn node_value(&self, node_id: NodeId) -> u64 {
+        self.consumed.node_value(node_id)
+    }
+
+    /// Returns how much more a node can decrement before its counter overflows
+    ///
+    /// # Returns
+    /// The remaining amount, or None if the node is not tracked
+    pub fn remaining_capacity_for_node(&self, node_id: NodeId) -> Option<u32> {
+        if node_id as usize >= CAPACITY {
+            return None;
+        }
+        Some(u32::MAX - self.consumed.node_value(node_id) as u32)
+    }
+
+    /// Returns this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.consumed.node_id()
+    }
+
+    /// Returns the maximum number of nodes this counter can track
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Checks if nothing has been consumed yet
+    pub fn is_empty(&self) -> bool {
+        self.consumed.is_empty()
+    }
+
+    /// Returns the number of nodes that have consumed something
+    pub fn active_nodes(&self) -> usize {
+        self.consumed.active_nodes()
+    }
+}
+
+impl<C: MemoryConfig> GDecCounter<C, 16> {
+    /// Creates a new grow-decrement counter with default capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < 16)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let counter = GDecCounter::<DefaultConfig>::new(1);
+    /// assert_eq!(counter.value(), 0);
+    /// ```
+    pub fn new(node_id: NodeId) -> Self {
+        Self::with_capacity(node_id)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for GDecCounter<C, CAPACITY> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.consumed.merge(&other.consumed)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.consumed.eq(&other.consumed)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.consumed.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.consumed.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.consumed.can_merge(&other.consumed)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for GDecCounter<C, CAPACITY> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY; // Maximum number of nodes
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.active_nodes()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Counters can't be compacted without losing data
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.element_count() < Self::MAX_ELEMENTS
+    }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        self.consumed.memory_breakdown()
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for GDecCounter<C, CAPACITY> {
+    const MAX_MERGE_CYCLES: u32 = GCounter::<C, CAPACITY>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 = GCounter::<C, CAPACITY>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 = GCounter::<C, CAPACITY>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.consumed.merge_bounded(&other.consumed)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.consumed.validate_bounded()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For this simple implementation, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For this simple implementation, we don't track budget
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> Snapshotable<C> for GDecCounter<C, CAPACITY> {
+    /// The state hash paired with the total consumption, which only grows
+    type Snapshot = (u32, u64);
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.consumed.snapshot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_decrement_and_merge() {
+        let mut a = GDecCounter::<DefaultConfig>::new(1);
+        let mut b = GDecCounter::<DefaultConfig>::new(2);
+        a.decrement(10).unwrap();
+        a.decrement(5).unwrap();
+        b.decrement(7).unwrap();
+        assert_eq!(a.decrement(0), Err(CRDTError::InvalidOperation));
+
+        let mut ab = a.clone();
+        ab.merge(&b).unwrap();
+        let mut ba = b.clone();
+        ba.merge(&a).unwrap();
+
+        assert_eq!(ab.value(), 22);
+        assert_eq!(ab.node_value(1), 15);
+        assert_eq!(ab.active_nodes(), 2);
+        assert!(ab.eq(&ba));
+
+        // Idempotent
+        ab.merge(&a).unwrap();
+        assert_eq!(ab.value(), 22);
+    }
+
+    #[test]
+    fn test_remaining_capacity_for_node() {
+        let mut counter = GDecCounter::<DefaultConfig, 4>::with_capacity(0);
+        assert_eq!(counter.remaining_capacity_for_node(0), Some(u32::MAX));
+        assert_eq!(counter.remaining_capacity_for_node(4), None);
+
+        counter.decrement(u32::MAX - 1).unwrap();
+        assert_eq!(counter.remaining_capacity_for_node(0), Some(1));
+        assert_eq!(counter.decrement(2), Err(CRDTError::Overflow));
+        counter.decrement(1).unwrap();
+        assert_eq!(counter.remaining_capacity_for_node(0), Some(0));
+    }
+}
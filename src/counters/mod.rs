@@ -1,11 +1,15 @@
 //! Counter CRDT implementations
 //!
 //! This module provides counter-based CRDTs for tracking numeric values
-//! with different semantics (grow-only, increment/decrement).
+//! with different semantics (grow-only, decrement-only, increment/decrement, presence).
 
 pub mod gcounter;
+pub mod gdec;
 pub mod pncounter;
+pub mod presence;
 
 // Re-export main types
-pub use gcounter::GCounter;
-pub use pncounter::PNCounter;
+pub use gcounter::{GCounter, GCounterCheckpoint, OverflowMode};
+pub use gdec::GDecCounter;
+pub use pncounter::{NodeCounts, PNCounter};
+pub use presence::{MAX_PRESENCE_NODES, PresenceBitmap};
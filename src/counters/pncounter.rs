@@ -7,9 +7,10 @@
 //! - Standard: Requires `&mut self` for modifications, single-threaded
 //! - Atomic: Allows `&self` for modifications, multi-threaded safe
 
+use crate::clock::FnvHasher;
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::{MemoryConfig, NodeId};
-use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+use crate::traits::{BoundedCRDT, CRDT, MemoryBreakdown, RealTimeCRDT, Snapshotable};
 
 #[cfg(feature = "hardware-atomic")]
 use core::sync::atomic::{AtomicU32, Ordering};
@@ -17,6 +18,11 @@ use core::sync::atomic::{AtomicU32, Ordering};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "postcard")]
+use crate::serialization::postcard::seq_max_size;
+#[cfg(feature = "postcard")]
+use postcard::experimental::max_size::MaxSize;
+
 /// Increment/Decrement Counter with configurable node arrays
 ///
 /// This counter supports both increment and decrement operations and provides
@@ -40,6 +46,8 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// | `decrement()` | ✅ | ✅ | `&mut self` / `&self` | Single / Multi | Negative increment |
 /// | `inc()` | ✅ | ✅ | `&mut self` / `&self` | Single / Multi | Convenience +1 |
 /// | `dec()` | ✅ | ✅ | `&mut self` / `&self` | Single / Multi | Convenience -1 |
+/// | `saturating_increment()` | ✅ | ✅ | `&mut self` / `&self` | Single / Multi | Clamps at upper bound |
+/// | `saturating_decrement()` | ✅ | ✅ | `&mut self` / `&self` | Single / Multi | Clamps at zero |
 /// | `merge()` | ✅ | ✅ | `&mut self` | Single / Multi | CRDT merge |
 /// | `value()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
 /// | `node_value()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
@@ -78,12 +86,16 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 #[cfg_attr(feature = "stm32", repr(align(4)))] // STM32 word alignment
 #[cfg_attr(feature = "cortex-m", repr(align(4)))] // ARM word alignment
 #[cfg_attr(feature = "riscv", repr(align(8)))] // RISC-V double-word alignment
+#[cfg_attr(feature = "esp32", repr(align(4)))] // Xtensa word alignment
+#[cfg_attr(feature = "nrf52", repr(align(4)))] // Cortex-M4 word alignment
 #[cfg_attr(
     not(any(
         feature = "aurix",
         feature = "stm32",
         feature = "cortex-m",
-        feature = "riscv"
+        feature = "riscv",
+        feature = "esp32",
+        feature = "nrf52"
     )),
     repr(align(4))
 )] // Default alignment
@@ -107,6 +119,15 @@ pub struct PNCounter<C: MemoryConfig, const CAPACITY: usize = 16> {
     /// This node's ID
     node_id: NodeId,
 
+    /// Lowest value the counter may hold
+    min: i64,
+
+    /// Highest value the counter may hold
+    max: i64,
+
+    /// Timestamp of this node's last local reset
+    last_reset: Option<u64>,
+
     /// Phantom data to maintain the memory config type
     _phantom: core::marker::PhantomData<C>,
 }
@@ -120,6 +141,9 @@ impl<C: MemoryConfig, const CAPACITY: usize> Clone for PNCounter<C, CAPACITY> {
                 positive: self.positive,
                 negative: self.negative,
                 node_id: self.node_id,
+                min: self.min,
+                max: self.max,
+                last_reset: self.last_reset,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -139,6 +163,9 @@ impl<C: MemoryConfig, const CAPACITY: usize> Clone for PNCounter<C, CAPACITY> {
                 positive: new_positive,
                 negative: new_negative,
                 node_id: self.node_id,
+                min: self.min,
+                max: self.max,
+                last_reset: self.last_reset,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -167,6 +194,9 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
                 positive: [0; CAPACITY],
                 negative: [0; CAPACITY],
                 node_id,
+                min: i64::MIN,
+                max: i64::MAX,
+                last_reset: None,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -177,10 +207,41 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
                 positive: [const { AtomicU32::new(0) }; CAPACITY],
                 negative: [const { AtomicU32::new(0) }; CAPACITY],
                 node_id,
+                min: i64::MIN,
+                max: i64::MAX,
+                last_reset: None,
                 _phantom: core::marker::PhantomData,
             }
         }
     }
+
+    /// Creates a new counter whose value is kept within `[min, max]`
+    ///
+    /// Local increments and decrements that would leave the range are
+    /// rejected, and merged states that would leave it are clamped. The range
+    /// must contain zero, the value of a fresh counter.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < CAPACITY)
+    /// * `min` - The lowest allowed value (must be <= 0)
+    /// * `max` - The highest allowed value (must be >= 0)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut wear = PNCounter::<DefaultConfig>::with_bounds(1, 0, 100);
+    /// wear.increment(80)?;
+    /// assert!(wear.increment(30).is_err());
+    /// assert_eq!(wear.saturating_increment(30)?, 100);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn with_bounds(node_id: NodeId, min: i64, max: i64) -> Self {
+        debug_assert!(min <= 0 && max >= 0, "bounds must contain zero");
+        let mut counter = Self::with_capacity(node_id);
+        counter.min = min;
+        counter.max = max;
+        counter
+    }
 }
 
 impl<C: MemoryConfig> PNCounter<C, 16> {
@@ -211,6 +272,7 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
     ///
     /// # Returns
     /// Ok(()) if successful, or an error if the increment would cause overflow
+    /// or push the value above the configured maximum
     ///
     /// # Example
     /// ```rust
@@ -231,6 +293,10 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
             return Err(CRDTError::InvalidNodeId);
         }
 
+        if self.value() + amount as i64 > self.max {
+            return Err(CRDTError::ConfigurationExceeded);
+        }
+
         // Check for overflow
         if self.positive[node_index] > u32::MAX - amount {
             return Err(CRDTError::BufferOverflow);
@@ -258,6 +324,10 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
             return Err(CRDTError::InvalidNodeId);
         }
 
+        if self.value() + amount as i64 > self.max {
+            return Err(CRDTError::ConfigurationExceeded);
+        }
+
         // Use atomic fetch_add with overflow check
         let old_value = self.positive[node_index].fetch_add(amount, Ordering::Relaxed);
 
@@ -278,6 +348,7 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
     ///
     /// # Returns
     /// Ok(()) if successful, or an error if the decrement would cause overflow
+    /// or push the value below the configured minimum
     ///
     /// # Example
     /// ```rust
@@ -299,6 +370,10 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
             return Err(CRDTError::InvalidNodeId);
         }
 
+        if self.value() - (amount as i64) < self.min {
+            return Err(CRDTError::ConfigurationExceeded);
+        }
+
         // Check for overflow
         if self.negative[node_index] > u32::MAX - amount {
             return Err(CRDTError::BufferOverflow);
@@ -326,6 +401,10 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
             return Err(CRDTError::InvalidNodeId);
         }
 
+        if self.value() - (amount as i64) < self.min {
+            return Err(CRDTError::ConfigurationExceeded);
+        }
+
         // Use atomic fetch_add with overflow check
         let old_value = self.negative[node_index].fetch_add(amount, Ordering::Relaxed);
 
@@ -339,6 +418,108 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
         Ok(())
     }
 
+    /// Increments this node's counter, clamping at the upper bound
+    ///
+    /// Applies as much of `amount` as fits below the configured maximum (or
+    /// `u64::MAX` for an unbounded counter) and this node's counter slot.
+    ///
+    /// # Returns
+    /// The resulting counter value, or 0 if the value is negative
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = PNCounter::<DefaultConfig>::with_bounds(1, 0, 10);
+    /// assert_eq!(counter.saturating_increment(7)?, 7);
+    /// assert_eq!(counter.saturating_increment(7)?, 10);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    #[cfg(not(feature = "hardware-atomic"))]
+    pub fn saturating_increment(&mut self, amount: u32) -> CRDTResult<u64> {
+        let applied = self.saturating_increment_amount(amount)?;
+        if applied > 0 {
+            self.increment(applied)?;
+        }
+        Ok(self.value().max(0) as u64)
+    }
+
+    /// Increments this node's counter, clamping at the upper bound (atomic version)
+    ///
+    /// # Returns
+    /// The resulting counter value, or 0 if the value is negative
+    #[cfg(feature = "hardware-atomic")]
+    pub fn saturating_increment(&self, amount: u32) -> CRDTResult<u64> {
+        let applied = self.saturating_increment_amount(amount)?;
+        if applied > 0 {
+            self.increment(applied)?;
+        }
+        Ok(self.value().max(0) as u64)
+    }
+
+    /// Decrements this node's counter, clamping at zero
+    ///
+    /// Applies as much of `amount` as keeps the value at or above zero (or
+    /// the configured minimum, if that is higher).
+    ///
+    /// # Returns
+    /// The resulting counter value, or 0 if the value is negative
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = PNCounter::<DefaultConfig>::new(1);
+    /// counter.increment(5)?;
+    /// assert_eq!(counter.saturating_decrement(3)?, 2);
+    /// assert_eq!(counter.saturating_decrement(3)?, 0);
+    /// assert_eq!(counter.value(), 0);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    #[cfg(not(feature = "hardware-atomic"))]
+    pub fn saturating_decrement(&mut self, amount: u32) -> CRDTResult<u64> {
+        let applied = self.saturating_decrement_amount(amount)?;
+        if applied > 0 {
+            self.decrement(applied)?;
+        }
+        Ok(self.value().max(0) as u64)
+    }
+
+    /// Decrements this node's counter, clamping at zero (atomic version)
+    ///
+    /// # Returns
+    /// The resulting counter value, or 0 if the value is negative
+    #[cfg(feature = "hardware-atomic")]
+    pub fn saturating_decrement(&self, amount: u32) -> CRDTResult<u64> {
+        let applied = self.saturating_decrement_amount(amount)?;
+        if applied > 0 {
+            self.decrement(applied)?;
+        }
+        Ok(self.value().max(0) as u64)
+    }
+
+    /// Returns how much of `amount` a saturating increment can apply
+    fn saturating_increment_amount(&self, amount: u32) -> CRDTResult<u32> {
+        let node_index = self.node_id as usize;
+        if node_index >= CAPACITY {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        let slot_room = u32::MAX as u64 - self.node_positive(self.node_id);
+        let bound_room = (self.max - self.value()).max(0) as u64;
+        Ok((amount as u64).min(slot_room).min(bound_room) as u32)
+    }
+
+    /// Returns how much of `amount` a saturating decrement can apply
+    fn saturating_decrement_amount(&self, amount: u32) -> CRDTResult<u32> {
+        let node_index = self.node_id as usize;
+        if node_index >= CAPACITY {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        let slot_room = u32::MAX as u64 - self.node_negative(self.node_id);
+        let bound_room = (self.value() - self.min.max(0)).max(0) as u64;
+        Ok((amount as u64).min(slot_room).min(bound_room) as u32)
+    }
+
     /// Increments this node's counter by 1
     ///
     /// # Returns
@@ -505,6 +686,153 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
         positive - negative
     }
 
+    /// Reads the raw positive and negative counters stored in a slot
+    fn counts_at(&self, index: usize) -> (u32, u32) {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            (self.positive[index], self.negative[index])
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            (
+                self.positive[index].load(Ordering::Relaxed),
+                self.negative[index].load(Ordering::Relaxed),
+            )
+        }
+    }
+
+    /// Gets the raw positive counter contributed by a node
+    ///
+    /// # Arguments
+    /// * `node_id` - The node to query
+    ///
+    /// # Returns
+    /// The node's total increments, or 0 if the node ID is invalid
+    pub fn positive_contribution_of(&self, node_id: NodeId) -> u32 {
+        let node_index = node_id as usize;
+        if node_index < CAPACITY {
+            self.counts_at(node_index).0
+        } else {
+            0
+        }
+    }
+
+    /// Gets the raw negative counter contributed by a node
+    ///
+    /// # Arguments
+    /// * `node_id` - The node to query
+    ///
+    /// # Returns
+    /// The node's total decrements, or 0 if the node ID is invalid
+    pub fn negative_contribution_of(&self, node_id: NodeId) -> u32 {
+        let node_index = node_id as usize;
+        if node_index < CAPACITY {
+            self.counts_at(node_index).1
+        } else {
+            0
+        }
+    }
+
+    /// Finds the node with the highest positive contribution
+    ///
+    /// Ties go to the lowest node ID, so every converged replica reports the
+    /// same node.
+    ///
+    /// # Returns
+    /// `(node_id, positive)` for the top contributor, or None if no node has
+    /// incremented
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut errors = PNCounter::<DefaultConfig>::new(1);
+    /// errors.increment(3)?;
+    ///
+    /// let mut other = PNCounter::<DefaultConfig>::new(4);
+    /// other.increment(7)?;
+    /// errors.merge(&other)?;
+    ///
+    /// assert_eq!(errors.max_contributor(), Some((4, 7)));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn max_contributor(&self) -> Option<(NodeId, u32)> {
+        let mut best: Option<(NodeId, u32)> = None;
+        for i in 0..CAPACITY {
+            let positive = self.counts_at(i).0;
+            if positive > 0 && best.is_none_or(|(_, top)| positive > top) {
+                best = Some((i as NodeId, positive));
+            }
+        }
+        best
+    }
+
+    /// Iterates over the contributions of every active node
+    ///
+    /// # Returns
+    /// `(node_id, positive, negative)` for each node with a nonzero counter,
+    /// in node ID order
+    pub fn contributions_iter(&self) -> impl Iterator<Item = (NodeId, u32, u32)> {
+        (0..CAPACITY).filter_map(move |i| {
+            let (positive, negative) = self.counts_at(i);
+            (positive > 0 || negative > 0).then_some((i as NodeId, positive, negative))
+        })
+    }
+
+    /// Zeros the positive and negative counters of this node
+    ///
+    /// Merges keep the per-node maximum, so a replica that still holds the
+    /// old counters will bring them back. Reset only once no peer has seen
+    /// this node's earlier counts, e.g. at start-up before the first
+    /// transmission or after every replica has been reset.
+    ///
+    /// # Arguments
+    /// * `node_id` - The node to reset (must be this node)
+    /// * `timestamp` - When the reset happened (must be newer than the last reset)
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `CRDTError::InvalidNodeId` for another node's
+    /// counters, or `CRDTError::InvalidOperation` for a stale timestamp
+    /// or a reset that would leave the value outside the configured bounds
+    pub fn reset_node(&mut self, node_id: NodeId, timestamp: u64) -> CRDTResult<()> {
+        let node_index = node_id as usize;
+        if node_id != self.node_id || node_index >= CAPACITY {
+            return Err(CRDTError::InvalidNodeId);
+        }
+        if self.last_reset.is_some_and(|last| timestamp <= last) {
+            return Err(CRDTError::InvalidOperation);
+        }
+
+        let value = self.value() - self.node_value(node_id);
+        if value < self.min || value > self.max {
+            return Err(CRDTError::InvalidOperation);
+        }
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.positive[node_index] = 0;
+            self.negative[node_index] = 0;
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            *self.positive[node_index].get_mut() = 0;
+            *self.negative[node_index].get_mut() = 0;
+        }
+
+        self.last_reset = Some(timestamp);
+        Ok(())
+    }
+
+    /// Gets the timestamp of this node's last reset
+    ///
+    /// # Returns
+    /// The timestamp passed to the last successful
+    /// [`reset_node`](Self::reset_node), or None if the node was never reset
+    pub fn last_reset(&self) -> Option<u64> {
+        self.last_reset
+    }
+
     /// Gets this node's ID
     ///
     /// # Returns
@@ -521,6 +849,53 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
         CAPACITY
     }
 
+    /// Returns the `(min, max)` value bounds of this counter
+    ///
+    /// Unbounded counters report `(i64::MIN, i64::MAX)`.
+    pub fn bounds(&self) -> (i64, i64) {
+        (self.min, self.max)
+    }
+
+    /// Pulls the value back inside the bounds after a merge
+    ///
+    /// The excess is booked against the opposing counters starting at the
+    /// lowest node slot, so the adjustment depends only on the merged state
+    /// and both sides of a merge end up identical.
+    fn clamp_to_bounds(&mut self) {
+        let value = self.value();
+        let (compensate_positive, mut excess) = if value > self.max {
+            (false, (value - self.max) as u64)
+        } else if value < self.min {
+            (true, (self.min - value) as u64)
+        } else {
+            return;
+        };
+
+        for i in 0..CAPACITY {
+            if excess == 0 {
+                break;
+            }
+
+            #[cfg(not(feature = "hardware-atomic"))]
+            let slot = if compensate_positive {
+                &mut self.positive[i]
+            } else {
+                &mut self.negative[i]
+            };
+
+            #[cfg(feature = "hardware-atomic")]
+            let slot = if compensate_positive {
+                self.positive[i].get_mut()
+            } else {
+                self.negative[i].get_mut()
+            };
+
+            let step = ((u32::MAX - *slot) as u64).min(excess);
+            *slot += step as u32;
+            excess -= step;
+        }
+    }
+
     /// Gets the positive value for all nodes as an array
     ///
     /// # Returns
@@ -660,6 +1035,222 @@ impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
     }
 }
 
+/// Version byte of the [`PNCounter::encode_to_slice`] layout
+const SLICE_FORMAT_VERSION: u8 = 1;
+
+/// Raw `(node, count)` pairs, one per node slot
+///
+/// Returned by [`PNCounter::to_positive_negative_arrays`].
+pub type NodeCounts<const CAPACITY: usize> = [(NodeId, u32); CAPACITY];
+
+// Compact binary encoding for PNCounter
+impl<C: MemoryConfig, const CAPACITY: usize> PNCounter<C, CAPACITY> {
+    /// Largest number of bytes [`encode_to_slice`](Self::encode_to_slice) writes
+    pub const ENCODE_MAX_BYTES: usize = 3 + CAPACITY * 8;
+
+    /// Encodes the counter into a byte slice without serde
+    ///
+    /// The layout is `[version, node_id, node_count, positive: [u32; node_count],
+    /// negative: [u32; node_count]]` with little-endian counts, where trailing
+    /// zero slots are omitted. It fits CAN payloads and needs no serde
+    /// dependency. The bounds are local policy and are not encoded.
+    ///
+    /// # Arguments
+    /// * `buf` - The output buffer, at most [`ENCODE_MAX_BYTES`](Self::ENCODE_MAX_BYTES) long
+    ///
+    /// # Returns
+    /// The number of bytes written, or `CRDTError::BufferOverflow` if the
+    /// buffer is too small
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = PNCounter::<DefaultConfig>::new(1);
+    /// counter.increment(5)?;
+    /// counter.decrement(2)?;
+    ///
+    /// let mut frame = [0u8; 64];
+    /// let len = counter.encode_to_slice(&mut frame)?;
+    /// assert_eq!(len, 3 + 2 * 8); // Two slots used
+    ///
+    /// let decoded = PNCounter::<DefaultConfig>::decode_from_slice(&frame[..len])?;
+    /// assert_eq!(decoded.value(), 3);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> CRDTResult<usize> {
+        // Trailing zero slots carry no state
+        let mut used = CAPACITY;
+        while used > 0 && self.counts_at(used - 1) == (0, 0) {
+            used -= 1;
+        }
+        let node_count = u8::try_from(used).map_err(|_| CRDTError::InvalidState)?;
+
+        let len = 3 + used * 8;
+        if buf.len() < len {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        buf[0] = SLICE_FORMAT_VERSION;
+        buf[1] = self.node_id;
+        buf[2] = node_count;
+        let (positive, negative) = buf[3..len].split_at_mut(used * 4);
+        let slots = positive
+            .chunks_exact_mut(4)
+            .zip(negative.chunks_exact_mut(4));
+        for (i, (pos, neg)) in slots.enumerate() {
+            let (pos_count, neg_count) = self.counts_at(i);
+            pos.copy_from_slice(&pos_count.to_le_bytes());
+            neg.copy_from_slice(&neg_count.to_le_bytes());
+        }
+        Ok(len)
+    }
+
+    /// Decodes a counter written by [`encode_to_slice`](Self::encode_to_slice)
+    ///
+    /// The decoded counter is unbounded.
+    ///
+    /// # Arguments
+    /// * `buf` - The encoded bytes
+    ///
+    /// # Returns
+    /// The decoded counter, `CRDTError::BufferOverflow` if the buffer is
+    /// shorter than the layout or the state does not fit CAPACITY, or
+    /// `CRDTError::InvalidState` for an unknown version
+    pub fn decode_from_slice(buf: &[u8]) -> CRDTResult<Self> {
+        let [version, node_id, node_count, ..] = *buf else {
+            return Err(CRDTError::BufferOverflow);
+        };
+        if version != SLICE_FORMAT_VERSION {
+            return Err(CRDTError::InvalidState);
+        }
+
+        let used = node_count as usize;
+        if node_id as usize >= CAPACITY || used > CAPACITY {
+            return Err(CRDTError::BufferOverflow);
+        }
+        if buf.len() < 3 + used * 8 {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        let mut counter = Self::with_capacity(node_id);
+        let (positive, negative) = buf[3..3 + used * 8].split_at(used * 4);
+        let slots = positive.chunks_exact(4).zip(negative.chunks_exact(4));
+        for (i, (pos, neg)) in slots.enumerate() {
+            let pos = u32::from_le_bytes([pos[0], pos[1], pos[2], pos[3]]);
+            let neg = u32::from_le_bytes([neg[0], neg[1], neg[2], neg[3]]);
+
+            #[cfg(not(feature = "hardware-atomic"))]
+            {
+                counter.positive[i] = pos;
+                counter.negative[i] = neg;
+            }
+
+            #[cfg(feature = "hardware-atomic")]
+            {
+                *counter.positive[i].get_mut() = pos;
+                *counter.negative[i].get_mut() = neg;
+            }
+        }
+        Ok(counter)
+    }
+
+    /// Builds a counter from raw `(node, count)` pairs
+    ///
+    /// Useful for test fixtures and for counters handed over from C code.
+    /// A node listed twice keeps its larger count, as a merge would. The
+    /// counter is unbounded.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the new counter's node
+    /// * `positive` - Increment totals per node
+    /// * `negative` - Decrement totals per node
+    ///
+    /// # Returns
+    /// The counter, or `CRDTError::InvalidNodeId` if any node ID is not
+    /// below CAPACITY
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let counter =
+    ///     PNCounter::<DefaultConfig>::from_positive_negative_arrays(0, &[(0, 7), (2, 5)], &[(2, 4)])?;
+    /// assert_eq!(counter.value(), 8);
+    /// assert_eq!(counter.node_value(2), 1);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn from_positive_negative_arrays(
+        node_id: NodeId,
+        positive: &[(NodeId, u32)],
+        negative: &[(NodeId, u32)],
+    ) -> CRDTResult<Self> {
+        let in_range = |&(node, _): &(NodeId, u32)| (node as usize) < CAPACITY;
+        if node_id as usize >= CAPACITY
+            || !positive.iter().all(in_range)
+            || !negative.iter().all(in_range)
+        {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        let mut counter = Self::with_capacity(node_id);
+        for &(node, count) in positive {
+            #[cfg(not(feature = "hardware-atomic"))]
+            let slot = &mut counter.positive[node as usize];
+            #[cfg(feature = "hardware-atomic")]
+            let slot = counter.positive[node as usize].get_mut();
+            *slot = (*slot).max(count);
+        }
+        for &(node, count) in negative {
+            #[cfg(not(feature = "hardware-atomic"))]
+            let slot = &mut counter.negative[node as usize];
+            #[cfg(feature = "hardware-atomic")]
+            let slot = counter.negative[node as usize].get_mut();
+            *slot = (*slot).max(count);
+        }
+        Ok(counter)
+    }
+
+    /// Returns the raw counts as `(node, count)` pairs
+    ///
+    /// Covers the same slots [`encode_to_slice`](Self::encode_to_slice)
+    /// writes: every node up to the last one with a nonzero count.
+    /// [`from_positive_negative_arrays`](Self::from_positive_negative_arrays)
+    /// rebuilds the counter from the used pairs.
+    ///
+    /// # Returns
+    /// The positive pairs, the negative pairs, and how many leading pairs of
+    /// each are used
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut counter = PNCounter::<DefaultConfig>::new(1);
+    /// counter.increment(5)?;
+    /// counter.decrement(2)?;
+    ///
+    /// let (positive, negative, used) = counter.to_positive_negative_arrays();
+    /// assert_eq!(used, 2);
+    /// assert_eq!(&positive[..used], &[(0, 0), (1, 5)]);
+    /// assert_eq!(&negative[..used], &[(0, 0), (1, 2)]);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn to_positive_negative_arrays(
+        &self,
+    ) -> (NodeCounts<CAPACITY>, NodeCounts<CAPACITY>, usize) {
+        let mut positive = [(0, 0); CAPACITY];
+        let mut negative = [(0, 0); CAPACITY];
+        let mut used = 0;
+        for i in 0..CAPACITY {
+            let (pos, neg) = self.counts_at(i);
+            positive[i] = (i as NodeId, pos);
+            negative[i] = (i as NodeId, neg);
+            if pos > 0 || neg > 0 {
+                used = i + 1;
+            }
+        }
+        (positive, negative, used)
+    }
+}
+
 // Serde implementation for PNCounter
 #[cfg(feature = "serde")]
 impl<C: MemoryConfig> Serialize for PNCounter<C> {
@@ -669,7 +1260,7 @@ impl<C: MemoryConfig> Serialize for PNCounter<C> {
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("PNCounter", 3)?;
+        let mut state = serializer.serialize_struct("PNCounter", 5)?;
 
         // Serialize the logical state (positive and negative counter values) as slices
         #[cfg(not(feature = "hardware-atomic"))]
@@ -692,6 +1283,8 @@ impl<C: MemoryConfig> Serialize for PNCounter<C> {
         }
 
         state.serialize_field("node_id", &self.node_id)?;
+        state.serialize_field("min", &self.min)?;
+        state.serialize_field("max", &self.max)?;
         state.end()
     }
 }
@@ -702,15 +1295,18 @@ impl<'de, C: MemoryConfig> Deserialize<'de> for PNCounter<C> {
     where
         D: Deserializer<'de>,
     {
+        use crate::serialization::fields::SeqFields;
         use core::fmt;
         use serde::de::{self, MapAccess, Visitor};
 
         #[derive(Deserialize)]
-        #[serde(field_identifier, rename_all = "lowercase")]
+        #[serde(field_identifier, rename_all = "snake_case")]
         enum Field {
             Positive,
             Negative,
             NodeId,
+            Min,
+            Max,
         }
 
         struct PNCounterVisitor<C: MemoryConfig> {
@@ -724,6 +1320,13 @@ impl<'de, C: MemoryConfig> Deserialize<'de> for PNCounter<C> {
                 formatter.write_str("struct PNCounter")
             }
 
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                self.visit_map(SeqFields::new(seq, FIELDS))
+            }
+
             fn visit_map<V>(self, mut map: V) -> Result<PNCounter<C>, V::Error>
             where
                 V: MapAccess<'de>,
@@ -731,6 +1334,8 @@ impl<'de, C: MemoryConfig> Deserialize<'de> for PNCounter<C> {
                 let mut positive = None;
                 let mut negative = None;
                 let mut node_id = None;
+                let mut min = None;
+                let mut max = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -858,12 +1463,27 @@ impl<'de, C: MemoryConfig> Deserialize<'de> for PNCounter<C> {
                             }
                             node_id = Some(map.next_value()?);
                         }
+                        Field::Min => {
+                            if min.is_some() {
+                                return Err(de::Error::duplicate_field("min"));
+                            }
+                            min = Some(map.next_value()?);
+                        }
+                        Field::Max => {
+                            if max.is_some() {
+                                return Err(de::Error::duplicate_field("max"));
+                            }
+                            max = Some(map.next_value()?);
+                        }
                     }
                 }
 
                 let positive = positive.ok_or_else(|| de::Error::missing_field("positive"))?;
                 let negative = negative.ok_or_else(|| de::Error::missing_field("negative"))?;
                 let node_id = node_id.ok_or_else(|| de::Error::missing_field("node_id"))?;
+                // Counters serialized before bounds existed are unbounded
+                let min = min.unwrap_or(i64::MIN);
+                let max = max.unwrap_or(i64::MAX);
 
                 // Reconstruct the PNCounter
                 #[cfg(not(feature = "hardware-atomic"))]
@@ -872,6 +1492,9 @@ impl<'de, C: MemoryConfig> Deserialize<'de> for PNCounter<C> {
                         positive,
                         negative,
                         node_id,
+                        min,
+                        max,
+                        last_reset: None,
                         _phantom: core::marker::PhantomData,
                     })
                 }
@@ -889,13 +1512,16 @@ impl<'de, C: MemoryConfig> Deserialize<'de> for PNCounter<C> {
                         positive: atomic_positive,
                         negative: atomic_negative,
                         node_id,
+                        min,
+                        max,
+                        last_reset: None,
                         _phantom: core::marker::PhantomData,
                     })
                 }
             }
         }
 
-        const FIELDS: &[&str] = &["positive", "negative", "node_id"];
+        const FIELDS: &[&str] = &["positive", "negative", "node_id", "min", "max"];
         deserializer.deserialize_struct(
             "PNCounter",
             FIELDS,
@@ -906,10 +1532,107 @@ impl<'de, C: MemoryConfig> Deserialize<'de> for PNCounter<C> {
     }
 }
 
+// Postcard size bound for PNCounter
+#[cfg(feature = "postcard")]
+impl<C: MemoryConfig> MaxSize for PNCounter<C> {
+    const POSTCARD_MAX_SIZE: usize = {
+        2 * seq_max_size(16, u32::POSTCARD_MAX_SIZE)
+            + NodeId::POSTCARD_MAX_SIZE
+            + 2 * i64::POSTCARD_MAX_SIZE
+    };
+}
+
+// CBOR implementation for PNCounter
+#[cfg(feature = "cbor")]
+impl<Ctx, C: MemoryConfig, const CAPACITY: usize> minicbor::Encode<Ctx> for PNCounter<C, CAPACITY> {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        _ctx: &mut Ctx,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        let positive = self.positive_counters();
+        let negative = self.negative_counters();
+
+        e.array(5)?;
+        e.u8(self.node_id)?;
+        for counters in [&positive, &negative] {
+            // Trailing zero slots carry no state
+            let used = counters.iter().rposition(|&v| v != 0).map_or(0, |i| i + 1);
+            e.array(used as u64)?;
+            for &value in &counters[..used] {
+                e.u32(value as u32)?;
+            }
+        }
+        e.i64(self.min)?;
+        e.i64(self.max)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<'b, Ctx, C: MemoryConfig, const CAPACITY: usize> minicbor::Decode<'b, Ctx>
+    for PNCounter<C, CAPACITY>
+{
+    fn decode(
+        d: &mut minicbor::Decoder<'b>,
+        _ctx: &mut Ctx,
+    ) -> Result<Self, minicbor::decode::Error> {
+        use crate::serialization::cbor;
+
+        cbor::expect_array_len(d, 5)?;
+
+        let node_id = d.u8()?;
+        if node_id as usize >= CAPACITY {
+            return Err(cbor::capacity_exceeded());
+        }
+
+        let mut positive = [0u32; CAPACITY];
+        let mut negative = [0u32; CAPACITY];
+        for counters in [&mut positive, &mut negative] {
+            let used = cbor::bounded_array_len(d, CAPACITY)?;
+            for slot in counters.iter_mut().take(used) {
+                *slot = d.u32()?;
+            }
+        }
+
+        let min = d.i64()?;
+        let max = d.i64()?;
+        if min > 0 || max < 0 {
+            return Err(cbor::invalid_state());
+        }
+
+        let mut counter = Self::with_bounds(node_id, min, max);
+        for i in 0..CAPACITY {
+            #[cfg(not(feature = "hardware-atomic"))]
+            {
+                counter.positive[i] = positive[i];
+                counter.negative[i] = negative[i];
+            }
+
+            #[cfg(feature = "hardware-atomic")]
+            {
+                *counter.positive[i].get_mut() = positive[i];
+                *counter.negative[i].get_mut() = negative[i];
+            }
+        }
+
+        Ok(counter)
+    }
+}
+
 impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for PNCounter<C, CAPACITY> {
     type Error = CRDTError;
 
     fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        // Both replicas must honour the tighter of the two ranges
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        if min > max {
+            return Err(CRDTError::InvalidMerge);
+        }
+        self.min = min;
+        self.max = max;
+
         // Take the maximum value for each node in both arrays
         #[cfg(not(feature = "hardware-atomic"))]
         {
@@ -955,6 +1678,8 @@ impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for PNCounter<C, CAPACITY>
                 }
             }
         }
+
+        self.clamp_to_bounds();
         Ok(())
     }
 
@@ -994,36 +1719,36 @@ impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for PNCounter<C, CAPACITY>
             return Err(CRDTError::InvalidNodeId);
         }
 
+        // Validate the stored counters respect the configured bounds
+        let value = self.value();
+        if self.min > self.max || value < self.min || value > self.max {
+            return Err(CRDTError::InvalidState);
+        }
+
         Ok(())
     }
 
     fn state_hash(&self) -> u32 {
-        // Simple hash based on counter values
-        let mut hash = 0u32;
+        // FNV-1a over the positive and negative counters in node order
+        let mut hasher = FnvHasher::new();
 
         #[cfg(not(feature = "hardware-atomic"))]
         {
-            for (i, (&pos, &neg)) in self.positive.iter().zip(self.negative.iter()).enumerate() {
-                if pos > 0 || neg > 0 {
-                    hash ^= pos ^ (neg << 8) ^ ((i as u32) << 16);
-                }
+            for (&pos, &neg) in self.positive.iter().zip(self.negative.iter()) {
+                hasher.write_u32(pos);
+                hasher.write_u32(neg);
             }
         }
 
         #[cfg(feature = "hardware-atomic")]
         {
-            for (i, (pos_atomic, neg_atomic)) in
-                self.positive.iter().zip(self.negative.iter()).enumerate()
-            {
-                let pos = pos_atomic.load(Ordering::Relaxed);
-                let neg = neg_atomic.load(Ordering::Relaxed);
-                if pos > 0 || neg > 0 {
-                    hash ^= pos ^ (neg << 8) ^ ((i as u32) << 16);
-                }
+            for (pos_atomic, neg_atomic) in self.positive.iter().zip(self.negative.iter()) {
+                hasher.write_u32(pos_atomic.load(Ordering::Relaxed));
+                hasher.write_u32(neg_atomic.load(Ordering::Relaxed));
             }
         }
 
-        hash
+        hasher.finish()
     }
 
     fn can_merge(&self, _other: &Self) -> bool {
@@ -1054,6 +1779,18 @@ impl<C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for PNCounter<C, CAP
         // For counters, we can always "add" (increment/decrement) if not at max capacity
         self.element_count() < Self::MAX_ELEMENTS
     }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        // One positive and one negative count per node
+        let slot = 2 * core::mem::size_of::<u32>();
+        let nodes = self.active_nodes();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            nodes * slot,
+            0,
+            (CAPACITY - nodes) * slot,
+        )
+    }
 }
 
 impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for PNCounter<C, CAPACITY> {
@@ -1081,6 +1818,19 @@ impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for PNCounter<C, CA
     }
 }
 
+impl<C: MemoryConfig, const CAPACITY: usize> Snapshotable<C> for PNCounter<C, CAPACITY> {
+    /// The state hash paired with the number of increments and decrements
+    /// applied, which only grows
+    type Snapshot = (u32, u64);
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (
+            self.state_hash(),
+            self.total_positive().wrapping_add(self.total_negative()),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1392,6 +2142,339 @@ mod tests {
         assert_eq!(counter1.node_negative(2), 2);
     }
 
+    #[test]
+    fn test_saturating_decrement() {
+        let mut counter = PNCounter::<DefaultConfig>::new(1);
+        counter.increment(5).unwrap();
+
+        assert_eq!(counter.saturating_decrement(3).unwrap(), 2);
+        assert_eq!(counter.saturating_decrement(10).unwrap(), 0);
+        assert_eq!(counter.value(), 0);
+        assert_eq!(counter.node_negative(1), 5);
+
+        // Already at zero: nothing is applied
+        assert_eq!(counter.saturating_decrement(1).unwrap(), 0);
+        assert_eq!(counter.node_negative(1), 5);
+    }
+
+    #[test]
+    fn test_saturating_increment() {
+        let mut counter = PNCounter::<DefaultConfig>::with_bounds(1, 0, 10);
+        assert_eq!(counter.saturating_increment(7).unwrap(), 7);
+        assert_eq!(counter.saturating_increment(7).unwrap(), 10);
+        assert_eq!(counter.node_positive(1), 10);
+
+        // Unbounded counters saturate at the node slot limit
+        let mut counter = PNCounter::<DefaultConfig>::new(1);
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            counter.positive[1] = u32::MAX - 1;
+        }
+        #[cfg(feature = "hardware-atomic")]
+        {
+            counter.positive[1].store(u32::MAX - 1, Ordering::Relaxed);
+        }
+        assert_eq!(counter.saturating_increment(5).unwrap(), u32::MAX as u64);
+
+        let invalid_counter = PNCounter::<DefaultConfig, 4>::with_capacity(5);
+        assert_eq!(
+            invalid_counter.saturating_increment_amount(1),
+            Err(CRDTError::InvalidNodeId)
+        );
+    }
+
+    #[test]
+    fn test_bounds_enforced_locally() {
+        let mut counter = PNCounter::<DefaultConfig>::with_bounds(1, -5, 10);
+        assert_eq!(counter.bounds(), (-5, 10));
+
+        counter.increment(10).unwrap();
+        assert_eq!(counter.increment(1), Err(CRDTError::ConfigurationExceeded));
+        counter.decrement(15).unwrap();
+        assert_eq!(counter.decrement(1), Err(CRDTError::ConfigurationExceeded));
+        assert_eq!(counter.value(), -5);
+
+        // Saturating decrement still stops at zero, not at the lower bound
+        counter.increment(3).unwrap();
+        assert_eq!(counter.saturating_decrement(10).unwrap(), 0);
+        assert_eq!(counter.value(), -2);
+    }
+
+    #[test]
+    fn test_merge_clamps_to_bounds() {
+        let mut counter1 = PNCounter::<DefaultConfig>::with_bounds(1, 0, 10);
+        let mut counter2 = PNCounter::<DefaultConfig>::with_bounds(2, 0, 10);
+        counter1.increment(8).unwrap();
+        counter2.increment(6).unwrap();
+
+        let mut merged1 = counter1.clone();
+        merged1.merge(&counter2).unwrap();
+        let mut merged2 = counter2.clone();
+        merged2.merge(&counter1).unwrap();
+
+        assert_eq!(merged1.value(), 10);
+        assert!(merged1.eq(&merged2));
+        assert!(merged1.validate().is_ok());
+
+        // Idempotent once clamped
+        let snapshot = merged1.clone();
+        merged1.merge(&snapshot).unwrap();
+        assert!(merged1.eq(&snapshot));
+    }
+
+    #[test]
+    fn test_merge_independently_clamped() {
+        let mut base1 = PNCounter::<DefaultConfig>::with_bounds(1, 0, 10);
+        let mut base2 = PNCounter::<DefaultConfig>::with_bounds(2, 0, 10);
+        let mut base3 = PNCounter::<DefaultConfig>::with_bounds(3, 0, 10);
+        base1.increment(9).unwrap();
+        base2.increment(7).unwrap();
+        base3.increment(5).unwrap();
+
+        // Two replicas clamp different merges on their own
+        let mut a = base1.clone();
+        a.merge(&base2).unwrap();
+        let mut b = base3.clone();
+        b.merge(&base2).unwrap();
+
+        let mut ab = a.clone();
+        ab.merge(&b).unwrap();
+        let mut ba = b.clone();
+        ba.merge(&a).unwrap();
+
+        assert!(ab.eq(&ba));
+        assert_eq!(ab.value(), 10);
+
+        let snapshot = ab.clone();
+        ab.merge(&snapshot).unwrap();
+        assert!(ab.eq(&snapshot));
+    }
+
+    #[test]
+    fn test_merge_lower_bound_and_bound_intersection() {
+        let mut counter1 = PNCounter::<DefaultConfig>::with_bounds(1, -4, 100);
+        let mut counter2 = PNCounter::<DefaultConfig>::with_bounds(2, -10, 50);
+        counter1.decrement(4).unwrap();
+        counter2.decrement(3).unwrap();
+
+        counter1.merge(&counter2).unwrap();
+        assert_eq!(counter1.bounds(), (-4, 50));
+        assert_eq!(counter1.value(), -4);
+
+        let disjoint = PNCounter::<DefaultConfig> {
+            min: 60,
+            max: 70,
+            ..PNCounter::new(3)
+        };
+        assert_eq!(counter1.merge(&disjoint), Err(CRDTError::InvalidMerge));
+    }
+
+    #[test]
+    fn test_validate_bounds() {
+        let mut counter = PNCounter::<DefaultConfig>::with_bounds(1, 0, 10);
+        counter.increment(10).unwrap();
+        assert!(counter.validate().is_ok());
+
+        // Stored counters outside the bounds are inconsistent
+        counter.max = 5;
+        assert_eq!(counter.validate(), Err(CRDTError::InvalidState));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut counter = PNCounter::<DefaultConfig>::new(1);
+        counter.increment(5).unwrap();
+        let snap = counter.snapshot();
+        assert!(!counter.has_changed_since(&snap));
+
+        // Back to the same value, but the state has moved on
+        counter.increment(3).unwrap();
+        counter.decrement(3).unwrap();
+        assert_eq!(counter.value(), 5);
+        assert!(counter.has_changed_since(&snap));
+    }
+
+    #[test]
+    fn test_contributions() {
+        let mut counter = PNCounter::<DefaultConfig>::new(1);
+        assert_eq!(counter.max_contributor(), None);
+        assert_eq!(counter.contributions_iter().count(), 0);
+
+        counter.increment(4).unwrap();
+        counter.decrement(1).unwrap();
+
+        let mut other = PNCounter::<DefaultConfig>::new(3);
+        other.increment(9).unwrap();
+        let mut decrementer = PNCounter::<DefaultConfig>::new(5);
+        decrementer.decrement(2).unwrap();
+        counter.merge(&other).unwrap();
+        counter.merge(&decrementer).unwrap();
+
+        assert_eq!(counter.positive_contribution_of(1), 4);
+        assert_eq!(counter.negative_contribution_of(1), 1);
+        assert_eq!(counter.positive_contribution_of(3), 9);
+        assert_eq!(counter.negative_contribution_of(5), 2);
+        assert_eq!(counter.positive_contribution_of(200), 0);
+        assert_eq!(counter.max_contributor(), Some((3, 9)));
+
+        let mut contributions = [(0, 0, 0); 3];
+        for (slot, contribution) in contributions.iter_mut().zip(counter.contributions_iter()) {
+            *slot = contribution;
+        }
+        assert_eq!(contributions, [(1, 4, 1), (3, 9, 0), (5, 0, 2)]);
+        assert_eq!(counter.contributions_iter().count(), 3);
+
+        // Ties go to the lowest node ID
+        let mut tie = PNCounter::<DefaultConfig>::new(7);
+        tie.increment(9).unwrap();
+        counter.merge(&tie).unwrap();
+        assert_eq!(counter.max_contributor(), Some((3, 9)));
+    }
+
+    #[test]
+    fn test_reset_node() {
+        let mut counter = PNCounter::<DefaultConfig>::new(1);
+        counter.increment(10).unwrap();
+        counter.decrement(4).unwrap();
+        assert_eq!(counter.last_reset(), None);
+
+        // Only this node's counters may be reset
+        assert_eq!(counter.reset_node(2, 1000), Err(CRDTError::InvalidNodeId));
+
+        counter.reset_node(1, 1000).unwrap();
+        assert_eq!(counter.value(), 0);
+        assert!(counter.is_empty());
+        assert_eq!(counter.last_reset(), Some(1000));
+
+        // Stale resets are rejected
+        assert_eq!(
+            counter.reset_node(1, 1000),
+            Err(CRDTError::InvalidOperation)
+        );
+
+        // Re-incrementing after the reset merges like a fresh counter
+        counter.increment(3).unwrap();
+        let mut other = PNCounter::<DefaultConfig>::new(2);
+        other.increment(5).unwrap();
+        other.decrement(1).unwrap();
+
+        let mut merged = other.clone();
+        merged.merge(&counter).unwrap();
+        counter.merge(&other).unwrap();
+
+        assert_eq!(counter.value(), 7);
+        assert_eq!(merged.value(), 7);
+        assert!(counter.eq(&merged));
+        assert_eq!(counter.positive_contribution_of(1), 3);
+        assert_eq!(counter.negative_contribution_of(1), 0);
+    }
+
+    #[test]
+    fn test_reset_node_respects_bounds() {
+        let mut counter = PNCounter::<DefaultConfig>::with_bounds(1, 0, 100);
+        counter.increment(10).unwrap();
+
+        // Node 2 decrements against node 1's increments
+        let mut other = PNCounter::<DefaultConfig>::with_bounds(2, 0, 100);
+        other.merge(&counter).unwrap();
+        other.decrement(8).unwrap();
+        counter.merge(&other).unwrap();
+
+        // Dropping node 1's increments would take the value to -8
+        assert_eq!(
+            counter.reset_node(1, 1000),
+            Err(CRDTError::InvalidOperation)
+        );
+        assert_eq!(counter.value(), 2);
+        assert_eq!(counter.last_reset(), None);
+    }
+
+    #[test]
+    fn test_slice_encoding() {
+        let mut counter = PNCounter::<DefaultConfig>::with_bounds(3, -10, 10);
+        counter.increment(4).unwrap();
+        let mut other = PNCounter::<DefaultConfig>::new(1);
+        other.decrement(9).unwrap();
+        counter.merge(&other).unwrap();
+        assert_eq!(PNCounter::<DefaultConfig>::ENCODE_MAX_BYTES, 3 + 16 * 8);
+
+        let mut buf = [0u8; 64];
+        let len = counter.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(len, 3 + 4 * 8);
+        assert_eq!(&buf[..3], &[1, 3, 4]);
+        // Node 1's decrement is the second negative slot
+        assert_eq!(&buf[3 + 16 + 4..3 + 16 + 8], &9u32.to_le_bytes());
+
+        // Bounds are not encoded
+        let decoded = PNCounter::<DefaultConfig>::decode_from_slice(&buf[..len]).unwrap();
+        assert!(decoded.eq(&counter));
+        assert_eq!(decoded.value(), -5);
+        assert_eq!(decoded.bounds(), (i64::MIN, i64::MAX));
+
+        assert_eq!(
+            counter.encode_to_slice(&mut buf[..len - 1]),
+            Err(CRDTError::BufferOverflow)
+        );
+        assert_eq!(
+            PNCounter::<DefaultConfig>::decode_from_slice(&buf[..2]).err(),
+            Some(CRDTError::BufferOverflow)
+        );
+
+        // An empty counter is just the header
+        let empty = PNCounter::<DefaultConfig>::new(0);
+        assert_eq!(empty.encode_to_slice(&mut buf).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_raw_arrays_and_codec_round_trip() {
+        type Counter = PNCounter<DefaultConfig>;
+        let mut buf = [0u8; Counter::ENCODE_MAX_BYTES];
+
+        // Every node count from empty to full
+        for used in 0..=DefaultConfig::MAX_NODES {
+            let positive: [(NodeId, u32); 16] =
+                core::array::from_fn(|i| (i as NodeId, (i as u32 + 1) * 1_000_000));
+            let negative: [(NodeId, u32); 16] =
+                core::array::from_fn(|i| (i as NodeId, u32::MAX - i as u32));
+            let counter =
+                Counter::from_positive_negative_arrays(3, &positive[..used], &negative[..used])
+                    .unwrap();
+
+            let (pos, neg, count) = counter.to_positive_negative_arrays();
+            assert_eq!(count, used);
+            assert_eq!(&pos[..count], &positive[..used]);
+            assert_eq!(&neg[..count], &negative[..used]);
+
+            let len = counter.encode_to_slice(&mut buf).unwrap();
+            assert_eq!(len, 3 + used * 8);
+            assert_eq!(buf[2] as usize, used);
+            let decoded = Counter::decode_from_slice(&buf[..len]).unwrap();
+            assert!(decoded.eq(&counter));
+            assert_eq!(decoded.node_id(), 3);
+        }
+
+        // Duplicates keep the larger count
+        let counter = Counter::from_positive_negative_arrays(0, &[(1, 5), (1, 3)], &[]).unwrap();
+        assert_eq!(counter.node_positive(1), 5);
+
+        assert_eq!(
+            Counter::from_positive_negative_arrays(16, &[], &[]).err(),
+            Some(CRDTError::InvalidNodeId)
+        );
+        assert_eq!(
+            Counter::from_positive_negative_arrays(0, &[], &[(16, 1)]).err(),
+            Some(CRDTError::InvalidNodeId)
+        );
+
+        // Unknown version
+        let len = counter.encode_to_slice(&mut buf).unwrap();
+        buf[0] = 2;
+        assert_eq!(
+            Counter::decode_from_slice(&buf[..len]).err(),
+            Some(CRDTError::InvalidState)
+        );
+    }
+
     #[cfg(all(test, feature = "serde"))]
     mod serde_tests {
         use super::*;
@@ -0,0 +1,313 @@
+//! Presence Bitmap CRDT
+//!
+//! A grow-only set of node IDs stored as one bit per node. It replaces a
+//! [`GCounter`] when only *whether* a node has reported in matters, not how
+//! often, e.g. for node discovery on IoT gateways.
+
+use crate::clock::CompactTimestamp;
+use crate::counters::GCounter;
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::traits::CRDT;
+
+/// Maximum number of nodes a [`PresenceBitmap`] can track
+pub const MAX_PRESENCE_NODES: usize = 64;
+
+/// Bytes of bit storage backing every [`PresenceBitmap`]
+const PRESENCE_BYTES: usize = MAX_PRESENCE_NODES / 8;
+
+/// Grow-only presence bitmap with one bit per node
+///
+/// Once a node is marked present it stays present; merge is a bitwise OR.
+/// The bitmap also remembers the latest timestamp at which any node was
+/// marked, so a gateway can tell how fresh its view is.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `CAPACITY`: The number of node IDs tracked (at most [`MAX_PRESENCE_NODES`])
+///
+/// # Memory Usage
+/// - Encoded size: `(CAPACITY + 7) / 8 + 8` bytes, see [`CRDT::size_bytes`]
+/// - Example: 12 bytes for 32 nodes, against 128+ bytes for a `GCounter`
+/// - The bitmap is `Copy`
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut gateway_a = PresenceBitmap::<DefaultConfig>::new(0);
+/// let mut gateway_b = PresenceBitmap::<DefaultConfig>::new(1);
+///
+/// gateway_a.mark_present(3, 1000)?;
+/// gateway_b.mark_present(7, 1200)?;
+/// gateway_a.merge(&gateway_b)?;
+///
+/// assert!(gateway_a.is_present(7));
+/// assert_eq!(gateway_a.present_count(), 2);
+/// assert_eq!(gateway_a.size_bytes(), 12);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PresenceBitmap<C: MemoryConfig, const CAPACITY: usize = 32> {
+    /// One bit per node ID, least significant bit first
+    bits: [u8; PRESENCE_BYTES],
+
+    /// This node's ID
+    node_id: NodeId,
+
+    /// Latest timestamp at which a node was marked present
+    last_seen: CompactTimestamp,
+
+    /// Phantom data to maintain the memory config type
+    _phantom: core::marker::PhantomData<C>,
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> PresenceBitmap<C, CAPACITY> {
+    /// Creates a new, empty presence bitmap with custom capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < CAPACITY)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let presence = PresenceBitmap::<DefaultConfig, 64>::with_capacity(1);
+    /// assert_eq!(presence.capacity(), 64);
+    /// ```
+    pub fn with_capacity(node_id: NodeId) -> Self {
+        const {
+            assert!(
+                CAPACITY <= MAX_PRESENCE_NODES,
+                "PresenceBitmap CAPACITY cannot exceed MAX_PRESENCE_NODES"
+            )
+        };
+
+        Self {
+            bits: [0; PRESENCE_BYTES],
+            node_id,
+            last_seen: CompactTimestamp::new(0),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Marks a node as present
+    ///
+    /// # Arguments
+    /// * `node_id` - The node that reported in
+    /// * `timestamp` - When it reported in
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::InvalidNodeId` if `node_id` is
+    /// not below CAPACITY
+    pub fn mark_present(&mut self, node_id: NodeId, timestamp: u64) -> CRDTResult<()> {
+        let index = node_id as usize;
+        if index >= CAPACITY {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        self.bits[index / 8] |= 1 << (index % 8);
+        self.last_seen = self.last_seen.max(CompactTimestamp::new(timestamp));
+        Ok(())
+    }
+
+    /// Checks if a node has been marked present
+    pub fn is_present(&self, node_id: NodeId) -> bool {
+        let index = node_id as usize;
+        index < CAPACITY && self.bits[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Returns the number of nodes marked present
+    pub fn present_count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterates over the nodes marked present, in ascending order
+    pub fn iter_present(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..CAPACITY as NodeId).filter(move |&node| self.is_present(node))
+    }
+
+    /// Iterates over the nodes not yet marked present, in ascending order
+    pub fn iter_absent(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..CAPACITY as NodeId).filter(move |&node| !self.is_present(node))
+    }
+
+    /// Returns the latest timestamp at which a node was marked present
+    pub fn last_seen(&self) -> CompactTimestamp {
+        self.last_seen
+    }
+
+    /// Returns this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Returns the number of node IDs this bitmap can track
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Checks if no node has been marked present
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&byte| byte == 0)
+    }
+}
+
+impl<C: MemoryConfig> PresenceBitmap<C, 32> {
+    /// Creates a new, empty presence bitmap with default capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < 32)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let presence = PresenceBitmap::<DefaultConfig>::new(1);
+    /// assert!(presence.is_empty());
+    /// ```
+    pub fn new(node_id: NodeId) -> Self {
+        Self::with_capacity(node_id)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> From<GCounter<C, CAPACITY>>
+    for PresenceBitmap<C, CAPACITY>
+{
+    /// Marks every node with a non-zero count as present
+    ///
+    /// Counters carry no timestamps, so the bitmap starts with a `last_seen`
+    /// of zero.
+    fn from(counter: GCounter<C, CAPACITY>) -> Self {
+        let mut presence = Self::with_capacity(counter.node_id());
+        for index in 0..CAPACITY {
+            if counter.node_value(index as NodeId) > 0 {
+                presence.bits[index / 8] |= 1 << (index % 8);
+            }
+        }
+        presence
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for PresenceBitmap<C, CAPACITY> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        for (bits, other_bits) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *bits |= other_bits;
+        }
+        self.last_seen = self.last_seen.max(other.last_seen);
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits && self.last_seen == other.last_seen
+    }
+
+    fn size_bytes(&self) -> usize {
+        // Bits for CAPACITY nodes plus the timestamp
+        CAPACITY.div_ceil(8) + core::mem::size_of::<CompactTimestamp>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        if self.node_id as usize >= CAPACITY {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        // No bits beyond CAPACITY may be set
+        for index in CAPACITY..MAX_PRESENCE_NODES {
+            if self.bits[index / 8] & (1 << (index % 8)) != 0 {
+                return Err(CRDTError::InvalidState);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        let low = u32::from_le_bytes([self.bits[0], self.bits[1], self.bits[2], self.bits[3]]);
+        let high = u32::from_le_bytes([self.bits[4], self.bits[5], self.bits[6], self.bits[7]]);
+        low ^ high.rotate_left(16) ^ (self.last_seen.as_u64() as u32)
+    }
+
+    fn can_merge(&self, _other: &Self) -> bool {
+        // Bitwise OR always succeeds
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_mark_and_iterate() {
+        let mut presence = PresenceBitmap::<DefaultConfig, 10>::with_capacity(0);
+        presence.mark_present(0, 100).unwrap();
+        presence.mark_present(9, 300).unwrap();
+        presence.mark_present(4, 200).unwrap();
+        presence.mark_present(4, 250).unwrap();
+
+        assert_eq!(presence.present_count(), 3);
+        assert!(presence.is_present(4));
+        assert!(!presence.is_present(5));
+        assert!(!presence.is_present(200));
+        assert_eq!(presence.last_seen().as_u64(), 300);
+
+        let mut present = presence.iter_present();
+        assert_eq!(present.next(), Some(0));
+        assert_eq!(present.next(), Some(4));
+        assert_eq!(present.next(), Some(9));
+        assert_eq!(present.next(), None);
+        drop(present);
+        assert_eq!(presence.iter_absent().count(), 7);
+
+        assert_eq!(
+            presence.mark_present(10, 400),
+            Err(CRDTError::InvalidNodeId)
+        );
+        assert!(presence.validate().is_ok());
+    }
+
+    #[test]
+    fn test_merge_is_bitwise_or() {
+        let mut a = PresenceBitmap::<DefaultConfig>::new(0);
+        let mut b = PresenceBitmap::<DefaultConfig>::new(1);
+        a.mark_present(1, 1000).unwrap();
+        a.mark_present(20, 1000).unwrap();
+        b.mark_present(20, 2000).unwrap();
+        b.mark_present(31, 2000).unwrap();
+
+        let mut ab = a;
+        ab.merge(&b).unwrap();
+        let mut ba = b;
+        ba.merge(&a).unwrap();
+
+        assert_eq!(ab.present_count(), 3);
+        assert!(ab.eq(&ba));
+        assert_eq!(ab.state_hash(), ba.state_hash());
+
+        // Idempotent
+        let before = ab;
+        ab.merge(&before).unwrap();
+        assert!(ab.eq(&before));
+    }
+
+    #[test]
+    fn test_from_gcounter() {
+        let mut counter = GCounter::<DefaultConfig, 32>::with_capacity(2);
+        counter.increment(5).unwrap();
+        let mut other = GCounter::<DefaultConfig, 32>::with_capacity(17);
+        other.increment(1).unwrap();
+        counter.merge(&other).unwrap();
+
+        let presence = PresenceBitmap::from(counter);
+        assert_eq!(presence.node_id(), 2);
+        assert_eq!(presence.present_count(), 2);
+        assert!(presence.is_present(2));
+        assert!(presence.is_present(17));
+        assert_eq!(presence.size_bytes(), 12);
+    }
+}
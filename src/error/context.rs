@@ -0,0 +1,77 @@
+//! Error context for diagnostics
+//!
+//! This module defines the context attached to errors raised by capacity-bound
+//! CRDT operations, so a `BufferOverflow` can say which CRDT overflowed and
+//! how full it was.
+
+use crate::error::CRDTError;
+use crate::memory::NodeId;
+
+/// Where and why an error was raised
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// CRDT type that raised the error, e.g. `"ORSet"`
+    pub crdt_type: &'static str,
+    /// Operation that failed, e.g. `"add"`
+    pub operation: &'static str,
+    /// Node the CRDT belongs to
+    pub node_id: NodeId,
+    /// Fill level when the error was raised: the number of elements, a
+    /// counter's value, or the offending node index
+    pub current_count: usize,
+    /// Limit that `current_count` is measured against
+    pub capacity: usize,
+}
+
+impl ErrorContext {
+    /// Creates a new error context
+    pub const fn new(
+        crdt_type: &'static str,
+        operation: &'static str,
+        node_id: NodeId,
+        current_count: usize,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            crdt_type,
+            operation,
+            node_id,
+            current_count,
+            capacity,
+        }
+    }
+}
+
+/// An error together with the context it was raised in
+///
+/// Created by [`CRDTError::with_context`]. The cause is kept as a `'static`
+/// reference so that [`CRDTError`] stays a fixed-size `Copy` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextualError {
+    /// The underlying error
+    cause: &'static CRDTError,
+    /// Where the error was raised
+    context: ErrorContext,
+}
+
+impl ContextualError {
+    /// Creates a contextual error
+    pub(crate) const fn new(cause: &'static CRDTError, context: ErrorContext) -> Self {
+        Self { cause, context }
+    }
+
+    /// Returns the underlying error
+    pub const fn cause(&self) -> CRDTError {
+        *self.cause
+    }
+
+    /// Returns the underlying error by reference
+    pub(crate) const fn cause_ref(&self) -> &'static CRDTError {
+        self.cause
+    }
+
+    /// Returns where the error was raised
+    pub const fn context(&self) -> &ErrorContext {
+        &self.context
+    }
+}
@@ -2,12 +2,14 @@
 //!
 //! This module provides comprehensive error types for multi-domain embedded CRDT operations.
 
+pub mod context;
 pub mod platform;
 pub mod realtime;
 pub mod safety;
 pub mod types;
 
 // Re-export main types
+pub use context::{ContextualError, ErrorContext};
 pub use platform::PlatformError;
 pub use realtime::RealTimeError;
 pub use safety::SafetyError;
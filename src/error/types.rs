@@ -2,13 +2,18 @@
 //!
 //! This module defines the main error types used throughout the library.
 
-use crate::error::{PlatformError, RealTimeError, SafetyError};
+use crate::error::{ContextualError, ErrorContext, PlatformError, RealTimeError, SafetyError};
 
 /// Main error type for CRDT operations
 ///
 /// This enum encompasses all possible errors that can occur during CRDT operations
 /// across different platforms and domains.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Errors may carry an [`ErrorContext`] (see [`with_context`](Self::with_context)).
+/// The context is diagnostic only: a contextual error compares equal to its
+/// [`cause`](Self::cause), and the classification methods look through it.
+/// Match on `error.cause()` rather than `error` to ignore the context.
+#[derive(Debug, Clone, Copy)]
 pub enum CRDTError {
     // Memory-related errors
     /// Out of memory in static allocation pools
@@ -29,6 +34,8 @@ pub enum CRDTError {
     InterruptOverrun,
     /// Platform-specific timeout
     PlatformSpecificTimeout,
+    /// Operation took more CPU cycles than its real-time budget
+    TimeBudgetExceeded,
 
     // Multi-domain safety errors
     /// Safety violation detected
@@ -55,6 +62,8 @@ pub enum CRDTError {
     InvalidState,
     /// Invalid operation attempted
     InvalidOperation,
+    /// Counter arithmetic overflow
+    Overflow,
 
     // Platform-specific errors
     /// Platform not supported
@@ -67,9 +76,82 @@ pub enum CRDTError {
     // Real-time specific errors
     /// Real-time constraint violation
     RealTimeViolation(RealTimeError),
+
+    // Diagnostics
+    /// Error with the CRDT and operation that raised it
+    Contextual(ContextualError),
 }
 
 impl CRDTError {
+    /// Attaches the context the error was raised in
+    ///
+    /// Replaces any context already attached. Errors that carry their own
+    /// detail (`PlatformNotSupported`, `RealTimeViolation`) are returned
+    /// unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::error::{CRDTError, ErrorContext};
+    ///
+    /// let ctx = ErrorContext::new("ORSet", "add", 1, 8, 8);
+    /// let error = CRDTError::BufferOverflow.with_context(ctx);
+    /// assert_eq!(error, CRDTError::BufferOverflow);
+    /// assert_eq!(error.context().map(|c| c.operation), Some("add"));
+    /// ```
+    pub const fn with_context(self, ctx: ErrorContext) -> CRDTError {
+        match self.static_cause() {
+            Some(cause) => Self::Contextual(ContextualError::new(cause, ctx)),
+            None => self,
+        }
+    }
+
+    /// Returns the error without any attached context
+    pub const fn cause(&self) -> CRDTError {
+        match self {
+            Self::Contextual(error) => error.cause(),
+            _ => *self,
+        }
+    }
+
+    /// Returns the attached context, if any
+    pub const fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::Contextual(error) => Some(error.context()),
+            _ => None,
+        }
+    }
+
+    /// Gets a `'static` copy of the cause, if it carries no data
+    const fn static_cause(&self) -> Option<&'static CRDTError> {
+        Some(match self {
+            Self::OutOfMemory => &Self::OutOfMemory,
+            Self::InvalidAlignment => &Self::InvalidAlignment,
+            Self::BufferOverflow => &Self::BufferOverflow,
+            Self::ConfigurationExceeded => &Self::ConfigurationExceeded,
+            Self::DeadlineExceeded => &Self::DeadlineExceeded,
+            Self::LockTimeout => &Self::LockTimeout,
+            Self::InterruptOverrun => &Self::InterruptOverrun,
+            Self::PlatformSpecificTimeout => &Self::PlatformSpecificTimeout,
+            Self::TimeBudgetExceeded => &Self::TimeBudgetExceeded,
+            Self::SafetyViolation => &Self::SafetyViolation,
+            Self::IntegrityCheckFailed => &Self::IntegrityCheckFailed,
+            Self::InvalidSafetyLevel => &Self::InvalidSafetyLevel,
+            Self::DomainSpecificError => &Self::DomainSpecificError,
+            Self::ClockSkew => &Self::ClockSkew,
+            Self::InvalidMerge => &Self::InvalidMerge,
+            Self::CausalityViolation => &Self::CausalityViolation,
+            Self::NodeCountExceeded => &Self::NodeCountExceeded,
+            Self::InvalidNodeId => &Self::InvalidNodeId,
+            Self::InvalidState => &Self::InvalidState,
+            Self::InvalidOperation => &Self::InvalidOperation,
+            Self::Overflow => &Self::Overflow,
+            Self::HardwareFeatureUnavailable => &Self::HardwareFeatureUnavailable,
+            Self::ConfigurationMismatch => &Self::ConfigurationMismatch,
+            Self::Contextual(error) => error.cause_ref(),
+            Self::PlatformNotSupported(_) | Self::RealTimeViolation(_) => return None,
+        })
+    }
+
     /// Returns true if this is a recoverable error
     pub const fn is_recoverable(&self) -> bool {
         match self {
@@ -77,6 +159,7 @@ impl CRDTError {
             Self::OutOfMemory
             | Self::BufferOverflow
             | Self::ConfigurationExceeded
+            | Self::Overflow
             | Self::SafetyViolation
             | Self::IntegrityCheckFailed
             | Self::CausalityViolation
@@ -89,6 +172,7 @@ impl CRDTError {
             | Self::LockTimeout
             | Self::InterruptOverrun
             | Self::PlatformSpecificTimeout
+            | Self::TimeBudgetExceeded
             | Self::InvalidSafetyLevel
             | Self::DomainSpecificError
             | Self::ClockSkew
@@ -99,6 +183,8 @@ impl CRDTError {
             | Self::InvalidOperation
             | Self::HardwareFeatureUnavailable
             | Self::RealTimeViolation(_) => true,
+
+            Self::Contextual(error) => error.cause().is_recoverable(),
         }
     }
 
@@ -108,7 +194,9 @@ impl CRDTError {
             Self::SafetyViolation
             | Self::IntegrityCheckFailed
             | Self::CausalityViolation
-            | Self::BufferOverflow => true,
+            | Self::BufferOverflow
+            | Self::Overflow => true,
+            Self::Contextual(error) => error.cause().is_safety_critical(),
             _ => false,
         }
     }
@@ -120,7 +208,9 @@ impl CRDTError {
             | Self::LockTimeout
             | Self::InterruptOverrun
             | Self::PlatformSpecificTimeout
+            | Self::TimeBudgetExceeded
             | Self::RealTimeViolation(_) => true,
+            Self::Contextual(error) => error.cause().is_realtime_error(),
             _ => false,
         }
     }
@@ -131,6 +221,7 @@ impl CRDTError {
             Self::PlatformNotSupported(_)
             | Self::HardwareFeatureUnavailable
             | Self::PlatformSpecificTimeout => true,
+            Self::Contextual(error) => error.cause().is_platform_error(),
             _ => false,
         }
     }
@@ -147,6 +238,7 @@ impl CRDTError {
             | Self::LockTimeout
             | Self::InterruptOverrun
             | Self::PlatformSpecificTimeout
+            | Self::TimeBudgetExceeded
             | Self::RealTimeViolation(_) => "RealTime",
 
             Self::SafetyViolation
@@ -160,15 +252,30 @@ impl CRDTError {
             | Self::NodeCountExceeded
             | Self::InvalidNodeId
             | Self::InvalidState
-            | Self::InvalidOperation => "CRDT",
+            | Self::InvalidOperation
+            | Self::Overflow => "CRDT",
 
             Self::PlatformNotSupported(_)
             | Self::HardwareFeatureUnavailable
             | Self::ConfigurationMismatch => "Platform",
+
+            Self::Contextual(error) => error.cause().category(),
+        }
+    }
+}
+
+impl PartialEq for CRDTError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.cause(), other.cause()) {
+            (Self::PlatformNotSupported(a), Self::PlatformNotSupported(b)) => a == b,
+            (Self::RealTimeViolation(a), Self::RealTimeViolation(b)) => a == b,
+            (a, b) => core::mem::discriminant(&a) == core::mem::discriminant(&b),
         }
     }
 }
 
+impl Eq for CRDTError {}
+
 impl From<SafetyError> for CRDTError {
     fn from(_error: SafetyError) -> Self {
         CRDTError::SafetyViolation
@@ -215,6 +322,34 @@ mod tests {
         assert_eq!(CRDTError::DeadlineExceeded.category(), "RealTime");
         assert_eq!(CRDTError::IntegrityCheckFailed.category(), "Safety");
         assert_eq!(CRDTError::ClockSkew.category(), "CRDT");
+        assert_eq!(CRDTError::Overflow.category(), "CRDT");
         assert_eq!(CRDTError::HardwareFeatureUnavailable.category(), "Platform");
     }
+
+    #[test]
+    fn test_error_context() {
+        let ctx = ErrorContext::new("LWWMap", "insert", 3, 8, 8);
+        let error = CRDTError::BufferOverflow.with_context(ctx);
+
+        assert_eq!(error.cause(), CRDTError::BufferOverflow);
+        assert_eq!(error.context(), Some(&ctx));
+        assert!(matches!(error.cause(), CRDTError::BufferOverflow));
+        assert_eq!(error, CRDTError::BufferOverflow);
+        assert_ne!(error, CRDTError::Overflow);
+        assert!(!error.is_recoverable());
+        assert!(error.is_safety_critical());
+        assert_eq!(error.category(), "Memory");
+
+        // A new context replaces the old one instead of nesting
+        let outer = ErrorContext::new("RecipeRegister", "apply", 3, 8, 8);
+        let rewrapped = error.with_context(outer);
+        assert_eq!(rewrapped.context(), Some(&outer));
+        assert_eq!(rewrapped.cause(), CRDTError::BufferOverflow);
+
+        // Errors with their own detail are left alone
+        let realtime = CRDTError::RealTimeViolation(RealTimeError::WatchdogTimeout);
+        assert_eq!(realtime.with_context(ctx).context(), None);
+        assert_eq!(CRDTError::Overflow.context(), None);
+        assert_eq!(CRDTError::Overflow.cause(), CRDTError::Overflow);
+    }
 }
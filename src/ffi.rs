@@ -0,0 +1,604 @@
+//! C bindings
+//!
+//! Exposes [`GCounter`], [`LWWRegister<u32>`](LWWRegister) and
+//! [`LWWMap<u8, u32>`](LWWMap) to C code, all with [`DefaultConfig`]. The C
+//! header is checked in as `include/crdtosphere.h`; regenerate it with
+//! `make header` (needs the `cbindgen` CLI) after changing this module.
+//!
+//! Handles are opaque pointers into fixed pools of [`CRDTOSPHERE_POOL_CAPACITY`] slots
+//! per type, so no heap is needed. A `*_new` function returns NULL when its
+//! pool is exhausted or the node ID is out of range, and every handle must
+//! be released with the matching `*_free` function to return its slot.
+//!
+//! Functions returning `int32_t` return [`CRDTOSPHERE_OK`] on success and a
+//! negative `CRDTOSPHERE_ERR_*` code on failure. Pointers that do not refer
+//! to a live handle of the right type, including NULL, are rejected with
+//! [`CRDTOSPHERE_ERR_INVALID_HANDLE`].
+//!
+//! Slots are claimed with atomic compare-and-swap, so this module needs a
+//! target with CAS support.
+//!
+//! # Example
+//! ```c
+//! #include "crdtosphere.h"
+//!
+//! GCounterHandle *local = crdtosphere_gcounter_new(1);
+//! GCounterHandle *remote = crdtosphere_gcounter_new(2);
+//!
+//! crdtosphere_gcounter_increment(local, 3);
+//! crdtosphere_gcounter_increment(remote, 4);
+//! if (crdtosphere_gcounter_merge(local, remote) == CRDTOSPHERE_OK) {
+//!     uint64_t total = crdtosphere_gcounter_value(local); // 7
+//! }
+//!
+//! crdtosphere_gcounter_free(remote);
+//! crdtosphere_gcounter_free(local);
+//! ```
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::counters::GCounter;
+use crate::error::CRDTError;
+use crate::maps::LWWMap;
+use crate::memory::{DefaultConfig, MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::traits::CRDT;
+
+/// Number of live handles available per CRDT type
+pub const CRDTOSPHERE_POOL_CAPACITY: usize = 8;
+
+/// Operation succeeded
+pub const CRDTOSPHERE_OK: i32 = 0;
+/// Handle is NULL, already freed, or of the wrong type
+pub const CRDTOSPHERE_ERR_INVALID_HANDLE: i32 = -1;
+/// Output pointer is NULL
+pub const CRDTOSPHERE_ERR_NULL_POINTER: i32 = -2;
+/// Register or key holds no value
+pub const CRDTOSPHERE_ERR_NOT_FOUND: i32 = -3;
+/// Fixed capacity exhausted
+pub const CRDTOSPHERE_ERR_BUFFER_OVERFLOW: i32 = -4;
+/// Counter arithmetic overflow
+pub const CRDTOSPHERE_ERR_OVERFLOW: i32 = -5;
+/// Node ID out of range
+pub const CRDTOSPHERE_ERR_INVALID_NODE_ID: i32 = -6;
+/// Operation not allowed, such as incrementing by zero
+pub const CRDTOSPHERE_ERR_INVALID_OPERATION: i32 = -7;
+/// Any other error
+pub const CRDTOSPHERE_ERR_OTHER: i32 = -100;
+
+/// Opaque handle to a `GCounter<DefaultConfig>`
+pub struct GCounterHandle(GCounter<DefaultConfig>);
+
+/// Opaque handle to an `LWWRegister<u32, DefaultConfig>`
+pub struct LWWRegisterHandle(LWWRegister<u32, DefaultConfig>);
+
+/// Opaque handle to an `LWWMap<u8, u32, DefaultConfig>`
+pub struct LWWMapHandle(LWWMap<u8, u32, DefaultConfig>);
+
+static GCOUNTERS: HandlePool<GCounterHandle, CRDTOSPHERE_POOL_CAPACITY> = HandlePool::new();
+static LWW_REGISTERS: HandlePool<LWWRegisterHandle, CRDTOSPHERE_POOL_CAPACITY> = HandlePool::new();
+static LWW_MAPS: HandlePool<LWWMapHandle, CRDTOSPHERE_POOL_CAPACITY> = HandlePool::new();
+
+/// Fixed pool of handle slots
+struct HandlePool<T, const N: usize> {
+    /// Handle storage, initialized while the slot is in use
+    slots: [UnsafeCell<MaybeUninit<T>>; N],
+
+    /// Marks the slots holding a live handle
+    in_use: [AtomicBool; N],
+}
+
+// SAFETY: A slot is only written by the caller that claimed it through
+// `in_use`, and callers of the FFI functions must not use one handle from
+// several threads at once
+unsafe impl<T: Send, const N: usize> Sync for HandlePool<T, N> {}
+
+impl<T, const N: usize> HandlePool<T, N> {
+    /// Creates a pool with all slots free
+    const fn new() -> Self {
+        Self {
+            slots: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+            in_use: [const { AtomicBool::new(false) }; N],
+        }
+    }
+
+    /// Moves a value into a free slot
+    ///
+    /// # Returns
+    /// The handle, or NULL if every slot is in use
+    fn alloc(&self, value: T) -> *mut T {
+        for (slot, in_use) in self.slots.iter().zip(&self.in_use) {
+            if in_use
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                let handle = slot.get().cast::<T>();
+                // SAFETY: The slot was free and is now claimed by this call
+                unsafe { handle.write(value) };
+                return handle;
+            }
+        }
+        ptr::null_mut()
+    }
+
+    /// Looks up a live handle
+    ///
+    /// # Returns
+    /// The slot pointer if `handle` refers to a live slot of this pool
+    fn resolve(&self, handle: *const T) -> Option<*mut T> {
+        self.slots
+            .iter()
+            .zip(&self.in_use)
+            .map(|(slot, in_use)| (slot.get().cast::<T>(), in_use))
+            .find(|(slot, in_use)| slot.cast_const() == handle && in_use.load(Ordering::Acquire))
+            .map(|(slot, _)| slot)
+    }
+
+    /// Drops a live handle and frees its slot
+    ///
+    /// Pointers that are not live handles of this pool are ignored.
+    fn free(&self, handle: *mut T) {
+        let Some(index) = (0..N).find(|&i| self.slots[i].get().cast::<T>() == handle) else {
+            return;
+        };
+        if self.in_use[index].load(Ordering::Acquire) {
+            // SAFETY: The slot holds a live value, and the caller gives up
+            // the handle by freeing it
+            unsafe { ptr::drop_in_place(handle) };
+            self.in_use[index].store(false, Ordering::Release);
+        }
+    }
+}
+
+/// Maps a CRDT error to its C error code
+fn error_code(error: CRDTError) -> i32 {
+    match error.cause() {
+        CRDTError::BufferOverflow => CRDTOSPHERE_ERR_BUFFER_OVERFLOW,
+        CRDTError::Overflow => CRDTOSPHERE_ERR_OVERFLOW,
+        CRDTError::InvalidNodeId => CRDTOSPHERE_ERR_INVALID_NODE_ID,
+        CRDTError::InvalidOperation => CRDTOSPHERE_ERR_INVALID_OPERATION,
+        _ => CRDTOSPHERE_ERR_OTHER,
+    }
+}
+
+/// Maps a CRDT result to a C status code
+fn status<T>(result: Result<T, CRDTError>) -> i32 {
+    match result {
+        Ok(_) => CRDTOSPHERE_OK,
+        Err(error) => error_code(error),
+    }
+}
+
+/// Checks that a node ID fits `DefaultConfig`
+fn valid_node(node_id: NodeId) -> bool {
+    (node_id as usize) < DefaultConfig::MAX_NODES
+}
+
+/// Creates a grow-only counter
+///
+/// # Returns
+/// A new handle, or NULL if the pool is exhausted or `node_id` is out of
+/// range
+#[unsafe(no_mangle)]
+pub extern "C" fn crdtosphere_gcounter_new(node_id: u8) -> *mut GCounterHandle {
+    if !valid_node(node_id) {
+        return ptr::null_mut();
+    }
+    GCOUNTERS.alloc(GCounterHandle(GCounter::new(node_id)))
+}
+
+/// Increments this node's count
+///
+/// # Safety
+/// `handle` must not be used concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_gcounter_increment(
+    handle: *mut GCounterHandle,
+    amount: u32,
+) -> i32 {
+    let Some(handle) = GCOUNTERS.resolve(handle) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    // SAFETY: `resolve` only returns live handles
+    status(unsafe { (*handle).0.increment(amount) })
+}
+
+/// Returns the counter's total, or 0 for an invalid handle
+///
+/// # Safety
+/// `handle` must not be modified concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_gcounter_value(handle: *const GCounterHandle) -> u64 {
+    match GCOUNTERS.resolve(handle) {
+        // SAFETY: `resolve` only returns live handles
+        Some(handle) => unsafe { (*handle).0.value() },
+        None => 0,
+    }
+}
+
+/// Merges `src` into `dst`
+///
+/// # Safety
+/// Neither handle may be used concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_gcounter_merge(
+    dst: *mut GCounterHandle,
+    src: *const GCounterHandle,
+) -> i32 {
+    let (Some(dst), Some(src)) = (GCOUNTERS.resolve(dst), GCOUNTERS.resolve(src)) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    if dst.cast_const() == src.cast_const() {
+        return CRDTOSPHERE_OK;
+    }
+    // SAFETY: `resolve` only returns live handles, and they are distinct
+    status(unsafe { (*dst).0.merge(&(*src).0) })
+}
+
+/// Releases a counter; NULL and invalid handles are ignored
+///
+/// # Safety
+/// `handle` must not be used afterwards, nor concurrently from another
+/// thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_gcounter_free(handle: *mut GCounterHandle) {
+    GCOUNTERS.free(handle);
+}
+
+/// Creates a last-writer-wins register holding a `uint32_t`
+///
+/// # Returns
+/// A new handle, or NULL if the pool is exhausted or `node_id` is out of
+/// range
+#[unsafe(no_mangle)]
+pub extern "C" fn crdtosphere_lww_register_new(node_id: u8) -> *mut LWWRegisterHandle {
+    if !valid_node(node_id) {
+        return ptr::null_mut();
+    }
+    LWW_REGISTERS.alloc(LWWRegisterHandle(LWWRegister::new(node_id)))
+}
+
+/// Writes a value; it only takes effect if `timestamp` is the latest
+///
+/// # Safety
+/// `handle` must not be used concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_register_set(
+    handle: *mut LWWRegisterHandle,
+    value: u32,
+    timestamp: u64,
+) -> i32 {
+    let Some(handle) = LWW_REGISTERS.resolve(handle) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    // SAFETY: `resolve` only returns live handles
+    status(unsafe { (*handle).0.set(value, timestamp) })
+}
+
+/// Reads the current value into `out`
+///
+/// # Returns
+/// `CRDTOSPHERE_ERR_NOT_FOUND` if the register was never written
+///
+/// # Safety
+/// `handle` must not be modified concurrently from another thread, and `out`
+/// must be NULL or valid for writing a `uint32_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_register_get(
+    handle: *const LWWRegisterHandle,
+    out: *mut u32,
+) -> i32 {
+    let Some(handle) = LWW_REGISTERS.resolve(handle) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    if out.is_null() {
+        return CRDTOSPHERE_ERR_NULL_POINTER;
+    }
+    // SAFETY: `resolve` only returns live handles
+    match unsafe { (*handle).0.get() } {
+        Some(value) => {
+            // SAFETY: The caller guarantees `out` is writable
+            unsafe { out.write(*value) };
+            CRDTOSPHERE_OK
+        }
+        None => CRDTOSPHERE_ERR_NOT_FOUND,
+    }
+}
+
+/// Merges `src` into `dst`
+///
+/// # Safety
+/// Neither handle may be used concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_register_merge(
+    dst: *mut LWWRegisterHandle,
+    src: *const LWWRegisterHandle,
+) -> i32 {
+    let (Some(dst), Some(src)) = (LWW_REGISTERS.resolve(dst), LWW_REGISTERS.resolve(src)) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    if dst.cast_const() == src.cast_const() {
+        return CRDTOSPHERE_OK;
+    }
+    // SAFETY: `resolve` only returns live handles, and they are distinct
+    status(unsafe { (*dst).0.merge(&(*src).0) })
+}
+
+/// Releases a register; NULL and invalid handles are ignored
+///
+/// # Safety
+/// `handle` must not be used afterwards, nor concurrently from another
+/// thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_register_free(handle: *mut LWWRegisterHandle) {
+    LWW_REGISTERS.free(handle);
+}
+
+/// Creates a last-writer-wins map from `uint8_t` keys to `uint32_t` values
+///
+/// # Returns
+/// A new handle, or NULL if the pool is exhausted or `node_id` is out of
+/// range
+#[unsafe(no_mangle)]
+pub extern "C" fn crdtosphere_lww_map_new(node_id: u8) -> *mut LWWMapHandle {
+    if !valid_node(node_id) {
+        return ptr::null_mut();
+    }
+    LWW_MAPS.alloc(LWWMapHandle(LWWMap::new(node_id)))
+}
+
+/// Inserts or updates a key; it only takes effect if `timestamp` is the
+/// latest for that key
+///
+/// # Safety
+/// `handle` must not be used concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_map_insert(
+    handle: *mut LWWMapHandle,
+    key: u8,
+    value: u32,
+    timestamp: u64,
+) -> i32 {
+    let Some(handle) = LWW_MAPS.resolve(handle) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    // SAFETY: `resolve` only returns live handles
+    status(unsafe { (*handle).0.insert(key, value, timestamp) })
+}
+
+/// Reads the value of `key` into `out`
+///
+/// # Returns
+/// `CRDTOSPHERE_ERR_NOT_FOUND` if the key is absent or deleted
+///
+/// # Safety
+/// `handle` must not be modified concurrently from another thread, and `out`
+/// must be NULL or valid for writing a `uint32_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_map_get(
+    handle: *const LWWMapHandle,
+    key: u8,
+    out: *mut u32,
+) -> i32 {
+    let Some(handle) = LWW_MAPS.resolve(handle) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    if out.is_null() {
+        return CRDTOSPHERE_ERR_NULL_POINTER;
+    }
+    // SAFETY: `resolve` only returns live handles
+    match unsafe { (*handle).0.get(&key) } {
+        Some(value) => {
+            // SAFETY: The caller guarantees `out` is writable
+            unsafe { out.write(*value) };
+            CRDTOSPHERE_OK
+        }
+        None => CRDTOSPHERE_ERR_NOT_FOUND,
+    }
+}
+
+/// Deletes a key with a timestamped tombstone
+///
+/// The tombstone is recorded even if the key holds no value yet, so a
+/// concurrent older insert from another replica stays deleted.
+///
+/// # Safety
+/// `handle` must not be used concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_map_delete(
+    handle: *mut LWWMapHandle,
+    key: u8,
+    timestamp: u64,
+) -> i32 {
+    let Some(handle) = LWW_MAPS.resolve(handle) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    // SAFETY: `resolve` only returns live handles
+    status(unsafe { (*handle).0.delete(&key, timestamp) })
+}
+
+/// Returns the number of live keys, or 0 for an invalid handle
+///
+/// # Safety
+/// `handle` must not be modified concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_map_len(handle: *const LWWMapHandle) -> usize {
+    match LWW_MAPS.resolve(handle) {
+        // SAFETY: `resolve` only returns live handles
+        Some(handle) => unsafe { (*handle).0.len() },
+        None => 0,
+    }
+}
+
+/// Merges `src` into `dst`
+///
+/// # Safety
+/// Neither handle may be used concurrently from another thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_map_merge(
+    dst: *mut LWWMapHandle,
+    src: *const LWWMapHandle,
+) -> i32 {
+    let (Some(dst), Some(src)) = (LWW_MAPS.resolve(dst), LWW_MAPS.resolve(src)) else {
+        return CRDTOSPHERE_ERR_INVALID_HANDLE;
+    };
+    if dst.cast_const() == src.cast_const() {
+        return CRDTOSPHERE_OK;
+    }
+    // SAFETY: `resolve` only returns live handles, and they are distinct
+    status(unsafe { (*dst).0.merge(&(*src).0) })
+}
+
+/// Releases a map; NULL and invalid handles are ignored
+///
+/// # Safety
+/// `handle` must not be used afterwards, nor concurrently from another
+/// thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crdtosphere_lww_map_free(handle: *mut LWWMapHandle) {
+    LWW_MAPS.free(handle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcounter() {
+        let local = crdtosphere_gcounter_new(1);
+        let remote = crdtosphere_gcounter_new(2);
+        assert!(!local.is_null() && !remote.is_null());
+
+        unsafe {
+            assert_eq!(crdtosphere_gcounter_increment(local, 3), CRDTOSPHERE_OK);
+            assert_eq!(crdtosphere_gcounter_increment(remote, 4), CRDTOSPHERE_OK);
+            assert_eq!(
+                crdtosphere_gcounter_increment(local, 0),
+                CRDTOSPHERE_ERR_INVALID_OPERATION
+            );
+            assert_eq!(crdtosphere_gcounter_merge(local, remote), CRDTOSPHERE_OK);
+            assert_eq!(crdtosphere_gcounter_merge(local, local), CRDTOSPHERE_OK);
+            assert_eq!(crdtosphere_gcounter_value(local), 7);
+
+            crdtosphere_gcounter_free(remote);
+            crdtosphere_gcounter_free(local);
+        }
+        assert!(crdtosphere_gcounter_new(DefaultConfig::MAX_NODES as u8).is_null());
+    }
+
+    #[test]
+    fn test_lww_register() {
+        let register = crdtosphere_lww_register_new(1);
+        let other = crdtosphere_lww_register_new(2);
+        let mut out = 0u32;
+
+        unsafe {
+            assert_eq!(
+                crdtosphere_lww_register_get(register, &mut out),
+                CRDTOSPHERE_ERR_NOT_FOUND
+            );
+            assert_eq!(
+                crdtosphere_lww_register_set(register, 10, 1000),
+                CRDTOSPHERE_OK
+            );
+            assert_eq!(
+                crdtosphere_lww_register_set(other, 20, 2000),
+                CRDTOSPHERE_OK
+            );
+            assert_eq!(
+                crdtosphere_lww_register_merge(register, other),
+                CRDTOSPHERE_OK
+            );
+            assert_eq!(
+                crdtosphere_lww_register_get(register, &mut out),
+                CRDTOSPHERE_OK
+            );
+            assert_eq!(out, 20);
+            assert_eq!(
+                crdtosphere_lww_register_get(register, ptr::null_mut()),
+                CRDTOSPHERE_ERR_NULL_POINTER
+            );
+
+            crdtosphere_lww_register_free(other);
+            crdtosphere_lww_register_free(register);
+        }
+    }
+
+    #[test]
+    fn test_lww_map() {
+        let map = crdtosphere_lww_map_new(1);
+        let other = crdtosphere_lww_map_new(2);
+        let mut out = 0u32;
+
+        unsafe {
+            assert_eq!(
+                crdtosphere_lww_map_insert(map, 1, 100, 1000),
+                CRDTOSPHERE_OK
+            );
+            assert_eq!(
+                crdtosphere_lww_map_insert(other, 2, 200, 1000),
+                CRDTOSPHERE_OK
+            );
+            assert_eq!(crdtosphere_lww_map_merge(map, other), CRDTOSPHERE_OK);
+            assert_eq!(crdtosphere_lww_map_len(map), 2);
+            assert_eq!(crdtosphere_lww_map_get(map, 2, &mut out), CRDTOSPHERE_OK);
+            assert_eq!(out, 200);
+
+            assert_eq!(crdtosphere_lww_map_delete(map, 1, 2000), CRDTOSPHERE_OK);
+            assert_eq!(
+                crdtosphere_lww_map_get(map, 1, &mut out),
+                CRDTOSPHERE_ERR_NOT_FOUND
+            );
+            assert_eq!(crdtosphere_lww_map_len(map), 1);
+
+            crdtosphere_lww_map_free(other);
+            crdtosphere_lww_map_free(map);
+        }
+    }
+
+    #[test]
+    fn test_invalid_handles() {
+        let counter = crdtosphere_gcounter_new(1);
+        let mut out = 0u32;
+
+        unsafe {
+            assert_eq!(
+                crdtosphere_gcounter_increment(ptr::null_mut(), 1),
+                CRDTOSPHERE_ERR_INVALID_HANDLE
+            );
+            // A handle of another type
+            assert_eq!(
+                crdtosphere_lww_register_get(counter.cast(), &mut out),
+                CRDTOSPHERE_ERR_INVALID_HANDLE
+            );
+
+            assert_eq!(crdtosphere_gcounter_value(ptr::null()), 0);
+
+            // Freeing pointers that are not handles is ignored
+            crdtosphere_lww_map_free(counter.cast());
+            crdtosphere_gcounter_free(ptr::null_mut());
+            assert_eq!(crdtosphere_gcounter_increment(counter, 1), CRDTOSPHERE_OK);
+            crdtosphere_gcounter_free(counter);
+        }
+    }
+
+    #[test]
+    fn test_pool_exhaustion() {
+        let pool = HandlePool::<u32, 2>::new();
+        let a = pool.alloc(1);
+        let b = pool.alloc(2);
+        assert!(!a.is_null() && !b.is_null());
+        assert!(pool.alloc(3).is_null());
+
+        // Freed handles are rejected, and freeing twice is harmless
+        pool.free(a);
+        assert!(pool.resolve(a).is_none());
+        pool.free(a);
+        let c = pool.alloc(4);
+        assert_eq!(c, a);
+        assert_eq!(pool.resolve(c).map(|slot| unsafe { *slot }), Some(4));
+    }
+}
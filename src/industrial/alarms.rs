@@ -0,0 +1,565 @@
+//! Alarm Log for Industrial Systems
+//!
+//! This module implements a distributed, bounded alarm log so every
+//! controller on a line sees the same raised, acknowledged and cleared
+//! alarms, following the alarm lifecycle used by IEC 61131 systems.
+
+use crate::clock::{FnvHasher, StateHash};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::sets::ORSet;
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Alarm log entry
+///
+/// Two entries are equal when their `alarm_id` matches: the same alarm
+/// raised by different nodes is one alarm.
+#[derive(Debug, Clone, Copy)]
+pub struct AlarmEntry {
+    /// Alarm identifier
+    pub alarm_id: u32,
+    /// Severity, higher is more severe
+    pub severity: u8,
+    /// Timestamp when the alarm was raised
+    pub timestamp: u64,
+    /// Node that raised the alarm
+    pub node_id: NodeId,
+    /// Whether an operator has acknowledged the alarm
+    pub acknowledged: bool,
+}
+
+impl AlarmEntry {
+    /// Creates an entry that only carries the alarm ID, for lookups
+    fn probe(alarm_id: u32) -> Self {
+        Self {
+            alarm_id,
+            severity: 0,
+            timestamp: 0,
+            node_id: 0,
+            acknowledged: false,
+        }
+    }
+}
+
+impl PartialEq for AlarmEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.alarm_id == other.alarm_id
+    }
+}
+
+impl Eq for AlarmEntry {}
+
+impl StateHash for AlarmEntry {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.alarm_id.hash_into(hasher);
+    }
+}
+
+/// Acknowledgement state of one alarm, resolved last-writer-wins
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct AckEntry {
+    alarm_id: u32,
+    acknowledged: bool,
+    timestamp: u64,
+    node_id: NodeId,
+}
+
+impl AckEntry {
+    /// Returns true if this write wins over `other`
+    fn wins_over(&self, other: &AckEntry) -> bool {
+        (self.timestamp, self.node_id) > (other.timestamp, other.node_id)
+    }
+}
+
+/// Industrial alarm log CRDT
+///
+/// Raised alarms live in an observed-remove set, so a clear only removes
+/// the raises it has seen. Acknowledgement is a last-writer-wins flag per
+/// alarm: raising an alarm writes "unacknowledged", acknowledging it writes
+/// "acknowledged", and the latest write wins on every replica.
+///
+/// The log is bounded: when it is full, `raise_alarm` evicts a cleared
+/// alarm, or else the oldest acknowledged alarm of lowest severity.
+/// Eviction is local, so a replica that still stores the evicted alarm
+/// brings it back on the next merge.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `CAPACITY`: The maximum number of alarm entries (defaults to 16)
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::industrial::AlarmLog;
+///
+/// let mut press = AlarmLog::<DefaultConfig>::new(1);
+/// let mut hmi = AlarmLog::<DefaultConfig>::new(2);
+///
+/// press.raise_alarm(100, 3, 1000)?;
+/// press.raise_alarm(200, 7, 1010)?;
+/// hmi.merge(&press)?;
+///
+/// // The operator acknowledges the most severe alarm on the HMI
+/// assert_eq!(hmi.highest_severity_active().map(|a| a.alarm_id), Some(200));
+/// assert!(hmi.acknowledge_alarm(200, 1100)?);
+///
+/// press.merge(&hmi)?;
+/// assert_eq!(press.highest_severity_active().map(|a| a.alarm_id), Some(100));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct AlarmLog<C: MemoryConfig, const CAPACITY: usize = 16> {
+    /// Raised alarms
+    alarms: ORSet<AlarmEntry, C, CAPACITY>,
+    /// Acknowledgement state, at most one entry per stored alarm
+    acks: [Option<AckEntry>; CAPACITY],
+    ack_count: usize,
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> AlarmLog<C, CAPACITY> {
+    /// Creates a new alarm log
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node
+    ///
+    /// # Returns
+    /// A new empty alarm log
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            alarms: ORSet::with_capacity(node_id),
+            acks: [None; CAPACITY],
+            ack_count: 0,
+        }
+    }
+
+    /// Raises an alarm
+    ///
+    /// Raising an alarm again marks it unacknowledged. If the log is full,
+    /// a cleared or acknowledged alarm is evicted to make room.
+    ///
+    /// # Arguments
+    /// * `id` - The alarm identifier
+    /// * `severity` - The alarm severity, higher is more severe
+    /// * `ts` - The timestamp of this operation
+    ///
+    /// # Returns
+    /// Ok(true) if this node had not raised the alarm yet, Ok(false) otherwise,
+    /// or `CRDTError::BufferOverflow` if the log is full of unacknowledged alarms
+    pub fn raise_alarm(&mut self, id: u32, severity: u8, ts: u64) -> CRDTResult<bool> {
+        let entry = AlarmEntry {
+            alarm_id: id,
+            severity,
+            timestamp: ts,
+            node_id: self.node_id(),
+            acknowledged: false,
+        };
+
+        let added = match self.alarms.add(entry, ts) {
+            Err(error) if error.cause() == CRDTError::BufferOverflow => {
+                let victim = self.eviction_candidate().ok_or(CRDTError::BufferOverflow)?;
+                self.evict(victim);
+                self.alarms.add(entry, ts)?
+            }
+            result => result?,
+        };
+
+        self.write_ack(id, false, ts)?;
+        Ok(added)
+    }
+
+    /// Acknowledges an active alarm
+    ///
+    /// # Arguments
+    /// * `id` - The alarm identifier
+    /// * `ts` - The timestamp of this operation
+    ///
+    /// # Returns
+    /// Ok(true) if the alarm was active and unacknowledged and is now
+    /// acknowledged, Ok(false) otherwise
+    pub fn acknowledge_alarm(&mut self, id: u32, ts: u64) -> CRDTResult<bool> {
+        if !self.is_active(id) || self.is_acknowledged(id) {
+            return Ok(false);
+        }
+
+        self.write_ack(id, true, ts)?;
+        Ok(self.is_acknowledged(id))
+    }
+
+    /// Clears an alarm
+    ///
+    /// # Arguments
+    /// * `id` - The alarm identifier
+    /// * `ts` - The timestamp of this operation
+    ///
+    /// # Returns
+    /// Ok(true) if the alarm was active, Ok(false) if it wasn't,
+    /// or an error if the tombstone storage is full
+    pub fn clear_alarm(&mut self, id: u32, ts: u64) -> CRDTResult<bool> {
+        self.alarms.remove(&AlarmEntry::probe(id), ts)
+    }
+
+    /// Checks if an alarm is raised and not cleared
+    pub fn is_active(&self, id: u32) -> bool {
+        self.alarms.contains(&AlarmEntry::probe(id))
+    }
+
+    /// Checks if an alarm has been acknowledged since it was last raised
+    pub fn is_acknowledged(&self, id: u32) -> bool {
+        self.find_ack(id)
+            .map(|index| self.acks[index].is_some_and(|ack| ack.acknowledged))
+            .unwrap_or(false)
+    }
+
+    /// Gets an active alarm with its current acknowledgement state
+    ///
+    /// If several nodes raised the alarm, the most severe entry is returned.
+    pub fn get(&self, id: u32) -> Option<AlarmEntry> {
+        let probe = AlarmEntry::probe(id);
+        self.alarms
+            .iter()
+            .filter(|entry| **entry == probe)
+            .max_by(|a, b| Self::severity_order(b, a))
+            .map(|entry| AlarmEntry {
+                acknowledged: self.is_acknowledged(id),
+                ..*entry
+            })
+    }
+
+    /// Returns the most severe unacknowledged alarm
+    pub fn highest_severity_active(&self) -> Option<&AlarmEntry> {
+        self.iter_active_by_severity().next()
+    }
+
+    /// Returns the unacknowledged alarms sorted by severity, most severe first
+    ///
+    /// Alarms with equal severity are ordered oldest first, then by ID.
+    ///
+    /// # Returns
+    /// An iterator over the unacknowledged alarms, one per alarm ID
+    pub fn iter_active_by_severity(&self) -> impl Iterator<Item = &AlarmEntry> {
+        let mut active: [Option<&AlarmEntry>; CAPACITY] = [None; CAPACITY];
+        let mut count = 0;
+
+        for entry in self.alarms.iter() {
+            if self.is_acknowledged(entry.alarm_id) {
+                continue;
+            }
+
+            match active[..count].iter_mut().flatten().find(|e| **e == entry) {
+                Some(existing) => {
+                    // Keep the most severe entry among the nodes that raised it
+                    if Self::severity_order(entry, existing).is_lt() {
+                        *existing = entry;
+                    }
+                }
+                None => {
+                    active[count] = Some(entry);
+                    count += 1;
+                }
+            }
+        }
+
+        active[..count].sort_unstable_by(|a, b| match (a, b) {
+            (Some(a), Some(b)) => Self::severity_order(a, b),
+            _ => core::cmp::Ordering::Equal,
+        });
+
+        active.into_iter().flatten()
+    }
+
+    /// Gets this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.alarms.node_id()
+    }
+
+    /// Severity descending, then oldest first, then alarm ID ascending
+    fn severity_order(a: &AlarmEntry, b: &AlarmEntry) -> core::cmp::Ordering {
+        b.severity
+            .cmp(&a.severity)
+            .then(a.timestamp.cmp(&b.timestamp))
+            .then(a.alarm_id.cmp(&b.alarm_id))
+    }
+
+    /// Picks the alarm to evict when the log is full
+    ///
+    /// Cleared alarms go first; otherwise the acknowledged alarm with the
+    /// lowest severity, oldest first. Unacknowledged alarms are never evicted.
+    fn eviction_candidate(&self) -> Option<AlarmEntry> {
+        self.alarms
+            .iter_stored()
+            .filter(|entry| !self.is_active(entry.alarm_id) || self.is_acknowledged(entry.alarm_id))
+            .min_by_key(|entry| {
+                (
+                    self.is_active(entry.alarm_id),
+                    entry.severity,
+                    entry.timestamp,
+                )
+            })
+            .copied()
+    }
+
+    /// Forgets an alarm locally, along with its acknowledgement state
+    fn evict(&mut self, victim: AlarmEntry) {
+        self.alarms.purge(&victim);
+
+        if let Some(index) = self.find_ack(victim.alarm_id) {
+            self.ack_count -= 1;
+            self.acks[index] = self.acks[self.ack_count].take();
+        }
+    }
+
+    /// Finds the acknowledgement slot of an alarm
+    fn find_ack(&self, id: u32) -> Option<usize> {
+        self.acks[..self.ack_count]
+            .iter()
+            .position(|ack| ack.is_some_and(|ack| ack.alarm_id == id))
+    }
+
+    /// Applies an acknowledgement write locally
+    fn write_ack(&mut self, id: u32, acknowledged: bool, ts: u64) -> CRDTResult<()> {
+        self.apply_ack(AckEntry {
+            alarm_id: id,
+            acknowledged,
+            timestamp: ts,
+            node_id: self.node_id(),
+        })
+    }
+
+    /// Stores an acknowledgement if it wins over the current one
+    fn apply_ack(&mut self, ack: AckEntry) -> CRDTResult<()> {
+        match self.find_ack(ack.alarm_id) {
+            Some(index) => {
+                if self.acks[index].is_some_and(|current| ack.wins_over(&current)) {
+                    self.acks[index] = Some(ack);
+                }
+            }
+            None => {
+                if self.ack_count >= CAPACITY {
+                    return Err(CRDTError::BufferOverflow);
+                }
+                self.acks[self.ack_count] = Some(ack);
+                self.ack_count += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for AlarmLog<C, CAPACITY> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.alarms.merge(&other.alarms)?;
+
+        // Acknowledgements of alarms evicted everywhere we know of are dropped
+        for ack in other.acks[..other.ack_count].iter().flatten() {
+            let probe = AlarmEntry::probe(ack.alarm_id);
+            if self.alarms.iter_stored().any(|entry| *entry == probe) {
+                self.apply_ack(*ack)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.alarms.eq(&other.alarms)
+            && self.ack_count == other.ack_count
+            && self.acks[..self.ack_count]
+                .iter()
+                .all(|ack| other.acks[..other.ack_count].contains(ack))
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        if self.ack_count > CAPACITY {
+            return Err(CRDTError::InvalidState);
+        }
+
+        self.alarms.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        // Order-independent hash over the unacknowledged alarms
+        let mut hash = 0u32;
+        for entry in self.iter_active_by_severity() {
+            hash ^= entry.alarm_id ^ ((entry.severity as u32) << 24);
+        }
+        hash ^ self.ack_count as u32
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.alarms.can_merge(&other.alarms)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for AlarmLog<C, CAPACITY> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.alarms.element_entries()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        self.alarms.compact()
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.alarms.element_entries() < CAPACITY || self.eviction_candidate().is_some()
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for AlarmLog<C, CAPACITY> {
+    const MAX_MERGE_CYCLES: u32 =
+        <ORSet<AlarmEntry, C, CAPACITY> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 =
+        <ORSet<AlarmEntry, C, CAPACITY> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 =
+        <ORSet<AlarmEntry, C, CAPACITY> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For industrial systems, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For industrial systems, we don't limit budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_raise_acknowledge_clear() {
+        let mut log = AlarmLog::<DefaultConfig>::new(1);
+        assert!(log.highest_severity_active().is_none());
+
+        assert!(log.raise_alarm(10, 5, 1000).unwrap());
+        assert!(!log.raise_alarm(10, 5, 1001).unwrap());
+        assert!(log.is_active(10));
+        assert!(!log.is_acknowledged(10));
+
+        assert!(log.acknowledge_alarm(10, 1100).unwrap());
+        assert!(!log.acknowledge_alarm(10, 1101).unwrap());
+        assert!(log.get(10).unwrap().acknowledged);
+        assert!(log.highest_severity_active().is_none());
+
+        assert!(log.clear_alarm(10, 1200).unwrap());
+        assert!(!log.is_active(10));
+        assert!(log.get(10).is_none());
+        assert!(!log.acknowledge_alarm(10, 1300).unwrap());
+        assert!(!log.clear_alarm(10, 1300).unwrap());
+    }
+
+    #[test]
+    fn test_iter_active_by_severity() {
+        let mut log = AlarmLog::<DefaultConfig>::new(1);
+
+        log.raise_alarm(1, 2, 1000).unwrap();
+        log.raise_alarm(2, 9, 1010).unwrap();
+        log.raise_alarm(3, 5, 1020).unwrap();
+        log.raise_alarm(4, 5, 1005).unwrap();
+        log.raise_alarm(5, 7, 1030).unwrap();
+        log.acknowledge_alarm(5, 1040).unwrap();
+
+        let mut order = [0u32; 4];
+        let mut count = 0;
+        for entry in log.iter_active_by_severity() {
+            order[count] = entry.alarm_id;
+            count += 1;
+        }
+
+        // Acknowledged alarm 5 is skipped; equal severities are oldest first
+        assert_eq!(count, 4);
+        assert_eq!(order, [2, 4, 3, 1]);
+        assert_eq!(log.highest_severity_active().unwrap().alarm_id, 2);
+    }
+
+    #[test]
+    fn test_reraise_requires_new_acknowledgement() {
+        let mut log = AlarmLog::<DefaultConfig>::new(1);
+
+        log.raise_alarm(7, 4, 1000).unwrap();
+        log.acknowledge_alarm(7, 1100).unwrap();
+        log.raise_alarm(7, 4, 1200).unwrap();
+
+        assert!(!log.is_acknowledged(7));
+        assert_eq!(log.highest_severity_active().unwrap().alarm_id, 7);
+    }
+
+    #[test]
+    fn test_merge_convergence() {
+        let mut line = AlarmLog::<DefaultConfig>::new(1);
+        let mut hmi = AlarmLog::<DefaultConfig>::new(2);
+
+        line.raise_alarm(1, 3, 1000).unwrap();
+        line.raise_alarm(2, 8, 1000).unwrap();
+        hmi.merge(&line).unwrap();
+        hmi.acknowledge_alarm(2, 1500).unwrap();
+        hmi.raise_alarm(3, 6, 1600).unwrap();
+        // Concurrent re-raise on the line, older than the acknowledgement
+        line.raise_alarm(2, 8, 1400).unwrap();
+
+        let mut merged1 = line.clone();
+        merged1.merge(&hmi).unwrap();
+        let mut merged2 = hmi.clone();
+        merged2.merge(&line).unwrap();
+
+        assert!(merged1.eq(&merged2));
+        assert!(merged1.is_acknowledged(2));
+        assert_eq!(merged1.highest_severity_active().unwrap().alarm_id, 3);
+
+        let before = merged1.clone();
+        merged1.merge(&before).unwrap();
+        assert!(merged1.eq(&before));
+    }
+
+    #[test]
+    fn test_eviction_when_full() {
+        let mut log = AlarmLog::<DefaultConfig, 4>::new(1);
+
+        log.raise_alarm(1, 5, 1000).unwrap();
+        log.raise_alarm(2, 3, 1100).unwrap();
+        log.raise_alarm(3, 3, 1200).unwrap();
+        log.raise_alarm(4, 9, 1300).unwrap();
+
+        // Nothing acknowledged or cleared, so nothing can be evicted
+        assert!(!log.can_add_element());
+        assert_eq!(log.raise_alarm(5, 1, 1400), Err(CRDTError::BufferOverflow));
+
+        // Oldest acknowledged alarm of lowest severity goes first
+        log.acknowledge_alarm(1, 1500).unwrap();
+        log.acknowledge_alarm(2, 1500).unwrap();
+        log.acknowledge_alarm(3, 1500).unwrap();
+        assert!(log.raise_alarm(5, 1, 1600).unwrap());
+        assert!(!log.is_active(2));
+        assert!(log.is_active(3));
+
+        // Cleared alarms are evicted before acknowledged ones
+        log.clear_alarm(4, 1700).unwrap();
+        assert!(log.raise_alarm(6, 2, 1800).unwrap());
+        assert!(log.is_active(1));
+        assert!(log.is_active(3));
+        assert_eq!(log.element_count(), 4);
+        assert!(log.validate().is_ok());
+    }
+}
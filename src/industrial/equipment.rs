@@ -3,8 +3,10 @@
 //! This module implements CRDTs for distributed industrial equipment management,
 //! enabling coordination of equipment states and maintenance across systems.
 
-use crate::clock::CompactTimestamp;
+use crate::clock::{CompactTimestamp, FnvHasher, StateHash};
+use crate::counters::GCounter;
 use crate::error::{CRDTError, CRDTResult};
+use crate::maps::LWWMap;
 use crate::memory::{MemoryConfig, NodeId};
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
@@ -398,6 +400,49 @@ impl<C: MemoryConfig> EquipmentRegistry<C> {
         }
     }
 
+    /// Copies utilization metrics into the equipment info and flags due
+    /// maintenance
+    ///
+    /// Sets the maintenance state to `PreventiveDue` if either interval has
+    /// been crossed and no maintenance is pending yet.
+    ///
+    /// # Arguments
+    /// * `metrics` - Utilization metrics of the equipment
+    /// * `interval_hours` - Operating hours between maintenances
+    /// * `interval_cycles` - Cycles between maintenances
+    /// * `timestamp` - Update timestamp
+    ///
+    /// # Returns
+    /// Ok(true) if maintenance is due, Ok(false) if not, or
+    /// `InvalidNodeId` if the equipment is not registered
+    pub fn sync_maintenance_metrics(
+        &mut self,
+        metrics: &EquipmentMetrics<C>,
+        interval_hours: u64,
+        interval_cycles: u64,
+        timestamp: u64,
+    ) -> CRDTResult<bool> {
+        let due = metrics.maintenance_due(interval_hours, interval_cycles);
+        let equipment = self
+            .find_equipment_mut(metrics.equipment_id())
+            .ok_or(CRDTError::InvalidNodeId)?;
+
+        equipment.update_metrics(
+            metrics.lifetime_hours().min(u32::MAX as u64) as u32,
+            metrics.lifetime_cycles().min(u32::MAX as u64) as u32,
+            timestamp,
+        );
+        if due
+            && equipment.maintenance_state.priority_level()
+                < MaintenanceState::PreventiveDue.priority_level()
+        {
+            equipment.update_maintenance(MaintenanceState::PreventiveDue, timestamp);
+        }
+
+        self.last_update = CompactTimestamp::new(timestamp);
+        Ok(due)
+    }
+
     /// Gets all equipment
     ///
     /// # Returns
@@ -742,6 +787,512 @@ impl<C: MemoryConfig> RealTimeCRDT<C> for EquipmentRegistry<C> {
     }
 }
 
+/// Utilization metrics of one piece of equipment for predictive maintenance
+///
+/// Counts operating hours and cycles logged by any controller. The counters
+/// since the last maintenance are reset by
+/// [`reset_maintenance_counters`](Self::reset_maintenance_counters), which
+/// starts a new maintenance epoch: merging adopts the counters of the newest
+/// epoch, so a reset is not undone by controllers that have not seen it yet.
+/// Lifetime totals are never reset and keep every logged hour and cycle for
+/// auditing, including those logged in an epoch that was later superseded.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::industrial::EquipmentMetrics;
+///
+/// let mut press = EquipmentMetrics::<DefaultConfig>::new(42, 1);
+/// press.log_utilization_hours(480)?;
+/// press.log_cycle()?;
+/// assert!(press.maintenance_due(500, 1));
+///
+/// // After maintenance
+/// press.reset_maintenance_counters()?;
+/// assert!(!press.maintenance_due(500, 1));
+/// assert_eq!(press.lifetime_hours(), 480);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct EquipmentMetrics<C: MemoryConfig> {
+    /// Equipment these metrics belong to
+    equipment_id: NodeId,
+    /// Operating hours since the last maintenance
+    utilization_hours: GCounter<C>,
+    /// Cycles since the last maintenance
+    cycle_count: GCounter<C>,
+    /// Operating hours since commissioning
+    lifetime_hours: GCounter<C>,
+    /// Cycles since commissioning
+    lifetime_cycles: GCounter<C>,
+    /// Number of maintenance resets
+    maintenance_epoch: u32,
+    /// This controller's ID
+    node_id: NodeId,
+}
+
+impl<C: MemoryConfig> EquipmentMetrics<C> {
+    /// Creates empty metrics for a piece of equipment
+    ///
+    /// # Arguments
+    /// * `equipment_id` - Equipment the metrics belong to
+    /// * `node_id` - The ID of this controller
+    pub fn new(equipment_id: NodeId, node_id: NodeId) -> Self {
+        Self {
+            equipment_id,
+            utilization_hours: GCounter::new(node_id),
+            cycle_count: GCounter::new(node_id),
+            lifetime_hours: GCounter::new(node_id),
+            lifetime_cycles: GCounter::new(node_id),
+            maintenance_epoch: 0,
+            node_id,
+        }
+    }
+
+    /// Logs operating hours observed by this controller
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error if a counter overflows
+    pub fn log_utilization_hours(&mut self, hours: u32) -> CRDTResult<()> {
+        self.utilization_hours.increment(hours)?;
+        self.lifetime_hours.increment(hours)
+    }
+
+    /// Logs one completed cycle observed by this controller
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error if a counter overflows
+    pub fn log_cycle(&mut self) -> CRDTResult<()> {
+        self.cycle_count.increment(1)?;
+        self.lifetime_cycles.increment(1)
+    }
+
+    /// Returns the operating hours since the last maintenance
+    pub fn total_utilization_hours(&self) -> u64 {
+        self.utilization_hours.value()
+    }
+
+    /// Returns the cycles since the last maintenance
+    pub fn total_cycles(&self) -> u64 {
+        self.cycle_count.value()
+    }
+
+    /// Returns the operating hours since commissioning
+    pub fn lifetime_hours(&self) -> u64 {
+        self.lifetime_hours.value()
+    }
+
+    /// Returns the cycles since commissioning
+    pub fn lifetime_cycles(&self) -> u64 {
+        self.lifetime_cycles.value()
+    }
+
+    /// Checks if either maintenance interval has been reached
+    ///
+    /// # Arguments
+    /// * `interval_hours` - Operating hours between maintenances
+    /// * `interval_cycles` - Cycles between maintenances
+    pub fn maintenance_due(&self, interval_hours: u64, interval_cycles: u64) -> bool {
+        self.total_utilization_hours() >= interval_hours || self.total_cycles() >= interval_cycles
+    }
+
+    /// Starts a new maintenance epoch with zeroed counters
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `Overflow` if the epoch counter is exhausted
+    pub fn reset_maintenance_counters(&mut self) -> CRDTResult<()> {
+        self.maintenance_epoch = self
+            .maintenance_epoch
+            .checked_add(1)
+            .ok_or(CRDTError::Overflow)?;
+        self.utilization_hours = GCounter::new(self.node_id);
+        self.cycle_count = GCounter::new(self.node_id);
+        Ok(())
+    }
+
+    /// Returns the number of maintenance resets seen
+    pub fn maintenance_epoch(&self) -> u32 {
+        self.maintenance_epoch
+    }
+
+    /// Returns the equipment these metrics belong to
+    pub fn equipment_id(&self) -> NodeId {
+        self.equipment_id
+    }
+}
+
+impl<C: MemoryConfig> CRDT<C> for EquipmentMetrics<C> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        if other.equipment_id != self.equipment_id {
+            return Err(CRDTError::InvalidMerge);
+        }
+
+        if other.maintenance_epoch > self.maintenance_epoch {
+            // Adopt the newer epoch, keeping our own counter slot
+            self.maintenance_epoch = other.maintenance_epoch;
+            self.utilization_hours = GCounter::new(self.node_id);
+            self.cycle_count = GCounter::new(self.node_id);
+        }
+        if other.maintenance_epoch == self.maintenance_epoch {
+            self.utilization_hours.merge(&other.utilization_hours)?;
+            self.cycle_count.merge(&other.cycle_count)?;
+        }
+
+        self.lifetime_hours.merge(&other.lifetime_hours)?;
+        self.lifetime_cycles.merge(&other.lifetime_cycles)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.equipment_id == other.equipment_id
+            && self.maintenance_epoch == other.maintenance_epoch
+            && self.utilization_hours.eq(&other.utilization_hours)
+            && self.cycle_count.eq(&other.cycle_count)
+            && self.lifetime_hours.eq(&other.lifetime_hours)
+            && self.lifetime_cycles.eq(&other.lifetime_cycles)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.utilization_hours.validate()?;
+        self.cycle_count.validate()?;
+        self.lifetime_hours.validate()?;
+        self.lifetime_cycles.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        (self.equipment_id as u32)
+            ^ self.maintenance_epoch.rotate_left(8)
+            ^ self.utilization_hours.state_hash()
+            ^ self.cycle_count.state_hash().rotate_left(16)
+            ^ self.lifetime_hours.state_hash().rotate_left(4)
+            ^ self.lifetime_cycles.state_hash().rotate_left(20)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.equipment_id == other.equipment_id
+    }
+}
+
+/// Lifecycle of a maintenance window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum WindowStatus {
+    /// Planned, not yet started
+    Scheduled = 0,
+    /// Maintenance under way
+    Active = 1,
+    /// Maintenance finished
+    Completed = 2,
+    /// Called off before completion
+    Cancelled = 3,
+}
+
+impl WindowStatus {
+    /// Returns true if the window can no longer change
+    pub fn is_final(&self) -> bool {
+        matches!(self, WindowStatus::Completed | WindowStatus::Cancelled)
+    }
+}
+
+/// Planned maintenance of one piece of equipment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaintenanceWindow {
+    /// Equipment to be maintained
+    pub equipment_id: u32,
+    /// Planned start (inclusive)
+    pub start_timestamp: u64,
+    /// Planned end (exclusive)
+    pub end_timestamp: u64,
+    /// Unique window identifier
+    pub window_id: u32,
+    /// Current lifecycle state
+    pub status: WindowStatus,
+}
+
+impl StateHash for MaintenanceWindow {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.equipment_id.hash_into(hasher);
+        self.start_timestamp.hash_into(hasher);
+        self.end_timestamp.hash_into(hasher);
+        self.window_id.hash_into(hasher);
+        (self.status as u8).hash_into(hasher);
+    }
+}
+
+impl MaintenanceWindow {
+    /// Creates a scheduled window
+    pub fn new(
+        window_id: u32,
+        equipment_id: u32,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) -> Self {
+        Self {
+            equipment_id,
+            start_timestamp,
+            end_timestamp,
+            window_id,
+            status: WindowStatus::Scheduled,
+        }
+    }
+
+    /// Checks if a time falls within the planned window
+    pub fn covers(&self, time: u64) -> bool {
+        (self.start_timestamp..self.end_timestamp).contains(&time)
+    }
+
+    /// Checks if the window is in effect at a given time
+    ///
+    /// Active windows are in effect until completed, even past their planned
+    /// end; scheduled windows are in effect during their planned time.
+    pub fn is_in_effect(&self, current_time: u64) -> bool {
+        match self.status {
+            WindowStatus::Active => true,
+            WindowStatus::Scheduled => self.covers(current_time),
+            WindowStatus::Completed | WindowStatus::Cancelled => false,
+        }
+    }
+}
+
+/// Maintenance window schedule shared by all PLCs of a facility
+///
+/// Windows are stored in an [`LWWMap`] keyed by window ID. Every status
+/// change rewrites the whole window, so concurrent changes to one window
+/// (e.g. a cancellation racing a completion) resolve to the latest one.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `CAPACITY`: Maximum number of windows
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::industrial::{MaintenanceSchedule, MaintenanceWindow};
+///
+/// let mut schedule = MaintenanceSchedule::<DefaultConfig>::new(1);
+/// schedule.schedule_window(MaintenanceWindow::new(1, 42, 10_000, 20_000), 1000)?;
+///
+/// assert_eq!(schedule.upcoming_windows(60_000, 5000).count(), 1);
+/// assert!(schedule.equipment_under_maintenance(42, 15_000));
+///
+/// schedule.activate_window(1, 9000)?;
+/// schedule.complete_window(1, 12_000)?;
+/// assert!(!schedule.equipment_under_maintenance(42, 15_000));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaintenanceSchedule<C: MemoryConfig, const CAPACITY: usize = 8> {
+    /// Windows by window ID
+    windows: LWWMap<u32, MaintenanceWindow, C, CAPACITY>,
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> MaintenanceSchedule<C, CAPACITY> {
+    /// Creates an empty schedule
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this controller
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            windows: LWWMap::with_capacity(node_id),
+        }
+    }
+
+    /// Adds or replaces a window
+    ///
+    /// # Arguments
+    /// * `window` - The window, keyed by its `window_id`
+    /// * `timestamp` - The timestamp of this change
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `InvalidOperation` if the window ends before
+    /// it starts, or `BufferOverflow` if the schedule is full
+    pub fn schedule_window(&mut self, window: MaintenanceWindow, timestamp: u64) -> CRDTResult<()> {
+        if window.end_timestamp <= window.start_timestamp {
+            return Err(CRDTError::InvalidOperation);
+        }
+        self.windows.insert(window.window_id, window, timestamp)?;
+        Ok(())
+    }
+
+    /// Marks a scheduled window as started
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `InvalidOperation` if the window is unknown or
+    /// not scheduled
+    pub fn activate_window(&mut self, window_id: u32, timestamp: u64) -> CRDTResult<()> {
+        self.transition(window_id, WindowStatus::Active, timestamp, |status| {
+            status == WindowStatus::Scheduled
+        })
+    }
+
+    /// Marks an active window as finished
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `InvalidOperation` if the window is unknown or
+    /// not active
+    pub fn complete_window(&mut self, window_id: u32, timestamp: u64) -> CRDTResult<()> {
+        self.transition(window_id, WindowStatus::Completed, timestamp, |status| {
+            status == WindowStatus::Active
+        })
+    }
+
+    /// Calls off a scheduled or active window
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `InvalidOperation` if the window is unknown,
+    /// completed or already cancelled
+    pub fn cancel_window(&mut self, window_id: u32, timestamp: u64) -> CRDTResult<()> {
+        self.transition(window_id, WindowStatus::Cancelled, timestamp, |status| {
+            !status.is_final()
+        })
+    }
+
+    /// Gets a window by ID
+    pub fn window(&self, window_id: u32) -> Option<&MaintenanceWindow> {
+        self.windows.get(&window_id)
+    }
+
+    /// Returns the windows in effect at a given time
+    ///
+    /// See [`MaintenanceWindow::is_in_effect`].
+    pub fn active_windows(&self, current_time: u64) -> impl Iterator<Item = &MaintenanceWindow> {
+        self.windows
+            .values()
+            .filter(move |window| window.is_in_effect(current_time))
+    }
+
+    /// Returns the scheduled windows starting within a lookahead period
+    ///
+    /// # Arguments
+    /// * `lookahead_ms` - How far ahead to look
+    /// * `current_time` - The current time
+    pub fn upcoming_windows(
+        &self,
+        lookahead_ms: u64,
+        current_time: u64,
+    ) -> impl Iterator<Item = &MaintenanceWindow> {
+        let horizon = current_time.saturating_add(lookahead_ms);
+        self.windows.values().filter(move |window| {
+            window.status == WindowStatus::Scheduled
+                && window.start_timestamp > current_time
+                && window.start_timestamp <= horizon
+        })
+    }
+
+    /// Checks if a piece of equipment has a window in effect
+    pub fn equipment_under_maintenance(&self, equipment_id: u32, current_time: u64) -> bool {
+        self.active_windows(current_time)
+            .any(|window| window.equipment_id == equipment_id)
+    }
+
+    /// Returns the number of windows
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Returns true if no windows are scheduled
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Rewrites a window with a new status if `allowed` accepts its current one
+    fn transition(
+        &mut self,
+        window_id: u32,
+        status: WindowStatus,
+        timestamp: u64,
+        allowed: impl Fn(WindowStatus) -> bool,
+    ) -> CRDTResult<()> {
+        let mut window = *self.window(window_id).ok_or(CRDTError::InvalidOperation)?;
+        if !allowed(window.status) {
+            return Err(CRDTError::InvalidOperation);
+        }
+        window.status = status;
+        self.windows.insert(window_id, window, timestamp)?;
+        Ok(())
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for MaintenanceSchedule<C, CAPACITY> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.windows.merge(&other.windows)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.windows.eq(&other.windows)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.windows.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.windows.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.windows.can_merge(&other.windows)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for MaintenanceSchedule<C, CAPACITY> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.windows.len()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        self.windows.compact()
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.element_count() < Self::MAX_ELEMENTS
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for MaintenanceSchedule<C, CAPACITY> {
+    const MAX_MERGE_CYCLES: u32 =
+        <LWWMap<u32, MaintenanceWindow, C, CAPACITY> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 =
+        <LWWMap<u32, MaintenanceWindow, C, CAPACITY> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 =
+        <LWWMap<u32, MaintenanceWindow, C, CAPACITY> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For industrial systems, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For industrial systems, we don't limit budget
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -986,4 +1537,186 @@ mod tests {
         assert!(registry1.merge_bounded(&registry2).is_ok());
         assert!(registry1.validate_bounded().is_ok());
     }
+
+    #[test]
+    fn test_equipment_metrics_maintenance_due() {
+        let mut metrics = EquipmentMetrics::<DefaultConfig>::new(42, 1);
+        assert!(!metrics.maintenance_due(100, 10));
+
+        metrics.log_utilization_hours(60).unwrap();
+        for _ in 0..9 {
+            metrics.log_cycle().unwrap();
+        }
+        assert!(!metrics.maintenance_due(100, 10));
+
+        // Either threshold triggers maintenance
+        metrics.log_cycle().unwrap();
+        assert!(metrics.maintenance_due(100, 10));
+        assert!(metrics.maintenance_due(60, 1000));
+        assert!(!metrics.maintenance_due(61, 1000));
+
+        let mut other = EquipmentMetrics::<DefaultConfig>::new(42, 2);
+        other.log_utilization_hours(40).unwrap();
+        metrics.merge(&other).unwrap();
+        assert_eq!(metrics.total_utilization_hours(), 100);
+        assert!(metrics.maintenance_due(100, 1000));
+
+        let foreign = EquipmentMetrics::<DefaultConfig>::new(7, 2);
+        assert!(!metrics.can_merge(&foreign));
+        assert_eq!(metrics.merge(&foreign), Err(CRDTError::InvalidMerge));
+    }
+
+    #[test]
+    fn test_reset_maintenance_counters() {
+        let mut local = EquipmentMetrics::<DefaultConfig>::new(42, 1);
+        let mut remote = EquipmentMetrics::<DefaultConfig>::new(42, 2);
+        local.log_utilization_hours(300).unwrap();
+        remote.log_utilization_hours(200).unwrap();
+        remote.log_cycle().unwrap();
+        local.merge(&remote).unwrap();
+
+        local.reset_maintenance_counters().unwrap();
+        assert_eq!(local.maintenance_epoch(), 1);
+        assert_eq!(local.total_utilization_hours(), 0);
+        assert_eq!(local.total_cycles(), 0);
+        assert_eq!(local.lifetime_hours(), 500);
+        assert_eq!(local.lifetime_cycles(), 1);
+
+        // A controller that missed the reset does not bring the hours back
+        remote.log_utilization_hours(10).unwrap();
+        local.merge(&remote).unwrap();
+        assert_eq!(local.total_utilization_hours(), 0);
+        assert_eq!(local.lifetime_hours(), 510);
+
+        // It adopts the reset and logs into the new epoch
+        remote.merge(&local).unwrap();
+        assert_eq!(remote.maintenance_epoch(), 1);
+        assert_eq!(remote.total_utilization_hours(), 0);
+        remote.log_utilization_hours(5).unwrap();
+        local.merge(&remote).unwrap();
+        assert_eq!(local.total_utilization_hours(), 5);
+        assert_eq!(local.lifetime_hours(), 515);
+
+        remote.merge(&local).unwrap();
+        assert!(local.eq(&remote));
+    }
+
+    #[test]
+    fn test_sync_maintenance_metrics() {
+        let mut registry = EquipmentRegistry::<DefaultConfig>::new(1);
+        registry.register_equipment(42, 0x2001, 1000).unwrap();
+
+        let mut metrics = EquipmentMetrics::<DefaultConfig>::new(42, 1);
+        metrics.log_utilization_hours(50).unwrap();
+        assert!(
+            !registry
+                .sync_maintenance_metrics(&metrics, 100, 1000, 1001)
+                .unwrap()
+        );
+        let equipment = registry.get_equipment(42).unwrap();
+        assert_eq!(equipment.operating_hours, 50);
+        assert_eq!(equipment.maintenance_state, MaintenanceState::None);
+
+        metrics.log_utilization_hours(50).unwrap();
+        assert!(
+            registry
+                .sync_maintenance_metrics(&metrics, 100, 1000, 1002)
+                .unwrap()
+        );
+        let equipment = registry.get_equipment(42).unwrap();
+        assert_eq!(equipment.maintenance_state, MaintenanceState::PreventiveDue);
+
+        // More urgent states are kept
+        registry
+            .update_maintenance_state(42, MaintenanceState::CorrectiveRequired, 1003)
+            .unwrap();
+        registry
+            .sync_maintenance_metrics(&metrics, 100, 1000, 1004)
+            .unwrap();
+        let equipment = registry.get_equipment(42).unwrap();
+        assert_eq!(
+            equipment.maintenance_state,
+            MaintenanceState::CorrectiveRequired
+        );
+
+        let unknown = EquipmentMetrics::<DefaultConfig>::new(7, 1);
+        assert_eq!(
+            registry.sync_maintenance_metrics(&unknown, 100, 1000, 1005),
+            Err(CRDTError::InvalidNodeId)
+        );
+    }
+
+    #[test]
+    fn test_maintenance_window_lifecycle() {
+        let mut schedule = MaintenanceSchedule::<DefaultConfig, 4>::new(1);
+        assert_eq!(
+            schedule.schedule_window(MaintenanceWindow::new(1, 42, 5000, 5000), 100),
+            Err(CRDTError::InvalidOperation)
+        );
+        schedule
+            .schedule_window(MaintenanceWindow::new(1, 42, 10_000, 20_000), 100)
+            .unwrap();
+        schedule
+            .schedule_window(MaintenanceWindow::new(2, 7, 50_000, 60_000), 100)
+            .unwrap();
+
+        assert_eq!(schedule.len(), 2);
+        assert!(
+            schedule
+                .upcoming_windows(10_000, 5000)
+                .map(|w| w.window_id)
+                .eq([1])
+        );
+        assert_eq!(schedule.upcoming_windows(100_000, 5000).count(), 2);
+        assert_eq!(schedule.active_windows(5000).count(), 0);
+        assert!(schedule.equipment_under_maintenance(42, 10_000));
+        assert!(!schedule.equipment_under_maintenance(42, 20_000));
+
+        // Transitions must follow the lifecycle
+        assert_eq!(
+            schedule.complete_window(1, 200),
+            Err(CRDTError::InvalidOperation)
+        );
+        assert_eq!(
+            schedule.activate_window(9, 200),
+            Err(CRDTError::InvalidOperation)
+        );
+        schedule.activate_window(1, 200).unwrap();
+        // An overrunning window stays in effect until completed
+        assert!(schedule.equipment_under_maintenance(42, 25_000));
+        schedule.complete_window(1, 300).unwrap();
+        assert!(!schedule.equipment_under_maintenance(42, 15_000));
+        assert_eq!(
+            schedule.cancel_window(1, 400),
+            Err(CRDTError::InvalidOperation)
+        );
+
+        schedule.cancel_window(2, 400).unwrap();
+        assert_eq!(schedule.window(2).unwrap().status, WindowStatus::Cancelled);
+        assert_eq!(schedule.upcoming_windows(100_000, 5000).count(), 0);
+        assert!(schedule.validate().is_ok());
+    }
+
+    #[test]
+    fn test_maintenance_schedule_merge() {
+        let mut plc1 = MaintenanceSchedule::<DefaultConfig>::new(1);
+        plc1.schedule_window(MaintenanceWindow::new(1, 42, 10_000, 20_000), 100)
+            .unwrap();
+        let mut plc2 = plc1.clone();
+
+        // Concurrent changes: the later cancellation wins
+        plc1.activate_window(1, 200).unwrap();
+        plc2.cancel_window(1, 300).unwrap();
+        plc2.schedule_window(MaintenanceWindow::new(2, 7, 30_000, 40_000), 300)
+            .unwrap();
+
+        let mut merged1 = plc1.clone();
+        merged1.merge(&plc2).unwrap();
+        plc2.merge(&plc1).unwrap();
+        assert!(merged1.eq(&plc2));
+        assert_eq!(merged1.state_hash(), plc2.state_hash());
+        assert_eq!(merged1.window(1).unwrap().status, WindowStatus::Cancelled);
+        assert!(!merged1.equipment_under_maintenance(42, 15_000));
+        assert!(merged1.equipment_under_maintenance(7, 35_000));
+    }
 }
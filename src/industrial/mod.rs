@@ -3,9 +3,18 @@
 //! This module provides CRDTs specifically designed for industrial automation
 //! and control systems, focusing on distributed coordination in manufacturing.
 
+pub mod alarms;
 pub mod equipment;
 pub mod processes;
+pub mod recipe;
+pub mod safety;
 
 // Re-export main types
-pub use equipment::{EquipmentInfo, EquipmentRegistry, EquipmentStatus, MaintenanceState};
-pub use processes::{ControlAction, ProcessControl, ProcessState, ProcessStep};
+pub use alarms::{AlarmEntry, AlarmLog};
+pub use equipment::{
+    EquipmentInfo, EquipmentMetrics, EquipmentRegistry, EquipmentStatus, MaintenanceSchedule,
+    MaintenanceState, MaintenanceWindow, WindowStatus,
+};
+pub use processes::{ControlAction, FaultState, ProcessControl, ProcessState, ProcessStep};
+pub use recipe::{RecipeRegister, RecipeValue};
+pub use safety::SILLevel;
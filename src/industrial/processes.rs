@@ -4,8 +4,10 @@
 //! enabling synchronization of manufacturing processes across control systems.
 
 use crate::clock::CompactTimestamp;
+use crate::counters::GCounter;
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
 /// Industrial process states
@@ -182,6 +184,109 @@ impl ProcessStep {
     }
 }
 
+/// Safety-conservative fault flag
+///
+/// Backed by an LWW register, except that a raised fault always beats a
+/// cleared one on merge, whatever their timestamps. Writes with the same value
+/// are ordered by timestamp as usual. A fault is therefore only cleared across
+/// the network once every replica has cleared it; until then, merging with a
+/// replica that still reports it raises it again.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::industrial::FaultState;
+///
+/// let mut line1 = FaultState::<DefaultConfig>::new(1);
+/// let mut line2 = FaultState::<DefaultConfig>::new(2);
+/// line1.enter(1000)?;
+/// line2.merge(&line1)?;
+///
+/// // Line 1 clears the fault later, but line 2 still reports it
+/// line1.clear(2000)?;
+/// line1.merge(&line2)?;
+/// assert!(line1.is_faulted());
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FaultState<C: MemoryConfig> {
+    /// Latest fault flag write
+    register: LWWRegister<bool, C>,
+    /// This node's ID
+    node_id: NodeId,
+}
+
+impl<C: MemoryConfig> FaultState<C> {
+    /// Creates a new fault flag with no fault raised
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            register: LWWRegister::new(node_id),
+            node_id,
+        }
+    }
+
+    /// Returns true if a fault is raised
+    pub fn is_faulted(&self) -> bool {
+        self.register.get() == Some(&true)
+    }
+
+    /// Raises the fault
+    pub fn enter(&mut self, timestamp: u64) -> CRDTResult<()> {
+        self.register.set(true, timestamp)
+    }
+
+    /// Clears the fault locally
+    ///
+    /// Has no effect if the fault was raised with a newer timestamp.
+    pub fn clear(&mut self, timestamp: u64) -> CRDTResult<()> {
+        self.register.set(false, timestamp)
+    }
+
+    /// Gets the timestamp of the latest fault flag write
+    pub fn timestamp(&self) -> CompactTimestamp {
+        self.register.timestamp()
+    }
+}
+
+impl<C: MemoryConfig> CRDT<C> for FaultState<C> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        if other.is_faulted() && !self.is_faulted() {
+            // Take over the fault write as is, keeping our own node ID
+            let mut adopted = LWWRegister::new(self.node_id);
+            adopted.merge(&other.register)?;
+            self.register = adopted;
+            Ok(())
+        } else if self.is_faulted() && !other.is_faulted() {
+            // A raised fault is never overridden by a clear
+            Ok(())
+        } else {
+            self.register.merge(&other.register)
+        }
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.register.eq(&other.register)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.register.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.register.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.register.can_merge(&other.register)
+    }
+}
+
 /// Industrial Process Control CRDT
 ///
 /// This CRDT manages distributed process coordination across industrial control systems,
@@ -228,6 +333,10 @@ pub struct ProcessControl<C: MemoryConfig> {
     local_controller_id: NodeId,
     /// Last update timestamp
     last_update: CompactTimestamp,
+    /// Number of step transitions, for auditing rollbacks
+    step_history: GCounter<C>,
+    /// Safety-conservative fault flag
+    fault: FaultState<C>,
     /// Phantom data for memory config
     _phantom: core::marker::PhantomData<C>,
 }
@@ -246,6 +355,8 @@ impl<C: MemoryConfig> ProcessControl<C> {
             process_count: 0,
             local_controller_id: controller_id,
             last_update: CompactTimestamp::new(0),
+            step_history: GCounter::new(controller_id),
+            fault: FaultState::new(controller_id),
             _phantom: core::marker::PhantomData,
         }
     }
@@ -442,6 +553,95 @@ impl<C: MemoryConfig> ProcessControl<C> {
         self.process_count
     }
 
+    /// Rolls a process back to an earlier step
+    ///
+    /// The step replaces the process's current step as an LWW update stamped
+    /// with `timestamp`, and the transition is counted in the
+    /// [`step_history`](Self::step_history).
+    ///
+    /// # Arguments
+    /// * `step` - The earlier step to return to
+    /// * `timestamp` - Rollback timestamp (must be newer than the current step)
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `CRDTError::InvalidNodeId` if the process is
+    /// unknown, or `CRDTError::InvalidOperation` if the step is not earlier
+    /// than the current one or the timestamp would lose the LWW update
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// use crdtosphere::industrial::{ProcessControl, ProcessStep};
+    ///
+    /// let mut control = ProcessControl::<DefaultConfig>::new(1);
+    /// control.register_process(42, 3, 1000)?;
+    ///
+    /// // Quality check failed, redo step 2
+    /// let step = *control.get_process(42).unwrap();
+    /// control.rollback_to_step(ProcessStep { step_number: 2, ..step }, 2000)?;
+    ///
+    /// assert_eq!(control.get_process(42).unwrap().step_number, 2);
+    /// assert_eq!(control.step_history().value(), 1);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn rollback_to_step(&mut self, step: ProcessStep, timestamp: u64) -> CRDTResult<()> {
+        let current = self
+            .get_process(step.process_id)
+            .ok_or(CRDTError::InvalidNodeId)?;
+
+        let timestamp = CompactTimestamp::new(timestamp);
+        if step.step_number >= current.step_number || timestamp <= current.timestamp {
+            return Err(CRDTError::InvalidOperation);
+        }
+
+        self.step_history.increment(1)?;
+        self.add_process_step(ProcessStep { timestamp, ..step })?;
+        self.last_update = timestamp;
+        Ok(())
+    }
+
+    /// Gets the step transition counter
+    ///
+    /// Step transitions are made by [`rollback_to_step`](Self::rollback_to_step),
+    /// so the counter tells auditors how many rollbacks happened on all
+    /// controllers.
+    pub fn step_history(&self) -> &GCounter<C> {
+        &self.step_history
+    }
+
+    /// Returns true if any controller has raised a fault that is not cleared everywhere
+    pub fn is_in_fault_state(&self) -> bool {
+        self.fault.is_faulted()
+    }
+
+    /// Raises the fault flag
+    ///
+    /// # Arguments
+    /// * `timestamp` - Fault timestamp
+    pub fn enter_fault_state(&mut self, timestamp: u64) -> CRDTResult<()> {
+        self.fault.enter(timestamp)?;
+        self.last_update = self.last_update.max(CompactTimestamp::new(timestamp));
+        Ok(())
+    }
+
+    /// Clears the fault flag on this controller
+    ///
+    /// Other controllers that still report the fault raise it again on the
+    /// next merge, see [`FaultState`].
+    ///
+    /// # Arguments
+    /// * `timestamp` - Clear timestamp
+    pub fn clear_fault_state(&mut self, timestamp: u64) -> CRDTResult<()> {
+        self.fault.clear(timestamp)?;
+        self.last_update = self.last_update.max(CompactTimestamp::new(timestamp));
+        Ok(())
+    }
+
+    /// Gets the fault flag
+    pub fn fault_state(&self) -> &FaultState<C> {
+        &self.fault
+    }
+
     /// Emergency stops all processes
     ///
     /// # Arguments
@@ -560,6 +760,8 @@ impl<C: MemoryConfig> CRDT<C> for ProcessControl<C> {
         for process in other.all_processes() {
             self.add_process_step(*process)?;
         }
+        self.step_history.merge(&other.step_history)?;
+        self.fault.merge(&other.fault)?;
 
         // Update timestamp to latest
         if other.last_update > self.last_update {
@@ -570,7 +772,10 @@ impl<C: MemoryConfig> CRDT<C> for ProcessControl<C> {
     }
 
     fn eq(&self, other: &Self) -> bool {
-        if self.process_count != other.process_count {
+        if self.process_count != other.process_count
+            || !self.step_history.eq(&other.step_history)
+            || !self.fault.eq(&other.fault)
+        {
             return false;
         }
 
@@ -607,7 +812,7 @@ impl<C: MemoryConfig> CRDT<C> for ProcessControl<C> {
                 ^ (process.state as u32);
         }
         hash ^= self.process_count as u32;
-        hash
+        hash ^ self.step_history.state_hash() ^ self.fault.state_hash().rotate_left(16)
     }
 
     fn can_merge(&self, _other: &Self) -> bool {
@@ -896,4 +1101,116 @@ mod tests {
         assert!(control1.merge_bounded(&control2).is_ok());
         assert!(control1.validate_bounded().is_ok());
     }
+
+    #[test]
+    fn test_rollback_to_step() {
+        let mut control = ProcessControl::<DefaultConfig>::new(1);
+        control.register_process(42, 5, 1000).unwrap();
+        let step = *control.get_process(42).unwrap();
+
+        // Only earlier steps with a winning timestamp are accepted
+        assert_eq!(
+            control.rollback_to_step(
+                ProcessStep {
+                    step_number: 5,
+                    ..step
+                },
+                2000
+            ),
+            Err(CRDTError::InvalidOperation)
+        );
+        assert_eq!(
+            control.rollback_to_step(
+                ProcessStep {
+                    step_number: 3,
+                    ..step
+                },
+                1000
+            ),
+            Err(CRDTError::InvalidOperation)
+        );
+        assert_eq!(
+            control.rollback_to_step(ProcessStep::new(7, 1, 1, 0), 2000),
+            Err(CRDTError::InvalidNodeId)
+        );
+        assert_eq!(control.step_history().value(), 0);
+
+        control
+            .rollback_to_step(
+                ProcessStep {
+                    step_number: 3,
+                    ..step
+                },
+                2000,
+            )
+            .unwrap();
+        let rolled_back = control.get_process(42).unwrap();
+        assert_eq!(rolled_back.step_number, 3);
+        assert_eq!(rolled_back.timestamp.as_u64(), 2000);
+        assert_eq!(control.step_history().value(), 1);
+
+        // Rollbacks on other controllers add up after merging
+        let mut other = ProcessControl::<DefaultConfig>::new(2);
+        other.merge(&control).unwrap();
+        let step = *other.get_process(42).unwrap();
+        other
+            .rollback_to_step(
+                ProcessStep {
+                    step_number: 1,
+                    ..step
+                },
+                3000,
+            )
+            .unwrap();
+
+        control.merge(&other).unwrap();
+        assert_eq!(control.get_process(42).unwrap().step_number, 1);
+        assert_eq!(control.step_history().value(), 2);
+
+        other.merge(&control).unwrap();
+        assert!(control.eq(&other));
+    }
+
+    #[test]
+    fn test_fault_state() {
+        let mut control = ProcessControl::<DefaultConfig>::new(1);
+        assert!(!control.is_in_fault_state());
+
+        control.enter_fault_state(1000).unwrap();
+        assert!(control.is_in_fault_state());
+        control.clear_fault_state(2000).unwrap();
+        assert!(!control.is_in_fault_state());
+
+        // A stale clear does not hide a newer fault
+        control.enter_fault_state(3000).unwrap();
+        control.clear_fault_state(2500).unwrap();
+        assert!(control.is_in_fault_state());
+    }
+
+    #[test]
+    fn test_fault_wins_on_merge() {
+        let mut node1 = ProcessControl::<DefaultConfig>::new(1);
+        let mut node2 = ProcessControl::<DefaultConfig>::new(2);
+        node1.enter_fault_state(1000).unwrap();
+        node2.merge(&node1).unwrap();
+
+        // Node 1 clears later, node 2 still reports the fault
+        node1.clear_fault_state(5000).unwrap();
+
+        let mut merged1 = node1.clone();
+        merged1.merge(&node2).unwrap();
+        let mut merged2 = node2.clone();
+        merged2.merge(&node1).unwrap();
+        assert!(merged1.is_in_fault_state());
+        assert!(merged2.is_in_fault_state());
+        assert!(merged1.fault_state().eq(merged2.fault_state()));
+
+        // Once both clear, the clear converges
+        node2.clear_fault_state(6000).unwrap();
+        node1.merge(&node2).unwrap();
+        node2.merge(&node1).unwrap();
+        assert!(!node1.is_in_fault_state());
+        assert!(!node2.is_in_fault_state());
+        assert!(node1.fault_state().eq(node2.fault_state()));
+    }
 }
@@ -0,0 +1,378 @@
+//! Recipe Management for Process Automation
+//!
+//! This module implements a recipe CRDT: the setpoints of a manufacturing
+//! recipe (temperature, pressure, speed, ...) are replicated across the
+//! PLCs of a line together with the process values they measure, so every
+//! controller can check the process against the active recipe.
+
+use crate::clock::{FnvHasher, StateHash};
+use crate::error::{CRDTError, CRDTResult};
+use crate::maps::LWWMap;
+use crate::memory::{MemoryConfig, NodeId};
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Recipe setpoint in fixed-point thousandths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecipeValue {
+    /// Target value × 1000
+    pub value_x1000: i64,
+    /// Application-defined unit code
+    pub unit: u8,
+    /// Lowest acceptable value × 1000
+    pub min_x1000: i64,
+    /// Highest acceptable value × 1000
+    pub max_x1000: i64,
+}
+
+impl StateHash for RecipeValue {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.value_x1000.hash_into(hasher);
+        self.unit.hash_into(hasher);
+        self.min_x1000.hash_into(hasher);
+        self.max_x1000.hash_into(hasher);
+    }
+}
+
+impl RecipeValue {
+    /// Creates a new setpoint
+    pub fn new(value_x1000: i64, unit: u8, min_x1000: i64, max_x1000: i64) -> Self {
+        Self {
+            value_x1000,
+            unit,
+            min_x1000,
+            max_x1000,
+        }
+    }
+
+    /// Checks if a value lies within the acceptable range
+    pub fn is_in_range(&self, value_x1000: i64) -> bool {
+        (self.min_x1000..=self.max_x1000).contains(&value_x1000)
+    }
+}
+
+/// Recipe Register CRDT
+///
+/// Keeps the recipe setpoints and the measured process values in two
+/// [`LWWMap`]s keyed by parameter ID; merge takes the latest write per key.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `ENTRIES`: Maximum number of recipe parameters
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::industrial::{RecipeRegister, RecipeValue};
+///
+/// const TEMPERATURE: u32 = 1;
+/// const PRESSURE: u32 = 2;
+///
+/// let mut plc = RecipeRegister::<DefaultConfig>::new(1);
+/// plc.apply_recipe_file(
+///     &[
+///         (TEMPERATURE, RecipeValue::new(180_000, 0, 175_000, 185_000)),
+///         (PRESSURE, RecipeValue::new(120_000, 1, 0, 300_000)),
+///     ],
+///     1000,
+/// )?;
+///
+/// plc.report_value(TEMPERATURE, 172_500, 1100)?;
+/// assert_eq!(plc.setpoint_delta(TEMPERATURE), Some(-7_500));
+/// assert!(!plc.validate_setpoint(TEMPERATURE));
+/// assert!(plc.out_of_range_keys().eq([TEMPERATURE]));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecipeRegister<C: MemoryConfig, const ENTRIES: usize = 16> {
+    /// Recipe setpoints per parameter
+    setpoints: LWWMap<u32, RecipeValue, C, ENTRIES>,
+    /// Latest measured value × 1000 per parameter
+    measurements: LWWMap<u32, i64, C, ENTRIES>,
+}
+
+impl<C: MemoryConfig, const ENTRIES: usize> RecipeRegister<C, ENTRIES> {
+    /// Creates a new empty recipe register
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this controller
+    ///
+    /// # Returns
+    /// A new recipe register CRDT
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            setpoints: LWWMap::with_capacity(node_id),
+            measurements: LWWMap::with_capacity(node_id),
+        }
+    }
+
+    /// Applies a recipe as one batch of setpoint writes
+    ///
+    /// The file is checked before anything is written, so either every
+    /// entry is applied or none is. Entries for the same key later in the
+    /// file win.
+    ///
+    /// # Arguments
+    /// * `entries` - `(parameter, setpoint)` pairs
+    /// * `timestamp` - The timestamp for all writes
+    ///
+    /// # Returns
+    /// The number of entries applied, `InvalidOperation` if an entry has
+    /// `min_x1000 > max_x1000`, or `BufferOverflow` if the new parameters
+    /// do not fit
+    pub fn apply_recipe_file(
+        &mut self,
+        entries: &[(u32, RecipeValue)],
+        timestamp: u64,
+    ) -> CRDTResult<usize> {
+        if entries
+            .iter()
+            .any(|(_, setpoint)| setpoint.min_x1000 > setpoint.max_x1000)
+        {
+            return Err(CRDTError::InvalidOperation);
+        }
+
+        let new_keys = entries
+            .iter()
+            .enumerate()
+            .filter(|&(i, (key, _))| {
+                !self.setpoints.contains_key(key)
+                    && !entries[..i].iter().any(|(earlier, _)| earlier == key)
+            })
+            .count();
+        if new_keys > self.setpoints.remaining_capacity() {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        for &(key, setpoint) in entries {
+            self.setpoints.insert(key, setpoint, timestamp)?;
+        }
+        Ok(entries.len())
+    }
+
+    /// Records a measured process value
+    ///
+    /// # Arguments
+    /// * `key` - The recipe parameter
+    /// * `value_x1000` - The measured value × 1000
+    /// * `timestamp` - When the value was measured
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `BufferOverflow` if the register is full
+    pub fn report_value(&mut self, key: u32, value_x1000: i64, timestamp: u64) -> CRDTResult<()> {
+        self.measurements.insert(key, value_x1000, timestamp)?;
+        Ok(())
+    }
+
+    /// Gets the recipe setpoint of a parameter
+    pub fn setpoint(&self, key: u32) -> Option<&RecipeValue> {
+        self.setpoints.get(&key)
+    }
+
+    /// Gets the latest measured value of a parameter
+    pub fn current_value(&self, key: u32) -> Option<i64> {
+        self.measurements.get(&key).copied()
+    }
+
+    /// Checks that a parameter's current value is within its recipe range
+    ///
+    /// Until a value is measured, the setpoint itself is checked.
+    ///
+    /// # Returns
+    /// true if the parameter has a setpoint and its value is in range
+    pub fn validate_setpoint(&self, key: u32) -> bool {
+        self.setpoint(key).is_some_and(|setpoint| {
+            let current = self.current_value(key).unwrap_or(setpoint.value_x1000);
+            setpoint.is_in_range(current)
+        })
+    }
+
+    /// Returns the parameters whose current value is out of range
+    pub fn out_of_range_keys(&self) -> impl Iterator<Item = u32> + '_ {
+        self.setpoints
+            .keys()
+            .copied()
+            .filter(|&key| !self.validate_setpoint(key))
+    }
+
+    /// Returns `current - target` for a parameter, e.g. as a PID error input
+    ///
+    /// # Returns
+    /// The difference × 1000, or None without a setpoint or a measurement
+    pub fn setpoint_delta(&self, key: u32) -> Option<i64> {
+        let target = self.setpoint(key)?.value_x1000;
+        Some(self.current_value(key)?.saturating_sub(target))
+    }
+
+    /// Returns the number of parameters with a setpoint
+    pub fn len(&self) -> usize {
+        self.setpoints.len()
+    }
+
+    /// Returns true if no recipe has been applied
+    pub fn is_empty(&self) -> bool {
+        self.setpoints.is_empty()
+    }
+}
+
+impl<C: MemoryConfig, const ENTRIES: usize> CRDT<C> for RecipeRegister<C, ENTRIES> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.setpoints.merge(&other.setpoints)?;
+        self.measurements.merge(&other.measurements)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.setpoints.eq(&other.setpoints) && self.measurements.eq(&other.measurements)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.setpoints.validate()?;
+        self.measurements.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.setpoints.state_hash() ^ self.measurements.state_hash().rotate_left(16)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.setpoints.can_merge(&other.setpoints)
+            && self.measurements.can_merge(&other.measurements)
+    }
+}
+
+impl<C: MemoryConfig, const ENTRIES: usize> BoundedCRDT<C> for RecipeRegister<C, ENTRIES> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = ENTRIES;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.setpoints.len()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        Ok(self.setpoints.compact()? + self.measurements.compact()?)
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.element_count() < Self::MAX_ELEMENTS
+    }
+}
+
+impl<C: MemoryConfig, const ENTRIES: usize> RealTimeCRDT<C> for RecipeRegister<C, ENTRIES> {
+    const MAX_MERGE_CYCLES: u32 =
+        <LWWMap<u32, RecipeValue, C, ENTRIES> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES
+            + <LWWMap<u32, i64, C, ENTRIES> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 =
+        <LWWMap<u32, RecipeValue, C, ENTRIES> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES
+            + <LWWMap<u32, i64, C, ENTRIES> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 =
+        <LWWMap<u32, RecipeValue, C, ENTRIES> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES
+            + <LWWMap<u32, i64, C, ENTRIES> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For industrial systems, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For industrial systems, we don't limit budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    const TEMPERATURE: u32 = 1;
+    const PRESSURE: u32 = 2;
+    const SPEED: u32 = 3;
+
+    #[test]
+    fn test_apply_recipe_and_check_ranges() {
+        let mut plc = RecipeRegister::<DefaultConfig, 2>::new(1);
+        let recipe = [
+            (TEMPERATURE, RecipeValue::new(180_000, 0, 175_000, 185_000)),
+            (PRESSURE, RecipeValue::new(120_000, 1, 0, 300_000)),
+        ];
+        assert_eq!(plc.apply_recipe_file(&recipe, 1000), Ok(2));
+        assert_eq!(plc.len(), 2);
+
+        // Nothing measured yet: the setpoints are checked
+        assert!(plc.validate_setpoint(TEMPERATURE));
+        assert_eq!(plc.setpoint_delta(TEMPERATURE), None);
+        assert!(!plc.validate_setpoint(SPEED));
+
+        plc.report_value(TEMPERATURE, 186_000, 1100).unwrap();
+        plc.report_value(PRESSURE, 119_000, 1100).unwrap();
+        assert_eq!(plc.setpoint_delta(TEMPERATURE), Some(6_000));
+        assert_eq!(plc.setpoint_delta(PRESSURE), Some(-1_000));
+        assert!(plc.out_of_range_keys().eq([TEMPERATURE]));
+
+        // Invalid or oversized files are rejected as a whole
+        let bad_range = [(PRESSURE, RecipeValue::new(0, 1, 10, 5))];
+        assert_eq!(
+            plc.apply_recipe_file(&bad_range, 2000),
+            Err(CRDTError::InvalidOperation)
+        );
+        let too_big = [
+            (TEMPERATURE, RecipeValue::new(190_000, 0, 185_000, 195_000)),
+            (SPEED, RecipeValue::new(1_500_000, 2, 0, 3_000_000)),
+        ];
+        assert_eq!(
+            plc.apply_recipe_file(&too_big, 2000),
+            Err(CRDTError::BufferOverflow)
+        );
+        assert_eq!(plc.setpoint(TEMPERATURE).unwrap().value_x1000, 180_000);
+
+        // A new recipe moves the target under the measured value
+        let next = [(TEMPERATURE, RecipeValue::new(190_000, 0, 185_000, 195_000))];
+        assert_eq!(plc.apply_recipe_file(&next, 2000), Ok(1));
+        assert_eq!(plc.setpoint_delta(TEMPERATURE), Some(-4_000));
+        assert_eq!(plc.out_of_range_keys().count(), 0);
+    }
+
+    #[test]
+    fn test_merge_follows_lww() {
+        let mut plc1 = RecipeRegister::<DefaultConfig>::new(1);
+        let mut plc2 = RecipeRegister::<DefaultConfig>::new(2);
+        plc1.apply_recipe_file(
+            &[(SPEED, RecipeValue::new(1_000_000, 2, 0, 2_000_000))],
+            1000,
+        )
+        .unwrap();
+        plc2.apply_recipe_file(
+            &[(SPEED, RecipeValue::new(1_200_000, 2, 0, 2_000_000))],
+            2000,
+        )
+        .unwrap();
+        plc2.report_value(SPEED, 1_150_000, 2100).unwrap();
+
+        let mut merged1 = plc1.clone();
+        merged1.merge(&plc2).unwrap();
+        let mut merged2 = plc2.clone();
+        merged2.merge(&plc1).unwrap();
+
+        assert!(merged1.eq(&merged2));
+        assert_eq!(merged1.state_hash(), merged2.state_hash());
+        assert_eq!(merged1.setpoint(SPEED).unwrap().value_x1000, 1_200_000);
+        assert_eq!(merged1.setpoint_delta(SPEED), Some(-50_000));
+        assert!(merged1.validate().is_ok());
+    }
+}
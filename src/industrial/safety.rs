@@ -0,0 +1,136 @@
+//! Safety Integrity Levels for Industrial Control
+//!
+//! This module defines the IEC 61508 safety integrity levels as a merge
+//! hierarchy, so memory configurations for industrial deployments can set
+//! `type SafetyConfig = SILLevel` and get the same "higher safety wins"
+//! merge semantics the automotive module provides for ASILs.
+
+use crate::traits::SafetyMergePolicy;
+
+#[cfg(feature = "automotive")]
+use crate::automotive::SafetyLevel;
+
+/// IEC 61508 Safety Integrity Levels
+///
+/// Unlike [`error::SILLevel`](crate::error::SILLevel), which names the
+/// level in safety error reports, this includes [`Ql`](SILLevel::Ql) for
+/// functions without a SIL rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum SILLevel {
+    /// Quality level (no safety integrity requirements)
+    Ql = 0,
+    /// SIL 1 - Lowest safety integrity level
+    SIL1 = 1,
+    /// SIL 2
+    SIL2 = 2,
+    /// SIL 3
+    SIL3 = 3,
+    /// SIL 4 - Highest safety integrity level
+    SIL4 = 4,
+}
+
+impl SILLevel {
+    /// Returns true if this SIL level is safety-critical (SIL 1-4)
+    pub fn is_safety_critical(&self) -> bool {
+        *self != SILLevel::Ql
+    }
+}
+
+impl SafetyMergePolicy for SILLevel {
+    fn priority(level: Self) -> u8 {
+        level as u8
+    }
+
+    fn is_safety_critical(level: Self) -> bool {
+        level.is_safety_critical()
+    }
+}
+
+/// Maps a safety level onto the SIL scale by its numeric priority
+#[cfg(feature = "automotive")]
+impl From<SafetyLevel> for SILLevel {
+    fn from(level: SafetyLevel) -> Self {
+        match level.priority() {
+            0 => SILLevel::Ql,
+            1 => SILLevel::SIL1,
+            2 => SILLevel::SIL2,
+            3 => SILLevel::SIL3,
+            _ => SILLevel::SIL4,
+        }
+    }
+}
+
+#[cfg(feature = "automotive")]
+impl From<SILLevel> for SafetyLevel {
+    fn from(level: SILLevel) -> Self {
+        SafetyLevel::Industrial(level as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sil_level_policy() {
+        assert!(SILLevel::SIL4 > SILLevel::SIL3);
+        assert!(SILLevel::SIL1 > SILLevel::Ql);
+        assert_eq!(SILLevel::priority(SILLevel::SIL3), 3);
+        assert!(SILLevel::priority(SILLevel::SIL2) > SILLevel::priority(SILLevel::SIL1));
+        assert!(<SILLevel as SafetyMergePolicy>::is_safety_critical(
+            SILLevel::SIL1
+        ));
+        assert!(!<SILLevel as SafetyMergePolicy>::is_safety_critical(
+            SILLevel::Ql
+        ));
+    }
+
+    #[cfg(feature = "automotive")]
+    #[test]
+    fn test_sil_hierarchy_in_safety_merge() {
+        use crate::automotive::{ASILLevel, SafetyCRDT};
+        use crate::memory::{DefaultConfig, define_memory_config};
+        use crate::traits::CRDT;
+
+        define_memory_config! {
+            name: PlantConfig,
+            total_memory: 32 * 1024,
+            max_registers: 50,
+            max_counters: 25,
+            max_sets: 15,
+            max_maps: 10,
+            max_nodes: 16,
+            safety_config: SILLevel,
+        }
+
+        // A SIL 2 interlock and a later ASIL A request
+        let interlock = SafetyLevel::from(SILLevel::SIL2);
+        let request = SafetyLevel::automotive(ASILLevel::AsilA);
+
+        let mut plant = SafetyCRDT::<u8, PlantConfig>::new(1, interlock);
+        plant.set(0, 1000).unwrap();
+        let mut vehicle = SafetyCRDT::<u8, PlantConfig>::new(2, request);
+        vehicle.set(1, 2000).unwrap();
+        plant.merge(&vehicle).unwrap();
+        assert_eq!(plant.get(), Some(&0)); // SIL 2 outranks SIL 1
+
+        // Without a hierarchy, industrial levels sort above every ASIL
+        let mut plant = SafetyCRDT::<u8, DefaultConfig>::new(1, interlock);
+        plant.set(0, 1000).unwrap();
+        let mut vehicle =
+            SafetyCRDT::<u8, DefaultConfig>::new(2, SafetyLevel::automotive(ASILLevel::AsilD));
+        vehicle.set(1, 2000).unwrap();
+        plant.merge(&vehicle).unwrap();
+        assert_eq!(plant.get(), Some(&0));
+
+        // In the SIL hierarchy ASIL D maps to SIL 4 and wins
+        let mut plant = SafetyCRDT::<u8, PlantConfig>::new(1, interlock);
+        plant.set(0, 1000).unwrap();
+        let mut vehicle =
+            SafetyCRDT::<u8, PlantConfig>::new(2, SafetyLevel::automotive(ASILLevel::AsilD));
+        vehicle.set(1, 2000).unwrap();
+        plant.merge(&vehicle).unwrap();
+        assert_eq!(plant.get(), Some(&1));
+    }
+}
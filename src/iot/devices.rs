@@ -3,8 +3,9 @@
 //! This module implements CRDTs for distributed IoT device management,
 //! enabling coordination of device states across IoT networks.
 
-use crate::clock::CompactTimestamp;
+use crate::clock::{CompactTimestamp, FnvHasher, StateHash};
 use crate::error::{CRDTError, CRDTResult};
+use crate::maps::LWWMap;
 use crate::memory::{MemoryConfig, NodeId};
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
@@ -36,6 +37,19 @@ impl ConnectionState {
     pub fn is_healthy(&self) -> bool {
         matches!(self, ConnectionState::Online | ConnectionState::Sleeping)
     }
+
+    /// Converts a raw state byte back into a connection state
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ConnectionState::Offline),
+            1 => Some(ConnectionState::Connecting),
+            2 => Some(ConnectionState::Online),
+            3 => Some(ConnectionState::Sleeping),
+            4 => Some(ConnectionState::Unstable),
+            5 => Some(ConnectionState::Maintenance),
+            _ => None,
+        }
+    }
 }
 
 /// IoT device operational status
@@ -69,6 +83,19 @@ impl DeviceStatus {
             DeviceStatus::Normal | DeviceStatus::Warning | DeviceStatus::Updating
         )
     }
+
+    /// Converts a raw status byte back into a device status
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(DeviceStatus::Normal),
+            1 => Some(DeviceStatus::Warning),
+            2 => Some(DeviceStatus::Error),
+            3 => Some(DeviceStatus::Critical),
+            4 => Some(DeviceStatus::Failed),
+            5 => Some(DeviceStatus::Updating),
+            _ => None,
+        }
+    }
 }
 
 /// Individual IoT device information
@@ -196,6 +223,9 @@ pub struct DeviceRegistry<C: MemoryConfig> {
 }
 
 impl<C: MemoryConfig> DeviceRegistry<C> {
+    /// Size of an encoded announcement in bytes
+    pub const ANNOUNCEMENT_LEN: usize = 24;
+
     /// Creates a new device registry
     ///
     /// # Arguments
@@ -415,6 +445,149 @@ impl<C: MemoryConfig> DeviceRegistry<C> {
         removed
     }
 
+    /// Checks if a device has not been seen within `ttl_ms`
+    ///
+    /// # Arguments
+    /// * `device_id` - Device to check
+    /// * `current_time` - Current timestamp
+    /// * `ttl_ms` - How long a device may stay silent, in milliseconds
+    ///
+    /// # Returns
+    /// true if the device is registered and stale, false otherwise
+    pub fn is_stale(&self, device_id: NodeId, current_time: u64, ttl_ms: u64) -> bool {
+        self.get_device(device_id)
+            .is_some_and(|device| device.is_stale(current_time, ttl_ms))
+    }
+
+    /// Evicts devices that have not been seen within `ttl_ms`
+    ///
+    /// Frees slots for newly discovered devices. Like
+    /// [`cleanup_stale_devices`](Self::cleanup_stale_devices), this is a local
+    /// operation: a peer that still lists an evicted device brings it back on
+    /// the next merge until that peer evicts it too.
+    ///
+    /// # Arguments
+    /// * `current_time` - Current timestamp
+    /// * `ttl_ms` - How long a device may stay silent, in milliseconds
+    ///
+    /// # Returns
+    /// Number of devices evicted
+    pub fn evict_stale(&mut self, current_time: u64, ttl_ms: u64) -> usize {
+        self.cleanup_stale_devices(current_time, ttl_ms)
+    }
+
+    /// Encodes this gateway's own device entry as a discovery announcement
+    ///
+    /// The announcement is a fixed [`ANNOUNCEMENT_LEN`](Self::ANNOUNCEMENT_LEN)
+    /// bytes, small enough for a single CAN FD or radio frame:
+    ///
+    /// | Bytes | Content |
+    /// |-------|---------|
+    /// | 0 | Device ID |
+    /// | 1 | Gateway ID |
+    /// | 2-3 | Device type (little endian) |
+    /// | 4 | Connection state |
+    /// | 5 | Device status |
+    /// | 6 | Battery level |
+    /// | 7 | Signal strength |
+    /// | 8-15 | Last seen timestamp (little endian) |
+    /// | 16-23 | Last update timestamp (little endian) |
+    ///
+    /// # Arguments
+    /// * `buf` - The output buffer
+    ///
+    /// # Returns
+    /// The number of bytes written, `CRDTError::InvalidNodeId` if this
+    /// gateway has not registered itself, or `CRDTError::BufferOverflow` if
+    /// the buffer is too small
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// use crdtosphere::iot::{ConnectionState, DeviceRegistry};
+    ///
+    /// let mut sensor = DeviceRegistry::<DefaultConfig>::new(7);
+    /// sensor.register_device(7, 0x1001, 1000)?;
+    /// sensor.update_device_connection(7, ConnectionState::Online, 1000)?;
+    ///
+    /// let mut buf = [0u8; 24];
+    /// let len = sensor.announce_self_bytes(&mut buf)?;
+    ///
+    /// // A gateway hearing the broadcast learns about the sensor
+    /// let mut gateway = DeviceRegistry::<DefaultConfig>::new(1);
+    /// gateway.apply_announcement(&buf[..len])?;
+    /// assert_eq!(
+    ///     gateway.get_device(7).map(|d| d.connection_state),
+    ///     Some(ConnectionState::Online)
+    /// );
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn announce_self_bytes(&self, buf: &mut [u8]) -> CRDTResult<usize> {
+        let device = self
+            .get_device(self.local_gateway_id)
+            .ok_or(CRDTError::InvalidNodeId)?;
+        if buf.len() < Self::ANNOUNCEMENT_LEN {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        buf[0] = device.device_id;
+        buf[1] = device.gateway_id;
+        buf[2..4].copy_from_slice(&device.device_type.to_le_bytes());
+        buf[4] = device.connection_state as u8;
+        buf[5] = device.device_status as u8;
+        buf[6] = device.battery_level;
+        buf[7] = device.signal_strength;
+        buf[8..16].copy_from_slice(&device.last_seen.as_u64().to_le_bytes());
+        buf[16..24].copy_from_slice(&device.last_update.as_u64().to_le_bytes());
+        Ok(Self::ANNOUNCEMENT_LEN)
+    }
+
+    /// Merges a device announcement received from another node
+    ///
+    /// Equivalent to merging a registry that holds only the announced
+    /// device: the entry is added, or replaces the stored one if it is newer.
+    ///
+    /// # Arguments
+    /// * `bytes` - An announcement produced by
+    ///   [`announce_self_bytes`](Self::announce_self_bytes)
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `CRDTError::InvalidState` for a malformed
+    /// announcement, `CRDTError::InvalidNodeId` for IDs outside the
+    /// configuration, or `CRDTError::BufferOverflow` if the registry is full
+    pub fn apply_announcement(&mut self, bytes: &[u8]) -> CRDTResult<()> {
+        if bytes.len() != Self::ANNOUNCEMENT_LEN {
+            return Err(CRDTError::InvalidState);
+        }
+
+        let timestamp_at = |offset: usize| {
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&bytes[offset..offset + 8]);
+            CompactTimestamp::new(u64::from_le_bytes(raw))
+        };
+        let device = DeviceInfo {
+            device_id: bytes[0],
+            gateway_id: bytes[1],
+            device_type: u16::from_le_bytes([bytes[2], bytes[3]]),
+            connection_state: ConnectionState::from_u8(bytes[4]).ok_or(CRDTError::InvalidState)?,
+            device_status: DeviceStatus::from_u8(bytes[5]).ok_or(CRDTError::InvalidState)?,
+            battery_level: bytes[6],
+            signal_strength: bytes[7],
+            last_seen: timestamp_at(8),
+            last_update: timestamp_at(16),
+        };
+
+        if device.device_id as usize >= C::MAX_NODES || device.gateway_id as usize >= C::MAX_NODES {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        self.add_device_info(device)?;
+        if device.last_update > self.last_update {
+            self.last_update = device.last_update;
+        }
+        Ok(())
+    }
+
     /// Finds a device by ID (mutable)
     fn find_device_mut(&mut self, device_id: NodeId) -> Option<&mut DeviceInfo> {
         for device_opt in &mut self.devices {
@@ -619,6 +792,340 @@ impl<C: MemoryConfig> RealTimeCRDT<C> for DeviceRegistry<C> {
     }
 }
 
+/// Firmware update progress of a device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum UpdateState {
+    /// Device runs its target version
+    Current = 0,
+    /// A newer version was requested but not yet started
+    Pending = 1,
+    /// Device is downloading the new image
+    Downloading = 2,
+    /// The last update attempt failed
+    Failed = 3,
+}
+
+impl UpdateState {
+    /// Returns true if an update has been requested but not completed
+    pub fn is_in_progress(&self) -> bool {
+        matches!(self, UpdateState::Pending | UpdateState::Downloading)
+    }
+}
+
+/// Firmware record of a single device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirmwareInfo {
+    /// Version the device reported running
+    pub version: u32,
+    /// Version the device should run; equal to `version` when up to date
+    pub target_version: u32,
+    /// When the reported version was first seen
+    pub install_timestamp: u64,
+    /// Update progress
+    pub update_state: UpdateState,
+    /// Checksum of the running image
+    pub checksum: u32,
+}
+
+impl StateHash for FirmwareInfo {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        self.version.hash_into(hasher);
+        self.target_version.hash_into(hasher);
+        self.install_timestamp.hash_into(hasher);
+        (self.update_state as u8).hash_into(hasher);
+        self.checksum.hash_into(hasher);
+    }
+}
+
+/// Firmware Version Map CRDT
+///
+/// Tracks the firmware each device runs and the progress of rollouts.
+/// Records are keyed by device ID in an [`LWWMap`], so the newest write
+/// for a device wins on merge.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+/// - `CAPACITY`: Maximum number of devices
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::iot::{FirmwareVersionMap, UpdateState};
+///
+/// let mut gateway = FirmwareVersionMap::<DefaultConfig>::new(1);
+/// gateway.report_version(100, 3, 0xABCD, 1000)?;
+/// gateway.report_version(101, 3, 0xABCD, 1000)?;
+///
+/// // Roll out version 4 to one device
+/// gateway.set_update_target(100, 4, 2000)?;
+/// assert_eq!(gateway.devices_needing_update(4).count(), 2);
+///
+/// gateway.report_version(100, 4, 0xBEEF, 3000)?;
+/// assert_eq!(gateway.firmware(100).unwrap().update_state, UpdateState::Current);
+/// assert_eq!(gateway.update_completion_fraction(4), 0.5);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FirmwareVersionMap<C: MemoryConfig, const CAPACITY: usize = 16> {
+    /// Firmware record per device ID
+    versions: LWWMap<u32, FirmwareInfo, C, CAPACITY>,
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> FirmwareVersionMap<C, CAPACITY> {
+    /// Creates a new firmware version map
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this gateway
+    ///
+    /// # Returns
+    /// A new firmware version map CRDT
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            versions: LWWMap::with_capacity(node_id),
+        }
+    }
+
+    /// Records the firmware a device reports running
+    ///
+    /// A device reaching its target version (or any version newer than its
+    /// previous one when no update is pending) becomes `Current`. A device
+    /// still reporting an older version keeps its update state.
+    ///
+    /// # Arguments
+    /// * `device_id` - The reporting device
+    /// * `version` - The running firmware version
+    /// * `checksum` - Checksum of the running image
+    /// * `timestamp` - When the report was received
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `BufferOverflow` if the map is full
+    pub fn report_version(
+        &mut self,
+        device_id: u32,
+        version: u32,
+        checksum: u32,
+        timestamp: u64,
+    ) -> CRDTResult<()> {
+        let previous = self.firmware(device_id).copied();
+
+        let (update_state, target_version) = match previous {
+            Some(info)
+                if info.update_state != UpdateState::Current && version < info.target_version =>
+            {
+                (info.update_state, info.target_version)
+            }
+            _ => (UpdateState::Current, version),
+        };
+        let install_timestamp = match previous {
+            Some(info) if info.version == version => info.install_timestamp,
+            _ => timestamp,
+        };
+
+        self.versions.insert(
+            device_id,
+            FirmwareInfo {
+                version,
+                target_version,
+                install_timestamp,
+                update_state,
+                checksum,
+            },
+            timestamp,
+        )?;
+        Ok(())
+    }
+
+    /// Requests an update of a device to `target_version`
+    ///
+    /// Devices already running `target_version` or newer are left alone.
+    ///
+    /// # Arguments
+    /// * `device_id` - The device to update
+    /// * `target_version` - The version to roll out
+    /// * `timestamp` - When the rollout was requested
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `InvalidOperation` if the device never reported
+    /// a version
+    pub fn set_update_target(
+        &mut self,
+        device_id: u32,
+        target_version: u32,
+        timestamp: u64,
+    ) -> CRDTResult<()> {
+        let info = *self
+            .firmware(device_id)
+            .ok_or(CRDTError::InvalidOperation)?;
+        if info.version >= target_version {
+            return Ok(());
+        }
+
+        self.versions.insert(
+            device_id,
+            FirmwareInfo {
+                target_version,
+                update_state: UpdateState::Pending,
+                ..info
+            },
+            timestamp,
+        )?;
+        Ok(())
+    }
+
+    /// Records the progress of a pending update
+    ///
+    /// # Arguments
+    /// * `device_id` - The device being updated
+    /// * `state` - The new update state, e.g. `Downloading` or `Failed`
+    /// * `timestamp` - When the state changed
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `InvalidOperation` if the device never reported
+    /// a version
+    pub fn set_update_state(
+        &mut self,
+        device_id: u32,
+        state: UpdateState,
+        timestamp: u64,
+    ) -> CRDTResult<()> {
+        let info = *self
+            .firmware(device_id)
+            .ok_or(CRDTError::InvalidOperation)?;
+
+        self.versions.insert(
+            device_id,
+            FirmwareInfo {
+                update_state: state,
+                ..info
+            },
+            timestamp,
+        )?;
+        Ok(())
+    }
+
+    /// Gets the firmware record of a device
+    pub fn firmware(&self, device_id: u32) -> Option<&FirmwareInfo> {
+        self.versions.get(&device_id)
+    }
+
+    /// Returns the devices running a version older than `target_version`
+    pub fn devices_needing_update(&self, target_version: u32) -> impl Iterator<Item = u32> + '_ {
+        self.versions
+            .iter()
+            .filter(move |(_, info)| info.version < target_version)
+            .map(|(device_id, _)| *device_id)
+    }
+
+    /// Returns the fraction of devices that run `target_version` or newer
+    ///
+    /// # Returns
+    /// A value between 0.0 and 1.0, or 0.0 if no device has reported
+    pub fn update_completion_fraction(&self, target_version: u32) -> f32 {
+        let total = self.versions.len();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let completed = self
+            .versions
+            .values()
+            .filter(|info| {
+                info.version >= target_version && info.update_state == UpdateState::Current
+            })
+            .count();
+        completed as f32 / total as f32
+    }
+
+    /// Returns the devices whose last update attempt failed
+    pub fn failed_devices(&self) -> impl Iterator<Item = u32> + '_ {
+        self.versions
+            .iter()
+            .filter(|(_, info)| info.update_state == UpdateState::Failed)
+            .map(|(device_id, _)| *device_id)
+    }
+
+    /// Returns the number of devices with a firmware record
+    pub fn device_count(&self) -> usize {
+        self.versions.len()
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> CRDT<C> for FirmwareVersionMap<C, CAPACITY> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.versions.merge(&other.versions)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.versions.eq(&other.versions)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.versions.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.versions.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.versions.can_merge(&other.versions)
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for FirmwareVersionMap<C, CAPACITY> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.versions.element_count()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        self.versions.compact()
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.versions.can_add_element()
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for FirmwareVersionMap<C, CAPACITY> {
+    const MAX_MERGE_CYCLES: u32 =
+        <LWWMap<u32, FirmwareInfo, C, CAPACITY> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 =
+        <LWWMap<u32, FirmwareInfo, C, CAPACITY> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 =
+        <LWWMap<u32, FirmwareInfo, C, CAPACITY> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For IoT systems, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For IoT systems, we don't limit budget
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -782,6 +1289,100 @@ mod tests {
         assert_eq!(registry.device_count(), 0);
     }
 
+    #[test]
+    fn test_announcement_roundtrip() {
+        let mut sensor = DeviceRegistry::<DefaultConfig>::new(7);
+        let mut buf = [0u8; 32];
+
+        // Nothing to announce before registering itself
+        assert_eq!(
+            sensor.announce_self_bytes(&mut buf),
+            Err(CRDTError::InvalidNodeId)
+        );
+
+        sensor.register_device(7, 0x2002, 1000).unwrap();
+        sensor.update_device_vitals(7, 80, 200, 1500).unwrap();
+        sensor
+            .update_device_status(7, DeviceStatus::Warning, 1600)
+            .unwrap();
+
+        let len = sensor.announce_self_bytes(&mut buf).unwrap();
+        assert_eq!(len, DeviceRegistry::<DefaultConfig>::ANNOUNCEMENT_LEN);
+        assert_eq!(
+            sensor.announce_self_bytes(&mut buf[..len - 1]),
+            Err(CRDTError::BufferOverflow)
+        );
+
+        // Applying the announcement matches merging the sensor's registry
+        let mut gateway = DeviceRegistry::<DefaultConfig>::new(1);
+        gateway.register_device(3, 0x1001, 1000).unwrap();
+        let mut merged = gateway.clone();
+        merged.merge(&sensor).unwrap();
+
+        gateway.apply_announcement(&buf[..len]).unwrap();
+        assert!(gateway.eq(&merged));
+        assert_eq!(gateway.get_device(7), sensor.get_device(7));
+
+        // An older announcement does not roll the entry back
+        sensor.update_device_vitals(7, 70, 180, 3000).unwrap();
+        let mut newer = [0u8; 24];
+        sensor.announce_self_bytes(&mut newer).unwrap();
+        gateway.apply_announcement(&newer).unwrap();
+        gateway.apply_announcement(&buf[..len]).unwrap();
+        assert_eq!(gateway.get_device(7).unwrap().battery_level, 70);
+    }
+
+    #[test]
+    fn test_malformed_announcements() {
+        let mut sensor = DeviceRegistry::<DefaultConfig>::new(7);
+        sensor.register_device(7, 0x2002, 1000).unwrap();
+        let mut buf = [0u8; 24];
+        sensor.announce_self_bytes(&mut buf).unwrap();
+
+        let mut gateway = DeviceRegistry::<DefaultConfig>::new(1);
+        assert_eq!(
+            gateway.apply_announcement(&buf[..23]),
+            Err(CRDTError::InvalidState)
+        );
+
+        let mut bad_state = buf;
+        bad_state[4] = 99;
+        assert_eq!(
+            gateway.apply_announcement(&bad_state),
+            Err(CRDTError::InvalidState)
+        );
+
+        let mut bad_id = buf;
+        bad_id[0] = 200;
+        assert_eq!(
+            gateway.apply_announcement(&bad_id),
+            Err(CRDTError::InvalidNodeId)
+        );
+        assert_eq!(gateway.device_count(), 0);
+    }
+
+    #[test]
+    fn test_evict_stale() {
+        let mut registry = DeviceRegistry::<DefaultConfig>::new(1);
+        registry.register_device(2, 0x1001, 1000).unwrap();
+        registry.register_device(3, 0x1001, 1000).unwrap();
+        registry
+            .update_device_connection(3, ConnectionState::Online, 8000)
+            .unwrap();
+
+        assert!(registry.is_stale(2, 10000, 5000));
+        assert!(!registry.is_stale(3, 10000, 5000));
+        assert!(!registry.is_stale(9, 10000, 5000)); // Unknown device
+
+        assert_eq!(registry.evict_stale(10000, 5000), 1);
+        assert!(registry.get_device(2).is_none());
+        assert!(registry.get_device(3).is_some());
+
+        // The freed slot takes a newly discovered device
+        registry.register_device(4, 0x1001, 10000).unwrap();
+        assert_eq!(registry.device_count(), 2);
+    }
+
     #[test]
     fn test_bounded_crdt_implementation() {
         let mut registry = DeviceRegistry::<DefaultConfig>::new(1);
@@ -802,4 +1403,81 @@ mod tests {
         assert!(registry1.merge_bounded(&registry2).is_ok());
         assert!(registry1.validate_bounded().is_ok());
     }
+
+    #[test]
+    fn test_firmware_rollout() {
+        let mut gateway = FirmwareVersionMap::<DefaultConfig>::new(1);
+        assert_eq!(gateway.update_completion_fraction(2), 0.0);
+        assert_eq!(
+            gateway.set_update_target(7, 2, 1000),
+            Err(CRDTError::InvalidOperation)
+        );
+
+        gateway.report_version(7, 1, 0x1111, 1000).unwrap();
+        gateway.report_version(8, 1, 0x1111, 1000).unwrap();
+        gateway.report_version(9, 2, 0x2222, 1000).unwrap();
+
+        gateway.set_update_target(7, 2, 2000).unwrap();
+        gateway.set_update_target(8, 2, 2000).unwrap();
+        gateway.set_update_target(9, 2, 2000).unwrap();
+        assert_eq!(
+            gateway.firmware(7).unwrap().update_state,
+            UpdateState::Pending
+        );
+        assert_eq!(
+            gateway.firmware(9).unwrap().update_state,
+            UpdateState::Current
+        );
+        assert_eq!(gateway.devices_needing_update(2).count(), 2);
+
+        // An old version report keeps the update pending
+        gateway.report_version(7, 1, 0x1111, 2500).unwrap();
+        assert_eq!(
+            gateway.firmware(7).unwrap().update_state,
+            UpdateState::Pending
+        );
+        assert_eq!(gateway.firmware(7).unwrap().install_timestamp, 1000);
+
+        // Reporting the newer version completes the update
+        gateway.report_version(7, 2, 0x2222, 3000).unwrap();
+        let info = gateway.firmware(7).unwrap();
+        assert_eq!(info.update_state, UpdateState::Current);
+        assert_eq!(info.target_version, 2);
+        assert_eq!(info.install_timestamp, 3000);
+
+        gateway
+            .set_update_state(8, UpdateState::Failed, 3000)
+            .unwrap();
+        assert!(gateway.failed_devices().eq([8]));
+        assert!(gateway.devices_needing_update(2).eq([8]));
+        assert!((gateway.update_completion_fraction(2) - 2.0 / 3.0).abs() < 1e-6);
+        assert!(gateway.validate().is_ok());
+    }
+
+    #[test]
+    fn test_firmware_merge_uses_latest_write() {
+        let mut gateway1 = FirmwareVersionMap::<DefaultConfig>::new(1);
+        let mut gateway2 = FirmwareVersionMap::<DefaultConfig>::new(2);
+        gateway1.report_version(7, 1, 0x1111, 1000).unwrap();
+        gateway2.merge(&gateway1).unwrap();
+
+        gateway2.set_update_target(7, 2, 2000).unwrap();
+        gateway2
+            .set_update_state(7, UpdateState::Downloading, 2100)
+            .unwrap();
+        gateway1.report_version(7, 2, 0x2222, 3000).unwrap();
+
+        let mut merged1 = gateway1.clone();
+        merged1.merge(&gateway2).unwrap();
+        let mut merged2 = gateway2.clone();
+        merged2.merge(&gateway1).unwrap();
+
+        assert!(merged1.eq(&merged2));
+        assert_eq!(merged1.firmware(7).unwrap().version, 2);
+        assert_eq!(
+            merged1.firmware(7).unwrap().update_state,
+            UpdateState::Current
+        );
+        assert_eq!(merged1.update_completion_fraction(2), 1.0);
+    }
 }
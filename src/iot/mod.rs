@@ -5,7 +5,14 @@
 
 pub mod devices;
 pub mod sensors;
+pub mod shadow;
 
 // Re-export main types
-pub use devices::{ConnectionState, DeviceInfo, DeviceRegistry, DeviceStatus};
-pub use sensors::{ReadingQuality, SensorNetwork, SensorReading, SensorType};
+pub use devices::{
+    ConnectionState, DeviceInfo, DeviceRegistry, DeviceStatus, FirmwareInfo, FirmwareVersionMap,
+    UpdateState,
+};
+pub use sensors::{
+    ReadingQuality, SensorBatch, SensorNetwork, SensorReading, SensorStats, SensorType,
+};
+pub use shadow::DeviceShadow;
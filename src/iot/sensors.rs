@@ -4,8 +4,10 @@
 //! enabling aggregation and synchronization of sensor readings across networks.
 
 use crate::clock::CompactTimestamp;
+use crate::counters::{GCounter, PNCounter};
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
 /// IoT sensor types
@@ -77,6 +79,25 @@ impl SensorType {
             SensorType::Digital => 1000,                             // 1 second
         }
     }
+
+    /// Converts a raw type byte back into a sensor type
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(SensorType::Temperature),
+            2 => Some(SensorType::Humidity),
+            3 => Some(SensorType::Pressure),
+            4 => Some(SensorType::Light),
+            5 => Some(SensorType::Motion),
+            6 => Some(SensorType::AirQuality),
+            7 => Some(SensorType::Sound),
+            8 => Some(SensorType::Proximity),
+            9 => Some(SensorType::Accelerometer),
+            10 => Some(SensorType::GPS),
+            11 => Some(SensorType::Analog),
+            12 => Some(SensorType::Digital),
+            _ => None,
+        }
+    }
 }
 
 /// Reading quality indicators
@@ -108,6 +129,17 @@ impl ReadingQuality {
     pub fn is_acceptable(&self) -> bool {
         matches!(self, ReadingQuality::Good | ReadingQuality::Excellent)
     }
+
+    /// Converts a raw quality byte back into a reading quality
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(ReadingQuality::Poor),
+            2 => Some(ReadingQuality::Fair),
+            3 => Some(ReadingQuality::Good),
+            4 => Some(ReadingQuality::Excellent),
+            _ => None,
+        }
+    }
 }
 
 /// Individual sensor reading
@@ -206,6 +238,211 @@ impl SensorReading {
     }
 }
 
+/// Running statistics over sensor readings
+///
+/// Keeps the minimum, maximum, count and sum of every reading recorded on
+/// any replica, so the mean and range survive after the individual readings
+/// are gone. The sum is stored multiplied by 1000 in a `PNCounter`, which
+/// keeps the mean exact to three decimals without floating point.
+///
+/// The minimum and maximum registers remember when the extreme was
+/// observed. Merging keeps the smaller minimum and the larger maximum; equal
+/// extremes fall back to last-writer-wins.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::iot::SensorStats;
+///
+/// let mut gateway1 = SensorStats::<DefaultConfig>::new(1);
+/// gateway1.record_reading(2100, 1000)?;
+/// let mut gateway2 = SensorStats::<DefaultConfig>::new(2);
+/// gateway2.record_reading(2400, 1001)?;
+///
+/// gateway1.merge(&gateway2)?;
+/// assert_eq!(gateway1.reading_count(), 2);
+/// assert_eq!(gateway1.mean_x1000(), Some(2_250_000));
+/// assert_eq!(gateway1.range(), Some((2100, 2400)));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SensorStats<C: MemoryConfig> {
+    /// Smallest reading and when it was observed
+    min_value: LWWRegister<i32, C>,
+    /// Largest reading and when it was observed
+    max_value: LWWRegister<i32, C>,
+    /// Number of readings
+    reading_count: GCounter<C>,
+    /// Sum of all readings multiplied by 1000
+    sum_x1000: PNCounter<C>,
+    /// This replica's ID
+    node_id: NodeId,
+}
+
+impl<C: MemoryConfig> SensorStats<C> {
+    /// Creates empty statistics
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this replica
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            min_value: LWWRegister::new(node_id),
+            max_value: LWWRegister::new(node_id),
+            reading_count: GCounter::new(node_id),
+            sum_x1000: PNCounter::new(node_id),
+            node_id,
+        }
+    }
+
+    /// Records a reading
+    ///
+    /// # Arguments
+    /// * `value` - Reading value
+    /// * `timestamp` - Reading timestamp
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `Overflow` if this replica's share of the sum
+    /// or count no longer fits; the statistics are unchanged on error
+    pub fn record_reading(&mut self, value: i32, timestamp: u64) -> CRDTResult<()> {
+        let amount = value.unsigned_abs() as u64 * 1000;
+        let recorded = if value < 0 {
+            self.sum_x1000.negative_contribution_of(self.node_id)
+        } else {
+            self.sum_x1000.positive_contribution_of(self.node_id)
+        };
+        if recorded as u64 + amount > u32::MAX as u64
+            || self.reading_count.node_value(self.node_id) >= u32::MAX as u64
+        {
+            return Err(CRDTError::Overflow);
+        }
+
+        self.reading_count.increment(1)?;
+        let mut remaining = amount;
+        while remaining > 0 {
+            let chunk = remaining.min(u32::MAX as u64) as u32;
+            if value < 0 {
+                self.sum_x1000.decrement(chunk)?;
+            } else {
+                self.sum_x1000.increment(chunk)?;
+            }
+            remaining -= chunk as u64;
+        }
+
+        if self.min_value.get().is_none_or(|min| value < *min) {
+            self.min_value = LWWRegister::new(self.node_id);
+            self.min_value.set(value, timestamp)?;
+        }
+        if self.max_value.get().is_none_or(|max| value > *max) {
+            self.max_value = LWWRegister::new(self.node_id);
+            self.max_value.set(value, timestamp)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the mean of all readings multiplied by 1000
+    ///
+    /// # Returns
+    /// The mean rounded towards zero, or None if nothing was recorded
+    pub fn mean_x1000(&self) -> Option<i64> {
+        match self.reading_count() {
+            0 => None,
+            count => Some(self.sum_x1000.value() / count as i64),
+        }
+    }
+
+    /// Returns the smallest and largest reading
+    pub fn range(&self) -> Option<(i32, i32)> {
+        Some((*self.min_value.get()?, *self.max_value.get()?))
+    }
+
+    /// Returns the number of readings
+    pub fn reading_count(&self) -> u64 {
+        self.reading_count.value()
+    }
+
+    /// Returns the register holding the smallest reading
+    pub fn min_register(&self) -> &LWWRegister<i32, C> {
+        &self.min_value
+    }
+
+    /// Returns the register holding the largest reading
+    pub fn max_register(&self) -> &LWWRegister<i32, C> {
+        &self.max_value
+    }
+
+    /// Merges an extreme-value register, keeping the value `wins` prefers
+    fn merge_extreme(
+        register: &mut LWWRegister<i32, C>,
+        other: &LWWRegister<i32, C>,
+        node_id: NodeId,
+        wins: fn(i32, i32) -> bool,
+    ) -> CRDTResult<()> {
+        match (register.get(), other.get()) {
+            (_, None) => Ok(()),
+            (Some(own), Some(theirs)) if own == theirs => register.merge(other),
+            (Some(own), Some(theirs)) if !wins(*theirs, *own) => Ok(()),
+            _ => {
+                // Adopt the other extreme, keeping our own node ID
+                let mut adopted = LWWRegister::new(node_id);
+                adopted.merge(other)?;
+                *register = adopted;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<C: MemoryConfig> CRDT<C> for SensorStats<C> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        Self::merge_extreme(
+            &mut self.min_value,
+            &other.min_value,
+            self.node_id,
+            |a, b| a < b,
+        )?;
+        Self::merge_extreme(
+            &mut self.max_value,
+            &other.max_value,
+            self.node_id,
+            |a, b| a > b,
+        )?;
+        self.reading_count.merge(&other.reading_count)?;
+        self.sum_x1000.merge(&other.sum_x1000)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.min_value.eq(&other.min_value)
+            && self.max_value.eq(&other.max_value)
+            && self.reading_count.eq(&other.reading_count)
+            && self.sum_x1000.eq(&other.sum_x1000)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.min_value.validate()?;
+        self.max_value.validate()?;
+        self.reading_count.validate()?;
+        self.sum_x1000.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.min_value.state_hash()
+            ^ self.max_value.state_hash().rotate_left(8)
+            ^ self.reading_count.state_hash().rotate_left(16)
+            ^ self.sum_x1000.state_hash().rotate_left(24)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.reading_count.can_merge(&other.reading_count)
+            && self.sum_x1000.can_merge(&other.sum_x1000)
+    }
+}
+
 /// IoT Sensor Network CRDT
 ///
 /// This CRDT manages distributed sensor data coordination across IoT networks,
@@ -219,6 +456,7 @@ impl SensorReading {
 /// - Quality-based reading prioritization
 /// - Location-based sensor grouping
 /// - Automatic stale data cleanup
+/// - Optional running statistics via [`with_stats`](Self::with_stats)
 ///
 /// # Example
 /// ```rust
@@ -250,6 +488,8 @@ pub struct SensorNetwork<C: MemoryConfig> {
     reading_count: usize,
     /// This gateway's ID
     local_gateway_id: NodeId,
+    /// Running statistics, once enabled
+    stats: Option<SensorStats<C>>,
     /// Last update timestamp
     last_update: CompactTimestamp,
     /// Phantom data for memory config
@@ -269,6 +509,7 @@ impl<C: MemoryConfig> SensorNetwork<C> {
             readings: [const { None }; 128],
             reading_count: 0,
             local_gateway_id: gateway_id,
+            stats: None,
             last_update: CompactTimestamp::new(0),
             _phantom: core::marker::PhantomData,
         }
@@ -348,6 +589,25 @@ impl<C: MemoryConfig> SensorNetwork<C> {
         Ok(())
     }
 
+    /// Enables running statistics and returns them
+    ///
+    /// Readings are not recorded into the statistics automatically; record
+    /// the ones that belong together, typically of one sensor type.
+    ///
+    /// # Arguments
+    /// * `node_id` - Replica ID for the statistics if they are not enabled yet
+    ///
+    /// # Returns
+    /// The statistics of this network
+    pub fn with_stats(&mut self, node_id: NodeId) -> &mut SensorStats<C> {
+        self.stats.get_or_insert_with(|| SensorStats::new(node_id))
+    }
+
+    /// Gets the running statistics, if enabled
+    pub fn stats(&self) -> Option<&SensorStats<C>> {
+        self.stats.as_ref()
+    }
+
     /// Gets all sensor readings
     ///
     /// # Returns
@@ -610,6 +870,11 @@ impl<C: MemoryConfig> CRDT<C> for SensorNetwork<C> {
             self.add_sensor_reading(*reading)?;
         }
 
+        // Statistics enabled on any replica are enabled on all of them
+        if let Some(other_stats) = &other.stats {
+            self.with_stats(self.local_gateway_id).merge(other_stats)?;
+        }
+
         // Update timestamp to latest
         if other.last_update > self.last_update {
             self.last_update = other.last_update;
@@ -623,6 +888,12 @@ impl<C: MemoryConfig> CRDT<C> for SensorNetwork<C> {
             return false;
         }
 
+        match (&self.stats, &other.stats) {
+            (None, None) => {}
+            (Some(stats), Some(other_stats)) if stats.eq(other_stats) => {}
+            _ => return false,
+        }
+
         // Check that all readings match
         for reading in self.all_readings() {
             let mut found = false;
@@ -659,6 +930,9 @@ impl<C: MemoryConfig> CRDT<C> for SensorNetwork<C> {
                 ^ (reading.value as u32);
         }
         hash ^= self.reading_count as u32;
+        if let Some(stats) = &self.stats {
+            hash ^= stats.state_hash().rotate_left(16);
+        }
         hash
     }
 
@@ -715,6 +989,254 @@ impl<C: MemoryConfig> RealTimeCRDT<C> for SensorNetwork<C> {
     }
 }
 
+/// Sensor readings batched into one frame
+///
+/// Amortizes frame overhead on LoRa or BLE links by sending several readings
+/// against a shared base timestamp. The batch is a transport container, not
+/// a CRDT; the receiving gateway applies it to its [`SensorNetwork`].
+///
+/// Only the sensor ID and type, value, quality and timestamp are sent.
+/// Decoded readings have location 0 and unknown vitals, as from
+/// [`SensorReading::new`].
+///
+/// # Type Parameters
+/// - `C`: The memory configuration, which bounds the sensor IDs
+/// - `N`: The maximum number of readings; at most 255 can be encoded
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::configs::IoTSensorConfig;
+/// use crdtosphere::iot::{ReadingQuality, SensorBatch, SensorNetwork, SensorReading, SensorType};
+///
+/// type Batch = SensorBatch<IoTSensorConfig, 8>;
+///
+/// let mut batch = Batch::new();
+/// batch.push(SensorReading::new(3, SensorType::Temperature, 2350, ReadingQuality::Good, 1000, 0))?;
+/// batch.push(SensorReading::new(4, SensorType::Humidity, 4500, ReadingQuality::Good, 1200, 0))?;
+///
+/// let mut frame = [0u8; 64];
+/// let len = batch.encode_to_slice(&mut frame)?;
+/// assert_eq!(len, Batch::HEADER_LEN + 2 * Batch::READING_LEN);
+///
+/// // The gateway decodes the frame and applies it
+/// let received = Batch::decode_from_slice(&frame[..len])?;
+/// let mut network = SensorNetwork::<IoTSensorConfig>::new(0);
+/// assert_eq!(received.apply_to_network(&mut network)?, 2);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SensorBatch<C: MemoryConfig, const N: usize> {
+    /// Readings in the order they were pushed
+    readings: [Option<SensorReading>; N],
+    /// Number of readings in the batch
+    len: usize,
+    /// Phantom data for memory config
+    _phantom: core::marker::PhantomData<C>,
+}
+
+impl<C: MemoryConfig, const N: usize> SensorBatch<C, N> {
+    /// Size of the encoded batch header in bytes
+    ///
+    /// | Bytes | Content |
+    /// |-------|---------|
+    /// | 0 | Number of readings |
+    /// | 1-8 | Base timestamp, the earliest in the batch (little endian) |
+    pub const HEADER_LEN: usize = 9;
+
+    /// Size of each encoded reading in bytes
+    ///
+    /// | Bytes | Content |
+    /// |-------|---------|
+    /// | 0 | Sensor ID |
+    /// | 1 | Sensor type |
+    /// | 2-5 | Value (little endian) |
+    /// | 6 | Reading quality |
+    /// | 7-8 | Milliseconds since the base timestamp (little endian) |
+    pub const READING_LEN: usize = 9;
+
+    /// Creates an empty batch
+    pub fn new() -> Self {
+        Self {
+            readings: [const { None }; N],
+            len: 0,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Adds a reading to the batch
+    ///
+    /// # Arguments
+    /// * `reading` - The reading to send
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `CRDTError::InvalidNodeId` for a sensor ID
+    /// outside the configuration, `CRDTError::BufferOverflow` if the batch
+    /// is full, or `CRDTError::Overflow` if the readings would span more
+    /// than `u16::MAX` milliseconds
+    pub fn push(&mut self, reading: SensorReading) -> CRDTResult<()> {
+        if reading.sensor_id as usize >= C::MAX_NODES {
+            return Err(CRDTError::InvalidNodeId);
+        }
+        if self.is_full() {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        let timestamp = reading.timestamp.as_u64();
+        let (first, last) = self
+            .iter()
+            .fold((timestamp, timestamp), |(first, last), r| {
+                let t = r.timestamp.as_u64();
+                (first.min(t), last.max(t))
+            });
+        if last - first > u16::MAX as u64 {
+            return Err(CRDTError::Overflow);
+        }
+
+        self.readings[self.len] = Some(reading);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the number of readings in the batch
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the batch holds no readings
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the batch holds N readings
+    pub fn is_full(&self) -> bool {
+        self.len >= N
+    }
+
+    /// Returns an iterator over the readings in the order they were pushed
+    pub fn iter(&self) -> impl Iterator<Item = &SensorReading> {
+        self.readings[..self.len].iter().filter_map(|r| r.as_ref())
+    }
+
+    /// Encodes the batch into a byte slice
+    ///
+    /// Writes a [`HEADER_LEN`](Self::HEADER_LEN) byte header followed by
+    /// [`READING_LEN`](Self::READING_LEN) bytes per reading.
+    ///
+    /// # Arguments
+    /// * `buf` - The output buffer
+    ///
+    /// # Returns
+    /// The number of bytes written, `CRDTError::BufferOverflow` if the
+    /// buffer is too small, or `CRDTError::InvalidState` if the batch holds
+    /// more than 255 readings
+    pub fn encode_to_slice(&self, buf: &mut [u8]) -> CRDTResult<usize> {
+        let count = u8::try_from(self.len).map_err(|_| CRDTError::InvalidState)?;
+        let len = Self::HEADER_LEN + self.len * Self::READING_LEN;
+        if buf.len() < len {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        let base = self.iter().map(|r| r.timestamp.as_u64()).min().unwrap_or(0);
+        buf[0] = count;
+        buf[1..Self::HEADER_LEN].copy_from_slice(&base.to_le_bytes());
+
+        let records = buf[Self::HEADER_LEN..len].chunks_exact_mut(Self::READING_LEN);
+        for (reading, record) in self.iter().zip(records) {
+            // push keeps every reading within u16::MAX of the earliest
+            let delta = (reading.timestamp.as_u64() - base) as u16;
+            record[0] = reading.sensor_id;
+            record[1] = reading.sensor_type as u8;
+            record[2..6].copy_from_slice(&reading.value.to_le_bytes());
+            record[6] = reading.quality as u8;
+            record[7..9].copy_from_slice(&delta.to_le_bytes());
+        }
+        Ok(len)
+    }
+
+    /// Decodes a batch written by [`encode_to_slice`](Self::encode_to_slice)
+    ///
+    /// # Arguments
+    /// * `buf` - The encoded bytes
+    ///
+    /// # Returns
+    /// The decoded batch, `CRDTError::BufferOverflow` if the buffer is
+    /// shorter than the layout or holds more than N readings,
+    /// `CRDTError::InvalidState` for an unknown sensor type or quality, or
+    /// `CRDTError::InvalidNodeId` for a sensor ID outside the configuration
+    pub fn decode_from_slice(buf: &[u8]) -> CRDTResult<Self> {
+        if buf.len() < Self::HEADER_LEN {
+            return Err(CRDTError::BufferOverflow);
+        }
+        let count = buf[0] as usize;
+        let len = Self::HEADER_LEN + count * Self::READING_LEN;
+        if count > N || buf.len() < len {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&buf[1..Self::HEADER_LEN]);
+        let base = u64::from_le_bytes(raw);
+
+        let mut batch = Self::new();
+        for record in buf[Self::HEADER_LEN..len].chunks_exact(Self::READING_LEN) {
+            let sensor_type = SensorType::from_u8(record[1]).ok_or(CRDTError::InvalidState)?;
+            let quality = ReadingQuality::from_u8(record[6]).ok_or(CRDTError::InvalidState)?;
+            let value = i32::from_le_bytes([record[2], record[3], record[4], record[5]]);
+            let delta = u16::from_le_bytes([record[7], record[8]]);
+            let timestamp = base
+                .checked_add(delta as u64)
+                .ok_or(CRDTError::InvalidState)?;
+
+            batch.push(SensorReading::new(
+                record[0],
+                sensor_type,
+                value,
+                quality,
+                timestamp,
+                0,
+            ))?;
+        }
+        Ok(batch)
+    }
+
+    /// Adds every reading in the batch to a sensor network
+    ///
+    /// A reading is accepted if the network holds it afterwards. It is not
+    /// if the network already has a reading from the same sensor that
+    /// overrides it, or if the network is full.
+    ///
+    /// # Arguments
+    /// * `network` - The network to add the readings to
+    ///
+    /// # Returns
+    /// The number of readings accepted
+    pub fn apply_to_network(&self, network: &mut SensorNetwork<C>) -> CRDTResult<usize> {
+        let mut accepted = 0;
+
+        for reading in self.iter() {
+            match network.add_sensor_reading(*reading) {
+                // A full network drops the reading, like a lost frame
+                Err(CRDTError::BufferOverflow) => continue,
+                result => result?,
+            }
+            if network.all_readings().any(|stored| stored == reading) {
+                accepted += 1;
+            }
+            if reading.timestamp > network.last_update {
+                network.last_update = reading.timestamp;
+            }
+        }
+
+        Ok(accepted)
+    }
+}
+
+impl<C: MemoryConfig, const N: usize> Default for SensorBatch<C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1037,4 +1559,277 @@ mod tests {
         assert!(network1.merge_bounded(&network2).is_ok());
         assert!(network1.validate_bounded().is_ok());
     }
+
+    #[test]
+    fn test_sensor_stats_merge_four_nodes() {
+        let readings: [&[(i32, u64)]; 4] = [
+            &[(2100, 1000), (2200, 1010)],
+            &[(-500, 1001)],
+            &[(2600, 1002), (2300, 1012), (2400, 1022)],
+            &[(1900, 1003), (-500, 1013)],
+        ];
+        let mut nodes = [
+            SensorStats::<DefaultConfig>::new(1),
+            SensorStats::<DefaultConfig>::new(2),
+            SensorStats::<DefaultConfig>::new(3),
+            SensorStats::<DefaultConfig>::new(4),
+        ];
+        for (node, readings) in nodes.iter_mut().zip(readings) {
+            for &(value, timestamp) in readings {
+                node.record_reading(value, timestamp).unwrap();
+            }
+        }
+
+        // Merge in opposite orders on two replicas
+        let mut forward = nodes[0].clone();
+        for node in &nodes[1..] {
+            forward.merge(node).unwrap();
+        }
+        let mut backward = nodes[3].clone();
+        for node in nodes[..3].iter().rev() {
+            backward.merge(node).unwrap();
+        }
+
+        // 2100 + 2200 - 500 + 2600 + 2300 + 2400 + 1900 - 500 = 12500
+        for stats in [&forward, &backward] {
+            assert_eq!(stats.reading_count(), 8);
+            assert_eq!(stats.mean_x1000(), Some(12_500_000 / 8));
+            assert_eq!(stats.range(), Some((-500, 2600)));
+        }
+        assert!(forward.eq(&backward));
+        assert_eq!(forward.state_hash(), backward.state_hash());
+
+        // Idempotent
+        let before = forward.clone();
+        forward.merge(&backward).unwrap();
+        assert!(forward.eq(&before));
+        assert_eq!(forward.max_register().timestamp().as_u64(), 1002);
+    }
+
+    #[test]
+    fn test_sensor_stats_empty_and_overflow() {
+        let mut stats = SensorStats::<DefaultConfig>::new(1);
+        assert_eq!(stats.mean_x1000(), None);
+        assert_eq!(stats.range(), None);
+
+        // 4_294_967 * 1000 still fits one node's u32 share
+        stats.record_reading(4_294_967, 1000).unwrap();
+        assert_eq!(stats.record_reading(1, 1001), Err(CRDTError::Overflow));
+        assert_eq!(stats.reading_count(), 1);
+        assert_eq!(stats.range(), Some((4_294_967, 4_294_967)));
+
+        stats.record_reading(-7, 1002).unwrap();
+        assert_eq!(stats.mean_x1000(), Some((4_294_967_000 - 7_000) / 2));
+    }
+
+    #[test]
+    fn test_sensor_network_with_stats() {
+        let mut gateway1 = SensorNetwork::<DefaultConfig>::new(1);
+        let mut gateway2 = SensorNetwork::<DefaultConfig>::new(2);
+        assert!(gateway1.stats().is_none());
+
+        gateway1
+            .add_reading(
+                10,
+                SensorType::Temperature,
+                2000,
+                ReadingQuality::Good,
+                1000,
+                1,
+            )
+            .unwrap();
+        gateway1.with_stats(1).record_reading(2000, 1000).unwrap();
+        gateway2.with_stats(2).record_reading(3000, 1001).unwrap();
+
+        gateway1.merge(&gateway2).unwrap();
+        let stats = gateway1.stats().unwrap();
+        assert_eq!(stats.reading_count(), 2);
+        assert_eq!(stats.mean_x1000(), Some(2_500_000));
+        assert_eq!(gateway1.reading_count(), 1);
+
+        // Statistics reach replicas that never enabled them
+        let mut gateway3 = SensorNetwork::<DefaultConfig>::new(3);
+        gateway3.merge(&gateway1).unwrap();
+        assert_eq!(gateway3.stats().unwrap().range(), Some((2000, 3000)));
+        assert!(!gateway3.eq(&gateway2));
+    }
+
+    #[test]
+    fn test_sensor_batch_round_trip() {
+        use crate::configs::IoTSensorConfig;
+
+        fn reading(i: usize) -> SensorReading {
+            SensorReading::new(
+                (i % 16) as NodeId,
+                SensorType::from_u8((i % 12) as u8 + 1).unwrap(),
+                if i % 2 == 0 {
+                    i32::MIN + i as i32
+                } else {
+                    i32::MAX - i as i32
+                },
+                ReadingQuality::from_u8((i % 4) as u8 + 1).unwrap(),
+                1_000_000 + (i as u64 * u16::MAX as u64) / 254,
+                0,
+            )
+        }
+
+        // Largest batch the one-byte count can encode
+        let mut batch = SensorBatch::<IoTSensorConfig, 255>::new();
+        for i in 0..255 {
+            batch.push(reading(i)).unwrap();
+        }
+        assert!(batch.is_full());
+        assert_eq!(batch.push(reading(0)), Err(CRDTError::BufferOverflow));
+
+        let mut buf = [0u8; 9 + 255 * 9];
+        let short = buf.len() - 1;
+        assert_eq!(
+            batch.encode_to_slice(&mut buf[..short]),
+            Err(CRDTError::BufferOverflow)
+        );
+        let len = batch.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(len, buf.len());
+
+        let decoded = SensorBatch::<IoTSensorConfig, 255>::decode_from_slice(&buf).unwrap();
+        assert_eq!(decoded.len(), 255);
+        assert!(decoded.iter().eq(batch.iter()));
+
+        // Largest batch that fits a 222 byte LoRa payload
+        let mut lora = SensorBatch::<IoTSensorConfig, 23>::new();
+        for i in 0..23 {
+            lora.push(reading(i)).unwrap();
+        }
+        let mut frame = [0u8; 222];
+        let len = lora.encode_to_slice(&mut frame).unwrap();
+        let decoded = SensorBatch::<IoTSensorConfig, 23>::decode_from_slice(&frame[..len]).unwrap();
+        assert!(decoded.iter().eq(lora.iter()));
+
+        // A smaller batch cannot hold the frame
+        assert_eq!(
+            SensorBatch::<IoTSensorConfig, 22>::decode_from_slice(&frame[..len]).map(|b| b.len()),
+            Err(CRDTError::BufferOverflow)
+        );
+    }
+
+    #[test]
+    fn test_sensor_batch_rejects_invalid_readings() {
+        use crate::configs::IoTSensorConfig;
+
+        let mut batch = SensorBatch::<IoTSensorConfig, 4>::default();
+        assert!(batch.is_empty());
+        assert_eq!(
+            batch.push(SensorReading::new(
+                16,
+                SensorType::Light,
+                0,
+                ReadingQuality::Good,
+                0,
+                0
+            )),
+            Err(CRDTError::InvalidNodeId)
+        );
+
+        batch
+            .push(SensorReading::new(
+                1,
+                SensorType::Light,
+                0,
+                ReadingQuality::Good,
+                70_000,
+                0,
+            ))
+            .unwrap();
+        assert!(
+            batch
+                .push(SensorReading::new(
+                    2,
+                    SensorType::Light,
+                    0,
+                    ReadingQuality::Good,
+                    70_000 + 65_535,
+                    0
+                ))
+                .is_ok()
+        );
+        assert_eq!(
+            batch.push(SensorReading::new(
+                3,
+                SensorType::Light,
+                0,
+                ReadingQuality::Good,
+                70_000 - 1,
+                0
+            )),
+            Err(CRDTError::Overflow)
+        );
+        assert_eq!(batch.len(), 2);
+
+        let mut buf = [0u8; 64];
+        let len = batch.encode_to_slice(&mut buf).unwrap();
+        type Batch = SensorBatch<IoTSensorConfig, 4>;
+        assert_eq!(
+            Batch::decode_from_slice(&buf[..len - 1]).map(|b| b.len()),
+            Err(CRDTError::BufferOverflow)
+        );
+
+        // Unknown quality byte
+        buf[Batch::HEADER_LEN + 6] = 9;
+        assert_eq!(
+            Batch::decode_from_slice(&buf[..len]).map(|b| b.len()),
+            Err(CRDTError::InvalidState)
+        );
+    }
+
+    #[test]
+    fn test_sensor_batch_apply_to_network() {
+        let mut network = SensorNetwork::<DefaultConfig>::new(0);
+        network
+            .add_reading(
+                1,
+                SensorType::Temperature,
+                2400,
+                ReadingQuality::Good,
+                2000,
+                0,
+            )
+            .unwrap();
+
+        let mut batch = SensorBatch::<DefaultConfig, 4>::new();
+        // Older than the reading the network already has
+        batch
+            .push(SensorReading::new(
+                1,
+                SensorType::Temperature,
+                2300,
+                ReadingQuality::Good,
+                1500,
+                0,
+            ))
+            .unwrap();
+        batch
+            .push(SensorReading::new(
+                2,
+                SensorType::Humidity,
+                5000,
+                ReadingQuality::Fair,
+                2500,
+                0,
+            ))
+            .unwrap();
+
+        assert_eq!(batch.apply_to_network(&mut network).unwrap(), 1);
+        assert_eq!(network.reading_count(), 2);
+        assert_eq!(
+            network
+                .latest_reading(SensorType::Temperature, 0)
+                .map(|r| r.value),
+            Some(2400)
+        );
+        assert_eq!(
+            network
+                .latest_reading(SensorType::Humidity, 0)
+                .map(|r| r.value),
+            Some(5000)
+        );
+    }
 }
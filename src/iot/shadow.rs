@@ -0,0 +1,292 @@
+//! Device Shadow for IoT Systems
+//!
+//! This module implements the device shadow pattern used by cloud IoT
+//! platforms: the cloud writes a desired state, the device writes the state
+//! it actually reports, and both converge independently across replicas.
+
+use crate::clock::StateHash;
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Device Shadow CRDT
+///
+/// Pairs a desired state (written by the cloud) with a reported state
+/// (written by the device). Each side is a last-writer-wins register with
+/// its own node ID, and merge combines the two registers independently.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::iot::DeviceShadow;
+///
+/// // Cloud is node 0, the thermostat is node 7
+/// let mut cloud = DeviceShadow::<u8, DefaultConfig>::new(0, 7);
+/// cloud.set_desired(21, 1000)?;
+///
+/// let mut device = DeviceShadow::<u8, DefaultConfig>::new(0, 7);
+/// device.report_actual(18, 900)?;
+///
+/// cloud.merge(&device)?;
+/// assert_eq!(cloud.delta(), Some((&21, &18)));
+///
+/// // Device catches up and reports the new setpoint
+/// device.merge(&cloud)?;
+/// device.report_actual(21, 1500)?;
+/// cloud.merge(&device)?;
+/// assert!(cloud.is_synchronized());
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeviceShadow<T: Clone + PartialEq, C: MemoryConfig> {
+    /// State requested by the cloud
+    desired: LWWRegister<T, C>,
+    /// State reported by the device
+    reported: LWWRegister<T, C>,
+}
+
+impl<T: Clone + PartialEq, C: MemoryConfig> DeviceShadow<T, C> {
+    /// Creates a new empty shadow
+    ///
+    /// # Arguments
+    /// * `desired_node_id` - Node that writes the desired state (the cloud)
+    /// * `reported_node_id` - Node that writes the reported state (the device)
+    pub fn new(desired_node_id: NodeId, reported_node_id: NodeId) -> Self {
+        Self {
+            desired: LWWRegister::new(desired_node_id),
+            reported: LWWRegister::new(reported_node_id),
+        }
+    }
+
+    /// Sets the desired state
+    pub fn set_desired(&mut self, value: T, timestamp: u64) -> CRDTResult<()> {
+        self.desired.set(value, timestamp)
+    }
+
+    /// Records the state the device actually reports
+    pub fn report_actual(&mut self, value: T, timestamp: u64) -> CRDTResult<()> {
+        self.reported.set(value, timestamp)
+    }
+
+    /// Returns the desired state
+    pub fn desired(&self) -> Option<&T> {
+        self.desired.get()
+    }
+
+    /// Returns the reported state
+    pub fn reported(&self) -> Option<&T> {
+        self.reported.get()
+    }
+
+    /// Returns true if the reported state matches the desired state
+    pub fn is_synchronized(&self) -> bool {
+        self.desired.get() == self.reported.get()
+    }
+
+    /// Returns `(desired, reported)` when both are set and differ
+    pub fn delta(&self) -> Option<(&T, &T)> {
+        match (self.desired.get(), self.reported.get()) {
+            (Some(desired), Some(reported)) if desired != reported => Some((desired, reported)),
+            _ => None,
+        }
+    }
+
+    /// Checks whether the shadow has been out of sync for longer than `max_lag_ms`
+    ///
+    /// The divergence is measured from the most recent write to either side,
+    /// since neither side has brought the states back into agreement since.
+    /// A device that never reports after a desired change is flagged once
+    /// the lag passes the threshold, which makes this useful for detecting
+    /// offline devices.
+    pub fn time_out_of_sync(&self, current_time: u64, max_lag_ms: u64) -> bool {
+        if self.is_synchronized() {
+            return false;
+        }
+
+        let last_write = self
+            .desired
+            .timestamp()
+            .as_u64()
+            .max(self.reported.timestamp().as_u64());
+        current_time.saturating_sub(last_write) > max_lag_ms
+    }
+}
+
+impl<T, C: MemoryConfig> CRDT<C> for DeviceShadow<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.desired.merge(&other.desired)?;
+        self.reported.merge(&other.reported)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.desired.eq(&other.desired) && self.reported.eq(&other.reported)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.desired.validate()?;
+        self.reported.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.desired.state_hash() ^ self.reported.state_hash().rotate_left(16)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.desired.can_merge(&other.desired) && self.reported.can_merge(&other.reported)
+    }
+}
+
+impl<T, C: MemoryConfig> BoundedCRDT<C> for DeviceShadow<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = 2; // Desired and reported states
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.desired.element_count() + self.reported.element_count()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Shadow registers cannot be compacted
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        // Writes always replace the existing states
+        true
+    }
+}
+
+impl<T, C: MemoryConfig> RealTimeCRDT<C> for DeviceShadow<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_MERGE_CYCLES: u32 = 60; // Two register merges
+    const MAX_VALIDATE_CYCLES: u32 = 30;
+    const MAX_SERIALIZE_CYCLES: u32 = 50;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        // Shadow merge is constant time
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For IoT systems, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For IoT systems, we don't limit budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_shadow_creation() {
+        let shadow = DeviceShadow::<u32, DefaultConfig>::new(0, 1);
+
+        assert!(shadow.desired().is_none());
+        assert!(shadow.reported().is_none());
+        assert!(shadow.is_synchronized());
+        assert!(shadow.delta().is_none());
+        assert!(!shadow.time_out_of_sync(10_000, 100));
+    }
+
+    #[test]
+    fn test_desired_and_reported_split() {
+        let mut shadow = DeviceShadow::<u32, DefaultConfig>::new(0, 1);
+
+        shadow.set_desired(42, 1000).unwrap();
+        assert!(!shadow.is_synchronized());
+        // No reported state yet, so there is nothing to diff against
+        assert!(shadow.delta().is_none());
+
+        shadow.report_actual(40, 1100).unwrap();
+        assert_eq!(shadow.delta(), Some((&42, &40)));
+
+        shadow.report_actual(42, 1200).unwrap();
+        assert!(shadow.is_synchronized());
+        assert!(shadow.delta().is_none());
+        assert_eq!(shadow.desired(), Some(&42));
+        assert_eq!(shadow.reported(), Some(&42));
+    }
+
+    #[test]
+    fn test_merge_registers_independently() {
+        let mut cloud = DeviceShadow::<u32, DefaultConfig>::new(0, 1);
+        let mut device = DeviceShadow::<u32, DefaultConfig>::new(0, 1);
+
+        cloud.set_desired(10, 2000).unwrap();
+        device.report_actual(5, 1000).unwrap();
+
+        let mut merged1 = cloud.clone();
+        merged1.merge(&device).unwrap();
+        let mut merged2 = device.clone();
+        merged2.merge(&cloud).unwrap();
+
+        // A newer desired write must not overwrite the reported state
+        assert_eq!(merged1.desired(), Some(&10));
+        assert_eq!(merged1.reported(), Some(&5));
+        assert!(merged1.eq(&merged2));
+        assert_eq!(merged1.element_count(), 2);
+
+        let before = merged1.clone();
+        merged1.merge(&before).unwrap();
+        assert!(merged1.eq(&before));
+    }
+
+    #[test]
+    fn test_time_out_of_sync() {
+        let mut shadow = DeviceShadow::<u32, DefaultConfig>::new(0, 1);
+
+        shadow.report_actual(1, 1000).unwrap();
+        shadow.set_desired(2, 2000).unwrap();
+
+        assert!(!shadow.time_out_of_sync(2500, 1000));
+        assert!(!shadow.time_out_of_sync(3000, 1000));
+        assert!(shadow.time_out_of_sync(3001, 1000));
+
+        // Clock behind the last write never reports a lag
+        assert!(!shadow.time_out_of_sync(500, 0));
+
+        shadow.report_actual(2, 4000).unwrap();
+        assert!(!shadow.time_out_of_sync(100_000, 1000));
+    }
+
+    #[test]
+    fn test_validation_and_traits() {
+        let mut shadow = DeviceShadow::<u32, DefaultConfig>::new(0, 1);
+        assert!(shadow.validate().is_ok());
+        assert!(shadow.can_add_element());
+        assert_eq!(shadow.compact().unwrap(), 0);
+        assert!(shadow.remaining_budget().is_none());
+
+        shadow.set_desired(1, 100).unwrap();
+        assert!(shadow.validate().is_ok());
+        assert_eq!(shadow.element_count(), 1);
+        assert!(shadow.merge_bounded(&shadow.clone()).is_ok());
+    }
+}
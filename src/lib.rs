@@ -34,24 +34,44 @@
 //! - `stm32` - STM32 ARM Cortex-M MCUs (power management optimizations)
 //! - `cortex-m` - Generic ARM Cortex-M platforms (memory constrained)
 //! - `riscv` - RISC-V embedded processors (variable multi-core)
+//! - `esp32` - Espressif ESP32 Xtensa MCUs (dual-core, Wi-Fi/BLE power management)
+//! - `nrf52` - Nordic nRF52 BLE MCUs (tight SRAM, radio and System OFF power management)
 //!
 //! ### Hardware Optimization Features
 //! - `hardware` - Enable all hardware optimizations
-//! - `hardware-atomic` - Hardware atomic operations for thread safety
+//! - `hardware-atomic` - Hardware atomic operations for thread safety; choose
+//!   this for multi-core targets such as RISC-V SMP
+//! - `critical-section` - Interrupt-safe wrappers in [`platform::interrupt`];
+//!   choose this for single-core Cortex-M bare metal. Mutually exclusive with
+//!   `hardware-atomic`
+//! - `cycle-counter` - Cycle counter for `RealTimeCRDT::timed_merge`, read from
+//!   CCNT on AURIX or from a platform-provided `crdtosphere_read_cycle_counter`
 //!
 //! ### Serialization Features
 //! - `serde` - Serde serialization support (no_std compatible)
+//! - `cbor` - CBOR serialization via minicbor (no_std compatible)
+//! - `postcard` - Postcard serialization with static size bounds (no_std compatible)
+//! - `hardware-crc` - Frame CRCs computed by a platform-provided `platform_crc32`
+//!
+//! ### Diagnostics Features
+//! - `debug` - Human-readable memory reports via [`memory::debug`] (uses `heapless`)
+//! - `diagnostics` - Merge and diff reports for [`sets::ORSet`]
+//!
+//! ### Interoperability Features
+//! - `ffi` - C bindings for `GCounter`, `LWWRegister<u32>` and `LWWMap<u8, u32>`, declared in `include/crdtosphere.h`
+//! - `alloc` - [`clock::StateHash`] for `String` and `Vec`, so heap values can be CRDT elements on hosts with an allocator
+//! - `wasm` - JavaScript bindings for `GCounter`, `LWWRegister<f64>` and `ORSet<u32>` via `wasm-bindgen`
 //!
 //! ## Platform Support Matrix
 //!
-//! | Feature | AURIX | STM32 | Cortex-M | RISC-V | Default |
-//! |---------|-------|-------|----------|--------|---------|
-//! | **Memory Alignment** | 32-byte | 4-byte | 4-byte | 8-byte | 4-byte |
-//! | **Max Merge Cycles** | 500 | 200 | 100 | 300 | 150 |
-//! | **Multi-core** | ✅ (3 cores) | ❌ | ❌ | ✅ (variable) | ❌ |
-//! | **Safety Features** | ✅ ASIL-D | ❌ | ❌ | ❌ | ❌ |
-//! | **Power Management** | ❌ | ✅ | ✅ | ❌ | ❌ |
-//! | **Real-Time Bounds** | ✅ (100μs) | ✅ (50μs) | ✅ (25μs) | ✅ (30μs) | ✅ (40μs) |
+//! | Feature | AURIX | STM32 | Cortex-M | RISC-V | ESP32 | nRF52 | Default |
+//! |---------|-------|-------|----------|--------|-------|-------|---------|
+//! | **Memory Alignment** | 32-byte | 4-byte | 4-byte | 8-byte | 4-byte | 4-byte | 4-byte |
+//! | **Max Merge Cycles** | 500 | 200 | 100 | 300 | 250 | 150 | 150 |
+//! | **Multi-core** | ✅ (3 cores) | ❌ | ❌ | ✅ (variable) | ✅ (2 cores) | ❌ | ❌ |
+//! | **Safety Features** | ✅ ASIL-D | ❌ | ❌ | ❌ | ❌ | ❌ | ❌ |
+//! | **Power Management** | ❌ | ✅ | ✅ | ❌ | ✅ | ✅ | ❌ |
+//! | **Real-Time Bounds** | ✅ (100μs) | ✅ (50μs) | ✅ (25μs) | ✅ (30μs) | ✅ (60μs) | ✅ (20μs) | ✅ (40μs) |
 //!
 //! **Note**: Platform features are mutually exclusive. Choose one per build.
 //!
@@ -113,27 +133,39 @@
 //!
 //! ### Counters
 //! - [`GCounter`] - Grow-only counter (increment only)
+//! - [`GDecCounter`] - Decrement-only counter for resource consumption
 //! - [`PNCounter`] - Increment/decrement counter
+//! - [`PresenceBitmap`] - One bit per node for "has reported in" tracking
 //!
 //! ### Registers
 //! - [`LWWRegister`] - Last-Writer-Wins register
+//! - [`LamportRegister`] - Last-Writer-Wins register with Lamport timestamps
 //! - [`MVRegister`] - Multi-Value register (concurrent writes preserved)
+//! - [`MVRegisterCausal`] - Multi-Value register with vector clock causality
 //!
 //! ### Sets
 //! - [`GSet`] - Grow-only set (add only)
 //! - [`ORSet`] - Observed-Remove set (add and remove)
+//! - [`TwoPSet`] - Two-Phase set (add and permanent remove)
 //!
 //! ### Maps
 //! - [`LWWMap`] - Last-Writer-Wins map
+//! - [`ORMap`] - Observed-Remove map with add-wins key deletion
 //!
 //!
 //! [`GCounter`]: crate::counters::GCounter
+//! [`GDecCounter`]: crate::counters::GDecCounter
 //! [`PNCounter`]: crate::counters::PNCounter
+//! [`PresenceBitmap`]: crate::counters::PresenceBitmap
 //! [`LWWRegister`]: crate::registers::LWWRegister
+//! [`LamportRegister`]: crate::registers::LamportRegister
 //! [`MVRegister`]: crate::registers::MVRegister
+//! [`MVRegisterCausal`]: crate::registers::MVRegisterCausal
 //! [`GSet`]: crate::sets::GSet
 //! [`ORSet`]: crate::sets::ORSet
+//! [`TwoPSet`]: crate::sets::TwoPSet
 //! [`LWWMap`]: crate::maps::LWWMap
+//! [`ORMap`]: crate::maps::ORMap
 
 #![no_std]
 #![deny(missing_docs)]
@@ -179,9 +211,25 @@ pub mod iot;
 #[cfg_attr(docsrs, doc(cfg(feature = "industrial")))]
 pub mod industrial;
 
+// Serialization modules
+pub mod serialization;
+
 // Configuration presets
 pub mod configs;
 
+// C bindings
+#[cfg(feature = "ffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+pub mod ffi;
+
+// `StateHash` impls for heap types and the JavaScript bindings
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "wasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+pub mod wasm;
+
 /// Prelude module of CRDTosphere
 ///
 /// Convenient re-exports for common CRDTosphere types and traits
@@ -197,14 +245,21 @@ pub mod prelude {
     pub use crate::error::CRDTError;
 
     // Re-export clock types
-    pub use crate::clock::CompactTimestamp;
+    pub use crate::clock::{
+        CompactTimestamp, FnvHasher, HLCTimestamp, HybridLogicalClock, IntoTimestamp, LamportClock,
+        StateHash, VectorClock,
+    };
 
     // Re-export configuration presets
     pub use crate::configs::*;
 
     // Re-export core CRDTs (always available)
-    pub use crate::counters::{GCounter, PNCounter};
-    pub use crate::maps::LWWMap;
-    pub use crate::registers::{LWWRegister, MVRegister};
-    pub use crate::sets::{GSet, ORSet};
+    pub use crate::counters::{
+        GCounter, GCounterCheckpoint, GDecCounter, OverflowMode, PNCounter, PresenceBitmap,
+    };
+    pub use crate::maps::{LWWMap, ORMap};
+    pub use crate::registers::{
+        LWWRegister, LamportRegister, MVRegister, MVRegisterCausal, MVRegisterResolved,
+    };
+    pub use crate::sets::{ConflictPolicy, GSet, ORSet, TwoPSet};
 }
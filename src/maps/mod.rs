@@ -4,6 +4,8 @@
 //! with different conflict resolution semantics.
 
 pub mod lww;
+pub mod ormap;
 
 // Re-export main types
 pub use lww::LWWMap;
+pub use ormap::ORMap;
@@ -0,0 +1,1289 @@
+//! Observed-Remove Map CRDT
+//!
+//! A map that supports safe key deletion by tagging every write with the
+//! writing node and its timestamp, and removing only the tags a node has observed.
+//! Uses zero allocation with fixed arrays for deterministic memory usage.
+
+use crate::clock::{CompactTimestamp, FnvHasher, StateHash};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::traits::{BoundedCRDT, CRDT, MemoryBreakdown, RealTimeCRDT};
+
+#[cfg(feature = "hardware-atomic")]
+use core::cell::UnsafeCell;
+#[cfg(feature = "hardware-atomic")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Observed-Remove Map with configurable entry and tombstone arrays
+///
+/// Every write is stored with a unique tag `(node_id, timestamp)`. Removing a
+/// key tombstones the tags the remover has observed, so a concurrent write from
+/// another node (with a tag the remover never saw) survives the merge. Each node
+/// keeps at most one tag per key: a newer write from the same node supersedes
+/// its older one.
+///
+/// # Type Parameters
+/// - `K`: The key type
+/// - `V`: The value type
+/// - `C`: Memory configuration that determines limits
+/// - `CAPACITY`: The maximum number of tagged entries and tombstones (defaults to 8)
+///
+/// # Memory Usage
+/// - Fixed size: (sizeof(K) + sizeof(V) + 9) * CAPACITY + (sizeof(K) + 17) * CAPACITY + 24 bytes
+/// - Example: For (u8, u32) with 8 entries = ~360 bytes
+/// - Completely predictable at compile time
+///
+/// # Feature Comparison
+///
+/// | Method | Standard | Atomic | Mutability | Thread Safety | Notes |
+/// |--------|----------|--------|------------|---------------|-------|
+/// | `insert()` | ✅ | ✅ | `&mut self` | Single | Tagged write |
+/// | `remove()` | ✅ | ✅ | `&mut self` | Single | Tombstones observed tags |
+/// | `get()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
+/// | `get_all_values()` | ✅ | ✅ | `&self` | Single / Multi | Concurrent values |
+/// | `merge()` | ✅ | ✅ | `&mut self` | Single | CRDT merge |
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut ecu1 = ORMap::<u8, u32, DefaultConfig>::new(1);
+/// let mut ecu2 = ORMap::<u8, u32, DefaultConfig>::new(2);
+///
+/// ecu1.insert(1, 100, 1000)?;
+/// ecu2.merge(&ecu1)?;
+///
+/// // ECU 1 removes the key while ECU 2 concurrently rewrites it
+/// ecu1.remove(&1, 2000)?;
+/// ecu2.insert(1, 200, 1500)?;
+///
+/// ecu1.merge(&ecu2)?;
+/// assert_eq!(ecu1.get(&1), Some(&200)); // The unobserved write survives
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug)]
+pub struct ORMap<K, V, C: MemoryConfig, const CAPACITY: usize = 8> {
+    /// Tagged key-value entries
+    #[cfg(not(feature = "hardware-atomic"))]
+    entries: [Option<TaggedEntry<K, V>>; CAPACITY],
+    #[cfg(not(feature = "hardware-atomic"))]
+    entry_count: usize,
+
+    /// Removed tags (tombstones)
+    #[cfg(not(feature = "hardware-atomic"))]
+    tombstones: [Option<TagTombstone<K>>; CAPACITY],
+    #[cfg(not(feature = "hardware-atomic"))]
+    tombstone_count: usize,
+
+    /// Atomic version uses UnsafeCell for the arrays
+    #[cfg(feature = "hardware-atomic")]
+    entries: UnsafeCell<[Option<TaggedEntry<K, V>>; CAPACITY]>,
+    #[cfg(feature = "hardware-atomic")]
+    entry_count: AtomicUsize,
+
+    #[cfg(feature = "hardware-atomic")]
+    tombstones: UnsafeCell<[Option<TagTombstone<K>>; CAPACITY]>,
+    #[cfg(feature = "hardware-atomic")]
+    tombstone_count: AtomicUsize,
+
+    /// This node's ID
+    node_id: NodeId,
+
+    /// Phantom data to maintain the memory config type
+    _phantom: core::marker::PhantomData<C>,
+}
+
+// SAFETY: The atomic version is safe to share between threads because:
+// 1. All mutation goes through `&mut self`, so writers are exclusive
+// 2. Shared references only read the arrays up to the published counts
+#[cfg(feature = "hardware-atomic")]
+unsafe impl<K, V, C: MemoryConfig, const CAPACITY: usize> Sync for ORMap<K, V, C, CAPACITY>
+where
+    K: Send,
+    V: Send,
+    C: Send + Sync,
+{
+}
+
+// Implement Clone manually due to atomic types not implementing Clone
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> Clone for ORMap<K, V, C, CAPACITY>
+where
+    K: Clone,
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            Self {
+                entries: self.entries.clone(),
+                entry_count: self.entry_count,
+                tombstones: self.tombstones.clone(),
+                tombstone_count: self.tombstone_count,
+                node_id: self.node_id,
+                _phantom: core::marker::PhantomData,
+            }
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            // For atomic version, we need to manually clone the UnsafeCell content
+            let cloned_entries = unsafe { (*self.entries.get()).clone() };
+            let cloned_tombstones = unsafe { (*self.tombstones.get()).clone() };
+            Self {
+                entries: UnsafeCell::new(cloned_entries),
+                entry_count: AtomicUsize::new(self.entry_count.load(Ordering::Relaxed)),
+                tombstones: UnsafeCell::new(cloned_tombstones),
+                tombstone_count: AtomicUsize::new(self.tombstone_count.load(Ordering::Relaxed)),
+                node_id: self.node_id,
+                _phantom: core::marker::PhantomData,
+            }
+        }
+    }
+}
+
+/// Key-value entry tagged with the writing node and timestamp
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct TaggedEntry<K, V> {
+    key: K,
+    value: V,
+    #[cfg_attr(feature = "serde", serde(with = "compact_timestamp_serde"))]
+    timestamp: CompactTimestamp,
+    node_id: NodeId,
+}
+
+/// Tombstone for a removed tag
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct TagTombstone<K> {
+    key: K,
+    #[cfg_attr(feature = "serde", serde(with = "compact_timestamp_serde"))]
+    timestamp: CompactTimestamp,
+    node_id: NodeId,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "compact_timestamp_serde", rename = "remove_timestamp")
+    )]
+    remove_timestamp: CompactTimestamp,
+}
+
+#[cfg(feature = "serde")]
+mod compact_timestamp_serde {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(timestamp: &CompactTimestamp, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        timestamp.as_u64().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<CompactTimestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let timestamp_u64 = u64::deserialize(deserializer)?;
+        Ok(CompactTimestamp::new(timestamp_u64))
+    }
+}
+
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> ORMap<K, V, C, CAPACITY>
+where
+    K: Clone + PartialEq,
+    V: Clone + PartialEq,
+{
+    /// Creates a new observed-remove map for the given node with custom capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    ///
+    /// # Returns
+    /// A new empty map
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let map = ORMap::<u8, u32, DefaultConfig, 16>::with_capacity(1);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn with_capacity(node_id: NodeId) -> Self {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            Self {
+                entries: [const { None }; CAPACITY],
+                entry_count: 0,
+                tombstones: [const { None }; CAPACITY],
+                tombstone_count: 0,
+                node_id,
+                _phantom: core::marker::PhantomData,
+            }
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            Self {
+                entries: UnsafeCell::new([const { None }; CAPACITY]),
+                entry_count: AtomicUsize::new(0),
+                tombstones: UnsafeCell::new([const { None }; CAPACITY]),
+                tombstone_count: AtomicUsize::new(0),
+                node_id,
+                _phantom: core::marker::PhantomData,
+            }
+        }
+    }
+}
+
+impl<K, V, C: MemoryConfig> ORMap<K, V, C, 8>
+where
+    K: Clone + PartialEq,
+    V: Clone + PartialEq,
+{
+    /// Creates a new observed-remove map for the given node with default capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    ///
+    /// # Returns
+    /// A new empty map
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let map = ORMap::<u8, u32, DefaultConfig>::new(1);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new(node_id: NodeId) -> Self {
+        Self::with_capacity(node_id)
+    }
+}
+
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> ORMap<K, V, C, CAPACITY>
+where
+    K: Clone + PartialEq,
+    V: Clone + PartialEq,
+{
+    /// Inserts a key-value pair under a new tag
+    ///
+    /// If this node already wrote the key, the newer write replaces the older
+    /// tag; a write that is not newer than this node's existing one is ignored,
+    /// even if that tag has since been removed.
+    ///
+    /// # Arguments
+    /// * `key` - The key to insert
+    /// * `value` - The value to associate with the key
+    /// * `timestamp` - The timestamp of this write, which forms part of its tag
+    ///
+    /// # Returns
+    /// Ok(true) if the write made an absent key present, Ok(false) if the key
+    /// was already present or the write was ignored, or an error if the entry
+    /// array is full
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut map = ORMap::<u8, u32, DefaultConfig>::new(1);
+    /// assert!(map.insert(1, 100, 1000)?);
+    /// assert!(!map.insert(1, 150, 2000)?);
+    /// assert_eq!(map.get(&1), Some(&150));
+    ///
+    /// // A write older than the removed tag is ignored
+    /// map.remove(&1, 3000)?;
+    /// assert!(!map.insert(1, 175, 1500)?);
+    /// assert!(!map.contains_key(&1));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn insert(&mut self, key: K, value: V, timestamp: u64) -> CRDTResult<bool> {
+        let was_present = self.contains_key(&key);
+        let stored = self.put_entry(TaggedEntry {
+            key,
+            value,
+            timestamp: CompactTimestamp::new(timestamp),
+            node_id: self.node_id,
+        })?;
+        Ok(stored && !was_present)
+    }
+
+    /// Removes a key by tombstoning every tag of it this node has observed
+    ///
+    /// Writes to the key that this node has not yet seen are unaffected and
+    /// will make the key visible again once merged.
+    ///
+    /// # Arguments
+    /// * `key` - The key to remove
+    /// * `timestamp` - The timestamp of this remove operation
+    ///
+    /// # Returns
+    /// Ok(true) if the key was present, Ok(false) if it wasn't,
+    /// or an error if the tombstone array cannot hold all observed tags
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut map = ORMap::<u8, u32, DefaultConfig>::new(1);
+    /// map.insert(1, 100, 1000)?;
+    /// assert!(map.remove(&1, 2000)?);
+    /// assert!(!map.contains_key(&1));
+    /// assert!(!map.remove(&1, 2001)?);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn remove(&mut self, key: &K, timestamp: u64) -> CRDTResult<bool> {
+        let remove_timestamp = CompactTimestamp::new(timestamp);
+
+        // Reserve space for every observed tag up front so removal is all-or-nothing
+        let live_tags = self.live_entries(key).count();
+        if live_tags == 0 {
+            return Ok(false);
+        }
+        if self.tombstone_slice().len() + live_tags > CAPACITY {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        let mut index = 0;
+        while index < self.entry_slice().len() {
+            if let Some(entry) = &self.entry_slice()[index] {
+                if entry.key == *key && self.is_live(entry) {
+                    let tombstone = TagTombstone {
+                        key: entry.key.clone(),
+                        timestamp: entry.timestamp,
+                        node_id: entry.node_id,
+                        remove_timestamp,
+                    };
+                    self.push_tombstone(tombstone)?;
+                }
+            }
+            index += 1;
+        }
+
+        Ok(true)
+    }
+
+    /// Gets the value for a key
+    ///
+    /// When concurrent writes left several live values, the one with the
+    /// highest timestamp wins, ties broken by the higher node ID. Use
+    /// [`get_all_values`](Self::get_all_values) to resolve them explicitly.
+    ///
+    /// # Arguments
+    /// * `key` - The key to look up
+    ///
+    /// # Returns
+    /// The value associated with the key, or None if the key isn't present
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.winning_entry(key).map(|entry| &entry.value)
+    }
+
+    /// Returns every live value for a key
+    ///
+    /// Concurrent writes from different nodes each keep their own tag, so a
+    /// key can carry several values until the application resolves them.
+    ///
+    /// # Arguments
+    /// * `key` - The key to look up
+    ///
+    /// # Returns
+    /// An iterator over the live values, empty if the key isn't present
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut map1 = ORMap::<u8, u32, DefaultConfig>::new(1);
+    /// let mut map2 = ORMap::<u8, u32, DefaultConfig>::new(2);
+    /// map1.insert(1, 100, 1000)?;
+    /// map2.insert(1, 200, 1000)?;
+    /// map1.merge(&map2)?;
+    ///
+    /// assert_eq!(map1.get_all_values(&1).count(), 2);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn get_all_values<'a>(&'a self, key: &'a K) -> impl Iterator<Item = &'a V> + 'a {
+        self.live_entries(key).map(|entry| &entry.value)
+    }
+
+    /// Checks if the map contains a key
+    ///
+    /// # Arguments
+    /// * `key` - The key to check for
+    ///
+    /// # Returns
+    /// true if the key has at least one live value, false otherwise
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.live_entries(key).next().is_some()
+    }
+
+    /// Returns the number of keys in the map
+    ///
+    /// # Returns
+    /// The count of keys with at least one live value
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Checks if the map is empty
+    ///
+    /// # Returns
+    /// true if no key has a live value, false otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of tagged entries
+    ///
+    /// # Returns
+    /// The capacity of the entry and tombstone arrays
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of free entry slots
+    ///
+    /// Removed entries keep their slot, since their tombstones must keep
+    /// matching them.
+    ///
+    /// # Returns
+    /// The number of tagged entries that can still be stored
+    pub fn remaining_capacity(&self) -> usize {
+        CAPACITY - self.entry_slice().len()
+    }
+
+    /// Gets this node's ID
+    ///
+    /// # Returns
+    /// The node ID of this map
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Returns an iterator over the key-value pairs
+    ///
+    /// # Returns
+    /// An iterator over (key, value) pairs, one per present key, using the
+    /// same value as [`get`](Self::get)
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entry_slice()
+            .iter()
+            .flatten()
+            .filter(move |entry| {
+                self.winning_entry(&entry.key)
+                    .is_some_and(|winner| core::ptr::eq(winner, *entry))
+            })
+            .map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Returns an iterator over the keys
+    ///
+    /// # Returns
+    /// An iterator over present keys
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns the number of tagged entries (including removed ones)
+    ///
+    /// # Returns
+    /// The total number of entries stored
+    pub fn entry_count(&self) -> usize {
+        self.entry_slice().len()
+    }
+
+    /// Returns the number of tombstones
+    ///
+    /// # Returns
+    /// The number of removed tags stored
+    pub fn tombstone_count(&self) -> usize {
+        self.tombstone_slice().len()
+    }
+
+    /// Checks whether an entry's tag has not been removed
+    fn is_live(&self, entry: &TaggedEntry<K, V>) -> bool {
+        !self.tombstone_slice().iter().flatten().any(|tombstone| {
+            tombstone.key == entry.key
+                && tombstone.node_id == entry.node_id
+                && tombstone.timestamp == entry.timestamp
+        })
+    }
+
+    /// Returns the live entries for a key
+    fn live_entries<'a>(&'a self, key: &'a K) -> impl Iterator<Item = &'a TaggedEntry<K, V>> {
+        self.entry_slice()
+            .iter()
+            .flatten()
+            .filter(move |entry| entry.key == *key && self.is_live(entry))
+    }
+
+    /// Returns the live entry with the highest (timestamp, node_id) for a key
+    fn winning_entry(&self, key: &K) -> Option<&TaggedEntry<K, V>> {
+        self.entry_slice()
+            .iter()
+            .flatten()
+            .filter(|entry| entry.key == *key && self.is_live(entry))
+            .max_by_key(|entry| (entry.timestamp, entry.node_id))
+    }
+
+    /// Returns the occupied portion of the entries array
+    fn entry_slice(&self) -> &[Option<TaggedEntry<K, V>>] {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            &self.entries[..self.entry_count]
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            let current_count = self.entry_count.load(Ordering::Relaxed);
+            let entries_ref = unsafe { &*self.entries.get() };
+            &entries_ref[..current_count]
+        }
+    }
+
+    /// Returns the occupied portion of the tombstones array
+    fn tombstone_slice(&self) -> &[Option<TagTombstone<K>>] {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            &self.tombstones[..self.tombstone_count]
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            let current_count = self.tombstone_count.load(Ordering::Relaxed);
+            let tombstones_ref = unsafe { &*self.tombstones.get() };
+            &tombstones_ref[..current_count]
+        }
+    }
+
+    /// Stores an entry, keeping only the newest tag per (key, node)
+    ///
+    /// Returns whether the entry was stored, i.e. false if the same node
+    /// already holds a tag for the key that is not older.
+    fn put_entry(&mut self, entry: TaggedEntry<K, V>) -> CRDTResult<bool> {
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (entries, count) = (&mut self.entries, &mut self.entry_count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (entries, count) = (self.entries.get_mut(), self.entry_count.get_mut());
+
+        for existing in entries[..*count].iter_mut().flatten() {
+            if existing.key == entry.key && existing.node_id == entry.node_id {
+                if entry.timestamp > existing.timestamp {
+                    *existing = entry;
+                    return Ok(true);
+                }
+                return Ok(false);
+            }
+        }
+
+        if *count >= CAPACITY {
+            return Err(CRDTError::BufferOverflow);
+        }
+        entries[*count] = Some(entry);
+        *count += 1;
+        Ok(true)
+    }
+
+    /// Appends a tombstone unless the same tag is already removed
+    fn push_tombstone(&mut self, tombstone: TagTombstone<K>) -> CRDTResult<()> {
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (tombstones, count) = (&mut self.tombstones, &mut self.tombstone_count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (tombstones, count) = (self.tombstones.get_mut(), self.tombstone_count.get_mut());
+
+        for existing in tombstones[..*count].iter_mut().flatten() {
+            if existing.key == tombstone.key
+                && existing.node_id == tombstone.node_id
+                && existing.timestamp == tombstone.timestamp
+            {
+                // Keep the latest removal of the tag so replicas agree
+                if tombstone.remove_timestamp > existing.remove_timestamp {
+                    existing.remove_timestamp = tombstone.remove_timestamp;
+                }
+                return Ok(());
+            }
+        }
+
+        if *count >= CAPACITY {
+            return Err(CRDTError::BufferOverflow);
+        }
+        tombstones[*count] = Some(tombstone);
+        *count += 1;
+        Ok(())
+    }
+
+    /// Counts the entries and tombstones of `other` that would need new slots
+    fn new_slots_needed(&self, other: &Self) -> (usize, usize) {
+        let new_entries = other
+            .entry_slice()
+            .iter()
+            .flatten()
+            .filter(|entry| {
+                !self
+                    .entry_slice()
+                    .iter()
+                    .flatten()
+                    .any(|ours| ours.key == entry.key && ours.node_id == entry.node_id)
+            })
+            .count();
+
+        let new_tombstones = other
+            .tombstone_slice()
+            .iter()
+            .flatten()
+            .filter(|tombstone| {
+                !self.tombstone_slice().iter().flatten().any(|ours| {
+                    ours.key == tombstone.key
+                        && ours.node_id == tombstone.node_id
+                        && ours.timestamp == tombstone.timestamp
+                })
+            })
+            .count();
+
+        (new_entries, new_tombstones)
+    }
+}
+
+/// Deserializes a fixed-size slot array from a sequence
+#[cfg(feature = "serde")]
+struct SlotsSeed<T, const N: usize> {
+    _phantom: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize> serde::de::DeserializeSeed<'de> for SlotsSeed<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [Option<T>; N];
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SlotsVisitor<T, const N: usize> {
+            _phantom: core::marker::PhantomData<T>,
+        }
+
+        impl<'de, T, const N: usize> serde::de::Visitor<'de> for SlotsVisitor<T, N>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = [Option<T>; N];
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(formatter, "a sequence of at most {} entries", N)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut array = [const { None }; N];
+                let mut index = 0;
+
+                while let Some(slot) = seq.next_element::<Option<T>>()? {
+                    if index >= N {
+                        return Err(serde::de::Error::custom("too many entries for capacity"));
+                    }
+                    array[index] = slot;
+                    index += 1;
+                }
+
+                Ok(array)
+            }
+        }
+
+        deserializer.deserialize_seq(SlotsVisitor::<T, N> {
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+// Serde implementation for ORMap
+#[cfg(feature = "serde")]
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> Serialize for ORMap<K, V, C, CAPACITY>
+where
+    K: Serialize + Clone + PartialEq,
+    V: Serialize + Clone + PartialEq,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ORMap", 3)?;
+
+        // Serialize only the used portions of the arrays as slices
+        state.serialize_field("entries", self.entry_slice())?;
+        state.serialize_field("tombstones", self.tombstone_slice())?;
+        state.serialize_field("node_id", &self.node_id)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de>
+    for ORMap<K, V, C, CAPACITY>
+where
+    K: Deserialize<'de> + Clone + PartialEq,
+    V: Deserialize<'de> + Clone + PartialEq,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use core::fmt;
+        use serde::de::{self, MapAccess, Visitor};
+
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Field {
+            Entries,
+            Tombstones,
+            NodeId,
+        }
+
+        struct ORMapVisitor<K, V, C: MemoryConfig, const CAPACITY: usize> {
+            _phantom: core::marker::PhantomData<(K, V, C)>,
+        }
+
+        impl<'de, K, V, C: MemoryConfig, const CAPACITY: usize> Visitor<'de>
+            for ORMapVisitor<K, V, C, CAPACITY>
+        where
+            K: Deserialize<'de> + Clone + PartialEq,
+            V: Deserialize<'de> + Clone + PartialEq,
+        {
+            type Value = ORMap<K, V, C, CAPACITY>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ORMap")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<ORMap<K, V, C, CAPACITY>, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = None;
+                let mut tombstones = None;
+                let mut node_id = None;
+
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Entries => {
+                            if entries.is_some() {
+                                return Err(de::Error::duplicate_field("entries"));
+                            }
+                            entries = Some(map.next_value_seed(SlotsSeed::<
+                                TaggedEntry<K, V>,
+                                CAPACITY,
+                            > {
+                                _phantom: core::marker::PhantomData,
+                            })?);
+                        }
+                        Field::Tombstones => {
+                            if tombstones.is_some() {
+                                return Err(de::Error::duplicate_field("tombstones"));
+                            }
+                            tombstones = Some(map.next_value_seed(SlotsSeed::<
+                                TagTombstone<K>,
+                                CAPACITY,
+                            > {
+                                _phantom: core::marker::PhantomData,
+                            })?);
+                        }
+                        Field::NodeId => {
+                            if node_id.is_some() {
+                                return Err(de::Error::duplicate_field("node_id"));
+                            }
+                            node_id = Some(map.next_value::<NodeId>()?);
+                        }
+                    }
+                }
+
+                let entries = entries.ok_or_else(|| de::Error::missing_field("entries"))?;
+                let tombstones =
+                    tombstones.ok_or_else(|| de::Error::missing_field("tombstones"))?;
+                let node_id = node_id.ok_or_else(|| de::Error::missing_field("node_id"))?;
+
+                // Slots are filled from the front, so the counts are the occupied prefix
+                let entry_count = entries.iter().take_while(|slot| slot.is_some()).count();
+                let tombstone_count = tombstones.iter().take_while(|slot| slot.is_some()).count();
+
+                #[cfg(not(feature = "hardware-atomic"))]
+                {
+                    Ok(ORMap {
+                        entries,
+                        entry_count,
+                        tombstones,
+                        tombstone_count,
+                        node_id,
+                        _phantom: core::marker::PhantomData,
+                    })
+                }
+
+                #[cfg(feature = "hardware-atomic")]
+                {
+                    Ok(ORMap {
+                        entries: UnsafeCell::new(entries),
+                        entry_count: AtomicUsize::new(entry_count),
+                        tombstones: UnsafeCell::new(tombstones),
+                        tombstone_count: AtomicUsize::new(tombstone_count),
+                        node_id,
+                        _phantom: core::marker::PhantomData,
+                    })
+                }
+            }
+        }
+
+        const FIELDS: &[&str] = &["entries", "tombstones", "node_id"];
+        deserializer.deserialize_struct(
+            "ORMap",
+            FIELDS,
+            ORMapVisitor {
+                _phantom: core::marker::PhantomData,
+            },
+        )
+    }
+}
+
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> Default for ORMap<K, V, C, CAPACITY>
+where
+    K: Clone + PartialEq,
+    V: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> CRDT<C> for ORMap<K, V, C, CAPACITY>
+where
+    K: Clone + PartialEq + core::fmt::Debug + StateHash,
+    V: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        // Check capacity first so a failed merge leaves the map untouched
+        if !self.can_merge(other) {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        for entry in other.entry_slice().iter().flatten() {
+            self.put_entry(entry.clone())?;
+        }
+        for tombstone in other.tombstone_slice().iter().flatten() {
+            self.push_tombstone(tombstone.clone())?;
+        }
+
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        // Compare the raw tagged state, including removed entries
+        let entries_match = self.entry_slice().len() == other.entry_slice().len()
+            && self.entry_slice().iter().flatten().all(|entry| {
+                other.entry_slice().iter().flatten().any(|theirs| {
+                    theirs.key == entry.key
+                        && theirs.value == entry.value
+                        && theirs.timestamp == entry.timestamp
+                        && theirs.node_id == entry.node_id
+                })
+            });
+
+        let tombstones_match = self.tombstone_slice().len() == other.tombstone_slice().len()
+            && self.tombstone_slice().iter().flatten().all(|tombstone| {
+                other.tombstone_slice().iter().flatten().any(|theirs| {
+                    theirs.key == tombstone.key
+                        && theirs.timestamp == tombstone.timestamp
+                        && theirs.node_id == tombstone.node_id
+                        && theirs.remove_timestamp == tombstone.remove_timestamp
+                })
+            });
+
+        entries_match && tombstones_match
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        // Validate node ID is within bounds
+        if self.node_id as usize >= C::MAX_NODES {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        // Validate that we don't exceed the configured maximum entries
+        let entries = self.entry_slice();
+        if entries.len() > C::MAX_MAP_ENTRIES {
+            return Err(CRDTError::ConfigurationExceeded);
+        }
+
+        // At most one tag per (key, node) and one tombstone per tag
+        for (i, entry) in entries.iter().enumerate() {
+            if let Some(entry) = entry {
+                if entries[i + 1..]
+                    .iter()
+                    .flatten()
+                    .any(|other| other.key == entry.key && other.node_id == entry.node_id)
+                {
+                    return Err(CRDTError::InvalidState);
+                }
+            }
+        }
+
+        let tombstones = self.tombstone_slice();
+        for (i, tombstone) in tombstones.iter().enumerate() {
+            if let Some(tombstone) = tombstone {
+                if tombstones[i + 1..].iter().flatten().any(|other| {
+                    other.key == tombstone.key
+                        && other.node_id == tombstone.node_id
+                        && other.timestamp == tombstone.timestamp
+                }) {
+                    return Err(CRDTError::InvalidState);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        // XOR of per-entry FNV-1a hashes (order-independent), over the raw
+        // tagged state like `eq`
+        let mut hash = 0u32;
+        for entry in self.entry_slice().iter().flatten() {
+            let mut hasher = FnvHasher::new();
+            hasher.write_value(&entry.key);
+            hasher.write_value(&entry.value);
+            hasher.write_u64(entry.timestamp.as_u64());
+            hasher.write_bytes(&[entry.node_id]);
+            hash ^= hasher.finish();
+        }
+        for tombstone in self.tombstone_slice().iter().flatten() {
+            let mut hasher = FnvHasher::new();
+            hasher.write_value(&tombstone.key);
+            hasher.write_u64(tombstone.timestamp.as_u64());
+            hasher.write_bytes(&[tombstone.node_id]);
+            hasher.write_u64(tombstone.remove_timestamp.as_u64());
+            hash ^= hasher.finish().rotate_left(16);
+        }
+        hash ^ (self.entry_slice().len() as u32) ^ ((self.tombstone_slice().len() as u32) << 8)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        let (new_entries, new_tombstones) = self.new_slots_needed(other);
+        self.entry_slice().len() + new_entries <= CAPACITY
+            && self.tombstone_slice().len() + new_tombstones <= CAPACITY
+    }
+}
+
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for ORMap<K, V, C, CAPACITY>
+where
+    K: Clone + PartialEq + core::fmt::Debug + StateHash,
+    V: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.entry_count()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Tombstones must outlive every replica's copy of their tag
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.entry_count() < CAPACITY
+    }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        let stamp = core::mem::size_of::<CompactTimestamp>() + core::mem::size_of::<NodeId>();
+        let entry = core::mem::size_of::<K>() + core::mem::size_of::<V>() + stamp;
+        // Tombstones also record when the tag was removed
+        let tombstone =
+            core::mem::size_of::<K>() + stamp + core::mem::size_of::<CompactTimestamp>();
+
+        let entries = self.entry_count();
+        let tombstones = self.tombstone_count();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            entries * entry,
+            tombstones * tombstone,
+            (CAPACITY - entries) * core::mem::size_of::<Option<TaggedEntry<K, V>>>()
+                + (CAPACITY - tombstones) * core::mem::size_of::<Option<TagTombstone<K>>>(),
+        )
+    }
+}
+
+impl<K, V, C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for ORMap<K, V, C, CAPACITY>
+where
+    K: Clone + PartialEq + core::fmt::Debug + StateHash,
+    V: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_MERGE_CYCLES: u32 = 400; // Entry and tombstone arrays, like ORSet
+    const MAX_VALIDATE_CYCLES: u32 = 200;
+    const MAX_SERIALIZE_CYCLES: u32 = 300;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        // ORMap merge is bounded by the number of entries and tombstones
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        // Validation is bounded by the number of entries and tombstones
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For this simple implementation, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For this simple implementation, we don't track budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_new_map() {
+        let map = ORMap::<u8, u32, DefaultConfig>::new(1);
+        assert!(map.is_empty());
+        assert_eq!(map.len(), 0);
+        assert_eq!(map.capacity(), 8);
+        assert_eq!(map.remaining_capacity(), 8);
+        assert_eq!(map.node_id(), 1);
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut map = ORMap::<u8, u32, DefaultConfig>::new(1);
+
+        assert!(map.insert(1, 100, 1000).unwrap());
+        assert!(map.insert(2, 200, 1000).unwrap());
+        assert_eq!(map.get(&1), Some(&100));
+        assert_eq!(map.get(&2), Some(&200));
+        assert_eq!(map.get(&3), None);
+        assert_eq!(map.len(), 2);
+
+        // A newer local write replaces the old tag in place
+        assert!(!map.insert(1, 150, 2000).unwrap());
+        assert_eq!(map.get(&1), Some(&150));
+        assert_eq!(map.entry_count(), 2);
+
+        // An older local write is ignored
+        map.insert(1, 50, 500).unwrap();
+        assert_eq!(map.get(&1), Some(&150));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map = ORMap::<u8, u32, DefaultConfig>::new(1);
+        map.insert(1, 100, 1000).unwrap();
+
+        assert!(map.remove(&1, 2000).unwrap());
+        assert!(!map.contains_key(&1));
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get_all_values(&1).count(), 0);
+        assert_eq!(map.tombstone_count(), 1);
+
+        // Removing again does nothing
+        assert!(!map.remove(&1, 2001).unwrap());
+        assert!(!map.remove(&9, 2001).unwrap());
+        assert_eq!(map.tombstone_count(), 1);
+
+        // Re-inserting creates a fresh tag that the tombstone doesn't cover
+        assert!(map.insert(1, 300, 3000).unwrap());
+        assert_eq!(map.get(&1), Some(&300));
+    }
+
+    #[test]
+    fn test_ignored_insert_reports_false() {
+        let mut map = ORMap::<u8, u32, DefaultConfig>::new(1);
+        map.insert(1, 100, 2000).unwrap();
+        map.remove(&1, 3000).unwrap();
+
+        // This node's removed tag is newer, so the write changes nothing
+        assert!(!map.insert(1, 150, 1500).unwrap());
+        assert!(!map.insert(1, 150, 2000).unwrap());
+        assert!(!map.contains_key(&1));
+        assert_eq!(map.entry_count(), 1);
+
+        // A stale write to a present key is ignored as well
+        map.insert(2, 200, 2000).unwrap();
+        assert!(!map.insert(2, 250, 1000).unwrap());
+        assert_eq!(map.get(&2), Some(&200));
+    }
+
+    #[test]
+    fn test_concurrent_insert_survives_remove() {
+        let mut map1 = ORMap::<u8, u32, DefaultConfig>::new(1);
+        let mut map2 = ORMap::<u8, u32, DefaultConfig>::new(2);
+
+        map1.insert(1, 100, 1000).unwrap();
+        map2.merge(&map1).unwrap();
+
+        // Node 1 removes what it observed, node 2 concurrently writes
+        map1.remove(&1, 2000).unwrap();
+        map2.insert(1, 200, 1500).unwrap();
+
+        let mut merged1 = map1.clone();
+        merged1.merge(&map2).unwrap();
+        let mut merged2 = map2.clone();
+        merged2.merge(&map1).unwrap();
+
+        // Add wins: the unobserved write survives, the observed one is gone
+        assert_eq!(merged1.get(&1), Some(&200));
+        assert_eq!(merged1.get_all_values(&1).count(), 1);
+        assert!(merged1.eq(&merged2));
+    }
+
+    #[test]
+    fn test_concurrent_values_preserved() {
+        let mut map1 = ORMap::<u8, u32, DefaultConfig>::new(1);
+        let mut map2 = ORMap::<u8, u32, DefaultConfig>::new(2);
+
+        map1.insert(1, 100, 1000).unwrap();
+        map2.insert(1, 200, 1000).unwrap();
+        map1.merge(&map2).unwrap();
+
+        let mut values = [0u32; 2];
+        for (slot, value) in values.iter_mut().zip(map1.get_all_values(&1)) {
+            *slot = *value;
+        }
+        values.sort_unstable();
+        assert_eq!(values, [100, 200]);
+
+        // get resolves deterministically: same timestamp, higher node wins
+        assert_eq!(map1.get(&1), Some(&200));
+        assert_eq!(map1.len(), 1);
+        assert_eq!(map1.iter().count(), 1);
+
+        // Removing the key tombstones every observed tag
+        assert!(map1.remove(&1, 2000).unwrap());
+        assert_eq!(map1.tombstone_count(), 2);
+        assert!(!map1.contains_key(&1));
+    }
+
+    #[test]
+    fn test_merge_commutative_and_idempotent() {
+        let mut map1 = ORMap::<u8, u32, DefaultConfig>::new(1);
+        let mut map2 = ORMap::<u8, u32, DefaultConfig>::new(2);
+
+        map1.insert(1, 10, 1000).unwrap();
+        map1.insert(2, 20, 1100).unwrap();
+        map2.insert(2, 25, 1200).unwrap();
+        map2.insert(3, 30, 1300).unwrap();
+        map2.merge(&map1).unwrap();
+        map2.remove(&1, 1400).unwrap();
+
+        let mut merged1 = map1.clone();
+        merged1.merge(&map2).unwrap();
+        let mut merged2 = map2.clone();
+        merged2.merge(&map1).unwrap();
+
+        assert!(merged1.eq(&merged2));
+        assert_eq!(merged1.state_hash(), merged2.state_hash());
+        assert!(!merged1.contains_key(&1));
+        assert_eq!(merged1.get(&2), Some(&25));
+        assert_eq!(merged1.get(&3), Some(&30));
+
+        let snapshot = merged1.clone();
+        merged1.merge(&snapshot).unwrap();
+        assert!(merged1.eq(&snapshot));
+        assert!(merged1.validate().is_ok());
+    }
+
+    #[test]
+    fn test_state_hash_covers_keys_and_values() {
+        let mut map1 = ORMap::<u8, u32, DefaultConfig>::new(1);
+        let mut map2 = ORMap::<u8, u32, DefaultConfig>::new(1);
+        map1.insert(1, 10, 1000).unwrap();
+        map2.insert(1, 11, 1000).unwrap();
+        assert!(!map1.eq(&map2));
+        assert_ne!(map1.state_hash(), map2.state_hash());
+
+        let mut map3 = ORMap::<u8, u32, DefaultConfig>::new(1);
+        map3.insert(2, 10, 1000).unwrap();
+        assert_ne!(map1.state_hash(), map3.state_hash());
+
+        // Tombstones for different keys hash differently too
+        map1.remove(&1, 2000).unwrap();
+        map3.remove(&2, 2000).unwrap();
+        assert_ne!(map1.state_hash(), map3.state_hash());
+    }
+
+    #[test]
+    fn test_capacity_limits() {
+        let mut map = ORMap::<u8, u32, DefaultConfig, 2>::with_capacity(1);
+        map.insert(1, 10, 1000).unwrap();
+        map.insert(2, 20, 1000).unwrap();
+        assert_eq!(map.insert(3, 30, 1000), Err(CRDTError::BufferOverflow));
+
+        // Overwriting an existing key needs no new slot
+        assert!(map.insert(1, 15, 2000).is_ok());
+
+        // A merge that doesn't fit leaves the map untouched
+        let mut other = ORMap::<u8, u32, DefaultConfig, 2>::with_capacity(2);
+        other.insert(5, 50, 1000).unwrap();
+        let before = map.clone();
+        assert!(!map.can_merge(&other));
+        assert_eq!(map.merge(&other), Err(CRDTError::BufferOverflow));
+        assert!(map.eq(&before));
+    }
+
+    #[test]
+    fn test_tombstone_overflow_is_atomic() {
+        let mut map = ORMap::<u8, u32, DefaultConfig, 2>::with_capacity(1);
+        let mut other = ORMap::<u8, u32, DefaultConfig, 2>::with_capacity(2);
+        map.insert(1, 10, 1000).unwrap();
+        other.insert(1, 20, 1000).unwrap();
+        map.merge(&other).unwrap();
+
+        // Two tags fit exactly
+        assert!(map.remove(&1, 2000).unwrap());
+        map.insert(1, 30, 3000).unwrap();
+
+        // A third tombstone doesn't, and nothing is written
+        assert_eq!(map.remove(&1, 4000), Err(CRDTError::BufferOverflow));
+        assert_eq!(map.tombstone_count(), 2);
+        assert_eq!(map.get(&1), Some(&30));
+    }
+
+    #[test]
+    fn test_bounded_and_real_time() {
+        let mut map = ORMap::<u8, u32, DefaultConfig>::new(1);
+        map.insert(1, 10, 1000).unwrap();
+
+        assert_eq!(map.element_count(), 1);
+        assert!(map.can_add_element());
+        assert!(map.memory_usage() > 0);
+        assert_eq!(map.compact().unwrap(), 0);
+
+        let other = ORMap::<u8, u32, DefaultConfig>::new(2);
+        assert!(map.merge_bounded(&other).is_ok());
+        assert!(map.validate_bounded().is_ok());
+    }
+
+    #[test]
+    fn test_validation() {
+        let map = ORMap::<u8, u32, DefaultConfig>::new(1);
+        assert!(map.validate().is_ok());
+
+        let invalid = ORMap::<u8, u32, DefaultConfig>::new(200);
+        assert_eq!(invalid.validate(), Err(CRDTError::InvalidNodeId));
+    }
+}
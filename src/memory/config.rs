@@ -3,11 +3,19 @@
 //! This module defines the MemoryConfig trait that allows users to configure
 //! memory limits for CRDTs at compile time.
 
+use crate::traits::{NoSafety, SafetyMergePolicy};
+
 /// Memory configuration trait for compile-time memory management
 ///
 /// This trait defines memory limits and constraints for CRDT implementations.
 /// All memory usage is determined at compile time to ensure deterministic behavior.
 pub trait MemoryConfig: Clone {
+    /// Human-readable name of this configuration, for diagnostics
+    const NAME: &'static str = "custom";
+
+    /// Description of this configuration, empty if it has none
+    const DOC: &'static str = "";
+
     /// Total memory budget for all CRDTs in bytes
     const TOTAL_CRDT_MEMORY: usize;
 
@@ -26,6 +34,12 @@ pub trait MemoryConfig: Clone {
     /// Maximum number of elements per set CRDT
     const MAX_SET_ELEMENTS: usize;
 
+    /// Maximum number of tombstones per set CRDT
+    ///
+    /// Removals are usually rarer than adds, so this defaults to half of
+    /// `MAX_SET_ELEMENTS`. Use it as the `TOMBSTONE_CAPACITY` of an `ORSet`.
+    const MAX_TOMBSTONE_ENTRIES: usize = Self::MAX_SET_ELEMENTS / 2;
+
     /// Maximum number of entries per map CRDT
     const MAX_MAP_ENTRIES: usize;
 
@@ -47,6 +61,20 @@ pub trait MemoryConfig: Clone {
     /// Cache line size for optimization in bytes
     const CACHE_LINE_SIZE: usize;
 
+    /// Safety level hierarchy used by safety-prioritized merges
+    ///
+    /// Use [`NoSafety`](crate::traits::NoSafety) when no hierarchy applies,
+    /// or a level type such as `automotive::ASILLevel` or
+    /// `industrial::SILLevel`.
+    type SafetyConfig: SafetyMergePolicy;
+
+    /// Number of `extends` links between this configuration and its root
+    ///
+    /// Hand-written configurations are roots. Configurations derived with
+    /// `define_memory_config! { extends: .. }` sit one level below their base,
+    /// so circular extension fails to evaluate at compile time.
+    const EXTENSION_DEPTH: usize = 0;
+
     /// Validates that the configuration is consistent and within bounds
     fn validate() -> Result<(), &'static str> {
         // Check that alignment is a power of 2
@@ -77,16 +105,7 @@ pub trait MemoryConfig: Clone {
 
     /// Estimates total memory usage based on configuration
     fn estimate_memory_usage() -> usize {
-        let clock_memory = Self::CLOCK_MEMORY_BUDGET;
-        let error_memory = Self::ERROR_BUFFER_SIZE;
-
-        // Estimate CRDT memory usage (conservative estimates)
-        let register_memory = Self::MAX_REGISTERS * 16; // ~16 bytes per register
-        let counter_memory = Self::MAX_COUNTERS * 8; // ~8 bytes per counter
-        let set_memory = Self::MAX_SETS * (8 + Self::MAX_SET_ELEMENTS.div_ceil(8)); // Metadata + bitmap
-        let map_memory = Self::MAX_MAPS * Self::MAX_MAP_ENTRIES * 12; // ~12 bytes per entry
-
-        clock_memory + error_memory + register_memory + counter_memory + set_memory + map_memory
+        crate::memory::validation::estimated_memory_usage::<Self>()
     }
 }
 
@@ -106,6 +125,8 @@ impl Default for DefaultConfig {
 }
 
 impl MemoryConfig for DefaultConfig {
+    const NAME: &'static str = "DefaultConfig";
+    const DOC: &'static str = "Default memory configuration for testing and examples";
     const TOTAL_CRDT_MEMORY: usize = 32 * 1024; // 32KB
     const MAX_REGISTERS: usize = 50;
     const MAX_COUNTERS: usize = 25;
@@ -119,6 +140,7 @@ impl MemoryConfig for DefaultConfig {
     const ERROR_BUFFER_SIZE: usize = 256;
     const MEMORY_ALIGNMENT: usize = 4;
     const CACHE_LINE_SIZE: usize = 32;
+    type SafetyConfig = NoSafety;
 }
 
 #[cfg(test)]
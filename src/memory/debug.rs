@@ -0,0 +1,109 @@
+//! Memory report formatting
+//!
+//! This module formats the [`MemoryBreakdown`] of a CRDT into a fixed-size
+//! `heapless::String`, so memory reports can be written to a UART, RTT or
+//! log sink without an allocator.
+
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::MemoryConfig;
+use crate::traits::{BoundedCRDT, MemoryBreakdown};
+use core::fmt::Write;
+use heapless::String;
+
+/// Formats a memory report for a CRDT
+///
+/// The report lists the total size, the element utilization and the
+/// per-field [`MemoryBreakdown`], one line each.
+///
+/// # Type Parameters
+/// - `N`: Capacity of the returned string in bytes (160 fits any report)
+///
+/// # Returns
+/// The report, or `CRDTError::BufferOverflow` if it does not fit in `N` bytes
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::memory::debug::print_memory_report;
+///
+/// let mut set = GSet::<u32, DefaultConfig, 4>::with_capacity();
+/// set.insert(42)?;
+///
+/// let report = print_memory_report::<_, DefaultConfig, 160>(&set)?;
+/// assert!(report.contains("utilization: 25% (1/4 elements)"));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+pub fn print_memory_report<T, C, const N: usize>(crdt: &T) -> CRDTResult<String<N>>
+where
+    T: BoundedCRDT<C>,
+    C: MemoryConfig,
+{
+    let breakdown = crdt.memory_breakdown();
+    let mut report = String::new();
+    write_report(
+        &mut report,
+        &breakdown,
+        crdt.utilization_percent(),
+        crdt.element_count(),
+        T::MAX_ELEMENTS,
+    )
+    .map_err(|_| CRDTError::BufferOverflow)?;
+    Ok(report)
+}
+
+/// Writes the report lines to any formatter
+fn write_report<W: Write>(
+    out: &mut W,
+    breakdown: &MemoryBreakdown,
+    utilization: u8,
+    elements: usize,
+    max_elements: usize,
+) -> core::fmt::Result {
+    writeln!(out, "memory: {} bytes", breakdown.total())?;
+    writeln!(
+        out,
+        "utilization: {}% ({}/{} elements)",
+        utilization, elements, max_elements
+    )?;
+    writeln!(out, "  metadata: {}", breakdown.metadata)?;
+    writeln!(out, "  elements: {}", breakdown.element_data)?;
+    writeln!(out, "  tombstones: {}", breakdown.tombstone_data)?;
+    write!(out, "  unused: {}", breakdown.unused_capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maps::LWWMap;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_report_lines() {
+        let mut map = LWWMap::<u8, u32, DefaultConfig, 4>::with_capacity(1);
+        map.insert(1, 100, 1000).unwrap();
+        map.insert(2, 200, 1000).unwrap();
+        map.delete(&2, 2000).unwrap();
+
+        let report = print_memory_report::<_, DefaultConfig, 160>(&map).unwrap();
+        let mut lines = report.lines();
+        let total = lines.next().unwrap();
+        assert!(total.starts_with("memory: "));
+        assert_eq!(lines.next(), Some("utilization: 50% (2/4 elements)"));
+        assert!(lines.next().unwrap().starts_with("  metadata: "));
+        // Two entries of key, value, timestamp and node ID
+        assert_eq!(lines.next(), Some("  elements: 28"));
+        // One tombstone of key, timestamp and node ID
+        assert_eq!(lines.next(), Some("  tombstones: 10"));
+        assert!(lines.next().unwrap().starts_with("  unused: "));
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_report_overflow() {
+        let map = LWWMap::<u8, u32, DefaultConfig>::new(1);
+        assert_eq!(
+            print_memory_report::<_, DefaultConfig, 16>(&map),
+            Err(CRDTError::BufferOverflow)
+        );
+    }
+}
@@ -23,10 +23,177 @@
 ///     max_nodes: 32,
 /// }
 /// ```
+///
+/// # Extending a base configuration
+///
+/// A configuration can inherit every field it does not set from another
+/// configuration with `extends`. Fields may be given in any order, bases may
+/// themselves extend other configurations, and the derived values are checked
+/// with [`MemoryValidator::validate_const`] at compile time.
+///
+/// ```rust
+/// use crdtosphere::memory::{MemoryConfig, define_memory_config};
+///
+/// define_memory_config! {
+///     name: PlatformConfig,
+///     total_memory: 32 * 1024,
+///     max_registers: 50,
+///     max_counters: 25,
+///     max_sets: 15,
+///     max_maps: 10,
+///     max_nodes: 16,
+/// }
+///
+/// define_memory_config! {
+///     name: SensorConfig,
+///     extends: PlatformConfig,
+///     max_nodes: 4,
+///     total_memory: 16 * 1024,
+/// }
+///
+/// assert_eq!(SensorConfig::MAX_NODES, 4);
+/// assert_eq!(SensorConfig::MAX_REGISTERS, 50);
+/// ```
+///
+/// Circular extension is rejected at compile time:
+///
+/// ```compile_fail
+/// use crdtosphere::memory::define_memory_config;
+///
+/// define_memory_config! { name: A, extends: B }
+/// define_memory_config! { name: B, extends: A }
+/// ```
+///
+/// So are overrides that fail validation:
+///
+/// ```compile_fail
+/// use crdtosphere::memory::{DefaultConfig, define_memory_config};
+///
+/// define_memory_config! { name: Misaligned, extends: DefaultConfig, memory_alignment: 3 }
+/// ```
+///
+/// # Documentation
+///
+/// An optional `doc` string right after `name` documents the generated type
+/// and is kept in [`MemoryConfig::DOC`] for runtime listings. The associated
+/// constants are documented with the values they were given, and every
+/// configuration is checked at compile time to instantiate a CRDT.
+///
+/// ```rust
+/// use crdtosphere::memory::{MemoryConfig, define_memory_config};
+/// use crdtosphere::counters::GCounter;
+///
+/// define_memory_config! {
+///     name: GatewayConfig,
+///     doc: "Configuration for automotive gateway ECUs with 128KB budget",
+///     total_memory: 128 * 1024,
+///     max_registers: 200,
+///     max_counters: 50,
+///     max_sets: 20,
+///     max_maps: 10,
+///     max_nodes: 32,
+/// }
+///
+/// assert_eq!(GatewayConfig::DOC, "Configuration for automotive gateway ECUs with 128KB budget");
+/// let counter = GCounter::<GatewayConfig>::new(0);
+/// assert_eq!(counter.value(), 0);
+/// ```
+///
+/// [`MemoryConfig::DOC`]: crate::memory::MemoryConfig::DOC
+///
+/// # Safety hierarchy
+///
+/// `safety_config` sets [`MemoryConfig::SafetyConfig`], the level hierarchy
+/// safety-prioritized merges rank by. It defaults to
+/// [`NoSafety`](crate::traits::NoSafety) and comes last; derived
+/// configurations inherit it from their base.
+///
+/// ```rust
+/// use crdtosphere::memory::{MemoryConfig, define_memory_config};
+/// use crdtosphere::traits::SafetyMergePolicy;
+///
+/// /// Two-level hierarchy for a plant controller
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum PlantLevel {
+///     Monitoring,
+///     Interlock,
+/// }
+///
+/// impl SafetyMergePolicy for PlantLevel {
+///     fn priority(level: Self) -> u8 {
+///         level as u8
+///     }
+///
+///     fn is_safety_critical(level: Self) -> bool {
+///         matches!(level, PlantLevel::Interlock)
+///     }
+/// }
+///
+/// define_memory_config! {
+///     name: PlantConfig,
+///     total_memory: 32 * 1024,
+///     max_registers: 50,
+///     max_counters: 25,
+///     max_sets: 15,
+///     max_maps: 10,
+///     max_nodes: 16,
+///     safety_config: PlantLevel,
+/// }
+///
+/// type Hierarchy = <PlantConfig as MemoryConfig>::SafetyConfig;
+/// assert!(Hierarchy::priority(PlantLevel::Interlock) > Hierarchy::priority(PlantLevel::Monitoring));
+/// ```
+///
+/// [`MemoryConfig::SafetyConfig`]: crate::memory::MemoryConfig::SafetyConfig
+///
+/// # Memory budget
+///
+/// Every configuration must have room for all the CRDTs it allows, as computed
+/// by [`MemoryValidator::required_memory`]. A configuration that cannot be
+/// satisfied fails to compile, and the error shows the required size next to
+/// `total_memory`:
+///
+/// ```compile_fail
+/// use crdtosphere::memory::define_memory_config;
+///
+/// define_memory_config! {
+///     name: OverBudget,
+///     total_memory: 2048,
+///     max_registers: 10000,
+///     max_counters: 1,
+///     max_sets: 1,
+///     max_maps: 1,
+///     max_nodes: 8,
+/// }
+/// ```
+///
+/// # Field checks
+///
+/// Fields that size arrays must be positive, `max_set_elements` may not
+/// exceed eight elements per node, and `memory_alignment` must be a power of
+/// two. Violations fail to compile with a message naming the field:
+///
+/// ```compile_fail
+/// use crdtosphere::memory::define_memory_config;
+///
+/// define_memory_config! {
+///     name: NoNodes,
+///     total_memory: 64 * 1024,
+///     max_registers: 10,
+///     max_counters: 10,
+///     max_sets: 10,
+///     max_maps: 10,
+///     max_nodes: 0,
+/// }
+/// ```
+///
+/// [`MemoryValidator::validate_const`]: crate::memory::MemoryValidator::validate_const
+/// [`MemoryValidator::required_memory`]: crate::memory::MemoryValidator::required_memory
 #[macro_export]
 macro_rules! define_memory_config {
     (
         name: $name:ident,
+        $(doc: $doc:expr,)?
         total_memory: $total:expr,
         max_registers: $registers:expr,
         max_counters: $counters:expr,
@@ -34,51 +201,305 @@ macro_rules! define_memory_config {
         max_maps: $maps:expr,
         max_nodes: $nodes:expr
         $(, max_set_elements: $set_elements:expr)?
+        $(, max_tombstone_entries: $tombstones:expr)?
         $(, max_map_entries: $map_entries:expr)?
         $(, max_history_size: $history:expr)?
         $(, clock_memory_budget: $clock_budget:expr)?
         $(, error_buffer_size: $error_buffer:expr)?
         $(, memory_alignment: $alignment:expr)?
         $(, cache_line_size: $cache_line:expr)?
+        $(, safety_config: $safety:ty)?
         $(,)?
     ) => {
-        /// Custom memory configuration
+        #[doc = define_memory_config!(@default $($doc)?, "Custom memory configuration")]
         #[derive(Debug, Clone, Copy)]
         pub struct $name;
 
         impl $crate::memory::MemoryConfig for $name {
+            const NAME: &'static str = stringify!($name);
+            const DOC: &'static str = define_memory_config!(@default $($doc)?, "");
+            #[doc = define_memory_config!(@doc "Total memory budget for all CRDTs in bytes", $total)]
             const TOTAL_CRDT_MEMORY: usize = $total;
+            #[doc = define_memory_config!(@doc "Maximum number of register CRDT instances", $registers)]
             const MAX_REGISTERS: usize = $registers;
+            #[doc = define_memory_config!(@doc "Maximum number of counter CRDT instances", $counters)]
             const MAX_COUNTERS: usize = $counters;
+            #[doc = define_memory_config!(@doc "Maximum number of set CRDT instances", $sets)]
             const MAX_SETS: usize = $sets;
+            #[doc = define_memory_config!(@doc "Maximum number of map CRDT instances", $maps)]
             const MAX_MAPS: usize = $maps;
+            #[doc = define_memory_config!(@doc "Maximum number of distinct nodes tracked", $nodes)]
             const MAX_NODES: usize = $nodes;
 
             // Optional parameters with defaults
+            #[doc = define_memory_config!(@doc "Maximum number of elements per set CRDT", $($set_elements)?, "32")]
             const MAX_SET_ELEMENTS: usize = define_memory_config!(@default $($set_elements)?, 32);
+            #[doc = define_memory_config!(@doc "Maximum number of tombstones per set CRDT", $($tombstones)?, "MAX_SET_ELEMENTS / 2")]
+            const MAX_TOMBSTONE_ENTRIES: usize =
+                define_memory_config!(@default $($tombstones)?, Self::MAX_SET_ELEMENTS / 2);
+            #[doc = define_memory_config!(@doc "Maximum number of entries per map CRDT", $($map_entries)?, "32")]
             const MAX_MAP_ENTRIES: usize = define_memory_config!(@default $($map_entries)?, 32);
+            #[doc = define_memory_config!(@doc "Maximum history size for multi-value CRDTs", $($history)?, "4")]
             const MAX_HISTORY_SIZE: usize = define_memory_config!(@default $($history)?, 4);
+            #[doc = define_memory_config!(@doc "Memory budget for clock management in bytes", $($clock_budget)?, "512")]
             const CLOCK_MEMORY_BUDGET: usize = define_memory_config!(@default $($clock_budget)?, 512);
+            #[doc = define_memory_config!(@doc "Error buffer size in bytes", $($error_buffer)?, "256")]
             const ERROR_BUFFER_SIZE: usize = define_memory_config!(@default $($error_buffer)?, 256);
+            #[doc = define_memory_config!(@doc "Memory alignment requirement in bytes", $($alignment)?, "4")]
             const MEMORY_ALIGNMENT: usize = define_memory_config!(@default $($alignment)?, 4);
+            #[doc = define_memory_config!(@doc "Cache line size for optimization in bytes", $($cache_line)?, "32")]
             const CACHE_LINE_SIZE: usize = define_memory_config!(@default $($cache_line)?, 32);
+            type SafetyConfig = define_memory_config!(@safety $($safety)?);
         }
 
-        // Runtime validation available via validate() method
-        // Note: Call $name::validate() at runtime to check configuration
+        // The fields must be consistent, and the CRDT limits must fit in the memory budget
+        const _: () = $crate::memory::macros::check_config_fields::<$name>();
+        const _: () = $crate::memory::macros::check_memory_budget::<$name>();
+
+        // A CRDT can be instantiated with the configuration
+        const _: () = {
+            let _ = $crate::registers::LWWRegister::<u32, $name>::new(0);
+        };
+    };
+
+    // Derived configuration: unspecified fields come from the base
+    (
+        name: $name:ident,
+        $(doc: $doc:expr,)?
+        extends: $base:ty
+        $(, $field:ident: $value:expr)*
+        $(,)?
+    ) => {
+        #[doc = define_memory_config!(@default $($doc)?, "Custom memory configuration derived from a base configuration")]
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        /// Fields not set here are inherited from the base configuration
+        impl $crate::memory::MemoryConfig for $name {
+            const NAME: &'static str = stringify!($name);
+            const DOC: &'static str = define_memory_config!(@default $($doc)?, "");
+            const TOTAL_CRDT_MEMORY: usize = define_memory_config!(@inherit $base, total_memory, TOTAL_CRDT_MEMORY $(, $field: $value)*);
+            const MAX_REGISTERS: usize = define_memory_config!(@inherit $base, max_registers, MAX_REGISTERS $(, $field: $value)*);
+            const MAX_COUNTERS: usize = define_memory_config!(@inherit $base, max_counters, MAX_COUNTERS $(, $field: $value)*);
+            const MAX_SETS: usize = define_memory_config!(@inherit $base, max_sets, MAX_SETS $(, $field: $value)*);
+            const MAX_MAPS: usize = define_memory_config!(@inherit $base, max_maps, MAX_MAPS $(, $field: $value)*);
+            const MAX_NODES: usize = define_memory_config!(@inherit $base, max_nodes, MAX_NODES $(, $field: $value)*);
+            const MAX_SET_ELEMENTS: usize = define_memory_config!(@inherit $base, max_set_elements, MAX_SET_ELEMENTS $(, $field: $value)*);
+            const MAX_TOMBSTONE_ENTRIES: usize = define_memory_config!(@inherit $base, max_tombstone_entries, MAX_TOMBSTONE_ENTRIES $(, $field: $value)*);
+            const MAX_MAP_ENTRIES: usize = define_memory_config!(@inherit $base, max_map_entries, MAX_MAP_ENTRIES $(, $field: $value)*);
+            const MAX_HISTORY_SIZE: usize = define_memory_config!(@inherit $base, max_history_size, MAX_HISTORY_SIZE $(, $field: $value)*);
+            const CLOCK_MEMORY_BUDGET: usize = define_memory_config!(@inherit $base, clock_memory_budget, CLOCK_MEMORY_BUDGET $(, $field: $value)*);
+            const ERROR_BUFFER_SIZE: usize = define_memory_config!(@inherit $base, error_buffer_size, ERROR_BUFFER_SIZE $(, $field: $value)*);
+            const MEMORY_ALIGNMENT: usize = define_memory_config!(@inherit $base, memory_alignment, MEMORY_ALIGNMENT $(, $field: $value)*);
+            const CACHE_LINE_SIZE: usize = define_memory_config!(@inherit $base, cache_line_size, CACHE_LINE_SIZE $(, $field: $value)*);
+            const EXTENSION_DEPTH: usize = <$base as $crate::memory::MemoryConfig>::EXTENSION_DEPTH + 1;
+            type SafetyConfig = <$base as $crate::memory::MemoryConfig>::SafetyConfig;
+        }
+
+        // Compile-time checks on the derived values
+        const _: () = {
+            $crate::memory::macros::check_override_fields(&[$(stringify!($field)),*]);
+            // A circular `extends` chain never finishes evaluating this
+            assert!(
+                <$name as $crate::memory::MemoryConfig>::EXTENSION_DEPTH
+                    <= $crate::memory::validation::MAX_EXTENSION_DEPTH,
+                "memory configuration extends chain is too deep"
+            );
+            $crate::memory::macros::check_config_fields::<$name>();
+            $crate::memory::macros::check_memory_budget::<$name>();
+            if let Err(message) = $crate::memory::MemoryValidator::validate_const::<$name>() {
+                panic!("{}", message);
+            }
+            let _ = $crate::registers::LWWRegister::<u32, $name>::new(0);
+        };
+    };
+
+    // Helper macro for inherited values
+    (@inherit $base:ty, $key:ident, $constant:ident $(, $field:ident: $value:expr)*) => {
+        $crate::memory::macros::inherit_field(
+            stringify!($key),
+            <$base as $crate::memory::MemoryConfig>::$constant,
+            &[$((stringify!($field), $value)),*],
+        )
     };
 
     // Helper macro for default values
     (@default $value:expr, $default:expr) => { $value };
     (@default , $default:expr) => { $default };
+
+    // Helper macro for constant docs, showing the value as written
+    (@doc $text:literal, $value:expr) => { concat!($text, " (= ", stringify!($value), ")") };
+    (@doc $text:literal, $value:expr, $default:literal) => {
+        concat!($text, " (= ", stringify!($value), ")")
+    };
+    (@doc $text:literal, , $default:literal) => {
+        concat!($text, " (= ", $default, ", the default)")
+    };
+
+    // Helper macro for the safety hierarchy
+    (@safety $safety:ty) => { $safety };
+    (@safety) => { $crate::traits::NoSafety };
 }
 
 // Re-export the macro for convenience
 pub use define_memory_config;
 
+use crate::memory::{MemoryConfig, MemoryValidator};
+
+/// Fields accepted by `define_memory_config!`
+const CONFIG_FIELDS: [&str; 14] = [
+    "total_memory",
+    "max_registers",
+    "max_counters",
+    "max_sets",
+    "max_maps",
+    "max_nodes",
+    "max_set_elements",
+    "max_tombstone_entries",
+    "max_map_entries",
+    "max_history_size",
+    "clock_memory_budget",
+    "error_buffer_size",
+    "memory_alignment",
+    "cache_line_size",
+];
+
+/// Compares two strings in `const` context
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the override for `key`, or the inherited value if there is none
+#[doc(hidden)]
+pub const fn inherit_field(key: &str, inherited: usize, overrides: &[(&str, usize)]) -> usize {
+    let mut i = 0;
+    while i < overrides.len() {
+        if str_eq(overrides[i].0, key) {
+            return overrides[i].1;
+        }
+        i += 1;
+    }
+    inherited
+}
+
+/// Rejects unknown and repeated fields in a derived configuration
+#[doc(hidden)]
+pub const fn check_override_fields(fields: &[&str]) {
+    let mut i = 0;
+    while i < fields.len() {
+        let mut known = false;
+        let mut k = 0;
+        while k < CONFIG_FIELDS.len() {
+            known |= str_eq(fields[i], CONFIG_FIELDS[k]);
+            k += 1;
+        }
+        if !known {
+            panic!("unknown field in define_memory_config!");
+        }
+
+        let mut j = i + 1;
+        while j < fields.len() {
+            if str_eq(fields[i], fields[j]) {
+                panic!("duplicate field in define_memory_config!");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Rejects configurations with impossible or inconsistent fields
+///
+/// Counts that size arrays or divide must be positive, set sizes must stay
+/// within eight elements per node, and the alignment must be a power of two.
+#[doc(hidden)]
+pub const fn check_config_fields<C: MemoryConfig>() {
+    assert!(C::TOTAL_CRDT_MEMORY > 0, "total_memory must be > 0");
+    assert!(C::MAX_NODES > 0, "max_nodes must be > 0");
+    assert!(C::MAX_SET_ELEMENTS > 0, "max_set_elements must be > 0");
+    assert!(C::MAX_MAP_ENTRIES > 0, "max_map_entries must be > 0");
+    assert!(C::CACHE_LINE_SIZE > 0, "cache_line_size must be > 0");
+    assert!(
+        C::MAX_SET_ELEMENTS <= C::MAX_NODES * 8,
+        "set elements per node exceeds reasonable bound"
+    );
+    assert!(
+        C::MEMORY_ALIGNMENT.is_power_of_two(),
+        "alignment must be power of two"
+    );
+}
+
+/// Appends `text` to a `const` message buffer, returning the new length
+const fn push_str(buffer: &mut [u8], mut len: usize, text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() && len < buffer.len() {
+        buffer[len] = bytes[i];
+        len += 1;
+        i += 1;
+    }
+    len
+}
+
+/// Appends `value` in decimal to a `const` message buffer, returning the new length
+const fn push_usize(buffer: &mut [u8], len: usize, mut value: usize) -> usize {
+    let mut digits = [0u8; 20];
+    let mut count = 0;
+    loop {
+        digits[count] = b'0' + (value % 10) as u8;
+        count += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+
+    let mut len = len;
+    while count > 0 && len < buffer.len() {
+        count -= 1;
+        buffer[len] = digits[count];
+        len += 1;
+    }
+    len
+}
+
+/// Rejects configurations whose CRDT limits don't fit in `total_memory`
+///
+/// The panic message carries the required size and the budget, so the
+/// compile error shows how far over budget the configuration is.
+#[doc(hidden)]
+pub const fn check_memory_budget<C: MemoryConfig>() {
+    let required = MemoryValidator::required_memory::<C>();
+    let budget = C::TOTAL_CRDT_MEMORY;
+    if required <= budget {
+        return;
+    }
+
+    let mut buffer = [0u8; 128];
+    let mut len = push_str(&mut buffer, 0, "memory budget exceeded: CRDT limits need ");
+    len = push_usize(&mut buffer, len, required);
+    len = push_str(&mut buffer, len, " bytes but total_memory is ");
+    len = push_usize(&mut buffer, len, budget);
+    len = push_str(&mut buffer, len, " bytes");
+    match core::str::from_utf8(buffer.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("memory budget exceeded"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::memory::MemoryConfig;
 
     define_memory_config! {
@@ -107,6 +528,43 @@ mod tests {
         assert_eq!(TestConfig::MEMORY_ALIGNMENT, 4);
     }
 
+    define_memory_config! {
+        name: DocumentedConfig,
+        doc: "Configuration with a description",
+        total_memory: 16 * 1024,
+        max_registers: 25,
+        max_counters: 15,
+        max_sets: 10,
+        max_maps: 5,
+        max_nodes: 8,
+    }
+
+    define_memory_config! {
+        name: DocumentedGatewayConfig,
+        doc: "Derived configuration with a description",
+        extends: DocumentedConfig,
+        max_nodes: 16,
+    }
+
+    #[test]
+    fn test_macro_doc() {
+        assert_eq!(DocumentedConfig::DOC, "Configuration with a description");
+        assert_eq!(
+            DocumentedGatewayConfig::DOC,
+            "Derived configuration with a description"
+        );
+        assert_eq!(DocumentedGatewayConfig::MAX_REGISTERS, 25);
+        assert_eq!(TestConfig::DOC, "");
+        assert_eq!(
+            define_memory_config!(@doc "Maximum number of distinct nodes tracked", 4 * 8),
+            "Maximum number of distinct nodes tracked (= 4 * 8)"
+        );
+        assert_eq!(
+            define_memory_config!(@doc "Error buffer size in bytes", , "256"),
+            "Error buffer size in bytes (= 256, the default)"
+        );
+    }
+
     #[test]
     fn test_macro_validation() {
         // This should compile without panicking due to compile-time validation
@@ -122,6 +580,7 @@ mod tests {
         max_maps: 2,
         max_nodes: 4,
         max_set_elements: 16,
+        max_tombstone_entries: 4,
         max_map_entries: 16,
         memory_alignment: 8,
     }
@@ -129,8 +588,66 @@ mod tests {
     #[test]
     fn test_macro_with_custom_values() {
         assert_eq!(CustomConfig::MAX_SET_ELEMENTS, 16);
+        assert_eq!(CustomConfig::MAX_TOMBSTONE_ENTRIES, 4);
+        assert_eq!(
+            TestConfig::MAX_TOMBSTONE_ENTRIES,
+            TestConfig::MAX_SET_ELEMENTS / 2
+        );
         assert_eq!(CustomConfig::MAX_MAP_ENTRIES, 16);
         assert_eq!(CustomConfig::MEMORY_ALIGNMENT, 8);
         assert!(CustomConfig::validate().is_ok());
     }
+
+    define_memory_config! {
+        name: GatewayConfig,
+        extends: TestConfig,
+        max_nodes: 32,
+        total_memory: 64 * 1024,
+    }
+
+    define_memory_config! {
+        name: EdgeGatewayConfig,
+        extends: GatewayConfig,
+        max_maps: 12,
+        cache_line_size: 64,
+    }
+
+    #[test]
+    fn test_macro_extends_base() {
+        assert_eq!(GatewayConfig::MAX_NODES, 32);
+        assert_eq!(GatewayConfig::TOTAL_CRDT_MEMORY, 64 * 1024);
+
+        // Everything else is inherited
+        assert_eq!(GatewayConfig::MAX_REGISTERS, TestConfig::MAX_REGISTERS);
+        assert_eq!(GatewayConfig::MAX_MAPS, TestConfig::MAX_MAPS);
+        assert_eq!(
+            GatewayConfig::MAX_SET_ELEMENTS,
+            TestConfig::MAX_SET_ELEMENTS
+        );
+        assert_eq!(GatewayConfig::CACHE_LINE_SIZE, TestConfig::CACHE_LINE_SIZE);
+
+        assert_eq!(TestConfig::EXTENSION_DEPTH, 0);
+        assert_eq!(GatewayConfig::EXTENSION_DEPTH, 1);
+        assert!(GatewayConfig::validate().is_ok());
+    }
+
+    #[test]
+    fn test_macro_extends_transitively() {
+        assert_eq!(EdgeGatewayConfig::MAX_MAPS, 12);
+        assert_eq!(EdgeGatewayConfig::CACHE_LINE_SIZE, 64);
+        assert_eq!(EdgeGatewayConfig::MAX_NODES, 32);
+        assert_eq!(EdgeGatewayConfig::TOTAL_CRDT_MEMORY, 64 * 1024);
+        assert_eq!(EdgeGatewayConfig::MAX_COUNTERS, TestConfig::MAX_COUNTERS);
+        assert_eq!(EdgeGatewayConfig::EXTENSION_DEPTH, 2);
+    }
+
+    #[test]
+    fn test_inherit_field() {
+        use super::inherit_field;
+
+        let overrides = [("max_nodes", 4), ("total_memory", 1024)];
+        assert_eq!(inherit_field("max_nodes", 16, &overrides), 4);
+        assert_eq!(inherit_field("total_memory", 4096, &overrides), 1024);
+        assert_eq!(inherit_field("max_sets", 15, &overrides), 15);
+    }
 }
@@ -4,6 +4,9 @@
 //! It includes compile-time memory configuration, validation, and static memory pools.
 
 pub mod config;
+#[cfg(feature = "debug")]
+#[cfg_attr(docsrs, doc(cfg(feature = "debug")))]
+pub mod debug;
 pub mod macros;
 pub mod validation;
 
@@ -3,7 +3,28 @@
 //! This module provides runtime memory validation to ensure
 //! configurations are safe and within bounds.
 
-use crate::memory::MemoryConfig;
+use crate::counters::GCounter;
+use crate::maps::LWWMap;
+use crate::memory::{DefaultConfig, MemoryConfig};
+use crate::registers::LWWRegister;
+use crate::sets::GSet;
+
+/// Deepest `extends` chain accepted by `define_memory_config!`
+pub const MAX_EXTENSION_DEPTH: usize = 16;
+
+/// Estimates total memory usage from a configuration's limits
+pub(crate) const fn estimated_memory_usage<C: MemoryConfig>() -> usize {
+    let clock_memory = C::CLOCK_MEMORY_BUDGET;
+    let error_memory = C::ERROR_BUFFER_SIZE;
+
+    // Estimate CRDT memory usage (conservative estimates)
+    let register_memory = C::MAX_REGISTERS * 16; // ~16 bytes per register
+    let counter_memory = C::MAX_COUNTERS * 8; // ~8 bytes per counter
+    let set_memory = C::MAX_SETS * (8 + C::MAX_SET_ELEMENTS.div_ceil(8)); // Metadata + bitmap
+    let map_memory = C::MAX_MAPS * C::MAX_MAP_ENTRIES * 12; // ~12 bytes per entry
+
+    clock_memory + error_memory + register_memory + counter_memory + set_memory + map_memory
+}
 
 /// Memory validator for runtime verification
 pub struct MemoryValidator;
@@ -14,6 +35,19 @@ impl MemoryValidator {
         C::validate()
     }
 
+    /// Returns the memory needed to hold every CRDT a configuration allows
+    ///
+    /// Each CRDT is counted at the in-memory size of a representative instance:
+    /// an `LWWRegister<u64>`, a `GCounter`, a `GSet<u32>` and an
+    /// `LWWMap<u8, u32>`, all with their default capacities. `define_memory_config!`
+    /// rejects configurations whose `total_memory` is smaller than this.
+    pub const fn required_memory<C: MemoryConfig>() -> usize {
+        C::MAX_REGISTERS * core::mem::size_of::<LWWRegister<u64, DefaultConfig>>()
+            + C::MAX_COUNTERS * core::mem::size_of::<GCounter<DefaultConfig>>()
+            + C::MAX_SETS * core::mem::size_of::<GSet<u32, DefaultConfig>>()
+            + C::MAX_MAPS * core::mem::size_of::<LWWMap<u8, u32, DefaultConfig>>()
+    }
+
     /// Checks if estimated memory usage is within budget
     pub fn check_memory_budget<C: MemoryConfig>() -> Result<(), &'static str> {
         let estimated = C::estimate_memory_usage();
@@ -32,7 +66,7 @@ impl MemoryValidator {
     }
 
     /// Validates alignment requirements
-    pub fn check_alignment<C: MemoryConfig>() -> Result<(), &'static str> {
+    pub const fn check_alignment<C: MemoryConfig>() -> Result<(), &'static str> {
         let alignment = C::MEMORY_ALIGNMENT;
 
         // Check that alignment is a power of 2
@@ -49,7 +83,7 @@ impl MemoryValidator {
     }
 
     /// Validates node count limits
-    pub fn check_node_limits<C: MemoryConfig>() -> Result<(), &'static str> {
+    pub const fn check_node_limits<C: MemoryConfig>() -> Result<(), &'static str> {
         let max_nodes = C::MAX_NODES;
 
         if max_nodes == 0 {
@@ -64,7 +98,7 @@ impl MemoryValidator {
     }
 
     /// Validates CRDT instance limits
-    pub fn check_crdt_limits<C: MemoryConfig>() -> Result<(), &'static str> {
+    pub const fn check_crdt_limits<C: MemoryConfig>() -> Result<(), &'static str> {
         if C::MAX_REGISTERS == 0 && C::MAX_COUNTERS == 0 && C::MAX_SETS == 0 && C::MAX_MAPS == 0 {
             return Err("At least one CRDT type must have a non-zero limit");
         }
@@ -82,11 +116,15 @@ impl MemoryValidator {
             return Err("MAX_MAP_ENTRIES must be non-zero if MAX_MAPS > 0");
         }
 
+        if C::MAX_TOMBSTONE_ENTRIES > C::MAX_SET_ELEMENTS {
+            return Err("MAX_TOMBSTONE_ENTRIES cannot exceed MAX_SET_ELEMENTS");
+        }
+
         Ok(())
     }
 
     /// Validates cache line size
-    pub fn check_cache_line<C: MemoryConfig>() -> Result<(), &'static str> {
+    pub const fn check_cache_line<C: MemoryConfig>() -> Result<(), &'static str> {
         let cache_line = C::CACHE_LINE_SIZE;
 
         // Check that cache line size is a power of 2
@@ -95,13 +133,41 @@ impl MemoryValidator {
         }
 
         // Check reasonable cache line bounds (8 to 128 bytes)
-        if !(8..=128).contains(&cache_line) {
+        if cache_line < 8 || cache_line > 128 {
             return Err("CACHE_LINE_SIZE should be between 8 and 128 bytes");
         }
 
         Ok(())
     }
 
+    /// Comprehensive validation usable in `const` context
+    ///
+    /// Runs the same checks as [`validate_all`](Self::validate_all), using the
+    /// default memory estimate. `define_memory_config!` evaluates this for
+    /// derived configurations so invalid overrides fail to compile.
+    pub const fn validate_const<C: MemoryConfig>() -> Result<(), &'static str> {
+        let checks = [
+            Self::check_alignment::<C>(),
+            Self::check_node_limits::<C>(),
+            Self::check_crdt_limits::<C>(),
+            Self::check_cache_line::<C>(),
+        ];
+
+        let mut i = 0;
+        while i < checks.len() {
+            if let Err(message) = checks[i] {
+                return Err(message);
+            }
+            i += 1;
+        }
+
+        if estimated_memory_usage::<C>() > C::TOTAL_CRDT_MEMORY {
+            return Err("Estimated memory usage exceeds configured budget");
+        }
+
+        Ok(())
+    }
+
     /// Comprehensive validation of all configuration aspects
     pub fn validate_all<C: MemoryConfig>() -> Result<(), &'static str> {
         Self::check_alignment::<C>()?;
@@ -118,8 +184,8 @@ impl MemoryValidator {
     }
 }
 
-// Note: Memory configuration validation is performed at runtime only.
-// Use MemoryValidator::validate_all::<YourConfig>() to validate configurations.
+// Note: define_memory_config! checks its fields and required_memory() at compile
+// time. The remaining checks run at runtime through MemoryValidator::validate_all::<YourConfig>().
 
 #[cfg(test)]
 mod tests {
@@ -171,4 +237,15 @@ mod tests {
     fn test_validated_config() {
         assert!(MemoryValidator::validate_all::<ValidatedConfig>().is_ok());
     }
+
+    #[test]
+    fn test_required_memory() {
+        let register = core::mem::size_of::<LWWRegister<u64, DefaultConfig>>();
+        let map = core::mem::size_of::<LWWMap<u8, u32, DefaultConfig>>();
+
+        assert!(MemoryValidator::required_memory::<ValidatedConfig>() >= 20 * register + 3 * map);
+        assert!(
+            MemoryValidator::required_memory::<DefaultConfig>() <= DefaultConfig::TOTAL_CRDT_MEMORY
+        );
+    }
 }
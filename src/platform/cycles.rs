@@ -0,0 +1,75 @@
+//! CPU cycle counter access
+//!
+//! Reads the free-running cycle counter that
+//! [`RealTimeCRDT::timed_merge`](crate::traits::RealTimeCRDT::timed_merge)
+//! measures merges with. With the `aurix` feature the counter is read from
+//! the TriCore CCNT register. Other platforms provide
+//! `crdtosphere_read_cycle_counter` themselves, e.g. on Cortex-M:
+//!
+//! ```rust,ignore
+//! #[unsafe(no_mangle)]
+//! pub extern "C" fn crdtosphere_read_cycle_counter() -> u32 {
+//!     cortex_m::peripheral::DWT::cycle_count()
+//! }
+//! ```
+
+#[cfg(not(feature = "aurix"))]
+unsafe extern "C" {
+    /// Current value of the platform's free-running cycle counter
+    fn crdtosphere_read_cycle_counter() -> u32;
+}
+
+/// Current value of the CCNT register of TriCore CPU0
+///
+/// Reads CCNT through CPU0's memory-mapped CSFR space, so every core sees
+/// CPU0's counter. Startup code must enable counting by setting CCTRL.CE.
+#[cfg(feature = "aurix")]
+#[unsafe(no_mangle)]
+extern "C" fn crdtosphere_read_cycle_counter() -> u32 {
+    // CPU0 CSFR base plus the CCNT offset
+    const CPU0_CCNT: *const u32 = 0xF881_FC04 as *const u32;
+
+    // SAFETY: CCNT is a read-only register mapped on every AURIX device
+    unsafe { core::ptr::read_volatile(CPU0_CCNT) }
+}
+
+/// Reads the platform cycle counter
+///
+/// # Returns
+/// The current cycle count, which wraps around at `u32::MAX`
+pub fn read_cycle_counter() -> u32 {
+    #[cfg(not(feature = "aurix"))]
+    {
+        // SAFETY: The platform must provide `crdtosphere_read_cycle_counter`
+        // as a safe function when it enables `cycle-counter`
+        unsafe { crdtosphere_read_cycle_counter() }
+    }
+
+    #[cfg(feature = "aurix")]
+    {
+        crdtosphere_read_cycle_counter()
+    }
+}
+
+#[cfg(all(test, not(feature = "aurix")))]
+mod tests {
+    use super::*;
+    use crate::counters::GCounter;
+    use crate::memory::DefaultConfig;
+    use crate::traits::RealTimeCRDT;
+
+    /// Stands in for the platform cycle counter
+    #[unsafe(no_mangle)]
+    extern "C" fn crdtosphere_read_cycle_counter() -> u32 {
+        0
+    }
+
+    #[test]
+    fn test_read_cycle_counter() {
+        let mut counter = GCounter::<DefaultConfig>::new(0);
+        let other = GCounter::<DefaultConfig>::new(1);
+
+        assert_eq!(read_cycle_counter(), 0);
+        assert!(counter.timed_merge(&other, read_cycle_counter).is_ok());
+    }
+}
@@ -0,0 +1,303 @@
+//! Interrupt-safe CRDT access for bare-metal targets
+//!
+//! The `hardware-atomic` feature lets several cores update a CRDT through
+//! `&self` using atomic instructions. On a single-core MCU without an OS the
+//! usual concurrency is an interrupt handler preempting the main loop, and
+//! the simplest correct primitive there is a critical section: interrupts
+//! are masked while the CRDT is touched.
+//!
+//! [`CriticalSectionCRDT`] wraps any CRDT in a
+//! `critical_section::Mutex<RefCell<_>>`, so it can live in a `static` and be
+//! updated from both the main loop and interrupt handlers. The target must
+//! provide a `critical-section` implementation, e.g. through its HAL or the
+//! `cortex-m` crate.
+//!
+//! # Choosing a feature
+//! - `critical-section` for single-core Cortex-M bare metal
+//! - `hardware-atomic` for multi-core targets such as RISC-V SMP or AURIX
+//!
+//! The two features are mutually exclusive.
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+
+use crate::counters::GCounter;
+use crate::error::CRDTResult;
+use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::sets::GSet;
+use crate::traits::{BoundedCRDT, CRDT};
+
+/// CRDT guarded by a critical section
+///
+/// Every access through `&self` runs inside `critical_section::with`.
+/// Methods taking `&mut self` already have exclusive access and skip the
+/// critical section.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::platform::interrupt::CriticalSectionGSet;
+///
+/// // Shared between the main loop and an interrupt handler
+/// static FAULTS: CriticalSectionGSet<u16, DefaultConfig> =
+///     CriticalSectionGSet::new(GSet::with_capacity());
+///
+/// // In the interrupt handler
+/// FAULTS.add(0x42)?;
+///
+/// // In the main loop
+/// assert!(FAULTS.contains(&0x42));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+pub struct CriticalSectionCRDT<X> {
+    /// The wrapped CRDT
+    inner: Mutex<RefCell<X>>,
+}
+
+/// [`GSet`] guarded by a critical section
+pub type CriticalSectionGSet<T, C, const CAPACITY: usize = 16> =
+    CriticalSectionCRDT<GSet<T, C, CAPACITY>>;
+
+/// [`LWWRegister`] guarded by a critical section
+pub type CriticalSectionLWWRegister<T, C> = CriticalSectionCRDT<LWWRegister<T, C>>;
+
+/// [`GCounter`] guarded by a critical section
+pub type CriticalSectionGCounter<C, const CAPACITY: usize = 16> =
+    CriticalSectionCRDT<GCounter<C, CAPACITY>>;
+
+impl<X> CriticalSectionCRDT<X> {
+    /// Wraps a CRDT
+    ///
+    /// # Arguments
+    /// * `crdt` - The CRDT to guard
+    pub const fn new(crdt: X) -> Self {
+        Self {
+            inner: Mutex::new(RefCell::new(crdt)),
+        }
+    }
+
+    /// Runs `f` on the CRDT inside a critical section
+    ///
+    /// # Arguments
+    /// * `f` - Called with exclusive access to the CRDT
+    ///
+    /// # Returns
+    /// The value returned by `f`
+    ///
+    /// # Panics
+    /// If `f` calls back into `with` on the same wrapper
+    pub fn with<R>(&self, f: impl FnOnce(&mut X) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.inner.borrow_ref_mut(cs)))
+    }
+
+    /// Gets the CRDT without a critical section
+    ///
+    /// `&mut self` already guarantees no interrupt handler holds a reference.
+    pub fn get_mut(&mut self) -> &mut X {
+        self.inner.get_mut().get_mut()
+    }
+
+    /// Unwraps the CRDT
+    pub fn into_inner(self) -> X {
+        self.inner.into_inner().into_inner()
+    }
+
+    /// Copies the current state out of the critical section
+    pub fn snapshot(&self) -> X
+    where
+        X: Clone,
+    {
+        self.with(|crdt| crdt.clone())
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize> CriticalSectionGSet<T, C, CAPACITY>
+where
+    T: Clone + PartialEq,
+{
+    /// Inserts an element
+    ///
+    /// # Returns
+    /// Ok(true) if the element was newly inserted, Ok(false) if it already
+    /// existed, or an error if the set is full
+    pub fn add(&self, element: T) -> CRDTResult<bool> {
+        self.with(|set| set.insert(element))
+    }
+
+    /// Checks if the set contains an element
+    pub fn contains(&self, element: &T) -> bool {
+        self.with(|set| set.contains(element))
+    }
+
+    /// Returns the number of elements in the set
+    pub fn len(&self) -> usize {
+        self.with(|set| set.len())
+    }
+
+    /// Returns true if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.with(|set| set.is_empty())
+    }
+}
+
+impl<T, C: MemoryConfig> CriticalSectionLWWRegister<T, C>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a guarded empty register
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    pub const fn with_node_id(node_id: NodeId) -> Self {
+        Self::new(LWWRegister::new(node_id))
+    }
+
+    /// Sets the register value with the given timestamp
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error otherwise
+    pub fn set(&self, value: T, timestamp: u64) -> CRDTResult<()> {
+        self.with(|register| register.set(value, timestamp))
+    }
+
+    /// Gets a copy of the current value
+    pub fn get(&self) -> Option<T> {
+        self.with(|register| register.get().cloned())
+    }
+}
+
+impl<C: MemoryConfig, const CAPACITY: usize> CriticalSectionGCounter<C, CAPACITY> {
+    /// Creates a guarded counter at zero
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    pub const fn with_node_id(node_id: NodeId) -> Self {
+        Self::new(GCounter::with_capacity(node_id))
+    }
+
+    /// Increments this node's count
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error if the counter overflows
+    pub fn increment(&self, amount: u32) -> CRDTResult<()> {
+        self.with(|counter| counter.increment(amount))
+    }
+
+    /// Gets the total count across all nodes
+    pub fn value(&self) -> u64 {
+        self.with(|counter| counter.value())
+    }
+}
+
+impl<C: MemoryConfig, X: CRDT<C>> CRDT<C> for CriticalSectionCRDT<X> {
+    type Error = X::Error;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        // Only `other` can be reached from an interrupt handler
+        let inner = self.inner.get_mut().get_mut();
+        critical_section::with(|cs| inner.merge(&other.inner.borrow_ref(cs)))
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        critical_section::with(|cs| self.inner.borrow_ref(cs).eq(&other.inner.borrow_ref(cs)))
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.with(|crdt| crdt.validate())
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.with(|crdt| crdt.state_hash())
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        critical_section::with(|cs| {
+            self.inner
+                .borrow_ref(cs)
+                .can_merge(&other.inner.borrow_ref(cs))
+        })
+    }
+}
+
+impl<C: MemoryConfig, X: BoundedCRDT<C>> BoundedCRDT<C> for CriticalSectionCRDT<X> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = X::MAX_ELEMENTS;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.with(|crdt| crdt.element_count())
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        self.get_mut().compact()
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.with(|crdt| crdt.can_add_element())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_gset_from_interrupt_context() {
+        static FAULTS: CriticalSectionGSet<u16, DefaultConfig, 4> =
+            CriticalSectionGSet::new(GSet::with_capacity());
+
+        // Simulated interrupt handler
+        let on_fault = |code: u16| FAULTS.add(code);
+        assert!(on_fault(0x10).unwrap());
+        assert!(!on_fault(0x10).unwrap());
+        on_fault(0x20).unwrap();
+
+        assert!(FAULTS.contains(&0x10));
+        assert!(!FAULTS.contains(&0x30));
+        assert_eq!(FAULTS.len(), 2);
+        assert_eq!(FAULTS.element_count(), 2);
+        assert!(FAULTS.can_add_element());
+        assert!(FAULTS.validate().is_ok());
+
+        let mut local = CriticalSectionGSet::<u16, DefaultConfig, 4>::new(GSet::with_capacity());
+        local.add(0x30).unwrap();
+        local.merge(&FAULTS).unwrap();
+        assert_eq!(local.len(), 3);
+        assert!(!local.eq(&FAULTS));
+        assert_eq!(local.snapshot().len(), 3);
+    }
+
+    #[test]
+    fn test_register_and_counter() {
+        let mut register = CriticalSectionLWWRegister::<i16, DefaultConfig>::with_node_id(1);
+        register.set(20, 1000).unwrap();
+        register.set(25, 900).unwrap();
+        assert_eq!(register.get(), Some(20));
+
+        let other = CriticalSectionLWWRegister::<i16, DefaultConfig>::with_node_id(2);
+        other.set(30, 2000).unwrap();
+        register.merge(&other).unwrap();
+        assert_eq!(register.get(), Some(30));
+        assert!(register.eq(&other));
+        assert_eq!(register.state_hash(), other.state_hash());
+
+        let mut counter = CriticalSectionGCounter::<DefaultConfig>::with_node_id(1);
+        let ticks = CriticalSectionGCounter::<DefaultConfig>::with_node_id(2);
+        counter.increment(3).unwrap();
+        ticks.increment(4).unwrap();
+        counter.merge(&ticks).unwrap();
+        assert_eq!(counter.value(), 7);
+        assert!(counter.can_merge(&ticks));
+        assert_eq!(counter.into_inner().node_value(2), 4);
+    }
+}
@@ -3,6 +3,40 @@
 //! This module provides platform-specific constants and optimizations
 //! for different embedded platforms without requiring HAL dependencies.
 
+// Platform features are mutually exclusive
+#[cfg(any(
+    all(feature = "aurix", feature = "stm32"),
+    all(feature = "aurix", feature = "cortex-m"),
+    all(feature = "aurix", feature = "riscv"),
+    all(feature = "aurix", feature = "esp32"),
+    all(feature = "stm32", feature = "cortex-m"),
+    all(feature = "stm32", feature = "riscv"),
+    all(feature = "stm32", feature = "esp32"),
+    all(feature = "cortex-m", feature = "riscv"),
+    all(feature = "cortex-m", feature = "esp32"),
+    all(feature = "riscv", feature = "esp32"),
+    all(feature = "nrf52", feature = "aurix"),
+    all(feature = "nrf52", feature = "stm32"),
+    all(feature = "nrf52", feature = "cortex-m"),
+    all(feature = "nrf52", feature = "riscv"),
+    all(feature = "nrf52", feature = "esp32"),
+))]
+compile_error!(
+    "platform features `aurix`, `stm32`, `cortex-m`, `riscv`, `esp32` and `nrf52` are mutually exclusive"
+);
+
+// Atomics and critical sections are alternative ways to share CRDTs
+#[cfg(all(feature = "hardware-atomic", feature = "critical-section"))]
+compile_error!("features `hardware-atomic` and `critical-section` are mutually exclusive");
+
+#[cfg(feature = "critical-section")]
+#[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+pub mod interrupt;
+
+#[cfg(feature = "cycle-counter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cycle-counter")))]
+pub mod cycles;
+
 /// Platform-specific constants for AURIX TriCore
 #[cfg(feature = "aurix")]
 pub mod constants {
@@ -103,12 +137,64 @@ pub mod constants {
     pub const PLATFORM_NAME: &str = "RISC-V";
 }
 
+/// Platform-specific constants for ESP32
+#[cfg(feature = "esp32")]
+pub mod constants {
+    /// Maximum merge cycles for ESP32 platform
+    pub const MAX_MERGE_CYCLES: u32 = 250;
+
+    /// Maximum interrupt latency in CPU cycles
+    pub const MAX_INTERRUPT_LATENCY: u32 = 60;
+
+    /// Cache line size in bytes
+    pub const CACHE_LINE_SIZE: usize = 32;
+
+    /// Supports multi-core operations (Xtensa LX6/LX7 dual core)
+    pub const SUPPORTS_MULTICORE: bool = true;
+
+    /// Maximum number of cores
+    pub const MAX_CORES: u8 = 2;
+
+    /// Memory alignment requirement
+    pub const MEMORY_ALIGNMENT: usize = 4;
+
+    /// Platform name
+    pub const PLATFORM_NAME: &str = "ESP32";
+}
+
+/// Platform-specific constants for nRF52
+#[cfg(feature = "nrf52")]
+pub mod constants {
+    /// Maximum merge cycles for nRF52 platform
+    pub const MAX_MERGE_CYCLES: u32 = 150;
+
+    /// Maximum interrupt latency in CPU cycles
+    pub const MAX_INTERRUPT_LATENCY: u32 = 20;
+
+    /// Cache line size in bytes
+    pub const CACHE_LINE_SIZE: usize = 32;
+
+    /// Supports multi-core operations
+    pub const SUPPORTS_MULTICORE: bool = false;
+
+    /// Maximum number of cores
+    pub const MAX_CORES: u8 = 1;
+
+    /// Memory alignment requirement
+    pub const MEMORY_ALIGNMENT: usize = 4;
+
+    /// Platform name
+    pub const PLATFORM_NAME: &str = "nRF52";
+}
+
 /// Default platform constants (when no specific platform is selected)
 #[cfg(not(any(
     feature = "aurix",
     feature = "stm32",
     feature = "cortex-m",
-    feature = "riscv"
+    feature = "riscv",
+    feature = "esp32",
+    feature = "nrf52"
 )))]
 pub mod constants {
     /// Maximum merge cycles for default platform
@@ -151,12 +237,22 @@ pub mod validation {
     #[cfg(feature = "riscv")]
     pub const MAX_ACTIVE_NODES: usize = 16; // RISC-V flexible limit
 
+    /// Maximum active nodes for platform-specific validation
+    #[cfg(feature = "esp32")]
+    pub const MAX_ACTIVE_NODES: usize = 16; // ESP32 mesh network limit
+
+    /// Maximum active nodes for platform-specific validation
+    #[cfg(feature = "nrf52")]
+    pub const MAX_ACTIVE_NODES: usize = 8; // nRF52 BLE connection limit
+
     /// Maximum active nodes for platform-specific validation (default)
     #[cfg(not(any(
         feature = "aurix",
         feature = "stm32",
         feature = "cortex-m",
-        feature = "riscv"
+        feature = "riscv",
+        feature = "esp32",
+        feature = "nrf52"
     )))]
     pub const MAX_ACTIVE_NODES: usize = 8; // Default conservative limit
 
@@ -176,12 +272,22 @@ pub mod validation {
     #[cfg(feature = "riscv")]
     pub const MAX_MEMORY_USAGE: usize = 4096; // RISC-V variable memory
 
+    /// Maximum memory usage for platform-specific validation
+    #[cfg(feature = "esp32")]
+    pub const MAX_MEMORY_USAGE: usize = 4096; // ESP32 SRAM shared with Wi-Fi stack
+
+    /// Maximum memory usage for platform-specific validation
+    #[cfg(feature = "nrf52")]
+    pub const MAX_MEMORY_USAGE: usize = 1536; // nRF52 SRAM shared with the SoftDevice
+
     /// Maximum memory usage for platform-specific validation
     #[cfg(not(any(
         feature = "aurix",
         feature = "stm32",
         feature = "cortex-m",
-        feature = "riscv"
+        feature = "riscv",
+        feature = "esp32",
+        feature = "nrf52"
     )))]
     pub const MAX_MEMORY_USAGE: usize = 2048; // Default moderate limit
 }
@@ -208,7 +314,7 @@ pub mod error_handling {
     #[cfg(feature = "aurix")]
     impl From<CRDTError> for AurixSafetyAction {
         fn from(err: CRDTError) -> Self {
-            match err {
+            match err.cause() {
                 CRDTError::BufferOverflow => AurixSafetyAction::SafeState,
                 CRDTError::InvalidState => AurixSafetyAction::SystemReset,
                 CRDTError::InvalidNodeId => AurixSafetyAction::IsolateNode,
@@ -234,7 +340,7 @@ pub mod error_handling {
     #[cfg(feature = "stm32")]
     impl From<CRDTError> for STM32PowerAction {
         fn from(err: CRDTError) -> Self {
-            match err {
+            match err.cause() {
                 CRDTError::BufferOverflow => STM32PowerAction::ReduceFrequency,
                 CRDTError::InvalidState => STM32PowerAction::EnterStopMode,
                 CRDTError::ConfigurationExceeded => STM32PowerAction::EnterStandbyMode,
@@ -260,7 +366,7 @@ pub mod error_handling {
     #[cfg(feature = "cortex-m")]
     impl From<CRDTError> for CortexMMemoryAction {
         fn from(err: CRDTError) -> Self {
-            match err {
+            match err.cause() {
                 CRDTError::BufferOverflow => CortexMMemoryAction::CompactMemory,
                 CRDTError::ConfigurationExceeded => CortexMMemoryAction::ReduceCapacity,
                 CRDTError::InvalidState => CortexMMemoryAction::ResetMinimal,
@@ -286,7 +392,7 @@ pub mod error_handling {
     #[cfg(feature = "riscv")]
     impl From<CRDTError> for RiscVPerformanceAction {
         fn from(err: CRDTError) -> Self {
-            match err {
+            match err.cause() {
                 CRDTError::BufferOverflow => RiscVPerformanceAction::OptimizePerformance,
                 CRDTError::ConfigurationExceeded => RiscVPerformanceAction::DistributeLoad,
                 CRDTError::InvalidState => RiscVPerformanceAction::ScaleDown,
@@ -294,6 +400,54 @@ pub mod error_handling {
             }
         }
     }
+
+    /// ESP32 power management actions for error handling
+    #[cfg(feature = "esp32")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ESP32PowerAction {
+        /// Continue normal operation
+        Continue,
+        /// Enter light sleep (RAM retained, radio paused)
+        EnterLightSleep,
+        /// Enter deep sleep (RTC memory only, wakes through reset)
+        EnterDeepSleep,
+    }
+
+    #[cfg(feature = "esp32")]
+    impl From<CRDTError> for ESP32PowerAction {
+        fn from(err: CRDTError) -> Self {
+            match err.cause() {
+                CRDTError::BufferOverflow => ESP32PowerAction::EnterLightSleep,
+                CRDTError::ConfigurationExceeded => ESP32PowerAction::EnterLightSleep,
+                CRDTError::InvalidState => ESP32PowerAction::EnterDeepSleep,
+                _ => ESP32PowerAction::Continue,
+            }
+        }
+    }
+
+    /// nRF52 power management actions for error handling
+    #[cfg(feature = "nrf52")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Nrf52PowerAction {
+        /// Continue normal operation
+        Continue,
+        /// Ask the BLE stack to turn the radio off until pressure drops
+        RequestRadioOff,
+        /// Enter System OFF (RAM lost unless retained, wakes through reset)
+        EnterSystemOff,
+    }
+
+    #[cfg(feature = "nrf52")]
+    impl From<CRDTError> for Nrf52PowerAction {
+        fn from(err: CRDTError) -> Self {
+            match err.cause() {
+                CRDTError::BufferOverflow => Nrf52PowerAction::RequestRadioOff,
+                CRDTError::ConfigurationExceeded => Nrf52PowerAction::RequestRadioOff,
+                CRDTError::InvalidState => Nrf52PowerAction::EnterSystemOff,
+                _ => Nrf52PowerAction::Continue,
+            }
+        }
+    }
 }
 
 /// Platform-specific multi-core support
@@ -304,7 +458,7 @@ pub mod multicore {
     use crate::error::CRDTResult;
 
     /// Multi-core coordination trait for platforms that support it
-    #[cfg(any(feature = "aurix", feature = "riscv"))]
+    #[cfg(any(feature = "aurix", feature = "riscv", feature = "esp32"))]
     pub trait MultiCoreCRDT {
         /// Number of cores available
         fn core_count() -> u8 {
@@ -392,6 +546,29 @@ mod tests {
             assert!(constants::SUPPORTS_MULTICORE);
             assert_eq!(constants::PLATFORM_NAME, "RISC-V");
         }
+
+        #[cfg(feature = "esp32")]
+        {
+            assert_eq!(constants::MAX_MERGE_CYCLES, 250);
+            assert_eq!(constants::MAX_CORES, 2);
+            assert_eq!(constants::MEMORY_ALIGNMENT, 4);
+            assert!(constants::SUPPORTS_MULTICORE);
+            assert_eq!(constants::PLATFORM_NAME, "ESP32");
+            assert_eq!(validation::MAX_ACTIVE_NODES, 16);
+            assert_eq!(validation::MAX_MEMORY_USAGE, 4096);
+        }
+
+        #[cfg(feature = "nrf52")]
+        {
+            assert_eq!(constants::MAX_MERGE_CYCLES, 150);
+            assert_eq!(constants::MAX_INTERRUPT_LATENCY, 20);
+            assert_eq!(constants::MAX_CORES, 1);
+            assert_eq!(constants::MEMORY_ALIGNMENT, 4);
+            assert!(!constants::SUPPORTS_MULTICORE);
+            assert_eq!(constants::PLATFORM_NAME, "nRF52");
+            assert_eq!(validation::MAX_ACTIVE_NODES, 8);
+            assert_eq!(validation::MAX_MEMORY_USAGE, 1536);
+        }
     }
 
     #[cfg(feature = "aurix")]
@@ -422,4 +599,36 @@ mod tests {
         let action: STM32PowerAction = CRDTError::InvalidState.into();
         assert_eq!(action, STM32PowerAction::EnterStopMode);
     }
+
+    #[cfg(feature = "esp32")]
+    #[test]
+    fn test_esp32_error_handling() {
+        use crate::error::CRDTError;
+        use error_handling::*;
+
+        let action: ESP32PowerAction = CRDTError::BufferOverflow.into();
+        assert_eq!(action, ESP32PowerAction::EnterLightSleep);
+
+        let action: ESP32PowerAction = CRDTError::InvalidState.into();
+        assert_eq!(action, ESP32PowerAction::EnterDeepSleep);
+
+        let action: ESP32PowerAction = CRDTError::InvalidNodeId.into();
+        assert_eq!(action, ESP32PowerAction::Continue);
+    }
+
+    #[cfg(feature = "nrf52")]
+    #[test]
+    fn test_nrf52_error_handling() {
+        use crate::error::CRDTError;
+        use error_handling::*;
+
+        let action: Nrf52PowerAction = CRDTError::BufferOverflow.into();
+        assert_eq!(action, Nrf52PowerAction::RequestRadioOff);
+
+        let action: Nrf52PowerAction = CRDTError::InvalidState.into();
+        assert_eq!(action, Nrf52PowerAction::EnterSystemOff);
+
+        let action: Nrf52PowerAction = CRDTError::InvalidNodeId.into();
+        assert_eq!(action, Nrf52PowerAction::Continue);
+    }
 }
@@ -0,0 +1,223 @@
+//! Lamport-timestamped Last-Writer-Wins Register CRDT
+//!
+//! A Last-Writer-Wins register that timestamps its own writes with a Lamport
+//! clock, so callers never pass timestamps and nodes need no synchronised
+//! time source. See [`crate::clock::lamport`] for when Lamport timestamps are
+//! preferable to wall-clock timestamps.
+
+use crate::clock::{CompactTimestamp, LamportClock, StateHash};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::traits::{CRDT, Snapshotable};
+
+/// Last-Writer-Wins Register with Lamport timestamps
+///
+/// Every [`set`](Self::set) ticks the register's internal [`LamportClock`],
+/// and every merge advances the clock past the other replica's, so a local
+/// write always wins against every write this replica has seen. Concurrent
+/// writes with equal Lamport values are resolved by node ID, as in
+/// [`LWWRegister`].
+///
+/// # Type Parameters
+/// - `T`: The value type stored in the register
+/// - `C`: Memory configuration that determines limits
+///
+/// # Memory Usage
+/// - Fixed size: an [`LWWRegister`] plus 8 bytes for the clock
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut mode = LamportRegister::<u8, DefaultConfig>::new(1);
+/// let mut other = LamportRegister::<u8, DefaultConfig>::new(2);
+///
+/// other.set(3)?;
+/// mode.merge(&other)?;
+///
+/// // Written after observing node 2's write, so it wins everywhere
+/// mode.set(5)?;
+/// other.merge(&mode)?;
+/// assert_eq!(other.get(), Some(&5));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct LamportRegister<T, C: MemoryConfig> {
+    /// Underlying register keyed by Lamport values
+    register: LWWRegister<T, C>,
+
+    /// Logical clock of this replica
+    clock: LamportClock,
+}
+
+impl<T, C: MemoryConfig> LamportRegister<T, C>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new Lamport register for the given node
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    ///
+    /// # Returns
+    /// A new empty register
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            register: LWWRegister::new(node_id),
+            clock: LamportClock::new(),
+        }
+    }
+
+    /// Sets a new value, timestamped by the internal clock
+    ///
+    /// # Arguments
+    /// * `value` - The new value to set
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or an error if the register rejects the write
+    pub fn set(&mut self, value: T) -> CRDTResult<()> {
+        let timestamp = self.clock.tick();
+        self.register.set(value, timestamp)
+    }
+
+    /// Gets the current value
+    ///
+    /// # Returns
+    /// The current value, or None if never set
+    pub fn get(&self) -> Option<&T> {
+        self.register.get()
+    }
+
+    /// Gets the Lamport timestamp of the current value
+    pub fn timestamp(&self) -> CompactTimestamp {
+        self.register.timestamp()
+    }
+
+    /// Gets the node ID that wrote the current value
+    pub fn current_node(&self) -> NodeId {
+        self.register.current_node()
+    }
+
+    /// Checks if the register is empty (never set)
+    pub fn is_empty(&self) -> bool {
+        self.register.is_empty()
+    }
+
+    /// Returns the internal Lamport clock
+    pub fn clock(&self) -> LamportClock {
+        self.clock
+    }
+
+    /// Returns the underlying register
+    pub fn as_lww(&self) -> &LWWRegister<T, C> {
+        &self.register
+    }
+}
+
+impl<T, C: MemoryConfig> CRDT<C> for LamportRegister<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.register.merge(&other.register)?;
+        self.clock.update_from(other.clock.current());
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        // Clocks are local bookkeeping, not replicated state
+        self.register.eq(&other.register)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.register.validate()?;
+        if self.register.timestamp().as_u64() > self.clock.current() {
+            return Err(CRDTError::InvalidState);
+        }
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.register.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.register.can_merge(&other.register)
+    }
+}
+
+impl<T, C: MemoryConfig> Snapshotable<C> for LamportRegister<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    /// The stamp of the current write
+    type Snapshot = (CompactTimestamp, NodeId);
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.register.snapshot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_set_ticks_clock() {
+        let mut register = LamportRegister::<u32, DefaultConfig>::new(1);
+        assert!(register.is_empty());
+
+        register.set(10).unwrap();
+        register.set(20).unwrap();
+        assert_eq!(register.get(), Some(&20));
+        assert_eq!(register.timestamp().as_u64(), 2);
+        assert_eq!(register.clock().current(), 2);
+        assert_eq!(register.current_node(), 1);
+        assert!(register.validate().is_ok());
+    }
+
+    #[test]
+    fn test_write_after_merge_wins() {
+        let mut slow = LamportRegister::<u32, DefaultConfig>::new(1);
+        let mut busy = LamportRegister::<u32, DefaultConfig>::new(2);
+        for value in 0..5 {
+            busy.set(value).unwrap();
+        }
+
+        // One local write would lose against five remote ones by timestamp,
+        // but after merging the clock has caught up
+        slow.merge(&busy).unwrap();
+        assert_eq!(slow.get(), Some(&4));
+        slow.set(100).unwrap();
+        assert!(slow.timestamp().as_u64() > busy.timestamp().as_u64());
+
+        busy.merge(&slow).unwrap();
+        assert_eq!(busy.get(), Some(&100));
+        assert!(busy.eq(&slow));
+    }
+
+    #[test]
+    fn test_concurrent_writes_tie_break_on_node() {
+        let mut a = LamportRegister::<u32, DefaultConfig>::new(1);
+        let mut b = LamportRegister::<u32, DefaultConfig>::new(2);
+        a.set(1).unwrap();
+        b.set(2).unwrap();
+
+        let mut merged_a = a.clone();
+        merged_a.merge(&b).unwrap();
+        let mut merged_b = b.clone();
+        merged_b.merge(&a).unwrap();
+
+        assert_eq!(merged_a.get(), Some(&2));
+        assert!(merged_a.eq(&merged_b));
+        assert_eq!(merged_a.snapshot(), merged_b.snapshot());
+    }
+}
@@ -7,10 +7,12 @@
 //! - Standard: Requires `&mut self` for modifications, single-threaded
 //! - Atomic: Allows `&self` for modifications, multi-threaded safe
 
-use crate::clock::CompactTimestamp;
+use crate::clock::{
+    CompactTimestamp, FnvHasher, HLCTimestamp, HybridLogicalClock, IntoTimestamp, StateHash,
+};
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::{MemoryConfig, NodeId};
-use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+use crate::traits::{BoundedCRDT, CRDT, DeltaCRDT, MemoryBreakdown, RealTimeCRDT, Snapshotable};
 
 #[cfg(feature = "hardware-atomic")]
 use core::cell::UnsafeCell;
@@ -20,6 +22,9 @@ use core::sync::atomic::{AtomicU8, AtomicU32, Ordering};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "postcard")]
+use postcard::experimental::max_size::MaxSize;
+
 /// Last-Writer-Wins Register
 ///
 /// This register resolves conflicts by keeping the value with the latest timestamp.
@@ -43,6 +48,11 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// | `timestamp()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
 /// | `current_node()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
 /// | `is_empty()` | ✅ | ✅ | `&self` | Single / Multi | Read-only |
+/// | `is_expired()` | ✅ | ✅ | `&self` | Single / Multi | TTL check |
+/// | `get_if_fresh()` | ✅ | ✅ | `&self` | Single / Multi | TTL-aware read |
+/// | `expire_before()` | ✅ | ✅ | `&mut self` | Single | Local expiry |
+/// | `compare_and_set()` | ✅ | ✅ | `&mut self` | Single | Lost-update detection |
+/// | `compare_and_set_timestamp()` | ✅ | ✅ | `&mut self` | Single | CAS on timestamp |
 ///
 /// **Feature Requirements:**
 /// - **Standard Version**: No additional features required (default)
@@ -89,6 +99,9 @@ pub struct LWWRegister<T, C: MemoryConfig> {
     /// This node's ID
     node_id: NodeId,
 
+    /// Local time-to-live of the value in milliseconds, if any
+    ttl_ms: Option<u64>,
+
     /// Phantom data to maintain the memory config type
     _phantom: core::marker::PhantomData<C>,
 }
@@ -118,6 +131,7 @@ where
                 current_timestamp: self.current_timestamp,
                 current_node_id: self.current_node_id,
                 node_id: self.node_id,
+                ttl_ms: self.ttl_ms,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -131,6 +145,7 @@ where
                 current_timestamp: AtomicU32::new(self.current_timestamp.load(Ordering::Relaxed)),
                 current_node_id: AtomicU8::new(self.current_node_id.load(Ordering::Relaxed)),
                 node_id: self.node_id,
+                ttl_ms: self.ttl_ms,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -154,7 +169,7 @@ where
     /// use crdtosphere::prelude::*;
     /// let register = LWWRegister::<i32, DefaultConfig>::new(1);
     /// ```
-    pub fn new(node_id: NodeId) -> Self {
+    pub const fn new(node_id: NodeId) -> Self {
         #[cfg(not(feature = "hardware-atomic"))]
         {
             Self {
@@ -162,6 +177,7 @@ where
                 current_timestamp: CompactTimestamp::zero(),
                 current_node_id: 0,
                 node_id,
+                ttl_ms: None,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -173,11 +189,41 @@ where
                 current_timestamp: AtomicU32::new(0),
                 current_node_id: AtomicU8::new(0),
                 node_id,
+                ttl_ms: None,
                 _phantom: core::marker::PhantomData,
             }
         }
     }
 
+    /// Creates a new LWW register whose value goes stale after `ttl_ms`
+    ///
+    /// The TTL is local to this replica: merges neither copy it nor consult it,
+    /// and it only affects [`is_expired`](Self::is_expired) and
+    /// [`get_if_fresh`](Self::get_if_fresh).
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    /// * `ttl_ms` - How long a value stays fresh after its timestamp
+    ///
+    /// # Returns
+    /// A new empty register
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = LWWRegister::<i32, DefaultConfig>::new_with_ttl(1, 500);
+    /// register.set(42, 1000)?;
+    ///
+    /// assert_eq!(register.get_if_fresh(1400), Some(&42));
+    /// assert_eq!(register.get_if_fresh(1600), None); // Stale, fall back to a default
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn new_with_ttl(node_id: NodeId, ttl_ms: u64) -> Self {
+        let mut register = Self::new(node_id);
+        register.ttl_ms = Some(ttl_ms);
+        register
+    }
+
     /// Sets a new value with the current timestamp
     ///
     /// # Concurrency Behavior
@@ -186,7 +232,8 @@ where
     ///
     /// # Arguments
     /// * `value` - The new value to set
-    /// * `timestamp` - The timestamp for this update
+    /// * `timestamp` - The timestamp for this update, in milliseconds or as an
+    ///   [`HLCTimestamp`](crate::clock::HLCTimestamp)
     ///
     /// # Returns
     /// Ok(()) if successful, or an error if the timestamp is older than current
@@ -216,8 +263,8 @@ where
     /// # Ok::<(), crdtosphere::error::CRDTError>(())
     /// ```
     #[cfg(not(feature = "hardware-atomic"))]
-    pub fn set(&mut self, value: T, timestamp: u64) -> CRDTResult<()> {
-        let new_timestamp = CompactTimestamp::new(timestamp);
+    pub fn set(&mut self, value: T, timestamp: impl IntoTimestamp) -> CRDTResult<()> {
+        let new_timestamp = CompactTimestamp::new(timestamp.into_timestamp());
 
         // Only update if this timestamp is newer (or same timestamp but higher node ID)
         if self.should_update(&new_timestamp, self.node_id) {
@@ -243,8 +290,8 @@ where
     /// successfully updating the timestamp atomically. This is safe because
     /// we ensure only one thread can update at a time through the atomic timestamp.
     #[cfg(feature = "hardware-atomic")]
-    pub fn set(&self, value: T, timestamp: u64) -> CRDTResult<()> {
-        let new_timestamp_u32 = timestamp as u32; // Truncate to u32 for ARM compatibility
+    pub fn set(&self, value: T, timestamp: impl IntoTimestamp) -> CRDTResult<()> {
+        let new_timestamp_u32 = timestamp.into_timestamp() as u32; // Truncate to u32 for ARM compatibility
 
         // Atomic compare-exchange loop to update timestamp and node_id together
         loop {
@@ -369,6 +416,450 @@ where
         }
     }
 
+    /// Finds the register holding the latest write, without modifying any
+    ///
+    /// The latest timestamp wins, with the higher node ID breaking ties like
+    /// in [`merge`](CRDT::merge). Exact ties keep the earliest candidate, and
+    /// empty registers never win.
+    ///
+    /// # Arguments
+    /// * `candidates` - The registers to compare
+    ///
+    /// # Returns
+    /// The winning register, or None if every candidate is empty (or there are none)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut ecu1 = LWWRegister::<i32, DefaultConfig>::new(1);
+    /// let mut ecu2 = LWWRegister::<i32, DefaultConfig>::new(2);
+    /// ecu1.set(85, 1000)?;
+    /// ecu2.set(90, 1000)?;
+    ///
+    /// let winner = LWWRegister::select_winner([&ecu1, &ecu2]);
+    /// assert_eq!(winner.and_then(|r| r.get()), Some(&90)); // Higher node ID
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn select_winner<'a, I>(candidates: I) -> Option<&'a Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        let mut winner: Option<&Self> = None;
+        for candidate in candidates {
+            if candidate.is_empty() {
+                continue;
+            }
+            let newer = winner.is_none_or(|best| {
+                (candidate.timestamp(), candidate.current_node())
+                    > (best.timestamp(), best.current_node())
+            });
+            if newer {
+                winner = Some(candidate);
+            }
+        }
+        winner
+    }
+
+    /// Merges many registers by applying only the latest write among them
+    ///
+    /// Scans all inputs with [`select_winner`](Self::select_winner) first, so
+    /// the register is modified at most once. The result is the same as
+    /// merging each input in turn.
+    ///
+    /// # Arguments
+    /// * `others` - The registers to merge; an empty input is a no-op
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut readings = [
+    ///     LWWRegister::<i32, DefaultConfig>::new(1),
+    ///     LWWRegister::<i32, DefaultConfig>::new(2),
+    ///     LWWRegister::<i32, DefaultConfig>::new(3),
+    /// ];
+    /// readings[0].set(70, 1000)?;
+    /// readings[1].set(72, 3000)?;
+    /// readings[2].set(71, 2000)?;
+    ///
+    /// let mut gateway = LWWRegister::<i32, DefaultConfig>::new(0);
+    /// gateway.merge_many(readings.iter())?;
+    /// assert_eq!(gateway.get(), Some(&72));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn merge_many<'a, I>(&mut self, others: I) -> CRDTResult<()>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        T: core::fmt::Debug + StateHash + 'a,
+        C: 'a,
+    {
+        match Self::select_winner(others) {
+            Some(winner) => self.merge(winner),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets a new value stamped by a hybrid logical clock
+    ///
+    /// Ticks `hlc` with a send event and writes the value with the new
+    /// timestamp, so callers do not have to thread HLC readings by hand.
+    ///
+    /// # Arguments
+    /// * `value` - The new value to set
+    /// * `hlc` - This node's hybrid logical clock
+    /// * `physical_now_ms` - The current reading of the local physical clock
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error otherwise
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut hlc = HybridLogicalClock::new(500);
+    /// let mut register = LWWRegister::<u32, DefaultConfig>::new(1);
+    ///
+    /// // Two writes in the same millisecond still order correctly
+    /// register.set_with_hlc(1, &mut hlc, 1000)?;
+    /// register.set_with_hlc(2, &mut hlc, 1000)?;
+    /// assert_eq!(register.get(), Some(&2));
+    /// assert_eq!(register.timestamp().as_u64(), hlc.now().as_u64());
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn set_with_hlc(
+        &mut self,
+        value: T,
+        hlc: &mut HybridLogicalClock,
+        physical_now_ms: u64,
+    ) -> CRDTResult<()> {
+        self.set(value, hlc.send(physical_now_ms))
+    }
+
+    /// Merges a remote register after passing its timestamp through a
+    /// hybrid logical clock
+    ///
+    /// The remote timestamp is fed to `hlc` as a receive event before the
+    /// merge, even if the remote value turns out to be older, so the clock
+    /// observes every timestamp it is shown. An empty remote register
+    /// carries no timestamp and leaves the clock untouched.
+    ///
+    /// # Arguments
+    /// * `other` - The remote register
+    /// * `hlc` - This node's hybrid logical clock
+    /// * `physical_now_ms` - The current reading of the local physical clock
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::ClockSkew` if the remote
+    /// timestamp runs too far ahead, in which case neither the clock nor
+    /// the register is changed
+    pub fn merge_with_hlc(
+        &mut self,
+        other: &Self,
+        hlc: &mut HybridLogicalClock,
+        physical_now_ms: u64,
+    ) -> CRDTResult<()>
+    where
+        T: core::fmt::Debug + StateHash,
+    {
+        if !other.is_empty() {
+            let remote = HLCTimestamp::from_u64(other.timestamp().as_u64());
+            hlc.receive(remote, physical_now_ms)?;
+        }
+        self.merge(other)
+    }
+
+    /// Merges another register and returns the resulting value
+    ///
+    /// Saves a separate [`get`](Self::get) after a [`merge`](CRDT::merge)
+    /// in hot loops.
+    ///
+    /// # Arguments
+    /// * `other` - The register to merge
+    ///
+    /// # Returns
+    /// The value after the merge, or None if both registers are empty
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut local = LWWRegister::<i32, DefaultConfig>::new(1);
+    /// let mut remote = LWWRegister::<i32, DefaultConfig>::new(2);
+    /// local.set(85, 1000)?;
+    /// remote.set(90, 2000)?;
+    ///
+    /// assert_eq!(local.merge_and_get(&remote)?, Some(&90));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn merge_and_get(&mut self, other: &Self) -> CRDTResult<Option<&T>>
+    where
+        T: core::fmt::Debug + StateHash,
+    {
+        self.merge(other)?;
+        Ok(self.get())
+    }
+
+    /// Merges another register and returns the resulting value with its
+    /// timestamp and author
+    ///
+    /// # Arguments
+    /// * `other` - The register to merge
+    ///
+    /// # Returns
+    /// The winning (value, timestamp, node ID), or None if both registers
+    /// are empty
+    pub fn merge_and_get_with_metadata(
+        &mut self,
+        other: &Self,
+    ) -> CRDTResult<Option<(&T, u64, NodeId)>>
+    where
+        T: core::fmt::Debug + StateHash,
+    {
+        self.merge(other)?;
+        let (timestamp, node_id) = (self.timestamp().as_u64(), self.current_node());
+        Ok(self.get().map(|value| (value, timestamp, node_id)))
+    }
+
+    /// Merges this register into `target`
+    ///
+    /// # Arguments
+    /// * `target` - The register to update
+    ///
+    /// # Returns
+    /// Ok(true) if `target` took this register's value, Ok(false) if it
+    /// already held the same or a newer write
+    pub fn merge_into(&self, target: &mut Self) -> CRDTResult<bool>
+    where
+        T: core::fmt::Debug + StateHash,
+    {
+        let before = (target.is_empty(), target.timestamp(), target.current_node());
+        target.merge(self)?;
+        Ok(before != (target.is_empty(), target.timestamp(), target.current_node()))
+    }
+
+    /// Gets the time-to-live of the value
+    ///
+    /// # Returns
+    /// The TTL in milliseconds, or None if values never expire
+    pub fn ttl(&self) -> Option<u64> {
+        self.ttl_ms
+    }
+
+    /// Checks if the current value has outlived its TTL
+    ///
+    /// # Arguments
+    /// * `current_time_ms` - The current time, in the same units as the timestamps
+    ///
+    /// # Returns
+    /// true if a value is set and `current_time_ms - timestamp > ttl_ms`,
+    /// false if the value is fresh, the register is empty or has no TTL
+    pub fn is_expired(&self, current_time_ms: u64) -> bool {
+        match self.ttl_ms {
+            Some(ttl_ms) if !self.is_empty() => {
+                current_time_ms.saturating_sub(self.timestamp().as_u64()) > ttl_ms
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the current value unless it has expired
+    ///
+    /// # Arguments
+    /// * `current_time_ms` - The current time, in the same units as the timestamps
+    ///
+    /// # Returns
+    /// The current value, or None if no value is set or it has expired
+    pub fn get_if_fresh(&self, current_time_ms: u64) -> Option<&T> {
+        if self.is_expired(current_time_ms) {
+            None
+        } else {
+            self.get()
+        }
+    }
+
+    /// Clears the value if it was written before `cutoff_timestamp`
+    ///
+    /// The register returns to its empty state. This is a local operation:
+    /// merging with a replica that still holds the value restores it.
+    ///
+    /// # Arguments
+    /// * `cutoff_timestamp` - Values with an older timestamp are cleared
+    ///
+    /// # Returns
+    /// true if a value was cleared, false otherwise
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = LWWRegister::<i32, DefaultConfig>::new(1);
+    /// register.set(42, 1000)?;
+    ///
+    /// assert!(!register.expire_before(1000));
+    /// assert!(register.expire_before(1001));
+    /// assert!(register.is_empty());
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn expire_before(&mut self, cutoff_timestamp: u64) -> bool {
+        if self.is_empty() || self.timestamp().as_u64() >= cutoff_timestamp {
+            return false;
+        }
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.current_value = None;
+            self.current_timestamp = CompactTimestamp::zero();
+            self.current_node_id = 0;
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            *self.current_value.get_mut() = None;
+            *self.current_timestamp.get_mut() = 0;
+            *self.current_node_id.get_mut() = 0;
+        }
+
+        true
+    }
+
+    /// Sets a new value only if the register still holds `expected`
+    ///
+    /// Use this to detect lost updates when several writers share a register:
+    /// read the value, compute the new one, and retry if this returns `Ok(false)`.
+    ///
+    /// "Atomic" here means free of data races on this replica only. It is not
+    /// distributed consensus: another node can still write concurrently, and
+    /// the usual last-writer-wins rules decide between the two on merge.
+    ///
+    /// # Arguments
+    /// * `expected` - The value the caller last observed
+    /// * `new_value` - The new value to set
+    /// * `timestamp` - The timestamp for this update
+    ///
+    /// # Returns
+    /// Ok(true) if the current value matched and [`set`](Self::set) was applied,
+    /// Ok(false) if the value differs (or the register is empty) and nothing changed
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = LWWRegister::<i32, DefaultConfig>::new(1);
+    /// register.set(10, 1000)?;
+    ///
+    /// assert!(register.compare_and_set(&10, 11, 1001)?);
+    /// assert!(!register.compare_and_set(&10, 12, 1002)?); // Lost update detected
+    /// assert_eq!(register.get(), Some(&11));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn compare_and_set(
+        &mut self,
+        expected: &T,
+        new_value: T,
+        timestamp: u64,
+    ) -> CRDTResult<bool> {
+        if self.get() != Some(expected) {
+            return Ok(false);
+        }
+
+        let observed_timestamp = self.timestamp().as_u64();
+        self.compare_and_set_timestamp(observed_timestamp, new_value, timestamp)
+    }
+
+    /// Sets a new value only if the current value still has timestamp `expected_ts`
+    ///
+    /// Like [`compare_and_set`](Self::compare_and_set), for callers that know
+    /// when the value was written but not the value itself. The same
+    /// single-replica meaning of "atomic" applies.
+    ///
+    /// # Arguments
+    /// * `expected_ts` - The timestamp the caller last observed
+    /// * `new_value` - The new value to set
+    /// * `new_ts` - The timestamp for this update
+    ///
+    /// # Returns
+    /// Ok(true) if the timestamp matched and [`set`](Self::set) was applied,
+    /// Ok(false) if it differs (or the register is empty) and nothing changed
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = LWWRegister::<i32, DefaultConfig>::new(1);
+    /// register.set(10, 1000)?;
+    ///
+    /// assert!(register.compare_and_set_timestamp(1000, 11, 1001)?);
+    /// assert!(!register.compare_and_set_timestamp(1000, 12, 1002)?);
+    /// assert_eq!(register.get(), Some(&11));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    #[cfg(not(feature = "hardware-atomic"))]
+    pub fn compare_and_set_timestamp(
+        &mut self,
+        expected_ts: u64,
+        new_value: T,
+        new_ts: u64,
+    ) -> CRDTResult<bool> {
+        if self.is_empty() || self.current_timestamp.as_u64() != expected_ts {
+            return Ok(false);
+        }
+
+        self.set(new_value, new_ts)?;
+        Ok(true)
+    }
+
+    /// Sets a new value only if the current value still has timestamp `expected_ts` (atomic version)
+    ///
+    /// # Arguments
+    /// * `expected_ts` - The timestamp the caller last observed
+    /// * `new_value` - The new value to set
+    /// * `new_ts` - The timestamp for this update
+    ///
+    /// # Returns
+    /// Ok(true) if the timestamp matched and the update was applied,
+    /// Ok(false) if it differs (or the register is empty) and nothing changed
+    ///
+    /// # Note
+    /// The check and the update go through a compare-exchange loop on the
+    /// timestamp, so the comparison never sees a torn timestamp. Timestamps are
+    /// truncated to `u32` like in [`set`](Self::set).
+    #[cfg(feature = "hardware-atomic")]
+    pub fn compare_and_set_timestamp(
+        &mut self,
+        expected_ts: u64,
+        new_value: T,
+        new_ts: u64,
+    ) -> CRDTResult<bool> {
+        let expected_u32 = expected_ts as u32;
+        let new_timestamp_u32 = new_ts as u32; // Truncate to u32 for ARM compatibility
+
+        loop {
+            let current_timestamp = self.current_timestamp.load(Ordering::Acquire);
+            if self.is_empty() || current_timestamp != expected_u32 {
+                return Ok(false);
+            }
+
+            // Same last-writer-wins rule as set(): an older write is accepted but has no effect
+            let current_node_id = self.current_node_id.load(Ordering::Relaxed);
+            let should_update = new_timestamp_u32 > current_timestamp
+                || (new_timestamp_u32 == current_timestamp && self.node_id > current_node_id);
+            if !should_update {
+                return Ok(true);
+            }
+
+            match self.current_timestamp.compare_exchange_weak(
+                current_timestamp,
+                new_timestamp_u32,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    self.current_node_id.store(self.node_id, Ordering::Relaxed);
+                    *self.current_value.get_mut() = Some(new_value);
+                    return Ok(true);
+                }
+                Err(_) => {
+                    // Spurious failure or a concurrent change, re-check
+                    continue;
+                }
+            }
+        }
+    }
+
     /// Determines if we should update with a new timestamp and node ID
     #[cfg(not(feature = "hardware-atomic"))]
     fn should_update(&self, new_timestamp: &CompactTimestamp, new_node_id: NodeId) -> bool {
@@ -399,7 +890,7 @@ where
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("LWWRegister", 4)?;
+        let mut state = serializer.serialize_struct("LWWRegister", 5)?;
 
         // Serialize the logical state
         #[cfg(not(feature = "hardware-atomic"))]
@@ -422,6 +913,7 @@ where
         }
 
         state.serialize_field("node_id", &self.node_id)?;
+        state.serialize_field("ttl_ms", &self.ttl_ms)?;
         state.end()
     }
 }
@@ -435,6 +927,7 @@ where
     where
         D: Deserializer<'de>,
     {
+        use crate::serialization::fields::SeqFields;
         use core::fmt;
         use serde::de::{self, MapAccess, Visitor};
 
@@ -445,6 +938,7 @@ where
             CurrentTimestamp,
             CurrentNodeId,
             NodeId,
+            TtlMs,
         }
 
         struct LWWRegisterVisitor<T, C: MemoryConfig> {
@@ -461,6 +955,13 @@ where
                 formatter.write_str("struct LWWRegister")
             }
 
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                self.visit_map(SeqFields::new(seq, FIELDS))
+            }
+
             fn visit_map<V>(self, mut map: V) -> Result<LWWRegister<T, C>, V::Error>
             where
                 V: MapAccess<'de>,
@@ -469,6 +970,7 @@ where
                 let mut current_timestamp = None;
                 let mut current_node_id = None;
                 let mut node_id = None;
+                let mut ttl_ms = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -496,6 +998,12 @@ where
                             }
                             node_id = Some(map.next_value::<NodeId>()?);
                         }
+                        Field::TtlMs => {
+                            if ttl_ms.is_some() {
+                                return Err(de::Error::duplicate_field("ttl_ms"));
+                            }
+                            ttl_ms = Some(map.next_value::<Option<u64>>()?);
+                        }
                     }
                 }
 
@@ -506,6 +1014,8 @@ where
                 let current_node_id =
                     current_node_id.ok_or_else(|| de::Error::missing_field("current_node_id"))?;
                 let node_id = node_id.ok_or_else(|| de::Error::missing_field("node_id"))?;
+                // Registers serialized before TTL support have none
+                let ttl_ms = ttl_ms.unwrap_or(None);
 
                 // Reconstruct the LWWRegister
                 #[cfg(not(feature = "hardware-atomic"))]
@@ -515,6 +1025,7 @@ where
                         current_timestamp: CompactTimestamp::new(current_timestamp),
                         current_node_id,
                         node_id,
+                        ttl_ms,
                         _phantom: core::marker::PhantomData,
                     })
                 }
@@ -526,6 +1037,7 @@ where
                         current_timestamp: AtomicU32::new(current_timestamp as u32),
                         current_node_id: AtomicU8::new(current_node_id),
                         node_id,
+                        ttl_ms,
                         _phantom: core::marker::PhantomData,
                     })
                 }
@@ -537,6 +1049,7 @@ where
             "current_timestamp",
             "current_node_id",
             "node_id",
+            "ttl_ms",
         ];
         deserializer.deserialize_struct(
             "LWWRegister",
@@ -548,9 +1061,99 @@ where
     }
 }
 
+// Postcard size bound for LWWRegister
+#[cfg(feature = "postcard")]
+impl<T, C: MemoryConfig> MaxSize for LWWRegister<T, C>
+where
+    T: MaxSize,
+{
+    const POSTCARD_MAX_SIZE: usize = {
+        // The atomic version keeps 32-bit timestamps
+        #[cfg(not(feature = "hardware-atomic"))]
+        const TIMESTAMP_MAX_SIZE: usize = u64::POSTCARD_MAX_SIZE;
+        #[cfg(feature = "hardware-atomic")]
+        const TIMESTAMP_MAX_SIZE: usize = u32::POSTCARD_MAX_SIZE;
+
+        Option::<T>::POSTCARD_MAX_SIZE
+            + TIMESTAMP_MAX_SIZE
+            + 2 * NodeId::POSTCARD_MAX_SIZE
+            + Option::<u64>::POSTCARD_MAX_SIZE
+    };
+}
+
+// CBOR implementation for LWWRegister
+#[cfg(feature = "cbor")]
+impl<Ctx, T, C: MemoryConfig> minicbor::Encode<Ctx> for LWWRegister<T, C>
+where
+    T: minicbor::Encode<Ctx> + Clone + PartialEq,
+{
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.array(4)?;
+        e.u8(self.node_id)?;
+        e.u8(self.current_node())?;
+        e.u64(self.timestamp().as_u64())?;
+        match self.get() {
+            Some(value) => e.encode_with(value, ctx)?,
+            None => e.null()?,
+        };
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<'b, Ctx, T, C: MemoryConfig> minicbor::Decode<'b, Ctx> for LWWRegister<T, C>
+where
+    T: minicbor::Decode<'b, Ctx> + Clone + PartialEq,
+{
+    fn decode(
+        d: &mut minicbor::Decoder<'b>,
+        ctx: &mut Ctx,
+    ) -> Result<Self, minicbor::decode::Error> {
+        crate::serialization::cbor::expect_array_len(d, 4)?;
+
+        let node_id = d.u8()?;
+        let current_node_id = d.u8()?;
+        let current_timestamp = d.u64()?;
+        let current_value = if d.datatype()? == minicbor::data::Type::Null {
+            d.null()?;
+            None
+        } else {
+            Some(d.decode_with(ctx)?)
+        };
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            Ok(LWWRegister {
+                current_value,
+                current_timestamp: CompactTimestamp::new(current_timestamp),
+                current_node_id,
+                node_id,
+                ttl_ms: None,
+                _phantom: core::marker::PhantomData,
+            })
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            Ok(LWWRegister {
+                current_value: UnsafeCell::new(current_value),
+                current_timestamp: AtomicU32::new(current_timestamp as u32),
+                current_node_id: AtomicU8::new(current_node_id),
+                node_id,
+                ttl_ms: None,
+                _phantom: core::marker::PhantomData,
+            })
+        }
+    }
+}
+
 impl<T, C: MemoryConfig> CRDT<C> for LWWRegister<T, C>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     type Error = CRDTError;
 
@@ -623,6 +1226,15 @@ where
         Ok(())
     }
 
+    fn merge_batch<'a, I>(&mut self, others: I) -> CRDTResult<()>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        // Only the latest write across all inputs is cloned
+        self.merge_many(others)
+    }
+
     fn eq(&self, other: &Self) -> bool {
         #[cfg(not(feature = "hardware-atomic"))]
         {
@@ -672,28 +1284,14 @@ where
     }
 
     fn state_hash(&self) -> u32 {
-        // Simple hash based on current state
-        let mut hash = 0u32;
-
-        #[cfg(not(feature = "hardware-atomic"))]
-        {
-            if let Some(ref _value) = self.current_value {
-                hash ^= self.current_timestamp.as_u64() as u32;
-                hash ^= (self.current_node_id as u32) << 16;
-            }
+        // FNV-1a over the value, its timestamp and its author
+        let mut hasher = FnvHasher::new();
+        if let Some(value) = self.get() {
+            hasher.write_value(value);
+            hasher.write_u64(self.timestamp().as_u64());
+            hasher.write_bytes(&[self.current_node()]);
         }
-
-        #[cfg(feature = "hardware-atomic")]
-        {
-            unsafe {
-                if let Some(_value) = &*self.current_value.get() {
-                    hash ^= self.current_timestamp.load(Ordering::Relaxed) as u32;
-                    hash ^= (self.current_node_id.load(Ordering::Relaxed) as u32) << 16;
-                }
-            }
-        }
-
-        hash
+        hasher.finish()
     }
 
     fn can_merge(&self, _other: &Self) -> bool {
@@ -704,7 +1302,7 @@ where
 
 impl<T, C: MemoryConfig> BoundedCRDT<C> for LWWRegister<T, C>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
     const MAX_ELEMENTS: usize = 1; // A register holds one value
@@ -740,11 +1338,22 @@ where
         // For registers, we can always "add" (update) if not at max capacity
         self.element_count() < Self::MAX_ELEMENTS
     }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        let value = core::mem::size_of::<T>()
+            + core::mem::size_of_val(&self.current_timestamp)
+            + core::mem::size_of::<NodeId>();
+        if self.element_count() == 1 {
+            MemoryBreakdown::new(self.memory_usage(), value, 0, 0)
+        } else {
+            MemoryBreakdown::new(self.memory_usage(), 0, 0, value)
+        }
+    }
 }
 
 impl<T, C: MemoryConfig> RealTimeCRDT<C> for LWWRegister<T, C>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_MERGE_CYCLES: u32 = 100;
     const MAX_VALIDATE_CYCLES: u32 = 50;
@@ -770,6 +1379,52 @@ where
     }
 }
 
+impl<T, C: MemoryConfig> DeltaCRDT<C> for LWWRegister<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    fn delta_since(&self, baseline: &Self) -> Self {
+        // The register itself if it would win against the baseline, otherwise empty
+        let newer = match (self.get(), baseline.get()) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(_), Some(_)) => {
+                (self.timestamp(), self.current_node())
+                    > (baseline.timestamp(), baseline.current_node())
+            }
+        };
+
+        if newer {
+            self.clone()
+        } else {
+            Self::new(self.node_id)
+        }
+    }
+
+    fn apply_delta(&mut self, delta: &Self) -> CRDTResult<()> {
+        delta.validate().map_err(|_| CRDTError::InvalidMerge)?;
+
+        // An empty delta must not carry any write metadata
+        if delta.is_empty() && delta.timestamp() != CompactTimestamp::zero() {
+            return Err(CRDTError::InvalidMerge);
+        }
+
+        self.merge(delta)
+    }
+}
+
+impl<T, C: MemoryConfig> Snapshotable<C> for LWWRegister<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    /// The stamp of the current write
+    type Snapshot = (CompactTimestamp, NodeId);
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.timestamp(), self.current_node())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -830,6 +1485,61 @@ mod tests {
         assert_eq!(register1.get(), Some(&20)); // Still register2's value
     }
 
+    #[test]
+    fn test_merge_batch() {
+        let mut others = [
+            LWWRegister::<i32, DefaultConfig>::new(1),
+            LWWRegister::<i32, DefaultConfig>::new(2),
+            LWWRegister::<i32, DefaultConfig>::new(3),
+            LWWRegister::<i32, DefaultConfig>::new(4),
+        ];
+        others[0].set(10, 1000).unwrap();
+        others[1].set(20, 3000).unwrap();
+        others[2].set(30, 3000).unwrap(); // Same timestamp, higher node wins
+        // others[3] stays empty
+
+        let mut batched = LWWRegister::<i32, DefaultConfig>::new(0);
+        batched.set(5, 2000).unwrap();
+        let mut sequential = batched.clone();
+
+        batched.merge_batch(others.iter()).unwrap();
+        for other in others.iter() {
+            sequential.merge(other).unwrap();
+        }
+
+        assert!(batched.eq(&sequential));
+        assert_eq!(batched.get(), Some(&30));
+        assert_eq!(batched.current_node(), 3);
+
+        // Nothing newer in the batch keeps the current value
+        let mut newest = LWWRegister::<i32, DefaultConfig>::new(5);
+        newest.set(50, 9000).unwrap();
+        newest.merge_batch(others.iter()).unwrap();
+        assert_eq!(newest.get(), Some(&50));
+    }
+
+    #[test]
+    fn test_select_winner_and_merge_many() {
+        let mut first = LWWRegister::<i32, DefaultConfig>::new(1);
+        let mut second = LWWRegister::<i32, DefaultConfig>::new(1);
+        let empty = LWWRegister::<i32, DefaultConfig>::new(7);
+        first.set(10, 1000).unwrap();
+        second.set(20, 1000).unwrap();
+
+        // Exact ties keep the earliest candidate, empty registers never win
+        let winner = LWWRegister::select_winner([&empty, &first, &second]).unwrap();
+        assert_eq!(winner.get(), Some(&10));
+        assert!(LWWRegister::select_winner([&empty]).is_none());
+        assert!(LWWRegister::<i32, DefaultConfig>::select_winner([]).is_none());
+
+        let mut register = LWWRegister::<i32, DefaultConfig>::new(0);
+        register.merge_many(core::iter::empty()).unwrap();
+        assert!(register.is_empty());
+        register.merge_many([&empty, &second, &first]).unwrap();
+        assert_eq!(register.get(), Some(&20));
+        assert_eq!(register.current_node(), 1);
+    }
+
     #[test]
     fn test_tiebreaker() {
         let mut register1 = LWWRegister::<i32, DefaultConfig>::new(1);
@@ -869,6 +1579,234 @@ mod tests {
         assert!(register1.validate_bounded().is_ok());
     }
 
+    #[test]
+    fn test_delta_since() {
+        let mut register1 = LWWRegister::<i32, DefaultConfig>::new(1);
+        let mut register2 = LWWRegister::<i32, DefaultConfig>::new(2);
+
+        register1.set(10, 1000).unwrap();
+        register2.set(20, 2000).unwrap();
+
+        // Newer register ships itself
+        let delta = register2.delta_since(&register1);
+        assert_eq!(delta.get(), Some(&20));
+        register1.apply_delta(&delta).unwrap();
+        assert_eq!(register1.get(), Some(&20));
+
+        // Older or identical register ships an empty delta
+        let empty = register1.delta_since(&register2);
+        assert!(empty.is_empty());
+        register2.apply_delta(&empty).unwrap();
+        assert_eq!(register2.get(), Some(&20));
+        assert!(register1.eq(&register2));
+    }
+
+    #[test]
+    fn test_apply_invalid_delta() {
+        let mut register = LWWRegister::<i32, DefaultConfig>::new(1);
+        let invalid = LWWRegister::<i32, DefaultConfig>::new(200);
+        assert_eq!(register.apply_delta(&invalid), Err(CRDTError::InvalidMerge));
+    }
+
+    #[test]
+    fn test_ttl_expiry() {
+        let mut register = LWWRegister::<i32, DefaultConfig>::new_with_ttl(1, 500);
+        assert_eq!(register.ttl(), Some(500));
+
+        // An empty register has nothing to expire
+        assert!(!register.is_expired(10_000));
+        assert_eq!(register.get_if_fresh(10_000), None);
+
+        register.set(42, 1000).unwrap();
+        assert!(!register.is_expired(1000));
+        assert!(!register.is_expired(1500)); // Exactly at the TTL is still fresh
+        assert!(register.is_expired(1501));
+        assert_eq!(register.get_if_fresh(1500), Some(&42));
+        assert_eq!(register.get_if_fresh(1501), None);
+
+        // Times before the write are never expired
+        assert!(!register.is_expired(0));
+
+        // Registers without a TTL never expire
+        let mut plain = LWWRegister::<i32, DefaultConfig>::new(1);
+        plain.set(42, 1000).unwrap();
+        assert_eq!(plain.ttl(), None);
+        assert!(!plain.is_expired(u64::MAX));
+        assert_eq!(plain.get_if_fresh(u64::MAX), Some(&42));
+    }
+
+    #[test]
+    fn test_expire_before() {
+        let mut register = LWWRegister::<i32, DefaultConfig>::new(1);
+        assert!(!register.expire_before(5000));
+
+        register.set(42, 1000).unwrap();
+        assert!(!register.expire_before(1000));
+        assert_eq!(register.get(), Some(&42));
+
+        assert!(register.expire_before(1001));
+        assert!(register.is_empty());
+        assert_eq!(register.timestamp(), CompactTimestamp::zero());
+        assert!(register.eq(&LWWRegister::new(1)));
+
+        // A fresh write is accepted again
+        register.set(7, 500).unwrap();
+        assert_eq!(register.get(), Some(&7));
+    }
+
+    #[test]
+    fn test_merge_expired_with_fresh() {
+        let mut stale = LWWRegister::<i32, DefaultConfig>::new_with_ttl(1, 500);
+        let mut fresh = LWWRegister::<i32, DefaultConfig>::new_with_ttl(2, 500);
+        stale.set(10, 1000).unwrap();
+        fresh.set(20, 2000).unwrap();
+
+        let now = 2200;
+        assert!(stale.is_expired(now));
+        assert!(!fresh.is_expired(now));
+
+        // Merging the stale register into the fresh one keeps the fresh value
+        let mut merged = fresh.clone();
+        merged.merge(&stale).unwrap();
+        assert_eq!(merged.get_if_fresh(now), Some(&20));
+
+        // And the fresh value replaces the stale one
+        stale.merge(&fresh).unwrap();
+        assert_eq!(stale.get_if_fresh(now), Some(&20));
+
+        // Local expiry state does not propagate
+        let mut expired = LWWRegister::<i32, DefaultConfig>::new(1);
+        expired.set(10, 1000).unwrap();
+        expired.expire_before(1500);
+        fresh.merge(&expired).unwrap();
+        assert_eq!(fresh.get(), Some(&20));
+    }
+
+    #[test]
+    fn test_compare_and_set() {
+        let mut register = LWWRegister::<i32, DefaultConfig>::new(1);
+
+        // An empty register never matches
+        assert!(!register.compare_and_set(&0, 1, 1000).unwrap());
+        assert!(register.is_empty());
+
+        register.set(10, 1000).unwrap();
+        assert!(register.compare_and_set(&10, 11, 1001).unwrap());
+        assert_eq!(register.get(), Some(&11));
+        assert_eq!(register.timestamp().as_u64(), 1001);
+
+        // A stale expectation leaves the register untouched
+        assert!(!register.compare_and_set(&10, 12, 1002).unwrap());
+        assert_eq!(register.get(), Some(&11));
+        assert_eq!(register.timestamp().as_u64(), 1001);
+    }
+
+    #[test]
+    fn test_compare_and_set_detects_lost_update() {
+        let mut ecu1 = LWWRegister::<i32, DefaultConfig>::new(1);
+        let mut ecu2 = LWWRegister::<i32, DefaultConfig>::new(2);
+        ecu1.set(100, 1000).unwrap();
+        ecu2.merge(&ecu1).unwrap();
+
+        // Both ECUs update within the same cycle; ecu2 wins the tie by node ID
+        let observed = *ecu1.get().unwrap();
+        ecu2.set(200, 1001).unwrap();
+        ecu1.merge(&ecu2).unwrap();
+
+        assert!(!ecu1.compare_and_set(&observed, 150, 1001).unwrap());
+        assert_eq!(ecu1.get(), Some(&200));
+    }
+
+    #[test]
+    fn test_compare_and_set_timestamp() {
+        let mut register = LWWRegister::<i32, DefaultConfig>::new(1);
+        assert!(!register.compare_and_set_timestamp(0, 1, 1000).unwrap());
+
+        register.set(10, 1000).unwrap();
+        assert!(!register.compare_and_set_timestamp(999, 11, 1001).unwrap());
+        assert_eq!(register.get(), Some(&10));
+
+        assert!(register.compare_and_set_timestamp(1000, 11, 1001).unwrap());
+        assert_eq!(register.get(), Some(&11));
+        assert_eq!(register.timestamp().as_u64(), 1001);
+
+        // An older write is accepted like set() but does not change the value
+        assert!(register.compare_and_set_timestamp(1001, 12, 500).unwrap());
+        assert_eq!(register.get(), Some(&11));
+    }
+
+    #[test]
+    fn test_state_hash_across_nodes() {
+        let mut node1 = LWWRegister::<u32, DefaultConfig>::new(1);
+        let mut node2 = LWWRegister::<u32, DefaultConfig>::new(2);
+        assert_eq!(node1.state_hash(), node2.state_hash());
+
+        node1.set(7, 1000).unwrap();
+        node2.merge(&node1).unwrap();
+        assert_eq!(node1.state_hash(), node2.state_hash());
+
+        // The value itself is part of the hash
+        let mut other = LWWRegister::<u32, DefaultConfig>::new(1);
+        other.set(8, 1000).unwrap();
+        assert_ne!(node1.state_hash(), other.state_hash());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut register = LWWRegister::<u16, DefaultConfig>::new(1);
+        register.set(80, 1000).unwrap();
+        let snap = register.snapshot();
+        assert_eq!(snap, (CompactTimestamp::new(1000), 1));
+
+        // An older write loses and leaves the snapshot alone
+        let mut other = LWWRegister::<u16, DefaultConfig>::new(2);
+        other.set(60, 500).unwrap();
+        register.merge(&other).unwrap();
+        assert!(!register.has_changed_since(&snap));
+
+        // Rewriting the same value is still a new write
+        register.set(80, 2000).unwrap();
+        assert!(register.has_changed_since(&snap));
+    }
+
+    #[test]
+    fn test_merge_and_get_matches_merge() {
+        // Empty, older, equal and newer timestamps against both node orders
+        let writes = [None, Some(500), Some(1000), Some(1500)];
+        for local_write in writes {
+            for remote_write in writes {
+                for remote_node in [0, 1, 2] {
+                    let mut local = LWWRegister::<u32, DefaultConfig>::new(1);
+                    let mut remote = LWWRegister::<u32, DefaultConfig>::new(remote_node);
+                    if let Some(ts) = local_write {
+                        local.set(10, ts).unwrap();
+                    }
+                    if let Some(ts) = remote_write {
+                        remote.set(20, ts).unwrap();
+                    }
+
+                    let mut expected = local.clone();
+                    expected.merge(&remote).unwrap();
+                    let expected_meta = expected
+                        .get()
+                        .map(|v| (*v, expected.timestamp().as_u64(), expected.current_node()));
+
+                    let mut merged = local.clone();
+                    assert_eq!(merged.merge_and_get(&remote).unwrap(), expected.get());
+
+                    let mut merged = local.clone();
+                    let meta = merged.merge_and_get_with_metadata(&remote).unwrap();
+                    assert_eq!(meta.map(|(v, ts, node)| (*v, ts, node)), expected_meta);
+
+                    let mut target = local.clone();
+                    let changed = remote.merge_into(&mut target).unwrap();
+                    assert!(target.eq(&expected));
+                    assert_eq!(changed, !local.eq(&expected));
+                }
+            }
+        }
+    }
+
     #[cfg(all(test, feature = "serde"))]
     mod serde_tests {
         use super::*;
@@ -3,9 +3,15 @@
 //! This module provides register-based CRDTs that store single values
 //! with conflict resolution semantics.
 
+pub mod lamport;
 pub mod lww;
 pub mod mv;
+pub mod mv_causal;
+pub mod mv_resolved;
 
 // Re-export main types
+pub use lamport::LamportRegister;
 pub use lww::LWWRegister;
-pub use mv::MVRegister;
+pub use mv::{MVRegister, NumericValue};
+pub use mv_causal::MVRegisterCausal;
+pub use mv_resolved::{Comparator, MVRegisterResolved};
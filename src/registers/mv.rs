@@ -3,10 +3,11 @@
 //! A register that can hold multiple concurrent values, allowing for conflict-free
 //! concurrent updates. Uses zero allocation with a fixed array for deterministic memory usage.
 
-use crate::clock::CompactTimestamp;
-use crate::error::{CRDTError, CRDTResult};
+use crate::clock::{CompactTimestamp, FnvHasher, StateHash};
+use crate::error::{CRDTError, CRDTResult, ErrorContext};
 use crate::memory::{MemoryConfig, NodeId};
-use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+use crate::traits::snapshot::stamp_hash;
+use crate::traits::{BoundedCRDT, CRDT, MemoryBreakdown, RealTimeCRDT, Snapshotable};
 
 #[cfg(feature = "hardware-atomic")]
 use core::cell::UnsafeCell;
@@ -16,6 +17,11 @@ use core::sync::atomic::{AtomicUsize, Ordering};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "postcard")]
+use crate::serialization::postcard::{seq_max_size, varint_len};
+#[cfg(feature = "postcard")]
+use postcard::experimental::max_size::MaxSize;
+
 /// Multi-Value Register with configurable value array
 ///
 /// This register can hold multiple concurrent values, each with its own timestamp
@@ -194,6 +200,7 @@ where
     where
         D: Deserializer<'de>,
     {
+        use crate::serialization::fields::SeqFields;
         use core::fmt;
         use serde::de::{self, MapAccess, Visitor};
 
@@ -220,6 +227,13 @@ where
                 formatter.write_str("struct MVRegister")
             }
 
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                self.visit_map(SeqFields::new(seq, FIELDS))
+            }
+
             fn visit_map<V>(self, mut map: V) -> Result<MVRegister<T, C, CAPACITY>, V::Error>
             where
                 V: MapAccess<'de>,
@@ -334,16 +348,16 @@ where
                 let count = count.ok_or_else(|| de::Error::missing_field("count"))?;
                 let node_id = node_id.ok_or_else(|| de::Error::missing_field("node_id"))?;
 
-                // Validate count matches values length
-                if count != values_vec.len() {
-                    return Err(de::Error::custom("count does not match values length"));
-                }
-
                 // Validate count is within capacity
                 if count > CAPACITY {
                     return Err(de::Error::custom("count exceeds capacity"));
                 }
 
+                // Validate count matches the number of values received
+                if count != values_vec.iter().flatten().count() {
+                    return Err(de::Error::custom("count does not match values length"));
+                }
+
                 // Reconstruct the MVRegister
                 #[cfg(not(feature = "hardware-atomic"))]
                 {
@@ -378,6 +392,98 @@ where
     }
 }
 
+// Postcard size bounds for MVRegister
+#[cfg(feature = "postcard")]
+impl<T: MaxSize> MaxSize for ValueEntry<T> {
+    const POSTCARD_MAX_SIZE: usize =
+        T::POSTCARD_MAX_SIZE + u64::POSTCARD_MAX_SIZE + NodeId::POSTCARD_MAX_SIZE;
+}
+
+#[cfg(feature = "postcard")]
+impl<T, C: MemoryConfig, const CAPACITY: usize> MaxSize for MVRegister<T, C, CAPACITY>
+where
+    T: MaxSize,
+{
+    const POSTCARD_MAX_SIZE: usize = {
+        seq_max_size(CAPACITY, Option::<ValueEntry<T>>::POSTCARD_MAX_SIZE)
+            + varint_len(CAPACITY)
+            + NodeId::POSTCARD_MAX_SIZE
+    };
+}
+
+// CBOR implementation for MVRegister
+#[cfg(feature = "cbor")]
+impl<Ctx, T, C: MemoryConfig, const CAPACITY: usize> minicbor::Encode<Ctx>
+    for MVRegister<T, C, CAPACITY>
+where
+    T: minicbor::Encode<Ctx> + Clone + PartialEq,
+{
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.array(2)?;
+        e.u8(self.node_id)?;
+        e.array(self.iter().count() as u64)?;
+        for (value, timestamp, node_id) in self.iter() {
+            e.array(3)?;
+            e.encode_with(value, ctx)?;
+            e.u64(timestamp.as_u64())?;
+            e.u8(node_id)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<'b, Ctx, T, C: MemoryConfig, const CAPACITY: usize> minicbor::Decode<'b, Ctx>
+    for MVRegister<T, C, CAPACITY>
+where
+    T: minicbor::Decode<'b, Ctx> + Clone + PartialEq,
+{
+    fn decode(
+        d: &mut minicbor::Decoder<'b>,
+        ctx: &mut Ctx,
+    ) -> Result<Self, minicbor::decode::Error> {
+        use crate::serialization::cbor;
+
+        cbor::expect_array_len(d, 2)?;
+        let node_id = d.u8()?;
+
+        let count = cbor::bounded_array_len(d, CAPACITY)?;
+        let mut values: [Option<ValueEntry<T>>; CAPACITY] = [const { None }; CAPACITY];
+        for slot in values.iter_mut().take(count) {
+            cbor::expect_array_len(d, 3)?;
+            *slot = Some(ValueEntry {
+                value: d.decode_with(ctx)?,
+                timestamp: CompactTimestamp::new(d.u64()?),
+                node_id: d.u8()?,
+            });
+        }
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            Ok(MVRegister {
+                values,
+                count,
+                node_id,
+                _phantom: core::marker::PhantomData,
+            })
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            Ok(MVRegister {
+                values: UnsafeCell::new(values),
+                count: AtomicUsize::new(count),
+                node_id,
+                _phantom: core::marker::PhantomData,
+            })
+        }
+    }
+}
+
 impl<T, C: MemoryConfig, const CAPACITY: usize> MVRegister<T, C, CAPACITY>
 where
     T: Clone + PartialEq,
@@ -482,7 +588,8 @@ where
 
         // New value from this node - check if we have space
         if self.count >= CAPACITY {
-            return Err(CRDTError::BufferOverflow);
+            let ctx = ErrorContext::new("MVRegister", "set", self.node_id, self.count, CAPACITY);
+            return Err(CRDTError::BufferOverflow.with_context(ctx));
         }
 
         // Insert new value
@@ -555,7 +662,14 @@ where
             } else {
                 // New value from this node - check if we have space
                 if current_count >= CAPACITY {
-                    return Err(CRDTError::BufferOverflow);
+                    let ctx = ErrorContext::new(
+                        "MVRegister",
+                        "set",
+                        self.node_id,
+                        current_count,
+                        CAPACITY,
+                    );
+                    return Err(CRDTError::BufferOverflow.with_context(ctx));
                 }
 
                 // Try to atomically increment count to reserve a slot
@@ -779,6 +893,152 @@ where
         self.node_id
     }
 
+    /// Counts the entries dominated by a newer entry from the same node
+    ///
+    /// `set` and `merge` keep one entry per node, but a register decoded
+    /// from the wire may carry several. Of two entries from one node, the
+    /// one with the lower timestamp is dominated; of two with equal
+    /// timestamps, the later slot is.
+    ///
+    /// # Returns
+    /// The number of entries [`clear_dominated`](Self::clear_dominated)
+    /// would remove
+    pub fn dominated_count(&self) -> usize {
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (values, count) = (&self.values, self.count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (values, count) = (
+            unsafe { &*self.values.get() },
+            self.count.load(Ordering::Relaxed),
+        );
+
+        let entries = &values[..count];
+        (0..count)
+            .filter(|&index| Self::is_dominated(entries, index))
+            .count()
+    }
+
+    /// Removes entries dominated by a newer entry from the same node
+    ///
+    /// Runs automatically at the end of `merge` once the register is at
+    /// least half full.
+    ///
+    /// # Returns
+    /// The number of entries removed
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = MVRegister::<u32, DefaultConfig>::new(1);
+    /// register.set(42, 1000)?;
+    /// // A register built by set and merge never holds dominated entries
+    /// assert_eq!(register.clear_dominated(), 0);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn clear_dominated(&mut self) -> usize {
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (values, count) = (&mut self.values, &mut self.count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (values, count) = (self.values.get_mut(), self.count.get_mut());
+
+        let mut dominated = [false; CAPACITY];
+        for (index, flag) in dominated.iter_mut().enumerate().take(*count) {
+            *flag = Self::is_dominated(&values[..*count], index);
+        }
+
+        // Pack the surviving entries into the first slots
+        let mut kept = 0;
+        let mut removed = 0;
+        for index in 0..*count {
+            let entry = values[index].take();
+            if dominated[index] {
+                removed += 1;
+            } else if entry.is_some() {
+                values[kept] = entry;
+                kept += 1;
+            }
+        }
+        *count = kept;
+        removed
+    }
+
+    /// Checks if the entry at `index` is dominated by another entry from its node
+    fn is_dominated(entries: &[Option<ValueEntry<T>>], index: usize) -> bool {
+        let Some(entry) = &entries[index] else {
+            return false;
+        };
+        entries.iter().enumerate().any(|(other_index, other)| {
+            other.as_ref().is_some_and(|other| {
+                other.node_id == entry.node_id
+                    && (other.timestamp > entry.timestamp
+                        || (other.timestamp == entry.timestamp && other_index < index))
+            })
+        })
+    }
+
+    /// Merges another register and returns the latest value
+    ///
+    /// # Arguments
+    /// * `other` - The register to merge
+    ///
+    /// # Returns
+    /// The value [`resolve_latest`](Self::resolve_latest) picks after the
+    /// merge, or None if both registers are empty
+    pub fn merge_and_get(&mut self, other: &Self) -> CRDTResult<Option<&T>>
+    where
+        T: core::fmt::Debug + StateHash,
+    {
+        self.merge(other)?;
+        Ok(self.resolve_latest())
+    }
+
+    /// Merges another register and returns the latest value with its
+    /// timestamp and author
+    ///
+    /// # Arguments
+    /// * `other` - The register to merge
+    ///
+    /// # Returns
+    /// The latest (value, timestamp, node ID), ties broken by the higher
+    /// node ID, or None if both registers are empty
+    pub fn merge_and_get_with_metadata(
+        &mut self,
+        other: &Self,
+    ) -> CRDTResult<Option<(&T, u64, NodeId)>>
+    where
+        T: core::fmt::Debug + StateHash,
+    {
+        self.merge(other)?;
+        Ok(self
+            .iter_with_metadata()
+            .max_by_key(|(_, timestamp, node_id)| (*timestamp, *node_id)))
+    }
+
+    /// Merges this register into `target`
+    ///
+    /// # Arguments
+    /// * `target` - The register to update
+    ///
+    /// # Returns
+    /// Ok(true) if `target` gained or replaced an entry (or dropped a
+    /// dominated one), Ok(false) if it already covered every entry here,
+    /// or `CRDTError::BufferOverflow` if `target` has no room for a new node
+    pub fn merge_into(&self, target: &mut Self) -> CRDTResult<bool>
+    where
+        T: core::fmt::Debug + StateHash,
+    {
+        let brings_news = self.iter().any(|(_, timestamp, node_id)| {
+            !target
+                .iter()
+                .any(|(_, ours, our_node)| our_node == node_id && ours >= timestamp)
+        });
+        let len_before = target.len();
+        target.merge(self)?;
+        Ok(brings_news || target.len() != len_before)
+    }
+
     /// Returns an iterator over the value entries
     ///
     /// # Returns
@@ -802,49 +1062,305 @@ where
             })
         }
     }
+
+    /// Returns an iterator over the value entries with structured timestamps
+    ///
+    /// Same as [`iter`](Self::iter); values are borrowed, not cloned.
+    ///
+    /// # Returns
+    /// An iterator over (value, timestamp, node_id) tuples
+    pub fn iter_entries(&self) -> impl Iterator<Item = (&T, CompactTimestamp, NodeId)> {
+        self.iter()
+    }
+
+    /// Returns an iterator over the values with their raw metadata
+    ///
+    /// # Returns
+    /// An iterator over (value, timestamp in milliseconds, node_id) tuples
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = MVRegister::<f32, DefaultConfig>::new(1);
+    /// register.set(42.0, 1000)?;
+    ///
+    /// let (value, timestamp, node_id) = register.iter_with_metadata().next().unwrap();
+    /// assert_eq!((*value, timestamp, node_id), (42.0, 1000, 1));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn iter_with_metadata(&self) -> impl Iterator<Item = (&T, u64, NodeId)> {
+        self.iter()
+            .map(|(value, timestamp, node_id)| (value, timestamp.as_u64(), node_id))
+    }
+
+    /// Gets the value and timestamp written by a specific node
+    ///
+    /// # Arguments
+    /// * `node_id` - The node ID to look up
+    ///
+    /// # Returns
+    /// The node's value and its timestamp, or None if no value exists
+    pub fn get_entry_for_node(&self, node_id: NodeId) -> Option<(&T, CompactTimestamp)> {
+        self.iter()
+            .find(|(_, _, entry_node)| *entry_node == node_id)
+            .map(|(value, timestamp, _)| (value, timestamp))
+    }
+
+    /// Picks the entry that compares greatest under `compare`
+    ///
+    /// Ties are broken by the higher timestamp, then the higher node ID, so the
+    /// winner does not depend on the order in which replicas were merged.
+    fn resolve_by<F>(&self, compare: F) -> Option<&T>
+    where
+        F: Fn(
+            (&T, CompactTimestamp, NodeId),
+            (&T, CompactTimestamp, NodeId),
+        ) -> core::cmp::Ordering,
+    {
+        self.iter()
+            .max_by(|a, b| compare(*a, *b).then_with(|| (a.1, a.2).cmp(&(b.1, b.2))))
+            .map(|(value, _, _)| value)
+    }
+
+    /// Resolves to the most recently written value
+    ///
+    /// # Returns
+    /// The value with the highest timestamp, ties broken by the higher node ID,
+    /// or None if empty
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = MVRegister::<f32, DefaultConfig>::new(1);
+    /// register.set(10.0, 1000)?;
+    ///
+    /// let mut other = MVRegister::<f32, DefaultConfig>::new(2);
+    /// other.set(20.0, 2000)?;
+    /// register.merge(&other)?;
+    ///
+    /// assert_eq!(register.resolve_latest(), Some(&20.0));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn resolve_latest(&self) -> Option<&T> {
+        self.resolve_by(|_, _| core::cmp::Ordering::Equal)
+    }
+
+    /// Resolves to the earliest written value
+    ///
+    /// # Returns
+    /// The value with the lowest timestamp, ties broken by the higher node ID,
+    /// or None if empty
+    pub fn resolve_earliest(&self) -> Option<&T> {
+        self.resolve_by(|a, b| b.1.cmp(&a.1))
+    }
+
+    /// Resolves to the value from the highest-priority node
+    ///
+    /// # Arguments
+    /// * `priority` - Maps a node ID to its priority; higher wins
+    ///
+    /// # Returns
+    /// The value from the node with the highest priority, ties broken as in
+    /// [`resolve_latest`](Self::resolve_latest), or None if empty
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = MVRegister::<u32, DefaultConfig>::new(1);
+    /// register.set(10, 2000)?;
+    ///
+    /// let mut other = MVRegister::<u32, DefaultConfig>::new(2);
+    /// other.set(20, 1000)?;
+    /// register.merge(&other)?;
+    ///
+    /// // Node 2 is the primary sensor
+    /// let resolved = register.resolve_by_node_priority(|node| if node == 2 { 10 } else { 0 });
+    /// assert_eq!(resolved, Some(&20));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn resolve_by_node_priority<F>(&self, priority: F) -> Option<&T>
+    where
+        F: Fn(NodeId) -> u8,
+    {
+        self.resolve_by(|a, b| priority(a.2).cmp(&priority(b.2)))
+    }
+
+    /// Resolves using a custom comparator
+    ///
+    /// # Arguments
+    /// * `comparator` - Orders two values; the greatest wins
+    ///
+    /// # Returns
+    /// The greatest value under `comparator`, ties broken as in
+    /// [`resolve_latest`](Self::resolve_latest), or None if empty
+    pub fn resolve_with<F>(&self, comparator: F) -> Option<&T>
+    where
+        F: Fn(&T, &T) -> core::cmp::Ordering,
+    {
+        self.resolve_by(|a, b| comparator(a.0, b.0))
+    }
+
+    /// Resolves to the largest value
+    ///
+    /// Values that cannot be compared (such as NaN) are treated as equal.
+    ///
+    /// # Returns
+    /// The largest value, ties broken as in [`resolve_latest`](Self::resolve_latest),
+    /// or None if empty
+    pub fn resolve_max(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.resolve_by(|a, b| a.0.partial_cmp(b.0).unwrap_or(core::cmp::Ordering::Equal))
+    }
+
+    /// Resolves to the smallest value
+    ///
+    /// Values that cannot be compared (such as NaN) are treated as equal.
+    ///
+    /// # Returns
+    /// The smallest value, ties broken as in [`resolve_latest`](Self::resolve_latest),
+    /// or None if empty
+    pub fn resolve_min(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.resolve_by(|a, b| b.0.partial_cmp(a.0).unwrap_or(core::cmp::Ordering::Equal))
+    }
+
+    /// Resolves to a value that enough nodes agree on
+    ///
+    /// Each node holds one entry, so a value held by several nodes counts
+    /// as their agreement. If more than one value meets the quorum, the
+    /// most widely held wins, ties broken as in [`resolve_latest`](Self::resolve_latest).
+    ///
+    /// # Arguments
+    /// * `min_agree` - The number of nodes that must hold the value
+    ///
+    /// # Returns
+    /// The agreed value, or None if no value is held by `min_agree` nodes
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut ecu1 = MVRegister::<u8, DefaultConfig>::new(1);
+    /// ecu1.set(3, 1000)?; // Gear reported by each ECU
+    /// for (node, gear) in [(2, 3), (3, 4)] {
+    ///     let mut ecu = MVRegister::<u8, DefaultConfig>::new(node);
+    ///     ecu.set(gear, 1000)?;
+    ///     ecu1.merge(&ecu)?;
+    /// }
+    ///
+    /// assert_eq!(ecu1.resolve_by_quorum(2), Some(&3));
+    /// assert_eq!(ecu1.resolve_by_quorum(3), None);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn resolve_by_quorum(&self, min_agree: usize) -> Option<&T> {
+        self.resolve_by(|a, b| {
+            self.value_agreement_count(a.0)
+                .cmp(&self.value_agreement_count(b.0))
+        })
+        .filter(|value| self.value_agreement_count(value) >= min_agree)
+    }
+
+    /// Counts the nodes holding a value
+    ///
+    /// # Arguments
+    /// * `value` - The value to look for
+    ///
+    /// # Returns
+    /// The number of nodes whose entry equals `value`
+    pub fn value_agreement_count(&self, value: &T) -> usize {
+        self.iter()
+            .filter(|(candidate, _, _)| *candidate == value)
+            .count()
+    }
+}
+
+/// Numeric value types that support averaging in an [`MVRegister`]
+pub trait NumericValue: Copy + PartialOrd {
+    /// Converts the value to `f64` for aggregation
+    fn to_f64(self) -> f64;
 }
 
-// Numeric operations for numeric types
-impl<C: MemoryConfig> MVRegister<f32, C> {
+macro_rules! impl_numeric_value {
+    ($($ty:ty),*) => {
+        $(
+            impl NumericValue for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_value!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+// Numeric aggregation for numeric types
+impl<T, C: MemoryConfig, const CAPACITY: usize> MVRegister<T, C, CAPACITY>
+where
+    T: NumericValue,
+{
     /// Calculates the average of all values
     ///
     /// # Returns
     /// The average value, or None if empty
-    pub fn average(&self) -> Option<f32> {
-        #[cfg(not(feature = "hardware-atomic"))]
-        {
-            if self.count == 0 {
-                return None;
-            }
-
-            let sum: f32 = self
-                .values
-                .iter()
-                .take(self.count)
-                .filter_map(|opt| opt.as_ref().map(|entry| entry.value))
-                .sum();
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut register = MVRegister::<i32, DefaultConfig>::new(1);
+    /// register.set(10, 1000)?;
+    ///
+    /// let mut other = MVRegister::<i32, DefaultConfig>::new(2);
+    /// other.set(15, 1000)?;
+    /// register.merge(&other)?;
+    ///
+    /// assert_eq!(register.average(), Some(12.5));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn average(&self) -> Option<f64> {
+        let (sum, count) = self
+            .iter()
+            .fold((0.0, 0usize), |(sum, count), (value, _, _)| {
+                (sum + value.to_f64(), count + 1)
+            });
 
-            Some(sum / self.count as f32)
+        if count == 0 {
+            None
+        } else {
+            Some(sum / count as f64)
         }
+    }
 
-        #[cfg(feature = "hardware-atomic")]
-        {
-            let current_count = self.count.load(Ordering::Relaxed);
-            if current_count == 0 {
-                return None;
-            }
-
-            let values_ref = unsafe { &*self.values.get() };
-            let sum: f32 = values_ref
-                .iter()
-                .take(current_count)
-                .filter_map(|opt| opt.as_ref().map(|entry| entry.value))
-                .sum();
+    /// Finds the median value
+    ///
+    /// With an even number of values the lower of the two middle values is
+    /// returned. Values that cannot be compared (such as NaN) are treated as equal.
+    ///
+    /// # Returns
+    /// The median value, or None if empty
+    pub fn median(&self) -> Option<T> {
+        let mut sorted = [None; CAPACITY];
+        let mut count = 0;
+        for (value, _, _) in self.iter() {
+            sorted[count] = Some(*value);
+            count += 1;
+        }
 
-            Some(sum / current_count as f32)
+        if count == 0 {
+            return None;
         }
+
+        sorted[..count]
+            .sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        sorted[(count - 1) / 2]
     }
+}
 
+// Floating-point min/max that tolerate NaN
+impl<C: MemoryConfig, const CAPACITY: usize> MVRegister<f32, C, CAPACITY> {
     /// Finds the minimum value
     ///
     /// # Returns
@@ -896,48 +1412,71 @@ impl<C: MemoryConfig> MVRegister<f32, C> {
                 .max_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
         }
     }
-}
 
-impl<C: MemoryConfig> MVRegister<f64, C> {
-    /// Calculates the average of all values
+    /// Resolves to the reading that enough nodes approximately agree on
+    ///
+    /// Readings are grouped into windows no wider than `tolerance`, and the
+    /// window holding the most readings wins (the lowest one on a tie).
+    /// NaN readings never agree with anything.
+    ///
+    /// # Arguments
+    /// * `min_agree` - The number of nodes that must fall in the winning window
+    /// * `tolerance` - The maximum spread of readings that still agree
     ///
     /// # Returns
-    /// The average value, or None if empty
-    pub fn average(&self) -> Option<f64> {
-        #[cfg(not(feature = "hardware-atomic"))]
-        {
-            if self.count == 0 {
-                return None;
+    /// The midpoint of the winning window, or None if fewer than
+    /// `min_agree` readings agree
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut temperature = MVRegister::<f32, DefaultConfig>::new(1);
+    /// temperature.set(23.5, 1000)?;
+    /// for (node, reading) in [(2, 23.6), (3, 35.0)] {
+    ///     let mut sensor = MVRegister::<f32, DefaultConfig>::new(node);
+    ///     sensor.set(reading, 1000)?;
+    ///     temperature.merge(&sensor)?;
+    /// }
+    ///
+    /// // The faulty third sensor is outvoted
+    /// let agreed = temperature.resolve_by_approx_quorum(2, 1.0).unwrap();
+    /// assert!((agreed - 23.55).abs() < 1e-4);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn resolve_by_approx_quorum(&self, min_agree: usize, tolerance: f32) -> Option<f32> {
+        let mut sorted = [0.0f32; CAPACITY];
+        let mut count = 0;
+        for (value, _, _) in self.iter() {
+            if !value.is_nan() {
+                sorted[count] = *value;
+                count += 1;
             }
-
-            let sum: f64 = self
-                .values
-                .iter()
-                .take(self.count)
-                .filter_map(|opt| opt.as_ref().map(|entry| entry.value))
-                .sum();
-
-            Some(sum / self.count as f64)
         }
-
-        #[cfg(feature = "hardware-atomic")]
-        {
-            let current_count = self.count.load(Ordering::Relaxed);
-            if current_count == 0 {
-                return None;
+        let sorted = &mut sorted[..count];
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+
+        // Slide a window from each reading and keep the fullest one
+        let mut best: Option<(usize, usize)> = None;
+        let mut end = 0;
+        for start in 0..sorted.len() {
+            end = end.max(start);
+            while end + 1 < sorted.len() && sorted[end + 1] - sorted[start] <= tolerance {
+                end += 1;
             }
+            if best.is_none_or(|(best_start, best_end)| end - start > best_end - best_start) {
+                best = Some((start, end));
+            }
+        }
 
-            let values_ref = unsafe { &*self.values.get() };
-            let sum: f64 = values_ref
-                .iter()
-                .take(current_count)
-                .filter_map(|opt| opt.as_ref().map(|entry| entry.value))
-                .sum();
-
-            Some(sum / current_count as f64)
+        let (start, end) = best?;
+        if end - start + 1 < min_agree {
+            return None;
         }
+        Some(sorted[start] + (sorted[end] - sorted[start]) / 2.0)
     }
+}
 
+impl<C: MemoryConfig, const CAPACITY: usize> MVRegister<f64, C, CAPACITY> {
     /// Finds the minimum value
     ///
     /// # Returns
@@ -993,7 +1532,7 @@ impl<C: MemoryConfig> MVRegister<f64, C> {
 
 impl<T, C: MemoryConfig, const CAPACITY: usize> CRDT<C> for MVRegister<T, C, CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     type Error = CRDTError;
 
@@ -1105,6 +1644,11 @@ where
                 }
             }
         }
+
+        // Keep stale entries from piling up in a filling register
+        if self.len() >= CAPACITY / 2 {
+            self.clear_dominated();
+        }
         Ok(())
     }
 
@@ -1245,39 +1789,26 @@ where
     }
 
     fn state_hash(&self) -> u32 {
-        // Simple hash based on values (order-independent)
+        // XOR of per-entry FNV-1a hashes (order-independent)
         let mut hash = 0u32;
 
         #[cfg(not(feature = "hardware-atomic"))]
-        {
-            for entry in self.values.iter().take(self.count) {
-                if let Some(entry) = entry {
-                    let value_ptr = &entry.value as *const T as usize;
-                    hash ^= (value_ptr as u32)
-                        ^ (entry.timestamp.as_u64() as u32)
-                        ^ (entry.node_id as u32);
-                }
-            }
-            hash ^= self.count as u32;
-        }
+        let (values, count) = (&self.values, self.count);
 
         #[cfg(feature = "hardware-atomic")]
-        {
-            let current_count = self.count.load(Ordering::Relaxed);
-            let values_ref = unsafe { &*self.values.get() };
-
-            for entry in values_ref.iter().take(current_count) {
-                if let Some(entry) = entry {
-                    let value_ptr = &entry.value as *const T as usize;
-                    hash ^= (value_ptr as u32)
-                        ^ (entry.timestamp.as_u64() as u32)
-                        ^ (entry.node_id as u32);
-                }
-            }
-            hash ^= current_count as u32;
+        let (values, count) = (
+            unsafe { &*self.values.get() },
+            self.count.load(Ordering::Relaxed),
+        );
+
+        for entry in values.iter().take(count).flatten() {
+            let mut hasher = FnvHasher::new();
+            hasher.write_value(&entry.value);
+            hasher.write_u64(entry.timestamp.as_u64());
+            hasher.write_bytes(&[entry.node_id]);
+            hash ^= hasher.finish();
         }
-
-        hash
+        hash ^ count as u32
     }
 
     fn can_merge(&self, other: &Self) -> bool {
@@ -1337,7 +1868,7 @@ where
 
 impl<T, C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for MVRegister<T, C, CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
     const MAX_ELEMENTS: usize = CAPACITY; // Maximum number of values
@@ -1359,20 +1890,33 @@ where
     }
 
     fn compact(&mut self) -> CRDTResult<usize> {
-        // MVRegisters can't be compacted without losing data
-        // This is a no-op that returns 0 bytes freed
-        Ok(0)
+        // Only dominated entries can go without losing data
+        Ok(self.clear_dominated() * core::mem::size_of::<Option<ValueEntry<T>>>())
     }
 
     fn can_add_element(&self) -> bool {
         // For registers, we can always "add" (update) if not at max capacity
         self.element_count() < Self::MAX_ELEMENTS
     }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        let value = core::mem::size_of::<T>()
+            + core::mem::size_of::<CompactTimestamp>()
+            + core::mem::size_of::<NodeId>();
+        let slot = core::mem::size_of::<Option<ValueEntry<T>>>();
+        let count = self.element_count();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            count * value,
+            0,
+            (CAPACITY - count) * slot,
+        )
+    }
 }
 
 impl<T, C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for MVRegister<T, C, CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_MERGE_CYCLES: u32 = 150; // Linear in number of values
     const MAX_VALIDATE_CYCLES: u32 = 75;
@@ -1398,6 +1942,34 @@ where
     }
 }
 
+impl<T, C: MemoryConfig, const CAPACITY: usize> Snapshotable<C> for MVRegister<T, C, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    /// XOR of the per-node value stamps
+    type Snapshot = u64;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        #[cfg(not(feature = "hardware-atomic"))]
+        let values = &self.values[..self.count];
+
+        #[cfg(feature = "hardware-atomic")]
+        let values = {
+            let current_count = self.count.load(Ordering::Relaxed);
+            let values_ref = unsafe { &*self.values.get() };
+            &values_ref[..current_count]
+        };
+
+        values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| entry.as_ref().map(|entry| (i, entry)))
+            .fold(0, |hash, (i, entry)| {
+                hash ^ stamp_hash(i, entry.timestamp, entry.node_id)
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1496,6 +2068,135 @@ mod tests {
         assert_eq!(register.max(), Some(30.0));
     }
 
+    #[test]
+    fn test_resolve_latest_all_combinations() {
+        // (timestamp, node) pairs for two writers and the expected winning value
+        let cases = [
+            // Unequal timestamps, unequal nodes: newer timestamp wins either way
+            ((2000, 1), (1000, 2), 10),
+            ((1000, 1), (2000, 2), 20),
+            // Equal timestamps, unequal nodes: higher node wins either way
+            ((1000, 1), (1000, 2), 20),
+            ((1000, 2), (1000, 1), 10),
+            // Same node: the register keeps only the newer write
+            ((2000, 1), (1000, 1), 10),
+            ((1000, 1), (2000, 1), 20),
+        ];
+
+        for ((ts_a, node_a), (ts_b, node_b), expected) in cases {
+            let mut a = MVRegister::<u32, DefaultConfig>::new(node_a);
+            a.set(10, ts_a).unwrap();
+            let mut b = MVRegister::<u32, DefaultConfig>::new(node_b);
+            b.set(20, ts_b).unwrap();
+
+            // Merge order must not matter
+            let mut ab = a.clone();
+            ab.merge(&b).unwrap();
+            let mut ba = b.clone();
+            ba.merge(&a).unwrap();
+
+            assert_eq!(ab.resolve_latest(), Some(&expected));
+            assert_eq!(ba.resolve_latest(), Some(&expected));
+        }
+
+        let empty = MVRegister::<u32, DefaultConfig>::new(1);
+        assert_eq!(empty.resolve_latest(), None);
+    }
+
+    #[test]
+    fn test_resolvers() {
+        let mut register = MVRegister::<i32, DefaultConfig>::new(1);
+        register.set(30, 1000).unwrap();
+
+        let mut other2 = MVRegister::<i32, DefaultConfig>::new(2);
+        other2.set(-5, 3000).unwrap();
+        register.merge(&other2).unwrap();
+
+        let mut other3 = MVRegister::<i32, DefaultConfig>::new(3);
+        other3.set(12, 1000).unwrap();
+        register.merge(&other3).unwrap();
+
+        assert_eq!(register.resolve_latest(), Some(&-5));
+        // Nodes 1 and 3 tie on the earliest timestamp; the higher node wins
+        assert_eq!(register.resolve_earliest(), Some(&12));
+        assert_eq!(register.resolve_max(), Some(&30));
+        assert_eq!(register.resolve_min(), Some(&-5));
+        assert_eq!(
+            register.resolve_by_node_priority(|node| if node == 1 { 9 } else { 1 }),
+            Some(&30)
+        );
+        // Equal priorities fall back to the latest write
+        assert_eq!(register.resolve_by_node_priority(|_| 0), Some(&-5));
+        // Closest to 10
+        assert_eq!(
+            register.resolve_with(|a, b| (b - 10).abs().cmp(&(a - 10).abs())),
+            Some(&12)
+        );
+    }
+
+    #[test]
+    fn test_average_and_median() {
+        let mut register = MVRegister::<u16, DefaultConfig>::new(1);
+        assert_eq!(register.average(), None);
+        assert_eq!(register.median(), None);
+
+        register.set(40, 1000).unwrap();
+        assert_eq!(register.median(), Some(40));
+
+        for (node, value) in [(2, 10), (3, 30), (4, 20)] {
+            let mut other = MVRegister::<u16, DefaultConfig>::new(node);
+            other.set(value, 1000).unwrap();
+            register.merge(&other).unwrap();
+        }
+
+        assert_eq!(register.average(), Some(25.0));
+        // Even count: lower middle value
+        assert_eq!(register.median(), Some(20));
+
+        let mut floats = MVRegister::<f64, DefaultConfig, 3>::with_capacity(1);
+        floats.set(2.5, 1000).unwrap();
+        let mut other = MVRegister::<f64, DefaultConfig, 3>::with_capacity(2);
+        other.set(-1.0, 1000).unwrap();
+        floats.merge(&other).unwrap();
+        let mut other = MVRegister::<f64, DefaultConfig, 3>::with_capacity(3);
+        other.set(7.0, 1000).unwrap();
+        floats.merge(&other).unwrap();
+
+        assert_eq!(floats.median(), Some(2.5));
+        assert_eq!(floats.min(), Some(-1.0));
+        assert_eq!(floats.max(), Some(7.0));
+    }
+
+    #[test]
+    fn test_iter_metadata_preserved_after_merge() {
+        let mut local = MVRegister::<u32, DefaultConfig>::new(1);
+        local.set(10, 1000).unwrap();
+
+        let mut remote = MVRegister::<u32, DefaultConfig>::new(7);
+        remote.set(70, 123_456).unwrap();
+        local.merge(&remote).unwrap();
+
+        let mut entries = [None; 4];
+        for (i, (value, timestamp, node_id)) in local.iter_with_metadata().enumerate() {
+            entries[i] = Some((*value, timestamp, node_id));
+        }
+        assert_eq!(entries[0], Some((10, 1000, 1)));
+        assert_eq!(entries[1], Some((70, 123_456, 7)));
+        assert_eq!(entries[2], None);
+
+        // Structured timestamps match what the remote node recorded
+        let (value, timestamp) = local.get_entry_for_node(7).unwrap();
+        assert_eq!(*value, 70);
+        assert_eq!(timestamp, remote.get_timestamp_from_node(7).unwrap());
+        assert_eq!(
+            local.iter_entries().find(|(_, _, node_id)| *node_id == 7),
+            Some((&70, CompactTimestamp::new(123_456), 7))
+        );
+
+        assert_eq!(local.get_entry_for_node(3), None);
+        assert_eq!(local.iter_entries().count(), 2);
+    }
+
     #[test]
     fn test_capacity_limits() {
         let mut register = MVRegister::<f32, DefaultConfig>::new(1);
@@ -1516,6 +2217,21 @@ mod tests {
         assert!(register.merge(&other5).is_err());
     }
 
+    #[test]
+    fn test_set_error_context() {
+        let mut register = MVRegister::<u32, DefaultConfig, 1>::with_capacity(1);
+        let mut other = MVRegister::<u32, DefaultConfig, 1>::with_capacity(2);
+        other.set(20, 1000).unwrap();
+        register.merge(&other).unwrap();
+
+        let error = register.set(10, 2000).unwrap_err();
+        assert_eq!(error.cause(), CRDTError::BufferOverflow);
+        assert_eq!(
+            error.context(),
+            Some(&ErrorContext::new("MVRegister", "set", 1, 1, 1))
+        );
+    }
+
     #[test]
     fn test_merge_idempotent() {
         let mut register1 = MVRegister::<f32, DefaultConfig>::new(1);
@@ -1696,6 +2412,186 @@ mod tests {
         assert_eq!(register1.get_from_node(2), Some(&20.0));
     }
 
+    #[test]
+    fn test_state_hash_across_nodes() {
+        let mut node1 = MVRegister::<u32, DefaultConfig>::new(1);
+        let mut node2 = MVRegister::<u32, DefaultConfig>::new(2);
+        node1.set(10, 1000).unwrap();
+        node2.set(20, 1000).unwrap();
+
+        let mut merged12 = node1.clone();
+        merged12.merge(&node2).unwrap();
+        let mut merged21 = node2.clone();
+        merged21.merge(&node1).unwrap();
+        assert_eq!(merged12.state_hash(), merged21.state_hash());
+
+        let mut different = MVRegister::<u32, DefaultConfig>::new(1);
+        different.set(11, 1000).unwrap();
+        assert_ne!(node1.state_hash(), different.state_hash());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut register = MVRegister::<u16, DefaultConfig>::new(1);
+        register.set(10, 1000).unwrap();
+        let snap = register.snapshot();
+        assert!(!register.has_changed_since(&snap));
+
+        let mut other = MVRegister::<u16, DefaultConfig>::new(2);
+        other.set(20, 1000).unwrap();
+        register.merge(&other).unwrap();
+        assert!(register.has_changed_since(&snap));
+
+        let snap = register.snapshot();
+        register.merge(&other).unwrap();
+        assert!(!register.has_changed_since(&snap));
+    }
+
+    #[test]
+    fn test_quorum_resolution() {
+        let mut register = MVRegister::<u32, DefaultConfig>::new(1);
+        assert_eq!(register.resolve_by_quorum(1), None);
+
+        register.set(7, 1000).unwrap();
+        for (node, value) in [(2, 9), (3, 7), (4, 9)] {
+            let mut other = MVRegister::<u32, DefaultConfig>::new(node);
+            other.set(value, 1000).unwrap();
+            register.merge(&other).unwrap();
+        }
+        assert_eq!(register.value_agreement_count(&7), 2);
+        assert_eq!(register.value_agreement_count(&9), 2);
+        assert_eq!(register.value_agreement_count(&8), 0);
+
+        // Equal support: the value from the highest node wins, as in resolve_latest
+        assert_eq!(register.resolve_by_quorum(2), Some(&9));
+        assert_eq!(register.resolve_by_quorum(3), None);
+    }
+
+    #[test]
+    fn test_approx_quorum_resolution() {
+        let mut register = MVRegister::<f32, DefaultConfig>::new(1);
+        assert_eq!(register.resolve_by_approx_quorum(0, 1.0), None);
+
+        register.set(23.5, 1000).unwrap();
+        for (node, reading) in [(2, 35.0), (3, 23.6)] {
+            let mut other = MVRegister::<f32, DefaultConfig>::new(node);
+            other.set(reading, 1000).unwrap();
+            register.merge(&other).unwrap();
+        }
+
+        let agreed = register.resolve_by_approx_quorum(2, 1.0).unwrap();
+        assert!((agreed - 23.55).abs() < 1e-4);
+        assert_eq!(register.resolve_by_approx_quorum(3, 1.0), None);
+        assert_eq!(register.resolve_by_approx_quorum(2, 0.05), None);
+
+        // A wide enough tolerance puts every reading in one window
+        let agreed = register.resolve_by_approx_quorum(3, 20.0).unwrap();
+        assert!((agreed - 29.25).abs() < 1e-4);
+
+        // NaN readings do not count towards a quorum
+        let mut faulty = MVRegister::<f32, DefaultConfig>::new(4);
+        faulty.set(f32::NAN, 1000).unwrap();
+        register.merge(&faulty).unwrap();
+        assert_eq!(register.resolve_by_approx_quorum(4, 100.0), None);
+    }
+
+    /// Appends an entry without the one-entry-per-node check, as a decoder would
+    fn push_raw(register: &mut MVRegister<u32, DefaultConfig>, value: u32, ts: u64, node: NodeId) {
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (values, count) = (&mut register.values, &mut register.count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (values, count) = (register.values.get_mut(), register.count.get_mut());
+
+        values[*count] = Some(ValueEntry {
+            value,
+            timestamp: CompactTimestamp::new(ts),
+            node_id: node,
+        });
+        *count += 1;
+    }
+
+    #[test]
+    fn test_clear_dominated() {
+        let mut register = MVRegister::<u32, DefaultConfig>::new(1);
+        push_raw(&mut register, 10, 100, 1);
+        push_raw(&mut register, 20, 200, 2);
+        push_raw(&mut register, 30, 300, 1);
+        assert!(register.validate().is_err());
+        assert_eq!(register.dominated_count(), 1);
+
+        assert_eq!(register.clear_dominated(), 1);
+        assert_eq!(register.len(), 2);
+        assert_eq!(register.get_from_node(1), Some(&30));
+        assert_eq!(register.dominated_count(), 0);
+        assert_eq!(register.clear_dominated(), 0);
+        assert!(register.validate().is_ok());
+
+        // Equal timestamps keep the first entry
+        let mut register = MVRegister::<u32, DefaultConfig>::new(1);
+        push_raw(&mut register, 10, 100, 3);
+        push_raw(&mut register, 11, 100, 3);
+        assert_eq!(register.dominated_count(), 1);
+        assert_eq!(
+            register.compact(),
+            Ok(core::mem::size_of::<Option<ValueEntry<u32>>>())
+        );
+        assert!(register.iter().map(|(value, _, _)| *value).eq([10]));
+    }
+
+    #[test]
+    fn test_merge_clears_dominated() {
+        let mut register = MVRegister::<u32, DefaultConfig>::new(1);
+        push_raw(&mut register, 10, 100, 1);
+        push_raw(&mut register, 30, 300, 1);
+
+        let mut other = MVRegister::<u32, DefaultConfig>::new(2);
+        other.set(20, 200).unwrap();
+        register.merge(&other).unwrap();
+
+        assert_eq!(register.len(), 2);
+        assert_eq!(register.dominated_count(), 0);
+        assert_eq!(register.get_from_node(1), Some(&30));
+        assert_eq!(register.get_from_node(2), Some(&20));
+    }
+
+    #[test]
+    fn test_merge_and_get_matches_merge() {
+        let writes = [None, Some(500), Some(1000), Some(1500)];
+        for local_write in writes {
+            for remote_write in writes {
+                for remote_node in [1, 2] {
+                    let mut local = MVRegister::<u32, DefaultConfig>::new(1);
+                    let mut remote = MVRegister::<u32, DefaultConfig>::new(remote_node);
+                    if let Some(ts) = local_write {
+                        local.set(10, ts).unwrap();
+                    }
+                    if let Some(ts) = remote_write {
+                        remote.set(20, ts).unwrap();
+                    }
+
+                    let mut expected = local.clone();
+                    expected.merge(&remote).unwrap();
+
+                    let mut merged = local.clone();
+                    assert_eq!(
+                        merged.merge_and_get(&remote).unwrap(),
+                        expected.resolve_latest()
+                    );
+
+                    let mut merged = local.clone();
+                    let meta = merged.merge_and_get_with_metadata(&remote).unwrap();
+                    assert_eq!(meta.map(|(v, _, _)| v), expected.resolve_latest());
+
+                    let mut target = local.clone();
+                    let changed = remote.merge_into(&mut target).unwrap();
+                    assert!(target.eq(&expected));
+                    assert_eq!(changed, !local.eq(&expected));
+                }
+            }
+        }
+    }
+
     #[cfg(all(test, feature = "serde"))]
     mod serde_tests {
         use super::*;
@@ -0,0 +1,459 @@
+//! Causal Multi-Value Register CRDT
+//!
+//! A multi-value register that stamps every write with a [`VectorClock`]
+//! instead of a scalar timestamp. Values are only kept side by side when
+//! their writes were truly concurrent, so clock skew between nodes cannot
+//! produce false conflicts.
+
+use crate::clock::{CompactTimestamp, VectorClock};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::traits::{BoundedCRDT, CRDT, MemoryBreakdown, RealTimeCRDT, Snapshotable};
+
+/// Multi-Value Register with vector clock causality
+///
+/// Every [`set`](Self::set) advances this node's component of the register's
+/// vector clock and replaces all values the node has observed. On merge, a
+/// value whose clock happens before another value's clock is dominated and
+/// evicted; values with concurrent clocks are all kept, up to CAPACITY.
+///
+/// # Type Parameters
+/// - `T`: The value type stored in the register
+/// - `C`: Memory configuration
+/// - `N`: The number of nodes tracked by each vector clock (defaults to 16)
+/// - `CAPACITY`: The maximum number of concurrent values (defaults to 4)
+///
+/// # Memory Usage
+/// - Fixed size: (sizeof(T) + 4 * N) * CAPACITY + 4 * N + 16 bytes
+/// - Example: For u32 with 16 nodes and 4 values = ~360 bytes
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut ecu_a = MVRegisterCausal::<u8, DefaultConfig>::new(1);
+/// let mut ecu_b = MVRegisterCausal::<u8, DefaultConfig>::new(2);
+///
+/// ecu_a.set(3)?;
+/// ecu_b.merge(&ecu_a)?;
+///
+/// // Node 2 has seen node 1's write, so its write replaces it everywhere
+/// ecu_b.set(4)?;
+/// ecu_a.merge(&ecu_b)?;
+/// assert_eq!(ecu_a.len(), 1);
+/// assert_eq!(ecu_a.iter().next(), Some(&4));
+/// assert_eq!(ecu_a.dominated_count(), 1);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct MVRegisterCausal<T, C: MemoryConfig, const N: usize = 16, const CAPACITY: usize = 4> {
+    /// Values with their causal context, packed into the first `count` slots
+    values: [Option<CausalEntry<T, C, N>>; CAPACITY],
+    count: usize,
+
+    /// Everything this replica has observed
+    clock: VectorClock<C, N>,
+
+    /// Entries evicted as dominated by the most recent merge
+    dominated: usize,
+
+    /// This node's ID
+    node_id: NodeId,
+}
+
+/// Value entry with the vector clock of its write
+#[derive(Debug, Clone)]
+struct CausalEntry<T, C: MemoryConfig, const N: usize> {
+    value: T,
+    clock: VectorClock<C, N>,
+}
+
+impl<T, C: MemoryConfig, const N: usize, const CAPACITY: usize> MVRegisterCausal<T, C, N, CAPACITY>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new causal register with custom clock size and capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < N)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let register = MVRegisterCausal::<f32, DefaultConfig, 8, 2>::with_capacity(1);
+    /// assert_eq!(register.capacity(), 2);
+    /// ```
+    pub fn with_capacity(node_id: NodeId) -> Self {
+        Self {
+            values: [const { None }; CAPACITY],
+            count: 0,
+            clock: VectorClock::new(),
+            dominated: 0,
+            node_id,
+        }
+    }
+
+    /// Sets a new value, replacing every value this node has observed
+    ///
+    /// # Arguments
+    /// * `value` - The new value to set
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::NodeCountExceeded` if this node's
+    /// ID is not tracked by the vector clock
+    pub fn set(&mut self, value: T) -> CRDTResult<()> {
+        if CAPACITY == 0 {
+            return Err(CRDTError::BufferOverflow);
+        }
+        self.clock.increment(self.node_id)?;
+
+        // The new clock dominates every stored entry
+        self.values = [const { None }; CAPACITY];
+        self.values[0] = Some(CausalEntry {
+            value,
+            clock: self.clock,
+        });
+        self.count = 1;
+        Ok(())
+    }
+
+    /// Iterates over the current values
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.entries().map(|entry| &entry.value)
+    }
+
+    /// Iterates over the current values with the vector clocks of their writes
+    pub fn iter_entries(&self) -> impl Iterator<Item = (&T, &VectorClock<C, N>)> {
+        self.entries().map(|entry| (&entry.value, &entry.clock))
+    }
+
+    /// Checks if this replica and another have each seen writes the other has not
+    ///
+    /// Replicas with equal clocks are not concurrent.
+    pub fn is_concurrent_with(&self, other: &Self) -> bool {
+        self.clock.concurrent_with(&other.clock)
+    }
+
+    /// Returns the number of entries evicted as dominated by the most recent merge
+    ///
+    /// An entry is dominated when its clock happens before the clock of
+    /// another entry on either side of the merge.
+    pub fn dominated_count(&self) -> usize {
+        self.dominated
+    }
+
+    /// Returns the vector clock of everything this replica has observed
+    pub fn clock(&self) -> &VectorClock<C, N> {
+        &self.clock
+    }
+
+    /// Returns the number of concurrent values
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Checks if the register is empty (never set)
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Checks if the register holds CAPACITY concurrent values
+    pub fn is_full(&self) -> bool {
+        self.count >= CAPACITY
+    }
+
+    /// Returns the maximum number of concurrent values
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Iterates over the stored entries
+    fn entries(&self) -> impl Iterator<Item = &CausalEntry<T, C, N>> {
+        self.values[..self.count].iter().flatten()
+    }
+
+    /// Checks if `clock` happens before the clock of any entry in either register
+    fn is_dominated(&self, other: &Self, clock: &VectorClock<C, N>) -> bool {
+        self.entries()
+            .chain(other.entries())
+            .any(|entry| clock.happens_before(&entry.clock))
+    }
+}
+
+impl<T, C: MemoryConfig> MVRegisterCausal<T, C, 16, 4>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new causal register with default clock size and capacity
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < 16)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let register = MVRegisterCausal::<u16, DefaultConfig>::new(1);
+    /// assert!(register.is_empty());
+    /// ```
+    pub fn new(node_id: NodeId) -> Self {
+        Self::with_capacity(node_id)
+    }
+}
+
+impl<T, C: MemoryConfig, const N: usize, const CAPACITY: usize> CRDT<C>
+    for MVRegisterCausal<T, C, N, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        let mut merged = [const { None }; CAPACITY];
+        let mut count = 0;
+        let mut dominated = 0;
+
+        for candidate in self.entries().chain(other.entries()) {
+            if self.is_dominated(other, &candidate.clock) {
+                dominated += 1;
+                continue;
+            }
+
+            // Both sides may hold the same write
+            let duplicate = merged[..count]
+                .iter()
+                .flatten()
+                .any(|entry: &CausalEntry<T, C, N>| entry.clock == candidate.clock);
+            if duplicate {
+                continue;
+            }
+
+            if count >= CAPACITY {
+                return Err(CRDTError::BufferOverflow);
+            }
+            merged[count] = Some(candidate.clone());
+            count += 1;
+        }
+
+        self.values = merged;
+        self.count = count;
+        self.dominated = dominated;
+        self.clock.merge(&other.clock);
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count
+            && self.entries().all(|entry| {
+                other.entries().any(|other_entry| {
+                    entry.clock == other_entry.clock && entry.value == other_entry.value
+                })
+            })
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        if self.node_id as usize >= N {
+            return Err(CRDTError::InvalidNodeId);
+        }
+
+        if self.count > CAPACITY {
+            return Err(CRDTError::InvalidState);
+        }
+
+        for entry in self.entries() {
+            // Every write must be covered by the replica clock and none may
+            // be dominated by another stored write
+            let covered = entry.clock == self.clock || entry.clock.happens_before(&self.clock);
+            if !covered || self.is_dominated(self, &entry.clock) {
+                return Err(CRDTError::InvalidState);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        let stamps = self.snapshot();
+        (stamps as u32) ^ ((stamps >> 32) as u32) ^ (self.count as u32)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        let mut survivors = 0;
+        for (index, candidate) in self.entries().chain(other.entries()).enumerate() {
+            let duplicate = self
+                .entries()
+                .chain(other.entries())
+                .take(index)
+                .any(|entry| entry.clock == candidate.clock);
+            if !duplicate && !self.is_dominated(other, &candidate.clock) {
+                survivors += 1;
+            }
+        }
+        survivors <= CAPACITY
+    }
+}
+
+impl<T, C: MemoryConfig, const N: usize, const CAPACITY: usize> BoundedCRDT<C>
+    for MVRegisterCausal<T, C, N, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+{
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY; // Maximum number of concurrent values
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.count
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Dominated entries are already evicted on merge
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.element_count() < Self::MAX_ELEMENTS
+    }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        let value = core::mem::size_of::<T>() + core::mem::size_of::<VectorClock<C, N>>();
+        let slot = core::mem::size_of::<Option<CausalEntry<T, C, N>>>();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            self.count * value,
+            0,
+            (CAPACITY - self.count) * slot,
+        )
+    }
+}
+
+impl<T, C: MemoryConfig, const N: usize, const CAPACITY: usize> RealTimeCRDT<C>
+    for MVRegisterCausal<T, C, N, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+{
+    const MAX_MERGE_CYCLES: u32 = 400; // Quadratic in values, linear in clock size
+    const MAX_VALIDATE_CYCLES: u32 = 200;
+    const MAX_SERIALIZE_CYCLES: u32 = 150;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        // Merge is bounded by 2 * CAPACITY entries of N counters each
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For this simple implementation, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For this simple implementation, we don't track budget
+    }
+}
+
+impl<T, C: MemoryConfig, const N: usize, const CAPACITY: usize> Snapshotable<C>
+    for MVRegisterCausal<T, C, N, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+{
+    /// XOR of the projected clocks of the stored writes
+    type Snapshot = u64;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.entries().fold(0, |hash, entry| {
+            hash ^ CompactTimestamp::from(entry.clock).as_u64()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_set_replaces_observed_values() {
+        let mut register = MVRegisterCausal::<u32, DefaultConfig, 4, 2>::with_capacity(1);
+        register.set(10).unwrap();
+        register.set(20).unwrap();
+
+        assert_eq!(register.len(), 1);
+        assert_eq!(register.iter().next(), Some(&20));
+        assert_eq!(register.clock().get(1), 2);
+        assert!(register.validate().is_ok());
+
+        let mut untracked = MVRegisterCausal::<u32, DefaultConfig, 4, 2>::with_capacity(4);
+        assert_eq!(untracked.set(1), Err(CRDTError::NodeCountExceeded));
+    }
+
+    #[test]
+    fn test_concurrent_writes_are_kept() {
+        let mut a = MVRegisterCausal::<u32, DefaultConfig>::new(1);
+        let mut b = MVRegisterCausal::<u32, DefaultConfig>::new(2);
+        a.set(1).unwrap();
+        b.set(2).unwrap();
+        assert!(a.is_concurrent_with(&b));
+
+        let mut ab = a.clone();
+        ab.merge(&b).unwrap();
+        let mut ba = b.clone();
+        ba.merge(&a).unwrap();
+
+        assert_eq!(ab.len(), 2);
+        assert_eq!(ab.dominated_count(), 0);
+        assert!(ab.eq(&ba));
+        assert_eq!(ab.state_hash(), ba.state_hash());
+        assert!(!ab.is_concurrent_with(&ba));
+
+        // Idempotent
+        let before = ab.clone();
+        ab.merge(&before).unwrap();
+        assert!(ab.eq(&before));
+        assert!(ab.validate().is_ok());
+    }
+
+    #[test]
+    fn test_causally_later_write_evicts_older() {
+        let mut a = MVRegisterCausal::<u32, DefaultConfig>::new(1);
+        let mut b = MVRegisterCausal::<u32, DefaultConfig>::new(2);
+        let mut c = MVRegisterCausal::<u32, DefaultConfig>::new(3);
+
+        a.set(1).unwrap();
+        b.merge(&a).unwrap();
+        b.set(2).unwrap();
+
+        // Node 3 receives the newer write first, then the stale one
+        c.merge(&b).unwrap();
+        c.merge(&a).unwrap();
+        assert_eq!(c.len(), 1);
+        assert_eq!(c.iter().next(), Some(&2));
+        assert_eq!(c.dominated_count(), 1);
+    }
+
+    #[test]
+    fn test_merge_overflow() {
+        let mut a = MVRegisterCausal::<u32, DefaultConfig, 4, 1>::with_capacity(1);
+        let mut b = MVRegisterCausal::<u32, DefaultConfig, 4, 1>::with_capacity(2);
+        a.set(1).unwrap();
+        b.set(2).unwrap();
+
+        assert!(!a.can_merge(&b));
+        assert_eq!(a.merge(&b), Err(CRDTError::BufferOverflow));
+        assert_eq!(a.iter().next(), Some(&1));
+    }
+}
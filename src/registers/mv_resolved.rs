@@ -0,0 +1,414 @@
+//! Comparator-Resolved Multi-Value Register CRDT
+//!
+//! A register that resolves conflicting writes at merge time with an
+//! application-supplied comparator, instead of keeping them side by side
+//! like [`MVRegister`](crate::registers::MVRegister) does.
+//!
+//! # Tradeoff
+//! Conflicts are resolved eagerly: the losing write is discarded during
+//! merge and can never be recovered, so the "concurrent writes preserved"
+//! guarantee of the standard multi-value register is lost. In exchange the
+//! register holds a single value, needs no capacity, and never requires a
+//! later `resolve_*` call.
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+use crate::clock::{CompactTimestamp, FnvHasher, StateHash};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT, Snapshotable};
+
+/// Decides which of two conflicting writes a [`MVRegisterResolved`] keeps
+///
+/// Called as `comparator(value_a, node_a, timestamp_a, value_b, node_b,
+/// timestamp_b)`; the write that compares greater wins.
+pub type Comparator<T> = fn(&T, NodeId, u64, &T, NodeId, u64) -> Ordering;
+
+/// Multi-Value Register that resolves conflicts with a comparator on merge
+///
+/// Merging two replicas keeps only the write the comparator ranks higher.
+/// Writes the comparator ranks `Equal` are ordered by timestamp, then node
+/// ID, so every replica picks the same winner.
+///
+/// The comparator must be a total order (in particular transitive) and
+/// every replica must use the same one, otherwise replicas may converge to
+/// different values. It is a function pointer rather than a closure so the
+/// register stays `Copy` for `Copy` values.
+///
+/// A local [`set`](Self::set) always replaces the held value. Whether it
+/// survives on other replicas depends on the comparator.
+///
+/// # Type Parameters
+/// - `T`: The value type stored in the register
+/// - `C`: Memory configuration
+///
+/// # Memory Usage
+/// - Fixed size: sizeof(T) + 8 (timestamp) + 1 (node) + 8 (comparator) + padding
+///
+/// # Example
+/// ```rust
+/// use core::cmp::Ordering;
+/// use crdtosphere::memory::NodeId;
+/// use crdtosphere::prelude::*;
+///
+/// /// Keeps the reading from the drone with the lowest battery
+/// fn lowest_battery(a: &u8, _: NodeId, _: u64, b: &u8, _: NodeId, _: u64) -> Ordering {
+///     b.cmp(a)
+/// }
+///
+/// let mut drone1 = MVRegisterResolved::<u8, DefaultConfig>::new(1, lowest_battery);
+/// let mut drone2 = MVRegisterResolved::<u8, DefaultConfig>::new(2, lowest_battery);
+/// drone1.set(40, 1000)?;
+/// drone2.set(15, 900)?;
+///
+/// drone1.merge(&drone2)?;
+/// assert_eq!(drone1.get(), Some(&15));
+/// assert_eq!(drone1.current_node(), 2);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug)]
+pub struct MVRegisterResolved<T, C: MemoryConfig> {
+    /// The winning write, if any
+    entry: Option<ResolvedEntry<T>>,
+
+    /// Conflict resolution rule
+    comparator: Comparator<T>,
+
+    /// This node's ID
+    node_id: NodeId,
+
+    /// Phantom data to maintain the memory config type
+    _phantom: PhantomData<C>,
+}
+
+/// The held value with the stamp of its write
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ResolvedEntry<T> {
+    value: T,
+    timestamp: CompactTimestamp,
+    node_id: NodeId,
+}
+
+impl<T: Clone, C: MemoryConfig> Clone for MVRegisterResolved<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            entry: self.entry.clone(),
+            comparator: self.comparator,
+            node_id: self.node_id,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy, C: MemoryConfig> Copy for MVRegisterResolved<T, C> {}
+
+impl<T, C: MemoryConfig> MVRegisterResolved<T, C>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new empty register
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    /// * `comparator` - Picks the winner of two conflicting writes; must be
+    ///   the same on every replica
+    pub const fn new(node_id: NodeId, comparator: Comparator<T>) -> Self {
+        Self {
+            entry: None,
+            comparator,
+            node_id,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets a new value, replacing the held one
+    ///
+    /// # Arguments
+    /// * `value` - The new value to set
+    /// * `timestamp` - The timestamp for this update
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::InvalidNodeId` if this node's ID
+    /// is out of range
+    pub fn set(&mut self, value: T, timestamp: u64) -> CRDTResult<()> {
+        if self.node_id as usize >= C::MAX_NODES {
+            return Err(CRDTError::InvalidNodeId);
+        }
+        self.entry = Some(ResolvedEntry {
+            value,
+            timestamp: CompactTimestamp::new(timestamp),
+            node_id: self.node_id,
+        });
+        Ok(())
+    }
+
+    /// Gets the current value
+    ///
+    /// # Returns
+    /// The winning value, or None if no value has been set
+    pub fn get(&self) -> Option<&T> {
+        self.entry.as_ref().map(|entry| &entry.value)
+    }
+
+    /// Gets the current value with its timestamp and author
+    ///
+    /// # Returns
+    /// The winning (value, timestamp, node ID), or None if no value has been set
+    pub fn get_with_metadata(&self) -> Option<(&T, u64, NodeId)> {
+        self.entry
+            .as_ref()
+            .map(|entry| (&entry.value, entry.timestamp.as_u64(), entry.node_id))
+    }
+
+    /// Gets the timestamp of the current value
+    ///
+    /// # Returns
+    /// The timestamp, or zero if no value has been set
+    pub fn timestamp(&self) -> CompactTimestamp {
+        self.entry
+            .as_ref()
+            .map_or(CompactTimestamp::zero(), |entry| entry.timestamp)
+    }
+
+    /// Gets the node ID that wrote the current value
+    ///
+    /// # Returns
+    /// The author's node ID, or this node's ID if no value has been set
+    pub fn current_node(&self) -> NodeId {
+        self.entry
+            .as_ref()
+            .map_or(self.node_id, |entry| entry.node_id)
+    }
+
+    /// Checks if the register is empty (never set)
+    pub fn is_empty(&self) -> bool {
+        self.entry.is_none()
+    }
+
+    /// Returns this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Returns the conflict resolution rule
+    pub fn comparator(&self) -> Comparator<T> {
+        self.comparator
+    }
+
+    /// Checks if `other` beats `entry` under the comparator
+    fn beats(&self, other: &ResolvedEntry<T>, entry: &ResolvedEntry<T>) -> bool {
+        (self.comparator)(
+            &other.value,
+            other.node_id,
+            other.timestamp.as_u64(),
+            &entry.value,
+            entry.node_id,
+            entry.timestamp.as_u64(),
+        )
+        .then_with(|| (other.timestamp, other.node_id).cmp(&(entry.timestamp, entry.node_id)))
+            == Ordering::Greater
+    }
+}
+
+impl<T, C: MemoryConfig> CRDT<C> for MVRegisterResolved<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        if let Some(theirs) = &other.entry {
+            let take = match &self.entry {
+                Some(ours) => self.beats(theirs, ours),
+                None => true,
+            };
+            if take {
+                self.entry = Some(theirs.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.entry == other.entry
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        if self.node_id as usize >= C::MAX_NODES {
+            return Err(CRDTError::InvalidNodeId);
+        }
+        if let Some(entry) = &self.entry {
+            if entry.node_id as usize >= C::MAX_NODES {
+                return Err(CRDTError::InvalidNodeId);
+            }
+        }
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        // FNV-1a over the value, its timestamp and its author
+        let mut hasher = FnvHasher::new();
+        if let Some(entry) = &self.entry {
+            hasher.write_value(&entry.value);
+            hasher.write_u64(entry.timestamp.as_u64());
+            hasher.write_bytes(&[entry.node_id]);
+        }
+        hasher.finish()
+    }
+
+    fn can_merge(&self, _other: &Self) -> bool {
+        // A single slot never overflows
+        true
+    }
+}
+
+impl<T, C: MemoryConfig> BoundedCRDT<C> for MVRegisterResolved<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = 1; // Only the winning value
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        usize::from(self.entry.is_some())
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Losing writes are dropped on merge
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        // Writes replace the held value
+        true
+    }
+}
+
+impl<T, C: MemoryConfig> RealTimeCRDT<C> for MVRegisterResolved<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_MERGE_CYCLES: u32 = 100; // One comparator call
+    const MAX_VALIDATE_CYCLES: u32 = 50;
+    const MAX_SERIALIZE_CYCLES: u32 = 75;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For this simple implementation, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For this simple implementation, we don't track budget
+    }
+}
+
+impl<T, C: MemoryConfig> Snapshotable<C> for MVRegisterResolved<T, C>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    /// The stamp of the current write
+    type Snapshot = (CompactTimestamp, NodeId);
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.timestamp(), self.current_node())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    /// Keeps the lowest value
+    fn lowest(a: &u8, _: NodeId, _: u64, b: &u8, _: NodeId, _: u64) -> Ordering {
+        b.cmp(a)
+    }
+
+    /// Ranks every write equal, leaving the tie-break to decide
+    fn indifferent(_: &u8, _: NodeId, _: u64, _: &u8, _: NodeId, _: u64) -> Ordering {
+        Ordering::Equal
+    }
+
+    type Register = MVRegisterResolved<u8, DefaultConfig>;
+
+    #[test]
+    fn test_comparator_picks_winner() {
+        let mut a = Register::new(1, lowest);
+        let mut b = Register::new(2, lowest);
+        let mut c = Register::new(3, lowest);
+        assert!(a.is_empty());
+        a.set(40, 1000).unwrap();
+        b.set(15, 900).unwrap();
+        c.set(25, 1100).unwrap();
+
+        // Every merge order converges on the lowest value
+        let mut abc = a;
+        abc.merge(&b).unwrap();
+        abc.merge(&c).unwrap();
+        let mut cba = c;
+        cba.merge(&b).unwrap();
+        cba.merge(&a).unwrap();
+        let mut bc_a = b;
+        bc_a.merge(&c).unwrap();
+        let mut a_bc = a;
+        a_bc.merge(&bc_a).unwrap();
+
+        for register in [abc, cba, a_bc] {
+            assert_eq!(register.get_with_metadata(), Some((&15, 900, 2)));
+            assert!(register.eq(&abc));
+            assert_eq!(register.state_hash(), abc.state_hash());
+        }
+
+        // Idempotent, and a local write still replaces the held value
+        abc.merge(&abc.clone()).unwrap();
+        assert_eq!(abc.get(), Some(&15));
+        abc.set(60, 1200).unwrap();
+        assert_eq!(abc.get(), Some(&60));
+        assert_eq!(abc.current_node(), 1);
+        assert_eq!(abc.element_count(), 1);
+        assert!(abc.validate().is_ok());
+    }
+
+    #[test]
+    fn test_equal_ranking_falls_back_to_stamp() {
+        let mut a = Register::new(1, indifferent);
+        let mut b = Register::new(2, indifferent);
+        a.set(1, 1000).unwrap();
+        b.set(2, 1000).unwrap();
+
+        let snap = a.snapshot();
+        let mut ab = a;
+        ab.merge(&b).unwrap();
+        let mut ba = b;
+        ba.merge(&a).unwrap();
+        assert_eq!(ab.get(), Some(&2)); // Same timestamp, higher node ID
+        assert!(ab.eq(&ba));
+        assert!(ab.has_changed_since(&snap));
+
+        // A newer write wins when the comparator has no preference
+        a.set(3, 2000).unwrap();
+        ab.merge(&a).unwrap();
+        assert_eq!(ab.get(), Some(&3));
+
+        let mut invalid = Register::new(DefaultConfig::MAX_NODES as NodeId, indifferent);
+        assert_eq!(invalid.set(1, 1000), Err(CRDTError::InvalidNodeId));
+        assert!(invalid.validate().is_err());
+    }
+}
@@ -0,0 +1,334 @@
+//! Distributed Resource Leases for Multi-Robot Systems
+//!
+//! This module implements a lease CRDT that lets robots claim a shared
+//! resource, such as a task or a narrow corridor, for a limited time.
+//! Claims are replicated with last-writer-wins semantics, so concurrent
+//! claims converge on a single holder once replicas have merged.
+
+use crate::counters::GCounter;
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Time-limited exclusive claim on a shared resource
+///
+/// The holder and its expiry are written together with the claim time as
+/// the LWW timestamp, so merge keeps the claim with the latest timestamp
+/// (the higher replica ID breaks ties). `lease_version` counts the claims
+/// granted across all replicas.
+///
+/// # Exclusion Guarantee
+/// This provides probabilistic exclusion, not strong mutual exclusion.
+/// Two partitioned robots can both be granted the lease and will only
+/// learn which claim won once they merge. Keep leases short and re-check
+/// [`is_held_by`](Self::is_held_by) after merging before committing to
+/// irreversible actions.
+///
+/// # Type Parameters
+/// - `C`: Memory configuration
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::robotics::ResourceLease;
+///
+/// // Both robots try to pick up the same box
+/// let mut robot1 = ResourceLease::<DefaultConfig>::new(1);
+/// let mut robot2 = ResourceLease::<DefaultConfig>::new(2);
+/// assert!(robot1.try_claim(1, 5000, 1000)?);
+/// assert!(robot2.try_claim(2, 5000, 1100)?);
+///
+/// // After merging, the later claim wins on both robots
+/// robot1.merge(&robot2)?;
+/// robot2.merge(&robot1)?;
+/// assert_eq!(robot1.current_holder(1200), Some(2));
+/// assert!(robot2.is_held_by(2, 1200));
+///
+/// // Robot 1 has to wait until the lease is released or expires
+/// assert!(!robot1.try_claim(1, 5000, 1200)?);
+/// robot2.release(2, 2000)?;
+/// robot1.merge(&robot2)?;
+/// assert!(robot1.try_claim(1, 5000, 2100)?);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResourceLease<C: MemoryConfig> {
+    /// Robot that made the latest claim
+    holder: LWWRegister<NodeId, C>,
+    /// Time at which the latest claim stops being valid
+    expiry_timestamp: LWWRegister<u64, C>,
+    /// Number of claims granted
+    lease_version: GCounter<C>,
+}
+
+impl<C: MemoryConfig> ResourceLease<C> {
+    /// Creates a new, unclaimed lease
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this replica
+    ///
+    /// # Returns
+    /// A new resource lease CRDT
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            holder: LWWRegister::new(node_id),
+            expiry_timestamp: LWWRegister::new(node_id),
+            lease_version: GCounter::new(node_id),
+        }
+    }
+
+    /// Tries to claim the lease
+    ///
+    /// The claim is granted if nobody holds the lease at `current_time` or
+    /// if `claimer` already holds it, in which case the lease is renewed.
+    ///
+    /// # Arguments
+    /// * `claimer` - The robot claiming the lease
+    /// * `duration_ms` - How long the lease is valid
+    /// * `current_time` - The claim time, also used as the LWW timestamp
+    ///
+    /// # Returns
+    /// Ok(true) if the claim was granted, Ok(false) if another robot holds
+    /// the lease or a newer claim already exists, or an error if the
+    /// version counter fails
+    pub fn try_claim(
+        &mut self,
+        claimer: NodeId,
+        duration_ms: u64,
+        current_time: u64,
+    ) -> CRDTResult<bool> {
+        if self
+            .current_holder(current_time)
+            .is_some_and(|holder| holder != claimer)
+        {
+            return Ok(false);
+        }
+
+        let expiry = current_time.saturating_add(duration_ms);
+        self.holder.set(claimer, current_time)?;
+        self.expiry_timestamp.set(expiry, current_time)?;
+        if self.holder.get() != Some(&claimer) || self.expiry() != Some(expiry) {
+            // A claim with a newer timestamp was already merged in
+            return Ok(false);
+        }
+
+        self.lease_version.increment(1)?;
+        Ok(true)
+    }
+
+    /// Checks if a robot holds a valid lease
+    ///
+    /// # Arguments
+    /// * `node_id` - The robot to check
+    /// * `current_time` - The current time
+    ///
+    /// # Returns
+    /// true if `node_id` holds the lease and it has not expired
+    pub fn is_held_by(&self, node_id: NodeId, current_time: u64) -> bool {
+        self.current_holder(current_time) == Some(node_id)
+    }
+
+    /// Releases the lease held by a robot
+    ///
+    /// The release is recorded as an expiry at `timestamp`, which must be
+    /// later than the claim for it to win the merge. Releasing a lease that
+    /// `node_id` does not hold is a no-op.
+    ///
+    /// # Arguments
+    /// * `node_id` - The robot giving up the lease
+    /// * `timestamp` - The release time
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error otherwise
+    pub fn release(&mut self, node_id: NodeId, timestamp: u64) -> CRDTResult<()> {
+        if !self.is_held_by(node_id, timestamp) {
+            return Ok(());
+        }
+
+        self.holder.set(node_id, timestamp)?;
+        self.expiry_timestamp.set(timestamp, timestamp)?;
+        Ok(())
+    }
+
+    /// Gets the robot holding a valid lease
+    ///
+    /// # Arguments
+    /// * `current_time` - The current time
+    ///
+    /// # Returns
+    /// The holder, or None if the lease was never claimed, has expired or
+    /// was released
+    pub fn current_holder(&self, current_time: u64) -> Option<NodeId> {
+        let expiry = *self.expiry_timestamp.get()?;
+        if current_time >= expiry {
+            return None;
+        }
+        self.holder.get().copied()
+    }
+
+    /// Gets the expiry time of the latest claim
+    ///
+    /// # Returns
+    /// The expiry time, or None if the lease was never claimed
+    pub fn expiry(&self) -> Option<u64> {
+        self.expiry_timestamp.get().copied()
+    }
+
+    /// Gets the number of claims granted across all replicas
+    pub fn lease_version(&self) -> u64 {
+        self.lease_version.value()
+    }
+
+    /// Gets this replica's ID
+    pub fn node_id(&self) -> NodeId {
+        self.lease_version.node_id()
+    }
+}
+
+impl<C: MemoryConfig> CRDT<C> for ResourceLease<C> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.holder.merge(&other.holder)?;
+        self.expiry_timestamp.merge(&other.expiry_timestamp)?;
+        self.lease_version.merge(&other.lease_version)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.holder.eq(&other.holder)
+            && self.expiry_timestamp.eq(&other.expiry_timestamp)
+            && self.lease_version.eq(&other.lease_version)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.holder.validate()?;
+        self.expiry_timestamp.validate()?;
+        self.lease_version.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.holder.state_hash()
+            ^ self.expiry_timestamp.state_hash().rotate_left(8)
+            ^ self.lease_version.state_hash().rotate_left(16)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.lease_version.can_merge(&other.lease_version)
+    }
+}
+
+impl<C: MemoryConfig> BoundedCRDT<C> for ResourceLease<C> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = 1; // Single holder
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        if self.holder.is_empty() { 0 } else { 1 }
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Registers and counters cannot be compacted
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        // The holder can always be replaced
+        true
+    }
+}
+
+impl<C: MemoryConfig> RealTimeCRDT<C> for ResourceLease<C> {
+    const MAX_MERGE_CYCLES: u32 = 2 * <LWWRegister<NodeId, C> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES
+        + <GCounter<C> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES;
+    const MAX_VALIDATE_CYCLES: u32 = 2
+        * <LWWRegister<NodeId, C> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES
+        + <GCounter<C> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES;
+    const MAX_SERIALIZE_CYCLES: u32 = 2
+        * <LWWRegister<NodeId, C> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES
+        + <GCounter<C> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For this simple implementation, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For this simple implementation, we don't track budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_claim_expire_and_release() {
+        let mut lease = ResourceLease::<DefaultConfig>::new(1);
+        assert_eq!(lease.current_holder(0), None);
+        assert_eq!(lease.expiry(), None);
+
+        assert!(lease.try_claim(1, 500, 1000).unwrap());
+        assert!(lease.is_held_by(1, 1499));
+        assert!(!lease.is_held_by(1, 1500));
+        assert_eq!(lease.expiry(), Some(1500));
+
+        // Another robot waits for expiry; the holder can renew
+        assert!(!lease.try_claim(2, 500, 1200).unwrap());
+        assert!(lease.try_claim(1, 500, 1400).unwrap());
+        assert_eq!(lease.current_holder(1600), Some(1));
+        assert!(lease.try_claim(2, 500, 1900).unwrap());
+        assert_eq!(lease.current_holder(2000), Some(2));
+        assert_eq!(lease.lease_version(), 3);
+
+        // Only the holder can release
+        lease.release(1, 2000).unwrap();
+        assert_eq!(lease.current_holder(2000), Some(2));
+        lease.release(2, 2100).unwrap();
+        assert_eq!(lease.current_holder(2100), None);
+        assert!(lease.try_claim(1, 500, 2200).unwrap());
+
+        // A claim older than the latest write is not granted
+        assert!(!lease.try_claim(1, 500, 1000).unwrap());
+        assert_eq!(lease.lease_version(), 4);
+    }
+
+    #[test]
+    fn test_concurrent_claims_converge() {
+        let mut a = ResourceLease::<DefaultConfig>::new(1);
+        let mut b = ResourceLease::<DefaultConfig>::new(2);
+        assert!(a.try_claim(1, 1000, 5000).unwrap());
+        assert!(b.try_claim(2, 1000, 5000).unwrap());
+
+        let mut ab = a.clone();
+        ab.merge(&b).unwrap();
+        let mut ba = b.clone();
+        ba.merge(&a).unwrap();
+
+        // Same claim time: the higher replica ID wins
+        assert_eq!(ab.current_holder(5500), Some(2));
+        assert!(ab.eq(&ba));
+        assert_eq!(ab.state_hash(), ba.state_hash());
+        assert_eq!(ab.lease_version(), 2);
+        assert!(ab.validate().is_ok());
+
+        // The merged replica sees the lease as taken
+        assert!(!ab.try_claim(1, 1000, 5500).unwrap());
+    }
+}
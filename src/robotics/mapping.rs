@@ -6,6 +6,7 @@
 use crate::clock::CompactTimestamp;
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
 /// Map point types
@@ -551,6 +552,213 @@ impl<C: MemoryConfig> RealTimeCRDT<C> for SharedMap<C> {
     }
 }
 
+/// Occupancy grid shared between robots
+///
+/// Each cell holds an occupancy estimate (0 = free, 255 = occupied) in its own
+/// LWW register, so the latest observation of a cell wins regardless of which
+/// robot made it. Dimensions are const generics, so the grid's memory is fixed
+/// at compile time and must fit within `C::TOTAL_CRDT_MEMORY`.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::robotics::OccupancyGrid;
+///
+/// let mut grid1 = OccupancyGrid::<DefaultConfig, 4, 4>::new(1);
+/// grid1.update_cell(0, 0, 0, 1000)?; // Free
+///
+/// let mut grid2 = OccupancyGrid::<DefaultConfig, 4, 4>::new(2);
+/// grid2.update_cell(0, 1, 255, 1100)?; // Occupied
+///
+/// grid1.merge(&grid2)?;
+/// assert_eq!(grid1.get_cell(0, 1), 255);
+///
+/// // (0, 0) is free and borders unexplored cells
+/// assert_eq!(grid1.frontier_cells().next(), Some((0, 0)));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct OccupancyGrid<C: MemoryConfig, const ROWS: usize, const COLS: usize> {
+    /// Occupancy registers, indexed by row then column
+    cells: [[LWWRegister<u8, C>; COLS]; ROWS],
+    /// This robot's ID
+    local_robot_id: NodeId,
+}
+
+impl<C: MemoryConfig, const ROWS: usize, const COLS: usize> OccupancyGrid<C, ROWS, COLS> {
+    /// Memory taken by the cell registers
+    pub const CELL_MEMORY: usize = ROWS * COLS * core::mem::size_of::<LWWRegister<u8, C>>();
+
+    /// Occupancy reported for cells no robot has observed
+    pub const UNKNOWN: u8 = 128;
+
+    /// Highest occupancy still treated as free space
+    pub const FREE_THRESHOLD: u8 = 127;
+
+    /// Creates a new occupancy grid with every cell unobserved
+    ///
+    /// Fails to compile if the cells exceed `C::TOTAL_CRDT_MEMORY`.
+    ///
+    /// # Arguments
+    /// * `robot_id` - The ID of this robot
+    ///
+    /// # Returns
+    /// A new occupancy grid CRDT
+    pub fn new(robot_id: NodeId) -> Self {
+        const {
+            assert!(
+                Self::CELL_MEMORY <= C::TOTAL_CRDT_MEMORY,
+                "occupancy grid exceeds the configured CRDT memory"
+            )
+        };
+
+        Self {
+            cells: core::array::from_fn(|_| core::array::from_fn(|_| LWWRegister::new(robot_id))),
+            local_robot_id: robot_id,
+        }
+    }
+
+    /// Records an occupancy observation for a cell
+    ///
+    /// # Arguments
+    /// * `row` - Cell row
+    /// * `col` - Cell column
+    /// * `value` - Occupancy (0 = free, 255 = occupied)
+    /// * `timestamp` - Observation timestamp
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::InvalidOperation` if the cell is
+    /// outside the grid
+    pub fn update_cell(
+        &mut self,
+        row: usize,
+        col: usize,
+        value: u8,
+        timestamp: u64,
+    ) -> CRDTResult<()> {
+        if row >= ROWS || col >= COLS {
+            return Err(CRDTError::InvalidOperation);
+        }
+        self.cells[row][col].set(value, timestamp)
+    }
+
+    /// Gets the occupancy of a cell
+    ///
+    /// # Returns
+    /// The latest observed occupancy, or [`UNKNOWN`](Self::UNKNOWN) if the
+    /// cell was never observed or is outside the grid
+    pub fn get_cell(&self, row: usize, col: usize) -> u8 {
+        self.observed(row, col).unwrap_or(Self::UNKNOWN)
+    }
+
+    /// Checks if any robot has observed a cell
+    pub fn is_observed(&self, row: usize, col: usize) -> bool {
+        self.observed(row, col).is_some()
+    }
+
+    /// Returns the free cells that border unobserved space
+    ///
+    /// These are the frontier cells of frontier-based exploration: a robot
+    /// driving to one of them uncovers new parts of the map. A cell counts as
+    /// free when its occupancy is at most [`FREE_THRESHOLD`](Self::FREE_THRESHOLD),
+    /// and neighbours are the four cells sharing an edge with it.
+    ///
+    /// # Returns
+    /// Iterator over `(row, col)` pairs in row-major order
+    pub fn frontier_cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..ROWS)
+            .flat_map(|row| (0..COLS).map(move |col| (row, col)))
+            .filter(move |&(row, col)| self.is_frontier(row, col))
+    }
+
+    /// Gets this robot's ID
+    pub fn robot_id(&self) -> NodeId {
+        self.local_robot_id
+    }
+
+    /// Returns the grid dimensions as `(rows, cols)`
+    pub fn dimensions(&self) -> (usize, usize) {
+        (ROWS, COLS)
+    }
+
+    /// Returns the observed occupancy of a cell inside the grid
+    fn observed(&self, row: usize, col: usize) -> Option<u8> {
+        if row >= ROWS || col >= COLS {
+            return None;
+        }
+        self.cells[row][col].get().copied()
+    }
+
+    /// Checks if a cell is free and has an unobserved neighbour
+    fn is_frontier(&self, row: usize, col: usize) -> bool {
+        if self
+            .observed(row, col)
+            .is_none_or(|value| value > Self::FREE_THRESHOLD)
+        {
+            return false;
+        }
+
+        let unobserved = |row: Option<usize>, col: Option<usize>| match (row, col) {
+            (Some(row), Some(col)) if row < ROWS && col < COLS => !self.is_observed(row, col),
+            _ => false,
+        };
+
+        unobserved(row.checked_sub(1), Some(col))
+            || unobserved(Some(row + 1), Some(col))
+            || unobserved(Some(row), col.checked_sub(1))
+            || unobserved(Some(row), Some(col + 1))
+    }
+}
+
+impl<C: MemoryConfig, const ROWS: usize, const COLS: usize> CRDT<C>
+    for OccupancyGrid<C, ROWS, COLS>
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        for (row, other_row) in self.cells.iter_mut().zip(other.cells.iter()) {
+            for (cell, other_cell) in row.iter_mut().zip(other_row.iter()) {
+                cell.merge(other_cell)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.cells
+            .iter()
+            .flatten()
+            .zip(other.cells.iter().flatten())
+            .all(|(cell, other_cell)| cell.eq(other_cell))
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        if self.local_robot_id as usize >= C::MAX_NODES {
+            return Err(CRDTError::InvalidNodeId);
+        }
+        for cell in self.cells.iter().flatten() {
+            cell.validate()?;
+        }
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.cells
+            .iter()
+            .flatten()
+            .fold(0u32, |hash, cell| hash.rotate_left(5) ^ cell.state_hash())
+    }
+
+    fn can_merge(&self, _other: &Self) -> bool {
+        // Cells are fixed, so any two grids of the same size can merge
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -737,4 +945,89 @@ mod tests {
         assert!(map1.merge_bounded(&map2).is_ok());
         assert!(map1.validate_bounded().is_ok());
     }
+
+    #[test]
+    fn test_occupancy_grid_cells() {
+        let mut grid = OccupancyGrid::<DefaultConfig, 3, 4>::new(1);
+        assert_eq!(grid.dimensions(), (3, 4));
+        assert_eq!(
+            grid.get_cell(1, 1),
+            OccupancyGrid::<DefaultConfig, 3, 4>::UNKNOWN
+        );
+        assert!(!grid.is_observed(1, 1));
+
+        grid.update_cell(1, 1, 200, 1000).unwrap();
+        assert_eq!(grid.get_cell(1, 1), 200);
+        assert!(grid.is_observed(1, 1));
+
+        // Older observations lose
+        grid.update_cell(1, 1, 10, 900).unwrap();
+        assert_eq!(grid.get_cell(1, 1), 200);
+
+        // Out of range cells
+        assert_eq!(
+            grid.update_cell(3, 0, 0, 1000),
+            Err(CRDTError::InvalidOperation)
+        );
+        assert_eq!(
+            grid.update_cell(0, 4, 0, 1000),
+            Err(CRDTError::InvalidOperation)
+        );
+        assert!(!grid.is_observed(3, 0));
+        assert!(grid.validate().is_ok());
+    }
+
+    #[test]
+    fn test_occupancy_grid_merge() {
+        let mut grid1 = OccupancyGrid::<DefaultConfig, 2, 2>::new(1);
+        let mut grid2 = OccupancyGrid::<DefaultConfig, 2, 2>::new(2);
+
+        grid1.update_cell(0, 0, 0, 1000).unwrap();
+        grid1.update_cell(1, 1, 255, 1000).unwrap();
+        grid2.update_cell(1, 1, 0, 2000).unwrap(); // Obstacle moved away
+        grid2.update_cell(0, 1, 255, 1500).unwrap();
+
+        let mut merged1 = grid1.clone();
+        merged1.merge(&grid2).unwrap();
+        let mut merged2 = grid2.clone();
+        merged2.merge(&grid1).unwrap();
+
+        assert!(merged1.eq(&merged2));
+        assert_eq!(merged1.state_hash(), merged2.state_hash());
+        assert_eq!(merged1.get_cell(0, 0), 0);
+        assert_eq!(merged1.get_cell(0, 1), 255);
+        assert_eq!(merged1.get_cell(1, 1), 0);
+        assert!(!merged1.is_observed(1, 0));
+    }
+
+    #[test]
+    fn test_occupancy_grid_frontier() {
+        let mut grid = OccupancyGrid::<DefaultConfig, 3, 3>::new(1);
+        assert_eq!(grid.frontier_cells().count(), 0);
+
+        // Explored the top row: two free cells and an obstacle
+        grid.update_cell(0, 0, 0, 1000).unwrap();
+        grid.update_cell(0, 1, 20, 1000).unwrap();
+        grid.update_cell(0, 2, 255, 1000).unwrap();
+
+        let mut frontier = [(0, 0); 2];
+        for (slot, cell) in frontier.iter_mut().zip(grid.frontier_cells()) {
+            *slot = cell;
+        }
+        assert_eq!(frontier, [(0, 0), (0, 1)]);
+        assert_eq!(grid.frontier_cells().count(), 2);
+
+        // Exploring the middle row moves the frontier down
+        for col in 0..3 {
+            grid.update_cell(1, col, 0, 2000).unwrap();
+        }
+        assert!(grid.frontier_cells().all(|(row, _)| row == 1));
+        assert_eq!(grid.frontier_cells().count(), 3);
+
+        // A fully explored grid has no frontier
+        for col in 0..3 {
+            grid.update_cell(2, col, 0, 3000).unwrap();
+        }
+        assert_eq!(grid.frontier_cells().count(), 0);
+    }
 }
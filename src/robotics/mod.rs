@@ -3,11 +3,22 @@
 //! This module provides CRDTs specifically designed for multi-robot coordination,
 //! focusing on distributed state synchronization between robots.
 
+pub mod lease;
 pub mod mapping;
+pub mod pose;
 pub mod signals;
 pub mod status;
 
 // Re-export main types
-pub use mapping::{MapData, MapPoint, MapPointType, SharedMap};
-pub use signals::{CoordinationSignals, Signal, SignalPriority, SignalType};
-pub use status::{BatteryLevel, OperationalMode, Position3D, RobotStatus};
+pub use lease::ResourceLease;
+pub use mapping::{MapData, MapPoint, MapPointType, OccupancyGrid, SharedMap};
+pub use pose::PoseCRDT;
+pub use signals::{
+    CoordinationSignals, MAX_SIGNAL_DEPENDENCIES, Signal, SignalDependency, SignalPriority,
+    SignalType,
+};
+pub use status::{
+    BatteryLevel, CAPABILITY_ARM, CAPABILITY_CAMERA, CAPABILITY_GNSS, CAPABILITY_GRIPPER,
+    CAPABILITY_LIDAR, FleetCapabilityView, FleetHealthView, MAX_CAPABILITIES, OperationalMode,
+    Position3D, RobotStatus,
+};
@@ -0,0 +1,444 @@
+//! Robot Pose Estimation with Uncertainty
+//!
+//! This module implements a pose CRDT that pairs a last-writer-wins position
+//! with a scalar uncertainty estimate, so robots can share localization
+//! results and fuse overlapping observations of the same pose.
+
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::robotics::status::Position3D;
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Robot Pose CRDT
+///
+/// Holds the most recent position estimate (millimeters) together with its
+/// uncertainty as a standard deviation in meters. An empty pose has infinite
+/// uncertainty.
+///
+/// Merging keeps the position with the latest timestamp and the smaller of
+/// the two uncertainties, so repeated exchanges never make the shared
+/// estimate look less certain than any replica has reported.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::robotics::{PoseCRDT, Position3D};
+///
+/// let mut lidar = PoseCRDT::<DefaultConfig>::new(1);
+/// lidar.update(Position3D::new(1000, 2000, 0), 0.2, 100)?;
+///
+/// let mut odometry = PoseCRDT::<DefaultConfig>::new(2);
+/// odometry.update(Position3D::new(1100, 2000, 0), 0.2, 100)?;
+///
+/// // Fusing two equally uncertain estimates yields a tighter one
+/// lidar.fuse_with_weight(&odometry, 200)?;
+/// let (position, uncertainty) = lidar.position_with_uncertainty().unwrap();
+/// assert_eq!(position, Position3D::new(1050, 2000, 0));
+/// assert!(uncertainty < 0.2);
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PoseCRDT<C: MemoryConfig> {
+    /// Latest position estimate
+    position: LWWRegister<Position3D, C>,
+    /// Standard deviation of the estimate in meters
+    uncertainty: f32,
+    /// This node's ID
+    node_id: NodeId,
+}
+
+impl<C: MemoryConfig> PoseCRDT<C> {
+    /// Creates a new empty pose for the given robot
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            position: LWWRegister::new(node_id),
+            uncertainty: f32::INFINITY,
+            node_id,
+        }
+    }
+
+    /// Records a new local pose estimate
+    ///
+    /// The uncertainty replaces the current one only if the position write
+    /// wins, so a stale estimate cannot overwrite a fresher uncertainty.
+    pub fn update(
+        &mut self,
+        position: Position3D,
+        uncertainty_m: f32,
+        timestamp: u64,
+    ) -> CRDTResult<()> {
+        if uncertainty_m.is_nan() || uncertainty_m < 0.0 {
+            return Err(CRDTError::InvalidState);
+        }
+
+        self.position.set(position, timestamp)?;
+
+        if self.position.current_node() == self.node_id
+            && self.position.timestamp().as_u64() == timestamp
+        {
+            self.uncertainty = uncertainty_m;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the current position estimate
+    pub fn position(&self) -> Option<Position3D> {
+        self.position.get().copied()
+    }
+
+    /// Returns the uncertainty of the current estimate in meters
+    pub fn uncertainty(&self) -> f32 {
+        self.uncertainty
+    }
+
+    /// Returns the current position together with its uncertainty
+    pub fn position_with_uncertainty(&self) -> Option<(Position3D, f32)> {
+        self.position().map(|position| (position, self.uncertainty))
+    }
+
+    /// Returns the timestamp of the current estimate
+    pub fn timestamp(&self) -> u64 {
+        self.position.timestamp().as_u64()
+    }
+
+    /// Checks whether two pose estimates agree within the given distance
+    ///
+    /// Returns false if either pose has no estimate yet.
+    pub fn is_within_tolerance(&self, other: &Self, tolerance_m: f32) -> bool {
+        match (self.position(), other.position()) {
+            (Some(a), Some(b)) => {
+                let tolerance_mm = tolerance_m * 1000.0;
+                (a.distance_squared(&b) as f32) <= tolerance_mm * tolerance_mm
+            }
+            _ => false,
+        }
+    }
+
+    /// Fuses another estimate into this one using inverse-variance weighting
+    ///
+    /// This is the scalar Kalman update: each position is weighted by the
+    /// other estimate's variance, and the fused variance is
+    /// `σa²σb² / (σa² + σb²)`, which is never larger than either input.
+    /// The result is written at `timestamp`, which must be newer than both
+    /// estimates so that the fused pose wins on every replica.
+    pub fn fuse_with_weight(&mut self, other: &Self, timestamp: u64) -> CRDTResult<()> {
+        let theirs = match other.position() {
+            Some(position) => position,
+            None => return Ok(()),
+        };
+
+        if timestamp <= self.timestamp() || timestamp <= other.timestamp() {
+            return Err(CRDTError::ClockSkew);
+        }
+
+        let ours = match self.position() {
+            Some(position) => position,
+            None => return self.update(theirs, other.uncertainty, timestamp),
+        };
+
+        let var_a = self.uncertainty * self.uncertainty;
+        let var_b = other.uncertainty * other.uncertainty;
+
+        // Gain applied to the other estimate; infinite variance means no information
+        let (gain, fused_uncertainty) = match (var_a.is_infinite(), var_b.is_infinite()) {
+            (true, true) => (0.5, f32::INFINITY),
+            (true, false) => (1.0, other.uncertainty),
+            (false, true) => (0.0, self.uncertainty),
+            (false, false) if var_a + var_b == 0.0 => (0.5, 0.0),
+            (false, false) => {
+                let sum = var_a + var_b;
+                (var_a / sum, Self::sqrt(var_a * var_b / sum))
+            }
+        };
+
+        // Round to the nearest millimeter (no float rounding intrinsics in no_std)
+        let blend = |a: i32, b: i32| -> i32 {
+            let delta = (b - a) as f32 * gain;
+            let rounded = if delta >= 0.0 {
+                delta + 0.5
+            } else {
+                delta - 0.5
+            };
+            a + rounded as i32
+        };
+        let fused = Position3D::new(
+            blend(ours.x, theirs.x),
+            blend(ours.y, theirs.y),
+            blend(ours.z, theirs.z),
+        );
+
+        self.update(fused, fused_uncertainty, timestamp)
+    }
+
+    /// Returns this node's ID
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Square root via Newton's method (no_std friendly)
+    fn sqrt(value: f32) -> f32 {
+        if value <= 0.0 {
+            return 0.0;
+        }
+
+        let mut x = value;
+        for _ in 0..10 {
+            x = 0.5 * (x + value / x);
+        }
+        x
+    }
+}
+
+impl<C: MemoryConfig> CRDT<C> for PoseCRDT<C> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.position.merge(&other.position)?;
+
+        // Keep the tightest uncertainty either replica has reported
+        if other.uncertainty < self.uncertainty {
+            self.uncertainty = other.uncertainty;
+        }
+
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.position.eq(&other.position)
+            && self.uncertainty.to_bits() == other.uncertainty.to_bits()
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        if self.uncertainty.is_nan() || self.uncertainty < 0.0 {
+            return Err(CRDTError::InvalidState);
+        }
+
+        self.position.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.position.state_hash() ^ self.uncertainty.to_bits()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.position.can_merge(&other.position)
+    }
+}
+
+impl<C: MemoryConfig> BoundedCRDT<C> for PoseCRDT<C> {
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = 1; // Single pose estimate
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        if self.position.is_empty() { 0 } else { 1 }
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Single pose cannot be compacted
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        // Updates always replace the existing estimate
+        true
+    }
+}
+
+impl<C: MemoryConfig> RealTimeCRDT<C> for PoseCRDT<C> {
+    const MAX_MERGE_CYCLES: u32 = 50; // Register merge plus a float compare
+    const MAX_VALIDATE_CYCLES: u32 = 25;
+    const MAX_SERIALIZE_CYCLES: u32 = 40;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        // Pose merge is constant time
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For robotics systems, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For robotics systems, we don't limit budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_pose_creation() {
+        let pose = PoseCRDT::<DefaultConfig>::new(1);
+
+        assert_eq!(pose.node_id(), 1);
+        assert!(pose.position_with_uncertainty().is_none());
+        assert!(pose.uncertainty().is_infinite());
+        assert_eq!(pose.element_count(), 0);
+    }
+
+    #[test]
+    fn test_update_and_stale_update() {
+        let mut pose = PoseCRDT::<DefaultConfig>::new(1);
+
+        pose.update(Position3D::new(100, 200, 0), 0.5, 1000)
+            .unwrap();
+        assert_eq!(
+            pose.position_with_uncertainty(),
+            Some((Position3D::new(100, 200, 0), 0.5))
+        );
+
+        // Older estimate loses and leaves the uncertainty alone
+        pose.update(Position3D::new(0, 0, 0), 0.1, 500).unwrap();
+        assert_eq!(
+            pose.position_with_uncertainty(),
+            Some((Position3D::new(100, 200, 0), 0.5))
+        );
+
+        assert!(pose.update(Position3D::new(0, 0, 0), -1.0, 2000).is_err());
+        assert!(
+            pose.update(Position3D::new(0, 0, 0), f32::NAN, 2000)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_merge_takes_latest_position_and_min_uncertainty() {
+        let mut pose1 = PoseCRDT::<DefaultConfig>::new(1);
+        let mut pose2 = PoseCRDT::<DefaultConfig>::new(2);
+
+        pose1.update(Position3D::new(100, 0, 0), 0.1, 1000).unwrap();
+        pose2.update(Position3D::new(200, 0, 0), 0.4, 2000).unwrap();
+
+        let mut merged12 = pose1.clone();
+        merged12.merge(&pose2).unwrap();
+        let mut merged21 = pose2.clone();
+        merged21.merge(&pose1).unwrap();
+
+        assert_eq!(
+            merged12.position_with_uncertainty(),
+            Some((Position3D::new(200, 0, 0), 0.1))
+        );
+        assert!(merged12.eq(&merged21));
+
+        // Idempotent
+        let before = merged12.clone();
+        merged12.merge(&before).unwrap();
+        assert!(merged12.eq(&before));
+    }
+
+    #[test]
+    fn test_is_within_tolerance() {
+        let mut pose1 = PoseCRDT::<DefaultConfig>::new(1);
+        let mut pose2 = PoseCRDT::<DefaultConfig>::new(2);
+
+        assert!(!pose1.is_within_tolerance(&pose2, 1.0));
+
+        pose1.update(Position3D::new(0, 0, 0), 0.1, 1000).unwrap();
+        pose2
+            .update(Position3D::new(300, 400, 0), 0.1, 1000)
+            .unwrap();
+
+        // 500mm apart
+        assert!(pose1.is_within_tolerance(&pose2, 0.5));
+        assert!(!pose1.is_within_tolerance(&pose2, 0.49));
+    }
+
+    #[test]
+    fn test_fuse_equal_uncertainty_reduces_uncertainty() {
+        let mut pose1 = PoseCRDT::<DefaultConfig>::new(1);
+        let mut pose2 = PoseCRDT::<DefaultConfig>::new(2);
+
+        pose1
+            .update(Position3D::new(1000, 0, 500), 0.2, 1000)
+            .unwrap();
+        pose2
+            .update(Position3D::new(2000, 100, 500), 0.2, 1000)
+            .unwrap();
+
+        pose1.fuse_with_weight(&pose2, 1500).unwrap();
+
+        let (position, uncertainty) = pose1.position_with_uncertainty().unwrap();
+        assert_eq!(position, Position3D::new(1500, 50, 500));
+        assert!(uncertainty < 0.2);
+        // σ / sqrt(2) for two equal estimates
+        assert!((uncertainty - 0.2 / core::f32::consts::SQRT_2).abs() < 1e-4);
+        assert_eq!(pose1.timestamp(), 1500);
+    }
+
+    #[test]
+    fn test_fuse_weights_toward_more_certain_estimate() {
+        let mut pose1 = PoseCRDT::<DefaultConfig>::new(1);
+        let mut pose2 = PoseCRDT::<DefaultConfig>::new(2);
+
+        pose1.update(Position3D::new(0, 0, 0), 0.3, 1000).unwrap();
+        pose2
+            .update(Position3D::new(1000, 0, 0), 0.1, 1000)
+            .unwrap();
+
+        pose1.fuse_with_weight(&pose2, 2000).unwrap();
+
+        // Variances 0.09 and 0.01 give a gain of 0.9 toward pose2
+        let (position, uncertainty) = pose1.position_with_uncertainty().unwrap();
+        assert_eq!(position, Position3D::new(900, 0, 0));
+        assert!(uncertainty < 0.1);
+    }
+
+    #[test]
+    fn test_fuse_edge_cases() {
+        let mut pose1 = PoseCRDT::<DefaultConfig>::new(1);
+        let mut pose2 = PoseCRDT::<DefaultConfig>::new(2);
+
+        // Fusing an empty pose is a no-op
+        pose1.fuse_with_weight(&pose2, 100).unwrap();
+        assert!(pose1.position().is_none());
+
+        // Fusing into an empty pose adopts the other estimate
+        pose2
+            .update(Position3D::new(10, 20, 30), 0.2, 1000)
+            .unwrap();
+        pose1.fuse_with_weight(&pose2, 1100).unwrap();
+        assert_eq!(
+            pose1.position_with_uncertainty(),
+            Some((Position3D::new(10, 20, 30), 0.2))
+        );
+
+        // Fused timestamp must be newer than both inputs
+        assert_eq!(
+            pose1.fuse_with_weight(&pose2, 1100),
+            Err(CRDTError::ClockSkew)
+        );
+    }
+
+    #[test]
+    fn test_validation_and_traits() {
+        let mut pose = PoseCRDT::<DefaultConfig>::new(1);
+        assert!(pose.validate().is_ok());
+
+        pose.update(Position3D::new(1, 2, 3), 0.0, 10).unwrap();
+        assert!(pose.validate().is_ok());
+        assert_eq!(pose.element_count(), 1);
+        assert!(pose.can_add_element());
+        assert_eq!(pose.compact().unwrap(), 0);
+        assert!(pose.remaining_budget().is_none());
+
+        pose.uncertainty = f32::NAN;
+        assert!(pose.validate().is_err());
+    }
+}
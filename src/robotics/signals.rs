@@ -3,11 +3,15 @@
 //! This module implements CRDTs for simple coordination signals and flags
 //! between robots, enabling lightweight distributed coordination patterns.
 
-use crate::clock::CompactTimestamp;
+use crate::clock::{CompactTimestamp, FnvHasher, StateHash};
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::{MemoryConfig, NodeId};
+use crate::sets::GSet;
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
+/// Maximum number of dependencies between signals
+pub const MAX_SIGNAL_DEPENDENCIES: usize = 32;
+
 /// Types of coordination signals
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
@@ -134,6 +138,32 @@ impl Signal {
         let timeout = self.priority.timeout_seconds() as u64 * 1000; // Convert to milliseconds
         current_time > self.timestamp.as_u64() + timeout
     }
+
+    /// Returns the signal ID used for dependencies
+    ///
+    /// Dependencies refer to signals by their data payload, which carries
+    /// the task or signal ID in dependency-tracked workflows.
+    pub fn id(&self) -> u32 {
+        self.data
+    }
+}
+
+/// Dependency of one signal on another
+///
+/// With priority inheritance enabled, `signal` is handled at least at the
+/// priority of `depends_on`, see [`CoordinationSignals::effective_priority`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignalDependency {
+    /// ID of the dependent signal
+    pub signal: u32,
+    /// ID of the signal it depends on
+    pub depends_on: u32,
+}
+
+impl StateHash for SignalDependency {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        (self.signal, self.depends_on).hash_into(hasher);
+    }
 }
 
 /// Multi-robot coordination signals CRDT
@@ -150,6 +180,7 @@ impl Signal {
 /// - Priority-based signal handling
 /// - Automatic signal expiration
 /// - Signal acknowledgment tracking
+/// - Optional priority inheritance along signal dependencies
 ///
 /// # Example
 /// ```rust
@@ -182,6 +213,10 @@ pub struct CoordinationSignals<C: MemoryConfig> {
     local_robot_id: NodeId,
     /// Last update timestamp
     last_update: CompactTimestamp,
+    /// Dependencies between signals
+    dependencies: GSet<SignalDependency, C, MAX_SIGNAL_DEPENDENCIES>,
+    /// Whether signals inherit the priority of their dependencies
+    priority_inheritance: bool,
     /// Phantom data for memory config
     _phantom: core::marker::PhantomData<C>,
 }
@@ -195,11 +230,29 @@ impl<C: MemoryConfig> CoordinationSignals<C> {
     /// # Returns
     /// A new coordination signals CRDT
     pub fn new(robot_id: NodeId) -> Self {
+        Self::with_priority_inheritance(robot_id, false)
+    }
+
+    /// Creates a new coordination signals CRDT with priority inheritance
+    ///
+    /// Priority inheritance is a local scheduling choice and is not
+    /// replicated; dependencies are replicated either way.
+    ///
+    /// # Arguments
+    /// * `robot_id` - The ID of this robot
+    /// * `enabled` - Whether [`effective_priority`](Self::effective_priority)
+    ///   follows signal dependencies
+    ///
+    /// # Returns
+    /// A new coordination signals CRDT
+    pub fn with_priority_inheritance(robot_id: NodeId, enabled: bool) -> Self {
         Self {
             signals: [const { None }; 32],
             signal_count: 0,
             local_robot_id: robot_id,
             last_update: CompactTimestamp::new(0),
+            dependencies: GSet::with_capacity(),
+            priority_inheritance: enabled,
             _phantom: core::marker::PhantomData,
         }
     }
@@ -334,6 +387,133 @@ impl<C: MemoryConfig> CoordinationSignals<C> {
         self.signal_count
     }
 
+    /// Records that one signal depends on another
+    ///
+    /// # Arguments
+    /// * `signal` - ID of the dependent signal
+    /// * `depends_on` - ID of the signal it depends on
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `CRDTError::InvalidState` if the dependency
+    /// would close a cycle, or `CRDTError::BufferOverflow` if the dependency
+    /// set is full
+    pub fn register_dependency(&mut self, signal: u32, depends_on: u32) -> CRDTResult<()> {
+        let mut closes_cycle = signal == depends_on;
+        self.walk_dependencies(depends_on, &mut |id| closes_cycle |= id == signal);
+        if closes_cycle {
+            return Err(CRDTError::InvalidState);
+        }
+
+        self.dependencies
+            .insert(SignalDependency { signal, depends_on })?;
+        Ok(())
+    }
+
+    /// Gets all registered dependencies
+    pub fn dependencies(&self) -> impl Iterator<Item = &SignalDependency> {
+        self.dependencies.iter()
+    }
+
+    /// Returns true if priority inheritance is enabled
+    pub fn priority_inheritance(&self) -> bool {
+        self.priority_inheritance
+    }
+
+    /// Gets the priority a signal should be handled at
+    ///
+    /// This is the highest priority among the stored signals with this ID
+    /// and, with priority inheritance enabled, among all signals it depends
+    /// on transitively. Unknown signals have `SignalPriority::Low`.
+    ///
+    /// # Cycles
+    /// The traversal tracks visited dependencies in a stack-allocated
+    /// `[bool; MAX_SIGNAL_DEPENDENCIES]`, so it terminates within
+    /// `MAX_SIGNAL_DEPENDENCIES` levels even if merging concurrent
+    /// dependencies has created a cycle; every signal on the cycle then
+    /// inherits the cycle's highest priority. Such cycles are reported as
+    /// `CRDTError::InvalidState` by [`validate`](CRDT::validate), and
+    /// [`register_dependency`](Self::register_dependency) refuses to create
+    /// one locally.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// use crdtosphere::robotics::{CoordinationSignals, SignalType, SignalPriority};
+    ///
+    /// let mut signals = CoordinationSignals::<DefaultConfig>::with_priority_inheritance(1, true);
+    /// signals.send_signal(SignalType::Start, SignalPriority::Low, 10, 1000, 0)?;
+    /// signals.send_signal(SignalType::Help, SignalPriority::Critical, 20, 1000, 0)?;
+    ///
+    /// // Signal 10 waits on signal 20 and must not be starved
+    /// signals.register_dependency(10, 20)?;
+    /// assert_eq!(signals.effective_priority(10), SignalPriority::Critical);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn effective_priority(&self, signal_id: u32) -> SignalPriority {
+        if !self.priority_inheritance {
+            return self.own_priority(signal_id);
+        }
+
+        let mut priority = SignalPriority::Low;
+        self.walk_dependencies(signal_id, &mut |id| {
+            priority = priority.max(self.own_priority(id));
+        });
+        priority
+    }
+
+    /// Gets the highest priority among the stored signals with an ID
+    fn own_priority(&self, signal_id: u32) -> SignalPriority {
+        self.all_signals()
+            .filter(|signal| signal.id() == signal_id)
+            .map(|signal| signal.priority)
+            .max()
+            .unwrap_or(SignalPriority::Low)
+    }
+
+    /// Calls `visit` for a signal and everything it depends on transitively
+    ///
+    /// # Returns
+    /// true if the traversal ran into a dependency cycle
+    fn walk_dependencies(&self, signal_id: u32, visit: &mut impl FnMut(u32)) -> bool {
+        let mut visited = [false; MAX_SIGNAL_DEPENDENCIES];
+        let mut on_path = [false; MAX_SIGNAL_DEPENDENCIES];
+        visit(signal_id);
+        self.walk_from(signal_id, &mut visited, &mut on_path, visit)
+    }
+
+    /// Depth-first step of [`walk_dependencies`](Self::walk_dependencies)
+    ///
+    /// Each dependency is followed at most once, so the recursion depth is
+    /// bounded by `MAX_SIGNAL_DEPENDENCIES`.
+    fn walk_from(
+        &self,
+        signal_id: u32,
+        visited: &mut [bool; MAX_SIGNAL_DEPENDENCIES],
+        on_path: &mut [bool; MAX_SIGNAL_DEPENDENCIES],
+        visit: &mut impl FnMut(u32),
+    ) -> bool {
+        let mut cycle = false;
+        for (index, dependency) in self.dependencies.iter().enumerate() {
+            if dependency.signal != signal_id {
+                continue;
+            }
+            if on_path[index] {
+                cycle = true;
+                continue;
+            }
+            if visited[index] {
+                continue;
+            }
+
+            visited[index] = true;
+            on_path[index] = true;
+            visit(dependency.depends_on);
+            cycle |= self.walk_from(dependency.depends_on, visited, on_path, visit);
+            on_path[index] = false;
+        }
+        cycle
+    }
+
     /// Cleans up expired signals
     ///
     /// # Arguments
@@ -447,6 +627,13 @@ impl<C: MemoryConfig> CoordinationSignals<C> {
             }
         }
 
+        // Concurrent dependencies may close a cycle once merged
+        for dependency in self.dependencies() {
+            if self.walk_dependencies(dependency.signal, &mut |_| {}) {
+                return Err(CRDTError::InvalidState);
+            }
+        }
+
         Ok(())
     }
 }
@@ -459,6 +646,7 @@ impl<C: MemoryConfig> CRDT<C> for CoordinationSignals<C> {
         for signal in other.all_signals() {
             self.add_signal(*signal)?;
         }
+        self.dependencies.merge(&other.dependencies)?;
 
         // Update timestamp to latest
         if other.last_update > self.last_update {
@@ -469,7 +657,7 @@ impl<C: MemoryConfig> CRDT<C> for CoordinationSignals<C> {
     }
 
     fn eq(&self, other: &Self) -> bool {
-        if self.signal_count != other.signal_count {
+        if self.signal_count != other.signal_count || !self.dependencies.eq(&other.dependencies) {
             return false;
         }
 
@@ -503,6 +691,9 @@ impl<C: MemoryConfig> CRDT<C> for CoordinationSignals<C> {
         for signal in self.all_signals() {
             hash ^= (signal.sender_id as u32) ^ (signal.timestamp.as_u64() as u32) ^ (signal.data);
         }
+        for dependency in self.dependencies() {
+            hash ^= dependency.signal.rotate_left(16) ^ dependency.depends_on;
+        }
         hash ^= self.signal_count as u32;
         hash
     }
@@ -727,6 +918,83 @@ mod tests {
         assert!(signals1.has_help_requests());
     }
 
+    #[test]
+    fn test_priority_inheritance() {
+        let mut signals = CoordinationSignals::<DefaultConfig>::with_priority_inheritance(1, true);
+        signals
+            .send_signal(SignalType::Start, SignalPriority::Low, 1, 1000, 0)
+            .unwrap();
+        signals
+            .send_signal(SignalType::Formation, SignalPriority::Normal, 2, 1000, 0)
+            .unwrap();
+        signals
+            .send_signal(SignalType::Help, SignalPriority::High, 3, 1000, 0)
+            .unwrap();
+
+        // 1 -> 2 -> 3, and 1 -> 3 directly (not a cycle)
+        signals.register_dependency(1, 2).unwrap();
+        signals.register_dependency(2, 3).unwrap();
+        signals.register_dependency(1, 3).unwrap();
+
+        assert_eq!(signals.effective_priority(1), SignalPriority::High);
+        assert_eq!(signals.effective_priority(2), SignalPriority::High);
+        assert_eq!(signals.effective_priority(3), SignalPriority::High);
+        assert_eq!(signals.effective_priority(99), SignalPriority::Low);
+
+        // Without inheritance only the signal's own priority counts
+        let mut plain = CoordinationSignals::<DefaultConfig>::new(2);
+        plain.merge(&signals).unwrap();
+        assert!(!plain.priority_inheritance());
+        assert_eq!(plain.dependencies().count(), 3);
+        assert_eq!(plain.effective_priority(1), SignalPriority::Low);
+    }
+
+    #[test]
+    fn test_dependency_cycles() {
+        let mut signals = CoordinationSignals::<DefaultConfig>::with_priority_inheritance(1, true);
+        assert_eq!(
+            signals.register_dependency(1, 1),
+            Err(CRDTError::InvalidState)
+        );
+        signals.register_dependency(1, 2).unwrap();
+        signals.register_dependency(2, 3).unwrap();
+        assert_eq!(
+            signals.register_dependency(3, 1),
+            Err(CRDTError::InvalidState)
+        );
+        assert!(signals.validate().is_ok());
+
+        // Two robots close the cycle concurrently
+        let mut other = CoordinationSignals::<DefaultConfig>::with_priority_inheritance(2, true);
+        other
+            .send_signal(SignalType::Emergency, SignalPriority::Critical, 3, 1000, 0)
+            .unwrap();
+        other.register_dependency(3, 1).unwrap();
+        signals.merge(&other).unwrap();
+
+        assert_eq!(signals.validate(), Err(CRDTError::InvalidState));
+        // The traversal still terminates, and the cycle shares its maximum
+        assert_eq!(signals.effective_priority(1), SignalPriority::Critical);
+        assert_eq!(signals.effective_priority(2), SignalPriority::Critical);
+    }
+
+    #[test]
+    fn test_dependency_merge() {
+        let mut signals1 = CoordinationSignals::<DefaultConfig>::new(1);
+        let mut signals2 = CoordinationSignals::<DefaultConfig>::new(2);
+        signals1.register_dependency(1, 2).unwrap();
+        signals2.register_dependency(3, 4).unwrap();
+
+        let mut merged1 = signals1.clone();
+        merged1.merge(&signals2).unwrap();
+        let mut merged2 = signals2.clone();
+        merged2.merge(&signals1).unwrap();
+
+        assert_eq!(merged1.dependencies().count(), 2);
+        assert!(merged1.eq(&merged2));
+        assert!(!merged1.eq(&signals1));
+    }
+
     #[test]
     fn test_bounded_crdt_implementation() {
         let mut signals = CoordinationSignals::<DefaultConfig>::new(1);
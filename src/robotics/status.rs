@@ -3,11 +3,27 @@
 //! This module implements CRDTs for sharing robot operational status,
 //! position, and health information across distributed robot networks.
 
-use crate::clock::CompactTimestamp;
+use crate::clock::{CompactTimestamp, FnvHasher, StateHash};
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::{MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::sets::GSet;
 use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
 
+/// Capability ID of a camera
+pub const CAPABILITY_CAMERA: u16 = 0x0001;
+/// Capability ID of a manipulator arm
+pub const CAPABILITY_ARM: u16 = 0x0002;
+/// Capability ID of a LIDAR scanner
+pub const CAPABILITY_LIDAR: u16 = 0x0003;
+/// Capability ID of a gripper
+pub const CAPABILITY_GRIPPER: u16 = 0x0004;
+/// Capability ID of a GNSS receiver
+pub const CAPABILITY_GNSS: u16 = 0x0005;
+
+/// Maximum number of capabilities a robot can advertise
+pub const MAX_CAPABILITIES: usize = 16;
+
 /// Robot operational modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u8)]
@@ -80,6 +96,12 @@ pub struct Position3D {
     pub z: i32,
 }
 
+impl StateHash for Position3D {
+    fn hash_into(&self, hasher: &mut FnvHasher) {
+        (self.x, self.y, self.z).hash_into(hasher);
+    }
+}
+
 impl Position3D {
     /// Creates a new 3D position
     pub fn new(x: i32, y: i32, z: i32) -> Self {
@@ -157,6 +179,7 @@ impl StatusInfo {
 /// - Position-based coordination
 /// - Battery level monitoring
 /// - Operational mode tracking
+/// - Capability advertisement for this robot
 ///
 /// # Example
 /// ```rust
@@ -186,6 +209,10 @@ pub struct RobotStatus<C: MemoryConfig> {
     robot_count: usize,
     /// This robot's ID
     local_robot_id: NodeId,
+    /// Capabilities advertised by this robot
+    capabilities: GSet<u16, C, MAX_CAPABILITIES>,
+    /// Health score of this robot (0 = dead, 255 = perfect)
+    health_score: LWWRegister<u8, C>,
     /// Last update timestamp
     last_update: CompactTimestamp,
     /// Phantom data for memory config
@@ -205,6 +232,8 @@ impl<C: MemoryConfig> RobotStatus<C> {
             robots: [const { None }; 16],
             robot_count: 0,
             local_robot_id: robot_id,
+            capabilities: GSet::with_capacity(),
+            health_score: LWWRegister::new(robot_id),
             last_update: CompactTimestamp::new(0),
             _phantom: core::marker::PhantomData,
         }
@@ -233,6 +262,67 @@ impl<C: MemoryConfig> RobotStatus<C> {
         Ok(())
     }
 
+    /// Advertises a capability of this robot
+    ///
+    /// Capabilities are never withdrawn; see the `CAPABILITY_*` constants
+    /// for well-known IDs.
+    ///
+    /// # Arguments
+    /// * `cap_id` - Capability ID
+    /// * `timestamp` - Update timestamp
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `BufferOverflow` if `MAX_CAPABILITIES`
+    /// capabilities are already advertised
+    pub fn advertise_capability(&mut self, cap_id: u16, timestamp: u64) -> CRDTResult<()> {
+        self.capabilities.insert(cap_id)?;
+        let timestamp = CompactTimestamp::new(timestamp);
+        if timestamp > self.last_update {
+            self.last_update = timestamp;
+        }
+        Ok(())
+    }
+
+    /// Sets the health score of this robot
+    ///
+    /// # Arguments
+    /// * `score` - Health from 0 (dead) to 255 (perfect)
+    /// * `timestamp` - Update timestamp
+    ///
+    /// # Returns
+    /// Ok(()) if successful, error otherwise
+    pub fn set_health_score(&mut self, score: u8, timestamp: u64) -> CRDTResult<()> {
+        self.health_score.set(score, timestamp)?;
+        let timestamp = CompactTimestamp::new(timestamp);
+        if timestamp > self.last_update {
+            self.last_update = timestamp;
+        }
+        Ok(())
+    }
+
+    /// Gets the health score of this robot
+    ///
+    /// # Returns
+    /// The latest score, or None if none has been set
+    pub fn health_score(&self) -> Option<u8> {
+        self.health_score.get().copied()
+    }
+
+    /// Checks if this robot has advertised a capability
+    pub fn has_capability(&self, cap_id: u16) -> bool {
+        self.capabilities.contains(&cap_id)
+    }
+
+    /// Gets the capabilities advertised by this robot
+    pub fn capabilities(&self) -> &GSet<u16, C, MAX_CAPABILITIES> {
+        &self.capabilities
+    }
+
+    /// Gets this robot's ID
+    pub fn robot_id(&self) -> NodeId {
+        self.local_robot_id
+    }
+
     /// Gets status of a specific robot
     ///
     /// # Arguments
@@ -316,14 +406,92 @@ impl<C: MemoryConfig> RobotStatus<C> {
         self.operational_robots().count()
     }
 
+    /// Merges with a nearby robot, preferring this replica's data on near-ties
+    ///
+    /// Behaves like [`merge`](CRDT::merge), except that when `distance_m` is
+    /// below `boost_threshold_m`, each robot entry held here is compared as
+    /// if its timestamp were `timestamp_slack_ms` later. The other robot's
+    /// data then only wins if it is more than the slack newer.
+    ///
+    /// This sacrifices strict CRDT commutativity for application-level
+    /// correctness: two robots boosting against each other can keep
+    /// different entries. Only the robot that owns this replica should use
+    /// it; relays and gateways must use the plain merge.
+    ///
+    /// # Arguments
+    /// * `other` - Status received from the other robot
+    /// * `distance_m` - Distance to the other robot in meters
+    /// * `boost_threshold_m` - Distance below which local data is preferred
+    /// * `timestamp_slack_ms` - How much newer remote data must be to win
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or `CRDTError::BufferOverflow` if there is no
+    /// room for a new robot
+    pub fn merge_with_proximity_boost(
+        &mut self,
+        other: &RobotStatus<C>,
+        distance_m: f32,
+        boost_threshold_m: f32,
+        timestamp_slack_ms: u64,
+    ) -> CRDTResult<()> {
+        let slack = if distance_m < boost_threshold_m {
+            timestamp_slack_ms
+        } else {
+            0
+        };
+        self.merge_from(other, slack)
+    }
+
+    /// Returns this robot's own position if it is fresh enough
+    ///
+    /// # Arguments
+    /// * `current_time_ms` - The current time
+    /// * `max_age_ms` - Maximum age of the position
+    ///
+    /// # Returns
+    /// The position, or None if this robot has no status or it is stale
+    pub fn effective_position(&self, current_time_ms: u64, max_age_ms: u64) -> Option<Position3D> {
+        let status = self.get_robot_status(self.local_robot_id)?;
+        let age = current_time_ms.saturating_sub(status.timestamp.as_u64());
+        (age <= max_age_ms).then_some(status.position)
+    }
+
+    /// Merges another replica, keeping local entries within `slack` of the remote ones
+    fn merge_from(&mut self, other: &Self, slack: u64) -> CRDTResult<()> {
+        // Merge all robot statuses from other
+        for robot in other.all_robots() {
+            self.add_or_update_robot_with_slack(*robot, slack)?;
+        }
+
+        // Capabilities and health belong to the local robot, so only
+        // replicas of the same robot contribute them
+        if other.local_robot_id == self.local_robot_id {
+            self.capabilities.merge(&other.capabilities)?;
+            self.health_score.merge(&other.health_score)?;
+        }
+
+        // Update timestamp to latest
+        if other.last_update > self.last_update {
+            self.last_update = other.last_update;
+        }
+
+        Ok(())
+    }
+
     /// Adds or updates a robot's status
     fn add_or_update_robot(&mut self, status: StatusInfo) -> CRDTResult<()> {
+        self.add_or_update_robot_with_slack(status, 0)
+    }
+
+    /// Adds or updates a robot's status, inflating the existing timestamp by `slack`
+    fn add_or_update_robot_with_slack(&mut self, status: StatusInfo, slack: u64) -> CRDTResult<()> {
         // Find existing robot or empty slot
         for i in 0..16 {
             if let Some(ref mut existing) = self.robots[i] {
                 if existing.robot_id == status.robot_id {
                     // Update if newer timestamp
-                    if status.timestamp > existing.timestamp {
+                    if status.timestamp.as_u64() > existing.timestamp.as_u64().saturating_add(slack)
+                    {
                         *existing = status;
                     }
                     return Ok(());
@@ -370,21 +538,14 @@ impl<C: MemoryConfig> CRDT<C> for RobotStatus<C> {
     type Error = CRDTError;
 
     fn merge(&mut self, other: &Self) -> CRDTResult<()> {
-        // Merge all robot statuses from other
-        for robot in other.all_robots() {
-            self.add_or_update_robot(*robot)?;
-        }
-
-        // Update timestamp to latest
-        if other.last_update > self.last_update {
-            self.last_update = other.last_update;
-        }
-
-        Ok(())
+        self.merge_from(other, 0)
     }
 
     fn eq(&self, other: &Self) -> bool {
-        if self.robot_count != other.robot_count {
+        if self.robot_count != other.robot_count
+            || !self.capabilities.eq(&other.capabilities)
+            || !self.health_score.eq(&other.health_score)
+        {
             return false;
         }
 
@@ -416,6 +577,12 @@ impl<C: MemoryConfig> CRDT<C> for RobotStatus<C> {
             hash ^= (robot.robot_id as u32) ^ (robot.timestamp.as_u64() as u32);
         }
         hash ^= self.robot_count as u32;
+        for cap_id in self.capabilities.iter() {
+            hash ^= (*cap_id as u32).wrapping_mul(0x9E37_79B1);
+        }
+        if let Some(score) = self.health_score() {
+            hash ^= (score as u32) << 24;
+        }
         hash
     }
 
@@ -479,6 +646,362 @@ impl<C: MemoryConfig> RealTimeCRDT<C> for RobotStatus<C> {
     }
 }
 
+/// Capability view over the status replicas of a robot fleet
+///
+/// Holds the latest known [`RobotStatus`] of up to `MAX_ROBOTS` robots,
+/// keyed by robot ID, so a coordinator can find which robots can take a
+/// task and which capabilities the whole fleet shares.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::robotics::{
+///     CAPABILITY_ARM, CAPABILITY_CAMERA, FleetCapabilityView, RobotStatus,
+/// };
+///
+/// let mut scout = RobotStatus::<DefaultConfig>::new(1);
+/// scout.advertise_capability(CAPABILITY_CAMERA, 1000)?;
+/// let mut picker = RobotStatus::<DefaultConfig>::new(2);
+/// picker.advertise_capability(CAPABILITY_CAMERA, 1000)?;
+/// picker.advertise_capability(CAPABILITY_ARM, 1000)?;
+///
+/// let mut fleet = FleetCapabilityView::<DefaultConfig, 4>::new();
+/// fleet.update_robot(&scout)?;
+/// fleet.update_robot(&picker)?;
+///
+/// assert!(fleet.robots_with_capability(CAPABILITY_ARM).eq([2]));
+/// let shared = fleet.intersect_capabilities();
+/// assert!(shared.contains(&CAPABILITY_CAMERA));
+/// assert!(!shared.contains(&CAPABILITY_ARM));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FleetCapabilityView<C: MemoryConfig, const MAX_ROBOTS: usize> {
+    /// Status replica of each known robot
+    robots: [Option<RobotStatus<C>>; MAX_ROBOTS],
+}
+
+impl<C: MemoryConfig, const MAX_ROBOTS: usize> FleetCapabilityView<C, MAX_ROBOTS> {
+    /// Creates an empty fleet view
+    pub fn new() -> Self {
+        Self {
+            robots: [const { None }; MAX_ROBOTS],
+        }
+    }
+
+    /// Merges a robot's status into the view
+    ///
+    /// # Arguments
+    /// * `status` - Status replica of the robot it describes
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `BufferOverflow` if the robot is new and the
+    /// view already holds `MAX_ROBOTS` robots
+    pub fn update_robot(&mut self, status: &RobotStatus<C>) -> CRDTResult<()> {
+        let robot_id = status.robot_id();
+        if let Some(existing) = self.robot_mut(robot_id) {
+            return existing.merge(status);
+        }
+
+        let slot = self
+            .robots
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(CRDTError::BufferOverflow)?;
+        *slot = Some(status.clone());
+        Ok(())
+    }
+
+    /// Gets the status replica of a robot
+    pub fn robot(&self, robot_id: NodeId) -> Option<&RobotStatus<C>> {
+        self.all_robots().find(|robot| robot.robot_id() == robot_id)
+    }
+
+    /// Gets the status replicas of all known robots
+    pub fn all_robots(&self) -> impl Iterator<Item = &RobotStatus<C>> {
+        self.robots.iter().filter_map(|robot| robot.as_ref())
+    }
+
+    /// Gets the number of known robots
+    pub fn robot_count(&self) -> usize {
+        self.all_robots().count()
+    }
+
+    /// Gets the IDs of the robots that advertise a capability
+    pub fn robots_with_capability(&self, cap_id: u16) -> impl Iterator<Item = NodeId> + '_ {
+        self.all_robots()
+            .filter(move |robot| robot.has_capability(cap_id))
+            .map(|robot| robot.robot_id())
+    }
+
+    /// Gets the capabilities advertised by every known robot
+    ///
+    /// # Returns
+    /// The shared capabilities, empty if no robots are known
+    #[cfg_attr(feature = "hardware-atomic", allow(unused_mut))]
+    pub fn intersect_capabilities(&self) -> GSet<u16, C, MAX_CAPABILITIES> {
+        let mut shared = GSet::with_capacity();
+        let mut robots = self.all_robots();
+        if let Some(first) = robots.next() {
+            for cap_id in first.capabilities().iter() {
+                if self.all_robots().all(|robot| robot.has_capability(*cap_id)) {
+                    // Cannot overflow: a subset of one robot's capabilities
+                    let _ = shared.insert(*cap_id);
+                }
+            }
+        }
+        shared
+    }
+
+    /// Finds the mutable status replica of a robot
+    fn robot_mut(&mut self, robot_id: NodeId) -> Option<&mut RobotStatus<C>> {
+        self.robots
+            .iter_mut()
+            .filter_map(|robot| robot.as_mut())
+            .find(|robot| robot.robot_id() == robot_id)
+    }
+}
+
+impl<C: MemoryConfig, const MAX_ROBOTS: usize> Default for FleetCapabilityView<C, MAX_ROBOTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: MemoryConfig, const MAX_ROBOTS: usize> CRDT<C> for FleetCapabilityView<C, MAX_ROBOTS> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        for robot in other.all_robots() {
+            self.update_robot(robot)?;
+        }
+        Ok(())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.robot_count() == other.robot_count()
+            && self.all_robots().all(|robot| {
+                other
+                    .robot(robot.robot_id())
+                    .is_some_and(|other_robot| robot.eq(other_robot))
+            })
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        for robot in self.all_robots() {
+            robot.validate()?;
+        }
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.all_robots().fold(0, |hash, robot| {
+            hash ^ robot.state_hash().rotate_left(robot.robot_id() as u32)
+        })
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        let new_robots = other
+            .all_robots()
+            .filter(|robot| self.robot(robot.robot_id()).is_none())
+            .count();
+        self.robot_count() + new_robots <= MAX_ROBOTS
+            && other.all_robots().all(|robot| {
+                self.robot(robot.robot_id())
+                    .is_none_or(|existing| existing.can_merge(robot))
+            })
+    }
+}
+
+/// Health view over the status replicas of a robot fleet
+///
+/// Holds the latest known [`RobotStatus`] of up to `MAX_ROBOTS` robots, like
+/// [`FleetCapabilityView`], so a coordinator can hand a task to the
+/// healthiest robot. The view also remembers the scores it last reported
+/// from [`degraded_robots`](Self::degraded_robots); that snapshot is local
+/// and does not take part in merges.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::robotics::{FleetHealthView, RobotStatus};
+///
+/// let mut scout = RobotStatus::<DefaultConfig>::new(1);
+/// scout.set_health_score(200, 1000)?;
+/// let mut picker = RobotStatus::<DefaultConfig>::new(2);
+/// picker.set_health_score(120, 1000)?;
+///
+/// let mut fleet = FleetHealthView::<DefaultConfig, 4>::new();
+/// fleet.fleet_merge(&[scout.clone(), picker])?;
+/// assert_eq!(fleet.healthiest_robot(), Some(1));
+/// assert_eq!(fleet.average_fleet_health(), Some(160));
+///
+/// // The scout loses a wheel
+/// assert_eq!(fleet.degraded_robots().count(), 0);
+/// scout.set_health_score(90, 2000)?;
+/// fleet.fleet_merge(&[scout])?;
+/// assert!(fleet.degraded_robots().eq([(1, 90)]));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FleetHealthView<C: MemoryConfig, const MAX_ROBOTS: usize> {
+    /// Status replica of each known robot
+    robots: FleetCapabilityView<C, MAX_ROBOTS>,
+    /// Scores seen by the last `degraded_robots` call
+    last_scores: [Option<(NodeId, u8)>; MAX_ROBOTS],
+}
+
+impl<C: MemoryConfig, const MAX_ROBOTS: usize> FleetHealthView<C, MAX_ROBOTS> {
+    /// Creates an empty fleet view
+    pub fn new() -> Self {
+        Self {
+            robots: FleetCapabilityView::new(),
+            last_scores: [None; MAX_ROBOTS],
+        }
+    }
+
+    /// Merges a robot's status into the view
+    ///
+    /// # Arguments
+    /// * `status` - Status replica of the robot it describes
+    ///
+    /// # Returns
+    /// Ok(()) if successful, `BufferOverflow` if the robot is new and the
+    /// view already holds `MAX_ROBOTS` robots
+    pub fn update_robot(&mut self, status: &RobotStatus<C>) -> CRDTResult<()> {
+        self.robots.update_robot(status)
+    }
+
+    /// Merges several robots' statuses into the view
+    ///
+    /// Each status is merged into the replica of the robot it describes.
+    ///
+    /// # Arguments
+    /// * `statuses` - Status replicas to merge
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or the first error (statuses before it stay merged)
+    pub fn fleet_merge(&mut self, statuses: &[RobotStatus<C>]) -> CRDTResult<()> {
+        for status in statuses {
+            self.update_robot(status)?;
+        }
+        Ok(())
+    }
+
+    /// Gets the status replica of a robot
+    pub fn robot(&self, robot_id: NodeId) -> Option<&RobotStatus<C>> {
+        self.robots.robot(robot_id)
+    }
+
+    /// Gets the number of known robots
+    pub fn robot_count(&self) -> usize {
+        self.robots.robot_count()
+    }
+
+    /// Gets the robot with the highest health score
+    ///
+    /// # Returns
+    /// The robot ID (the lowest one on ties), or None if no robot has reported a score
+    pub fn healthiest_robot(&self) -> Option<NodeId> {
+        self.scores()
+            .max_by_key(|&(robot_id, score)| (score, core::cmp::Reverse(robot_id)))
+            .map(|(robot_id, _)| robot_id)
+    }
+
+    /// Gets the average health score of the robots that reported one
+    ///
+    /// # Returns
+    /// The average rounded down, or None if no robot has reported a score
+    pub fn average_fleet_health(&self) -> Option<u8> {
+        let (sum, count) = self
+            .scores()
+            .fold((0u32, 0u32), |(sum, count), (_, score)| {
+                (sum + score as u32, count + 1)
+            });
+        (count > 0).then(|| (sum / count) as u8)
+    }
+
+    /// Gets the IDs of the robots whose health score is above a threshold
+    pub fn robots_above_threshold(&self, threshold: u8) -> impl Iterator<Item = NodeId> + '_ {
+        self.scores()
+            .filter(move |&(_, score)| score > threshold)
+            .map(|(robot_id, _)| robot_id)
+    }
+
+    /// Gets the robots whose health dropped by more than 20% since the last call
+    ///
+    /// Compares each score with the one seen by the previous call, then
+    /// remembers the current scores for the next one. Robots without an
+    /// earlier score are not reported.
+    ///
+    /// # Returns
+    /// The robot IDs with their current scores
+    pub fn degraded_robots(&mut self) -> impl Iterator<Item = (NodeId, u8)> {
+        let mut degraded = [None; MAX_ROBOTS];
+        let mut current = [None; MAX_ROBOTS];
+        for (slot, (robot_id, score)) in self.scores().enumerate() {
+            let previous = self
+                .last_scores
+                .iter()
+                .flatten()
+                .find(|(id, _)| *id == robot_id)
+                .map(|&(_, previous)| previous);
+            // score < 80% of previous
+            if previous.is_some_and(|previous| (score as u32) * 5 < (previous as u32) * 4) {
+                degraded[slot] = Some((robot_id, score));
+            }
+            current[slot] = Some((robot_id, score));
+        }
+        self.last_scores = current;
+        degraded.into_iter().flatten()
+    }
+
+    /// Gets the health score of every robot that reported one
+    fn scores(&self) -> impl Iterator<Item = (NodeId, u8)> + '_ {
+        self.robots
+            .all_robots()
+            .filter_map(|robot| Some((robot.robot_id(), robot.health_score()?)))
+    }
+}
+
+impl<C: MemoryConfig, const MAX_ROBOTS: usize> Default for FleetHealthView<C, MAX_ROBOTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: MemoryConfig, const MAX_ROBOTS: usize> CRDT<C> for FleetHealthView<C, MAX_ROBOTS> {
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.robots.merge(&other.robots)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.robots.eq(&other.robots)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.robots.validate()
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.robots.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.robots.can_merge(&other.robots)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -674,4 +1197,229 @@ mod tests {
         assert!(status1.merge_bounded(&status2).is_ok());
         assert!(status1.validate_bounded().is_ok());
     }
+
+    #[test]
+    fn test_capability_advertisement() {
+        let mut status = RobotStatus::<DefaultConfig>::new(1);
+        assert!(!status.has_capability(CAPABILITY_LIDAR));
+
+        status.advertise_capability(CAPABILITY_LIDAR, 1000).unwrap();
+        status.advertise_capability(CAPABILITY_LIDAR, 1001).unwrap();
+        assert!(status.has_capability(CAPABILITY_LIDAR));
+        assert_eq!(status.capabilities().len(), 1);
+
+        // A replica of the same robot contributes capabilities
+        let mut replica = RobotStatus::<DefaultConfig>::new(1);
+        replica.advertise_capability(CAPABILITY_GNSS, 1002).unwrap();
+        status.merge(&replica).unwrap();
+        assert!(status.has_capability(CAPABILITY_GNSS));
+
+        // Another robot's capabilities stay its own
+        let mut other = RobotStatus::<DefaultConfig>::new(2);
+        other.advertise_capability(CAPABILITY_ARM, 1003).unwrap();
+        status.merge(&other).unwrap();
+        assert!(!status.has_capability(CAPABILITY_ARM));
+
+        for cap_id in 0x100..0x100 + MAX_CAPABILITIES as u16 - 2 {
+            status.advertise_capability(cap_id, 1004).unwrap();
+        }
+        assert_eq!(
+            status.advertise_capability(0x200, 1005),
+            Err(CRDTError::BufferOverflow)
+        );
+    }
+
+    #[test]
+    fn test_fleet_capability_view() {
+        let mut fleet = FleetCapabilityView::<DefaultConfig, 3>::new();
+        assert!(fleet.intersect_capabilities().is_empty());
+
+        let mut robots = [
+            RobotStatus::<DefaultConfig>::new(1),
+            RobotStatus::<DefaultConfig>::new(2),
+            RobotStatus::<DefaultConfig>::new(3),
+        ];
+        for robot in robots.iter_mut() {
+            robot.advertise_capability(CAPABILITY_CAMERA, 1000).unwrap();
+        }
+        robots[0]
+            .advertise_capability(CAPABILITY_LIDAR, 1000)
+            .unwrap();
+        robots[2]
+            .advertise_capability(CAPABILITY_LIDAR, 1000)
+            .unwrap();
+        robots[2]
+            .advertise_capability(CAPABILITY_ARM, 1000)
+            .unwrap();
+        for robot in robots.iter() {
+            fleet.update_robot(robot).unwrap();
+        }
+
+        let mut lidar = [0; 3];
+        let mut count = 0;
+        for robot_id in fleet.robots_with_capability(CAPABILITY_LIDAR) {
+            lidar[count] = robot_id;
+            count += 1;
+        }
+        assert_eq!(&lidar[..count], &[1, 3]);
+
+        let shared = fleet.intersect_capabilities();
+        assert_eq!(shared.len(), 1);
+        assert!(shared.contains(&CAPABILITY_CAMERA));
+
+        // Full view
+        assert_eq!(
+            fleet.update_robot(&RobotStatus::new(4)),
+            Err(CRDTError::BufferOverflow)
+        );
+    }
+
+    #[test]
+    fn test_fleet_capability_view_merge() {
+        let mut scout = RobotStatus::<DefaultConfig>::new(1);
+        scout.advertise_capability(CAPABILITY_CAMERA, 1000).unwrap();
+        let mut picker = RobotStatus::<DefaultConfig>::new(2);
+        picker.advertise_capability(CAPABILITY_ARM, 1000).unwrap();
+
+        let mut view1 = FleetCapabilityView::<DefaultConfig, 4>::new();
+        view1.update_robot(&scout).unwrap();
+        let mut view2 = FleetCapabilityView::<DefaultConfig, 4>::new();
+        view2.update_robot(&picker).unwrap();
+
+        // A later advertisement reaches view2 first
+        scout
+            .advertise_capability(CAPABILITY_GRIPPER, 2000)
+            .unwrap();
+        view2.update_robot(&scout).unwrap();
+
+        view1.merge(&view2).unwrap();
+        view2.merge(&view1).unwrap();
+        assert!(view1.eq(&view2));
+        assert_eq!(view1.state_hash(), view2.state_hash());
+        assert!(view1.robot(1).unwrap().has_capability(CAPABILITY_GRIPPER));
+        assert!(view1.robots_with_capability(CAPABILITY_ARM).eq([2]));
+    }
+
+    #[test]
+    fn test_merge_with_proximity_boost() {
+        let mut robot = RobotStatus::<DefaultConfig>::new(1);
+        robot
+            .update_status(
+                OperationalMode::Active,
+                Position3D::new(100, 0, 0),
+                BatteryLevel::High,
+                1000,
+            )
+            .unwrap();
+
+        // A relayed estimate of robot 1, slightly newer than its own reading
+        let mut estimate = RobotStatus::<DefaultConfig>::new(1);
+        estimate
+            .update_status(
+                OperationalMode::Active,
+                Position3D::new(900, 0, 0),
+                BatteryLevel::High,
+                1050,
+            )
+            .unwrap();
+        let mut neighbor = RobotStatus::<DefaultConfig>::new(2);
+        neighbor.merge(&estimate).unwrap();
+
+        // Nearby and within the slack: own data is kept
+        let mut boosted = robot.clone();
+        boosted
+            .merge_with_proximity_boost(&neighbor, 2.0, 5.0, 100)
+            .unwrap();
+        assert_eq!(
+            boosted.effective_position(1100, 500),
+            Some(Position3D::new(100, 0, 0))
+        );
+
+        // Too far away for the boost: the newer data wins as in a plain merge
+        let mut distant = robot.clone();
+        distant
+            .merge_with_proximity_boost(&neighbor, 20.0, 5.0, 100)
+            .unwrap();
+        let mut plain = robot.clone();
+        plain.merge(&neighbor).unwrap();
+        assert!(distant.eq(&plain));
+        assert_eq!(
+            distant.effective_position(1100, 500),
+            Some(Position3D::new(900, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_effective_position_staleness() {
+        let mut robot = RobotStatus::<DefaultConfig>::new(3);
+        assert_eq!(robot.effective_position(0, 100), None);
+
+        robot
+            .update_status(
+                OperationalMode::Idle,
+                Position3D::new_2d(5, 5),
+                BatteryLevel::Medium,
+                1000,
+            )
+            .unwrap();
+        assert_eq!(
+            robot.effective_position(1100, 100),
+            Some(Position3D::new_2d(5, 5))
+        );
+        assert_eq!(robot.effective_position(1101, 100), None);
+    }
+
+    #[test]
+    fn test_health_score_merge() {
+        let mut robot = RobotStatus::<DefaultConfig>::new(1);
+        let mut replica = robot.clone();
+        robot.set_health_score(200, 1000).unwrap();
+        replica.set_health_score(150, 2000).unwrap();
+        robot.merge(&replica).unwrap();
+        assert_eq!(robot.health_score(), Some(150));
+
+        // Another robot's status does not carry over its health
+        let mut other = RobotStatus::<DefaultConfig>::new(2);
+        other.set_health_score(255, 3000).unwrap();
+        robot.merge(&other).unwrap();
+        assert_eq!(robot.health_score(), Some(150));
+        assert!(robot.eq(&replica));
+    }
+
+    #[test]
+    fn test_fleet_health_view() {
+        let mut statuses = [
+            RobotStatus::<DefaultConfig>::new(1),
+            RobotStatus::<DefaultConfig>::new(2),
+            RobotStatus::<DefaultConfig>::new(3),
+            RobotStatus::<DefaultConfig>::new(4),
+        ];
+        statuses[0].set_health_score(100, 1000).unwrap();
+        statuses[1].set_health_score(250, 1000).unwrap();
+        statuses[2].set_health_score(250, 1000).unwrap();
+        // Robot 4 has not reported a score
+
+        let mut fleet = FleetHealthView::<DefaultConfig, 4>::new();
+        assert_eq!(fleet.healthiest_robot(), None);
+        assert_eq!(fleet.average_fleet_health(), None);
+        fleet.fleet_merge(&statuses).unwrap();
+
+        assert_eq!(fleet.robot_count(), 4);
+        assert_eq!(fleet.healthiest_robot(), Some(2));
+        assert_eq!(fleet.average_fleet_health(), Some(200));
+        assert!(fleet.robots_above_threshold(100).eq([2, 3]));
+
+        // Only drops of more than 20% count
+        assert_eq!(fleet.degraded_robots().count(), 0);
+        statuses[1].set_health_score(200, 2000).unwrap();
+        statuses[2].set_health_score(199, 2000).unwrap();
+        statuses[3].set_health_score(10, 2000).unwrap();
+        fleet.fleet_merge(&statuses).unwrap();
+        assert!(fleet.degraded_robots().eq([(3, 199)]));
+        assert_eq!(fleet.degraded_robots().count(), 0);
+
+        let mut full = FleetHealthView::<DefaultConfig, 2>::new();
+        assert_eq!(full.fleet_merge(&statuses), Err(CRDTError::BufferOverflow));
+        assert_eq!(full.robot_count(), 2);
+    }
 }
@@ -0,0 +1,403 @@
+//! CBOR encoding for CRDT state
+//!
+//! Every core CRDT implements `minicbor::Encode` and `minicbor::Decode` when
+//! the `cbor` feature is enabled. State is written as definite-length CBOR
+//! arrays rather than maps, so no field names go over the wire:
+//!
+//! | Type | Encoding |
+//! |------|----------|
+//! | `GCounter` | `[node_id, [count0, count1, ...]]` |
+//! | `PNCounter` | `[node_id, [pos0, ...], [neg0, ...], min, max]` |
+//! | `LWWRegister` | `[node_id, writer_node_id, timestamp, value \| null]` |
+//! | `MVRegister` | `[node_id, [[value, timestamp, node_id], ...]]` |
+//! | `GSet` | `[element, ...]` |
+//! | `ORSet` | `[node_id, [[element, timestamp, node_id], ...], [[element, timestamp, node_id, removed_at], ...]]` |
+//! | `LWWMap` | `[node_id, [[key, value, timestamp, node_id], ...], [[key, timestamp, node_id], ...]]` |
+//!
+//! Counter arrays omit trailing zero slots, and a `GCounter` whose wrap flag
+//! is set carries a trailing `true`. Local policy such as register TTLs,
+//! counter overflow modes and `ORSet` conflict policies is not encoded;
+//! decoded replicas use the defaults.
+//!
+//! Decoding into a CRDT with a smaller capacity than the sender's fails
+//! instead of truncating state.
+//!
+//! # Example
+//! ```rust
+//! use crdtosphere::prelude::*;
+//! use crdtosphere::serialization::{from_cbor_bytes, to_cbor_bytes};
+//!
+//! let mut counter = GCounter::<DefaultConfig>::new(1);
+//! counter.increment(5)?;
+//!
+//! let mut buf = [0u8; 64];
+//! let len = to_cbor_bytes(&counter, &mut buf)?;
+//! assert_eq!(&buf[..len], &[0x82, 0x01, 0x82, 0x00, 0x05]);
+//!
+//! let decoded: GCounter<DefaultConfig> = from_cbor_bytes(&buf[..len])?;
+//! assert_eq!(decoded.value(), 5);
+//! # Ok::<(), crdtosphere::error::CRDTError>(())
+//! ```
+
+use crate::error::{CRDTError, CRDTResult};
+use minicbor::data::Type;
+use minicbor::decode::{self, Decoder};
+use minicbor::encode::write::Cursor;
+
+/// Decode error message for state that does not fit the receiving CRDT
+const CAPACITY_EXCEEDED: &str = "capacity exceeded";
+
+/// Decode error message for structurally valid but inconsistent state
+const INVALID_STATE: &str = "invalid state";
+
+/// Encodes a value into the buffer
+///
+/// # Arguments
+/// * `value` - The CRDT (or any CBOR-encodable value) to encode
+/// * `buf` - The output buffer
+///
+/// # Returns
+/// The number of bytes written, or `CRDTError::BufferOverflow` if the
+/// buffer is too small
+pub fn to_cbor_bytes<T: minicbor::Encode<()>>(value: &T, buf: &mut [u8]) -> CRDTResult<usize> {
+    let mut cursor = Cursor::new(buf);
+    match minicbor::encode(value, &mut cursor) {
+        Ok(()) => Ok(cursor.position()),
+        Err(err) if err.is_write() => Err(CRDTError::BufferOverflow),
+        Err(_) => Err(CRDTError::InvalidState),
+    }
+}
+
+/// Decodes a value from the buffer
+///
+/// # Arguments
+/// * `buf` - The CBOR-encoded bytes
+///
+/// # Returns
+/// The decoded value, `CRDTError::BufferOverflow` if the encoded state does
+/// not fit the target type's capacity, or `CRDTError::InvalidState` if the
+/// bytes are malformed
+pub fn from_cbor_bytes<'b, T: minicbor::Decode<'b, ()>>(buf: &'b [u8]) -> CRDTResult<T> {
+    minicbor::decode(buf).map_err(|err| {
+        if is_capacity_error(&err) {
+            CRDTError::BufferOverflow
+        } else {
+            CRDTError::InvalidState
+        }
+    })
+}
+
+/// Reads the header of a definite-length array
+pub(crate) fn array_len(d: &mut Decoder<'_>) -> Result<usize, decode::Error> {
+    match d.array()? {
+        Some(len) => Ok(len as usize),
+        None => Err(decode::Error::type_mismatch(Type::ArrayIndef)),
+    }
+}
+
+/// Reads an array header and checks it against the receiving capacity
+pub(crate) fn bounded_array_len(
+    d: &mut Decoder<'_>,
+    capacity: usize,
+) -> Result<usize, decode::Error> {
+    let len = array_len(d)?;
+    if len > capacity {
+        return Err(capacity_exceeded());
+    }
+    Ok(len)
+}
+
+/// Reads an array header that must have exactly `expected` items
+pub(crate) fn expect_array_len(d: &mut Decoder<'_>, expected: usize) -> Result<(), decode::Error> {
+    if array_len(d)? != expected {
+        return Err(invalid_state());
+    }
+    Ok(())
+}
+
+/// Error for state that does not fit the receiving CRDT
+pub(crate) fn capacity_exceeded() -> decode::Error {
+    decode::Error::message(CAPACITY_EXCEEDED)
+}
+
+/// Error for structurally valid but inconsistent state
+pub(crate) fn invalid_state() -> decode::Error {
+    decode::Error::message(INVALID_STATE)
+}
+
+/// Checks whether a decode error was raised by `capacity_exceeded`
+fn is_capacity_error(err: &decode::Error) -> bool {
+    use core::fmt::Write;
+
+    /// Checks that formatted output ends with the capacity message
+    struct SuffixMatcher {
+        tail: [u8; CAPACITY_EXCEEDED.len()],
+        len: usize,
+    }
+
+    impl Write for SuffixMatcher {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            for &byte in s.as_bytes() {
+                self.tail.copy_within(1.., 0);
+                self.tail[self.tail.len() - 1] = byte;
+                self.len += 1;
+            }
+            Ok(())
+        }
+    }
+
+    if !err.is_message() {
+        return false;
+    }
+
+    let mut matcher = SuffixMatcher {
+        tail: [0; CAPACITY_EXCEEDED.len()],
+        len: 0,
+    };
+    write!(matcher, "{}", err).is_ok()
+        && matcher.len >= CAPACITY_EXCEEDED.len()
+        && matcher.tail == *CAPACITY_EXCEEDED.as_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::counters::{GCounter, OverflowMode, PNCounter};
+    use crate::maps::LWWMap;
+    use crate::memory::DefaultConfig;
+    use crate::registers::{LWWRegister, MVRegister};
+    use crate::sets::{GSet, ORSet};
+    use crate::traits::CRDT;
+
+    /// Encodes and decodes a value through a stack buffer
+    fn round_trip<T, U>(value: &T) -> CRDTResult<U>
+    where
+        T: minicbor::Encode<()>,
+        U: for<'b> minicbor::Decode<'b, ()>,
+    {
+        let mut buf = [0u8; 512];
+        let len = to_cbor_bytes(value, &mut buf)?;
+        from_cbor_bytes(&buf[..len])
+    }
+
+    #[test]
+    fn test_gcounter_wire_format() {
+        let mut counter = GCounter::<DefaultConfig>::new(1);
+        counter.increment(5).unwrap();
+
+        let mut buf = [0u8; 16];
+        let len = to_cbor_bytes(&counter, &mut buf).unwrap();
+        // [1, [0, 5]]
+        assert_eq!(&buf[..len], &[0x82, 0x01, 0x82, 0x00, 0x05]);
+    }
+
+    #[test]
+    fn test_gcounter_capacity_boundaries() {
+        // Empty, single slot, and every slot up to the capacity in use
+        for used in 0..=8u8 {
+            let mut counter = GCounter::<DefaultConfig, 8>::with_capacity(0);
+            for node in 0..used {
+                let mut other = GCounter::<DefaultConfig, 8>::with_capacity(node);
+                other.increment(node as u32 + 1).unwrap();
+                counter.merge(&other).unwrap();
+            }
+
+            let decoded: GCounter<DefaultConfig, 8> = round_trip(&counter).unwrap();
+            assert!(decoded.eq(&counter));
+            assert_eq!(decoded.node_id(), counter.node_id());
+
+            // Fits a smaller counter only while the highest used slot does
+            let smaller: CRDTResult<GCounter<DefaultConfig, 4>> = round_trip(&counter);
+            if used <= 4 {
+                assert_eq!(smaller.unwrap().value(), counter.value());
+            } else {
+                assert_eq!(smaller.err(), Some(CRDTError::BufferOverflow));
+            }
+        }
+
+        // The owning node must fit as well
+        let counter = GCounter::<DefaultConfig, 8>::with_capacity(7);
+        let smaller: CRDTResult<GCounter<DefaultConfig, 4>> = round_trip(&counter);
+        assert_eq!(smaller.err(), Some(CRDTError::BufferOverflow));
+    }
+
+    #[test]
+    fn test_gcounter_wrap_flag() {
+        let mut counter = GCounter::<DefaultConfig>::with_overflow_mode(2, OverflowMode::Wrap);
+        counter.increment(u32::MAX).unwrap();
+        counter.increment(2).unwrap();
+        assert!(counter.has_wrapped());
+
+        let decoded: GCounter<DefaultConfig> = round_trip(&counter).unwrap();
+        assert!(decoded.has_wrapped());
+        assert!(decoded.eq(&counter));
+    }
+
+    #[test]
+    fn test_pncounter_capacity_boundaries() {
+        for used in 0..=8u8 {
+            let mut counter = PNCounter::<DefaultConfig, 8>::with_capacity(0);
+            for node in 0..used {
+                let mut other = PNCounter::<DefaultConfig, 8>::with_capacity(node);
+                other.increment(10).unwrap();
+                other.decrement(node as u32 + 1).unwrap();
+                counter.merge(&other).unwrap();
+            }
+
+            let decoded: PNCounter<DefaultConfig, 8> = round_trip(&counter).unwrap();
+            assert!(decoded.eq(&counter));
+            assert_eq!(decoded.value(), counter.value());
+
+            let smaller: CRDTResult<PNCounter<DefaultConfig, 4>> = round_trip(&counter);
+            if used <= 4 {
+                assert_eq!(smaller.unwrap().value(), counter.value());
+            } else {
+                assert_eq!(smaller.err(), Some(CRDTError::BufferOverflow));
+            }
+        }
+
+        let bounded = PNCounter::<DefaultConfig>::with_bounds(1, -10, 10);
+        let decoded: PNCounter<DefaultConfig> = round_trip(&bounded).unwrap();
+        assert_eq!(decoded.bounds(), (-10, 10));
+    }
+
+    #[test]
+    fn test_lww_register_round_trip() {
+        let empty = LWWRegister::<u32, DefaultConfig>::new(3);
+        let decoded: LWWRegister<u32, DefaultConfig> = round_trip(&empty).unwrap();
+        assert!(decoded.eq(&empty));
+        assert_eq!(decoded.get(), None);
+
+        let mut register = LWWRegister::<u32, DefaultConfig>::new(3);
+        register.set(42, 1000).unwrap();
+        let decoded: LWWRegister<u32, DefaultConfig> = round_trip(&register).unwrap();
+        assert!(decoded.eq(&register));
+        assert_eq!(decoded.get(), Some(&42));
+        assert_eq!(decoded.timestamp().as_u64(), 1000);
+        assert_eq!(decoded.current_node(), 3);
+    }
+
+    #[test]
+    fn test_mv_register_capacity_boundaries() {
+        for used in 0..=4u8 {
+            let mut register = MVRegister::<u32, DefaultConfig, 4>::with_capacity(0);
+            for node in 0..used {
+                let mut other = MVRegister::<u32, DefaultConfig, 4>::with_capacity(node);
+                other.set(node as u32 * 10, 1000 + node as u64).unwrap();
+                register.merge(&other).unwrap();
+            }
+
+            let decoded: MVRegister<u32, DefaultConfig, 4> = round_trip(&register).unwrap();
+            assert!(decoded.eq(&register));
+
+            let smaller: CRDTResult<MVRegister<u32, DefaultConfig, 2>> = round_trip(&register);
+            if used <= 2 {
+                assert!(smaller.is_ok());
+            } else {
+                assert_eq!(smaller.err(), Some(CRDTError::BufferOverflow));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gset_capacity_boundaries() {
+        for used in 0..=8u32 {
+            let mut set = GSet::<u32, DefaultConfig, 8>::with_capacity();
+            for element in 0..used {
+                set.insert(element * 7).unwrap();
+            }
+
+            let decoded: GSet<u32, DefaultConfig, 8> = round_trip(&set).unwrap();
+            assert!(decoded.eq(&set));
+
+            let smaller: CRDTResult<GSet<u32, DefaultConfig, 4>> = round_trip(&set);
+            if used <= 4 {
+                assert_eq!(smaller.unwrap().len(), used as usize);
+            } else {
+                assert_eq!(smaller.err(), Some(CRDTError::BufferOverflow));
+            }
+        }
+    }
+
+    #[test]
+    fn test_orset_capacity_boundaries() {
+        for used in 0..=8u32 {
+            let mut set = ORSet::<u32, DefaultConfig, 8>::with_capacity(1);
+            for element in 0..used {
+                set.add(element, 1000 + element as u64).unwrap();
+            }
+            if used > 0 {
+                set.remove(&0, 5000).unwrap();
+            }
+
+            let decoded: ORSet<u32, DefaultConfig, 8> = round_trip(&set).unwrap();
+            assert!(decoded.eq(&set));
+            assert_eq!(decoded.len(), set.len());
+            assert_eq!(decoded.tombstone_entries(), set.tombstone_entries());
+
+            let smaller: CRDTResult<ORSet<u32, DefaultConfig, 4>> = round_trip(&set);
+            if used <= 4 {
+                assert_eq!(smaller.unwrap().len(), set.len());
+            } else {
+                assert_eq!(smaller.err(), Some(CRDTError::BufferOverflow));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lww_map_capacity_boundaries() {
+        for used in 0..=8u8 {
+            let mut map = LWWMap::<u8, u32, DefaultConfig, 8>::with_capacity(1);
+            for key in 0..used {
+                map.insert(key, key as u32 * 100, 1000).unwrap();
+            }
+            if used > 0 {
+                map.delete(&0, 2000).unwrap();
+            }
+
+            let decoded: LWWMap<u8, u32, DefaultConfig, 8> = round_trip(&map).unwrap();
+            assert!(decoded.eq(&map));
+            assert_eq!(decoded.len(), map.len());
+
+            let smaller: CRDTResult<LWWMap<u8, u32, DefaultConfig, 4>> = round_trip(&map);
+            if used <= 4 {
+                assert_eq!(smaller.unwrap().len(), map.len());
+            } else {
+                assert_eq!(smaller.err(), Some(CRDTError::BufferOverflow));
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_buffer_too_small() {
+        let mut counter = GCounter::<DefaultConfig>::new(1);
+        counter.increment(1_000_000).unwrap();
+
+        let mut buf = [0u8; 4];
+        assert_eq!(
+            to_cbor_bytes(&counter, &mut buf),
+            Err(CRDTError::BufferOverflow)
+        );
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        // Truncated payload
+        let truncated: CRDTResult<GCounter<DefaultConfig>> = from_cbor_bytes(&[0x82, 0x01]);
+        assert_eq!(truncated.err(), Some(CRDTError::InvalidState));
+
+        // Map instead of array
+        let wrong_type: CRDTResult<GCounter<DefaultConfig>> = from_cbor_bytes(&[0xa0]);
+        assert_eq!(wrong_type.err(), Some(CRDTError::InvalidState));
+
+        // Wrong number of fields
+        let wrong_shape: CRDTResult<LWWRegister<u32, DefaultConfig>> =
+            from_cbor_bytes(&[0x81, 0x01]);
+        assert_eq!(wrong_shape.err(), Some(CRDTError::InvalidState));
+
+        // Inverted PNCounter bounds: [1, [], [], 5, -5]
+        let inverted: CRDTResult<PNCounter<DefaultConfig>> =
+            from_cbor_bytes(&[0x85, 0x01, 0x80, 0x80, 0x05, 0x24]);
+        assert_eq!(inverted.err(), Some(CRDTError::InvalidState));
+    }
+}
@@ -0,0 +1,232 @@
+//! CRC32-protected frames
+//!
+//! Serialized CRDT state can be corrupted on the bus, and a corrupted state
+//! that still decodes would be merged into every replica. `CrcProtectedFrame`
+//! prefixes a payload with its CRC32 so the receiver can drop it instead:
+//!
+//! | Bytes | Content |
+//! |-------|---------|
+//! | 0-3 | CRC32 of the payload (little endian) |
+//! | 4.. | Payload |
+//!
+//! The CRC is the IEEE 802.3 CRC32 (reflected polynomial `0xEDB88320`), the
+//! same one computed by Ethernet, zlib and the STM32 CRC unit in its
+//! bit-reversed mode. By default it is computed in software, bit by bit, so
+//! no lookup table is kept in flash. With the `hardware-crc` feature the
+//! platform supplies the implementation instead:
+//!
+//! ```rust,ignore
+//! #[unsafe(no_mangle)]
+//! pub fn platform_crc32(data: &[u8]) -> u32 {
+//!     // Feed `data` through the CRC peripheral
+//! }
+//! ```
+//!
+//! # Example
+//! ```rust
+//! use crdtosphere::serialization::CrcProtectedFrame;
+//! # #[cfg(feature = "hardware-crc")]
+//! # #[unsafe(no_mangle)]
+//! # pub fn platform_crc32(data: &[u8]) -> u32 {
+//! #     crdtosphere::serialization::crc::crc32_software(data)
+//! # }
+//!
+//! let mut buf = [0u8; 12];
+//! let len = CrcProtectedFrame::<8>::encode(&[1, 2, 3], &mut buf)?;
+//! assert_eq!(len, 7);
+//! assert_eq!(CrcProtectedFrame::<8>::decode(&buf[..len])?, &[1, 2, 3]);
+//!
+//! // A flipped bit is detected
+//! buf[5] ^= 0x01;
+//! assert!(CrcProtectedFrame::<8>::decode(&buf[..len]).is_err());
+//! # Ok::<(), crdtosphere::error::CRDTError>(())
+//! ```
+
+use crate::error::{CRDTError, CRDTResult};
+
+/// Size of the CRC header in bytes
+pub const CRC_LEN: usize = 4;
+
+/// Reflected IEEE 802.3 CRC32 polynomial
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+#[cfg(feature = "hardware-crc")]
+unsafe extern "Rust" {
+    /// IEEE 802.3 CRC32 of `data`, computed by the platform's CRC unit
+    fn platform_crc32(data: &[u8]) -> u32;
+}
+
+/// Computes the IEEE 802.3 CRC32 of `data` in software
+///
+/// Processes one bit at a time, trading speed for not needing a 1KB
+/// lookup table.
+pub const fn crc32_software(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF;
+    let mut i = 0;
+    while i < data.len() {
+        crc ^= data[i] as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    !crc
+}
+
+/// Computes the IEEE 802.3 CRC32 of `data`
+///
+/// Uses the platform CRC unit with the `hardware-crc` feature, and
+/// [`crc32_software`] otherwise.
+pub fn crc32(data: &[u8]) -> u32 {
+    #[cfg(feature = "hardware-crc")]
+    {
+        // SAFETY: The platform must provide `platform_crc32` as a safe
+        // function when it enables `hardware-crc`
+        unsafe { platform_crc32(data) }
+    }
+
+    #[cfg(not(feature = "hardware-crc"))]
+    {
+        crc32_software(data)
+    }
+}
+
+/// Payload of up to `N` bytes framed with a CRC32 header
+///
+/// A frame occupies at most [`FRAME_LEN`](Self::FRAME_LEN) bytes: the
+/// 4-byte CRC followed by the payload.
+#[derive(Debug, Clone, Copy)]
+pub struct CrcProtectedFrame<const N: usize>;
+
+impl<const N: usize> CrcProtectedFrame<N> {
+    /// Largest encoded frame, in bytes
+    pub const FRAME_LEN: usize = CRC_LEN + N;
+
+    /// Writes `payload` behind its CRC32 into `buf`
+    ///
+    /// # Arguments
+    /// * `payload` - Up to `N` bytes to protect
+    /// * `buf` - The output buffer
+    ///
+    /// # Returns
+    /// The number of bytes written, or `CRDTError::BufferOverflow` if the
+    /// payload is longer than `N` or the buffer is too small
+    pub fn encode(payload: &[u8], buf: &mut [u8]) -> CRDTResult<usize> {
+        let len = CRC_LEN + payload.len();
+        if payload.len() > N || buf.len() < len {
+            return Err(CRDTError::BufferOverflow);
+        }
+
+        buf[..CRC_LEN].copy_from_slice(&crc32(payload).to_le_bytes());
+        buf[CRC_LEN..len].copy_from_slice(payload);
+        Ok(len)
+    }
+
+    /// Verifies a frame and returns its payload
+    ///
+    /// # Arguments
+    /// * `buf` - A frame produced by [`encode`](Self::encode)
+    ///
+    /// # Returns
+    /// The payload, or `CRDTError::InvalidState` if the frame is truncated,
+    /// longer than `FRAME_LEN`, or fails the CRC check
+    pub fn decode(buf: &[u8]) -> CRDTResult<&[u8]> {
+        if buf.len() < CRC_LEN || buf.len() > Self::FRAME_LEN {
+            return Err(CRDTError::InvalidState);
+        }
+
+        let (header, payload) = buf.split_at(CRC_LEN);
+        let expected = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        if crc32(payload) != expected {
+            return Err(CRDTError::InvalidState);
+        }
+        Ok(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stands in for the platform CRC unit
+    #[cfg(feature = "hardware-crc")]
+    #[unsafe(no_mangle)]
+    fn platform_crc32(data: &[u8]) -> u32 {
+        crc32_software(data)
+    }
+
+    #[test]
+    fn test_crc32_check_value() {
+        // Standard check value for CRC-32/ISO-HDLC
+        assert_eq!(crc32_software(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32_software(&[]), 0);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let payload = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80];
+        let mut buf = [0u8; CrcProtectedFrame::<8>::FRAME_LEN];
+
+        let len = CrcProtectedFrame::<8>::encode(&payload, &mut buf).unwrap();
+        assert_eq!(len, 12);
+        assert_eq!(&buf[..CRC_LEN], &crc32(&payload).to_le_bytes());
+        assert_eq!(CrcProtectedFrame::<8>::decode(&buf).unwrap(), &payload);
+
+        // An empty payload is still protected
+        let len = CrcProtectedFrame::<8>::encode(&[], &mut buf).unwrap();
+        assert_eq!(CrcProtectedFrame::<8>::decode(&buf[..len]).unwrap(), &[]);
+    }
+
+    #[test]
+    fn test_corruption_detected() {
+        let mut buf = [0u8; 12];
+        let len = CrcProtectedFrame::<8>::encode(&[1, 2, 3, 4], &mut buf).unwrap();
+
+        for i in 0..len {
+            let mut corrupted = buf;
+            corrupted[i] ^= 0x80;
+            assert_eq!(
+                CrcProtectedFrame::<8>::decode(&corrupted[..len]),
+                Err(CRDTError::InvalidState)
+            );
+        }
+
+        // Dropping a byte breaks the CRC too
+        assert_eq!(
+            CrcProtectedFrame::<8>::decode(&buf[..len - 1]),
+            Err(CRDTError::InvalidState)
+        );
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let mut buf = [0u8; 16];
+
+        // Payload longer than N
+        assert_eq!(
+            CrcProtectedFrame::<4>::encode(&[0; 5], &mut buf),
+            Err(CRDTError::BufferOverflow)
+        );
+        // Buffer too small for the CRC and payload
+        assert_eq!(
+            CrcProtectedFrame::<8>::encode(&[0; 8], &mut buf[..11]),
+            Err(CRDTError::BufferOverflow)
+        );
+
+        // Frames shorter than the header or longer than FRAME_LEN
+        assert_eq!(
+            CrcProtectedFrame::<4>::decode(&buf[..3]),
+            Err(CRDTError::InvalidState)
+        );
+        assert_eq!(
+            CrcProtectedFrame::<4>::decode(&buf[..9]),
+            Err(CRDTError::InvalidState)
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Struct deserialization for non-self-describing formats
+//!
+//! The CRDT `Deserialize` impls collect their fields through `visit_map`.
+//! Formats such as postcard do not encode field names and hand structs to
+//! `visit_seq` instead, with the fields in declaration order. [`SeqFields`]
+//! presents such a sequence as a map keyed by the field names, so both paths
+//! share one implementation.
+
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess};
+
+/// Map view over the fields of a struct encoded as a sequence
+pub(crate) struct SeqFields<A> {
+    /// The underlying sequence
+    seq: A,
+
+    /// Field names, in serialization order
+    fields: &'static [&'static str],
+
+    /// Index of the next field
+    next: usize,
+}
+
+impl<A> SeqFields<A> {
+    /// Wraps a sequence holding the values of `fields`, in order
+    pub(crate) fn new(seq: A, fields: &'static [&'static str]) -> Self {
+        Self {
+            seq,
+            fields,
+            next: 0,
+        }
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> MapAccess<'de> for SeqFields<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.fields.get(self.next) {
+            Some(field) => seed
+                .deserialize(BorrowedStrDeserializer::new(field))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, A::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let index = self.next;
+        self.next += 1;
+        self.seq
+            .next_element_seed(seed)?
+            .ok_or_else(|| A::Error::invalid_length(index, &"a value for every field"))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.next)
+    }
+}
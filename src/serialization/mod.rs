@@ -0,0 +1,21 @@
+//! Compact binary serialization formats
+//!
+//! This module provides wire formats for exchanging CRDT state between
+//! nodes that are more compact than the serde-based representations, and
+//! CRC framing to detect state corrupted in transit.
+
+#[cfg(feature = "cbor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+pub mod cbor;
+pub mod crc;
+#[cfg(feature = "serde")]
+pub(crate) mod fields;
+#[cfg(feature = "postcard")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+pub mod postcard;
+
+#[cfg(feature = "postcard")]
+pub use self::postcard::{deserialize_postcard, serialize_postcard};
+#[cfg(feature = "cbor")]
+pub use cbor::{from_cbor_bytes, to_cbor_bytes};
+pub use crc::{CrcProtectedFrame, crc32};
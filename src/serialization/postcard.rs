@@ -0,0 +1,302 @@
+//! Postcard encoding for CRDT state
+//!
+//! [Postcard](https://docs.rs/postcard) is a compact `no_std` format that
+//! uses the existing serde implementations: struct fields are written in
+//! order without names, integers wider than a byte as varints and sequences
+//! behind a varint length.
+//!
+//! Every core CRDT also implements [`MaxSize`], so receive buffers can be
+//! sized statically. `POSTCARD_MAX_SIZE` is the encoded length of a CRDT
+//! whose arrays are full and whose integers all take their longest varint
+//! encoding.
+//!
+//! # Example
+//! ```rust
+//! use crdtosphere::prelude::*;
+//! use crdtosphere::serialization::{deserialize_postcard, serialize_postcard};
+//! use postcard::experimental::max_size::MaxSize;
+//!
+//! type Readings = GSet<u16, DefaultConfig, 8>;
+//!
+//! let mut readings = Readings::with_capacity();
+//! readings.insert(21)?;
+//! readings.insert(22)?;
+//!
+//! let mut buf = [0u8; Readings::POSTCARD_MAX_SIZE];
+//! let bytes = serialize_postcard(&readings, &mut buf)?;
+//!
+//! let decoded: Readings = deserialize_postcard(bytes)?;
+//! assert!(decoded.contains(&22));
+//! # Ok::<(), crdtosphere::error::CRDTError>(())
+//! ```
+
+use crate::error::{CRDTError, CRDTResult};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+pub use postcard::experimental::max_size::MaxSize;
+
+/// Encodes a value into the buffer
+///
+/// # Arguments
+/// * `value` - The CRDT (or any serializable value) to encode
+/// * `buf` - The output buffer
+///
+/// # Returns
+/// The encoded bytes at the start of `buf`, or `CRDTError::BufferOverflow`
+/// if the buffer is too small
+pub fn serialize_postcard<'a, T: Serialize>(value: &T, buf: &'a mut [u8]) -> CRDTResult<&'a [u8]> {
+    match postcard::to_slice(value, buf) {
+        Ok(bytes) => Ok(bytes),
+        Err(postcard::Error::SerializeBufferFull) => Err(CRDTError::BufferOverflow),
+        Err(_) => Err(CRDTError::InvalidState),
+    }
+}
+
+/// Decodes a value from the buffer
+///
+/// # Arguments
+/// * `buf` - The postcard-encoded bytes, without trailing data
+///
+/// # Returns
+/// The decoded value, or `CRDTError::InvalidState` if the bytes are
+/// malformed, have trailing data, or hold state that does not fit the target
+/// type's capacity
+pub fn deserialize_postcard<T: DeserializeOwned>(buf: &[u8]) -> CRDTResult<T> {
+    match postcard::take_from_bytes(buf) {
+        Ok((value, [])) => Ok(value),
+        _ => Err(CRDTError::InvalidState),
+    }
+}
+
+/// Number of bytes in the varint encoding of `value`
+pub(crate) const fn varint_len(value: usize) -> usize {
+    let mut len = 1;
+    let mut rest = value >> 7;
+    while rest != 0 {
+        len += 1;
+        rest >>= 7;
+    }
+    len
+}
+
+/// Largest encoding of a sequence of at most `capacity` items
+pub(crate) const fn seq_max_size(capacity: usize, item_max_size: usize) -> usize {
+    varint_len(capacity) + capacity * item_max_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::counters::{GCounter, PNCounter};
+    use crate::maps::LWWMap;
+    use crate::memory::DefaultConfig;
+    use crate::registers::{LWWRegister, MVRegister};
+    use crate::sets::{GSet, ORSet};
+    use crate::traits::CRDT;
+
+    /// Large enough for every CRDT under test
+    const BUF_LEN: usize = 1024;
+
+    /// Timestamp with the longest varint encoding
+    const LATE: u64 = u64::MAX - 1;
+
+    /// Encodes a value, checks its length and decodes it again
+    fn round_trip<T: Serialize + DeserializeOwned + MaxSize>(value: &T, full: bool) -> T {
+        let mut buf = [0u8; BUF_LEN];
+        let bytes = serialize_postcard(value, &mut buf).unwrap();
+        if full {
+            assert_eq!(bytes.len(), T::POSTCARD_MAX_SIZE);
+        } else {
+            assert!(bytes.len() <= T::POSTCARD_MAX_SIZE);
+        }
+        deserialize_postcard(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_varint_len() {
+        assert_eq!(varint_len(0), 1);
+        assert_eq!(varint_len(127), 1);
+        assert_eq!(varint_len(128), 2);
+        assert_eq!(varint_len(16_383), 2);
+        assert_eq!(varint_len(16_384), 3);
+        assert_eq!(varint_len(u32::MAX as usize), u32::POSTCARD_MAX_SIZE);
+    }
+
+    #[test]
+    fn test_pncounter() {
+        let mut counter = PNCounter::<DefaultConfig>::with_bounds(0, i64::MIN, i64::MAX);
+        for node in 1..16 {
+            let mut other = PNCounter::<DefaultConfig>::new(node);
+            other.increment(u32::MAX).unwrap();
+            other.decrement(u32::MAX).unwrap();
+            counter.merge(&other).unwrap();
+        }
+        counter.increment(u32::MAX).unwrap();
+        counter.decrement(u32::MAX).unwrap();
+
+        let decoded = round_trip(&counter, true);
+        assert!(decoded.eq(&counter));
+        assert_eq!(decoded.bounds(), (i64::MIN, i64::MAX));
+
+        let small = PNCounter::<DefaultConfig>::new(3);
+        assert!(round_trip(&small, false).eq(&small));
+    }
+
+    #[test]
+    fn test_lww_register() {
+        let mut register = LWWRegister::<u32, DefaultConfig>::new_with_ttl(1, u64::MAX);
+        register.set(u32::MAX, LATE).unwrap();
+
+        let decoded = round_trip(&register, true);
+        assert_eq!(decoded.get(), Some(&u32::MAX));
+        assert_eq!(decoded.ttl(), Some(u64::MAX));
+
+        let empty = LWWRegister::<u32, DefaultConfig>::new(1);
+        assert!(round_trip(&empty, false).is_empty());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let mut counter = GCounter::<DefaultConfig, 4>::with_capacity(1);
+        counter.increment(300).unwrap();
+        let mut buf = [0u8; GCounter::<DefaultConfig, 4>::POSTCARD_MAX_SIZE];
+        let len = serialize_postcard(&counter, &mut buf).unwrap().len();
+
+        // Truncated, with trailing data, and for a smaller counter
+        assert_eq!(
+            deserialize_postcard::<GCounter<DefaultConfig, 4>>(&buf[..len - 1]).unwrap_err(),
+            CRDTError::InvalidState
+        );
+        assert_eq!(
+            deserialize_postcard::<GCounter<DefaultConfig, 4>>(&buf[..len + 1]).unwrap_err(),
+            CRDTError::InvalidState
+        );
+        assert_eq!(
+            deserialize_postcard::<GCounter<DefaultConfig, 2>>(&buf[..len]).unwrap_err(),
+            CRDTError::InvalidState
+        );
+
+        // Output buffer too small
+        assert_eq!(
+            serialize_postcard(&counter, &mut buf[..len - 1]).unwrap_err(),
+            CRDTError::BufferOverflow
+        );
+    }
+
+    /// Round-trips full and empty instances of every capacity-generic CRDT
+    macro_rules! capacity_round_trips {
+        ($($name:ident => $cap:literal),* $(,)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    round_trip_gcounter::<$cap>();
+                    round_trip_mv_register::<$cap>();
+                    round_trip_gset::<$cap>();
+                    round_trip_orset::<$cap>();
+                    round_trip_lww_map::<$cap>();
+                }
+            )*
+        };
+    }
+
+    capacity_round_trips! {
+        test_capacity_1 => 1,
+        test_capacity_2 => 2,
+        test_capacity_3 => 3,
+        test_capacity_4 => 4,
+        test_capacity_5 => 5,
+        test_capacity_6 => 6,
+        test_capacity_7 => 7,
+        test_capacity_8 => 8,
+        test_capacity_9 => 9,
+        test_capacity_10 => 10,
+        test_capacity_11 => 11,
+        test_capacity_12 => 12,
+        test_capacity_13 => 13,
+        test_capacity_14 => 14,
+        test_capacity_15 => 15,
+        test_capacity_16 => 16,
+    }
+
+    fn round_trip_gcounter<const N: usize>() {
+        let mut counter = GCounter::<DefaultConfig, N>::with_capacity(0);
+        counter.increment(u32::MAX).unwrap();
+        for node in 1..N as u8 {
+            let mut other = GCounter::<DefaultConfig, N>::with_capacity(node);
+            other.increment(u32::MAX).unwrap();
+            counter.merge(&other).unwrap();
+        }
+
+        let decoded = round_trip(&counter, true);
+        assert!(decoded.eq(&counter));
+        assert_eq!(decoded.value(), u32::MAX as u64 * N as u64);
+
+        let empty = GCounter::<DefaultConfig, N>::with_capacity(0);
+        assert!(round_trip(&empty, false).eq(&empty));
+    }
+
+    fn round_trip_mv_register<const N: usize>() {
+        let mut register = MVRegister::<u32, DefaultConfig, N>::with_capacity(0);
+        register.set(u32::MAX, LATE).unwrap();
+        for node in 1..N as u8 {
+            let mut other = MVRegister::<u32, DefaultConfig, N>::with_capacity(node);
+            other.set(u32::MAX - node as u32, LATE).unwrap();
+            register.merge(&other).unwrap();
+        }
+        assert!(register.is_full());
+
+        let decoded = round_trip(&register, true);
+        assert!(decoded.eq(&register));
+
+        let empty = MVRegister::<u32, DefaultConfig, N>::with_capacity(0);
+        assert!(round_trip(&empty, false).is_empty());
+    }
+
+    fn round_trip_gset<const N: usize>() {
+        let mut set = GSet::<u32, DefaultConfig, N>::with_capacity();
+        for i in 0..N as u32 {
+            set.insert(u32::MAX - i).unwrap();
+        }
+
+        let decoded = round_trip(&set, true);
+        assert!(decoded.eq(&set));
+
+        let empty = GSet::<u32, DefaultConfig, N>::with_capacity();
+        assert!(round_trip(&empty, false).is_empty());
+    }
+
+    fn round_trip_orset<const N: usize>() {
+        let mut set = ORSet::<u32, DefaultConfig, N>::with_capacity(1);
+        for i in 0..N as u32 {
+            set.add(u32::MAX - i, LATE).unwrap();
+        }
+        for i in 0..N as u32 {
+            set.remove(&(u32::MAX - i), u64::MAX).unwrap();
+        }
+
+        let decoded = round_trip(&set, true);
+        assert!(decoded.eq(&set));
+        assert!(decoded.is_empty());
+
+        let empty = ORSet::<u32, DefaultConfig, N>::with_capacity(1);
+        assert!(round_trip(&empty, false).eq(&empty));
+    }
+
+    fn round_trip_lww_map<const N: usize>() {
+        let mut map = LWWMap::<u32, u32, DefaultConfig, N>::with_capacity(1);
+        for i in 0..N as u32 {
+            map.insert(u32::MAX - i, u32::MAX, LATE).unwrap();
+        }
+        for i in 0..N as u32 {
+            map.delete(&(u32::MAX - i), u64::MAX).unwrap();
+        }
+
+        let decoded = round_trip(&map, true);
+        assert!(decoded.eq(&map));
+        assert!(decoded.is_empty());
+
+        let empty = LWWMap::<u32, u32, DefaultConfig, N>::with_capacity(1);
+        assert!(round_trip(&empty, false).is_empty());
+    }
+}
@@ -0,0 +1,367 @@
+//! Bloom-filter-accelerated Grow-only Set
+//!
+//! Wraps a [`GSet`] with a small Bloom filter so that membership tests for
+//! absent elements are rejected without scanning the element array.
+
+use core::hash::Hash;
+
+use crate::clock::{FnvHasher, StateHash};
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::MemoryConfig;
+use crate::sets::GSet;
+use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+
+/// Grow-only Set with a Bloom filter in front of `contains`
+///
+/// [`GSet::contains`] is a linear scan, which adds up when a large set is
+/// queried in a tight loop (e.g. checking every incoming event against a
+/// list of known devices). This wrapper keeps a Bloom filter with two FNV-1a
+/// hash functions next to the set: elements whose bits are not all set are
+/// rejected immediately, and only possible hits fall back to the scan, so
+/// [`contains`](Self::contains) always answers exactly like the set.
+///
+/// The filter is node-local state derived from the elements. It is rebuilt
+/// after every merge and not part of equality or the state hash.
+///
+/// # Type Parameters
+/// - `T`: The element type stored in the set
+/// - `C`: Memory configuration
+/// - `CAPACITY`: The maximum number of elements (defaults to 16)
+/// - `BLOOM_BYTES`: Size of the filter in bytes (defaults to 64, i.e. 512 bits)
+///
+/// # Memory Usage
+/// - Fixed size: sizeof(GSet) + BLOOM_BYTES bytes
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::sets::GSetBloom;
+///
+/// let mut known_devices = GSetBloom::<u32, DefaultConfig, 64>::with_capacity();
+/// known_devices.add(0x1001)?;
+/// known_devices.add(0x1002)?;
+///
+/// assert!(known_devices.contains(&0x1001));
+/// assert!(!known_devices.contains(&0x2001)); // Usually rejected by the filter
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct GSetBloom<T, C: MemoryConfig, const CAPACITY: usize = 16, const BLOOM_BYTES: usize = 64>
+{
+    /// The replicated set
+    set: GSet<T, C, CAPACITY>,
+    /// Bloom filter over the set's elements
+    filter: [u8; BLOOM_BYTES],
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize, const BLOOM_BYTES: usize>
+    GSetBloom<T, C, CAPACITY, BLOOM_BYTES>
+where
+    T: Hash + Clone + PartialEq,
+{
+    /// Creates a new empty set with custom capacity and filter size
+    pub const fn with_capacity() -> Self {
+        Self {
+            set: GSet::with_capacity(),
+            filter: [0; BLOOM_BYTES],
+        }
+    }
+
+    /// Wraps an existing set, building its filter
+    ///
+    /// # Arguments
+    /// * `set` - The set to accelerate
+    pub fn from_set(set: GSet<T, C, CAPACITY>) -> Self {
+        let mut bloom = Self {
+            set,
+            filter: [0; BLOOM_BYTES],
+        };
+        bloom.rebuild_filter();
+        bloom
+    }
+
+    /// Adds an element to the set and the filter
+    ///
+    /// # Arguments
+    /// * `element` - The element to add
+    ///
+    /// # Returns
+    /// Ok(true) if the element was newly inserted, Ok(false) if it already
+    /// existed, or an error if the set is full
+    pub fn add(&mut self, element: T) -> CRDTResult<bool> {
+        let bits = Self::bit_positions(&element);
+        let inserted = self.set.insert(element)?;
+        Self::set_bits(&mut self.filter, bits);
+        Ok(inserted)
+    }
+
+    /// Checks if the set contains an element
+    ///
+    /// Gives the same answer as [`GSet::contains`]; the filter only skips
+    /// the scan for elements that are certainly absent.
+    pub fn contains(&self, element: &T) -> bool {
+        self.may_contain(element) && self.set.contains(element)
+    }
+
+    /// Estimates the probability that an absent element passes the filter
+    ///
+    /// Computed as `fill^2` from the fraction of filter bits set, which is
+    /// the share of absent-element queries that still need a scan.
+    ///
+    /// # Returns
+    /// A value between 0.0 and 1.0
+    pub fn false_positive_rate(&self) -> f32 {
+        if BLOOM_BYTES == 0 {
+            return 1.0;
+        }
+        let set_bits: u32 = self.filter.iter().map(|byte| byte.count_ones()).sum();
+        let fill = set_bits as f32 / (BLOOM_BYTES * 8) as f32;
+        fill * fill
+    }
+
+    /// Returns the wrapped set
+    pub fn as_set(&self) -> &GSet<T, C, CAPACITY> {
+        &self.set
+    }
+
+    /// Unwraps the set, dropping the filter
+    pub fn into_set(self) -> GSet<T, C, CAPACITY> {
+        self.set
+    }
+
+    /// Returns an iterator over the elements
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.set.iter()
+    }
+
+    /// Returns the number of elements in the set
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    /// Returns true if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    /// Checks the filter; false means the element is certainly absent
+    fn may_contain(&self, element: &T) -> bool {
+        if BLOOM_BYTES == 0 {
+            return true;
+        }
+        Self::bit_positions(element)
+            .iter()
+            .all(|&bit| self.filter[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Returns the filter bits of an element under both hash functions
+    fn bit_positions(element: &T) -> [usize; 2] {
+        let bits = (BLOOM_BYTES * 8).max(1);
+        let mut hasher = FnvHasher::new();
+        element.hash(&mut hasher);
+        let first = hasher.finish();
+        // The second function hashes the element followed by a salt byte
+        hasher.write_bytes(&[0xa5]);
+        let second = hasher.finish();
+        [first as usize % bits, second as usize % bits]
+    }
+
+    /// Sets the given filter bits
+    fn set_bits(filter: &mut [u8; BLOOM_BYTES], bits: [usize; 2]) {
+        if BLOOM_BYTES == 0 {
+            return;
+        }
+        for bit in bits {
+            filter[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    /// Recomputes the filter from the set's elements
+    fn rebuild_filter(&mut self) {
+        self.filter = [0; BLOOM_BYTES];
+        for element in self.set.iter() {
+            Self::set_bits(&mut self.filter, Self::bit_positions(element));
+        }
+    }
+}
+
+impl<T, C: MemoryConfig> GSetBloom<T, C, 16, 64>
+where
+    T: Hash + Clone + PartialEq,
+{
+    /// Creates a new empty set with default capacity and filter size
+    pub const fn new() -> Self {
+        Self::with_capacity()
+    }
+}
+
+impl<T, C: MemoryConfig> Default for GSetBloom<T, C>
+where
+    T: Hash + Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize, const BLOOM_BYTES: usize> CRDT<C>
+    for GSetBloom<T, C, CAPACITY, BLOOM_BYTES>
+where
+    T: Hash + Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        let result = self.set.merge(&other.set);
+        // Also rebuilt on failure, which may leave a partial merge behind
+        self.rebuild_filter();
+        result
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.set.eq(&other.set)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.set.validate()?;
+        // A missing bit would turn into a false negative
+        if !self.set.iter().all(|element| self.may_contain(element)) {
+            return Err(CRDTError::InvalidState);
+        }
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.set.state_hash()
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.set.can_merge(&other.set)
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize, const BLOOM_BYTES: usize> BoundedCRDT<C>
+    for GSetBloom<T, C, CAPACITY, BLOOM_BYTES>
+where
+    T: Hash + Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.set.len()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Neither the set nor the filter can shrink
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.element_count() < Self::MAX_ELEMENTS
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize, const BLOOM_BYTES: usize> RealTimeCRDT<C>
+    for GSetBloom<T, C, CAPACITY, BLOOM_BYTES>
+where
+    T: Hash + Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    // Merge also rebuilds the filter, linear in the number of elements
+    const MAX_MERGE_CYCLES: u32 = <GSet<T, C, CAPACITY> as RealTimeCRDT<C>>::MAX_MERGE_CYCLES * 2;
+    const MAX_VALIDATE_CYCLES: u32 =
+        <GSet<T, C, CAPACITY> as RealTimeCRDT<C>>::MAX_VALIDATE_CYCLES * 2;
+    const MAX_SERIALIZE_CYCLES: u32 =
+        <GSet<T, C, CAPACITY> as RealTimeCRDT<C>>::MAX_SERIALIZE_CYCLES;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        // For this simple implementation, we don't track budget
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {
+        // For this simple implementation, we don't track budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_no_false_negatives() {
+        let mut devices = GSetBloom::<u32, DefaultConfig, 32>::with_capacity();
+        assert_eq!(devices.false_positive_rate(), 0.0);
+        assert!(!devices.contains(&1));
+
+        let id = |i: u32| i.wrapping_mul(2_654_435_761);
+        for i in 0..32 {
+            assert!(devices.add(id(i)).unwrap());
+        }
+        assert!(!devices.add(id(0)).unwrap());
+        assert_eq!(devices.add(7), Err(CRDTError::BufferOverflow));
+
+        for i in 0..32 {
+            assert!(devices.contains(&id(i)));
+        }
+        assert!(devices.validate().is_ok());
+
+        // Answers match the plain set for absent elements too
+        for probe in 1000..3000u32 {
+            assert_eq!(devices.contains(&probe), devices.as_set().contains(&probe));
+        }
+        let rate = devices.false_positive_rate();
+        assert!(rate > 0.0 && rate < 0.1);
+    }
+
+    #[test]
+    fn test_merge_rebuilds_filter() {
+        let mut gateway = GSetBloom::<u16, DefaultConfig, 32, 16>::with_capacity();
+        let mut sensor = GSetBloom::<u16, DefaultConfig, 32, 16>::with_capacity();
+        for id in 0..10 {
+            gateway.add(id).unwrap();
+            sensor.add(id + 100).unwrap();
+        }
+
+        let mut merged = gateway.clone();
+        merged.merge(&sensor).unwrap();
+        sensor.merge(&gateway).unwrap();
+        assert!(merged.eq(&sensor));
+        assert_eq!(merged.state_hash(), sensor.state_hash());
+        assert_eq!(merged.len(), 20);
+        for id in (0..10).chain(100..110) {
+            assert!(merged.contains(&id));
+        }
+        assert!(merged.validate().is_ok());
+
+        let rebuilt = GSetBloom::<_, _, 32, 16>::from_set(merged.clone().into_set());
+        assert_eq!(rebuilt.false_positive_rate(), merged.false_positive_rate());
+        assert!(rebuilt.iter().all(|id| rebuilt.contains(id)));
+    }
+
+    #[test]
+    fn test_without_filter() {
+        let mut set = GSetBloom::<u8, DefaultConfig, 4, 0>::with_capacity();
+        set.add(3).unwrap();
+        assert!(set.contains(&3));
+        assert!(!set.contains(&4));
+        assert_eq!(set.false_positive_rate(), 1.0);
+    }
+}
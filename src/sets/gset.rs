@@ -3,9 +3,10 @@
 //! A set that can only add elements, never remove them.
 //! Uses zero allocation with a fixed array for deterministic memory usage.
 
+use crate::clock::fnv::{StateHash, hash_value};
 use crate::error::{CRDTError, CRDTResult};
 use crate::memory::MemoryConfig;
-use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+use crate::traits::{BoundedCRDT, CRDT, MemoryBreakdown, RealTimeCRDT, Snapshotable};
 
 #[cfg(feature = "hardware-atomic")]
 use core::cell::UnsafeCell;
@@ -15,6 +16,11 @@ use core::sync::atomic::{AtomicUsize, Ordering};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "postcard")]
+use crate::serialization::postcard::{seq_max_size, varint_len};
+#[cfg(feature = "postcard")]
+use postcard::experimental::max_size::MaxSize;
+
 /// Grow-only Set with configurable element array
 ///
 /// This set can only add elements and provides eventual consistency
@@ -123,7 +129,7 @@ where
     /// let set = GSet::<u32, DefaultConfig, 32>::with_capacity();
     /// assert!(set.is_empty());
     /// ```
-    pub fn with_capacity() -> Self {
+    pub const fn with_capacity() -> Self {
         #[cfg(not(feature = "hardware-atomic"))]
         {
             Self {
@@ -417,7 +423,7 @@ where
             let elements_ref = unsafe { &*self.elements.get() };
 
             // Use fixed-size array instead of Vec for no_std compatibility
-            let mut collected = [None; 16];
+            let mut collected = [None; CAPACITY];
             let mut idx = 0;
             for opt in elements_ref.iter().take(current_count) {
                 if let Some(element) = opt.as_ref() {
@@ -431,21 +437,228 @@ where
         }
     }
 
-    /// Converts the set to an array
+    /// Creates a set holding the elements of an array
+    ///
+    /// Duplicates in `elements` are stored once.
+    ///
+    /// # Arguments
+    /// * `elements` - The initial elements
     ///
     /// # Returns
-    /// An array containing all elements in the set, with None for unused slots
-    pub fn to_array(&self) -> [Option<T>; CAPACITY] {
-        #[cfg(not(feature = "hardware-atomic"))]
-        {
-            self.elements.clone()
+    /// The new set, or `BufferOverflow` if there are more distinct elements
+    /// than `CAPACITY`
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let set = GSet::<u8, DefaultConfig, 8>::from_array(&[3, 1, 2, 1])?;
+    /// assert_eq!(set.len(), 3);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    #[cfg_attr(feature = "hardware-atomic", allow(unused_mut))]
+    pub fn from_array<const N: usize>(elements: &[T; N]) -> CRDTResult<Self> {
+        let mut set = Self::with_capacity();
+        set.dedup_add_all(elements.iter().cloned())?;
+        Ok(set)
+    }
+
+    /// Inserts every element that is not already in the set
+    ///
+    /// Useful when bulk-syncing data that largely overlaps with the set.
+    ///
+    /// # Arguments
+    /// * `elements` - The elements to insert
+    ///
+    /// # Returns
+    /// The number of newly inserted elements, or `BufferOverflow` if the set
+    /// filled up (elements inserted before that point are kept)
+    #[cfg(not(feature = "hardware-atomic"))]
+    pub fn dedup_add_all<I: IntoIterator<Item = T>>(&mut self, elements: I) -> CRDTResult<usize> {
+        let mut added = 0;
+        for element in elements {
+            if self.insert(element)? {
+                added += 1;
+            }
         }
+        Ok(added)
+    }
 
-        #[cfg(feature = "hardware-atomic")]
-        {
-            let elements_ref = unsafe { &*self.elements.get() };
-            elements_ref.clone()
+    /// Inserts every element that is not already in the set (atomic version)
+    ///
+    /// # Arguments
+    /// * `elements` - The elements to insert
+    ///
+    /// # Returns
+    /// The number of newly inserted elements, or `BufferOverflow` if the set
+    /// filled up (elements inserted before that point are kept)
+    #[cfg(feature = "hardware-atomic")]
+    pub fn dedup_add_all<I: IntoIterator<Item = T>>(&self, elements: I) -> CRDTResult<usize> {
+        let mut added = 0;
+        for element in elements {
+            if self.insert(element)? {
+                added += 1;
+            }
         }
+        Ok(added)
+    }
+
+    /// Copies up to `N` elements into a fixed-size array
+    ///
+    /// # Returns
+    /// The array, with `None` in unused slots, and the number of elements
+    /// written. Elements beyond the first `N` are left out.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let set = GSet::<u8, DefaultConfig, 8>::from_array(&[1, 2, 3])?;
+    /// let (array, written) = set.to_array::<2>();
+    /// assert_eq!(written, 2);
+    /// assert!(array.iter().all(Option::is_some));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn to_array<const N: usize>(&self) -> ([Option<T>; N], usize) {
+        let mut array = [const { None }; N];
+        let mut written = 0;
+        for (slot, element) in array.iter_mut().zip(self.iter()) {
+            *slot = Some(element.clone());
+            written += 1;
+        }
+        (array, written)
+    }
+
+    /// Copies up to `N` elements into a fixed-size array in ascending order
+    ///
+    /// When the set holds more than `N` elements, the `N` smallest are kept.
+    ///
+    /// # Returns
+    /// The sorted array, with `None` in unused slots, and the number of
+    /// elements written
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let set = GSet::<u8, DefaultConfig, 8>::from_array(&[3, 1, 2])?;
+    /// let (sorted, written) = set.to_sorted_array::<4>();
+    /// assert_eq!(written, 3);
+    /// assert_eq!(sorted, [Some(1), Some(2), Some(3), None]);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn to_sorted_array<const N: usize>(&self) -> ([Option<T>; N], usize)
+    where
+        T: Ord,
+    {
+        let mut array: [Option<T>; N] = [const { None }; N];
+        let mut written = 0;
+        for element in self.iter() {
+            // Insertion sort, dropping the largest element once full
+            let mut pos = written;
+            while pos > 0 && array[pos - 1].as_ref().is_some_and(|prev| prev > element) {
+                pos -= 1;
+            }
+            if pos == N {
+                continue;
+            }
+            if written == N {
+                written -= 1;
+            }
+            array[pos..=written].rotate_right(1);
+            array[pos] = Some(element.clone());
+            written += 1;
+        }
+        (array, written)
+    }
+
+    /// Returns an iterator over the elements in ascending order
+    ///
+    /// Each call to `next()` scans the whole set for the next larger element,
+    /// so a full pass costs O(N²) comparisons but never allocates. The order
+    /// does not depend on the order elements were inserted or merged in.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut set1 = GSet::<u8, DefaultConfig, 8>::from_array(&[5, 1])?;
+    /// let set2 = GSet::<u8, DefaultConfig, 8>::from_array(&[3, 5])?;
+    /// set1.merge(&set2)?;
+    /// assert!(set1.iter_sorted().eq([1, 3, 5].iter()));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn iter_sorted(&self) -> SortedGSetIter<'_, T, C, CAPACITY>
+    where
+        T: Ord,
+    {
+        SortedGSetIter {
+            set: self,
+            last: None,
+        }
+    }
+
+    /// Copies up to `N` elements into a fixed-size array in ascending order
+    ///
+    /// Unlike [`to_sorted_array`](Self::to_sorted_array), unused slots are
+    /// filled with `T::default()`, so only the first `written` entries are
+    /// meaningful.
+    ///
+    /// # Returns
+    /// The sorted array and the number of elements written. When the set
+    /// holds more than `N` elements, the `N` smallest are kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let set = GSet::<u8, DefaultConfig, 8>::from_array(&[3, 1, 2])?;
+    /// let (sorted, written) = set.to_sorted_slice::<4>();
+    /// assert_eq!(&sorted[..written], &[1, 2, 3]);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn to_sorted_slice<const N: usize>(&self) -> ([T; N], usize)
+    where
+        T: Ord + Default,
+    {
+        let mut array: [T; N] = core::array::from_fn(|_| T::default());
+        let mut written = 0;
+        for (slot, element) in array.iter_mut().zip(self.iter_sorted()) {
+            *slot = element.clone();
+            written += 1;
+        }
+        (array, written)
+    }
+
+    /// Returns the smallest element
+    ///
+    /// # Returns
+    /// The smallest element, or None if the set is empty
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns the largest element
+    ///
+    /// # Returns
+    /// The largest element, or None if the set is empty
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns the element at position `n` in ascending order
+    ///
+    /// # Arguments
+    /// * `n` - Zero-based rank, so `nth_smallest(0)` is the minimum
+    ///
+    /// # Returns
+    /// The element, or None if the set has `n` or fewer elements
+    pub fn nth_smallest(&self, n: usize) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter_sorted().nth(n)
     }
 
     /// Checks if this set is a subset of another set
@@ -456,12 +669,7 @@ where
     /// # Returns
     /// true if all elements in this set are also in the other set
     pub fn is_subset(&self, other: &Self) -> bool {
-        for element in self.iter() {
-            if !other.contains(element) {
-                return false;
-            }
-        }
-        true
+        self.is_subset_of(other)
     }
 
     /// Checks if this set is a superset of another set
@@ -472,27 +680,187 @@ where
     /// # Returns
     /// true if all elements in the other set are also in this set
     pub fn is_superset(&self, other: &Self) -> bool {
-        other.is_subset(self)
+        self.is_superset_of(other)
+    }
+
+    /// Checks if this set is a subset of a set of any capacity
+    ///
+    /// # Arguments
+    /// * `other` - The other set to compare against
+    ///
+    /// # Returns
+    /// true if all elements in this set are also in the other set
+    pub fn is_subset_of<const CAP2: usize>(&self, other: &GSet<T, C, CAP2>) -> bool {
+        self.iter().all(|element| other.contains(element))
+    }
+
+    /// Checks if this set is a superset of a set of any capacity
+    ///
+    /// # Arguments
+    /// * `other` - The other set to compare against
+    ///
+    /// # Returns
+    /// true if all elements in the other set are also in this set
+    pub fn is_superset_of<const CAP2: usize>(&self, other: &GSet<T, C, CAP2>) -> bool {
+        other.is_subset_of(self)
     }
 
     /// Returns the union of this set with another set (without modifying either)
     ///
+    /// GSet carries no node ID, so the result is a plain aggregate that any
+    /// node (typically a gateway) can merge or query.
+    ///
     /// # Arguments
     /// * `other` - The other set to union with
     ///
     /// # Returns
-    /// A new set containing all elements from both sets, or an error if the result would be too large
-    pub fn union(&self, other: &Self) -> CRDTResult<Self>
+    /// A new set containing all elements from both sets, or
+    /// `BufferOverflow` if the result does not fit in `OUT`
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut ecu1 = GSet::<u8, DefaultConfig, 4>::with_capacity();
+    /// ecu1.insert(1)?;
+    /// ecu1.insert(2)?;
+    /// let mut ecu2 = GSet::<u8, DefaultConfig, 8>::with_capacity();
+    /// ecu2.insert(2)?;
+    /// ecu2.insert(3)?;
+    ///
+    /// let all: GSet<u8, DefaultConfig, 12> = ecu1.union(&ecu2)?;
+    /// assert_eq!(all.len(), 3);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn union<const CAP2: usize, const OUT: usize>(
+        &self,
+        other: &GSet<T, C, CAP2>,
+    ) -> CRDTResult<GSet<T, C, OUT>> {
+        Self::collect_into(self.iter().chain(other.iter()))
+    }
+
+    /// Returns the elements present in both sets (without modifying either)
+    ///
+    /// # Arguments
+    /// * `other` - The other set to intersect with
+    ///
+    /// # Returns
+    /// A new set containing the shared elements, or `BufferOverflow` if the
+    /// result does not fit in `OUT`
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut ecu1 = GSet::<u8, DefaultConfig, 4>::with_capacity();
+    /// ecu1.insert(1)?;
+    /// ecu1.insert(2)?;
+    /// let mut ecu2 = GSet::<u8, DefaultConfig, 8>::with_capacity();
+    /// ecu2.insert(2)?;
+    /// ecu2.insert(3)?;
+    ///
+    /// let shared: GSet<u8, DefaultConfig, 4> = ecu1.intersection(&ecu2)?;
+    /// assert!(shared.contains(&2));
+    /// assert_eq!(shared.len(), 1);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn intersection<const CAP2: usize, const OUT: usize>(
+        &self,
+        other: &GSet<T, C, CAP2>,
+    ) -> CRDTResult<GSet<T, C, OUT>> {
+        Self::collect_into(self.iter().filter(|element| other.contains(element)))
+    }
+
+    /// Returns the elements of this set that are not in the other set
+    /// (without modifying either)
+    ///
+    /// # Arguments
+    /// * `other` - The set whose elements are excluded
+    ///
+    /// # Returns
+    /// A new set containing the elements exclusive to this set, or
+    /// `BufferOverflow` if the result does not fit in `OUT`
+    pub fn difference<const CAP2: usize, const OUT: usize>(
+        &self,
+        other: &GSet<T, C, CAP2>,
+    ) -> CRDTResult<GSet<T, C, OUT>> {
+        Self::collect_into(self.iter().filter(|element| !other.contains(element)))
+    }
+
+    /// Counts the elements of this set that are not in the other set
+    ///
+    /// Like [`difference`](Self::difference), but only counts: a single pass
+    /// over this set with no result set to fill.
+    ///
+    /// # Arguments
+    /// * `other` - The set whose elements are excluded
+    ///
+    /// # Returns
+    /// The number of elements exclusive to this set
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut seen = GSet::<u8, DefaultConfig, 4>::with_capacity();
+    /// seen.insert(1)?;
+    /// seen.insert(2)?;
+    /// let mut acked = GSet::<u8, DefaultConfig, 8>::with_capacity();
+    /// acked.insert(2)?;
+    ///
+    /// assert_eq!(seen.difference_count(&acked), 1);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn difference_count<const CAP2: usize>(&self, other: &GSet<T, C, CAP2>) -> usize {
+        self.iter()
+            .filter(|element| !other.contains(element))
+            .count()
+    }
+
+    /// Builds a new set from the given elements, skipping duplicates
+    #[cfg_attr(feature = "hardware-atomic", allow(unused_mut))]
+    fn collect_into<'a, const OUT: usize>(
+        elements: impl Iterator<Item = &'a T>,
+    ) -> CRDTResult<GSet<T, C, OUT>>
     where
-        T: core::fmt::Debug,
+        T: 'a,
     {
-        let mut result = self.clone();
-        result.merge(other)?;
+        let mut result = GSet::<T, C, OUT>::with_capacity();
+        for element in elements {
+            result.insert(element.clone())?;
+        }
         Ok(result)
     }
 }
 
 // Serde implementation for GSet
+/// Iterator over the elements of a [`GSet`] in ascending order
+///
+/// Created by [`GSet::iter_sorted`].
+#[derive(Debug)]
+pub struct SortedGSetIter<'a, T, C: MemoryConfig, const CAPACITY: usize> {
+    /// The set being iterated
+    set: &'a GSet<T, C, CAPACITY>,
+    /// The element returned by the previous call to `next()`
+    last: Option<&'a T>,
+}
+
+impl<'a, T, C: MemoryConfig, const CAPACITY: usize> Iterator for SortedGSetIter<'a, T, C, CAPACITY>
+where
+    T: Clone + PartialEq + Ord,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        // Elements are unique, so the next one is the smallest above `last`
+        let last = self.last;
+        let next = self
+            .set
+            .iter()
+            .filter(|&element| last.is_none_or(|last| element > last))
+            .min()?;
+        self.last = Some(next);
+        Some(next)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<T, C: MemoryConfig, const CAPACITY: usize> Serialize for GSet<T, C, CAPACITY>
 where
@@ -536,6 +904,7 @@ where
     where
         D: Deserializer<'de>,
     {
+        use crate::serialization::fields::SeqFields;
         use core::fmt;
         use serde::de::{self, MapAccess, Visitor};
 
@@ -560,6 +929,13 @@ where
                 formatter.write_str("struct GSet")
             }
 
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                self.visit_map(SeqFields::new(seq, FIELDS))
+            }
+
             fn visit_map<V>(self, mut map: V) -> Result<GSet<T, C, CAPACITY>, V::Error>
             where
                 V: MapAccess<'de>,
@@ -704,6 +1080,71 @@ where
     }
 }
 
+// Postcard size bound for GSet
+#[cfg(feature = "postcard")]
+impl<T, C: MemoryConfig, const CAPACITY: usize> MaxSize for GSet<T, C, CAPACITY>
+where
+    T: MaxSize,
+{
+    const POSTCARD_MAX_SIZE: usize =
+        { seq_max_size(CAPACITY, Option::<T>::POSTCARD_MAX_SIZE) + varint_len(CAPACITY) };
+}
+
+// CBOR implementation for GSet
+#[cfg(feature = "cbor")]
+impl<Ctx, T, C: MemoryConfig, const CAPACITY: usize> minicbor::Encode<Ctx> for GSet<T, C, CAPACITY>
+where
+    T: minicbor::Encode<Ctx> + Clone + PartialEq,
+{
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.array(self.len() as u64)?;
+        for element in self.iter() {
+            e.encode_with(element, ctx)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<'b, Ctx, T, C: MemoryConfig, const CAPACITY: usize> minicbor::Decode<'b, Ctx>
+    for GSet<T, C, CAPACITY>
+where
+    T: minicbor::Decode<'b, Ctx> + Clone + PartialEq,
+{
+    fn decode(
+        d: &mut minicbor::Decoder<'b>,
+        ctx: &mut Ctx,
+    ) -> Result<Self, minicbor::decode::Error> {
+        let count = crate::serialization::cbor::bounded_array_len(d, CAPACITY)?;
+        let mut elements: [Option<T>; CAPACITY] = [const { None }; CAPACITY];
+        for slot in elements.iter_mut().take(count) {
+            *slot = Some(d.decode_with(ctx)?);
+        }
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            Ok(GSet {
+                elements,
+                count,
+                _phantom: core::marker::PhantomData,
+            })
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            Ok(GSet {
+                elements: UnsafeCell::new(elements),
+                count: AtomicUsize::new(count),
+                _phantom: core::marker::PhantomData,
+            })
+        }
+    }
+}
+
 impl<T, C: MemoryConfig> Default for GSet<T, C>
 where
     T: Clone + PartialEq,
@@ -715,7 +1156,7 @@ where
 
 impl<T, C: MemoryConfig, const CAPACITY: usize> CRDT<C> for GSet<T, C, CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     type Error = CRDTError;
 
@@ -740,7 +1181,7 @@ where
             for element in other.iter() {
                 if !self.contains(element) {
                     let current_count = self.count.load(Ordering::Relaxed);
-                    if current_count >= 16 {
+                    if current_count >= CAPACITY {
                         return Err(CRDTError::BufferOverflow);
                     }
 
@@ -813,7 +1254,7 @@ where
         #[cfg(not(feature = "hardware-atomic"))]
         {
             // Validate count is within bounds
-            if self.count > 16 {
+            if self.count > CAPACITY {
                 return Err(CRDTError::ConfigurationExceeded);
             }
 
@@ -842,7 +1283,7 @@ where
             let elements_ref = unsafe { &*self.elements.get() };
 
             // Validate count is within bounds
-            if current_count > 16 {
+            if current_count > CAPACITY {
                 return Err(CRDTError::ConfigurationExceeded);
             }
 
@@ -869,13 +1310,10 @@ where
     }
 
     fn state_hash(&self) -> u32 {
-        // Simple hash based on elements (order-independent)
+        // XOR of per-element FNV-1a hashes (order-independent)
         let mut hash = 0u32;
         for element in self.iter() {
-            // This is a simplified hash - in practice you'd want a proper hash function
-            // For now, we'll use the memory address as a simple hash
-            let element_ptr = element as *const T as usize;
-            hash ^= element_ptr as u32;
+            hash ^= hash_value(element);
         }
 
         #[cfg(not(feature = "hardware-atomic"))]
@@ -902,19 +1340,19 @@ where
 
         #[cfg(not(feature = "hardware-atomic"))]
         {
-            self.count + unique_in_other <= 16
+            self.count + unique_in_other <= CAPACITY
         }
 
         #[cfg(feature = "hardware-atomic")]
         {
-            self.count.load(Ordering::Relaxed) + unique_in_other <= 16
+            self.count.load(Ordering::Relaxed) + unique_in_other <= CAPACITY
         }
     }
 }
 
 impl<T, C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for GSet<T, C, CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
     const MAX_ELEMENTS: usize = CAPACITY; // Maximum number of elements
@@ -945,11 +1383,21 @@ where
         // For fixed-size arrays, only check element count, not memory usage
         self.element_count() < Self::MAX_ELEMENTS
     }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        let count = self.element_count();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            count * core::mem::size_of::<T>(),
+            0,
+            (CAPACITY - count) * core::mem::size_of::<Option<T>>(),
+        )
+    }
 }
 
 impl<T, C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for GSet<T, C, CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_MERGE_CYCLES: u32 = 200; // Linear in number of elements
     const MAX_VALIDATE_CYCLES: u32 = 100;
@@ -975,6 +1423,18 @@ where
     }
 }
 
+impl<T, C: MemoryConfig, const CAPACITY: usize> Snapshotable<C> for GSet<T, C, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    /// The element count, since elements are only ever added
+    type Snapshot = usize;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1068,17 +1528,90 @@ mod tests {
         set.insert(3).unwrap();
         set.insert(2).unwrap();
 
-        let array = set.to_array();
+        let (array, written) = set.to_array::<4>();
+        assert_eq!(written, 3);
+        assert_eq!(array[3], None);
         let mut elements = [0u32; 3];
-        let mut i = 0;
-        for opt in array.iter() {
-            if let Some(val) = opt {
-                elements[i] = *val;
-                i += 1;
-            }
+        for (i, opt) in array.iter().take(written).enumerate() {
+            elements[i] = opt.unwrap();
         }
         elements.sort(); // Order is not guaranteed
         assert_eq!(elements, [1, 2, 3]);
+
+        // Smaller arrays are filled up to their length
+        let (array, written) = set.to_array::<2>();
+        assert_eq!(written, 2);
+        assert!(array.iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn test_from_array_sorted_roundtrip() {
+        let input = [42u16, 7, 19, 3, 25];
+        let set = GSet::<u16, DefaultConfig, 8>::from_array(&input).unwrap();
+        assert_eq!(set.len(), 5);
+
+        let (sorted, written) = set.to_sorted_array::<5>();
+        assert_eq!(written, 5);
+        assert_eq!(sorted, [Some(3), Some(7), Some(19), Some(25), Some(42)]);
+
+        let again = GSet::<u16, DefaultConfig, 8>::from_array(&sorted.map(Option::unwrap)).unwrap();
+        assert!(set.eq(&again));
+
+        // Only the smallest elements fit in a shorter array
+        let (smallest, written) = set.to_sorted_array::<3>();
+        assert_eq!(written, 3);
+        assert_eq!(smallest, [Some(3), Some(7), Some(19)]);
+
+        // Duplicates collapse, too many distinct elements overflow
+        let set = GSet::<u16, DefaultConfig, 2>::from_array(&[1, 1, 2]).unwrap();
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            GSet::<u16, DefaultConfig, 2>::from_array(&[1, 2, 3]).unwrap_err(),
+            CRDTError::BufferOverflow
+        );
+    }
+
+    #[test]
+    fn test_sorted_iteration() {
+        let empty = GSet::<u16, DefaultConfig, 8>::with_capacity();
+        assert_eq!(empty.iter_sorted().next(), None);
+        assert_eq!(empty.min_element(), None);
+        assert_eq!(empty.max_element(), None);
+        assert_eq!(empty.nth_smallest(0), None);
+
+        // Overlapping elements from two nodes appear once after the merge
+        let mut node1 = GSet::<u16, DefaultConfig, 8>::from_array(&[40, 10, 30]).unwrap();
+        let node2 = GSet::<u16, DefaultConfig, 8>::from_array(&[30, 20, 10]).unwrap();
+        let mut merged_other_way = node2.clone();
+        merged_other_way.merge(&node1).unwrap();
+        node1.merge(&node2).unwrap();
+
+        assert!(node1.iter_sorted().eq([10, 20, 30, 40].iter()));
+        assert!(node1.iter_sorted().eq(merged_other_way.iter_sorted()));
+        assert_eq!(node1.min_element(), Some(&10));
+        assert_eq!(node1.max_element(), Some(&40));
+        assert_eq!(node1.nth_smallest(2), Some(&30));
+        assert_eq!(node1.nth_smallest(4), None);
+
+        let (sorted, written) = node1.to_sorted_slice::<6>();
+        assert_eq!(written, 4);
+        assert_eq!(sorted, [10, 20, 30, 40, 0, 0]);
+        let (smallest, written) = node1.to_sorted_slice::<2>();
+        assert_eq!(written, 2);
+        assert_eq!(smallest, [10, 20]);
+    }
+
+    #[test]
+    fn test_dedup_add_all() {
+        let mut set = GSet::<u8, DefaultConfig, 4>::with_capacity();
+        assert_eq!(set.dedup_add_all([1, 2]).unwrap(), 2);
+        assert_eq!(set.dedup_add_all([2, 3, 1, 3]).unwrap(), 1);
+        assert_eq!(set.len(), 3);
+
+        // Known elements never count against the remaining capacity
+        assert_eq!(set.dedup_add_all([1, 2, 3, 4]).unwrap(), 1);
+        assert!(set.is_full());
+        assert_eq!(set.dedup_add_all([4, 5]), Err(CRDTError::BufferOverflow));
     }
 
     #[test]
@@ -1193,7 +1726,7 @@ mod tests {
         set2.insert(2).unwrap();
         set2.insert(3).unwrap();
 
-        let union = set1.union(&set2).unwrap();
+        let union: GSet<u32, DefaultConfig> = set1.union(&set2).unwrap();
 
         assert_eq!(union.len(), 3);
         assert!(union.contains(&1));
@@ -1205,6 +1738,77 @@ mod tests {
         assert_eq!(set2.len(), 2);
     }
 
+    #[test]
+    fn test_intersection_and_difference() {
+        let mut ecu1 = GSet::<u8, DefaultConfig, 4>::with_capacity();
+        let mut ecu2 = GSet::<u8, DefaultConfig, 8>::with_capacity();
+        for sensor in [1, 2, 3] {
+            ecu1.insert(sensor).unwrap();
+        }
+        for sensor in [2, 3, 4, 5] {
+            ecu2.insert(sensor).unwrap();
+        }
+
+        let shared: GSet<u8, DefaultConfig, 4> = ecu1.intersection(&ecu2).unwrap();
+        assert_eq!(shared.len(), 2);
+        assert!(shared.contains(&2) && shared.contains(&3));
+
+        let only_ecu1: GSet<u8, DefaultConfig, 4> = ecu1.difference(&ecu2).unwrap();
+        assert_eq!(only_ecu1.len(), 1);
+        assert!(only_ecu1.contains(&1));
+
+        let only_ecu2: GSet<u8, DefaultConfig, 4> = ecu2.difference(&ecu1).unwrap();
+        assert_eq!(only_ecu2.len(), 2);
+        assert!(only_ecu2.contains(&4) && only_ecu2.contains(&5));
+        assert_eq!(ecu1.difference_count(&ecu2), 1);
+        assert_eq!(ecu2.difference_count(&ecu1), 2);
+        assert_eq!(ecu1.difference_count(&ecu1), 0);
+
+        // Inputs are untouched
+        assert_eq!(ecu1.len(), 3);
+        assert_eq!(ecu2.len(), 4);
+    }
+
+    #[test]
+    fn test_set_operations_output_overflow() {
+        let mut set1 = GSet::<u8, DefaultConfig, 4>::with_capacity();
+        let mut set2 = GSet::<u8, DefaultConfig, 4>::with_capacity();
+        for element in [1, 2, 3] {
+            set1.insert(element).unwrap();
+        }
+        for element in [3, 4, 5] {
+            set2.insert(element).unwrap();
+        }
+
+        // Five distinct elements do not fit in four slots
+        let union: CRDTResult<GSet<u8, DefaultConfig, 4>> = set1.union(&set2);
+        assert_eq!(union.err(), Some(CRDTError::BufferOverflow));
+        let union: GSet<u8, DefaultConfig, 5> = set1.union(&set2).unwrap();
+        assert_eq!(union.len(), 5);
+
+        let difference: CRDTResult<GSet<u8, DefaultConfig, 1>> = set1.difference(&set2);
+        assert_eq!(difference.err(), Some(CRDTError::BufferOverflow));
+
+        // An empty result fits in any capacity
+        let empty = GSet::<u8, DefaultConfig, 4>::with_capacity();
+        let intersection: GSet<u8, DefaultConfig, 1> = set1.intersection(&empty).unwrap();
+        assert!(intersection.is_empty());
+    }
+
+    #[test]
+    fn test_subset_of_mixed_capacity() {
+        let mut small = GSet::<u8, DefaultConfig, 2>::with_capacity();
+        let mut large = GSet::<u8, DefaultConfig, 8>::with_capacity();
+        small.insert(1).unwrap();
+        large.insert(1).unwrap();
+        large.insert(2).unwrap();
+
+        assert!(small.is_subset_of(&large));
+        assert!(!large.is_subset_of(&small));
+        assert!(large.is_superset_of(&small));
+        assert!(!small.is_superset_of(&large));
+    }
+
     #[test]
     fn test_bounded_crdt() {
         let mut set = GSet::<u32, DefaultConfig>::new();
@@ -1336,6 +1940,58 @@ mod tests {
         assert!(set1.contains(&3));
     }
 
+    #[test]
+    fn test_capacity_above_default() {
+        let mut set1 = GSet::<u32, DefaultConfig, 32>::with_capacity();
+        let mut set2 = GSet::<u32, DefaultConfig, 32>::with_capacity();
+        for i in 0..12 {
+            set1.insert(i).unwrap();
+            set2.insert(i + 100).unwrap();
+        }
+
+        assert!(set1.can_merge(&set2));
+        set1.merge(&set2).unwrap();
+        assert_eq!(set1.len(), 24);
+        assert_eq!(set1.iter().count(), 24);
+        assert!(set1.validate().is_ok());
+    }
+
+    #[test]
+    fn test_state_hash_across_nodes() {
+        let mut node1 = GSet::<u32, DefaultConfig>::new();
+        let mut node2 = GSet::<u32, DefaultConfig>::new();
+        for element in [1, 2, 3] {
+            node1.insert(element).unwrap();
+        }
+        for element in [3, 1, 2] {
+            node2.insert(element).unwrap();
+        }
+        assert_eq!(node1.state_hash(), node2.state_hash());
+
+        // Independent of where the elements are stored
+        let copy = node1.clone();
+        assert_eq!(node1.state_hash(), copy.state_hash());
+
+        node2.insert(4).unwrap();
+        assert_ne!(node1.state_hash(), node2.state_hash());
+        node1.merge(&node2).unwrap();
+        assert_eq!(node1.state_hash(), node2.state_hash());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut set = GSet::<u32, DefaultConfig>::new();
+        set.insert(1).unwrap();
+        let snap = set.snapshot();
+
+        // Re-inserting an existing element is a no-op
+        set.insert(1).unwrap();
+        assert!(!set.has_changed_since(&snap));
+
+        set.insert(2).unwrap();
+        assert!(set.has_changed_since(&snap));
+    }
+
     #[cfg(all(test, feature = "serde"))]
     mod serde_tests {
         use super::*;
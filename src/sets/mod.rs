@@ -1,11 +1,17 @@
 //! Set CRDT implementations
 //!
 //! This module provides set-based CRDTs for tracking collections of elements
-//! with different semantics (grow-only, add/remove).
+//! with different semantics (grow-only, add/remove, two-phase).
 
+pub mod bloom;
 pub mod gset;
 pub mod orset;
+pub mod twopset;
 
 // Re-export main types
-pub use gset::GSet;
-pub use orset::ORSet;
+pub use bloom::GSetBloom;
+pub use gset::{GSet, SortedGSetIter};
+pub use orset::{ConflictPolicy, FrozenORSet, ORSet};
+#[cfg(feature = "diagnostics")]
+pub use orset::{DiffReport, MergeReport};
+pub use twopset::TwoPSet;
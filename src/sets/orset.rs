@@ -4,9 +4,15 @@
 //! Uses zero allocation with fixed arrays for deterministic memory usage.
 
 use crate::clock::CompactTimestamp;
-use crate::error::{CRDTError, CRDTResult};
+use crate::clock::fnv::{StateHash, hash_value};
+use crate::error::{CRDTError, CRDTResult, ErrorContext};
 use crate::memory::{MemoryConfig, NodeId};
-use crate::traits::{BoundedCRDT, CRDT, RealTimeCRDT};
+use crate::sets::GSet;
+use crate::traits::bounded::utilization_percent;
+use crate::traits::snapshot::stamp_hash;
+use crate::traits::{
+    BoundedCRDT, CRDT, DeltaCRDT, EvictionPolicy, MemoryBreakdown, RealTimeCRDT, Snapshotable,
+};
 
 #[cfg(feature = "hardware-atomic")]
 use core::cell::UnsafeCell;
@@ -21,6 +27,31 @@ use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "postcard")]
+use crate::serialization::postcard::{seq_max_size, varint_len};
+#[cfg(feature = "postcard")]
+use postcard::experimental::max_size::MaxSize;
+
+/// How an [`ORSet`] resolves an add and a remove with equal timestamps
+///
+/// When one node adds an element at the same timestamp another node removes
+/// it, the two operations are concurrent and neither observed the other.
+/// Standard OR-Set semantics let the remove win; many applications expect
+/// the add to win instead.
+///
+/// The policy only decides ties. An add strictly newer than every remove
+/// always makes the element present, and a remove strictly newer than every
+/// add always hides it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConflictPolicy {
+    /// An add with the same timestamp as the latest remove keeps the element
+    AddWins,
+    /// A remove with the same timestamp as the latest add hides the element
+    #[default]
+    RemoveWins,
+}
+
 /// Observed-Remove Set with configurable element and tombstone arrays
 ///
 /// This set supports both add and remove operations by using unique tags
@@ -31,10 +62,16 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// - `T`: The element type stored in the set
 /// - `C`: Memory configuration that determines the default maximum number of elements
 /// - `CAPACITY`: The maximum number of elements this set can hold (defaults to 8)
+/// - `TOMBSTONE_CAPACITY`: The maximum number of tombstones (defaults to `CAPACITY`)
+///
+/// Sets that see few removals can use a smaller tombstone array, e.g.
+/// `C::MAX_TOMBSTONE_ENTRIES` of the target configuration.
 ///
 /// # Memory Usage
-/// - Fixed size: 2 * (sizeof(T) + 9) * CAPACITY + 16 bytes
-/// - Example: For u32 with 8 elements = ~224 bytes, with 16 elements = ~432 bytes
+/// - Fixed size: one element slot per CAPACITY and one tombstone slot per
+///   TOMBSTONE_CAPACITY, plus 24 bytes of counts and node ID
+/// - Example: For u32 with 8 elements = 480 bytes, with 8 elements and
+///   4 tombstones = 352 bytes
 /// - Completely predictable at compile time
 ///
 /// # Example
@@ -63,7 +100,12 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// # Ok::<(), crdtosphere::error::CRDTError>(())
 /// ```
 #[derive(Debug)]
-pub struct ORSet<T, C: MemoryConfig, const CAPACITY: usize = 8> {
+pub struct ORSet<
+    T,
+    C: MemoryConfig,
+    const CAPACITY: usize = 8,
+    const TOMBSTONE_CAPACITY: usize = CAPACITY,
+> {
     /// Elements with their tags
     #[cfg(not(feature = "hardware-atomic"))]
     elements: [Option<ElementEntry<T>>; CAPACITY],
@@ -72,7 +114,7 @@ pub struct ORSet<T, C: MemoryConfig, const CAPACITY: usize = 8> {
 
     /// Removed element tags (tombstones)
     #[cfg(not(feature = "hardware-atomic"))]
-    tombstones: [Option<TombstoneEntry<T>>; CAPACITY],
+    tombstones: [Option<TombstoneEntry<T>>; TOMBSTONE_CAPACITY],
     #[cfg(not(feature = "hardware-atomic"))]
     tombstone_count: usize,
 
@@ -83,13 +125,23 @@ pub struct ORSet<T, C: MemoryConfig, const CAPACITY: usize = 8> {
     element_count: AtomicUsize,
 
     #[cfg(feature = "hardware-atomic")]
-    tombstones: UnsafeCell<[Option<TombstoneEntry<T>>; CAPACITY]>,
+    tombstones: UnsafeCell<[Option<TombstoneEntry<T>>; TOMBSTONE_CAPACITY]>,
     #[cfg(feature = "hardware-atomic")]
     tombstone_count: AtomicUsize,
 
+    /// Number of present elements, kept in step with every change to the
+    /// entries so that `len` does not have to rescan them
+    #[cfg(not(feature = "hardware-atomic"))]
+    active_count: usize,
+    #[cfg(feature = "hardware-atomic")]
+    active_count: AtomicUsize,
+
     /// This node's ID
     node_id: NodeId,
 
+    /// How equal add and remove timestamps are resolved
+    conflict_policy: ConflictPolicy,
+
     /// Phantom data to maintain the memory config type
     _phantom: core::marker::PhantomData<C>,
 }
@@ -107,7 +159,8 @@ where
 }
 
 // Implement Clone manually due to atomic types not implementing Clone
-impl<T, C: MemoryConfig, const CAPACITY: usize> Clone for ORSet<T, C, CAPACITY>
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> Clone
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
     T: Clone,
 {
@@ -119,7 +172,9 @@ where
                 element_count: self.element_count,
                 tombstones: self.tombstones.clone(),
                 tombstone_count: self.tombstone_count,
+                active_count: self.active_count,
                 node_id: self.node_id,
+                conflict_policy: self.conflict_policy,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -134,7 +189,9 @@ where
                 element_count: AtomicUsize::new(self.element_count.load(Ordering::Relaxed)),
                 tombstones: UnsafeCell::new(cloned_tombstones),
                 tombstone_count: AtomicUsize::new(self.tombstone_count.load(Ordering::Relaxed)),
+                active_count: AtomicUsize::new(self.active_count.load(Ordering::Relaxed)),
                 node_id: self.node_id,
+                conflict_policy: self.conflict_policy,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -187,7 +244,8 @@ mod compact_timestamp_serde {
     }
 }
 
-impl<T, C: MemoryConfig, const CAPACITY: usize> ORSet<T, C, CAPACITY>
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+    ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
     T: Clone + PartialEq,
 {
@@ -211,9 +269,11 @@ where
             Self {
                 elements: [const { None }; CAPACITY],
                 element_count: 0,
-                tombstones: [const { None }; CAPACITY],
+                tombstones: [const { None }; TOMBSTONE_CAPACITY],
                 tombstone_count: 0,
+                active_count: 0,
                 node_id,
+                conflict_policy: ConflictPolicy::RemoveWins,
                 _phantom: core::marker::PhantomData,
             }
         }
@@ -223,13 +283,33 @@ where
             Self {
                 elements: UnsafeCell::new([const { None }; CAPACITY]),
                 element_count: AtomicUsize::new(0),
-                tombstones: UnsafeCell::new([const { None }; CAPACITY]),
+                tombstones: UnsafeCell::new([const { None }; TOMBSTONE_CAPACITY]),
                 tombstone_count: AtomicUsize::new(0),
+                active_count: AtomicUsize::new(0),
                 node_id,
+                conflict_policy: ConflictPolicy::RemoveWins,
                 _phantom: core::marker::PhantomData,
             }
         }
     }
+
+    /// Creates a new observed-remove set with an explicit conflict policy
+    ///
+    /// Sets built with `new` or `with_capacity` use
+    /// [`ConflictPolicy::RemoveWins`]. Every replica of a set must use the
+    /// same policy, otherwise merging fails with `CRDTError::InvalidMerge`.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    /// * `policy` - How equal add and remove timestamps are resolved
+    ///
+    /// # Returns
+    /// A new empty set
+    pub fn with_conflict_policy(node_id: NodeId, policy: ConflictPolicy) -> Self {
+        let mut set = Self::with_capacity(node_id);
+        set.conflict_policy = policy;
+        set
+    }
 }
 
 impl<T, C: MemoryConfig> ORSet<T, C, 8>
@@ -253,9 +333,52 @@ where
     pub fn new(node_id: NodeId) -> Self {
         Self::with_capacity(node_id)
     }
+
+    /// Creates a new set with default capacity where concurrent adds win
+    ///
+    /// An add and a remove of the same element with identical timestamps
+    /// leave the element present.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    ///
+    /// # Returns
+    /// A new empty set using [`ConflictPolicy::AddWins`]
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut sensors1 = ORSet::<u32, DefaultConfig>::new_add_wins(1);
+    /// let mut sensors2 = ORSet::<u32, DefaultConfig>::new_add_wins(2);
+    /// sensors1.add(7, 1000)?;
+    /// sensors2.merge(&sensors1)?;
+    ///
+    /// // Node 2's clock stamps the removal with the add's timestamp
+    /// sensors2.remove(&7, 1000)?;
+    /// sensors1.merge(&sensors2)?;
+    /// assert!(sensors1.contains(&7));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn new_add_wins(node_id: NodeId) -> Self {
+        Self::with_conflict_policy(node_id, ConflictPolicy::AddWins)
+    }
+
+    /// Creates a new set with default capacity where concurrent removes win
+    ///
+    /// This is the standard OR-Set behaviour and the same as [`new`](Self::new).
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of this node (must be < MAX_NODES)
+    ///
+    /// # Returns
+    /// A new empty set using [`ConflictPolicy::RemoveWins`]
+    pub fn new_remove_wins(node_id: NodeId) -> Self {
+        Self::with_conflict_policy(node_id, ConflictPolicy::RemoveWins)
+    }
 }
 
-impl<T, C: MemoryConfig, const CAPACITY: usize> ORSet<T, C, CAPACITY>
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+    ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
     T: Clone + PartialEq,
 {
@@ -280,8 +403,10 @@ where
     #[cfg(not(feature = "hardware-atomic"))]
     pub fn add(&mut self, element: T, timestamp: u64) -> CRDTResult<bool> {
         let new_timestamp = CompactTimestamp::new(timestamp);
+        let was_present = self.contains(&element);
 
         // Check if element already exists from this node
+        let mut refreshed = false;
         for existing in self.elements.iter_mut().take(self.element_count) {
             if let Some(existing_entry) = existing {
                 if existing_entry.element == element && existing_entry.node_id == self.node_id {
@@ -289,14 +414,22 @@ where
                     if new_timestamp > existing_entry.timestamp {
                         existing_entry.timestamp = new_timestamp;
                     }
-                    return Ok(false); // Element already exists from this node
+                    refreshed = true;
+                    break;
                 }
             }
         }
+        if refreshed {
+            // A newer add can bring back a removed element
+            let is_present = self.contains(&element);
+            self.shift_active_count(usize::from(was_present), usize::from(is_present));
+            return Ok(false); // Element already exists from this node
+        }
 
         // Check if we have space
         if self.element_count >= CAPACITY {
-            return Err(CRDTError::BufferOverflow);
+            let ctx = ErrorContext::new("ORSet", "add", self.node_id, self.element_count, CAPACITY);
+            return Err(CRDTError::BufferOverflow.with_context(ctx));
         }
 
         // Add the new element
@@ -306,6 +439,10 @@ where
             node_id: self.node_id,
         });
         self.element_count += 1;
+        let is_present = self.elements[self.element_count - 1]
+            .as_ref()
+            .is_some_and(|entry| self.contains(&entry.element));
+        self.shift_active_count(usize::from(was_present), usize::from(is_present));
         Ok(true)
     }
 
@@ -321,6 +458,7 @@ where
     #[cfg(feature = "hardware-atomic")]
     pub fn add(&self, element: T, timestamp: u64) -> CRDTResult<bool> {
         let new_timestamp = CompactTimestamp::new(timestamp);
+        let was_present = self.contains(&element);
 
         // Atomic compare-exchange loop for coordination
         loop {
@@ -343,7 +481,8 @@ where
 
             // Check if we have space
             if current_count >= CAPACITY {
-                return Err(CRDTError::BufferOverflow);
+                let ctx = ErrorContext::new("ORSet", "add", self.node_id, current_count, CAPACITY);
+                return Err(CRDTError::BufferOverflow.with_context(ctx));
             }
 
             // Try to atomically increment count to reserve a slot
@@ -361,6 +500,10 @@ where
                         timestamp: new_timestamp,
                         node_id: self.node_id,
                     });
+                    let is_present = elements_mut[current_count]
+                        .as_ref()
+                        .is_some_and(|entry| self.contains(&entry.element));
+                    self.shift_active_count(usize::from(was_present), usize::from(is_present));
                     return Ok(true);
                 }
                 Err(_) => {
@@ -379,7 +522,8 @@ where
     ///
     /// # Returns
     /// Ok(true) if the element was removed, Ok(false) if it wasn't present,
-    /// or an error if the tombstone storage is full
+    /// or `CRDTError::BufferOverflow` with a `"remove"` context whose
+    /// capacity is the tombstone capacity if the tombstone storage is full
     ///
     /// # Example
     /// ```rust
@@ -401,12 +545,14 @@ where
 
         // Find all matching elements to remove
         let mut removed_any = false;
+        let mut result = Ok(());
         for existing in self.elements.iter().take(self.element_count) {
             if let Some(existing_entry) = existing {
                 if existing_entry.element == *element {
                     // Check if we have space for tombstone
-                    if self.tombstone_count >= CAPACITY {
-                        return Err(CRDTError::BufferOverflow);
+                    if self.tombstone_count >= TOMBSTONE_CAPACITY {
+                        result = Err(self.tombstone_overflow("remove", self.tombstone_count));
+                        break;
                     }
 
                     // Add tombstone for this specific element entry
@@ -422,7 +568,10 @@ where
             }
         }
 
-        Ok(removed_any)
+        // Tombstones written before an overflow stay, so recheck either way
+        let is_present = self.contains(element);
+        self.shift_active_count(1, usize::from(is_present));
+        result.map(|()| removed_any)
     }
 
     /// Removes an element from the set (atomic version)
@@ -433,7 +582,8 @@ where
     ///
     /// # Returns
     /// Ok(true) if the element was removed, Ok(false) if it wasn't present,
-    /// or an error if the tombstone storage is full
+    /// or `CRDTError::BufferOverflow` with a `"remove"` context whose
+    /// capacity is the tombstone capacity if the tombstone storage is full
     #[cfg(feature = "hardware-atomic")]
     pub fn remove(&self, element: &T, timestamp: u64) -> CRDTResult<bool> {
         let remove_timestamp = CompactTimestamp::new(timestamp);
@@ -473,8 +623,8 @@ where
             }
 
             // Check if we have space for all tombstones
-            if current_tombstone_count + tombstones_to_add.len() > CAPACITY {
-                return Err(CRDTError::BufferOverflow);
+            if current_tombstone_count + tombstones_to_add.len() > TOMBSTONE_CAPACITY {
+                return Err(self.tombstone_overflow("remove", current_tombstone_count));
             }
 
             // Try to atomically reserve space for tombstones
@@ -491,6 +641,8 @@ where
                     for (i, tombstone) in tombstones_to_add.into_iter().enumerate() {
                         tombstones_mut[current_tombstone_count + i] = Some(tombstone);
                     }
+                    let is_present = self.contains(element);
+                    self.shift_active_count(1, usize::from(is_present));
                     return Ok(true);
                 }
                 Err(_) => {
@@ -569,8 +721,11 @@ where
 
             // Element is present if it was added after the latest remove (or never removed)
             match max_remove_timestamp {
-                None => true,                             // Never removed
-                Some(max_remove) => max_add > max_remove, // Added after latest remove
+                None => true, // Never removed
+                Some(max_remove) => match self.conflict_policy {
+                    ConflictPolicy::AddWins => max_add >= max_remove,
+                    ConflictPolicy::RemoveWins => max_add > max_remove, // Added after latest remove
+                },
             }
         }
 
@@ -625,14 +780,19 @@ where
 
             // Element is present if it was added after the latest remove (or never removed)
             match max_remove_timestamp {
-                None => true,                             // Never removed
-                Some(max_remove) => max_add > max_remove, // Added after latest remove
+                None => true, // Never removed
+                Some(max_remove) => match self.conflict_policy {
+                    ConflictPolicy::AddWins => max_add >= max_remove,
+                    ConflictPolicy::RemoveWins => max_add > max_remove, // Added after latest remove
+                },
             }
         }
     }
 
     /// Returns the number of elements currently in the set
     ///
+    /// The count is maintained by every operation, so this is O(1).
+    ///
     /// # Returns
     /// The count of elements (excluding removed ones)
     ///
@@ -650,66 +810,12 @@ where
     pub fn len(&self) -> usize {
         #[cfg(not(feature = "hardware-atomic"))]
         {
-            let mut count = 0;
-
-            // For each element entry, check if it's present and not already counted
-            for i in 0..self.element_count {
-                if let Some(entry) = &self.elements[i] {
-                    if self.contains(&entry.element) {
-                        // Check if we've already counted this element value
-                        let mut already_counted = false;
-                        for j in 0..i {
-                            if let Some(prev_entry) = &self.elements[j] {
-                                if prev_entry.element == entry.element
-                                    && self.contains(&prev_entry.element)
-                                {
-                                    already_counted = true;
-                                    break;
-                                }
-                            }
-                        }
-
-                        // If not already counted, increment count
-                        if !already_counted {
-                            count += 1;
-                        }
-                    }
-                }
-            }
-            count
+            self.active_count
         }
 
         #[cfg(feature = "hardware-atomic")]
         {
-            let mut count = 0;
-            let current_element_count = self.element_count.load(Ordering::Relaxed);
-            let elements_ref = unsafe { &*self.elements.get() };
-
-            // For each element entry, check if it's present and not already counted
-            for i in 0..current_element_count {
-                if let Some(entry) = &elements_ref[i] {
-                    if self.contains(&entry.element) {
-                        // Check if we've already counted this element value
-                        let mut already_counted = false;
-                        for j in 0..i {
-                            if let Some(prev_entry) = &elements_ref[j] {
-                                if prev_entry.element == entry.element
-                                    && self.contains(&prev_entry.element)
-                                {
-                                    already_counted = true;
-                                    break;
-                                }
-                            }
-                        }
-
-                        // If not already counted, increment count
-                        if !already_counted {
-                            count += 1;
-                        }
-                    }
-                }
-            }
-            count
+            self.active_count.load(Ordering::Relaxed)
         }
     }
 
@@ -779,6 +885,46 @@ where
         self.node_id
     }
 
+    /// Gets the policy used to resolve equal add and remove timestamps
+    pub fn conflict_policy(&self) -> ConflictPolicy {
+        self.conflict_policy
+    }
+
+    /// Changes the policy used to resolve equal add and remove timestamps
+    ///
+    /// # Warning
+    /// Membership is computed from the stored entries on every query, so
+    /// switching the policy of a set that already holds elements and
+    /// tombstones can change which elements are present without any
+    /// structural change: an element whose latest add and remove share a
+    /// timestamp appears under [`ConflictPolicy::AddWins`] and disappears
+    /// under [`ConflictPolicy::RemoveWins`]. Replicas that have not switched
+    /// will also refuse to merge with this one until they do.
+    ///
+    /// The policy is local configuration and is not serialized, so a
+    /// decoded replica starts with [`ConflictPolicy::RemoveWins`] and has to
+    /// be switched back before merging with add-wins replicas.
+    ///
+    /// # Arguments
+    /// * `policy` - The new policy
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut set = ORSet::<u32, DefaultConfig>::new(1);
+    /// set.add(42, 1000)?;
+    /// set.remove(&42, 1000)?; // Same timestamp as the add
+    /// assert!(!set.contains(&42));
+    ///
+    /// set.set_conflict_policy(ConflictPolicy::AddWins);
+    /// assert!(set.contains(&42)); // Same entries, different membership
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn set_conflict_policy(&mut self, policy: ConflictPolicy) {
+        self.conflict_policy = policy;
+        self.recount_active();
+    }
+
     /// Returns an iterator over the elements currently in the set
     ///
     /// # Returns
@@ -807,6 +953,302 @@ where
         }
     }
 
+    /// Returns an iterator over every stored element entry, including removed ones
+    ///
+    /// An element added by several nodes appears once per node.
+    ///
+    /// # Returns
+    /// An iterator over the stored elements
+    pub fn iter_stored(&self) -> impl Iterator<Item = &T> {
+        self.element_slice()
+            .iter()
+            .flatten()
+            .map(|entry| &entry.element)
+    }
+
+    /// Returns the latest add timestamp of a present element
+    ///
+    /// An element that was removed and re-added reports the re-add.
+    ///
+    /// # Arguments
+    /// * `element` - The element to look up
+    ///
+    /// # Returns
+    /// The timestamp of the most recent add, or None if the element is not
+    /// present
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// devices.add(7, 1000)?;
+    /// devices.remove(&7, 2000)?;
+    /// assert_eq!(devices.element_timestamp(&7), None);
+    ///
+    /// // Re-added by another node
+    /// let mut peer = ORSet::<u32, DefaultConfig>::new(2);
+    /// peer.add(7, 3000)?;
+    /// devices.merge(&peer)?;
+    /// assert_eq!(devices.element_timestamp(&7), Some(3000));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn element_timestamp(&self, element: &T) -> Option<u64> {
+        if !self.contains(element) {
+            return None;
+        }
+        self.element_slice()
+            .iter()
+            .flatten()
+            .filter(|entry| entry.element == *element)
+            .map(|entry| entry.timestamp.as_u64())
+            .max()
+    }
+
+    /// Returns an iterator over the elements added more than `age_ms` ago
+    ///
+    /// Ages are measured from each element's latest add, so re-adding an
+    /// element makes it young again.
+    ///
+    /// # Arguments
+    /// * `current_time` - The current time, in the units of the add timestamps
+    /// * `age_ms` - Elements last added before `current_time - age_ms` are yielded
+    ///
+    /// # Returns
+    /// An iterator over the present elements older than `age_ms`
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// devices.add(7, 1000)?;
+    /// devices.add(8, 250_000)?;
+    ///
+    /// // Devices not seen in the last five minutes
+    /// let mut stale = devices.elements_older_than(310_000, 300_000);
+    /// assert_eq!(stale.next(), Some(&7));
+    /// assert_eq!(stale.next(), None);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn elements_older_than(&self, current_time: u64, age_ms: u64) -> impl Iterator<Item = &T> {
+        let cutoff = current_time.saturating_sub(age_ms);
+        self.present_latest_adds()
+            .filter(move |(_, added)| added.as_u64() < cutoff)
+            .map(|(element, _)| element)
+    }
+
+    /// Returns an iterator over the elements whose age falls in a range
+    ///
+    /// The age of an element is `current_time` minus its latest add
+    /// timestamp; elements added after `current_time` have age zero.
+    ///
+    /// # Arguments
+    /// * `current_time` - The current time, in the units of the add timestamps
+    /// * `min_age_ms` - The minimum age, inclusive
+    /// * `max_age_ms` - The maximum age, inclusive
+    ///
+    /// # Returns
+    /// An iterator over the present elements with an age in
+    /// `min_age_ms..=max_age_ms`
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// devices.add(1, 1000)?;
+    /// devices.add(2, 5000)?;
+    /// devices.add(3, 9000)?;
+    ///
+    /// let mut aging = devices.elements_in_age_range(10_000, 1000, 5000);
+    /// assert_eq!(aging.next(), Some(&2));
+    /// assert_eq!(aging.next(), Some(&3));
+    /// assert_eq!(aging.next(), None);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn elements_in_age_range(
+        &self,
+        current_time: u64,
+        min_age_ms: u64,
+        max_age_ms: u64,
+    ) -> impl Iterator<Item = &T> {
+        self.present_latest_adds()
+            .filter(move |(_, added)| {
+                let age = current_time.saturating_sub(added.as_u64());
+                (min_age_ms..=max_age_ms).contains(&age)
+            })
+            .map(|(element, _)| element)
+    }
+
+    /// Returns the present element with the oldest latest add
+    ///
+    /// # Returns
+    /// The element and its latest add timestamp, or None if the set is empty
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// devices.add(7, 1000)?;
+    /// devices.add(8, 2000)?;
+    /// assert_eq!(devices.oldest_element(), Some((&7, 1000)));
+    /// assert_eq!(devices.youngest_element(), Some((&8, 2000)));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn oldest_element(&self) -> Option<(&T, u64)> {
+        self.present_latest_adds()
+            .min_by_key(|(_, added)| *added)
+            .map(|(element, added)| (element, added.as_u64()))
+    }
+
+    /// Returns the present element with the most recent add
+    ///
+    /// # Returns
+    /// The element and its latest add timestamp, or None if the set is empty
+    pub fn youngest_element(&self) -> Option<(&T, u64)> {
+        self.present_latest_adds()
+            .max_by_key(|(_, added)| *added)
+            .map(|(element, added)| (element, added.as_u64()))
+    }
+
+    /// Copies the current membership into a grow-only set
+    ///
+    /// For read-only consumers such as dashboards and loggers that do not
+    /// need remove semantics. A `GSet` stores neither node IDs nor
+    /// timestamps, so only the elements themselves are copied.
+    ///
+    /// # Returns
+    /// The snapshot, or `CRDTError::BufferOverflow` if more than `OUT`
+    /// elements are present
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut set = ORSet::<u32, DefaultConfig>::new(1);
+    /// set.add(1, 1000)?;
+    /// set.add(2, 1000)?;
+    /// set.remove(&1, 2000)?;
+    ///
+    /// let snapshot = set.snapshot_as_gset::<4>()?;
+    /// assert_eq!(snapshot.len(), 1);
+    /// assert!(snapshot.contains(&2));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn snapshot_as_gset<const OUT: usize>(&self) -> CRDTResult<GSet<T, C, OUT>> {
+        #[cfg_attr(feature = "hardware-atomic", allow(unused_mut))]
+        let mut snapshot = GSet::with_capacity();
+        for element in self.iter() {
+            // Elements added by several nodes are only inserted once
+            snapshot.insert(element.clone())?;
+        }
+        Ok(snapshot)
+    }
+
+    /// Returns the union of this set with another set (without modifying either)
+    ///
+    /// The result belongs to node 0 and holds the element entries of every
+    /// element present in either set, plus the tombstones of both sets.
+    /// Tombstones that would hide a present element are left out, so the
+    /// result contains exactly the elements present in either input.
+    ///
+    /// # Arguments
+    /// * `other` - The other set to union with
+    ///
+    /// # Returns
+    /// The new set, `BufferOverflow` if the entries or tombstones do not fit
+    /// in `OUT`, or `InvalidMerge` if the sets use different conflict policies
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// // Capabilities advertised by two robots
+    /// let mut robot1 = ORSet::<u8, DefaultConfig>::new(1);
+    /// robot1.add(1, 1000)?;
+    /// robot1.add(2, 1000)?;
+    /// let mut robot2 = ORSet::<u8, DefaultConfig, 4>::with_capacity(2);
+    /// robot2.add(2, 1100)?;
+    /// robot2.add(3, 1100)?;
+    ///
+    /// let fleet: ORSet<u8, DefaultConfig, 16> = robot1.union_with(&robot2)?;
+    /// assert_eq!(fleet.len(), 3);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn union_with<const CAP2: usize, const OUT: usize>(
+        &self,
+        other: &ORSet<T, C, CAP2>,
+    ) -> CRDTResult<ORSet<T, C, OUT>> {
+        self.combine_with(other, |in_self, in_other| in_self || in_other)
+    }
+
+    /// Returns the elements present in both sets (without modifying either)
+    ///
+    /// The result belongs to node 0 and keeps the tombstones of both sets,
+    /// so removed elements stay removed if it is merged with either input.
+    ///
+    /// # Arguments
+    /// * `other` - The other set to intersect with
+    ///
+    /// # Returns
+    /// The new set, `BufferOverflow` if the entries or tombstones do not fit
+    /// in `OUT`, or `InvalidMerge` if the sets use different conflict policies
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut robot1 = ORSet::<u8, DefaultConfig>::new(1);
+    /// robot1.add(1, 1000)?;
+    /// robot1.add(2, 1000)?;
+    /// let mut robot2 = ORSet::<u8, DefaultConfig>::new(2);
+    /// robot2.add(2, 1100)?;
+    /// robot2.add(3, 1100)?;
+    ///
+    /// let shared: ORSet<u8, DefaultConfig, 16> = robot1.intersection_with(&robot2)?;
+    /// assert!(shared.contains(&2));
+    /// assert_eq!(shared.len(), 1);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn intersection_with<const CAP2: usize, const OUT: usize>(
+        &self,
+        other: &ORSet<T, C, CAP2>,
+    ) -> CRDTResult<ORSet<T, C, OUT>> {
+        self.combine_with(other, |in_self, in_other| in_self && in_other)
+    }
+
+    /// Returns the elements present in exactly one of the two sets
+    /// (without modifying either)
+    ///
+    /// Entries and tombstones are copied as for
+    /// [`union_with`](Self::union_with).
+    ///
+    /// # Arguments
+    /// * `other` - The other set to compare against
+    ///
+    /// # Returns
+    /// The new set, `BufferOverflow` if the entries or tombstones do not fit
+    /// in `OUT`, or `InvalidMerge` if the sets use different conflict policies
+    pub fn symmetric_difference_with<const CAP2: usize, const OUT: usize>(
+        &self,
+        other: &ORSet<T, C, CAP2>,
+    ) -> CRDTResult<ORSet<T, C, OUT>> {
+        self.combine_with(other, |in_self, in_other| in_self != in_other)
+    }
+
+    /// Wraps a copy of the current state in a read-only view
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut set = ORSet::<u32, DefaultConfig>::new(1);
+    /// set.add(42, 1000)?;
+    ///
+    /// let frozen = set.freeze();
+    /// set.remove(&42, 2000)?;
+    /// assert!(frozen.contains(&42));
+    /// assert!(!frozen.is_subset_of_orset(&set));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn freeze(&self) -> FrozenORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY> {
+        FrozenORSet { set: self.clone() }
+    }
+
     /// Returns the number of element entries (including removed ones)
     ///
     /// # Returns
@@ -838,19 +1280,1248 @@ where
             self.tombstone_count.load(Ordering::Relaxed)
         }
     }
-}
 
-// Serde implementation for ORSet
-#[cfg(feature = "serde")]
-impl<T, C: MemoryConfig, const CAPACITY: usize> Serialize for ORSet<T, C, CAPACITY>
-where
-    T: Serialize + Clone + PartialEq,
-{
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        use serde::ser::SerializeStruct;
+    /// Returns the maximum number of tombstone entries
+    pub fn tombstone_capacity(&self) -> usize {
+        TOMBSTONE_CAPACITY
+    }
+
+    /// Returns the tombstone array utilization as a percentage (0-100)
+    pub fn tombstone_utilization_percent(&self) -> u8 {
+        utilization_percent(self.tombstone_entries(), TOMBSTONE_CAPACITY)
+    }
+
+    /// Drops tombstones that no longer shadow any element entry
+    ///
+    /// A tombstone is only dropped when no element entry with the same
+    /// (element, node_id, timestamp) triple remains and no add of the same
+    /// element is still covered by its remove timestamp. Observable membership
+    /// is therefore unchanged, and a concurrent re-add from another node can
+    /// never be swallowed by compaction.
+    ///
+    /// # Returns
+    /// The number of bytes reclaimed in the tombstone array
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut set = ORSet::<u32, DefaultConfig>::new(1);
+    /// set.add(42, 1000)?;
+    /// set.remove(&42, 2000)?;
+    ///
+    /// // The tombstone still hides the add, so it must be kept
+    /// assert_eq!(set.compact_tombstones(), 0);
+    /// assert!(!set.contains(&42));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn compact_tombstones(&mut self) -> usize {
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (elements, element_count, tombstones, tombstone_count) = (
+            &self.elements,
+            self.element_count,
+            &mut self.tombstones,
+            &mut self.tombstone_count,
+        );
+
+        #[cfg(feature = "hardware-atomic")]
+        let (elements, element_count, tombstones, tombstone_count) = (
+            &*self.elements.get_mut(),
+            *self.element_count.get_mut(),
+            self.tombstones.get_mut(),
+            self.tombstone_count.get_mut(),
+        );
+
+        let mut kept = 0;
+        for i in 0..*tombstone_count {
+            let tombstone = match tombstones[i].take() {
+                Some(tombstone) => tombstone,
+                None => continue,
+            };
+
+            // Keep the tombstone if its add is still stored or it still hides an add
+            let still_needed = elements.iter().take(element_count).flatten().any(|entry| {
+                entry.element == tombstone.element
+                    && ((entry.node_id == tombstone.node_id
+                        && entry.timestamp == tombstone.timestamp)
+                        || entry.timestamp <= tombstone.remove_timestamp)
+            });
+
+            if still_needed {
+                tombstones[kept] = Some(tombstone);
+                kept += 1;
+            }
+        }
+
+        let dropped = *tombstone_count - kept;
+        *tombstone_count = kept;
+        dropped * core::mem::size_of::<Option<TombstoneEntry<T>>>()
+    }
+
+    /// Forgets an element locally, freeing its element and tombstone slots
+    ///
+    /// Unlike `remove`, this leaves no tombstone behind: it is a local
+    /// eviction for bounded logs, and a replica that still stores the
+    /// element will bring it back on the next merge.
+    ///
+    /// # Arguments
+    /// * `element` - The element to forget
+    ///
+    /// # Returns
+    /// The number of element and tombstone entries dropped
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut set = ORSet::<u32, DefaultConfig>::new(1);
+    /// set.add(42, 1000)?;
+    /// set.remove(&42, 2000)?;
+    ///
+    /// assert_eq!(set.purge(&42), 2);
+    /// assert_eq!(set.element_entries(), 0);
+    /// assert_eq!(set.tombstone_entries(), 0);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn purge(&mut self, element: &T) -> usize {
+        let was_present = self.contains(element);
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (elements, element_count, tombstones, tombstone_count) = (
+            &mut self.elements[..],
+            &mut self.element_count,
+            &mut self.tombstones,
+            &mut self.tombstone_count,
+        );
+
+        #[cfg(feature = "hardware-atomic")]
+        let (elements, element_count, tombstones, tombstone_count) = (
+            &mut self.elements.get_mut()[..],
+            self.element_count.get_mut(),
+            self.tombstones.get_mut(),
+            self.tombstone_count.get_mut(),
+        );
+
+        let mut dropped = Self::drop_entries_of(elements, element_count, element);
+
+        let mut kept = 0;
+        for i in 0..*tombstone_count {
+            match tombstones[i].take() {
+                Some(tombstone) if tombstone.element != *element => {
+                    tombstones[kept] = Some(tombstone);
+                    kept += 1;
+                }
+                _ => {}
+            }
+        }
+        dropped += *tombstone_count - kept;
+        *tombstone_count = kept;
+
+        self.shift_active_count(usize::from(was_present), 0);
+        dropped
+    }
+
+    /// Evicts the least recently added element
+    ///
+    /// The victim is the present element whose latest add is the oldest;
+    /// ties go to the element stored first. Unlike [`purge`](Self::purge),
+    /// the eviction is an observed remove: a tombstone is recorded for each
+    /// of the element's entries, so it stays removed when merging with
+    /// replicas that still hold it. The element entries are then dropped to
+    /// free their slots.
+    ///
+    /// # Returns
+    /// The evicted element, or None if the set is empty or the tombstones
+    /// for the victim do not fit
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut peers = ORSet::<u32, DefaultConfig>::new(1);
+    /// peers.add(7, 1000)?;
+    /// peers.add(8, 2000)?;
+    /// let replica = peers.clone();
+    ///
+    /// assert_eq!(peers.evict_lru(), Some(7));
+    /// peers.merge(&replica)?;
+    /// assert!(!peers.contains(&7)); // Stays evicted after the merge
+    /// assert!(peers.contains(&8));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn evict_lru(&mut self) -> Option<T> {
+        let (element, last_added) = self.lru_element()?;
+
+        // One tombstone per entry, all or nothing
+        let needed = self
+            .element_slice()
+            .iter()
+            .flatten()
+            .filter(|entry| entry.element == element)
+            .count();
+        if needed > TOMBSTONE_CAPACITY - self.tombstone_entries() {
+            return None;
+        }
+
+        // Removing just after the latest add hides it under either conflict policy
+        let remove_timestamp = CompactTimestamp::new(last_added.as_u64().saturating_add(1));
+        for i in 0..self.element_slice().len() {
+            let tombstone = match &self.element_slice()[i] {
+                Some(entry) if entry.element == element => TombstoneEntry {
+                    element: entry.element.clone(),
+                    timestamp: entry.timestamp,
+                    node_id: entry.node_id,
+                    remove_timestamp,
+                },
+                _ => continue,
+            };
+            self.push_tombstone(tombstone).ok()?;
+        }
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (elements, element_count) = (&mut self.elements[..], &mut self.element_count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (elements, element_count) = (
+            &mut self.elements.get_mut()[..],
+            self.element_count.get_mut(),
+        );
+
+        Self::drop_entries_of(elements, element_count, &element);
+        Some(element)
+    }
+
+    /// Evicts up to `n` elements, least recently added first
+    ///
+    /// # Arguments
+    /// * `n` - The maximum number of elements to evict
+    ///
+    /// # Returns
+    /// The number of elements evicted, which is less than `n` if the set
+    /// runs out of elements or tombstone slots
+    pub fn evict_oldest_n(&mut self, n: usize) -> usize {
+        let mut evicted = 0;
+        while evicted < n && self.evict_lru().is_some() {
+            evicted += 1;
+        }
+        evicted
+    }
+
+    /// Adds an element, applying `policy` if the set is full
+    ///
+    /// With [`EvictionPolicy::EvictLRU`], a full set makes room by calling
+    /// [`evict_lru`](Self::evict_lru) before adding. With
+    /// [`EvictionPolicy::RefuseWhenFull`] this behaves like
+    /// [`add`](Self::add).
+    ///
+    /// # Arguments
+    /// * `element` - The element to add
+    /// * `timestamp` - The timestamp for this add operation
+    /// * `policy` - What to do when there is no free element slot
+    ///
+    /// # Returns
+    /// Ok(true) if the element was newly added, Ok(false) if it already
+    /// existed, or `CRDTError::BufferOverflow` if the set is full and no
+    /// element could be evicted
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut recent = ORSet::<u32, DefaultConfig, 2>::with_capacity(1);
+    /// recent.add(1, 1000)?;
+    /// recent.add(2, 2000)?;
+    ///
+    /// assert!(recent.add_with_eviction(3, 3000, EvictionPolicy::RefuseWhenFull).is_err());
+    /// assert!(recent.add_with_eviction(3, 3000, EvictionPolicy::EvictLRU)?);
+    /// assert!(!recent.contains(&1) && recent.contains(&3));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn add_with_eviction(
+        &mut self,
+        element: T,
+        timestamp: u64,
+        policy: EvictionPolicy,
+    ) -> CRDTResult<bool> {
+        let needs_slot = !self
+            .element_slice()
+            .iter()
+            .flatten()
+            .any(|entry| entry.element == element && entry.node_id == self.node_id);
+
+        if policy == EvictionPolicy::EvictLRU && needs_slot && self.element_entries() >= CAPACITY {
+            self.evict_lru().ok_or(CRDTError::BufferOverflow)?;
+        }
+        self.add(element, timestamp)
+    }
+
+    /// Finds the present element with the oldest latest add
+    fn lru_element(&self) -> Option<(T, CompactTimestamp)> {
+        self.present_latest_adds()
+            .min_by_key(|(_, added)| *added)
+            .map(|(element, added)| (element.clone(), added))
+    }
+
+    /// Yields every present element once, with its latest add timestamp
+    fn present_latest_adds(&self) -> impl Iterator<Item = (&T, CompactTimestamp)> {
+        let entries = self.element_slice();
+        entries.iter().enumerate().filter_map(move |(i, entry)| {
+            let element = &entry.as_ref()?.element;
+
+            // Visit an element added by several nodes only at its first entry
+            if entries[..i].iter().flatten().any(|e| e.element == *element)
+                || !self.contains(element)
+            {
+                return None;
+            }
+
+            let last_added = entries[i..]
+                .iter()
+                .flatten()
+                .filter(|e| e.element == *element)
+                .map(|e| e.timestamp)
+                .max()?;
+            Some((element, last_added))
+        })
+    }
+
+    /// Drops every entry of `element` and compacts the rest to the front
+    ///
+    /// # Returns
+    /// The number of entries dropped
+    fn drop_entries_of(
+        elements: &mut [Option<ElementEntry<T>>],
+        element_count: &mut usize,
+        element: &T,
+    ) -> usize {
+        let mut kept = 0;
+        for i in 0..*element_count {
+            match elements[i].take() {
+                Some(entry) if entry.element != *element => {
+                    elements[kept] = Some(entry);
+                    kept += 1;
+                }
+                _ => {}
+            }
+        }
+        let dropped = *element_count - kept;
+        *element_count = kept;
+        dropped
+    }
+
+    /// Removes every element for which `predicate` returns false
+    ///
+    /// Each removal goes through [`remove`](Self::remove), so it leaves a
+    /// tombstone and stays removed when merging with replicas that still
+    /// hold the element.
+    ///
+    /// # Arguments
+    /// * `predicate` - Returns true for elements to keep
+    /// * `timestamp` - The timestamp for the remove operations
+    ///
+    /// # Returns
+    /// The number of elements removed, or an error if the tombstone storage
+    /// fills up (elements removed before that stay removed)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// for id in 1..=4 {
+    ///     devices.add(id, 1000)?;
+    /// }
+    ///
+    /// assert_eq!(devices.retain(|&id| id % 2 == 0, 2000)?, 2);
+    /// assert!(devices.contains(&2) && !devices.contains(&3));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(
+        &mut self,
+        mut predicate: F,
+        timestamp: u64,
+    ) -> CRDTResult<usize> {
+        self.retain_if_present(|element, _| predicate(element), timestamp)
+    }
+
+    /// Removes every element for which `predicate` returns false, given its add timestamp
+    ///
+    /// The predicate receives each present element with the timestamp of
+    /// its latest add, which allows age-based eviction.
+    ///
+    /// # Arguments
+    /// * `predicate` - Returns true for elements to keep
+    /// * `timestamp` - The timestamp for the remove operations
+    ///
+    /// # Returns
+    /// The number of elements removed, or an error if the tombstone storage
+    /// fills up (elements removed before that stay removed)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// devices.add(7, 1000)?;
+    /// devices.add(8, 250_000)?;
+    ///
+    /// // Evict devices not seen in the last five minutes
+    /// let now = 310_000;
+    /// assert_eq!(devices.retain_if_present(|_, seen| now - seen < 300_000, now)?, 1);
+    /// assert!(!devices.contains(&7) && devices.contains(&8));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn retain_if_present<F: FnMut(&T, u64) -> bool>(
+        &mut self,
+        mut predicate: F,
+        timestamp: u64,
+    ) -> CRDTResult<usize> {
+        let mut removed = 0;
+        self.remove_where(
+            |element, added| !predicate(element, added),
+            timestamp,
+            |_| removed += 1,
+        )?;
+        Ok(removed)
+    }
+
+    /// Removes every element for which `predicate` returns true and yields them
+    ///
+    /// Like [`retain`](Self::retain) with the predicate inverted, but hands
+    /// the removed elements back, e.g. for logging.
+    ///
+    /// # Arguments
+    /// * `predicate` - Returns true for elements to remove
+    /// * `timestamp` - The timestamp for the remove operations
+    ///
+    /// # Returns
+    /// An iterator over the removed elements, or an error if the tombstone
+    /// storage fills up (elements removed before that stay removed)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// for id in 1..=4 {
+    ///     devices.add(id, 1000)?;
+    /// }
+    ///
+    /// let mut drained = devices.drain_if(|&id| id > 2, 2000)?;
+    /// assert_eq!(drained.next(), Some(3));
+    /// assert_eq!(drained.next(), Some(4));
+    /// assert_eq!(drained.next(), None);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn drain_if<F: FnMut(&T) -> bool>(
+        &mut self,
+        mut predicate: F,
+        timestamp: u64,
+    ) -> CRDTResult<impl Iterator<Item = T>> {
+        let mut drained: [Option<T>; CAPACITY] = core::array::from_fn(|_| None);
+        let mut count = 0;
+        self.remove_where(
+            |element, _| predicate(element),
+            timestamp,
+            |element| {
+                drained[count] = Some(element);
+                count += 1;
+            },
+        )?;
+        Ok(drained.into_iter().flatten())
+    }
+
+    /// Removes every present element in a single pass
+    ///
+    /// Each removal is an observed remove, so replicas that merge this state
+    /// drop the elements too, while concurrent adds they have not yet sent
+    /// survive. Replacing the set with a fresh one instead would lose that
+    /// causal history, and the next merge would bring every element back.
+    ///
+    /// Elements whose tombstones no longer fit are skipped, so the clear may
+    /// be partial; see [`observe_remove_all_strict`](Self::observe_remove_all_strict).
+    ///
+    /// # Arguments
+    /// * `timestamp` - The timestamp for the remove operations
+    ///
+    /// # Returns
+    /// The number of elements cleared
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    /// let mut devices = ORSet::<u32, DefaultConfig>::new(1);
+    /// devices.add(1, 1000)?;
+    /// devices.add(2, 1000)?;
+    ///
+    /// // The gateway leaves the network and forgets its devices
+    /// assert_eq!(devices.observe_remove_all(2000)?, 2);
+    /// assert!(devices.is_empty());
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn observe_remove_all(&mut self, timestamp: u64) -> CRDTResult<usize> {
+        let mut cleared = 0;
+
+        // Removing only adds tombstones, so the element entries stay put
+        for i in 0..self.element_slice().len() {
+            let entries = self.element_slice();
+            let element = match &entries[i] {
+                Some(entry) => &entry.element,
+                None => continue,
+            };
+
+            // Visit an element added by several nodes only at its first entry
+            if entries[..i].iter().flatten().any(|e| e.element == *element)
+                || !self.contains(element)
+            {
+                continue;
+            }
+
+            // One tombstone per entry; skip elements that would only partly fit
+            let needed = entries[i..]
+                .iter()
+                .flatten()
+                .filter(|e| e.element == *element)
+                .count();
+            if needed > TOMBSTONE_CAPACITY - self.tombstone_entries() {
+                continue;
+            }
+
+            let element = element.clone();
+            if self.remove(&element, timestamp)? {
+                cleared += 1;
+            }
+        }
+
+        Ok(cleared)
+    }
+
+    /// Removes every present element, or none of them
+    ///
+    /// Like [`observe_remove_all`](Self::observe_remove_all), but checks
+    /// [`is_clearing_feasible`](Self::is_clearing_feasible) first.
+    ///
+    /// # Arguments
+    /// * `timestamp` - The timestamp for the remove operations
+    ///
+    /// # Returns
+    /// The number of elements cleared, or `CRDTError::BufferOverflow` if the
+    /// tombstones for all of them do not fit (the set is left unchanged)
+    pub fn observe_remove_all_strict(&mut self, timestamp: u64) -> CRDTResult<usize> {
+        if !self.is_clearing_feasible() {
+            return Err(CRDTError::BufferOverflow);
+        }
+        self.observe_remove_all(timestamp)
+    }
+
+    /// Checks if the remaining tombstone slots can hold a full clear
+    ///
+    /// A present element needs one tombstone for each node that added it,
+    /// so this counts the element entries still present rather than the
+    /// elements.
+    pub fn is_clearing_feasible(&self) -> bool {
+        let needed = self
+            .element_slice()
+            .iter()
+            .flatten()
+            .filter(|entry| self.contains(&entry.element))
+            .count();
+        needed <= TOMBSTONE_CAPACITY - self.tombstone_entries()
+    }
+
+    /// Removes each present element matching `should_remove`, in storage order
+    ///
+    /// `should_remove` sees every element once, with its latest add
+    /// timestamp, and `on_removed` receives the elements that were removed.
+    fn remove_where(
+        &mut self,
+        mut should_remove: impl FnMut(&T, u64) -> bool,
+        timestamp: u64,
+        mut on_removed: impl FnMut(T),
+    ) -> CRDTResult<()> {
+        // Removing only adds tombstones, so the element entries stay put
+        for i in 0..self.element_slice().len() {
+            let entries = self.element_slice();
+            let element = match &entries[i] {
+                Some(entry) => &entry.element,
+                None => continue,
+            };
+
+            // Visit an element added by several nodes only at its first entry
+            if entries[..i].iter().flatten().any(|e| e.element == *element)
+                || !self.contains(element)
+            {
+                continue;
+            }
+
+            let added = entries[i..]
+                .iter()
+                .flatten()
+                .filter(|e| e.element == *element)
+                .map(|e| e.timestamp)
+                .max()
+                .map_or(0, |ts| ts.as_u64());
+
+            if should_remove(element, added) {
+                let element = element.clone();
+                if self.remove(&element, timestamp)? {
+                    on_removed(element);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts the present elements by scanning the entries
+    fn count_present(&self) -> usize {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            let mut count = 0;
+
+            // For each element entry, check if it's present and not already counted
+            for i in 0..self.element_count {
+                if let Some(entry) = &self.elements[i] {
+                    if self.contains(&entry.element) {
+                        // Check if we've already counted this element value
+                        let mut already_counted = false;
+                        for j in 0..i {
+                            if let Some(prev_entry) = &self.elements[j] {
+                                if prev_entry.element == entry.element
+                                    && self.contains(&prev_entry.element)
+                                {
+                                    already_counted = true;
+                                    break;
+                                }
+                            }
+                        }
+
+                        // If not already counted, increment count
+                        if !already_counted {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+            count
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            let mut count = 0;
+            let current_element_count = self.element_count.load(Ordering::Relaxed);
+            let elements_ref = unsafe { &*self.elements.get() };
+
+            // For each element entry, check if it's present and not already counted
+            for i in 0..current_element_count {
+                if let Some(entry) = &elements_ref[i] {
+                    if self.contains(&entry.element) {
+                        // Check if we've already counted this element value
+                        let mut already_counted = false;
+                        for j in 0..i {
+                            if let Some(prev_entry) = &elements_ref[j] {
+                                if prev_entry.element == entry.element
+                                    && self.contains(&prev_entry.element)
+                                {
+                                    already_counted = true;
+                                    break;
+                                }
+                            }
+                        }
+
+                        // If not already counted, increment count
+                        if !already_counted {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+            count
+        }
+    }
+
+    /// Counts the elements `other` stores entries for that are present in `self`
+    ///
+    /// Merging `other` can only change the membership of these elements.
+    fn count_present_of(&self, other: &Self) -> usize {
+        let stored = || {
+            let added = other.element_slice().iter().flatten();
+            let removed = other.tombstone_slice().iter().flatten();
+            added
+                .map(|entry| &entry.element)
+                .chain(removed.map(|tombstone| &tombstone.element))
+        };
+
+        let mut count = 0;
+        for (i, element) in stored().enumerate() {
+            // Elements stored in several entries are counted once
+            let seen = stored().take(i).any(|prev| prev == element);
+            if !seen && self.contains(element) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Updates the shadow count after the membership of some elements changed
+    ///
+    /// `before` and `after` count the affected elements that were present
+    /// before and after the change.
+    #[cfg(not(feature = "hardware-atomic"))]
+    fn shift_active_count(&mut self, before: usize, after: usize) {
+        self.active_count = self.active_count + after - before;
+    }
+
+    /// Updates the shadow count after the membership of some elements changed
+    ///
+    /// `before` and `after` count the affected elements that were present
+    /// before and after the change.
+    #[cfg(feature = "hardware-atomic")]
+    fn shift_active_count(&self, before: usize, after: usize) {
+        if after > before {
+            self.active_count
+                .fetch_add(after - before, Ordering::Relaxed);
+        } else {
+            self.active_count
+                .fetch_sub(before - after, Ordering::Relaxed);
+        }
+    }
+
+    /// Recomputes the shadow count from the entries
+    fn recount_active(&mut self) {
+        let count = self.count_present();
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            self.active_count = count;
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            *self.active_count.get_mut() = count;
+        }
+    }
+
+    /// Returns the occupied portion of the elements array
+    fn element_slice(&self) -> &[Option<ElementEntry<T>>] {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            &self.elements[..self.element_count]
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            let current_element_count = self.element_count.load(Ordering::Relaxed);
+            let elements_ref = unsafe { &*self.elements.get() };
+            &elements_ref[..current_element_count]
+        }
+    }
+
+    /// Returns the occupied portion of the tombstones array
+    fn tombstone_slice(&self) -> &[Option<TombstoneEntry<T>>] {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            &self.tombstones[..self.tombstone_count]
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            let current_tombstone_count = self.tombstone_count.load(Ordering::Relaxed);
+            let tombstones_ref = unsafe { &*self.tombstones.get() };
+            &tombstones_ref[..current_tombstone_count]
+        }
+    }
+
+    /// Appends an element entry without checking for duplicates
+    fn push_element(&mut self, entry: ElementEntry<T>) -> CRDTResult<()> {
+        let was_present = self.contains(&entry.element);
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (elements, count) = (&mut self.elements, &mut self.element_count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (elements, count) = (self.elements.get_mut(), self.element_count.get_mut());
+
+        if *count >= CAPACITY {
+            return Err(CRDTError::BufferOverflow);
+        }
+        elements[*count] = Some(entry);
+        *count += 1;
+
+        let is_present = self
+            .element_slice()
+            .last()
+            .and_then(Option::as_ref)
+            .is_some_and(|entry| self.contains(&entry.element));
+        self.shift_active_count(usize::from(was_present), usize::from(is_present));
+        Ok(())
+    }
+
+    /// Builds the error for a full tombstone array
+    ///
+    /// Carries a context whose capacity is `TOMBSTONE_CAPACITY`, so callers
+    /// can tell it apart from a full element array, which `merge` reports
+    /// without context and `add` with an `"add"` context.
+    fn tombstone_overflow(&self, operation: &'static str, tombstone_count: usize) -> CRDTError {
+        let ctx = ErrorContext::new(
+            "ORSet",
+            operation,
+            self.node_id,
+            tombstone_count,
+            TOMBSTONE_CAPACITY,
+        );
+        CRDTError::BufferOverflow.with_context(ctx)
+    }
+
+    /// Appends a tombstone entry without checking for duplicates
+    fn push_tombstone(&mut self, tombstone: TombstoneEntry<T>) -> CRDTResult<()> {
+        let was_present = self.contains(&tombstone.element);
+
+        #[cfg(not(feature = "hardware-atomic"))]
+        let (tombstones, count) = (&mut self.tombstones, &mut self.tombstone_count);
+
+        #[cfg(feature = "hardware-atomic")]
+        let (tombstones, count) = (self.tombstones.get_mut(), self.tombstone_count.get_mut());
+
+        if *count >= TOMBSTONE_CAPACITY {
+            return Err(CRDTError::BufferOverflow);
+        }
+        tombstones[*count] = Some(tombstone);
+        *count += 1;
+
+        let is_present = self
+            .tombstone_slice()
+            .last()
+            .and_then(Option::as_ref)
+            .is_some_and(|tombstone| self.contains(&tombstone.element));
+        self.shift_active_count(usize::from(was_present), usize::from(is_present));
+        Ok(())
+    }
+
+    /// Checks whether one of `tombstones` removes an add entry
+    ///
+    /// Matches [`contains`](Self::contains): a remove hides adds before it,
+    /// and adds at the same time under `ConflictPolicy::RemoveWins`.
+    fn is_covered(
+        policy: ConflictPolicy,
+        tombstones: &[Option<TombstoneEntry<T>>],
+        entry: &ElementEntry<T>,
+    ) -> bool {
+        tombstones.iter().flatten().any(|tombstone| {
+            tombstone.element == entry.element
+                && match policy {
+                    ConflictPolicy::AddWins => tombstone.remove_timestamp > entry.timestamp,
+                    ConflictPolicy::RemoveWins => tombstone.remove_timestamp >= entry.timestamp,
+                }
+        })
+    }
+
+    /// Copies the entries of `other` that `self` does not store yet
+    ///
+    /// Adds that a tombstone stored here already removes are skipped.
+    ///
+    /// On overflow the entries copied so far are kept.
+    fn merge_entries(&mut self, other: &Self) -> CRDTResult<()> {
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            let other_element_count = other.element_count;
+            let other_tombstone_count = other.tombstone_count;
+            let other_elements_ref = &other.elements;
+            let other_tombstones_ref = &other.tombstones;
+
+            // Merge elements
+            for other_entry in other_elements_ref.iter().take(other_element_count) {
+                if let Some(other_entry) = other_entry {
+                    // Check if we already have this exact entry
+                    let mut found = false;
+
+                    for our_entry in self.elements.iter().take(self.element_count) {
+                        if let Some(our_entry) = our_entry {
+                            if our_entry.element == other_entry.element
+                                && our_entry.timestamp == other_entry.timestamp
+                                && our_entry.node_id == other_entry.node_id
+                            {
+                                found = true;
+                                break;
+                            }
+                        }
+                    }
+
+                    if !found {
+                        // An add removed here, e.g. by eviction, stays removed
+                        let tombstones = &self.tombstones[..self.tombstone_count];
+                        if Self::is_covered(self.conflict_policy, tombstones, other_entry) {
+                            continue;
+                        }
+
+                        // Check if we have space
+                        if self.element_count >= CAPACITY {
+                            return Err(CRDTError::BufferOverflow);
+                        }
+
+                        // Add the element entry
+                        self.elements[self.element_count] = Some(ElementEntry {
+                            element: other_entry.element.clone(),
+                            timestamp: other_entry.timestamp,
+                            node_id: other_entry.node_id,
+                        });
+                        self.element_count += 1;
+                    }
+                }
+            }
+
+            // Merge tombstones
+
+            for other_tombstone in other_tombstones_ref.iter().take(other_tombstone_count) {
+                if let Some(other_tombstone) = other_tombstone {
+                    // Check if we already have this exact tombstone
+                    let mut found = false;
+                    for our_tombstone in self.tombstones.iter().take(self.tombstone_count) {
+                        if let Some(our_tombstone) = our_tombstone {
+                            if our_tombstone.element == other_tombstone.element
+                                && our_tombstone.timestamp == other_tombstone.timestamp
+                                && our_tombstone.node_id == other_tombstone.node_id
+                                && our_tombstone.remove_timestamp
+                                    == other_tombstone.remove_timestamp
+                            {
+                                found = true;
+                                break;
+                            }
+                        }
+                    }
+
+                    if !found {
+                        // The tombstone array fills up independently of the elements
+                        if self.tombstone_count >= TOMBSTONE_CAPACITY {
+                            return Err(self.tombstone_overflow("merge", self.tombstone_count));
+                        }
+
+                        // Add the tombstone entry
+                        self.tombstones[self.tombstone_count] = Some(TombstoneEntry {
+                            element: other_tombstone.element.clone(),
+                            timestamp: other_tombstone.timestamp,
+                            node_id: other_tombstone.node_id,
+                            remove_timestamp: other_tombstone.remove_timestamp,
+                        });
+                        self.tombstone_count += 1;
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "hardware-atomic")]
+        {
+            // For atomic version, merge requires &mut self so it's not thread-safe during merge
+            // But we can still implement the same logic using unsafe access to the UnsafeCell
+            let other_element_count = other.element_count.load(Ordering::Relaxed);
+            let other_tombstone_count = other.tombstone_count.load(Ordering::Relaxed);
+            let other_elements_ref = unsafe { &*other.elements.get() };
+            let other_tombstones_ref = unsafe { &*other.tombstones.get() };
+
+            let self_elements_mut = unsafe { &mut *self.elements.get() };
+            let self_tombstones_mut = unsafe { &mut *self.tombstones.get() };
+            let mut self_element_count = self.element_count.load(Ordering::Relaxed);
+            let mut self_tombstone_count = self.tombstone_count.load(Ordering::Relaxed);
+
+            // Merge elements
+            for other_entry in other_elements_ref.iter().take(other_element_count) {
+                if let Some(other_entry) = other_entry {
+                    // Check if we already have this exact entry
+                    let mut found = false;
+                    for our_entry in self_elements_mut.iter().take(self_element_count) {
+                        if let Some(our_entry) = our_entry {
+                            if our_entry.element == other_entry.element
+                                && our_entry.timestamp == other_entry.timestamp
+                                && our_entry.node_id == other_entry.node_id
+                            {
+                                found = true;
+                                break;
+                            }
+                        }
+                    }
+
+                    if !found {
+                        // An add removed here, e.g. by eviction, stays removed
+                        let tombstones = &self_tombstones_mut[..self_tombstone_count];
+                        if Self::is_covered(self.conflict_policy, tombstones, other_entry) {
+                            continue;
+                        }
+
+                        // Check if we have space
+                        if self_element_count >= CAPACITY {
+                            return Err(CRDTError::BufferOverflow);
+                        }
+
+                        // Add the element entry
+                        self_elements_mut[self_element_count] = Some(ElementEntry {
+                            element: other_entry.element.clone(),
+                            timestamp: other_entry.timestamp,
+                            node_id: other_entry.node_id,
+                        });
+                        self_element_count += 1;
+                    }
+                }
+            }
+
+            // Merge tombstones
+
+            for other_tombstone in other_tombstones_ref.iter().take(other_tombstone_count) {
+                if let Some(other_tombstone) = other_tombstone {
+                    // Check if we already have this exact tombstone
+                    let mut found = false;
+                    for our_tombstone in self_tombstones_mut.iter().take(self_tombstone_count) {
+                        if let Some(our_tombstone) = our_tombstone {
+                            if our_tombstone.element == other_tombstone.element
+                                && our_tombstone.timestamp == other_tombstone.timestamp
+                                && our_tombstone.node_id == other_tombstone.node_id
+                                && our_tombstone.remove_timestamp
+                                    == other_tombstone.remove_timestamp
+                            {
+                                found = true;
+                                break;
+                            }
+                        }
+                    }
+
+                    if !found {
+                        // The tombstone array fills up independently of the elements
+                        if self_tombstone_count >= TOMBSTONE_CAPACITY {
+                            return Err(self.tombstone_overflow("merge", self_tombstone_count));
+                        }
+
+                        // Add the tombstone entry
+                        self_tombstones_mut[self_tombstone_count] = Some(TombstoneEntry {
+                            element: other_tombstone.element.clone(),
+                            timestamp: other_tombstone.timestamp,
+                            node_id: other_tombstone.node_id,
+                            remove_timestamp: other_tombstone.remove_timestamp,
+                        });
+                        self_tombstone_count += 1;
+                    }
+                }
+            }
+
+            // Update the atomic counts
+            self.element_count
+                .store(self_element_count, Ordering::Relaxed);
+            self.tombstone_count
+                .store(self_tombstone_count, Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+
+    /// Builds a node 0 set from the elements chosen by `select`
+    ///
+    /// `select` gets whether an element is present in `self` and in `other`.
+    /// Entries of chosen elements are copied from both sets, and tombstones
+    /// from both sets except those hiding a chosen element that their own set
+    /// does not contain. Identical entries are stored once.
+    fn combine_with<const CAP2: usize, const OUT: usize>(
+        &self,
+        other: &ORSet<T, C, CAP2>,
+        select: impl Fn(bool, bool) -> bool,
+    ) -> CRDTResult<ORSet<T, C, OUT>> {
+        if self.conflict_policy != other.conflict_policy {
+            return Err(CRDTError::InvalidMerge);
+        }
+
+        let mut result = ORSet::<T, C, OUT>::with_conflict_policy(0, self.conflict_policy);
+        let selected = |element: &T| select(self.contains(element), other.contains(element));
+
+        for entry in self
+            .element_slice()
+            .iter()
+            .chain(other.element_slice())
+            .flatten()
+            .filter(|entry| selected(&entry.element))
+        {
+            let duplicate = result.element_slice().iter().flatten().any(|stored| {
+                stored.element == entry.element
+                    && stored.timestamp == entry.timestamp
+                    && stored.node_id == entry.node_id
+            });
+            if !duplicate {
+                result.push_element(entry.clone())?;
+            }
+        }
+
+        let tombstones = self
+            .tombstone_slice()
+            .iter()
+            .flatten()
+            .map(|tombstone| (tombstone, self.contains(&tombstone.element)))
+            .chain(
+                other
+                    .tombstone_slice()
+                    .iter()
+                    .flatten()
+                    .map(|tombstone| (tombstone, other.contains(&tombstone.element))),
+            );
+        for (tombstone, in_source) in tombstones {
+            // The source set removed the element, but the other set still has it
+            if !in_source && selected(&tombstone.element) {
+                continue;
+            }
+
+            let duplicate = result.tombstone_slice().iter().flatten().any(|stored| {
+                stored.element == tombstone.element
+                    && stored.timestamp == tombstone.timestamp
+                    && stored.node_id == tombstone.node_id
+                    && stored.remove_timestamp == tombstone.remove_timestamp
+            });
+            if !duplicate {
+                result.push_tombstone(tombstone.clone())?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Outcome of [`ORSet::merge_checked`]
+#[cfg(feature = "diagnostics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MergeReport {
+    /// Element entries copied from the other set
+    pub elements_added: usize,
+    /// Tombstones copied from the other set
+    pub tombstones_added: usize,
+    /// Element entries not copied because a tombstone stored here removes them
+    pub elements_suppressed_by_tombstone: usize,
+    /// Free tombstone slots after the merge
+    pub tombstone_capacity_remaining: usize,
+    /// Free element entry slots after the merge
+    pub element_capacity_remaining: usize,
+    /// True if the merge completed without error
+    pub ok: bool,
+    /// The error the merge stopped with, if any
+    pub error: Option<CRDTError>,
+}
+
+/// Membership comparison from [`ORSet::diff_report`]
+#[cfg(feature = "diagnostics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffReport {
+    /// Elements present only in this set
+    pub symmetric_only_in_self: usize,
+    /// Elements present only in the other set
+    pub symmetric_only_in_other: usize,
+    /// Elements present in both sets
+    pub in_both: usize,
+}
+
+#[cfg(feature = "diagnostics")]
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+    ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    /// Merges another set and reports what the merge did
+    ///
+    /// Has the same effect as [`merge`](CRDT::merge), including keeping the
+    /// entries copied before an overflow, and is meant for finding out why
+    /// two replicas do not converge.
+    ///
+    /// # Arguments
+    /// * `other` - The set to merge
+    ///
+    /// # Returns
+    /// A report of the entries copied, the remaining capacity and any error
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    ///
+    /// let mut sensor1 = ORSet::<u8, DefaultConfig>::new(1);
+    /// sensor1.add(7, 1000)?;
+    /// sensor1.remove(&7, 2000)?;
+    ///
+    /// // A stale add of the removed element arrives from another node
+    /// let mut sensor2 = ORSet::<u8, DefaultConfig>::new(2);
+    /// sensor2.add(7, 1500)?;
+    /// sensor2.add(9, 1500)?;
+    ///
+    /// let report = sensor1.merge_checked(&sensor2);
+    /// assert!(report.ok);
+    /// assert_eq!(report.elements_added, 1);
+    /// assert_eq!(report.elements_suppressed_by_tombstone, 1);
+    /// assert!(!sensor1.contains(&7));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn merge_checked(&mut self, other: &Self) -> MergeReport {
+        let elements_before = self.element_entries();
+        let tombstones_before = self.tombstone_entries();
+
+        // The merge skips adds that a tombstone stored here removes
+        let elements_suppressed_by_tombstone = other
+            .element_slice()
+            .iter()
+            .flatten()
+            .filter(|entry| {
+                let stored = self.element_slice().iter().flatten().any(|ours| {
+                    ours.element == entry.element
+                        && ours.timestamp == entry.timestamp
+                        && ours.node_id == entry.node_id
+                });
+                !stored && Self::is_covered(self.conflict_policy, self.tombstone_slice(), entry)
+            })
+            .count();
+
+        let result = self.merge(other);
+
+        MergeReport {
+            elements_added: self.element_entries() - elements_before,
+            tombstones_added: self.tombstone_entries() - tombstones_before,
+            elements_suppressed_by_tombstone,
+            tombstone_capacity_remaining: TOMBSTONE_CAPACITY - self.tombstone_entries(),
+            element_capacity_remaining: CAPACITY - self.element_entries(),
+            ok: result.is_ok(),
+            error: result.err(),
+        }
+    }
+
+    /// Compares the elements present in this set and another
+    ///
+    /// # Arguments
+    /// * `other` - The set to compare with
+    ///
+    /// # Returns
+    /// The number of elements present in only one of the sets or in both
+    pub fn diff_report(&self, other: &Self) -> DiffReport {
+        let mut report = DiffReport {
+            symmetric_only_in_self: 0,
+            symmetric_only_in_other: 0,
+            in_both: 0,
+        };
+
+        // Elements added by several nodes are counted once
+        for (i, element) in self.iter().enumerate() {
+            if self.iter().take(i).any(|prev| prev == element) {
+                continue;
+            }
+            if other.contains(element) {
+                report.in_both += 1;
+            } else {
+                report.symmetric_only_in_self += 1;
+            }
+        }
+        for (i, element) in other.iter().enumerate() {
+            if !other.iter().take(i).any(|prev| prev == element) && !self.contains(element) {
+                report.symmetric_only_in_other += 1;
+            }
+        }
+
+        report
+    }
+}
+
+// Serde implementation for ORSet
+#[cfg(feature = "serde")]
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> Serialize
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Serialize + Clone + PartialEq,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
 
         let mut state = serializer.serialize_struct("ORSet", 5)?;
 
@@ -883,7 +2554,8 @@ where
 }
 
 #[cfg(feature = "serde")]
-impl<'de, T, C: MemoryConfig, const CAPACITY: usize> Deserialize<'de> for ORSet<T, C, CAPACITY>
+impl<'de, T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+    Deserialize<'de> for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
     T: Deserialize<'de> + Clone + PartialEq,
 {
@@ -891,6 +2563,7 @@ where
     where
         D: Deserializer<'de>,
     {
+        use crate::serialization::fields::SeqFields;
         use core::fmt;
         use serde::de::{self, MapAccess, Visitor};
 
@@ -904,21 +2577,37 @@ where
             NodeId,
         }
 
-        struct ORSetVisitor<T, C: MemoryConfig, const CAPACITY: usize> {
+        struct ORSetVisitor<
+            T,
+            C: MemoryConfig,
+            const CAPACITY: usize,
+            const TOMBSTONE_CAPACITY: usize,
+        > {
             _phantom: core::marker::PhantomData<(T, C)>,
         }
 
-        impl<'de, T, C: MemoryConfig, const CAPACITY: usize> Visitor<'de> for ORSetVisitor<T, C, CAPACITY>
+        impl<'de, T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+            Visitor<'de> for ORSetVisitor<T, C, CAPACITY, TOMBSTONE_CAPACITY>
         where
             T: Deserialize<'de> + Clone + PartialEq,
         {
-            type Value = ORSet<T, C, CAPACITY>;
+            type Value = ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("struct ORSet")
             }
 
-            fn visit_map<V>(self, mut map: V) -> Result<ORSet<T, C, CAPACITY>, V::Error>
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                self.visit_map(SeqFields::new(seq, FIELDS))
+            }
+
+            fn visit_map<V>(
+                self,
+                mut map: V,
+            ) -> Result<ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>, V::Error>
             where
                 V: MapAccess<'de>,
             {
@@ -1101,7 +2790,10 @@ where
                             }
 
                             let tombstones_array =
-                                map.next_value_seed(TombstonesDeserializer::<T, CAPACITY> {
+                                map.next_value_seed(TombstonesDeserializer::<
+                                    T,
+                                    TOMBSTONE_CAPACITY,
+                                > {
                                     _phantom: core::marker::PhantomData,
                                 })?;
                             tombstones = Some(tombstones_array);
@@ -1135,34 +2827,40 @@ where
                 if element_count > CAPACITY {
                     return Err(de::Error::custom("element_count exceeds capacity"));
                 }
-                if tombstone_count > CAPACITY {
-                    return Err(de::Error::custom("tombstone_count exceeds capacity"));
+                if tombstone_count > TOMBSTONE_CAPACITY {
+                    return Err(de::Error::custom(
+                        "tombstone_count exceeds tombstone capacity",
+                    ));
                 }
 
                 // Reconstruct the ORSet
                 #[cfg(not(feature = "hardware-atomic"))]
-                {
-                    Ok(ORSet {
-                        elements: elements_array,
-                        element_count,
-                        tombstones: tombstones_array,
-                        tombstone_count,
-                        node_id,
-                        _phantom: core::marker::PhantomData,
-                    })
-                }
+                let mut set = ORSet {
+                    elements: elements_array,
+                    element_count,
+                    tombstones: tombstones_array,
+                    tombstone_count,
+                    active_count: 0,
+                    node_id,
+                    conflict_policy: ConflictPolicy::RemoveWins,
+                    _phantom: core::marker::PhantomData,
+                };
 
                 #[cfg(feature = "hardware-atomic")]
-                {
-                    Ok(ORSet {
-                        elements: UnsafeCell::new(elements_array),
-                        element_count: AtomicUsize::new(element_count),
-                        tombstones: UnsafeCell::new(tombstones_array),
-                        tombstone_count: AtomicUsize::new(tombstone_count),
-                        node_id,
-                        _phantom: core::marker::PhantomData,
-                    })
-                }
+                let mut set = ORSet {
+                    elements: UnsafeCell::new(elements_array),
+                    element_count: AtomicUsize::new(element_count),
+                    tombstones: UnsafeCell::new(tombstones_array),
+                    tombstone_count: AtomicUsize::new(tombstone_count),
+                    active_count: AtomicUsize::new(0),
+                    node_id,
+                    conflict_policy: ConflictPolicy::RemoveWins,
+                    _phantom: core::marker::PhantomData,
+                };
+
+                // The shadow count is derived state and is not serialized
+                set.recount_active();
+                Ok(set)
             }
         }
 
@@ -1183,197 +2881,200 @@ where
     }
 }
 
-impl<T, C: MemoryConfig, const CAPACITY: usize> Default for ORSet<T, C, CAPACITY>
+// Postcard size bounds for ORSet
+#[cfg(feature = "postcard")]
+impl<T: MaxSize> MaxSize for ElementEntry<T> {
+    const POSTCARD_MAX_SIZE: usize =
+        T::POSTCARD_MAX_SIZE + u64::POSTCARD_MAX_SIZE + NodeId::POSTCARD_MAX_SIZE;
+}
+
+#[cfg(feature = "postcard")]
+impl<T: MaxSize> MaxSize for TombstoneEntry<T> {
+    const POSTCARD_MAX_SIZE: usize =
+        T::POSTCARD_MAX_SIZE + 2 * u64::POSTCARD_MAX_SIZE + NodeId::POSTCARD_MAX_SIZE;
+}
+
+#[cfg(feature = "postcard")]
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> MaxSize
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
-    T: Clone + PartialEq,
+    T: MaxSize,
 {
-    fn default() -> Self {
-        Self::with_capacity(0)
-    }
+    const POSTCARD_MAX_SIZE: usize = {
+        seq_max_size(CAPACITY, Option::<ElementEntry<T>>::POSTCARD_MAX_SIZE)
+            + seq_max_size(CAPACITY, Option::<TombstoneEntry<T>>::POSTCARD_MAX_SIZE)
+            + 2 * varint_len(CAPACITY)
+            + NodeId::POSTCARD_MAX_SIZE
+    };
 }
 
-impl<T, C: MemoryConfig, const CAPACITY: usize> CRDT<C> for ORSet<T, C, CAPACITY>
+// CBOR implementation for ORSet
+#[cfg(feature = "cbor")]
+impl<Ctx, T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+    minicbor::Encode<Ctx> for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: minicbor::Encode<Ctx> + Clone + PartialEq,
 {
-    type Error = CRDTError;
-
-    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
-        #[cfg(not(feature = "hardware-atomic"))]
-        {
-            let other_element_count = other.element_count;
-            let other_tombstone_count = other.tombstone_count;
-            let other_elements_ref = &other.elements;
-            let other_tombstones_ref = &other.tombstones;
-
-            // Merge elements
-            for other_entry in other_elements_ref.iter().take(other_element_count) {
-                if let Some(other_entry) = other_entry {
-                    // Check if we already have this exact entry
-                    let mut found = false;
-
-                    for our_entry in self.elements.iter().take(self.element_count) {
-                        if let Some(our_entry) = our_entry {
-                            if our_entry.element == other_entry.element
-                                && our_entry.timestamp == other_entry.timestamp
-                                && our_entry.node_id == other_entry.node_id
-                            {
-                                found = true;
-                                break;
-                            }
-                        }
-                    }
-
-                    if !found {
-                        // Check if we have space
-                        if self.element_count >= CAPACITY {
-                            return Err(CRDTError::BufferOverflow);
-                        }
-
-                        // Add the element entry
-                        self.elements[self.element_count] = Some(ElementEntry {
-                            element: other_entry.element.clone(),
-                            timestamp: other_entry.timestamp,
-                            node_id: other_entry.node_id,
-                        });
-                        self.element_count += 1;
-                    }
-                }
-            }
-
-            // Merge tombstones
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        e.array(3)?;
+        e.u8(self.node_id)?;
+
+        let elements = self.element_slice();
+        e.array(elements.iter().flatten().count() as u64)?;
+        for entry in elements.iter().flatten() {
+            e.array(3)?;
+            e.encode_with(&entry.element, ctx)?;
+            e.u64(entry.timestamp.as_u64())?;
+            e.u8(entry.node_id)?;
+        }
 
-            for other_tombstone in other_tombstones_ref.iter().take(other_tombstone_count) {
-                if let Some(other_tombstone) = other_tombstone {
-                    // Check if we already have this exact tombstone
-                    let mut found = false;
-                    for our_tombstone in self.tombstones.iter().take(self.tombstone_count) {
-                        if let Some(our_tombstone) = our_tombstone {
-                            if our_tombstone.element == other_tombstone.element
-                                && our_tombstone.timestamp == other_tombstone.timestamp
-                                && our_tombstone.node_id == other_tombstone.node_id
-                                && our_tombstone.remove_timestamp
-                                    == other_tombstone.remove_timestamp
-                            {
-                                found = true;
-                                break;
-                            }
-                        }
-                    }
+        let tombstones = self.tombstone_slice();
+        e.array(tombstones.iter().flatten().count() as u64)?;
+        for tombstone in tombstones.iter().flatten() {
+            e.array(4)?;
+            e.encode_with(&tombstone.element, ctx)?;
+            e.u64(tombstone.timestamp.as_u64())?;
+            e.u8(tombstone.node_id)?;
+            e.u64(tombstone.remove_timestamp.as_u64())?;
+        }
+        Ok(())
+    }
+}
 
-                    if !found {
-                        // Check if we have space
-                        if self.tombstone_count >= 8 {
-                            return Err(CRDTError::BufferOverflow);
-                        }
+#[cfg(feature = "cbor")]
+impl<'b, Ctx, T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+    minicbor::Decode<'b, Ctx> for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: minicbor::Decode<'b, Ctx> + Clone + PartialEq,
+{
+    fn decode(
+        d: &mut minicbor::Decoder<'b>,
+        ctx: &mut Ctx,
+    ) -> Result<Self, minicbor::decode::Error> {
+        use crate::serialization::cbor;
+
+        cbor::expect_array_len(d, 3)?;
+        let mut set = Self::with_capacity(d.u8()?);
+
+        let element_count = cbor::bounded_array_len(d, CAPACITY)?;
+        for _ in 0..element_count {
+            cbor::expect_array_len(d, 3)?;
+            let entry = ElementEntry {
+                element: d.decode_with(ctx)?,
+                timestamp: CompactTimestamp::new(d.u64()?),
+                node_id: d.u8()?,
+            };
+            set.push_element(entry)
+                .map_err(|_| cbor::capacity_exceeded())?;
+        }
 
-                        // Add the tombstone entry
-                        self.tombstones[self.tombstone_count] = Some(TombstoneEntry {
-                            element: other_tombstone.element.clone(),
-                            timestamp: other_tombstone.timestamp,
-                            node_id: other_tombstone.node_id,
-                            remove_timestamp: other_tombstone.remove_timestamp,
-                        });
-                        self.tombstone_count += 1;
-                    }
-                }
-            }
+        let tombstone_count = cbor::bounded_array_len(d, TOMBSTONE_CAPACITY)?;
+        for _ in 0..tombstone_count {
+            cbor::expect_array_len(d, 4)?;
+            let tombstone = TombstoneEntry {
+                element: d.decode_with(ctx)?,
+                timestamp: CompactTimestamp::new(d.u64()?),
+                node_id: d.u8()?,
+                remove_timestamp: CompactTimestamp::new(d.u64()?),
+            };
+            set.push_tombstone(tombstone)
+                .map_err(|_| cbor::capacity_exceeded())?;
         }
 
-        #[cfg(feature = "hardware-atomic")]
-        {
-            // For atomic version, merge requires &mut self so it's not thread-safe during merge
-            // But we can still implement the same logic using unsafe access to the UnsafeCell
-            let other_element_count = other.element_count.load(Ordering::Relaxed);
-            let other_tombstone_count = other.tombstone_count.load(Ordering::Relaxed);
-            let other_elements_ref = unsafe { &*other.elements.get() };
-            let other_tombstones_ref = unsafe { &*other.tombstones.get() };
+        Ok(set)
+    }
+}
 
-            let self_elements_mut = unsafe { &mut *self.elements.get() };
-            let self_tombstones_mut = unsafe { &mut *self.tombstones.get() };
-            let mut self_element_count = self.element_count.load(Ordering::Relaxed);
-            let mut self_tombstone_count = self.tombstone_count.load(Ordering::Relaxed);
+/// Read-only view of an [`ORSet`]
+///
+/// Created by [`ORSet::freeze`]. Exposes membership queries only, so code
+/// analysing a snapshot, such as safety monitoring on a gateway ECU, cannot
+/// add, remove or merge by accident.
+#[derive(Debug, Clone)]
+pub struct FrozenORSet<
+    T,
+    C: MemoryConfig,
+    const CAPACITY: usize = 8,
+    const TOMBSTONE_CAPACITY: usize = CAPACITY,
+> {
+    /// The frozen state
+    set: ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>,
+}
 
-            // Merge elements
-            for other_entry in other_elements_ref.iter().take(other_element_count) {
-                if let Some(other_entry) = other_entry {
-                    // Check if we already have this exact entry
-                    let mut found = false;
-                    for our_entry in self_elements_mut.iter().take(self_element_count) {
-                        if let Some(our_entry) = our_entry {
-                            if our_entry.element == other_entry.element
-                                && our_entry.timestamp == other_entry.timestamp
-                                && our_entry.node_id == other_entry.node_id
-                            {
-                                found = true;
-                                break;
-                            }
-                        }
-                    }
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize>
+    FrozenORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Clone + PartialEq,
+{
+    /// Checks if an element was present when the set was frozen
+    pub fn contains(&self, element: &T) -> bool {
+        self.set.contains(element)
+    }
 
-                    if !found {
-                        // Check if we have space
-                        if self_element_count >= 8 {
-                            return Err(CRDTError::BufferOverflow);
-                        }
+    /// Returns the number of elements present when the set was frozen
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
 
-                        // Add the element entry
-                        self_elements_mut[self_element_count] = Some(ElementEntry {
-                            element: other_entry.element.clone(),
-                            timestamp: other_entry.timestamp,
-                            node_id: other_entry.node_id,
-                        });
-                        self_element_count += 1;
-                    }
-                }
-            }
+    /// Checks if the set was empty when it was frozen
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
 
-            // Merge tombstones
+    /// Returns an iterator over the elements present when the set was frozen
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.set.iter()
+    }
 
-            for other_tombstone in other_tombstones_ref.iter().take(other_tombstone_count) {
-                if let Some(other_tombstone) = other_tombstone {
-                    // Check if we already have this exact tombstone
-                    let mut found = false;
-                    for our_tombstone in self_tombstones_mut.iter().take(self_tombstone_count) {
-                        if let Some(our_tombstone) = our_tombstone {
-                            if our_tombstone.element == other_tombstone.element
-                                && our_tombstone.timestamp == other_tombstone.timestamp
-                                && our_tombstone.node_id == other_tombstone.node_id
-                                && our_tombstone.remove_timestamp
-                                    == other_tombstone.remove_timestamp
-                            {
-                                found = true;
-                                break;
-                            }
-                        }
-                    }
+    /// Checks if every frozen element is currently present in `other`
+    pub fn is_subset_of_orset<const OTHER: usize>(&self, other: &ORSet<T, C, OTHER>) -> bool {
+        self.iter().all(|element| other.contains(element))
+    }
+}
 
-                    if !found {
-                        // Check if we have space
-                        if self_tombstone_count >= 8 {
-                            return Err(CRDTError::BufferOverflow);
-                        }
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> PartialEq
+    for FrozenORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        CRDT::eq(&self.set, &other.set)
+    }
+}
 
-                        // Add the tombstone entry
-                        self_tombstones_mut[self_tombstone_count] = Some(TombstoneEntry {
-                            element: other_tombstone.element.clone(),
-                            timestamp: other_tombstone.timestamp,
-                            node_id: other_tombstone.node_id,
-                            remove_timestamp: other_tombstone.remove_timestamp,
-                        });
-                        self_tombstone_count += 1;
-                    }
-                }
-            }
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> Default
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> CRDT<C>
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    type Error = CRDTError;
 
-            // Update the atomic counts
-            self.element_count
-                .store(self_element_count, Ordering::Relaxed);
-            self.tombstone_count
-                .store(self_tombstone_count, Ordering::Relaxed);
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        // Replicas resolving ties differently would never converge
+        if self.conflict_policy != other.conflict_policy {
+            return Err(CRDTError::InvalidMerge);
         }
 
-        Ok(())
+        // Only elements stored in `other` can change membership
+        let before = self.count_present_of(other);
+        let result = self.merge_entries(other);
+        let after = self.count_present_of(other);
+        self.shift_active_count(before, after);
+        result
     }
 
     fn eq(&self, other: &Self) -> bool {
@@ -1448,7 +3149,7 @@ where
         #[cfg(not(feature = "hardware-atomic"))]
         {
             // Validate counts are within bounds
-            if self.element_count > 8 || self.tombstone_count > 8 {
+            if self.element_count > CAPACITY || self.tombstone_count > TOMBSTONE_CAPACITY {
                 return Err(CRDTError::ConfigurationExceeded);
             }
 
@@ -1464,7 +3165,7 @@ where
             let current_tombstone_count = self.tombstone_count.load(Ordering::Relaxed);
 
             // Validate counts are within bounds
-            if current_element_count > 8 || current_tombstone_count > 8 {
+            if current_element_count > CAPACITY || current_tombstone_count > TOMBSTONE_CAPACITY {
                 return Err(CRDTError::ConfigurationExceeded);
             }
 
@@ -1474,36 +3175,28 @@ where
             }
         }
 
+        // The shadow count must agree with the entries
+        if self.len() != self.count_present() {
+            return Err(CRDTError::InvalidState);
+        }
+
         Ok(())
     }
 
     fn state_hash(&self) -> u32 {
-        // Hash based on current elements (order-independent)
+        // XOR of per-element FNV-1a hashes over the present elements
+        // (order-independent, like eq)
         let mut hash = 0u32;
-
-        #[cfg(not(feature = "hardware-atomic"))]
-        {
-            for entry in self.elements.iter().take(self.element_count) {
-                if let Some(entry) = entry {
-                    if self.contains(&entry.element) {
-                        let element_ptr = &entry.element as *const T as usize;
-                        hash ^= element_ptr as u32;
-                    }
-                }
-            }
-        }
-
-        #[cfg(feature = "hardware-atomic")]
-        {
-            let current_element_count = self.element_count.load(Ordering::Relaxed);
-            let elements_ref = unsafe { &*self.elements.get() };
-            for entry in elements_ref.iter().take(current_element_count) {
-                if let Some(entry) = entry {
-                    if self.contains(&entry.element) {
-                        let element_ptr = &entry.element as *const T as usize;
-                        hash ^= element_ptr as u32;
-                    }
-                }
+        let entries = self.element_slice();
+        for (i, entry) in entries.iter().enumerate() {
+            let Some(entry) = entry else { continue };
+            // Elements added by several nodes are hashed once
+            let seen = entries[..i]
+                .iter()
+                .flatten()
+                .any(|prev| prev.element == entry.element);
+            if !seen && self.contains(&entry.element) {
+                hash ^= hash_value(&entry.element);
             }
         }
 
@@ -1512,6 +3205,10 @@ where
     }
 
     fn can_merge(&self, other: &Self) -> bool {
+        if self.conflict_policy != other.conflict_policy {
+            return false;
+        }
+
         #[cfg(not(feature = "hardware-atomic"))]
         {
             // Check if merging would exceed capacity for elements
@@ -1532,7 +3229,9 @@ where
                             }
                         }
                     }
-                    if !found {
+                    // Adds removed here are skipped by the merge
+                    let tombstones = &self.tombstones[..self.tombstone_count];
+                    if !found && !Self::is_covered(self.conflict_policy, tombstones, other_entry) {
                         new_elements += 1;
                     }
                 }
@@ -1564,7 +3263,8 @@ where
                 }
             }
 
-            self.element_count + new_elements <= 8 && self.tombstone_count + new_tombstones <= 8
+            self.element_count + new_elements <= CAPACITY
+                && self.tombstone_count + new_tombstones <= TOMBSTONE_CAPACITY
         }
 
         #[cfg(feature = "hardware-atomic")]
@@ -1595,7 +3295,9 @@ where
                             }
                         }
                     }
-                    if !found {
+                    // Adds removed here are skipped by the merge
+                    let tombstones = &self_tombstones_ref[..self_tombstone_count];
+                    if !found && !Self::is_covered(self.conflict_policy, tombstones, other_entry) {
                         new_elements += 1;
                     }
                 }
@@ -1625,14 +3327,16 @@ where
                 }
             }
 
-            self_element_count + new_elements <= 8 && self_tombstone_count + new_tombstones <= 8
+            self_element_count + new_elements <= CAPACITY
+                && self_tombstone_count + new_tombstones <= TOMBSTONE_CAPACITY
         }
     }
 }
 
-impl<T, C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for ORSet<T, C, CAPACITY>
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> BoundedCRDT<C>
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
     const MAX_ELEMENTS: usize = CAPACITY; // Maximum number of element entries
@@ -1646,20 +3350,39 @@ where
     }
 
     fn compact(&mut self) -> CRDTResult<usize> {
-        // ORSets can't be easily compacted without losing causality information
-        // This is a no-op that returns 0 bytes freed
-        Ok(0)
+        // Only tombstones that no longer affect membership are dropped
+        Ok(self.compact_tombstones())
     }
 
     fn can_add_element(&self) -> bool {
         // For fixed-size arrays, only check element count, not memory usage
         self.element_count() < Self::MAX_ELEMENTS
     }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        let stamp = core::mem::size_of::<CompactTimestamp>() + core::mem::size_of::<NodeId>();
+        let element = core::mem::size_of::<T>() + stamp;
+        // Tombstones also record when the element was removed
+        let tombstone = element + core::mem::size_of::<CompactTimestamp>();
+
+        let elements = self.element_entries();
+        let tombstones = self.tombstone_entries();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            elements * element,
+            tombstones * tombstone,
+            (CAPACITY - elements) * core::mem::size_of::<Option<ElementEntry<T>>>()
+                + (TOMBSTONE_CAPACITY - tombstones)
+                    * core::mem::size_of::<Option<TombstoneEntry<T>>>(),
+        )
+        .with_tombstone_utilization(self.tombstone_utilization_percent())
+    }
 }
 
-impl<T, C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for ORSet<T, C, CAPACITY>
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> RealTimeCRDT<C>
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
 where
-    T: Clone + PartialEq + core::fmt::Debug,
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
 {
     const MAX_MERGE_CYCLES: u32 = 400; // More complex due to element and tombstone merging
     const MAX_VALIDATE_CYCLES: u32 = 200;
@@ -1685,6 +3408,90 @@ where
     }
 }
 
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> DeltaCRDT<C>
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    fn delta_since(&self, baseline: &Self) -> Self {
+        let mut delta = Self::with_conflict_policy(self.node_id, self.conflict_policy);
+
+        // Element entries the baseline has not observed
+        for entry in self.element_slice().iter().flatten() {
+            let known = baseline.element_slice().iter().flatten().any(|other| {
+                other.element == entry.element
+                    && other.timestamp == entry.timestamp
+                    && other.node_id == entry.node_id
+            });
+            if !known {
+                // Cannot overflow: the delta has the same capacity as self
+                let _ = delta.push_element(entry.clone());
+            }
+        }
+
+        // Tombstones the baseline has not observed
+        for tombstone in self.tombstone_slice().iter().flatten() {
+            let known = baseline.tombstone_slice().iter().flatten().any(|other| {
+                other.element == tombstone.element
+                    && other.timestamp == tombstone.timestamp
+                    && other.node_id == tombstone.node_id
+                    && other.remove_timestamp == tombstone.remove_timestamp
+            });
+            if !known {
+                let _ = delta.push_tombstone(tombstone.clone());
+            }
+        }
+
+        delta
+    }
+
+    fn apply_delta(&mut self, delta: &Self) -> CRDTResult<()> {
+        delta.validate().map_err(|_| CRDTError::InvalidMerge)?;
+
+        // Every tombstone must remove an element that was observed as added
+        for tombstone in delta.tombstone_slice().iter().flatten() {
+            let observed = delta
+                .element_slice()
+                .iter()
+                .chain(self.element_slice().iter())
+                .flatten()
+                .any(|entry| entry.element == tombstone.element);
+            if !observed {
+                return Err(CRDTError::InvalidMerge);
+            }
+        }
+
+        self.merge(delta)
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize, const TOMBSTONE_CAPACITY: usize> Snapshotable<C>
+    for ORSet<T, C, CAPACITY, TOMBSTONE_CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    /// XOR of the per-element add and remove stamps
+    type Snapshot = u64;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        let mut hash = 0;
+        for (i, entry) in self.element_slice().iter().enumerate() {
+            if let Some(entry) = entry {
+                hash ^= stamp_hash(i, entry.timestamp, entry.node_id);
+            }
+        }
+        // Tombstones hash into their own slot range
+        for (i, tombstone) in self.tombstone_slice().iter().enumerate() {
+            if let Some(tombstone) = tombstone {
+                hash ^= stamp_hash(CAPACITY + i, tombstone.timestamp, tombstone.node_id)
+                    ^ stamp_hash(CAPACITY + i, tombstone.remove_timestamp, tombstone.node_id)
+                        .rotate_left(32);
+            }
+        }
+        hash
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1835,6 +3642,20 @@ mod tests {
         assert!(set.add(8, 2000).is_err());
     }
 
+    #[test]
+    fn test_add_error_context() {
+        let mut set = ORSet::<u32, DefaultConfig, 2>::with_capacity(3);
+        set.add(1, 1000).unwrap();
+        set.add(2, 1000).unwrap();
+
+        let error = set.add(3, 1000).unwrap_err();
+        assert_eq!(error.cause(), CRDTError::BufferOverflow);
+        assert_eq!(
+            error.context(),
+            Some(&ErrorContext::new("ORSet", "add", 3, 2, 2))
+        );
+    }
+
     #[test]
     fn test_iter() {
         let mut set = ORSet::<u32, DefaultConfig>::new(1);
@@ -1913,6 +3734,76 @@ mod tests {
         assert!(!set.can_add_element());
     }
 
+    #[test]
+    fn test_snapshot_as_gset() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        let mut other = ORSet::<u32, DefaultConfig>::new(2);
+        set.add(1, 1000).unwrap();
+        set.add(2, 1000).unwrap();
+        set.add(3, 1000).unwrap();
+        other.add(2, 1500).unwrap();
+        set.merge(&other).unwrap();
+        set.remove(&3, 2000).unwrap();
+
+        // Element 2 has entries from two nodes but is copied once
+        let snapshot = set.snapshot_as_gset::<2>().unwrap();
+        assert_eq!(snapshot.len(), 2);
+        assert!(snapshot.contains(&1));
+        assert!(snapshot.contains(&2));
+        assert!(!snapshot.contains(&3));
+
+        assert_eq!(
+            set.snapshot_as_gset::<1>().unwrap_err(),
+            CRDTError::BufferOverflow
+        );
+    }
+
+    #[test]
+    fn test_freeze() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        set.add(1, 1000).unwrap();
+        set.add(2, 1000).unwrap();
+
+        let frozen = set.freeze();
+        assert_eq!(frozen, set.freeze());
+        assert!(frozen.is_subset_of_orset(&set));
+
+        set.remove(&2, 2000).unwrap();
+        set.add(3, 2000).unwrap();
+
+        // The frozen view is unaffected by later changes
+        assert_eq!(frozen.len(), 2);
+        assert!(frozen.contains(&2));
+        assert!(!frozen.contains(&3));
+        assert_eq!(frozen.iter().copied().sum::<u32>(), 3);
+        assert!(!frozen.is_subset_of_orset(&set));
+        assert_ne!(frozen, set.freeze());
+
+        let mut larger = ORSet::<u32, DefaultConfig, 16>::with_capacity(2);
+        larger.add(1, 1000).unwrap();
+        larger.add(2, 1000).unwrap();
+        assert!(frozen.is_subset_of_orset(&larger));
+    }
+
+    #[test]
+    fn test_memory_breakdown() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        set.add(1, 1000).unwrap();
+        set.add(2, 1000).unwrap();
+        set.remove(&2, 2000).unwrap();
+
+        // Element entries hold value, timestamp and node ID; tombstones add
+        // the removal timestamp
+        let breakdown = set.memory_breakdown();
+        assert_eq!(breakdown.element_data, set.element_entries() * (4 + 8 + 1));
+        assert_eq!(
+            breakdown.tombstone_data,
+            set.tombstone_entries() * (4 + 8 + 1 + 8)
+        );
+        assert!(breakdown.tombstone_data > 0);
+        assert_eq!(breakdown.total(), set.memory_usage());
+    }
+
     #[test]
     fn test_validation() {
         let mut set = ORSet::<u32, DefaultConfig>::new(1);
@@ -1952,6 +3843,676 @@ mod tests {
         assert!(!set1.can_merge(&set2));
     }
 
+    #[test]
+    #[cfg(not(feature = "hardware-atomic"))]
+    fn test_compact_tombstones_drops_superseded() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+
+        set.add(42, 1000).unwrap();
+        set.add(7, 1001).unwrap();
+        set.remove(&42, 2000).unwrap();
+        set.remove(&7, 2001).unwrap();
+
+        // Re-adding from the same node bumps the entry past the tombstone
+        set.add(42, 3000).unwrap();
+        assert_eq!(set.tombstone_entries(), 2);
+
+        let reclaimed = set.compact_tombstones();
+        assert_eq!(
+            reclaimed,
+            core::mem::size_of::<Option<TombstoneEntry<u32>>>()
+        );
+        assert_eq!(set.tombstone_entries(), 1);
+
+        // Membership is unchanged
+        assert!(set.contains(&42));
+        assert!(!set.contains(&7));
+        assert_eq!(set.len(), 1);
+
+        // Compaction is idempotent
+        assert_eq!(set.compact_tombstones(), 0);
+        assert_eq!(set.tombstone_entries(), 1);
+    }
+
+    #[test]
+    fn test_compact_tombstones_keeps_shadowing_tombstones() {
+        let mut set1 = ORSet::<u32, DefaultConfig>::new(1);
+        let mut set2 = ORSet::<u32, DefaultConfig>::new(2);
+
+        set1.add(42, 1000).unwrap();
+        set1.remove(&42, 2000).unwrap();
+        set2.add(42, 1500).unwrap(); // Concurrent add covered by the remove
+        set2.add(9, 1600).unwrap();
+
+        set1.merge(&set2).unwrap();
+        assert!(!set1.contains(&42));
+        assert!(set1.contains(&9));
+
+        assert_eq!(set1.compact(), Ok(0));
+        assert_eq!(set1.tombstone_entries(), 1);
+        assert!(!set1.contains(&42));
+        assert!(set1.contains(&9));
+    }
+
+    #[test]
+    #[cfg(not(feature = "hardware-atomic"))]
+    fn test_merge_idempotent_after_compaction() {
+        let mut set1 = ORSet::<u32, DefaultConfig>::new(1);
+        let mut set2 = ORSet::<u32, DefaultConfig>::new(2);
+
+        set1.add(1, 1000).unwrap();
+        set1.remove(&1, 1500).unwrap();
+        set1.add(1, 2000).unwrap();
+        set2.add(2, 1200).unwrap();
+        set1.merge(&set2).unwrap();
+
+        assert!(set1.compact_tombstones() > 0);
+        let snapshot = set1.clone();
+
+        set1.merge(&snapshot).unwrap();
+        assert!(set1.eq(&snapshot));
+        assert_eq!(set1.element_entries(), snapshot.element_entries());
+        assert_eq!(set1.tombstone_entries(), snapshot.tombstone_entries());
+    }
+
+    #[test]
+    fn test_purge_and_iter_stored() {
+        let mut set1 = ORSet::<u32, DefaultConfig>::new(1);
+        let mut set2 = ORSet::<u32, DefaultConfig>::new(2);
+
+        set1.add(1, 1000).unwrap();
+        set1.add(2, 1000).unwrap();
+        set1.remove(&1, 1500).unwrap();
+        set2.add(2, 1100).unwrap();
+        set1.merge(&set2).unwrap();
+
+        // Removed and duplicate entries are still stored
+        assert_eq!(set1.iter_stored().count(), 3);
+
+        assert_eq!(set1.purge(&1), 2);
+        assert_eq!(set1.purge(&2), 2);
+        assert_eq!(set1.purge(&3), 0);
+        assert!(set1.is_empty());
+        assert_eq!(set1.element_entries(), 0);
+        assert_eq!(set1.tombstone_entries(), 0);
+
+        // Purging is local: the other replica brings the element back
+        set1.merge(&set2).unwrap();
+        assert!(set1.contains(&2));
+    }
+
+    #[test]
+    fn test_merge_beyond_default_capacity() {
+        let mut set1 = ORSet::<u32, DefaultConfig, 16>::with_capacity(1);
+        let mut set2 = ORSet::<u32, DefaultConfig, 16>::with_capacity(2);
+
+        for i in 0..10 {
+            set1.add(i, 1000).unwrap();
+            set1.remove(&i, 2000).unwrap();
+        }
+        for i in 0..5 {
+            set2.add(i + 100, 1000).unwrap();
+        }
+
+        set1.merge(&set2).unwrap();
+        set2.merge(&set1).unwrap();
+        assert_eq!(set1.len(), 5);
+        assert!(set1.eq(&set2));
+    }
+
+    #[test]
+    fn test_delta_since() {
+        let mut set1 = ORSet::<u32, DefaultConfig>::new(1);
+        let mut set2 = ORSet::<u32, DefaultConfig>::new(2);
+
+        for i in 0..4 {
+            set1.add(i, 1000 + i as u64).unwrap();
+        }
+        set2.merge(&set1).unwrap();
+        set1.add(10, 2000).unwrap();
+        set1.remove(&2, 2001).unwrap();
+
+        // Only the new add and the new tombstone are shipped
+        let delta = set1.delta_since(&set2);
+        assert_eq!(delta.element_entries(), 1);
+        assert_eq!(delta.tombstone_entries(), 1);
+        assert!(delta.element_entries() * 4 <= set1.element_entries());
+
+        set2.apply_delta(&delta).unwrap();
+        assert!(set2.eq(&set1));
+        assert!(set2.contains(&10));
+        assert!(!set2.contains(&2));
+
+        // Identical states produce an empty delta that applies as a no-op
+        let empty = set1.delta_since(&set2);
+        assert_eq!(empty.element_entries(), 0);
+        assert_eq!(empty.tombstone_entries(), 0);
+        set2.apply_delta(&empty).unwrap();
+        assert!(set2.eq(&set1));
+    }
+
+    #[test]
+    fn test_apply_invalid_delta() {
+        let mut set1 = ORSet::<u32, DefaultConfig>::new(1);
+        let mut set2 = ORSet::<u32, DefaultConfig>::new(2);
+        set1.add(42, 1000).unwrap();
+        set1.remove(&42, 2000).unwrap();
+
+        // A delta with a tombstone for an element nobody observed being added
+        let mut delta = set1.delta_since(&set2);
+        #[cfg(not(feature = "hardware-atomic"))]
+        {
+            delta.elements = [const { None }; 8];
+            delta.element_count = 0;
+        }
+        #[cfg(feature = "hardware-atomic")]
+        {
+            *delta.elements.get_mut() = [const { None }; 8];
+            *delta.element_count.get_mut() = 0;
+        }
+
+        assert_eq!(set2.apply_delta(&delta), Err(CRDTError::InvalidMerge));
+        assert_eq!(set2.element_entries(), 0);
+    }
+
+    #[test]
+    fn test_state_hash_across_nodes() {
+        let mut node1 = ORSet::<u32, DefaultConfig>::new(1);
+        let mut node2 = ORSet::<u32, DefaultConfig>::new(2);
+        node1.add(1, 1000).unwrap();
+        node1.add(2, 1000).unwrap();
+        node2.add(2, 1100).unwrap();
+        node2.add(3, 1100).unwrap();
+        node2.remove(&3, 1200).unwrap();
+
+        let mut merged12 = node1.clone();
+        merged12.merge(&node2).unwrap();
+        let mut merged21 = node2.clone();
+        merged21.merge(&node1).unwrap();
+        assert_eq!(merged12.state_hash(), merged21.state_hash());
+
+        // Same elements, different tags: equal, so the hashes agree too
+        let mut single = ORSet::<u32, DefaultConfig>::new(3);
+        single.add(2, 500).unwrap();
+        single.add(1, 500).unwrap();
+        assert!(single.eq(&merged12));
+        assert_eq!(single.state_hash(), merged12.state_hash());
+
+        single.add(4, 600).unwrap();
+        assert_ne!(single.state_hash(), merged12.state_hash());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        set.add(1, 1000).unwrap();
+        let snap = set.snapshot();
+        assert!(!set.has_changed_since(&snap));
+
+        set.remove(&1, 2000).unwrap();
+        assert!(set.has_changed_since(&snap));
+
+        let removed = set.snapshot();
+        let mut other = ORSet::<u32, DefaultConfig>::new(2);
+        other.add(1, 3000).unwrap();
+        set.merge(&other).unwrap();
+        assert!(set.has_changed_since(&removed));
+    }
+
+    #[test]
+    fn test_retain_survives_merge() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        for element in 1..=5 {
+            set.add(element, 1000).unwrap();
+        }
+        let before = set.clone();
+
+        assert_eq!(set.retain(|&e| e <= 2, 2000).unwrap(), 3);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.tombstone_entries(), 3);
+
+        // A replica that still holds the elements does not bring them back
+        set.merge(&before).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(!set.contains(&3));
+
+        let mut other = before;
+        other.merge(&set).unwrap();
+        assert!(other.eq(&set));
+        assert!(!other.contains(&5));
+    }
+
+    #[test]
+    fn test_retain_if_present_uses_latest_add() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        set.add(1, 1000).unwrap();
+        set.add(2, 1000).unwrap();
+
+        // Node 2 refreshed element 1 later
+        let mut other = ORSet::<u32, DefaultConfig>::new(2);
+        other.add(1, 5000).unwrap();
+        set.merge(&other).unwrap();
+
+        let mut seen = [0u64; 2];
+        let removed = set
+            .retain_if_present(
+                |&element, added| {
+                    seen[element as usize - 1] = added;
+                    added >= 4000
+                },
+                6000,
+            )
+            .unwrap();
+
+        assert_eq!(removed, 1);
+        assert_eq!(seen, [5000, 1000]);
+        assert!(set.contains(&1));
+        assert!(!set.contains(&2));
+    }
+
+    #[test]
+    fn test_drain_if() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        for element in 1..=4 {
+            set.add(element, 1000).unwrap();
+        }
+        set.remove(&4, 1500).unwrap();
+
+        // Removed elements are not drained again
+        let mut drained = [0u32; 4];
+        let mut count = 0;
+        for element in set.drain_if(|&e| e % 2 == 0, 2000).unwrap() {
+            drained[count] = element;
+            count += 1;
+        }
+        assert_eq!(&drained[..count], &[2]);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&1) && set.contains(&3));
+    }
+
+    #[test]
+    fn test_separate_tombstone_capacity() {
+        const TOMBSTONES: usize = DefaultConfig::MAX_TOMBSTONE_ENTRIES;
+        type SmallSet = ORSet<u32, DefaultConfig, 8, 2>;
+        assert!(
+            core::mem::size_of::<SmallSet>() < core::mem::size_of::<ORSet<u32, DefaultConfig, 8>>()
+        );
+        assert_eq!(TOMBSTONES, 16);
+
+        let mut set = SmallSet::with_capacity(1);
+        for element in 1..=4 {
+            set.add(element, 1000).unwrap();
+        }
+        set.remove(&1, 2000).unwrap();
+        assert_eq!(set.tombstone_capacity(), 2);
+        assert_eq!(set.tombstone_utilization_percent(), 50);
+        assert_eq!(set.memory_breakdown().tombstone_utilization, 50);
+        set.remove(&2, 2000).unwrap();
+
+        // The element array has room, but the tombstone array is full
+        assert!(set.can_add_element());
+        let error = set.remove(&3, 3000).unwrap_err();
+        assert_eq!(error.cause(), CRDTError::BufferOverflow);
+        assert_eq!(
+            error.context(),
+            Some(&ErrorContext::new("ORSet", "remove", 1, 2, 2))
+        );
+
+        let mut other = SmallSet::with_capacity(2);
+        other.add(3, 1000).unwrap();
+        other.remove(&3, 3000).unwrap();
+        assert!(!set.can_merge(&other));
+        let error = set.merge(&other).unwrap_err();
+        assert_eq!(error.cause(), CRDTError::BufferOverflow);
+        assert_eq!(
+            error.context(),
+            Some(&ErrorContext::new("ORSet", "merge", 1, 2, 2))
+        );
+
+        // A full element array is reported without the tombstone context
+        let mut full = SmallSet::with_capacity(3);
+        for element in 1..=8 {
+            full.add(element, 1000).unwrap();
+        }
+        let mut extra = SmallSet::with_capacity(4);
+        extra.add(9, 1000).unwrap();
+        let error = full.merge(&extra).unwrap_err();
+        assert_eq!(error.cause(), CRDTError::BufferOverflow);
+        assert_eq!(error.context(), None);
+    }
+
+    #[test]
+    fn test_conflict_policy() {
+        let mut add_wins = ORSet::<u32, DefaultConfig>::new_add_wins(1);
+        let mut remove_wins = ORSet::<u32, DefaultConfig>::new_remove_wins(1);
+        assert_eq!(add_wins.conflict_policy(), ConflictPolicy::AddWins);
+        assert_eq!(remove_wins.conflict_policy(), ConflictPolicy::RemoveWins);
+        assert_eq!(
+            ORSet::<u32, DefaultConfig>::new(1).conflict_policy(),
+            ConflictPolicy::RemoveWins
+        );
+
+        // Node 1 adds and node 2 removes at the same timestamp
+        for set in [&mut add_wins, &mut remove_wins] {
+            let mut remover =
+                ORSet::<u32, DefaultConfig>::with_conflict_policy(2, set.conflict_policy());
+            set.add(5, 1000).unwrap();
+            remover.merge(set).unwrap();
+            remover.remove(&5, 1000).unwrap();
+            set.merge(&remover).unwrap();
+        }
+        assert!(add_wins.contains(&5));
+        assert_eq!(add_wins.len(), 1);
+        assert!(!remove_wins.contains(&5));
+
+        // Strictly ordered operations are unaffected by the policy
+        add_wins.remove(&5, 1001).unwrap();
+        assert!(!add_wins.contains(&5));
+
+        // The policy survives clone and delta, but not a mismatched merge
+        let cloned = add_wins.clone();
+        assert_eq!(cloned.conflict_policy(), ConflictPolicy::AddWins);
+        let delta = add_wins.delta_since(&ORSet::new_add_wins(2));
+        assert_eq!(delta.conflict_policy(), ConflictPolicy::AddWins);
+        assert!(!add_wins.can_merge(&remove_wins));
+        assert_eq!(add_wins.merge(&remove_wins), Err(CRDTError::InvalidMerge));
+
+        // Switching the policy flips membership without touching the entries
+        let entries = remove_wins.element_entries();
+        remove_wins.set_conflict_policy(ConflictPolicy::AddWins);
+        assert!(remove_wins.contains(&5));
+        assert_eq!(remove_wins.element_entries(), entries);
+    }
+
+    #[test]
+    fn test_evict_lru() {
+        type SmallSet = ORSet<u32, DefaultConfig, 3, 4>;
+        let mut set = SmallSet::with_capacity(1);
+        let mut other = SmallSet::with_capacity(2);
+        set.add(1, 1000).unwrap();
+        set.add(2, 3000).unwrap();
+        other.add(1, 2500).unwrap();
+        set.merge(&other).unwrap();
+        let replica = set.clone();
+
+        // Element 1 was re-added at 2500, so element 2 is no longer the newest
+        assert_eq!(set.lru_element(), Some((1, CompactTimestamp::new(2500))));
+        assert_eq!(set.evict_lru(), Some(1));
+        assert!(!set.contains(&1));
+        assert_eq!(set.element_entries(), 1);
+        assert_eq!(set.tombstone_entries(), 2);
+
+        // Both adds are tombstoned, so the replica cannot bring it back
+        set.merge(&replica).unwrap();
+        assert!(!set.contains(&1));
+        assert!(set.contains(&2));
+        let mut replica = replica;
+        replica.merge(&set).unwrap();
+        assert!(!replica.contains(&1));
+
+        // The merge does not restore the evicted entries
+        assert_eq!(set.element_entries(), 1);
+        assert_eq!(set.evict_oldest_n(5), 1);
+        assert!(set.is_empty());
+        assert_eq!(set.tombstone_entries(), 3);
+        assert_eq!(set.evict_lru(), None);
+    }
+
+    #[test]
+    fn test_add_with_eviction() {
+        let mut set = ORSet::<u32, DefaultConfig, 2>::with_capacity(1);
+        set.add(1, 2000).unwrap();
+        set.add(2, 1000).unwrap();
+
+        assert_eq!(
+            set.add_with_eviction(3, 3000, EvictionPolicy::RefuseWhenFull),
+            Err(CRDTError::BufferOverflow)
+        );
+        assert_eq!(EvictionPolicy::default(), EvictionPolicy::RefuseWhenFull);
+
+        // Re-adding a stored element needs no slot and evicts nothing
+        assert!(
+            !set.add_with_eviction(1, 2500, EvictionPolicy::EvictLRU)
+                .unwrap()
+        );
+        assert_eq!(set.len(), 2);
+
+        assert!(
+            set.add_with_eviction(3, 3000, EvictionPolicy::EvictLRU)
+                .unwrap()
+        );
+        assert!(!set.contains(&2));
+        assert!(set.contains(&1) && set.contains(&3));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_eviction_survives_merge() {
+        let mut set = ORSet::<u8, DefaultConfig, 2>::with_capacity(1);
+        set.add(1, 1000).unwrap();
+        set.add(2, 2000).unwrap();
+        let replica = set.clone();
+
+        assert!(
+            set.add_with_eviction(3, 3000, EvictionPolicy::EvictLRU)
+                .unwrap()
+        );
+        let stored = set.element_entries();
+
+        // The replica still holds the evicted element, which must not take its slot back
+        assert!(set.can_merge(&replica));
+        assert!(set.merge(&replica).is_ok());
+        assert_eq!(set.element_entries(), stored);
+        assert!(!set.contains(&1));
+        assert!(set.contains(&2) && set.contains(&3));
+    }
+
+    #[test]
+    fn test_element_aging() {
+        let mut set = ORSet::<u32, DefaultConfig>::new(1);
+        assert_eq!(set.oldest_element(), None);
+        assert_eq!(set.youngest_element(), None);
+
+        set.add(1, 1000).unwrap();
+        set.add(2, 2000).unwrap();
+        set.add(3, 3000).unwrap();
+
+        // A second node adds element 2 later
+        let mut other = ORSet::<u32, DefaultConfig>::new(2);
+        other.add(2, 4000).unwrap();
+        set.merge(&other).unwrap();
+        assert_eq!(set.element_timestamp(&2), Some(4000));
+
+        // Removed and re-added: the re-add counts
+        set.remove(&1, 5000).unwrap();
+        assert_eq!(set.element_timestamp(&1), None);
+        other.add(1, 6000).unwrap();
+        set.merge(&other).unwrap();
+        assert_eq!(set.element_timestamp(&1), Some(6000));
+        assert_eq!(set.element_timestamp(&9), None);
+
+        assert_eq!(set.oldest_element(), Some((&3, 3000)));
+        assert_eq!(set.youngest_element(), Some((&1, 6000)));
+
+        assert!(set.elements_older_than(7000, 2500).copied().eq([2, 3]));
+        assert_eq!(set.elements_older_than(7000, 4000).count(), 0);
+        assert_eq!(set.elements_older_than(1000, 5000).count(), 0);
+
+        assert!(
+            set.elements_in_age_range(7000, 1000, 3000)
+                .copied()
+                .eq([1, 2])
+        );
+        assert_eq!(set.elements_in_age_range(7000, 0, u64::MAX).count(), 3);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut a = ORSet::<u32, DefaultConfig>::new(1);
+        a.add(1, 1000).unwrap();
+        a.add(2, 1000).unwrap();
+        a.add(3, 1000).unwrap();
+        a.remove(&3, 2000).unwrap();
+
+        let mut b = ORSet::<u32, DefaultConfig>::new(2);
+        b.add(2, 1100).unwrap();
+        b.add(3, 1100).unwrap();
+        b.add(4, 1100).unwrap();
+        b.remove(&4, 1200).unwrap();
+
+        // a's tombstone for 3 would hide b's add, so it is left out
+        let union: ORSet<u32, DefaultConfig> = a.union_with(&b).unwrap();
+        assert_eq!(union.node_id(), 0);
+        assert_eq!(union.len(), 3);
+        assert!(union.contains(&1) && union.contains(&2) && union.contains(&3));
+        assert_eq!(union.element_entries(), 5);
+        assert_eq!(union.tombstone_entries(), 1);
+        assert!(union.validate().is_ok());
+
+        let shared: ORSet<u32, DefaultConfig> = a.intersection_with(&b).unwrap();
+        assert_eq!(shared.len(), 1);
+        assert!(shared.contains(&2));
+        assert_eq!(shared.tombstone_entries(), 2);
+
+        // Tombstones from both inputs keep removed elements from coming back
+        let mut resurrect = shared.clone();
+        resurrect.merge(&b).unwrap();
+        resurrect.merge(&a).unwrap();
+        assert!(!resurrect.contains(&3));
+        assert!(!resurrect.contains(&4));
+
+        let exclusive: ORSet<u32, DefaultConfig> = a.symmetric_difference_with(&b).unwrap();
+        assert_eq!(exclusive.len(), 2);
+        assert!(exclusive.contains(&1) && exclusive.contains(&3));
+
+        // Identical entries are stored once, and removed elements are dropped
+        let same: ORSet<u32, DefaultConfig> = a.union_with(&a.clone()).unwrap();
+        assert_eq!(same.element_entries(), 2);
+        assert_eq!(same.tombstone_entries(), a.tombstone_entries());
+
+        assert_eq!(
+            a.union_with::<8, 2>(&b).map(|set| set.len()),
+            Err(CRDTError::BufferOverflow)
+        );
+        let add_wins = ORSet::<u32, DefaultConfig>::new_add_wins(3);
+        assert_eq!(
+            a.union_with::<8, 8>(&add_wins).map(|set| set.len()),
+            Err(CRDTError::InvalidMerge)
+        );
+    }
+
+    #[test]
+    fn test_observe_remove_all() {
+        type SmallSet = ORSet<u32, DefaultConfig, 8, 3>;
+        let mut set = SmallSet::with_capacity(1);
+        let mut other = SmallSet::with_capacity(2);
+        set.add(1, 1000).unwrap();
+        set.add(2, 1000).unwrap();
+        other.add(2, 1100).unwrap();
+        set.merge(&other).unwrap();
+        set.add(3, 1200).unwrap();
+
+        // Element 2 was added by both nodes and needs two tombstones
+        assert!(!set.is_clearing_feasible());
+        assert_eq!(
+            set.observe_remove_all_strict(2000),
+            Err(CRDTError::BufferOverflow)
+        );
+        assert_eq!(set.len(), 3);
+
+        // A partial clear skips the element that no longer fits
+        assert_eq!(set.observe_remove_all(2000).unwrap(), 2);
+        assert!(set.contains(&3));
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.tombstone_entries(), 3);
+
+        // Merging spreads the removals to the peer
+        other.merge(&set).unwrap();
+        assert!(!other.contains(&1));
+        assert!(!other.contains(&2));
+        assert!(other.contains(&3));
+
+        let mut roomy = ORSet::<u32, DefaultConfig>::new(1);
+        roomy.add(5, 1000).unwrap();
+        roomy.add(6, 1000).unwrap();
+        assert!(roomy.is_clearing_feasible());
+        assert_eq!(roomy.observe_remove_all_strict(3000).unwrap(), 2);
+        assert!(roomy.is_empty());
+        assert_eq!(roomy.observe_remove_all(3000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_active_count_matches_full_count() {
+        type SmallSet = ORSet<u32, DefaultConfig, 6>;
+        let mut replicas = [
+            SmallSet::with_capacity(0),
+            SmallSet::with_capacity(1),
+            SmallSet::with_capacity(2),
+        ];
+
+        // Deterministic pseudo-random operations over a few elements so that
+        // adds, removes and merges keep colliding; errors are expected
+        let mut seed = 0x2545_f491_u32;
+        for step in 0..2000u64 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            let i = (seed % 3) as usize;
+            let element = (seed >> 8) % 5;
+            let timestamp = 1000 + step / 3;
+
+            match (seed >> 16) % 12 {
+                0..=3 => {
+                    let _ = replicas[i].add(element, timestamp);
+                }
+                4 | 5 => {
+                    let _ = replicas[i].remove(&element, timestamp);
+                }
+                6 | 7 => {
+                    let other = replicas[(i + 1) % 3].clone();
+                    let _ = replicas[i].merge(&other);
+                }
+                8 => {
+                    replicas[i].compact_tombstones();
+                }
+                9 => {
+                    replicas[i].purge(&element);
+                }
+                10 => {
+                    let _ =
+                        replicas[i].add_with_eviction(element, timestamp, EvictionPolicy::EvictLRU);
+                }
+                _ => {
+                    let baseline = replicas[i].clone();
+                    let _ = replicas[i].retain(|&e| e != element, timestamp);
+                    let delta = replicas[i].delta_since(&baseline);
+                    assert_eq!(delta.len(), delta.count_present());
+                    let _ = replicas[(i + 2) % 3].apply_delta(&delta);
+                }
+            }
+
+            for replica in &replicas {
+                assert_eq!(replica.len(), replica.count_present(), "step {}", step);
+                assert!(replica.validate().is_ok());
+            }
+        }
+
+        // Switching the policy can change membership without touching entries
+        let mut set = SmallSet::with_capacity(0);
+        set.add(7, 1000).unwrap();
+        set.remove(&7, 1000).unwrap();
+        assert_eq!(set.len(), 0);
+        set.set_conflict_policy(ConflictPolicy::AddWins);
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.len(), set.count_present());
+
+        // Derived sets and decoded copies start with a correct count
+        let gset: GSet<u32, DefaultConfig, 4> = set.snapshot_as_gset().unwrap();
+        assert_eq!(gset.len(), 1);
+        let union: ORSet<u32, DefaultConfig, 12> = replicas[0].union_with(&replicas[1]).unwrap();
+        assert_eq!(union.len(), union.count_present());
+    }
+
     #[cfg(all(test, feature = "serde"))]
     mod serde_tests {
         use super::*;
@@ -2041,4 +4602,68 @@ mod tests {
             assert_eq!(set.tombstone_entries(), 1);
         }
     }
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn test_merge_checked_and_diff_report() {
+        let mut set1 =
+            ORSet::<u32, DefaultConfig, 5, 2>::with_conflict_policy(1, ConflictPolicy::RemoveWins);
+        set1.add(1, 1000).unwrap();
+        set1.add(2, 1000).unwrap();
+        set1.remove(&2, 2000).unwrap();
+
+        let mut set2 =
+            ORSet::<u32, DefaultConfig, 5, 2>::with_conflict_policy(2, ConflictPolicy::RemoveWins);
+        set2.add(2, 2000).unwrap(); // Ties with the remove, which wins
+        set2.add(3, 1000).unwrap();
+        set2.add(4, 1000).unwrap();
+        set2.remove(&4, 1500).unwrap();
+
+        let diff = set1.diff_report(&set2);
+        assert_eq!(
+            diff,
+            DiffReport {
+                symmetric_only_in_self: 1,
+                symmetric_only_in_other: 2,
+                in_both: 0,
+            }
+        );
+
+        let mut merged = set1.clone();
+        let report = merged.merge_checked(&set2);
+        assert_eq!(
+            report,
+            MergeReport {
+                elements_added: 2,
+                tombstones_added: 1,
+                elements_suppressed_by_tombstone: 1,
+                tombstone_capacity_remaining: 0,
+                element_capacity_remaining: 1,
+                ok: true,
+                error: None,
+            }
+        );
+
+        let mut expected = set1.clone();
+        expected.merge(&set2).unwrap();
+        assert!(merged.eq(&expected));
+        assert_eq!(merged.diff_report(&expected).in_both, 2);
+
+        // Merging again copies nothing
+        let report = merged.merge_checked(&set2);
+        assert_eq!((report.elements_added, report.tombstones_added), (0, 0));
+
+        // A full tombstone array stops the merge
+        let mut set3 =
+            ORSet::<u32, DefaultConfig, 5, 2>::with_conflict_policy(3, ConflictPolicy::RemoveWins);
+        set3.add(5, 1000).unwrap();
+        set3.remove(&5, 1100).unwrap();
+        let report = merged.merge_checked(&set3);
+        assert!(!report.ok);
+        assert_eq!(
+            report.error.map(|e| e.cause()),
+            Some(CRDTError::BufferOverflow)
+        );
+        assert_eq!(report.tombstone_capacity_remaining, 0);
+    }
 }
@@ -0,0 +1,441 @@
+//! Two-Phase Set CRDT
+//!
+//! A set that supports both add and remove, where a removed element can never
+//! be added back. Built from two grow-only sets with zero allocation.
+
+use crate::clock::StateHash;
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::MemoryConfig;
+use crate::sets::GSet;
+use crate::traits::{BoundedCRDT, CRDT, MemoryBreakdown, RealTimeCRDT};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Two-Phase Set with configurable element arrays
+///
+/// Additions are recorded in one grow-only set and removals in another.
+/// An element is present when it has been added and not removed. Because
+/// both halves only grow, a removal is permanent: once an element has been
+/// removed it cannot be added again on any replica.
+///
+/// # Type Parameters
+/// - `T`: The element type stored in the set
+/// - `C`: Memory configuration
+/// - `CAPACITY`: The maximum number of elements each half can hold (defaults to 8)
+///
+/// # Memory Usage
+/// - Fixed size: 2 * (sizeof(T) * CAPACITY + 8) bytes
+/// - Example: For u32 with 8 elements = 80 bytes
+/// - Completely predictable at compile time
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// // Track commissioned sensors; decommissioned ones never come back
+/// let mut sensors1 = TwoPSet::<u32, DefaultConfig>::new();
+/// sensors1.add(1)?;
+/// sensors1.add(2)?;
+///
+/// let mut sensors2 = sensors1.clone();
+/// sensors2.remove(&2)?;
+///
+/// sensors1.merge(&sensors2)?;
+/// assert!(sensors1.contains(&1));
+/// assert!(!sensors1.contains(&2));
+///
+/// // Re-adding a removed element is rejected
+/// assert!(sensors1.add(2).is_err());
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: Serialize + Clone + PartialEq",
+        deserialize = "T: Deserialize<'de> + Clone + PartialEq"
+    ))
+)]
+pub struct TwoPSet<T, C: MemoryConfig, const CAPACITY: usize = 8> {
+    /// Every element ever added
+    added: GSet<T, C, CAPACITY>,
+    /// Every element ever removed (always a subset of `added`)
+    removed: GSet<T, C, CAPACITY>,
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize> TwoPSet<T, C, CAPACITY>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new two-phase set with custom capacity
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    ///
+    /// let set = TwoPSet::<u32, DefaultConfig, 4>::with_capacity();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn with_capacity() -> Self {
+        Self {
+            added: GSet::with_capacity(),
+            removed: GSet::with_capacity(),
+        }
+    }
+}
+
+impl<T, C: MemoryConfig> TwoPSet<T, C, 8>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new two-phase set with default capacity (8)
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    ///
+    /// let set = TwoPSet::<u32, DefaultConfig>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_capacity()
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize> TwoPSet<T, C, CAPACITY>
+where
+    T: Clone + PartialEq,
+{
+    /// Adds an element to the set
+    ///
+    /// Returns `Ok(true)` if the element was newly added and `Ok(false)` if it
+    /// was already present.
+    ///
+    /// # Errors
+    /// - `CRDTError::InvalidOperation` if the element has already been removed
+    /// - `CRDTError::BufferOverflow` if the add set is full
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    ///
+    /// let mut set = TwoPSet::<u32, DefaultConfig>::new();
+    /// assert!(set.add(42)?);
+    /// assert!(!set.add(42)?);
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn add(&mut self, element: T) -> CRDTResult<bool> {
+        if self.removed.contains(&element) {
+            return Err(CRDTError::InvalidOperation);
+        }
+        self.added.insert(element)
+    }
+
+    /// Removes an element from the set
+    ///
+    /// Returns `Ok(true)` if the element was present and is now removed, and
+    /// `Ok(false)` if it was never added or has already been removed.
+    ///
+    /// # Errors
+    /// - `CRDTError::BufferOverflow` if the remove set is full
+    ///
+    /// # Example
+    /// ```rust
+    /// use crdtosphere::prelude::*;
+    ///
+    /// let mut set = TwoPSet::<u32, DefaultConfig>::new();
+    /// set.add(42)?;
+    /// assert!(set.remove(&42)?);
+    /// assert!(!set.remove(&42)?);
+    /// assert!(!set.contains(&42));
+    /// # Ok::<(), crdtosphere::error::CRDTError>(())
+    /// ```
+    pub fn remove(&mut self, element: &T) -> CRDTResult<bool> {
+        if !self.contains(element) {
+            return Ok(false);
+        }
+        self.removed.insert(element.clone())
+    }
+
+    /// Checks if an element is currently in the set
+    pub fn contains(&self, element: &T) -> bool {
+        self.added.contains(element) && !self.removed.contains(element)
+    }
+
+    /// Checks if an element has been removed
+    ///
+    /// A removed element can never be added back.
+    pub fn is_removed(&self, element: &T) -> bool {
+        self.removed.contains(element)
+    }
+
+    /// Returns the number of elements currently in the set
+    pub fn len(&self) -> usize {
+        self.added.len() - self.removed.len()
+    }
+
+    /// Returns true if no elements are currently in the set
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of elements each half of the set can hold
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns an iterator over the elements currently in the set
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.added
+            .iter()
+            .filter(move |element| !self.removed.contains(element))
+    }
+}
+
+impl<T, C: MemoryConfig> Default for TwoPSet<T, C>
+where
+    T: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize> CRDT<C> for TwoPSet<T, C, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    type Error = CRDTError;
+
+    fn merge(&mut self, other: &Self) -> CRDTResult<()> {
+        self.added.merge(&other.added)?;
+        self.removed.merge(&other.removed)
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.added.eq(&other.added) && self.removed.eq(&other.removed)
+    }
+
+    fn size_bytes(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn validate(&self) -> CRDTResult<()> {
+        self.added.validate()?;
+        self.removed.validate()?;
+
+        // Only elements that were added can have been removed
+        if !self.removed.is_subset(&self.added) {
+            return Err(CRDTError::InvalidState);
+        }
+
+        Ok(())
+    }
+
+    fn state_hash(&self) -> u32 {
+        self.added.state_hash() ^ self.removed.state_hash().rotate_left(16)
+    }
+
+    fn can_merge(&self, other: &Self) -> bool {
+        self.added.can_merge(&other.added) && self.removed.can_merge(&other.removed)
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize> BoundedCRDT<C> for TwoPSet<T, C, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_SIZE_BYTES: usize = core::mem::size_of::<Self>();
+    const MAX_ELEMENTS: usize = CAPACITY;
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    fn element_count(&self) -> usize {
+        // Removed elements still occupy a slot in the add set
+        self.added.len()
+    }
+
+    fn compact(&mut self) -> CRDTResult<usize> {
+        // Dropping either half would let removed elements reappear
+        Ok(0)
+    }
+
+    fn can_add_element(&self) -> bool {
+        self.element_count() < Self::MAX_ELEMENTS
+    }
+
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        // The remove set acts as the tombstone store
+        let added = self.added.memory_breakdown();
+        let removed = self.removed.memory_breakdown();
+        MemoryBreakdown::new(
+            self.memory_usage(),
+            added.element_data,
+            removed.element_data,
+            added.unused_capacity + removed.unused_capacity,
+        )
+    }
+}
+
+impl<T, C: MemoryConfig, const CAPACITY: usize> RealTimeCRDT<C> for TwoPSet<T, C, CAPACITY>
+where
+    T: Clone + PartialEq + core::fmt::Debug + StateHash,
+{
+    const MAX_MERGE_CYCLES: u32 = 400; // Two GSet merges
+    const MAX_VALIDATE_CYCLES: u32 = 250;
+    const MAX_SERIALIZE_CYCLES: u32 = 300;
+
+    fn merge_bounded(&mut self, other: &Self) -> CRDTResult<()> {
+        self.merge(other)
+    }
+
+    fn validate_bounded(&self) -> CRDTResult<()> {
+        self.validate()
+    }
+
+    fn remaining_budget(&self) -> Option<u32> {
+        None
+    }
+
+    fn set_budget(&mut self, _cycles: u32) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_new_set() {
+        let set = TwoPSet::<u32, DefaultConfig>::new();
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+        assert_eq!(set.capacity(), 8);
+    }
+
+    #[test]
+    fn test_add_remove() {
+        let mut set = TwoPSet::<u32, DefaultConfig>::new();
+
+        assert!(set.add(1).unwrap());
+        assert!(set.add(2).unwrap());
+        assert!(!set.add(1).unwrap());
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(&1).unwrap());
+        assert!(!set.contains(&1));
+        assert!(set.is_removed(&1));
+        assert!(set.contains(&2));
+        assert_eq!(set.len(), 1);
+
+        // Removing again, or removing something never added, is a no-op
+        assert!(!set.remove(&1).unwrap());
+        assert!(!set.remove(&3).unwrap());
+        assert!(!set.is_removed(&3));
+    }
+
+    #[test]
+    fn test_add_after_remove_rejected() {
+        let mut set = TwoPSet::<u32, DefaultConfig>::new();
+        set.add(1).unwrap();
+        set.remove(&1).unwrap();
+
+        assert_eq!(set.add(1), Err(CRDTError::InvalidOperation));
+        assert!(!set.contains(&1));
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut set = TwoPSet::<u32, DefaultConfig>::new();
+        set.add(1).unwrap();
+        set.add(2).unwrap();
+        set.add(3).unwrap();
+        set.remove(&2).unwrap();
+
+        let mut count = 0;
+        for element in set.iter() {
+            assert!(*element == 1 || *element == 3);
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut set1 = TwoPSet::<u32, DefaultConfig>::new();
+        set1.add(1).unwrap();
+        set1.add(2).unwrap();
+
+        let mut set2 = set1.clone();
+        set2.remove(&1).unwrap();
+        set2.add(3).unwrap();
+
+        set1.merge(&set2).unwrap();
+        assert!(!set1.contains(&1));
+        assert!(set1.contains(&2));
+        assert!(set1.contains(&3));
+        assert_eq!(set1.len(), 2);
+
+        // The removal wins on every replica
+        assert_eq!(set1.add(1), Err(CRDTError::InvalidOperation));
+    }
+
+    #[test]
+    fn test_merge_commutative() {
+        let mut set1 = TwoPSet::<u32, DefaultConfig>::new();
+        set1.add(1).unwrap();
+        set1.add(2).unwrap();
+        set1.remove(&2).unwrap();
+
+        let mut set2 = TwoPSet::<u32, DefaultConfig>::new();
+        set2.add(2).unwrap();
+        set2.add(3).unwrap();
+
+        let mut merged1 = set1.clone();
+        merged1.merge(&set2).unwrap();
+        let mut merged2 = set2.clone();
+        merged2.merge(&set1).unwrap();
+
+        assert!(merged1.eq(&merged2));
+        assert!(!merged1.contains(&2));
+    }
+
+    #[test]
+    fn test_capacity_limits() {
+        let mut set = TwoPSet::<u32, DefaultConfig, 2>::with_capacity();
+        set.add(1).unwrap();
+        set.add(2).unwrap();
+        assert_eq!(set.add(3), Err(CRDTError::BufferOverflow));
+        assert!(!set.can_add_element());
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut set = TwoPSet::<u32, DefaultConfig>::new();
+        set.add(1).unwrap();
+        set.remove(&1).unwrap();
+        assert!(set.validate().is_ok());
+
+        // A removal without a matching add is not a reachable state
+        set.removed.insert(7).unwrap();
+        assert_eq!(set.validate(), Err(CRDTError::InvalidState));
+    }
+
+    #[test]
+    fn test_bounded_crdt() {
+        let mut set = TwoPSet::<u32, DefaultConfig>::new();
+        set.add(1).unwrap();
+        set.add(2).unwrap();
+        set.remove(&1).unwrap();
+
+        assert_eq!(set.element_count(), 2);
+        assert_eq!(set.compact().unwrap(), 0);
+        assert!(set.can_add_element());
+        assert!(set.merge_bounded(&TwoPSet::new()).is_ok());
+        assert!(set.validate_bounded().is_ok());
+    }
+}
@@ -34,14 +34,12 @@ pub trait BoundedCRDT<C: MemoryConfig>: CRDT<C> {
         self.memory_usage() >= Self::MAX_SIZE_BYTES
     }
 
-    /// Returns the memory utilization as a percentage (0-100)
+    /// Returns the element utilization as a percentage (0-100)
+    ///
+    /// Fixed-size CRDTs always occupy `MAX_SIZE_BYTES`, so utilization is
+    /// measured against `MAX_ELEMENTS` rather than memory usage.
     fn utilization_percent(&self) -> u8 {
-        if Self::MAX_SIZE_BYTES == 0 {
-            return 100;
-        }
-
-        let utilization = (self.memory_usage() * 100) / Self::MAX_SIZE_BYTES;
-        utilization.min(100) as u8
+        utilization_percent(self.element_count(), Self::MAX_ELEMENTS)
     }
 
     /// Checks if adding an element would exceed memory bounds
@@ -83,6 +81,26 @@ pub trait BoundedCRDT<C: MemoryConfig>: CRDT<C> {
             utilization_percent: self.utilization_percent(),
         }
     }
+
+    /// Returns where the memory of this CRDT goes
+    ///
+    /// The default reports all memory as metadata; CRDTs with fixed-size
+    /// element storage override it with a per-field breakdown.
+    fn memory_breakdown(&self) -> MemoryBreakdown {
+        MemoryBreakdown::new(self.memory_usage(), 0, 0, 0)
+    }
+}
+
+/// Computes `used * 100 / capacity`, clamped to 0-100
+///
+/// A zero capacity counts as full.
+pub const fn utilization_percent(used: usize, capacity: usize) -> u8 {
+    if capacity == 0 {
+        return 100;
+    }
+
+    let percent = used.saturating_mul(100) / capacity;
+    if percent > 100 { 100 } else { percent as u8 }
 }
 
 /// Memory statistics for bounded CRDTs
@@ -122,6 +140,80 @@ impl MemoryStats {
     }
 }
 
+/// Per-field memory breakdown of a CRDT
+///
+/// The four byte fields always add up to the CRDT's memory usage:
+/// - `element_data`: payload bytes of live elements, values and counters
+/// - `tombstone_data`: payload bytes of removal records
+/// - `unused_capacity`: bytes of empty slots that can still be filled
+/// - `metadata`: everything else (counts, node IDs, slot tags and padding)
+///
+/// CRDTs with a separately sized tombstone array also report how full it
+/// is in `tombstone_utilization`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryBreakdown {
+    /// Bookkeeping bytes not attributable to elements or tombstones
+    pub metadata: usize,
+    /// Bytes used by live elements
+    pub element_data: usize,
+    /// Bytes used by tombstones
+    pub tombstone_data: usize,
+    /// Bytes reserved by empty slots
+    pub unused_capacity: usize,
+    /// Percentage (0-100) of a separate tombstone array in use, 0 if none
+    pub tombstone_utilization: u8,
+}
+
+impl MemoryBreakdown {
+    /// Creates a breakdown of `total` bytes, attributing the rest to metadata
+    pub const fn new(
+        total: usize,
+        element_data: usize,
+        tombstone_data: usize,
+        unused_capacity: usize,
+    ) -> Self {
+        Self {
+            metadata: total
+                .saturating_sub(element_data)
+                .saturating_sub(tombstone_data)
+                .saturating_sub(unused_capacity),
+            element_data,
+            tombstone_data,
+            unused_capacity,
+            tombstone_utilization: 0,
+        }
+    }
+
+    /// Sets the tombstone array utilization percentage
+    pub const fn with_tombstone_utilization(mut self, percent: u8) -> Self {
+        self.tombstone_utilization = percent;
+        self
+    }
+
+    /// Returns the total number of bytes accounted for
+    pub const fn total(&self) -> usize {
+        self.metadata + self.element_data + self.tombstone_data + self.unused_capacity
+    }
+
+    /// Returns the bytes holding replicated state (elements and tombstones)
+    pub const fn used(&self) -> usize {
+        self.element_data + self.tombstone_data
+    }
+}
+
+/// What a full capacity-managed CRDT does with a new element
+///
+/// Used by [`ORSet::add_with_eviction`](crate::sets::ORSet::add_with_eviction)
+/// and [`LWWMap::insert_with_eviction`](crate::maps::LWWMap::insert_with_eviction).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvictionPolicy {
+    /// Reject the new element with `CRDTError::BufferOverflow`
+    #[default]
+    RefuseWhenFull,
+    /// Evict the least recently updated element to make room
+    EvictLRU,
+}
+
 /// Trait for CRDTs that support memory pressure handling
 ///
 /// This trait provides methods for CRDTs to handle memory pressure situations
@@ -282,6 +374,28 @@ mod tests {
         assert_eq!(stats.element_count, 5);
         assert_eq!(stats.max_elements, 10);
         assert_eq!(stats.remaining_elements(), 5);
+        assert_eq!(stats.utilization_percent, 50);
+
+        let breakdown = crdt.memory_breakdown();
+        assert_eq!(breakdown.metadata, crdt.memory_usage());
+        assert_eq!(breakdown.total(), crdt.memory_usage());
+    }
+
+    #[test]
+    fn test_utilization_percent() {
+        const HALF: u8 = utilization_percent(5, 10);
+        assert_eq!(HALF, 50);
+        assert_eq!(utilization_percent(0, 10), 0);
+        assert_eq!(utilization_percent(20, 10), 100);
+        assert_eq!(utilization_percent(0, 0), 100);
+    }
+
+    #[test]
+    fn test_memory_breakdown() {
+        const BREAKDOWN: MemoryBreakdown = MemoryBreakdown::new(100, 40, 10, 30);
+        assert_eq!(BREAKDOWN.metadata, 20);
+        assert_eq!(BREAKDOWN.used(), 50);
+        assert_eq!(BREAKDOWN.total(), 100);
     }
 
     #[test]
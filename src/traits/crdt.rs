@@ -31,6 +31,32 @@ pub trait CRDT<C: MemoryConfig> {
     /// Returns `Ok(())` if the merge was successful, or an error if the merge failed.
     fn merge(&mut self, other: &Self) -> CRDTResult<()>;
 
+    /// Merges several CRDT instances into this one in a single call
+    ///
+    /// The result is the same as merging each instance in turn. Types whose
+    /// merge can be computed as one pass over all inputs override this to
+    /// skip the intermediate states, for example when a burst of updates
+    /// from many nodes arrives at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `others` - The CRDT instances to merge
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if every merge was successful, or the first error
+    /// encountered.
+    fn merge_batch<'a, I>(&mut self, others: I) -> CRDTResult<()>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        for other in others {
+            self.merge(other)?;
+        }
+        Ok(())
+    }
+
     /// Checks if this CRDT is equal to another
     ///
     /// Two CRDTs are considered equal if they represent the same logical state,
@@ -85,28 +111,6 @@ pub trait PartiallyOrdered<C: MemoryConfig>: CRDT<C> {
     }
 }
 
-/// Trait for CRDTs that support delta operations
-///
-/// Delta CRDTs can generate and apply deltas (incremental changes) rather
-/// than merging entire states, which can be more efficient for network
-/// transmission.
-pub trait DeltaCRDT<C: MemoryConfig>: CRDT<C> {
-    /// The type representing a delta (incremental change)
-    type Delta;
-
-    /// Generates a delta representing changes since the given state
-    fn delta_since(&self, other: &Self) -> Option<Self::Delta>;
-
-    /// Applies a delta to this CRDT
-    fn apply_delta(&mut self, delta: &Self::Delta) -> CRDTResult<()>;
-
-    /// Merges two deltas into a single delta
-    fn merge_deltas(delta1: &Self::Delta, delta2: &Self::Delta) -> Self::Delta;
-
-    /// Returns the size of a delta in bytes
-    fn delta_size(delta: &Self::Delta) -> usize;
-}
-
 /// Trait for CRDTs that support causal consistency
 ///
 /// Causal CRDTs maintain causal relationships between operations and can
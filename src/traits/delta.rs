@@ -0,0 +1,46 @@
+//! Delta-state CRDT trait definition
+//!
+//! This module defines the DeltaCRDT trait for CRDTs that can ship only the
+//! part of their state that a peer is missing instead of the full state.
+
+use crate::error::CRDTResult;
+use crate::memory::MemoryConfig;
+use crate::traits::CRDT;
+
+/// Trait for CRDTs that support delta-state synchronisation
+///
+/// A delta is itself a (usually much smaller) instance of the CRDT that only
+/// carries the state a baseline replica has not seen yet. Applying the delta
+/// to the baseline brings it up to date, exactly as a full merge would.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut sender = GCounter::<DefaultConfig>::new(1);
+/// sender.increment(5)?;
+/// let mut receiver = GCounter::<DefaultConfig>::new(2);
+///
+/// // Only ship what the receiver is missing
+/// let delta = sender.delta_since(&receiver);
+/// receiver.apply_delta(&delta)?;
+/// assert_eq!(receiver.value(), 5);
+///
+/// // Identical states produce a no-op delta
+/// let empty = sender.delta_since(&receiver);
+/// assert!(empty.is_empty());
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+pub trait DeltaCRDT<C: MemoryConfig>: CRDT<C> + Sized {
+    /// Returns the minimal sub-state needed to bring `baseline` up to date
+    ///
+    /// If `baseline` already contains everything in `self`, the returned delta
+    /// is empty and applies as a no-op.
+    fn delta_since(&self, baseline: &Self) -> Self;
+
+    /// Applies a delta produced by [`delta_since`](Self::delta_since)
+    ///
+    /// This behaves like `merge`, but rejects a delta that is not a well-formed
+    /// sub-state with `CRDTError::InvalidMerge`.
+    fn apply_delta(&mut self, delta: &Self) -> CRDTResult<()>;
+}
@@ -0,0 +1,225 @@
+//! Diffable trait definition
+//!
+//! This module defines the Diffable trait for CRDTs that can describe how
+//! their state differs from an older baseline, and the SyncProtocol wrapper
+//! that uses it to send only what changed since the last sync.
+
+use crate::error::CRDTResult;
+use crate::memory::MemoryConfig;
+use crate::traits::{CRDT, DeltaCRDT};
+
+/// Trait for CRDTs that can compute the difference to a baseline state
+///
+/// The diff carries only what `baseline` is missing, which keeps packets
+/// small on low-bandwidth links such as LoRa or BLE advertisements. Every
+/// [`DeltaCRDT`] is `Diffable` with its delta as the diff:
+///
+/// - `GCounter`: only the nodes whose count exceeds the baseline
+/// - `LWWRegister`: empty if unchanged, a copy of the register otherwise
+/// - `LWWMap`: only the entries and tombstones newer than the baseline's
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut config = LWWMap::<u8, u16, DefaultConfig>::new(1);
+/// config.insert(1, 100, 1000)?;
+/// let synced = config.clone();
+///
+/// config.insert(2, 200, 2000)?;
+/// let diff = config.diff_from(&synced);
+/// assert_eq!(diff.len(), 1);
+///
+/// let mut peer = synced.clone();
+/// peer.apply_diff(&diff)?;
+/// assert_eq!(peer.get(&2), Some(&200));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+pub trait Diffable<C: MemoryConfig>: CRDT<C> {
+    /// The compact difference type produced by [`diff_from`](Self::diff_from)
+    type Diff: CRDT<C>;
+
+    /// Returns the part of `self` that `baseline` has not seen
+    fn diff_from(&self, baseline: &Self) -> Self::Diff;
+
+    /// Applies a diff produced by [`diff_from`](Self::diff_from)
+    ///
+    /// This is equivalent to a merge, but may reject a diff that is not a
+    /// valid monotone extension with `CRDTError::InvalidMerge`.
+    fn apply_diff(&mut self, diff: &Self::Diff) -> CRDTResult<()>;
+}
+
+impl<C: MemoryConfig, T: DeltaCRDT<C>> Diffable<C> for T {
+    type Diff = T;
+
+    fn diff_from(&self, baseline: &Self) -> Self::Diff {
+        self.delta_since(baseline)
+    }
+
+    fn apply_diff(&mut self, diff: &Self::Diff) -> CRDTResult<()> {
+        self.apply_delta(diff)
+    }
+}
+
+/// Wrapper that tracks the state last synced with a peer
+///
+/// [`prepare_sync_packet`](Self::prepare_sync_packet) returns the diff
+/// between the current state and the baseline the peer is known to have;
+/// [`mark_synced`](Self::mark_synced) moves the baseline forward once the
+/// peer has acknowledged it. Packets received from the peer are applied to
+/// both, so they are not echoed back.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut node = SyncProtocol::new(GCounter::<DefaultConfig>::new(1));
+/// let mut gateway = SyncProtocol::new(GCounter::<DefaultConfig>::new(2));
+///
+/// node.get_mut().increment(5)?;
+/// let packet = node.prepare_sync_packet();
+/// gateway.apply_sync_packet(&packet)?;
+/// node.mark_synced();
+///
+/// assert_eq!(gateway.get().value(), 5);
+/// assert!(node.prepare_sync_packet().is_empty());
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SyncProtocol<T: Diffable<C> + Clone, C: MemoryConfig> {
+    /// The local state
+    state: T,
+
+    /// The state the peer is known to have
+    baseline: T,
+
+    /// Phantom data to maintain the memory config type
+    _phantom: core::marker::PhantomData<C>,
+}
+
+impl<T: Diffable<C> + Clone, C: MemoryConfig> SyncProtocol<T, C> {
+    /// Wraps a CRDT, treating its current state as already synced
+    pub fn new(state: T) -> Self {
+        let baseline = state.clone();
+        Self::with_baseline(state, baseline)
+    }
+
+    /// Wraps a CRDT with the state the peer is known to have
+    ///
+    /// Use a fresh, empty CRDT as `baseline` to send the full state in the
+    /// first packet.
+    pub fn with_baseline(state: T, baseline: T) -> Self {
+        Self {
+            state,
+            baseline,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the changes since the last sync
+    pub fn prepare_sync_packet(&self) -> T::Diff {
+        self.state.diff_from(&self.baseline)
+    }
+
+    /// Applies a packet received from the peer
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or an error if the packet is rejected
+    pub fn apply_sync_packet(&mut self, diff: &T::Diff) -> CRDTResult<()> {
+        self.state.apply_diff(diff)?;
+        self.baseline.apply_diff(diff)
+    }
+
+    /// Records that the peer has received the current state
+    pub fn mark_synced(&mut self) {
+        self.baseline = self.state.clone();
+    }
+
+    /// Returns a reference to the local state
+    pub fn get(&self) -> &T {
+        &self.state
+    }
+
+    /// Returns a mutable reference to the local state
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.state
+    }
+
+    /// Returns a reference to the last synced state
+    pub fn baseline(&self) -> &T {
+        &self.baseline
+    }
+
+    /// Unwraps the local state
+    pub fn into_inner(self) -> T {
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::counters::GCounter;
+    use crate::maps::LWWMap;
+    use crate::memory::DefaultConfig;
+    use crate::registers::LWWRegister;
+
+    #[test]
+    fn test_gcounter_diff() {
+        let mut baseline = GCounter::<DefaultConfig>::new(1);
+        baseline.increment(3).unwrap();
+        let mut other = GCounter::<DefaultConfig>::new(2);
+        other.increment(4).unwrap();
+        baseline.merge(&other).unwrap();
+
+        let mut current = baseline.clone();
+        current.increment(2).unwrap();
+
+        // Only node 1 moved past the baseline
+        let diff = current.diff_from(&baseline);
+        assert_eq!(diff.node_value(1), 5);
+        assert_eq!(diff.node_value(2), 0);
+
+        baseline.apply_diff(&diff).unwrap();
+        assert!(baseline.eq(&current));
+    }
+
+    #[test]
+    fn test_lww_register_diff() {
+        let mut register = LWWRegister::<u16, DefaultConfig>::new(1);
+        register.set(10, 1000).unwrap();
+        let baseline = register.clone();
+
+        assert!(register.diff_from(&baseline).is_empty());
+
+        register.set(20, 2000).unwrap();
+        let diff = register.diff_from(&baseline);
+        assert_eq!(diff.get(), Some(&20));
+    }
+
+    #[test]
+    fn test_sync_protocol_round_trip() {
+        let mut node =
+            SyncProtocol::with_baseline(LWWMap::<u8, u16, DefaultConfig>::new(1), LWWMap::new(1));
+        let mut gateway = SyncProtocol::new(LWWMap::<u8, u16, DefaultConfig>::new(2));
+
+        node.get_mut().insert(1, 100, 1000).unwrap();
+        node.get_mut().insert(2, 200, 1000).unwrap();
+        let packet = node.prepare_sync_packet();
+        assert_eq!(packet.len(), 2);
+        gateway.apply_sync_packet(&packet).unwrap();
+        node.mark_synced();
+
+        // Only the change since the last sync is sent
+        node.get_mut().insert(2, 250, 2000).unwrap();
+        let packet = node.prepare_sync_packet();
+        assert_eq!(packet.len(), 1);
+        gateway.apply_sync_packet(&packet).unwrap();
+        node.mark_synced();
+        assert_eq!(gateway.get().get(&2), Some(&250));
+
+        // Received packets are not echoed back
+        assert!(gateway.prepare_sync_packet().is_empty());
+        assert!(node.prepare_sync_packet().is_empty());
+    }
+}
@@ -5,13 +5,19 @@
 
 pub mod bounded;
 pub mod crdt;
+pub mod delta;
+pub mod diff;
 pub mod platform;
 pub mod realtime;
 pub mod safety;
+pub mod snapshot;
 
 // Re-export main traits
-pub use bounded::BoundedCRDT;
+pub use bounded::{BoundedCRDT, EvictionPolicy, MemoryBreakdown};
 pub use crdt::CRDT;
+pub use delta::DeltaCRDT;
+pub use diff::{Diffable, SyncProtocol};
 pub use platform::PlatformCRDT;
-pub use realtime::RealTimeCRDT;
-pub use safety::SafetyCRDT;
+pub use realtime::{RealTimeCRDT, compile_time_budget_check};
+pub use safety::{NoSafety, SafetyCRDT, SafetyMergePolicy};
+pub use snapshot::{DirtyCRDT, Snapshotable};
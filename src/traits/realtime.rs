@@ -2,7 +2,7 @@
 //!
 //! This module defines traits for CRDTs that must meet real-time constraints.
 
-use crate::error::CRDTResult;
+use crate::error::{CRDTError, CRDTResult};
 use crate::memory::MemoryConfig;
 use crate::traits::CRDT;
 
@@ -32,6 +32,26 @@ pub trait RealTimeCRDT<C: MemoryConfig>: CRDT<C> {
     /// or return a timeout error.
     fn validate_bounded(&self) -> CRDTResult<()>;
 
+    /// Performs a bounded merge and measures it against MAX_MERGE_CYCLES
+    ///
+    /// `cycle_counter` is read before and after
+    /// [`merge_bounded`](Self::merge_bounded), and wraparound between the two
+    /// reads is handled. Pass `platform::cycles::read_cycle_counter` with the
+    /// `cycle-counter` feature, or any other free-running counter.
+    ///
+    /// The merge is not rolled back when it overruns: the state is merged
+    /// and `CRDTError::TimeBudgetExceeded` reports the overrun.
+    fn timed_merge<F: Fn() -> u32>(&mut self, other: &Self, cycle_counter: F) -> CRDTResult<()> {
+        let start = cycle_counter();
+        self.merge_bounded(other)?;
+        let elapsed = cycle_counter().wrapping_sub(start);
+
+        if elapsed > Self::MAX_MERGE_CYCLES {
+            return Err(CRDTError::TimeBudgetExceeded);
+        }
+        Ok(())
+    }
+
     /// Returns the worst-case execution time for merge in CPU cycles
     fn merge_wcet(&self) -> u32 {
         Self::MAX_MERGE_CYCLES
@@ -59,6 +79,26 @@ pub trait RealTimeCRDT<C: MemoryConfig>: CRDT<C> {
     fn set_budget(&mut self, cycles: u32);
 }
 
+/// Checks a CRDT's merge budget against the platform's at compile time
+///
+/// Fails const evaluation if `T::MAX_MERGE_CYCLES` exceeds
+/// [`MAX_MERGE_CYCLES`](crate::platform::constants::MAX_MERGE_CYCLES) of the
+/// selected platform feature, so evaluate it in a constant.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+/// use crdtosphere::traits::compile_time_budget_check;
+///
+/// const _: () = compile_time_budget_check::<DefaultConfig, GCounter<DefaultConfig>>();
+/// ```
+pub const fn compile_time_budget_check<C: MemoryConfig, T: RealTimeCRDT<C>>() {
+    assert!(
+        T::MAX_MERGE_CYCLES <= crate::platform::constants::MAX_MERGE_CYCLES,
+        "MAX_MERGE_CYCLES exceeds the platform merge budget"
+    );
+}
+
 /// Real-time operation types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RTOperation {
@@ -119,8 +159,8 @@ pub trait PrioritizedCRDT<C: MemoryConfig>: RealTimeCRDT<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::error::CRDTError;
     use crate::memory::DefaultConfig;
+    use core::cell::Cell;
 
     // Mock real-time CRDT for testing
     struct MockRTCRDT {
@@ -197,4 +237,46 @@ mod tests {
         crdt.set_budget(1000);
         assert_eq!(crdt.remaining_budget(), Some(1000));
     }
+
+    #[test]
+    fn test_timed_merge() {
+        let mut crdt = MockRTCRDT {
+            value: 10,
+            budget: None,
+        };
+        let other = MockRTCRDT {
+            value: 20,
+            budget: None,
+        };
+
+        // Each read advances the fake counter by a fixed step
+        let cycles = &Cell::new(u32::MAX - 40);
+        let counter = |step: u32| {
+            move || {
+                let now = cycles.get();
+                cycles.set(now.wrapping_add(step));
+                now
+            }
+        };
+
+        // Wraps past zero but stays within budget
+        assert!(crdt.timed_merge(&other, counter(100)).is_ok());
+        assert_eq!(crdt.value, 20);
+
+        // Overruns, but the merge is still applied
+        let other = MockRTCRDT {
+            value: 30,
+            budget: None,
+        };
+        assert_eq!(
+            crdt.timed_merge(&other, counter(101)),
+            Err(CRDTError::TimeBudgetExceeded)
+        );
+        assert_eq!(crdt.value, 30);
+    }
+
+    #[test]
+    fn test_compile_time_budget_check() {
+        const _: () = compile_time_budget_check::<DefaultConfig, MockRTCRDT>();
+    }
 }
@@ -3,7 +3,7 @@
 //! This module defines traits for CRDTs that must meet safety standards
 //! across multiple domains (automotive, industrial, aerospace).
 
-use crate::error::CRDTResult;
+use crate::error::{CRDTResult, SafetyError};
 use crate::memory::MemoryConfig;
 use crate::traits::CRDT;
 
@@ -46,6 +46,60 @@ pub trait SafetyCRDT<C: MemoryConfig>: CRDT<C> {
 
     /// Returns safety metadata for this CRDT
     fn safety_metadata(&self) -> SafetyMetadata<Self::SafetyLevel>;
+
+    /// Locks the CRDT in its current state after a safety violation
+    ///
+    /// A frozen CRDT rejects merges and updates with
+    /// `CRDTError::InvalidOperation` until [`force_unfreeze`](Self::force_unfreeze)
+    /// is called. The freeze is part of the replicated state: merging a
+    /// frozen replica freezes the receiver too.
+    fn emergency_freeze(&mut self, reason: SafetyError, timestamp: u64) -> CRDTResult<()>;
+
+    /// Returns the reason and time of the freeze, or None if not frozen
+    fn freeze_reason(&self) -> Option<(SafetyError, u64)>;
+
+    /// Checks if the CRDT is frozen
+    fn is_frozen(&self) -> bool {
+        self.freeze_reason().is_some()
+    }
+
+    /// Lifts a freeze, given the authorization token configured for this CRDT
+    ///
+    /// Only the local replica is unfrozen; merging a replica that is still
+    /// frozen freezes it again.
+    fn force_unfreeze(&mut self, authorization_token: u32) -> CRDTResult<()>;
+}
+
+/// Ranking of a safety level hierarchy used when merging
+///
+/// Selected per deployment through
+/// [`MemoryConfig::SafetyConfig`](crate::memory::MemoryConfig::SafetyConfig),
+/// so the "higher safety wins" merge rule works with any standard:
+/// ISO 26262 ASILs, IEC 61508 SILs, DO-178C DALs, IEC 62304 software
+/// classes, or a hierarchy of your own.
+pub trait SafetyMergePolicy: Copy {
+    /// Returns the merge priority of a level (higher wins)
+    fn priority(level: Self) -> u8;
+
+    /// Returns true if the level carries safety requirements
+    fn is_safety_critical(level: Self) -> bool;
+}
+
+/// Safety hierarchy that ranks all levels equally
+///
+/// The default [`MemoryConfig::SafetyConfig`](crate::memory::MemoryConfig::SafetyConfig):
+/// no level takes precedence over another on its account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoSafety;
+
+impl SafetyMergePolicy for NoSafety {
+    fn priority(_level: Self) -> u8 {
+        0
+    }
+
+    fn is_safety_critical(_level: Self) -> bool {
+        false
+    }
 }
 
 /// Safety status information
@@ -248,6 +302,7 @@ mod tests {
         value: u32,
         safety_level: TestSafetyLevel,
         fail_safe_mode: bool,
+        frozen: Option<(SafetyError, u64)>,
     }
 
     impl MockSafetyCRDT {
@@ -256,6 +311,7 @@ mod tests {
                 value: 0,
                 safety_level,
                 fail_safe_mode: false,
+                frozen: None,
             }
         }
     }
@@ -348,6 +404,23 @@ mod tests {
                 check_interval: 1000,
             }
         }
+
+        fn emergency_freeze(&mut self, reason: SafetyError, timestamp: u64) -> CRDTResult<()> {
+            self.frozen.get_or_insert((reason, timestamp));
+            Ok(())
+        }
+
+        fn freeze_reason(&self) -> Option<(SafetyError, u64)> {
+            self.frozen
+        }
+
+        fn force_unfreeze(&mut self, authorization_token: u32) -> CRDTResult<()> {
+            if authorization_token != 42 {
+                return Err(CRDTError::SafetyViolation);
+            }
+            self.frozen = None;
+            Ok(())
+        }
     }
 
     impl FailSafeCRDT<DefaultConfig> for MockSafetyCRDT {
@@ -400,6 +473,28 @@ mod tests {
 
         let status = crdt1.safety_check().unwrap();
         assert!(status.is_safe);
+
+        // is_frozen defaults to checking the freeze reason
+        assert!(!crdt1.is_frozen());
+        crdt1
+            .emergency_freeze(SafetyError::SafetyBarrierBreach, 1000)
+            .unwrap();
+        assert!(crdt1.is_frozen());
+        assert!(crdt1.force_unfreeze(0).is_err());
+        crdt1.force_unfreeze(42).unwrap();
+        assert!(!crdt1.is_frozen());
+    }
+
+    #[test]
+    fn test_no_safety_policy() {
+        assert_eq!(NoSafety::priority(NoSafety), 0);
+        assert!(!NoSafety::is_safety_critical(NoSafety));
+        assert_eq!(
+            <<DefaultConfig as MemoryConfig>::SafetyConfig as SafetyMergePolicy>::priority(
+                NoSafety
+            ),
+            0
+        );
     }
 
     #[test]
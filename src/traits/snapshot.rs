@@ -0,0 +1,174 @@
+//! Snapshot trait definition
+//!
+//! This module defines the Snapshotable trait for CRDTs that can capture a
+//! small fingerprint of their logical state, and the DirtyCRDT wrapper that
+//! uses it to tell whether a CRDT changed since it was last transmitted.
+
+use crate::clock::CompactTimestamp;
+use crate::memory::{MemoryConfig, NodeId};
+use crate::traits::CRDT;
+
+/// Trait for CRDTs that can capture a cheap point-in-time snapshot
+///
+/// A snapshot is a small `Copy` value derived from the CRDT's logical state.
+/// Comparing a fresh snapshot with an older one answers "has anything changed?"
+/// without serializing or cloning the full state. Snapshots are only meant to
+/// be compared with snapshots of the same replica.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut counter = GCounter::<DefaultConfig>::new(1);
+/// let snap = counter.snapshot();
+/// assert!(!counter.has_changed_since(&snap));
+///
+/// counter.increment(1)?;
+/// assert!(counter.has_changed_since(&snap));
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+pub trait Snapshotable<C: MemoryConfig>: CRDT<C> {
+    /// The fingerprint type captured by [`snapshot`](Self::snapshot)
+    type Snapshot: PartialEq + Copy;
+
+    /// Captures the current logical state
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Checks if the logical state differs from an earlier snapshot
+    fn has_changed_since(&self, snap: &Self::Snapshot) -> bool {
+        self.snapshot() != *snap
+    }
+}
+
+/// Wrapper that tracks whether a CRDT changed since it was marked clean
+///
+/// The wrapper remembers the snapshot taken at the last
+/// [`mark_clean`](Self::mark_clean), so periodic transmission can skip CRDTs
+/// that have nothing new to send.
+///
+/// # Example
+/// ```rust
+/// use crdtosphere::prelude::*;
+///
+/// let mut speed = DirtyCRDT::new(LWWRegister::<u16, DefaultConfig>::new(1));
+/// assert!(!speed.is_dirty());
+///
+/// speed.get_mut().set(80, 1000)?;
+/// assert!(speed.is_dirty());
+///
+/// // After transmitting the value
+/// speed.mark_clean();
+/// assert!(!speed.is_dirty());
+/// # Ok::<(), crdtosphere::error::CRDTError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirtyCRDT<T: Snapshotable<C>, C: MemoryConfig> {
+    /// The wrapped CRDT
+    inner: T,
+
+    /// Snapshot taken when the CRDT was last marked clean
+    clean: T::Snapshot,
+
+    /// Phantom data to maintain the memory config type
+    _phantom: core::marker::PhantomData<C>,
+}
+
+impl<T: Snapshotable<C>, C: MemoryConfig> DirtyCRDT<T, C> {
+    /// Wraps a CRDT, treating its current state as clean
+    pub fn new(inner: T) -> Self {
+        let clean = inner.snapshot();
+        Self {
+            inner,
+            clean,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns true if the CRDT changed since it was last marked clean
+    pub fn is_dirty(&self) -> bool {
+        self.inner.has_changed_since(&self.clean)
+    }
+
+    /// Records the current state as clean
+    pub fn mark_clean(&mut self) {
+        self.clean = self.inner.snapshot();
+    }
+
+    /// Returns a reference to the wrapped CRDT
+    pub fn get(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped CRDT
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwraps the CRDT
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// Hashes one timestamped entry for XOR-combined snapshots
+///
+/// The slot index keeps entries that share a timestamp and node from
+/// cancelling each other out.
+pub(crate) fn stamp_hash(slot: usize, timestamp: CompactTimestamp, node_id: NodeId) -> u64 {
+    let mut hash = timestamp.as_u64().wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (((slot as u64) << 8) | node_id as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+
+    // SplitMix64 finalizer
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::counters::GCounter;
+    use crate::maps::LWWMap;
+    use crate::memory::DefaultConfig;
+
+    #[test]
+    fn test_dirty_tracking() {
+        let mut counter = DirtyCRDT::new(GCounter::<DefaultConfig>::new(1));
+        assert!(!counter.is_dirty());
+
+        counter.get_mut().increment(3).unwrap();
+        assert!(counter.is_dirty());
+
+        counter.mark_clean();
+        assert!(!counter.is_dirty());
+        assert_eq!(counter.into_inner().value(), 3);
+    }
+
+    #[test]
+    fn test_merge_without_news_stays_clean() {
+        let mut local = LWWMap::<u8, u16, DefaultConfig>::new(1);
+        local.insert(1, 10, 1000).unwrap();
+        let mut remote = local.clone();
+
+        let mut tracked = DirtyCRDT::new(local);
+        tracked.get_mut().merge(&remote).unwrap();
+        assert!(!tracked.is_dirty());
+
+        remote.insert(1, 20, 2000).unwrap();
+        tracked.get_mut().merge(&remote).unwrap();
+        assert!(tracked.is_dirty());
+    }
+
+    #[test]
+    fn test_stamp_hash_distinguishes_slots() {
+        let ts = CompactTimestamp::new(1000);
+        assert_ne!(stamp_hash(0, ts, 1), stamp_hash(1, ts, 1));
+        assert_ne!(stamp_hash(0, ts, 1), stamp_hash(0, ts, 2));
+        assert_ne!(
+            stamp_hash(0, ts, 1),
+            stamp_hash(0, CompactTimestamp::new(1001), 1)
+        );
+    }
+}
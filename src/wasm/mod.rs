@@ -0,0 +1,285 @@
+//! WebAssembly bindings
+//!
+//! Exposes [`GCounter`], [`LWWRegister<f64>`](LWWRegister) and
+//! [`ORSet<u32>`](ORSet) to JavaScript through `wasm-bindgen`, all with
+//! [`DefaultConfig`]. The main use is a browser dashboard mirroring CRDTs
+//! that live on embedded devices: the device encodes its state with postcard
+//! (see [`serialization::postcard`](crate::serialization::postcard)), sends
+//! the bytes over e.g. a WebSocket, and the page calls `merge_from_bytes`.
+//!
+//! Only this module needs `alloc` (for the byte vectors handed to
+//! JavaScript); the CRDT types themselves stay `no_std`.
+//!
+//! Counts and timestamps cross the boundary as JavaScript numbers, so they
+//! are exact up to 2^53. Errors are thrown as `Error` objects carrying the
+//! [`CRDTError`] variant name.
+//!
+//! # Example
+//! ```javascript
+//! import { WasmGCounter } from "crdtosphere";
+//!
+//! const view = new WasmGCounter(0);
+//! socket.onmessage = (event) => {
+//!     view.merge_from_bytes(new Uint8Array(event.data));
+//!     render(view.value());
+//! };
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::counters::GCounter;
+use crate::error::{CRDTError, CRDTResult};
+use crate::memory::{DefaultConfig, MemoryConfig, NodeId};
+use crate::registers::LWWRegister;
+use crate::serialization::postcard::MaxSize;
+use crate::serialization::{deserialize_postcard, serialize_postcard};
+use crate::sets::ORSet;
+use crate::traits::CRDT;
+
+type CounterState = GCounter<DefaultConfig>;
+type RegisterState = LWWRegister<f64, DefaultConfig>;
+type SetState = ORSet<u32, DefaultConfig>;
+
+/// Grow-only counter for JavaScript
+#[wasm_bindgen]
+pub struct WasmGCounter(CounterState);
+
+#[wasm_bindgen]
+impl WasmGCounter {
+    /// Creates a counter at zero
+    ///
+    /// Throws if `node_id` is not below `MAX_NODES`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(node_id: NodeId) -> Result<WasmGCounter, JsError> {
+        check_node_id(node_id).map_err(js_error)?;
+        Ok(Self(CounterState::new(node_id)))
+    }
+
+    /// Increments this node's count
+    pub fn increment(&mut self, amount: u32) -> Result<(), JsError> {
+        self.0.increment(amount).map_err(js_error)
+    }
+
+    /// Returns the total count across all nodes
+    pub fn value(&self) -> f64 {
+        self.0.value() as f64
+    }
+
+    /// Merges a postcard-encoded `GCounter<DefaultConfig>`
+    pub fn merge_from_bytes(&mut self, bytes: &[u8]) -> Result<(), JsError> {
+        merge_from_bytes(&mut self.0, bytes).map_err(js_error)
+    }
+
+    /// Encodes the counter with postcard
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = [0u8; CounterState::POSTCARD_MAX_SIZE];
+        to_bytes(&self.0, &mut buf)
+    }
+}
+
+/// Last-writer-wins register of a number for JavaScript
+#[wasm_bindgen]
+pub struct WasmLWWRegister(RegisterState);
+
+#[wasm_bindgen]
+impl WasmLWWRegister {
+    /// Creates an empty register
+    ///
+    /// Throws if `node_id` is not below `MAX_NODES`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(node_id: NodeId) -> Result<WasmLWWRegister, JsError> {
+        check_node_id(node_id).map_err(js_error)?;
+        Ok(Self(RegisterState::new(node_id)))
+    }
+
+    /// Sets the value if `timestamp` (e.g. `Date.now()`) is newer
+    pub fn set(&mut self, value: f64, timestamp: f64) -> Result<(), JsError> {
+        let timestamp = to_timestamp(timestamp).map_err(js_error)?;
+        self.0.set(value, timestamp).map_err(js_error)
+    }
+
+    /// Returns the current value, or `undefined` if never set
+    pub fn get(&self) -> Option<f64> {
+        self.0.get().copied()
+    }
+
+    /// Merges a postcard-encoded `LWWRegister<f64, DefaultConfig>`
+    pub fn merge_from_bytes(&mut self, bytes: &[u8]) -> Result<(), JsError> {
+        merge_from_bytes(&mut self.0, bytes).map_err(js_error)
+    }
+
+    /// Encodes the register with postcard
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = [0u8; RegisterState::POSTCARD_MAX_SIZE];
+        to_bytes(&self.0, &mut buf)
+    }
+}
+
+/// Observed-remove set of numbers for JavaScript
+#[wasm_bindgen]
+pub struct WasmORSet(SetState);
+
+#[wasm_bindgen]
+impl WasmORSet {
+    /// Creates an empty set
+    ///
+    /// Throws if `node_id` is not below `MAX_NODES`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(node_id: NodeId) -> Result<WasmORSet, JsError> {
+        check_node_id(node_id).map_err(js_error)?;
+        Ok(Self(SetState::new(node_id)))
+    }
+
+    /// Adds an element, returning true if it was not present
+    pub fn add(&mut self, element: u32, timestamp: f64) -> Result<bool, JsError> {
+        let timestamp = to_timestamp(timestamp).map_err(js_error)?;
+        self.0.add(element, timestamp).map_err(js_error)
+    }
+
+    /// Removes an element, returning true if it was present
+    pub fn remove(&mut self, element: u32, timestamp: f64) -> Result<bool, JsError> {
+        let timestamp = to_timestamp(timestamp).map_err(js_error)?;
+        self.0.remove(&element, timestamp).map_err(js_error)
+    }
+
+    /// Checks if the set contains an element
+    pub fn contains(&self, element: u32) -> bool {
+        self.0.contains(&element)
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the elements as a `Uint32Array`
+    pub fn values(&self) -> Vec<u32> {
+        self.0.iter().copied().collect()
+    }
+
+    /// Merges a postcard-encoded `ORSet<u32, DefaultConfig>`
+    pub fn merge_from_bytes(&mut self, bytes: &[u8]) -> Result<(), JsError> {
+        merge_from_bytes(&mut self.0, bytes).map_err(js_error)
+    }
+
+    /// Encodes the set with postcard
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = [0u8; SetState::POSTCARD_MAX_SIZE];
+        to_bytes(&self.0, &mut buf)
+    }
+}
+
+/// Decodes a peer state and merges it into `crdt`
+fn merge_from_bytes<X>(crdt: &mut X, bytes: &[u8]) -> CRDTResult<()>
+where
+    X: CRDT<DefaultConfig, Error = CRDTError> + serde::de::DeserializeOwned,
+{
+    let other: X = deserialize_postcard(bytes)?;
+    other.validate()?;
+    crdt.merge(&other)
+}
+
+/// Encodes `crdt` into `buf`, which must hold its `POSTCARD_MAX_SIZE`
+fn to_bytes<X: serde::Serialize>(crdt: &X, buf: &mut [u8]) -> Vec<u8> {
+    serialize_postcard(crdt, buf)
+        .expect("POSTCARD_MAX_SIZE bounds the encoding")
+        .to_vec()
+}
+
+/// Rejects node IDs the CRDTs cannot track
+fn check_node_id(node_id: NodeId) -> CRDTResult<()> {
+    if (node_id as usize) < DefaultConfig::MAX_NODES {
+        Ok(())
+    } else {
+        Err(CRDTError::InvalidNodeId)
+    }
+}
+
+/// Converts a JavaScript millisecond timestamp
+fn to_timestamp(ms: f64) -> CRDTResult<u64> {
+    if ms.is_finite() && ms >= 0.0 {
+        Ok(ms as u64)
+    } else {
+        Err(CRDTError::InvalidOperation)
+    }
+}
+
+/// Wraps an error for JavaScript
+fn js_error(error: CRDTError) -> JsError {
+    JsError::new(&format!("{:?}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Error paths create JavaScript objects, so they only run under
+    // `wasm-pack test`; the rest also runs natively
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[cfg(target_arch = "wasm32")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_gcounter_from_device_bytes() {
+        // State as encoded on the device
+        let mut device = CounterState::new(3);
+        device.increment(40).unwrap();
+        let mut buf = [0u8; CounterState::POSTCARD_MAX_SIZE];
+        let bytes = serialize_postcard(&device, &mut buf).unwrap();
+
+        let mut view = WasmGCounter::new(0).unwrap();
+        view.increment(2).unwrap();
+        view.merge_from_bytes(bytes).unwrap();
+        assert_eq!(view.value(), 42.0);
+
+        // Round trip through the browser's own encoding
+        let mut copy = WasmGCounter::new(1).unwrap();
+        copy.merge_from_bytes(&view.to_bytes()).unwrap();
+        assert_eq!(copy.value(), 42.0);
+    }
+
+    #[test]
+    fn test_register_and_set() {
+        let mut device = RegisterState::new(2);
+        device.set(21.5, 2000).unwrap();
+        let mut buf = [0u8; RegisterState::POSTCARD_MAX_SIZE];
+        let bytes = serialize_postcard(&device, &mut buf).unwrap();
+
+        let mut view = WasmLWWRegister::new(0).unwrap();
+        assert_eq!(view.get(), None);
+        view.set(19.0, 1000.0).unwrap();
+        view.merge_from_bytes(bytes).unwrap();
+        assert_eq!(view.get(), Some(21.5));
+
+        let mut devices = WasmORSet::new(0).unwrap();
+        assert!(devices.add(7, 1000.0).unwrap());
+        assert!(devices.add(9, 1000.0).unwrap());
+        assert!(devices.remove(7, 1500.0).unwrap());
+        let mut other = WasmORSet::new(1).unwrap();
+        other.merge_from_bytes(&devices.to_bytes()).unwrap();
+        assert!(other.contains(9));
+        assert!(!other.contains(7));
+        assert_eq!(other.len(), 1);
+        assert_eq!(other.values(), [9]);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[test]
+    fn test_errors() {
+        assert!(WasmGCounter::new(DefaultConfig::MAX_NODES as NodeId).is_err());
+        let mut view = WasmGCounter::new(0).unwrap();
+        assert!(view.merge_from_bytes(&[0xff; 4]).is_err());
+        let mut register = WasmLWWRegister::new(0).unwrap();
+        assert!(register.set(1.0, f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,15 @@
+use crdtosphere::memory::define_memory_config;
+
+// Alignments must be powers of two
+define_memory_config! {
+    name: MisalignedConfig,
+    total_memory: 64 * 1024,
+    max_registers: 10,
+    max_counters: 10,
+    max_sets: 10,
+    max_maps: 10,
+    max_nodes: 8,
+    memory_alignment: 6,
+}
+
+fn main() {}
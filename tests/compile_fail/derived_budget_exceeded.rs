@@ -0,0 +1,10 @@
+use crdtosphere::memory::{DefaultConfig, define_memory_config};
+
+// Shrinking the budget leaves the inherited limits without room
+define_memory_config! {
+    name: ShrunkConfig,
+    extends: DefaultConfig,
+    total_memory: 4 * 1024,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use crdtosphere::memory::define_memory_config;
+
+// 10000 registers can never fit in 2KB
+define_memory_config! {
+    name: OverBudgetConfig,
+    total_memory: 2048,
+    max_registers: 10000,
+    max_counters: 1,
+    max_sets: 1,
+    max_maps: 1,
+    max_nodes: 8,
+}
+
+fn main() {}
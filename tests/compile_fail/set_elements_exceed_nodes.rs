@@ -0,0 +1,15 @@
+use crdtosphere::memory::define_memory_config;
+
+// 64 set elements for 2 nodes is more than eight per node
+define_memory_config! {
+    name: CrowdedSetsConfig,
+    total_memory: 64 * 1024,
+    max_registers: 10,
+    max_counters: 10,
+    max_sets: 10,
+    max_maps: 10,
+    max_nodes: 2,
+    max_set_elements: 64,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use crdtosphere::memory::define_memory_config;
+
+// Node-indexed arrays need at least one node
+define_memory_config! {
+    name: NoNodesConfig,
+    total_memory: 64 * 1024,
+    max_registers: 10,
+    max_counters: 10,
+    max_sets: 10,
+    max_maps: 10,
+    max_nodes: 0,
+}
+
+fn main() {}
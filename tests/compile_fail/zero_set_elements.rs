@@ -0,0 +1,15 @@
+use crdtosphere::memory::define_memory_config;
+
+// Sets must be able to hold at least one element
+define_memory_config! {
+    name: EmptySetsConfig,
+    total_memory: 64 * 1024,
+    max_registers: 10,
+    max_counters: 10,
+    max_sets: 10,
+    max_maps: 10,
+    max_nodes: 8,
+    max_set_elements: 0,
+}
+
+fn main() {}
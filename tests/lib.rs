@@ -59,11 +59,19 @@ pub fn node_id_strategy() -> impl Strategy<Value = u8> {
     #[cfg(feature = "riscv")]
     let max_nodes = 8u8; // RISC-V: reasonable limit for testing
 
+    #[cfg(feature = "esp32")]
+    let max_nodes = 16u8; // ESP32: dual-core IoT limit
+
+    #[cfg(feature = "nrf52")]
+    let max_nodes = 8u8; // nRF52: BLE connection limit
+
     #[cfg(not(any(
         feature = "aurix",
         feature = "stm32",
         feature = "cortex-m",
-        feature = "riscv"
+        feature = "riscv",
+        feature = "esp32",
+        feature = "nrf52"
     )))]
     let max_nodes = 16u8; // Default: full array size for generic platforms
 
@@ -212,6 +220,41 @@ where
     value_fn(after) >= value_fn(before)
 }
 
+/// Helper function to verify monotonicity across a merge
+/// For grow-only structures: value(merge(a, b)) >= max(value(a), value(b))
+pub fn assert_monotone_after_merge<T, V>(a: &T, b: &T, value_fn: fn(&T) -> V) -> bool
+where
+    T: CRDT<DefaultConfig> + Clone,
+    V: PartialOrd,
+{
+    let mut merged = a.clone();
+    if merged.merge(b).is_err() {
+        // Capacity limits prevent the merge, nothing to compare
+        return true;
+    }
+
+    value_fn(&merged) >= value_fn(a) && value_fn(&merged) >= value_fn(b)
+}
+
+/// Helper function to verify that a merge keeps the newest write
+/// For LWW structures: max_timestamp(merge(a, b)) = max(max_timestamp(a), max_timestamp(b))
+pub fn assert_no_data_loss_after_merge<T>(
+    a: &T,
+    b: &T,
+    max_timestamp_fn: fn(&T) -> Option<u64>,
+) -> bool
+where
+    T: CRDT<DefaultConfig> + Clone,
+{
+    let mut merged = a.clone();
+    if merged.merge(b).is_err() {
+        // Capacity limits prevent the merge, nothing to compare
+        return true;
+    }
+
+    max_timestamp_fn(&merged) == max_timestamp_fn(a).max(max_timestamp_fn(b))
+}
+
 /// Helper function to verify memory bounds are respected
 pub fn assert_memory_bounds<T>(crdt: &T, max_bytes: usize) -> bool
 where
@@ -0,0 +1,267 @@
+//! CRDT law matrix for the core types
+//!
+//! Every core CRDT is checked against the same three merge laws, generated
+//! from one table so that no type/law pair is missed:
+//! - Commutativity: merge(a, b) = merge(b, a)
+//! - Associativity: merge(merge(a, b), c) = merge(a, merge(b, c))
+//! - Idempotence: merge(a, a) = a
+//!
+//! Associativity needs three replicas and is only run with the `slow-tests`
+//! feature. On top of the laws, grow-only types are checked for monotone
+//! merges and LWW types for keeping the newest write.
+
+#![allow(unused_mut)]
+#![allow(special_module_name)]
+
+use crdtosphere::counters::{GCounter, PNCounter};
+use crdtosphere::maps::LWWMap;
+use crdtosphere::prelude::*;
+use crdtosphere::registers::{LWWRegister, MVRegister};
+use crdtosphere::sets::{GSet, ORSet};
+use proptest::prelude::*;
+
+mod lib;
+use lib::*;
+
+/// Generate timestamps for LWW-style operations
+fn timestamp_strategy() -> impl Strategy<Value = u64> {
+    1u64..10_000
+}
+
+/// Generate counter operations: (is_increment, amount)
+fn pncounter_ops_strategy() -> impl Strategy<Value = Vec<(bool, u32)>> {
+    prop::collection::vec((any::<bool>(), small_increment_strategy()), 0..20)
+}
+
+/// Generate register writes: (value, timestamp)
+fn register_ops_strategy() -> impl Strategy<Value = Vec<(u32, u64)>> {
+    prop::collection::vec((0u32..100, timestamp_strategy()), 0..10)
+}
+
+/// Generate GSet elements; the small domain keeps three-way unions in capacity
+fn gset_ops_strategy() -> impl Strategy<Value = Vec<u32>> {
+    prop::collection::vec(0u32..8, 0..8)
+}
+
+/// Generate ORSet operations: (is_add, element, timestamp)
+///
+/// Each replica stores one entry per element it adds, so replicas are kept
+/// short enough for three of them to fit in the default capacity.
+fn orset_ops_strategy() -> impl Strategy<Value = Vec<(bool, u32, u64)>> {
+    prop::collection::vec((any::<bool>(), 0u32..4, timestamp_strategy()), 0..3)
+}
+
+/// Generate map writes: (key, value, timestamp)
+fn lwwmap_ops_strategy() -> impl Strategy<Value = Vec<(u8, u32, u64)>> {
+    prop::collection::vec((0u8..6, 0u32..100, timestamp_strategy()), 0..8)
+}
+
+fn build_gcounter(node: u8, ops: Vec<u32>) -> GCounter<DefaultConfig> {
+    let mut counter = GCounter::new(node);
+    for amount in ops {
+        let _ = counter.increment(amount);
+    }
+    counter
+}
+
+fn build_pncounter(node: u8, ops: Vec<(bool, u32)>) -> PNCounter<DefaultConfig> {
+    let mut counter = PNCounter::new(node);
+    for (is_increment, amount) in ops {
+        let _ = if is_increment {
+            counter.increment(amount)
+        } else {
+            counter.decrement(amount)
+        };
+    }
+    counter
+}
+
+fn build_lww_register(node: u8, ops: Vec<(u32, u64)>) -> LWWRegister<u32, DefaultConfig> {
+    let mut register = LWWRegister::new(node);
+    for (value, timestamp) in ops {
+        let _ = register.set(value, timestamp);
+    }
+    register
+}
+
+fn build_mv_register(node: u8, ops: Vec<(u32, u64)>) -> MVRegister<u32, DefaultConfig> {
+    let mut register = MVRegister::new(node);
+    for (value, timestamp) in ops {
+        let _ = register.set(value, timestamp);
+    }
+    register
+}
+
+fn build_gset(_node: u8, ops: Vec<u32>) -> GSet<u32, DefaultConfig> {
+    let mut set = GSet::new();
+    for element in ops {
+        let _ = set.insert(element);
+    }
+    set
+}
+
+fn build_orset(node: u8, ops: Vec<(bool, u32, u64)>) -> ORSet<u32, DefaultConfig> {
+    let mut set = ORSet::new(node);
+    for (is_add, element, timestamp) in ops {
+        let _ = if is_add {
+            set.add(element, timestamp)
+        } else {
+            set.remove(&element, timestamp)
+        };
+    }
+    set
+}
+
+fn build_lwwmap(node: u8, ops: Vec<(u8, u32, u64)>) -> LWWMap<u8, u32, DefaultConfig> {
+    let mut map = LWWMap::new(node);
+    for (key, value, timestamp) in ops {
+        let _ = map.insert(key, value, timestamp);
+    }
+    map
+}
+
+/// Generates a module per CRDT type with one test per merge law
+///
+/// Replicas always get distinct node IDs, as two replicas sharing an ID are
+/// the same replica and may legitimately diverge.
+macro_rules! crdt_law_matrix {
+    ($($module:ident: $build:ident($ops:expr);)*) => {
+        $(
+            mod $module {
+                use super::*;
+
+                proptest! {
+                    #![proptest_config(crdt_config())]
+
+                    #[test]
+                    fn commutativity(
+                        node1 in node_id_strategy(),
+                        node2 in node_id_strategy(),
+                        ops1 in $ops,
+                        ops2 in $ops,
+                    ) {
+                        prop_assume!(node1 != node2);
+                        let a = $build(node1, ops1);
+                        let b = $build(node2, ops2);
+                        prop_assert!(assert_crdt_commutativity(&a, &b));
+                    }
+
+                    #[cfg(feature = "slow-tests")]
+                    #[test]
+                    fn associativity(
+                        node1 in node_id_strategy(),
+                        node2 in node_id_strategy(),
+                        node3 in node_id_strategy(),
+                        ops1 in $ops,
+                        ops2 in $ops,
+                        ops3 in $ops,
+                    ) {
+                        prop_assume!(node1 != node2 && node2 != node3 && node1 != node3);
+                        let a = $build(node1, ops1);
+                        let b = $build(node2, ops2);
+                        let c = $build(node3, ops3);
+                        prop_assert!(assert_crdt_associativity(&a, &b, &c));
+                    }
+
+                    #[test]
+                    fn idempotence(node in node_id_strategy(), ops in $ops) {
+                        let a = $build(node, ops);
+                        prop_assert!(assert_crdt_idempotence(&a));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+crdt_law_matrix! {
+    gcounter_laws: build_gcounter(operation_sequence_strategy(small_increment_strategy()));
+    pncounter_laws: build_pncounter(pncounter_ops_strategy());
+    lww_register_laws: build_lww_register(register_ops_strategy());
+    mv_register_laws: build_mv_register(register_ops_strategy());
+    gset_laws: build_gset(gset_ops_strategy());
+    orset_laws: build_orset(orset_ops_strategy());
+    lwwmap_laws: build_lwwmap(lwwmap_ops_strategy());
+}
+
+proptest! {
+    #![proptest_config(crdt_config())]
+
+    /// Property: merging never lowers a GCounter's value
+    #[test]
+    fn gcounter_is_monotone_after_merge(
+        node1 in node_id_strategy(),
+        node2 in node_id_strategy(),
+        ops1 in operation_sequence_strategy(small_increment_strategy()),
+        ops2 in operation_sequence_strategy(small_increment_strategy()),
+    ) {
+        prop_assume!(node1 != node2);
+        let a = build_gcounter(node1, ops1);
+        let b = build_gcounter(node2, ops2);
+        prop_assert!(assert_monotone_after_merge(&a, &b, |c| c.value()));
+    }
+
+    /// Property: merging never lowers either half of a PNCounter
+    ///
+    /// The value itself may drop when the other replica decremented.
+    #[test]
+    fn pncounter_is_monotone_after_merge(
+        node1 in node_id_strategy(),
+        node2 in node_id_strategy(),
+        ops1 in pncounter_ops_strategy(),
+        ops2 in pncounter_ops_strategy(),
+    ) {
+        prop_assume!(node1 != node2);
+        let a = build_pncounter(node1, ops1);
+        let b = build_pncounter(node2, ops2);
+        let increments = assert_monotone_after_merge(&a, &b, |c| {
+            c.positive_counters().iter().sum::<u64>()
+        });
+        let decrements = assert_monotone_after_merge(&a, &b, |c| {
+            c.negative_counters().iter().sum::<u64>()
+        });
+        prop_assert!(increments && decrements);
+    }
+
+    /// Property: merging never shrinks a GSet
+    #[test]
+    fn gset_is_monotone_after_merge(ops1 in gset_ops_strategy(), ops2 in gset_ops_strategy()) {
+        let a = build_gset(0, ops1);
+        let b = build_gset(0, ops2);
+        prop_assert!(assert_monotone_after_merge(&a, &b, |s| s.len()));
+    }
+
+    /// Property: the newest register write survives a merge
+    #[test]
+    fn lww_register_has_no_data_loss_after_merge(
+        node1 in node_id_strategy(),
+        node2 in node_id_strategy(),
+        ops1 in register_ops_strategy(),
+        ops2 in register_ops_strategy(),
+    ) {
+        prop_assume!(node1 != node2);
+        let a = build_lww_register(node1, ops1);
+        let b = build_lww_register(node2, ops2);
+        let kept_newest = assert_no_data_loss_after_merge(&a, &b, |r| {
+            if r.is_empty() { None } else { Some(r.timestamp().as_u64()) }
+        });
+        prop_assert!(kept_newest);
+    }
+
+    /// Property: the newest map write survives a merge
+    #[test]
+    fn lwwmap_has_no_data_loss_after_merge(
+        node1 in node_id_strategy(),
+        node2 in node_id_strategy(),
+        ops1 in lwwmap_ops_strategy(),
+        ops2 in lwwmap_ops_strategy(),
+    ) {
+        prop_assume!(node1 != node2);
+        let a = build_lwwmap(node1, ops1);
+        let b = build_lwwmap(node2, ops2);
+        let kept_newest = assert_no_data_loss_after_merge(&a, &b, |m| {
+            m.keys().filter_map(|k| m.get_timestamp(k)).map(|ts| ts.as_u64()).max()
+        });
+        prop_assert!(kept_newest);
+    }
+}
@@ -300,6 +300,46 @@ proptest! {
             }
         }
     }
+
+    /// Test De Morgan's laws using difference from a universe as the complement
+    /// Property: U \ (A ∪ B) = (U \ A) ∩ (U \ B) and U \ (A ∩ B) = (U \ A) ∪ (U \ B)
+    #[test]
+    fn gset_set_algebra_obeys_de_morgan(
+        elements1 in prop::collection::vec(0u32..16, 0..16),
+        elements2 in prop::collection::vec(0u32..16, 0..16),
+    ) {
+        let mut universe = GSet::<u32, DefaultConfig>::new();
+        for element in 0..16 {
+            universe.insert(element).unwrap();
+        }
+
+        let mut a = GSet::<u32, DefaultConfig>::new();
+        let mut b = GSet::<u32, DefaultConfig, 16>::with_capacity();
+        for element in elements1 {
+            a.insert(element).unwrap();
+        }
+        for element in elements2 {
+            b.insert(element).unwrap();
+        }
+
+        let not_a: GSet<u32, DefaultConfig> = universe.difference(&a).unwrap();
+        let not_b: GSet<u32, DefaultConfig> = universe.difference(&b).unwrap();
+
+        let a_or_b: GSet<u32, DefaultConfig> = a.union(&b).unwrap();
+        let not_a_or_b: GSet<u32, DefaultConfig> = universe.difference(&a_or_b).unwrap();
+        let not_a_and_not_b: GSet<u32, DefaultConfig> = not_a.intersection(&not_b).unwrap();
+        prop_assert!(not_a_or_b.eq(&not_a_and_not_b));
+
+        let a_and_b: GSet<u32, DefaultConfig> = a.intersection(&b).unwrap();
+        let not_a_and_b: GSet<u32, DefaultConfig> = universe.difference(&a_and_b).unwrap();
+        let not_a_or_not_b: GSet<u32, DefaultConfig> = not_a.union(&not_b).unwrap();
+        prop_assert!(not_a_and_b.eq(&not_a_or_not_b));
+
+        // The operations never modify their inputs
+        prop_assert!(a.is_subset_of(&a_or_b));
+        prop_assert!(a_or_b.is_superset_of(&b));
+        prop_assert!(a_and_b.is_subset_of(&a) && a_and_b.is_subset_of(&b));
+    }
 }
 
 #[cfg(test)]
@@ -318,6 +318,7 @@ proptest! {
 
     /// Test register with different value types
     /// Property: LWWRegister should work with different data types
+    #[cfg(feature = "alloc")]
     #[test]
     fn lww_register_different_types(
         node in node_id_strategy(),
@@ -402,6 +403,60 @@ mod unit_tests {
         assert_eq!(register1.timestamp().as_u64(), 10);
     }
 
+    #[test]
+    fn test_lww_register_hlc_rounds_advance_monotonically() {
+        let mut hlc_a = HybridLogicalClock::new(1000);
+        let mut hlc_b = HybridLogicalClock::new(1000);
+        let mut register_a = LWWRegister::<u32, DefaultConfig>::new(1);
+        let mut register_b = LWWRegister::<u32, DefaultConfig>::new(2);
+        let mut last_a = hlc_a.now();
+        let mut last_b = hlc_b.now();
+
+        for round in 0..10u32 {
+            // Node A's physical clock runs behind and steps backwards
+            let physical_a = 1000 + (round as u64 % 3) * 5;
+            let physical_b = 1000 + round as u64 * 20;
+
+            register_a
+                .set_with_hlc(round * 2, &mut hlc_a, physical_a)
+                .unwrap();
+            assert!(hlc_a.now() > last_a);
+            last_a = hlc_a.now();
+
+            register_b
+                .merge_with_hlc(&register_a, &mut hlc_b, physical_b)
+                .unwrap();
+            assert!(hlc_b.now() > last_b);
+            assert!(hlc_b.now() > last_a);
+            last_b = hlc_b.now();
+
+            register_b
+                .set_with_hlc(round * 2 + 1, &mut hlc_b, physical_b)
+                .unwrap();
+            assert!(hlc_b.now() > last_b);
+            last_b = hlc_b.now();
+
+            register_a
+                .merge_with_hlc(&register_b, &mut hlc_a, physical_a)
+                .unwrap();
+            assert!(hlc_a.now() > last_a);
+            assert!(hlc_a.now() > last_b);
+            last_a = hlc_a.now();
+
+            // Every write wins over the one it observed
+            assert_eq!(register_a.get(), Some(&(round * 2 + 1)));
+            assert!(register_a.eq(&register_b));
+        }
+
+        // Merging an older value still advances the clock
+        let stale = register_a.clone();
+        register_a.set_with_hlc(99, &mut hlc_a, 1000).unwrap();
+        let before = hlc_a.now();
+        register_a.merge_with_hlc(&stale, &mut hlc_a, 1000).unwrap();
+        assert!(hlc_a.now() > before);
+        assert_eq!(register_a.get(), Some(&99));
+    }
+
     #[test]
     fn test_lww_register_empty_state() {
         let register = LWWRegister::<u32, DefaultConfig>::new(1);
@@ -509,6 +509,7 @@ proptest! {
     /// Test LWWMap remove operation
     /// Property: remove operations work correctly and free capacity
     #[test]
+    #[allow(deprecated)]
     fn lwwmap_remove_basic(
         node in node_id_strategy(),
         operations in prop::collection::vec((test_key_strategy(), test_value_strategy(), timestamp_strategy()), 1..8),
@@ -544,6 +545,7 @@ proptest! {
     /// Test LWWMap remove and reinsert
     /// Property: removing and reinserting keys works correctly
     #[test]
+    #[allow(deprecated)]
     fn lwwmap_remove_and_reinsert(
         node in node_id_strategy(),
         key in test_key_strategy(),
@@ -575,6 +577,7 @@ proptest! {
     /// Test LWWMap remove capacity management
     /// Property: removing entries frees up capacity for new insertions
     #[test]
+    #[allow(deprecated)]
     fn lwwmap_remove_capacity_management(
         node in node_id_strategy(),
         keys in prop::collection::vec(test_key_strategy(), 8..12), // More than capacity
@@ -633,6 +636,7 @@ proptest! {
     /// Test LWWMap remove all entries
     /// Property: removing all entries results in empty map
     #[test]
+    #[allow(deprecated)]
     fn lwwmap_remove_all_entries(
         node in node_id_strategy(),
         operations in prop::collection::vec((test_key_strategy(), test_value_strategy(), timestamp_strategy()), 1..8),
@@ -670,6 +674,7 @@ proptest! {
     /// Test LWWMap remove order independence
     /// Property: order of removal doesn't affect final state
     #[test]
+    #[allow(deprecated)]
     fn lwwmap_remove_order_independence(
         node in node_id_strategy(),
         keys in prop::collection::vec(test_key_strategy(), 3..6),
@@ -712,6 +717,30 @@ proptest! {
             prop_assert!(!map2.contains_key(&keys[1]));
         }
     }
+
+    /// Test that deletions survive merges in either order
+    /// Property: replicas converge and a deleted key stays hidden unless rewritten later
+    #[test]
+    fn lwwmap_delete_converges(
+        key in test_key_strategy(),
+        value in test_value_strategy(),
+        insert_ts in timestamp_strategy(),
+        delete_ts in timestamp_strategy(),
+    ) {
+        let mut map1 = LWWMap::<u8, u32, DefaultConfig>::new(1);
+        map1.insert(key, value, insert_ts).unwrap();
+        let mut map2 = map1.clone();
+        map2.delete(&key, delete_ts).unwrap();
+
+        let mut merged1 = map1.clone();
+        merged1.merge(&map2).unwrap();
+        let mut merged2 = map2.clone();
+        merged2.merge(&map1).unwrap();
+
+        prop_assert!(merged1.eq(&merged2));
+        prop_assert_eq!(merged1.contains_key(&key), insert_ts >= delete_ts);
+        prop_assert!(merged1.validate().is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -839,6 +868,37 @@ mod unit_tests {
         assert_eq!(values, [10, 20, 30]);
     }
 
+    #[test]
+    fn test_lwwmap_hlc_rounds_advance_monotonically() {
+        let mut hlc_a = HybridLogicalClock::new(1000);
+        let mut hlc_b = HybridLogicalClock::new(1000);
+        let mut map_a = LWWMap::<u8, u32, DefaultConfig>::new(1);
+        let mut map_b = LWWMap::<u8, u32, DefaultConfig>::new(2);
+        let mut last = 0u64;
+
+        for round in 0..10u32 {
+            // Both physical clocks are stuck in the same millisecond
+            map_a
+                .insert_with_hlc(1, round * 2, &mut hlc_a, 1000)
+                .unwrap();
+            let ts_a = map_a.get_timestamp(&1).unwrap().as_u64();
+            assert!(ts_a > last);
+
+            map_b.merge(&map_a).unwrap();
+            hlc_b.receive(hlc_a.now(), 1000).unwrap();
+            map_b
+                .insert_with_hlc(1, round * 2 + 1, &mut hlc_b, 1000)
+                .unwrap();
+            let ts_b = map_b.get_timestamp(&1).unwrap().as_u64();
+            assert!(ts_b > ts_a);
+
+            map_a.merge(&map_b).unwrap();
+            hlc_a.receive(hlc_b.now(), 1000).unwrap();
+            assert_eq!(map_a.get(&1), Some(&(round * 2 + 1)));
+            last = ts_b;
+        }
+    }
+
     #[test]
     fn test_lwwmap_metadata() {
         let mut map = LWWMap::<u8, u32, DefaultConfig>::new(1);
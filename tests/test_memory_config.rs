@@ -0,0 +1,13 @@
+//! Compile-time tests for `define_memory_config!`
+//!
+//! Every file in `tests/compile_fail` defines a configuration that must be rejected
+//! when the crate is compiled, with the error recorded next to it.
+
+// The recorded errors include CRDT sizes, which differ with atomic storage
+#![cfg(not(feature = "hardware-atomic"))]
+
+#[test]
+fn should_not_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
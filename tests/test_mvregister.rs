@@ -379,7 +379,7 @@ proptest! {
             let expected_max = expected_values.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
 
             if let Some(avg) = register.average() {
-                prop_assert!((avg - expected_avg).abs() < 0.001); // Float comparison with tolerance
+                prop_assert!((avg - expected_avg as f64).abs() < 0.001); // Float comparison with tolerance
             }
 
             if let Some(min) = register.min() {
@@ -0,0 +1,106 @@
+//! Property-based tests for ORMap CRDT
+//!
+//! This module tests the properties that ORMap must satisfy:
+//! - Commutativity: merge(a, b) = merge(b, a)
+//! - Associativity: merge(merge(a, b), c) = merge(a, merge(b, c))
+//! - Idempotence: merge(a, a) = a
+//! - Add-wins: a write the remover never observed survives the remove
+
+#![allow(unused_mut)]
+#![allow(special_module_name)]
+
+use crdtosphere::prelude::*;
+use proptest::prelude::*;
+
+mod lib;
+use lib::*;
+
+/// Large enough that merging three replicas never overflows
+type Map = ORMap<u8, u32, DefaultConfig, 32>;
+
+/// Map operations on a small key space
+#[derive(Debug, Clone)]
+enum MapOp {
+    Insert(u8, u32, u64),
+    Remove(u8, u64),
+}
+
+fn map_op_strategy() -> impl Strategy<Value = MapOp> {
+    prop_oneof![
+        (0u8..4, any::<u32>(), 1u64..1000).prop_map(|(k, v, ts)| MapOp::Insert(k, v, ts)),
+        (0u8..4, 1u64..1000).prop_map(|(k, ts)| MapOp::Remove(k, ts)),
+    ]
+}
+
+/// Generate a map for one node by replaying a sequence of operations
+///
+/// Each replica gets its own node ID, since tags must be unique per writer.
+fn map_strategy(node_id: u8) -> impl Strategy<Value = Map> {
+    prop::collection::vec(map_op_strategy(), 0..8).prop_map(move |ops| {
+        let mut map = Map::with_capacity(node_id);
+        for op in ops {
+            match op {
+                MapOp::Insert(k, v, ts) => {
+                    let _ = map.insert(k, v, ts);
+                }
+                MapOp::Remove(k, ts) => {
+                    let _ = map.remove(&k, ts);
+                }
+            }
+        }
+        map
+    })
+}
+
+proptest! {
+    #![proptest_config(crdt_config())]
+
+    /// Property: merge(a, b) = merge(b, a)
+    #[test]
+    fn ormap_merge_is_commutative(a in map_strategy(1), b in map_strategy(2)) {
+        prop_assert!(assert_crdt_commutativity(&a, &b));
+    }
+
+    /// Property: merge(merge(a, b), c) = merge(a, merge(b, c))
+    #[test]
+    fn ormap_merge_is_associative(
+        a in map_strategy(1),
+        b in map_strategy(2),
+        c in map_strategy(3),
+    ) {
+        prop_assert!(assert_crdt_associativity(&a, &b, &c));
+    }
+
+    /// Property: merge(a, a) = a
+    #[test]
+    fn ormap_merge_is_idempotent(a in map_strategy(1)) {
+        prop_assert!(assert_crdt_idempotence(&a));
+    }
+
+    /// Property: a concurrent insert survives a remove that didn't observe it
+    #[test]
+    fn ormap_concurrent_insert_wins(
+        key in 0u8..4,
+        value in any::<u32>(),
+        insert_ts in 1u64..1000,
+        remove_ts in 1u64..1000,
+    ) {
+        let mut remover = Map::with_capacity(1);
+        let mut writer = Map::with_capacity(2);
+
+        remover.insert(key, 0, 1).unwrap();
+        remover.remove(&key, remove_ts).unwrap();
+        writer.insert(key, value, insert_ts).unwrap();
+
+        remover.merge(&writer).unwrap();
+        prop_assert_eq!(remover.get(&key), Some(&value));
+    }
+
+    /// Property: merged state stays valid
+    #[test]
+    fn ormap_merge_preserves_validity(a in map_strategy(1), b in map_strategy(2)) {
+        let mut merged = a.clone();
+        merged.merge(&b).unwrap();
+        prop_assert!(merged.validate().is_ok());
+    }
+}
@@ -5,6 +5,7 @@
 //! - Associativity: merge(merge(a, b), c) = merge(a, merge(b, c))
 //! - Idempotence: merge(a, a) = a
 //! - Increment/Decrement semantics: values can increase and decrease
+//! - Bounded counters: clamped merges stay commutative and idempotent
 //! - Eventual consistency: all replicas converge
 
 #![allow(unused_mut)]
@@ -364,6 +365,46 @@ proptest! {
         prop_assert_eq!(counter1.value(), counter2.value());
         prop_assert_eq!(counter1.value(), inc_count as i64 - dec_count as i64);
     }
+
+    /// Test that bounded counters stay mergeable after clamping independently
+    /// Property: merge is commutative and idempotent, and the result stays in bounds
+    #[test]
+    fn pncounter_bounded_merge_is_commutative_and_idempotent(
+        node1 in node_id_strategy(),
+        node2 in node_id_strategy(),
+        node3 in node_id_strategy(),
+        operations1 in operation_sequence_strategy(counter_operation_strategy()),
+        operations2 in operation_sequence_strategy(counter_operation_strategy()),
+        operations3 in operation_sequence_strategy(counter_operation_strategy()),
+    ) {
+        let mut replicas = [
+            PNCounter::<DefaultConfig>::with_bounds(node1, 0, 200),
+            PNCounter::<DefaultConfig>::with_bounds(node2, 0, 200),
+            PNCounter::<DefaultConfig>::with_bounds(node3, 0, 200),
+        ];
+        for (counter, operations) in replicas.iter_mut().zip([operations1, operations2, operations3]) {
+            for op in operations {
+                match op {
+                    CounterOp::Increment(amount) => { let _ = counter.saturating_increment(amount); }
+                    CounterOp::Decrement(amount) => { let _ = counter.saturating_decrement(amount); }
+                }
+            }
+        }
+
+        // Each side clamps its own merge before they meet
+        let mut a = replicas[0].clone();
+        let _ = a.merge(&replicas[1]);
+        let mut b = replicas[2].clone();
+        let _ = b.merge(&replicas[1]);
+
+        prop_assert!(assert_crdt_commutativity(&a, &b));
+        prop_assert!(assert_crdt_idempotence(&a));
+
+        let mut merged = a.clone();
+        merged.merge(&b).unwrap();
+        prop_assert!(merged.validate().is_ok());
+        prop_assert!(assert_crdt_idempotence(&merged));
+    }
 }
 
 #[cfg(test)]
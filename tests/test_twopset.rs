@@ -0,0 +1,103 @@
+//! Property-based tests for TwoPSet CRDT
+//!
+//! This module tests the properties that TwoPSet must satisfy:
+//! - Commutativity: merge(a, b) = merge(b, a)
+//! - Associativity: merge(merge(a, b), c) = merge(a, merge(b, c))
+//! - Idempotence: merge(a, a) = a
+//! - Irreversibility: a removed element can never be added back
+
+#![allow(unused_mut)]
+#![allow(special_module_name)]
+
+use crdtosphere::prelude::*;
+use crdtosphere::sets::TwoPSet;
+use proptest::prelude::*;
+
+mod lib;
+use lib::*;
+
+type Set = TwoPSet<u32, DefaultConfig>;
+
+/// Set operations; values stay below the default capacity so merges never overflow
+#[derive(Debug, Clone)]
+enum SetOp {
+    Add(u32),
+    Remove(u32),
+}
+
+fn set_op_strategy() -> impl Strategy<Value = SetOp> {
+    prop_oneof![
+        (0u32..8).prop_map(SetOp::Add),
+        (0u32..8).prop_map(SetOp::Remove),
+    ]
+}
+
+/// Generate a set by replaying a sequence of operations
+fn set_strategy() -> impl Strategy<Value = Set> {
+    prop::collection::vec(set_op_strategy(), 0..12).prop_map(|ops| {
+        let mut set = Set::new();
+        for op in ops {
+            match op {
+                SetOp::Add(x) => {
+                    let _ = set.add(x);
+                }
+                SetOp::Remove(x) => {
+                    let _ = set.remove(&x);
+                }
+            }
+        }
+        set
+    })
+}
+
+proptest! {
+    #![proptest_config(crdt_config())]
+
+    /// Property: merge(a, b) = merge(b, a)
+    #[test]
+    fn twopset_merge_is_commutative(a in set_strategy(), b in set_strategy()) {
+        prop_assert!(assert_crdt_commutativity(&a, &b));
+    }
+
+    /// Property: merge(merge(a, b), c) = merge(a, merge(b, c))
+    #[test]
+    fn twopset_merge_is_associative(
+        a in set_strategy(),
+        b in set_strategy(),
+        c in set_strategy(),
+    ) {
+        prop_assert!(assert_crdt_associativity(&a, &b, &c));
+    }
+
+    /// Property: merge(a, a) = a
+    #[test]
+    fn twopset_merge_is_idempotent(a in set_strategy()) {
+        prop_assert!(assert_crdt_idempotence(&a));
+    }
+
+    /// Property: after remove(x) returns Ok(true), add(x) returns InvalidOperation
+    #[test]
+    fn twopset_remove_is_irreversible(
+        mut set in set_strategy(),
+        x in 0u32..8,
+        other in set_strategy(),
+    ) {
+        let _ = set.add(x);
+        if set.remove(&x) == Ok(true) {
+            prop_assert_eq!(set.add(x), Err(CRDTError::InvalidOperation));
+
+            // Merging in another replica cannot resurrect the element
+            let _ = set.merge(&other);
+            prop_assert!(!set.contains(&x));
+            prop_assert_eq!(set.add(x), Err(CRDTError::InvalidOperation));
+        }
+    }
+
+    /// Property: merged state stays valid
+    #[test]
+    fn twopset_merge_preserves_validity(a in set_strategy(), b in set_strategy()) {
+        let mut merged = a.clone();
+        merged.merge(&b).unwrap();
+        prop_assert!(merged.validate().is_ok());
+    }
+}
@@ -0,0 +1,152 @@
+//! Property-based tests for VectorClock
+//!
+//! This module tests the properties that VectorClock must satisfy:
+//! - happens_before is a strict partial order (irreflexive, asymmetric, transitive)
+//! - Merge is commutative, associative and idempotent
+//! - Merge produces an upper bound of both inputs
+
+#![allow(unused_mut)]
+#![allow(special_module_name)]
+
+use crdtosphere::clock::VectorClock;
+use crdtosphere::prelude::*;
+use proptest::prelude::*;
+
+mod lib;
+use lib::*;
+
+type Clock = VectorClock<DefaultConfig, 8>;
+
+/// Generate a clock by replaying a sequence of node events
+fn clock_strategy() -> impl Strategy<Value = Clock> {
+    prop::collection::vec(0u8..8, 0..20).prop_map(|events| {
+        let mut clock = Clock::new();
+        for node in events {
+            clock.increment(node).unwrap();
+        }
+        clock
+    })
+}
+
+proptest! {
+    #![proptest_config(crdt_config())]
+
+    /// Property: !(a < a)
+    #[test]
+    fn vector_clock_happens_before_is_irreflexive(a in clock_strategy()) {
+        prop_assert!(!a.happens_before(&a));
+        prop_assert!(!a.concurrent_with(&a));
+    }
+
+    /// Property: a < b implies !(b < a)
+    #[test]
+    fn vector_clock_happens_before_is_asymmetric(
+        a in clock_strategy(),
+        b in clock_strategy(),
+    ) {
+        if a.happens_before(&b) {
+            prop_assert!(!b.happens_before(&a));
+        }
+    }
+
+    /// Property: a < b and b < c implies a < c
+    #[test]
+    fn vector_clock_happens_before_is_transitive(
+        a in clock_strategy(),
+        events_b in prop::collection::vec(0u8..8, 1..5),
+        events_c in prop::collection::vec(0u8..8, 1..5),
+    ) {
+        // Build b and c as causal successors of a
+        let mut b = a;
+        for node in events_b {
+            b.increment(node).unwrap();
+        }
+        let mut c = b;
+        for node in events_c {
+            c.increment(node).unwrap();
+        }
+
+        prop_assert!(a.happens_before(&b));
+        prop_assert!(b.happens_before(&c));
+        prop_assert!(a.happens_before(&c));
+    }
+
+    /// Property: exactly one of equal, before, after or concurrent holds
+    #[test]
+    fn vector_clock_ordering_is_exclusive(
+        a in clock_strategy(),
+        b in clock_strategy(),
+    ) {
+        let relations = [
+            a == b,
+            a.happens_before(&b),
+            b.happens_before(&a),
+            a.concurrent_with(&b),
+        ];
+        prop_assert_eq!(relations.iter().filter(|&&r| r).count(), 1);
+    }
+
+    /// Property: merge(a, a) = a
+    #[test]
+    fn vector_clock_merge_is_idempotent(a in clock_strategy()) {
+        let mut merged = a;
+        merged.merge(&a);
+        prop_assert_eq!(merged, a);
+    }
+
+    /// Property: merge(a, b) = merge(b, a)
+    #[test]
+    fn vector_clock_merge_is_commutative(
+        a in clock_strategy(),
+        b in clock_strategy(),
+    ) {
+        let mut ab = a;
+        ab.merge(&b);
+        let mut ba = b;
+        ba.merge(&a);
+        prop_assert_eq!(ab, ba);
+    }
+
+    /// Property: merge(merge(a, b), c) = merge(a, merge(b, c))
+    #[test]
+    fn vector_clock_merge_is_associative(
+        a in clock_strategy(),
+        b in clock_strategy(),
+        c in clock_strategy(),
+    ) {
+        let mut left = a;
+        left.merge(&b);
+        left.merge(&c);
+
+        let mut bc = b;
+        bc.merge(&c);
+        let mut right = a;
+        right.merge(&bc);
+
+        prop_assert_eq!(left, right);
+    }
+
+    /// Property: a <= merge(a, b) and b <= merge(a, b)
+    #[test]
+    fn vector_clock_merge_is_upper_bound(
+        a in clock_strategy(),
+        b in clock_strategy(),
+    ) {
+        let mut merged = a;
+        merged.merge(&b);
+
+        prop_assert!(merged == a || a.happens_before(&merged));
+        prop_assert!(merged == b || b.happens_before(&merged));
+    }
+
+    /// Property: a < b implies timestamp(a) < timestamp(b)
+    #[test]
+    fn vector_clock_timestamp_preserves_causality(
+        a in clock_strategy(),
+        b in clock_strategy(),
+    ) {
+        if a.happens_before(&b) {
+            prop_assert!(CompactTimestamp::from(a) < CompactTimestamp::from(b));
+        }
+    }
+}